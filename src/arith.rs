@@ -14,6 +14,8 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
+    int256::{self, U256},
+    rounding::Discarded,
     types::extra::{If, True},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
@@ -681,6 +683,48 @@ macro_rules! mul_div_widen {
                 }
             }
 
+            // Like overflowing_mul, but the product is incremented by one after
+            // it is shifted right if round_up returns true. round_up is only
+            // called when non-zero bits are discarded, and is passed the
+            // discarded bits as returned by discarded_bits.
+            #[inline]
+            pub fn overflowing_mul_round_up<F>(
+                lhs: $Single,
+                rhs: $Single,
+                frac_nbits: i32,
+                round_up: F,
+            ) -> ($Single, bool)
+            where
+                F: FnOnce(u128) -> bool,
+            {
+                const BITS2: i32 = <$Double>::BITS as i32;
+
+                if frac_nbits <= 0 {
+                    return overflowing_mul(lhs, rhs, frac_nbits);
+                }
+                let prod2 = lhs as $Double * rhs as $Double;
+                let (lo, ext) = if_signed_unsigned!(
+                    $Signedness,
+                    (prod2 as i128 as u128, if prod2 < 0 { u128::MAX } else { 0 }),
+                    (prod2 as u128, 0),
+                );
+                let discarded = super::discarded_bits(ext, lo, ext, frac_nbits as u32);
+                let shifted2 = if frac_nbits >= BITS2 {
+                    ext as $Double
+                } else {
+                    prod2 >> frac_nbits
+                };
+                // shifted2 has at least one spare bit, so this cannot overflow
+                let shifted2 = if discarded != 0 && round_up(discarded) {
+                    shifted2 + 1
+                } else {
+                    shifted2
+                };
+                let shifted = shifted2 as $Single;
+                let overflow = (shifted as $Double) != shifted2;
+                (shifted, overflow)
+            }
+
             #[inline]
             pub const fn overflowing_mul_add(
                 m1: $Single,
@@ -822,6 +866,46 @@ pub mod u128 {
         }
     }
 
+    // Like overflowing_mul, but the product is incremented by one after it is
+    // shifted right if round_up returns true. round_up is only called when
+    // non-zero bits are discarded, and is passed the discarded bits as returned
+    // by discarded_bits.
+    #[inline]
+    pub fn overflowing_mul_round_up<F>(
+        lhs: u128,
+        rhs: u128,
+        frac_nbits: i32,
+        round_up: F,
+    ) -> (u128, bool)
+    where
+        F: FnOnce(u128) -> bool,
+    {
+        if frac_nbits <= 0 {
+            return overflowing_mul(lhs, rhs, frac_nbits);
+        }
+        let frac_nbits = frac_nbits as u32;
+        let prod = int256::wide_mul_u128(lhs, rhs);
+        let discarded = super::discarded_bits(prod.hi, prod.lo, 0, frac_nbits);
+        let (hi, lo) = if frac_nbits >= 256 {
+            (0, 0)
+        } else if frac_nbits >= 128 {
+            (0, prod.hi >> (frac_nbits - 128))
+        } else {
+            (
+                prod.hi >> frac_nbits,
+                (prod.lo >> frac_nbits) | (prod.hi << (128 - frac_nbits)),
+            )
+        };
+        if discarded != 0 && round_up(discarded) {
+            let (lo, carry) = lo.overflowing_add(1);
+            // hi < 2^127, so this cannot overflow
+            let hi = hi + carry as u128;
+            (lo, hi != 0)
+        } else {
+            (lo, hi != 0)
+        }
+    }
+
     #[inline]
     pub const fn overflowing_mul_add(
         m1: u128,
@@ -923,6 +1007,48 @@ pub mod i128 {
         }
     }
 
+    // Like overflowing_mul, but the product is incremented by one after it is
+    // shifted right if round_up returns true. round_up is only called when
+    // non-zero bits are discarded, and is passed the discarded bits as returned
+    // by discarded_bits.
+    #[inline]
+    pub fn overflowing_mul_round_up<F>(
+        lhs: i128,
+        rhs: i128,
+        frac_nbits: i32,
+        round_up: F,
+    ) -> (i128, bool)
+    where
+        F: FnOnce(u128) -> bool,
+    {
+        if frac_nbits <= 0 {
+            return overflowing_mul(lhs, rhs, frac_nbits);
+        }
+        let frac_nbits = frac_nbits as u32;
+        let prod = int256::wide_mul_i128(lhs, rhs);
+        let ext = prod.hi >> 127;
+        let discarded = super::discarded_bits(prod.hi as u128, prod.lo, ext as u128, frac_nbits);
+        let (hi, lo) = if frac_nbits >= 256 {
+            (ext, ext as u128)
+        } else if frac_nbits >= 128 {
+            (ext, (prod.hi >> (frac_nbits - 128)) as u128)
+        } else {
+            (
+                prod.hi >> frac_nbits,
+                (prod.lo >> frac_nbits) | ((prod.hi as u128) << (128 - frac_nbits)),
+            )
+        };
+        let (hi, lo) = if discarded != 0 && round_up(discarded) {
+            let (lo, carry) = lo.overflowing_add(1);
+            // |hi| < 2^126, so this cannot overflow
+            (hi + carry as i128, lo)
+        } else {
+            (hi, lo)
+        };
+        let val = lo as i128;
+        (val, hi != val >> 127)
+    }
+
     #[inline]
     pub const fn overflowing_mul_add(
         m1: i128,
@@ -1019,6 +1145,118 @@ pub const fn saturating_add_sub_i32(a: i32, b: i32, c: i32) -> i32 {
     }
 }
 
+// Returns the bits that are discarded when a 256-bit number hi:lo, extended with
+// ext which is either zero or all ones, is shifted right by shift. Only the 128
+// most significant discarded bits are kept, and they are aligned so that the
+// most significant bit has a weight of one half of the least significant bit of
+// the shifted number.
+pub const fn discarded_bits(hi: u128, lo: u128, ext: u128, shift: u32) -> u128 {
+    if shift == 0 {
+        0
+    } else if shift <= 128 {
+        lo << (128 - shift)
+    } else if shift < 256 {
+        (hi << (256 - shift)) | (lo >> (shift - 128))
+    } else if shift == 256 {
+        hi
+    } else if shift < 384 {
+        (ext << (384 - shift)) | (hi >> (shift - 256))
+    } else {
+        ext
+    }
+}
+
+// Returns the reciprocal of val with frac_nbits fractional bits truncated, that
+// is 2^(2 frac_nbits) / val truncated. The returned bool is true if the
+// truncated reciprocal does not fit in u128, in which case the low bits are
+// returned.
+pub const fn recip_u128(val: NonZeroU128, frac_nbits: u32) -> (u128, bool, Discarded) {
+    // 2^(2 frac_nbits) can need 257 bits, so 2^(2 frac_nbits) - 1 is divided
+    // instead and the quotient and remainder are then adjusted.
+    let nbits = 2 * frac_nbits;
+    let lo = if nbits == 0 {
+        0
+    } else if nbits < 128 {
+        u128::MAX >> (128 - nbits)
+    } else {
+        u128::MAX
+    };
+    let hi = if nbits <= 128 {
+        0
+    } else {
+        u128::MAX >> (256 - nbits)
+    };
+    let (q, r) = int256::div_rem_u256_u128(U256 { lo, hi }, val);
+    // 2^(2 frac_nbits) = q × val + r + 1, with r + 1 ≤ val
+    let d = val.get();
+    let r = r + 1;
+    if r == d {
+        let (q, carry) = int256::overflowing_add_u256(q, U256 { lo: 1, hi: 0 });
+        return (q.lo, carry || q.hi != 0, Discarded::Zero);
+    }
+    (q.lo, q.hi != 0, rem_discarded(r, d))
+}
+
+// Returns lhs × rhs with frac_nbits fractional bits truncated, where 0 ≤
+// frac_nbits ≤ 128. The returned bool is true if the truncated product does not
+// fit in u128, in which case the low bits are returned.
+pub const fn mul_u128(lhs: u128, rhs: u128, frac_nbits: u32) -> (u128, bool, Discarded) {
+    let prod = int256::wide_mul_u128(lhs, rhs);
+    if frac_nbits == 0 {
+        return (prod.lo, prod.hi != 0, Discarded::Zero);
+    }
+    let (q, overflow, rem) = if frac_nbits == 128 {
+        (prod.hi, false, prod.lo)
+    } else {
+        let q = (prod.lo >> frac_nbits) | (prod.hi << (128 - frac_nbits));
+        let rem = prod.lo << (128 - frac_nbits);
+        (q, prod.hi >> frac_nbits != 0, rem)
+    };
+    // rem holds the discarded bits in its most significant bits
+    let discarded = if rem == 0 {
+        Discarded::Zero
+    } else if rem < 1 << 127 {
+        Discarded::LessThanHalf
+    } else if rem == 1 << 127 {
+        Discarded::Half
+    } else {
+        Discarded::GreaterThanHalf
+    };
+    (q, overflow, discarded)
+}
+
+// Returns lhs / rhs with frac_nbits fractional bits truncated, where 0 ≤
+// frac_nbits ≤ 128. The returned bool is true if the truncated quotient does
+// not fit in u128, in which case the low bits are returned.
+pub const fn div_u128(lhs: u128, rhs: NonZeroU128, frac_nbits: u32) -> (u128, bool, Discarded) {
+    let lhs2 = if frac_nbits == 0 {
+        U256 { lo: lhs, hi: 0 }
+    } else if frac_nbits == 128 {
+        U256 { lo: 0, hi: lhs }
+    } else {
+        U256 {
+            lo: lhs << frac_nbits,
+            hi: lhs >> (128 - frac_nbits),
+        }
+    };
+    let (q, r) = int256::div_rem_u256_u128(lhs2, rhs);
+    (q.lo, q.hi != 0, rem_discarded(r, rhs.get()))
+}
+
+// Returns the discarded part of a quotient with remainder rem < divisor.
+const fn rem_discarded(rem: u128, divisor: u128) -> Discarded {
+    // compare rem with divisor / 2 by comparing rem with divisor - rem
+    if rem == 0 {
+        Discarded::Zero
+    } else if rem < divisor - rem {
+        Discarded::LessThanHalf
+    } else if rem == divisor - rem {
+        Discarded::Half
+    } else {
+        Discarded::GreaterThanHalf
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -1805,4 +2043,238 @@ mod tests {
             (I0F128::from_bits(-2), true)
         );
     }
+
+    macro_rules! check_recip_round {
+        ($Fixed:ident, $Inner:ident, $frac:literal) => {{
+            use crate::rounding::tests::ALL_MODES;
+            type F = $Fixed<$frac>;
+            for bits in $Inner::MIN..=$Inner::MAX {
+                if bits == 0 {
+                    continue;
+                }
+                let x = F::from_bits(bits);
+                let exact = (2.0 * $frac as f64).exp2() / bits as f64;
+                for mode in ALL_MODES {
+                    let tie = (exact - exact.trunc()).abs() == 0.5;
+                    let expected = match mode {
+                        RoundingMode::ToZero => exact.trunc(),
+                        RoundingMode::AwayFromZero if exact < 0.0 => exact.floor(),
+                        RoundingMode::AwayFromZero => exact.ceil(),
+                        RoundingMode::Floor => exact.floor(),
+                        RoundingMode::Ceil => exact.ceil(),
+                        RoundingMode::HalfEven => exact.round_ties_even(),
+                        RoundingMode::HalfAwayFromZero => exact.round(),
+                        RoundingMode::HalfToZero if tie => exact.trunc(),
+                        RoundingMode::HalfToZero => exact.round(),
+                    };
+                    let overflow = expected < $Inner::MIN as f64 || expected > $Inner::MAX as f64;
+                    let wrapped = F::from_bits(expected as i64 as $Inner);
+                    assert_eq!(
+                        x.overflowing_recip_round(mode),
+                        (wrapped, overflow),
+                        "{x} {mode:?}"
+                    );
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn recip_round_8() {
+        check_recip_round!(FixedU8, u8, 0);
+        check_recip_round!(FixedU8, u8, 3);
+        check_recip_round!(FixedU8, u8, 7);
+        check_recip_round!(FixedU8, u8, 8);
+        check_recip_round!(FixedI8, i8, 0);
+        check_recip_round!(FixedI8, i8, 3);
+        check_recip_round!(FixedI8, i8, 7);
+        check_recip_round!(FixedI8, i8, 8);
+    }
+
+    #[test]
+    fn recip_round_128() {
+        let third_floor = FixedU128::<64>::from_bits(u64::MAX as u128 / 3);
+        let third_ceil = FixedU128::<64>::from_bits(u64::MAX as u128 / 3 + 1);
+        let three = FixedU128::<64>::from_num(3);
+        assert_eq!(three.recip_round(RoundingMode::Floor), third_floor);
+        assert_eq!(three.recip_round(RoundingMode::HalfEven), third_floor);
+        assert_eq!(three.recip_round(RoundingMode::Ceil), third_ceil);
+        let neg_three = FixedI128::<64>::from_num(-3);
+        let third_floor = FixedI128::<64>::from_bits(-(third_ceil.to_bits() as i128));
+        assert_eq!(neg_three.recip_round(RoundingMode::Floor), third_floor);
+
+        // 2^256 does not fit in 256 bits
+        let min = FixedU128::<128>::DELTA;
+        assert_eq!(
+            min.overflowing_recip_round(RoundingMode::ToZero),
+            (FixedU128::ZERO, true)
+        );
+        let half = FixedU128::<128>::from_num(0.5);
+        assert_eq!(half.checked_recip_round(RoundingMode::Floor), None);
+        let neg_one = FixedI128::<127>::NEG_ONE;
+        assert_eq!(
+            neg_one.overflowing_recip_round(RoundingMode::Ceil),
+            (neg_one, false)
+        );
+        let neg_max = -FixedI128::<127>::MAX;
+        // −1/(1 − 2^−127) is slightly less than −1
+        assert_eq!(
+            neg_max.overflowing_recip_round(RoundingMode::ToZero),
+            (FixedI128::<127>::MAX, true)
+        );
+    }
+
+    macro_rules! check_mul_div_round {
+        ($Fixed:ident, $Inner:ident, $frac:expr) => {{
+            use crate::rounding::tests::{round_ratio, ALL_MODES};
+            type F = $Fixed<$frac>;
+            let wrap = |v: i128| -> ($Inner, bool) {
+                let wrapped = v as $Inner;
+                (wrapped, i128::from(wrapped) != v)
+            };
+            for a in $Inner::MIN..=$Inner::MAX {
+                for b in $Inner::MIN..=$Inner::MAX {
+                    let (x, y) = (F::from_bits(a), F::from_bits(b));
+                    let (a, b) = (i128::from(a), i128::from(b));
+                    for mode in ALL_MODES {
+                        let prod = wrap(round_ratio(a * b, 1 << $frac, mode));
+                        let ans = x.overflowing_mul_round(y, mode);
+                        assert_eq!((ans.0.to_bits(), ans.1), prod, "{x} * {y} {mode:?}");
+                        if b != 0 {
+                            let (num, den) = if b < 0 { (-a, -b) } else { (a, b) };
+                            let quot = wrap(round_ratio(num << $frac, den, mode));
+                            let ans = x.overflowing_div_round(y, mode);
+                            assert_eq!((ans.0.to_bits(), ans.1), quot, "{x} / {y} {mode:?}");
+                        } else {
+                            assert_eq!(x.checked_div_round(y, mode), None);
+                        }
+                    }
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn mul_div_round_8() {
+        check_mul_div_round!(FixedU8, u8, 0);
+        check_mul_div_round!(FixedU8, u8, 5);
+        check_mul_div_round!(FixedU8, u8, 8);
+        check_mul_div_round!(FixedI8, i8, 0);
+        check_mul_div_round!(FixedI8, i8, 3);
+        check_mul_div_round!(FixedI8, i8, 8);
+    }
+
+    #[test]
+    fn mul_div_round_128() {
+        // (2^64 + 1) × 2^-65 = 0.5 + 2^-65
+        let a = FixedU128::<64>::from_bits((1 << 64) + 1);
+        let b = FixedU128::<64>::from_num(0.5);
+        let half = FixedU128::<64>::from_num(0.5);
+        let up = half + FixedU128::<64>::DELTA;
+        assert_eq!(a.mul_round(b, RoundingMode::Floor), half);
+        assert_eq!(a.mul_round(b, RoundingMode::HalfEven), half);
+        assert_eq!(a.mul_round(b, RoundingMode::HalfAwayFromZero), up);
+        assert_eq!(a.mul_round(b, RoundingMode::HalfToZero), half);
+        assert_eq!(a.mul_round(b, RoundingMode::Ceil), up);
+        let neg = FixedI128::<64>::from_bits(-((1 << 64) + 1));
+        let b = FixedI128::<64>::from_num(0.5);
+        let half = FixedI128::<64>::from_num(-0.5);
+        assert_eq!(neg.mul_round(b, RoundingMode::Ceil), half);
+        assert_eq!(neg.mul_round(b, RoundingMode::ToZero), half);
+        assert_eq!(
+            neg.mul_round(b, RoundingMode::Floor),
+            half - FixedI128::<64>::DELTA
+        );
+
+        // 2^128 / 3 = (2^128 - 1) / 3 + 1/3
+        let one = FixedU128::<127>::from_num(1);
+        let one_point_5 = FixedU128::<127>::from_num(1.5);
+        let two_thirds_floor = FixedU128::<127>::from_bits(u128::MAX / 3);
+        let two_thirds_ceil = two_thirds_floor + FixedU128::<127>::DELTA;
+        assert_eq!(
+            one.div_round(one_point_5, RoundingMode::Floor),
+            two_thirds_floor
+        );
+        assert_eq!(
+            one.div_round(one_point_5, RoundingMode::HalfEven),
+            two_thirds_floor
+        );
+        assert_eq!(
+            one.div_round(one_point_5, RoundingMode::Ceil),
+            two_thirds_ceil
+        );
+        let max = FixedI128::<0>::MAX;
+        let half = FixedI128::<0>::from_bits(2);
+        assert_eq!(
+            max.overflowing_div_round(half, RoundingMode::AwayFromZero),
+            (FixedI128::<0>::from_bits(i128::MAX / 2 + 1), false)
+        );
+        assert_eq!(
+            FixedI128::<0>::MIN.overflowing_div_round(FixedI128::<0>::NEG_ONE, RoundingMode::Floor),
+            (FixedI128::<0>::MIN, true)
+        );
+    }
+
+    macro_rules! check_mul_round_up {
+        ($Inner:ident, $frac:expr) => {{
+            use crate::rounding::tests::round_ratio;
+            use crate::rounding::RoundingMode;
+            let wrap = |v: i128| -> ($Inner, bool) {
+                let wrapped = v as $Inner;
+                (wrapped, i128::from(wrapped) != v)
+            };
+            for a in $Inner::MIN..=$Inner::MAX {
+                for b in $Inner::MIN..=$Inner::MAX {
+                    let exact = i128::from(a) * i128::from(b);
+                    let rem = exact.rem_euclid(1 << $frac) as u128;
+                    let check = |discarded: u128| {
+                        assert_eq!(discarded, rem << (128 - $frac), "{a} * {b}");
+                        true
+                    };
+                    let up = super::$Inner::overflowing_mul_round_up(a, b, $frac, check);
+                    let ceil = wrap(round_ratio(exact, 1 << $frac, RoundingMode::Ceil));
+                    assert_eq!(up, ceil, "{a} * {b}");
+                    let down = super::$Inner::overflowing_mul_round_up(a, b, $frac, |_| false);
+                    let floor = wrap(round_ratio(exact, 1 << $frac, RoundingMode::Floor));
+                    assert_eq!(down, floor, "{a} * {b}");
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn mul_round_up_8() {
+        check_mul_round_up!(u8, 1);
+        check_mul_round_up!(u8, 8);
+        check_mul_round_up!(u8, 13);
+        check_mul_round_up!(u8, 20);
+        check_mul_round_up!(i8, 1);
+        check_mul_round_up!(i8, 7);
+        check_mul_round_up!(i8, 11);
+        check_mul_round_up!(i8, 20);
+    }
+
+    #[test]
+    fn mul_round_up_128() {
+        // (2^64 + 1) × 2^-65 = 0.5 + 2^-65, and the discarded 2^-65 is half
+        // the least significant bit
+        let (a, b) = ((1u128 << 64) + 1, 1u128 << 63);
+        let up = super::u128::overflowing_mul_round_up(a, b, 64, |d| d == 1 << 127);
+        assert_eq!(up, ((1 << 63) + 1, false));
+        let (a, b) = (-(1i128 << 64) - 1, 1i128 << 63);
+        let up = super::i128::overflowing_mul_round_up(a, b, 64, |d| d == 1 << 127);
+        assert_eq!(up, (-(1 << 63), false));
+        let down = super::i128::overflowing_mul_round_up(a, b, 64, |_| false);
+        assert_eq!(down, (-(1 << 63) - 1, false));
+
+        // -2^-300 is floored to -1, and the discarded bits beyond the 256-bit
+        // product are all ones, so the discarded fraction is just below one
+        let up = super::i128::overflowing_mul_round_up(-1, 1, 300, |d| d == !0);
+        assert_eq!(up, (0, false));
+        // (2^128 - 1)² × 2^-128 = 2^128 - 2 + 2^-128
+        let up = super::u128::overflowing_mul_round_up(u128::MAX, u128::MAX, 128, |d| d == 1);
+        assert_eq!(up, (u128::MAX, false));
+        let up = super::u128::overflowing_mul_round_up(u128::MAX, 2, 0, |_| true);
+        assert_eq!(up, (u128::MAX - 1, true));
+    }
 }
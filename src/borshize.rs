@@ -14,10 +14,11 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    types::extra::{Storage, Width},
+    FixedI, FixedI128, FixedI16, FixedI24, FixedI32, FixedI48, FixedI64, FixedI8, FixedU,
+    FixedU128, FixedU16, FixedU24, FixedU32, FixedU48, FixedU64, FixedU8,
 };
-use borsh::io::{Read, Result, Write};
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
 use borsh::{BorshDeserialize, BorshSerialize};
 
 macro_rules! borsh_fixed {
@@ -48,3 +49,38 @@ borsh_fixed! { FixedU16 is u16 }
 borsh_fixed! { FixedU32 is u32 }
 borsh_fixed! { FixedU64 is u64 }
 borsh_fixed! { FixedU128 is u128 }
+
+// Numbers with fewer bits than their underlying integer are serialized as the
+// underlying integer, and bits that do not fit are a deserialization error.
+macro_rules! borsh_narrow {
+    ($Fixed:ident<$($Param:ident),*>, where [$($bound:tt)*]; $Bits:ty) => {
+        impl<$(const $Param: i32),*> BorshSerialize for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                self.to_bits().serialize(writer)
+            }
+        }
+
+        impl<$(const $Param: i32),*> BorshDeserialize for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                let bits = <$Bits>::deserialize_reader(reader)?;
+                $Fixed::checked_from_bits(bits)
+                    .ok_or_else(|| Error::new(ErrorKind::InvalidData, "bits do not fit"))
+            }
+        }
+    };
+}
+
+borsh_narrow! { FixedI24<FRAC>, where []; i32 }
+borsh_narrow! { FixedI48<FRAC>, where []; i64 }
+borsh_narrow! { FixedU24<FRAC>, where []; u32 }
+borsh_narrow! { FixedU48<FRAC>, where []; u64 }
+borsh_narrow! { FixedI<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,]; <Width<{ INT + FRAC }> as Storage>::Signed }
+borsh_narrow! { FixedU<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,]; <Width<{ INT + FRAC }> as Storage>::Unsigned }
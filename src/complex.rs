@@ -0,0 +1,1084 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::dot::saturate;
+use crate::traits::Fixed;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+// Generates the checked, wrapping and unwrapped variants of an operation from
+// its overflowing variant.
+macro_rules! complex_op {
+    (
+        {op, result} = {$op:literal, $result:literal},
+        fn($($arg:ident: $Arg:ty)?) -> $Ret:ty,
+        {checked, wrapping, unwrapped, overflowing} =
+            {$checked:ident, $wrapping:ident, $unwrapped:ident, $overflowing:ident},
+        {lets, lhs, args, ans} = {$lets:literal, $lhs:literal, $args:literal, $ans:literal},
+        {over_lets, over_lhs, over_args, wrap} =
+            {$over_lets:literal, $over_lhs:literal, $over_args:literal, $wrap:literal},
+    ) => {
+        comment! {
+            "Checked ", $op, ". Returns the ", $result, ", or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Complex};
+type C = Complex<I16F16>;
+let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+", $lets, $over_lets,
+            "assert_eq!(", $lhs, ".", stringify!($checked), "(", $args, "), Some(", $ans, "));
+assert_eq!(", $over_lhs, ".", stringify!($checked), "(", $over_args, "), None);
+```
+";
+            #[inline]
+            #[must_use]
+            pub fn $checked(self $(, $arg: $Arg)?) -> Option<$Ret> {
+                match self.$overflowing($($arg)?) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        comment! {
+            "Wrapping ", $op, ". Returns the ", $result, ", wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Complex};
+type C = Complex<I16F16>;
+let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+", $lets, $over_lets, "assert_eq!(", $lhs, ".", stringify!($wrapping), "(", $args, "), ", $ans, ");
+assert_eq!(", $over_lhs, ".", stringify!($wrapping), "(", $over_args, "), ", $wrap, ");
+```
+";
+            #[inline]
+            #[must_use]
+            pub fn $wrapping(self $(, $arg: $Arg)?) -> $Ret {
+                self.$overflowing($($arg)?).0
+            }
+        }
+
+        comment! {
+            "Unwrapped ", $op, ". Returns the ", $result, ", panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Complex};
+type C = Complex<I16F16>;
+let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+", $lets, "assert_eq!(", $lhs, ".", stringify!($unwrapped), "(", $args, "), ", $ans, ");
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Complex};
+type C = Complex<I16F16>;
+", $over_lets, "let _overflow = ", $over_lhs, ".", stringify!($unwrapped), "(", $over_args, ");
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn $unwrapped(self $(, $arg: $Arg)?) -> $Ret {
+                match self.$overflowing($($arg)?) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+    };
+}
+
+/**
+A complex number with fixed-point real and imaginary parts.
+
+Addition and subtraction are performed on each component. For multiplication,
+the products of the components are computed exactly and summed in double width,
+so that each component of the result is rounded only once. Like for the
+multiplication of fixed-point numbers, the result is rounded down.
+
+The operators panic on overflow when debug assertions are enabled and wrap
+otherwise, like the operators of the fixed-point numbers. The methods have
+checked, saturating, wrapping, unwrapped and overflowing variants. The
+saturating variants saturate each component of the result separately.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Complex};
+type C = Complex<I16F16>;
+let a = C::new(I16F16::from_num(3), I16F16::from_num(4));
+let b = C::new(I16F16::from_num(0.5), I16F16::from_num(-1));
+assert_eq!(a + b, C::new(I16F16::from_num(3.5), I16F16::from_num(3)));
+// (3 + 4i)(0.5 - i) = 5.5 - i
+assert_eq!(a * b, C::new(I16F16::from_num(5.5), I16F16::from_num(-1)));
+assert_eq!(a.norm_sqr(), 25);
+assert_eq!(a.abs(), 5);
+```
+*/
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Complex<F> {
+    /// The real part.
+    pub re: F,
+    /// The imaginary part.
+    pub im: F,
+}
+
+impl<F: Fixed> Complex<F> {
+    /// Zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// assert_eq!(Complex::<I16F16>::ZERO, Complex::new(I16F16::ZERO, I16F16::ZERO));
+    /// ```
+    pub const ZERO: Complex<F> = Complex {
+        re: F::ZERO,
+        im: F::ZERO,
+    };
+
+    /// Creates a complex number from its real and imaginary parts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// let c = Complex::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// assert_eq!(c.re, 1.5);
+    /// assert_eq!(c.im, -2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new(re: F, im: F) -> Complex<F> {
+        Complex { re, im }
+    }
+
+    /// Returns the complex conjugate, that is the number with the imaginary
+    /// part negated.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned. For unsigned numbers, the result overflows unless the
+    /// imaginary part is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// let c = Complex::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// assert_eq!(c.conj(), Complex::new(I16F16::from_num(1.5), I16F16::from_num(-2)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn conj(self) -> Complex<F> {
+        let (ans, overflow) = self.overflowing_conj();
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Returns the square of the absolute value, that is
+    /// <i>re</i><sup>2</sup>&nbsp;+&nbsp;<i>im</i><sup>2</sup>.
+    ///
+    /// The squares are computed exactly and summed in double width, so the
+    /// result is rounded down only once.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// let c = Complex::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// assert_eq!(c.norm_sqr(), 6.25);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn norm_sqr(self) -> F {
+        let (ans, overflow) = self.overflowing_norm_sqr();
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Returns the absolute value, also known as the modulus.
+    ///
+    /// The absolute value is computed using [`hypot`], so it is rounded down
+    /// and only overflows if the result does not fit.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// let c = Complex::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// assert_eq!(c.abs(), 2.5);
+    /// ```
+    ///
+    /// [`hypot`]: crate::FixedI32::hypot
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn abs(self) -> F {
+        self.re.hypot(self.im)
+    }
+
+    /// Multiplies both components by a real number.
+    ///
+    /// This is equivalent to the `*` operator with a right-hand side of type
+    /// `F`.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// let c = Complex::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// let scaled = Complex::new(I16F16::from_num(0.75), I16F16::from_num(-1));
+    /// assert_eq!(c.scale(I16F16::from_num(0.5)), scaled);
+    /// assert_eq!(c * I16F16::from_num(0.5), scaled);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn scale(self, factor: F) -> Complex<F> {
+        let (ans, overflow) = self.overflowing_scale(factor);
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    complex_op! {
+        {op, result} = {"addition", "sum"},
+        fn(rhs: Complex<F>) -> Complex<F>,
+        {checked, wrapping, unwrapped, overflowing} =
+            {checked_add, wrapping_add, unwrapped_add, overflowing_add},
+        {lets, lhs, args, ans} = {
+            "let b = C::new(I16F16::from_num(-3), I16F16::from_num(0.25));\n",
+            "a",
+            "b",
+            "C::new(I16F16::from_num(-1.5), I16F16::from_num(2.25))"
+        },
+        {over_lets, over_lhs, over_args, wrap} = {
+            "let max = C::new(I16F16::MAX, I16F16::MAX);\n",
+            "max",
+            "max",
+            "C::new(I16F16::from_bits(-2), I16F16::from_bits(-2))"
+        },
+    }
+
+    /// Saturating addition. Returns the sum, saturating each component on
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let b = C::new(I16F16::from_num(-3), I16F16::from_num(0.25));
+    /// let sum = C::new(I16F16::from_num(-1.5), I16F16::from_num(2.25));
+    /// assert_eq!(a.saturating_add(b), sum);
+    /// let c = C::new(I16F16::MAX, I16F16::MIN);
+    /// assert_eq!(c.saturating_add(c), c);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_add(self, rhs: Complex<F>) -> Complex<F> {
+        Complex {
+            re: self.re.saturating_add(rhs.re),
+            im: self.im.saturating_add(rhs.im),
+        }
+    }
+
+    /// Overflowing addition.
+    ///
+    /// Returns a [tuple] of the sum and a [`bool`] indicating whether an
+    /// overflow has occurred in either component. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let b = C::new(I16F16::from_num(-3), I16F16::from_num(0.25));
+    /// let sum = C::new(I16F16::from_num(-1.5), I16F16::from_num(2.25));
+    /// assert_eq!(a.overflowing_add(b), (sum, false));
+    /// let c = C::new(I16F16::ZERO, I16F16::MAX);
+    /// let wrapped = C::new(I16F16::ZERO, I16F16::from_bits(-2));
+    /// assert_eq!(c.overflowing_add(c), (wrapped, true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_add(self, rhs: Complex<F>) -> (Complex<F>, bool) {
+        let (re, overflow_re) = self.re.overflowing_add(rhs.re);
+        let (im, overflow_im) = self.im.overflowing_add(rhs.im);
+        (Complex { re, im }, overflow_re | overflow_im)
+    }
+
+    complex_op! {
+        {op, result} = {"subtraction", "difference"},
+        fn(rhs: Complex<F>) -> Complex<F>,
+        {checked, wrapping, unwrapped, overflowing} =
+            {checked_sub, wrapping_sub, unwrapped_sub, overflowing_sub},
+        {lets, lhs, args, ans} = {
+            "let b = C::new(I16F16::from_num(-3), I16F16::from_num(0.25));\n",
+            "a",
+            "b",
+            "C::new(I16F16::from_num(4.5), I16F16::from_num(1.75))"
+        },
+        {over_lets, over_lhs, over_args, wrap} = {
+            "let max = C::new(I16F16::MAX, I16F16::MAX);\n\
+             let min = C::new(I16F16::MIN, I16F16::MIN);\n",
+            "min",
+            "max",
+            "C::new(I16F16::DELTA, I16F16::DELTA)"
+        },
+    }
+
+    /// Saturating subtraction. Returns the difference, saturating each
+    /// component on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let b = C::new(I16F16::from_num(-3), I16F16::from_num(0.25));
+    /// let diff = C::new(I16F16::from_num(4.5), I16F16::from_num(1.75));
+    /// assert_eq!(a.saturating_sub(b), diff);
+    /// let c = C::new(I16F16::MAX, I16F16::MIN);
+    /// let neg_c = C::new(I16F16::MIN, I16F16::MAX);
+    /// assert_eq!(c.saturating_sub(neg_c), c);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Complex<F>) -> Complex<F> {
+        Complex {
+            re: self.re.saturating_sub(rhs.re),
+            im: self.im.saturating_sub(rhs.im),
+        }
+    }
+
+    /// Overflowing subtraction.
+    ///
+    /// Returns a [tuple] of the difference and a [`bool`] indicating whether an
+    /// overflow has occurred in either component. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let b = C::new(I16F16::from_num(-3), I16F16::from_num(0.25));
+    /// let diff = C::new(I16F16::from_num(4.5), I16F16::from_num(1.75));
+    /// assert_eq!(a.overflowing_sub(b), (diff, false));
+    /// let c = C::new(I16F16::MIN, I16F16::ZERO);
+    /// let wrapped = C::new(I16F16::MAX, I16F16::ZERO);
+    /// assert_eq!(c.overflowing_sub(C::new(I16F16::DELTA, I16F16::ZERO)), (wrapped, true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_sub(self, rhs: Complex<F>) -> (Complex<F>, bool) {
+        let (re, overflow_re) = self.re.overflowing_sub(rhs.re);
+        let (im, overflow_im) = self.im.overflowing_sub(rhs.im);
+        (Complex { re, im }, overflow_re | overflow_im)
+    }
+
+    complex_op! {
+        {op, result} = {"multiplication", "product"},
+        fn(rhs: Complex<F>) -> Complex<F>,
+        {checked, wrapping, unwrapped, overflowing} =
+            {checked_mul, wrapping_mul, unwrapped_mul, overflowing_mul},
+        {lets, lhs, args, ans} = {
+            "let b = C::new(I16F16::from_num(-3), I16F16::from_num(0.25));\n",
+            "a",
+            "b",
+            "C::new(I16F16::from_num(-5), I16F16::from_num(-5.625))"
+        },
+        {over_lets, over_lhs, over_args, wrap} = {
+            "let max = C::new(I16F16::MAX, I16F16::MAX);\n",
+            "max",
+            "max",
+            "C::new(I16F16::ZERO, I16F16::from_num(-2))"
+        },
+    }
+
+    /// Saturating multiplication. Returns the product, saturating each
+    /// component on overflow.
+    ///
+    /// Each component of the product is computed exactly before it is
+    /// saturated, so a component can saturate even if one of the products of
+    /// which it is composed would not fit on its own, and a component does not
+    /// saturate if only the intermediate products do not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let b = C::new(I16F16::from_num(-3), I16F16::from_num(0.25));
+    /// let prod = C::new(I16F16::from_num(-5), I16F16::from_num(-5.625));
+    /// assert_eq!(a.saturating_mul(b), prod);
+    /// // MAX² - MAX² = 0 does not overflow, but 2 MAX² does
+    /// let max = C::new(I16F16::MAX, I16F16::MAX);
+    /// assert_eq!(max.saturating_mul(max), C::new(I16F16::ZERO, I16F16::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_mul(self, rhs: Complex<F>) -> Complex<F> {
+        let (re, re_overflow, re_neg) = F::sum_prods(&[(self.re, rhs.re)], &[(self.im, rhs.im)]);
+        let (im, im_overflow, im_neg) = F::sum_prods(&[(self.re, rhs.im), (self.im, rhs.re)], &[]);
+        Complex {
+            re: saturate(re, re_overflow, re_neg),
+            im: saturate(im, im_overflow, im_neg),
+        }
+    }
+
+    /// Overflowing multiplication.
+    ///
+    /// Returns a [tuple] of the product and a [`bool`] indicating whether an
+    /// overflow has occurred in either component. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let b = C::new(I16F16::from_num(-3), I16F16::from_num(0.25));
+    /// let prod = C::new(I16F16::from_num(-5), I16F16::from_num(-5.625));
+    /// assert_eq!(a.overflowing_mul(b), (prod, false));
+    /// let max = C::new(I16F16::MAX, I16F16::MAX);
+    /// let wrapped = C::new(I16F16::ZERO, I16F16::from_num(-2));
+    /// assert_eq!(max.overflowing_mul(max), (wrapped, true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_mul(self, rhs: Complex<F>) -> (Complex<F>, bool) {
+        let (re, re_overflow, _) = F::sum_prods(&[(self.re, rhs.re)], &[(self.im, rhs.im)]);
+        let (im, im_overflow, _) = F::sum_prods(&[(self.re, rhs.im), (self.im, rhs.re)], &[]);
+        (Complex { re, im }, re_overflow | im_overflow)
+    }
+
+    complex_op! {
+        {op, result} = {"multiplication by a real number", "product"},
+        fn(factor: F) -> Complex<F>,
+        {checked, wrapping, unwrapped, overflowing} =
+            {checked_scale, wrapping_scale, unwrapped_scale, overflowing_scale},
+        {lets, lhs, args, ans} = {
+            "",
+            "a",
+            "I16F16::from_num(2)",
+            "C::new(I16F16::from_num(3), I16F16::from_num(4))"
+        },
+        {over_lets, over_lhs, over_args, wrap} = {
+            "let max = C::new(I16F16::MAX, I16F16::MAX);\n",
+            "max",
+            "I16F16::from_num(2)",
+            "C::new(I16F16::from_bits(-2), I16F16::from_bits(-2))"
+        },
+    }
+
+    /// Saturating multiplication by a real number. Returns the product,
+    /// saturating each component on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let prod = C::new(I16F16::from_num(-3), I16F16::from_num(-4));
+    /// assert_eq!(a.saturating_scale(I16F16::from_num(-2)), prod);
+    /// let c = C::new(I16F16::MAX, I16F16::from_num(-1));
+    /// let sat = C::new(I16F16::MIN, I16F16::from_num(2));
+    /// assert_eq!(c.saturating_scale(I16F16::from_num(-2)), sat);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_scale(self, factor: F) -> Complex<F> {
+        Complex {
+            re: self.re.saturating_mul(factor),
+            im: self.im.saturating_mul(factor),
+        }
+    }
+
+    /// Overflowing multiplication by a real number.
+    ///
+    /// Returns a [tuple] of the product and a [`bool`] indicating whether an
+    /// overflow has occurred in either component. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let prod = C::new(I16F16::from_num(3), I16F16::from_num(4));
+    /// assert_eq!(a.overflowing_scale(I16F16::from_num(2)), (prod, false));
+    /// let c = C::new(I16F16::MAX, I16F16::from_num(-1));
+    /// let wrapped = C::new(I16F16::from_bits(-2), I16F16::from_num(-2));
+    /// assert_eq!(c.overflowing_scale(I16F16::from_num(2)), (wrapped, true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_scale(self, factor: F) -> (Complex<F>, bool) {
+        let (re, overflow_re) = self.re.overflowing_mul(factor);
+        let (im, overflow_im) = self.im.overflowing_mul(factor);
+        (Complex { re, im }, overflow_re | overflow_im)
+    }
+
+    complex_op! {
+        {op, result} = {"complex conjugate", "conjugate"},
+        fn() -> Complex<F>,
+        {checked, wrapping, unwrapped, overflowing} =
+            {checked_conj, wrapping_conj, unwrapped_conj, overflowing_conj},
+        {lets, lhs, args, ans} = {
+            "",
+            "a",
+            "",
+            "C::new(I16F16::from_num(1.5), I16F16::from_num(-2))"
+        },
+        {over_lets, over_lhs, over_args, wrap} = {
+            "let min = C::new(I16F16::MIN, I16F16::MIN);\n",
+            "min",
+            "",
+            "min"
+        },
+    }
+
+    /// Saturating complex conjugate. Returns the conjugate, saturating on
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let conj = C::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// assert_eq!(a.saturating_conj(), conj);
+    /// let min = C::new(I16F16::MIN, I16F16::MIN);
+    /// assert_eq!(min.saturating_conj(), C::new(I16F16::MIN, I16F16::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_conj(self) -> Complex<F> {
+        Complex {
+            re: self.re,
+            im: self.im.saturating_neg(),
+        }
+    }
+
+    /// Overflowing complex conjugate.
+    ///
+    /// Returns a [tuple] of the conjugate and a [`bool`] indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// let conj = C::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// assert_eq!(a.overflowing_conj(), (conj, false));
+    /// let min = C::new(I16F16::MIN, I16F16::MIN);
+    /// assert_eq!(min.overflowing_conj(), (min, true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_conj(self) -> (Complex<F>, bool) {
+        let (im, overflow) = self.im.overflowing_neg();
+        (Complex { re: self.re, im }, overflow)
+    }
+
+    complex_op! {
+        {op, result} = {"square of the absolute value", "square of the absolute value"},
+        fn() -> F,
+        {checked, wrapping, unwrapped, overflowing} =
+            {checked_norm_sqr, wrapping_norm_sqr, unwrapped_norm_sqr, overflowing_norm_sqr},
+        {lets, lhs, args, ans} = {
+            "",
+            "a",
+            "",
+            "I16F16::from_num(6.25)"
+        },
+        {over_lets, over_lhs, over_args, wrap} = {
+            "let max = C::new(I16F16::MAX, I16F16::MAX);\n",
+            "max",
+            "",
+            "I16F16::from_num(-2)"
+        },
+    }
+
+    /// Saturating square of the absolute value. Returns the square of the
+    /// absolute value, saturating on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// assert_eq!(a.saturating_norm_sqr(), 6.25);
+    /// let max = C::new(I16F16::MAX, I16F16::MAX);
+    /// assert_eq!(max.saturating_norm_sqr(), I16F16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_norm_sqr(self) -> F {
+        let (ans, overflow, neg) = F::sum_prods(&[(self.re, self.re), (self.im, self.im)], &[]);
+        saturate(ans, overflow, neg)
+    }
+
+    /// Overflowing square of the absolute value.
+    ///
+    /// Returns a [tuple] of the square of the absolute value and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// assert_eq!(a.overflowing_norm_sqr(), (I16F16::from_num(6.25), false));
+    /// let max = C::new(I16F16::MAX, I16F16::MAX);
+    /// assert_eq!(max.overflowing_norm_sqr(), (I16F16::from_num(-2), true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_norm_sqr(self) -> (F, bool) {
+        let (ans, overflow, _) = F::sum_prods(&[(self.re, self.re), (self.im, self.im)], &[]);
+        (ans, overflow)
+    }
+
+    complex_op! {
+        {op, result} = {"absolute value", "absolute value"},
+        fn() -> F,
+        {checked, wrapping, unwrapped, overflowing} =
+            {checked_abs, wrapping_abs, unwrapped_abs, overflowing_abs},
+        {lets, lhs, args, ans} = {
+            "",
+            "a",
+            "",
+            "I16F16::from_num(2.5)"
+        },
+        {over_lets, over_lhs, over_args, wrap} = {
+            "let max = C::new(I16F16::MAX, I16F16::MAX);\n",
+            "max",
+            "",
+            "I16F16::MAX.wrapping_hypot(I16F16::MAX)"
+        },
+    }
+
+    /// Saturating absolute value. Returns the absolute value, saturating on
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// assert_eq!(a.saturating_abs(), 2.5);
+    /// let max = C::new(I16F16::MAX, I16F16::MAX);
+    /// assert_eq!(max.saturating_abs(), I16F16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_abs(self) -> F {
+        self.re.saturating_hypot(self.im)
+    }
+
+    /// Overflowing absolute value.
+    ///
+    /// Returns a [tuple] of the absolute value and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Complex};
+    /// type C = Complex<I16F16>;
+    /// let a = C::new(I16F16::from_num(1.5), I16F16::from_num(2));
+    /// assert_eq!(a.overflowing_abs(), (I16F16::from_num(2.5), false));
+    /// let max = C::new(I16F16::MAX, I16F16::MAX);
+    /// assert!(max.overflowing_abs().1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_abs(self) -> (F, bool) {
+        self.re.overflowing_hypot(self.im)
+    }
+}
+
+macro_rules! op {
+    ($Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $Rhs:ty, $overflowing:ident) => {
+        impl<F: Fixed> $Op<$Rhs> for Complex<F> {
+            type Output = Complex<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, rhs: $Rhs) -> Complex<F> {
+                let (ans, overflow) = self.$overflowing(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<F: Fixed> $OpAssign<$Rhs> for Complex<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, rhs: $Rhs) {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+    };
+}
+
+op! { Add add, AddAssign add_assign, Complex<F>, overflowing_add }
+op! { Sub sub, SubAssign sub_assign, Complex<F>, overflowing_sub }
+op! { Mul mul, MulAssign mul_assign, Complex<F>, overflowing_mul }
+op! { Mul mul, MulAssign mul_assign, F, overflowing_scale }
+
+impl<F: Fixed> From<F> for Complex<F> {
+    /// Creates a complex number with the given real part and a zero imaginary
+    /// part.
+    #[inline]
+    fn from(re: F) -> Complex<F> {
+        Complex { re, im: F::ZERO }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dot::tests::{round_prods, PROD_BITS};
+    use crate::types::{I1F15, I8F8, U8F8};
+    use crate::{Complex, FixedI128, FixedI16, FixedI24, FixedU, FixedU16};
+
+    // Checks multiplication against the exact product computed using integer
+    // arithmetic, with the result rounded down and wrapped to 16 bits.
+    fn check_mul<const FRAC: i32>() {
+        type F<const FRAC: i32> = FixedI16<FRAC>;
+        let values = PROD_BITS;
+        for (&a, &b) in values.iter().zip(values.iter().rev()) {
+            for (&c, &d) in values.iter().zip(values.iter().skip(1)) {
+                let x = Complex::new(F::<FRAC>::from_bits(a), F::from_bits(b));
+                let y = Complex::new(F::<FRAC>::from_bits(c), F::from_bits(d));
+                let (a, b, c, d) = (i64::from(a), i64::from(b), i64::from(c), i64::from(d));
+                let (re, re_overflow, re_sat) = round_prods::<FRAC>(a * c - b * d);
+                let (im, im_overflow, im_sat) = round_prods::<FRAC>(a * d + b * c);
+                let wrapped = Complex::new(re, im);
+                let overflow = re_overflow | im_overflow;
+                assert_eq!(x.overflowing_mul(y), (wrapped, overflow), "{x:?} × {y:?}");
+                assert_eq!(x.checked_mul(y), (!overflow).then_some(wrapped));
+                let saturated = Complex::new(re_sat, im_sat);
+                assert_eq!(x.saturating_mul(y), saturated, "{x:?} × {y:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn mul_rounds_once() {
+        check_mul::<0>();
+        check_mul::<8>();
+        check_mul::<15>();
+        check_mul::<16>();
+        check_mul::<20>();
+        check_mul::<{ -3 }>();
+    }
+
+    #[test]
+    fn mul_single_rounding() {
+        // Δ × Δ - (-Δ) × Δ = 2Δ² is below Δ, so it rounds down to zero, while
+        // rounding each product separately would give 0 - (-Δ) = Δ.
+        let delta = I8F8::DELTA;
+        let x = Complex::new(delta, -delta);
+        let y = Complex::new(delta, delta);
+        assert_eq!(x * y, Complex::new(I8F8::ZERO, I8F8::ZERO));
+        assert_eq!(delta * delta - (-delta) * delta, delta);
+        // the components match real multiplication when the imaginary parts are zero
+        let a = I8F8::from_num(-3.3);
+        let b = I8F8::from_num(1.7);
+        assert_eq!(Complex::from(a) * Complex::from(b), Complex::from(a * b));
+    }
+
+    #[test]
+    fn unsigned() {
+        let a = Complex::new(U8F8::from_num(3), U8F8::from_num(1));
+        let b = Complex::new(U8F8::from_num(1), U8F8::from_num(2));
+        // (3 + i)(1 + 2i) = 1 + 7i, with 3 - 2 computed exactly
+        assert_eq!(a * b, Complex::new(U8F8::from_num(1), U8F8::from_num(7)));
+        // (1 + 2i)(1 + 2i) = -3 + 4i, which saturates to 0 + 4i
+        assert_eq!(b.checked_mul(b), None);
+        assert_eq!(
+            b.saturating_mul(b),
+            Complex::new(U8F8::ZERO, U8F8::from_num(4))
+        );
+        assert_eq!(b.checked_conj(), None);
+        assert_eq!(a.norm_sqr(), 10);
+        assert_eq!(
+            Complex::new(U8F8::MAX, U8F8::ZERO).saturating_norm_sqr(),
+            U8F8::MAX
+        );
+        let c = Complex::new(FixedU16::<0>::from_num(3), FixedU16::from_num(4));
+        assert_eq!(c.abs(), 5);
+    }
+
+    #[test]
+    fn components() {
+        let a = Complex::new(I8F8::from_num(1.5), I8F8::from_num(-2));
+        let b = Complex::new(I8F8::from_num(0.25), I8F8::MAX);
+        assert_eq!(a + a, Complex::new(I8F8::from_num(3), I8F8::from_num(-4)));
+        assert_eq!(
+            a.overflowing_add(b),
+            (
+                Complex::new(I8F8::from_num(1.75), I8F8::MAX - I8F8::from_num(2)),
+                false
+            )
+        );
+        assert_eq!(
+            b.overflowing_sub(a),
+            (
+                Complex::new(
+                    I8F8::from_num(-1.25),
+                    I8F8::MIN + I8F8::from_num(2) - I8F8::DELTA
+                ),
+                true
+            )
+        );
+        assert_eq!(
+            b.saturating_sub(a),
+            Complex::new(I8F8::from_num(-1.25), I8F8::MAX)
+        );
+        let mut c = a;
+        c += b;
+        c -= b;
+        c *= I8F8::from_num(2);
+        assert_eq!(c, Complex::new(I8F8::from_num(3), I8F8::from_num(-4)));
+        assert_eq!(
+            a.scale(I8F8::from_num(-0.5)),
+            Complex::new(I8F8::from_num(-0.75), I8F8::ONE)
+        );
+        assert_eq!(b.checked_scale(I8F8::from_num(2)), None);
+        assert_eq!(
+            b.saturating_scale(I8F8::from_num(-2)),
+            Complex::new(I8F8::from_num(-0.5), I8F8::MIN)
+        );
+        assert_eq!(
+            a.conj(),
+            Complex::new(I8F8::from_num(1.5), I8F8::from_num(2))
+        );
+        assert_eq!(a.norm_sqr(), 6.25);
+        assert_eq!(a.abs(), 2.5);
+        // 12² + 12² = 288 does not fit, but the absolute value does
+        let f = Complex::new(I8F8::from_num(12), I8F8::from_num(-12));
+        assert_eq!(f.checked_norm_sqr(), None);
+        assert_eq!(f.saturating_norm_sqr(), I8F8::MAX);
+        // √288 = 16.97…
+        assert_eq!(f.unwrapped_abs(), I8F8::from_bits(4344));
+        let g = Complex::new(I1F15::from_num(0.375), I1F15::from_num(-0.5));
+        assert_eq!(g.abs(), 0.625);
+        assert_eq!(g.norm_sqr(), 0.390625);
+    }
+
+    #[test]
+    fn conj_abs_overflow() {
+        // the conjugate only overflows when the imaginary part is MIN
+        let a = Complex::new(I8F8::from_num(1.5), I8F8::MIN);
+        assert_eq!(a.checked_conj(), None);
+        assert_eq!(a.overflowing_conj(), (a, true));
+        assert_eq!(a.wrapping_conj(), a);
+        assert_eq!(
+            a.saturating_conj(),
+            Complex::new(I8F8::from_num(1.5), I8F8::MAX)
+        );
+        let b = Complex::new(I8F8::MIN, I8F8::MAX);
+        assert_eq!(b.checked_conj(), Some(Complex::new(I8F8::MIN, -I8F8::MAX)));
+        assert_eq!(
+            Complex::new(U8F8::ONE, U8F8::DELTA).saturating_conj(),
+            Complex::from(U8F8::ONE)
+        );
+
+        // 128² + 128² = 2¹⁵ wraps to zero, and √(2¹⁵) = 181.02… does not fit
+        let c = Complex::new(I8F8::MIN, I8F8::MIN);
+        assert_eq!(c.overflowing_norm_sqr(), (I8F8::ZERO, true));
+        assert_eq!(c.saturating_norm_sqr(), I8F8::MAX);
+        assert_eq!(c.checked_abs(), None);
+        assert_eq!(c.saturating_abs(), I8F8::MAX);
+        // |MIN| = 128 does not fit either, but |-MAX| fits
+        assert_eq!(Complex::new(I8F8::ZERO, I8F8::MIN).checked_abs(), None);
+        assert_eq!(
+            Complex::new(-I8F8::MAX, I8F8::ZERO).checked_abs(),
+            Some(I8F8::MAX)
+        );
+        // the square of the absolute value overflows, but the absolute value
+        // is exact
+        let d = Complex::new(I8F8::from_num(96), I8F8::from_num(-72));
+        assert_eq!(d.checked_norm_sqr(), None);
+        assert_eq!(d.checked_abs(), Some(I8F8::from_num(120)));
+        let e = Complex::new(U8F8::MAX, U8F8::MAX);
+        assert_eq!(e.checked_abs(), None);
+        assert_eq!(e.saturating_abs(), U8F8::MAX);
+    }
+
+    #[test]
+    fn wide() {
+        type F = FixedI128<64>;
+        let big = F::from_num(1u64 << 40);
+        let x = Complex::new(big, big + F::DELTA);
+        let y = Complex::new(big, -big);
+        // re = big² + big² + Δbig, which does not fit, im = -big² + big² + Δbig = Δbig
+        let (prod, overflow) = x.overflowing_mul(y);
+        assert!(overflow);
+        assert_eq!(prod.im, F::from_num(1u64 << 40) * F::DELTA);
+        let small = Complex::new(F::DELTA, F::DELTA);
+        assert_eq!(small * small, Complex::new(F::ZERO, F::ZERO));
+        let neg = Complex::new(F::DELTA, -F::DELTA);
+        assert_eq!(neg * neg, Complex::new(F::ZERO, -F::DELTA));
+    }
+
+    #[test]
+    fn narrow() {
+        type F = FixedI24<8>;
+        let x = Complex::new(F::from_num(100), F::from_num(20));
+        let y = Complex::new(F::from_num(100), F::from_num(-20));
+        // 100² + 20² = 10400 fits in 16 integer bits
+        assert_eq!(x * y, Complex::new(F::from_num(10400), F::ZERO));
+        // 200² + 20² = 40400 does not fit in 24 bits, even though it fits in 32 bits
+        let z = Complex::new(F::from_num(200), F::from_num(20));
+        let w = Complex::new(F::from_num(200), F::from_num(-20));
+        assert_eq!(z.checked_mul(w), None);
+        assert_eq!(z.saturating_mul(w), Complex::new(F::MAX, F::ZERO));
+        type U = FixedU<4, 4>;
+        let u = Complex::new(U::from_num(1), U::from_num(2));
+        // (1 + 2i)(1 + 2i) = -3 + 4i, which saturates to 0 + 4i
+        assert_eq!(u.checked_mul(u), None);
+        assert_eq!(u.saturating_mul(u), Complex::new(U::ZERO, U::from_num(4)));
+    }
+}
@@ -1901,4 +1901,435 @@ mod tests {
         assert_eq!(U0F128::from_num(I128F0::ZERO), 0);
         assert_eq!(U0F128::from_num(U128F0::ZERO), 0);
     }
+
+    macro_rules! check_num_round {
+        ($Src:ident<$src_frac:tt>, $Dst:ident<$dst_frac:tt>, $DstInner:ident) => {{
+            use crate::rounding::tests::{round_ratio, ALL_MODES};
+            let shift = $src_frac - $dst_frac;
+            for bits in
+                <crate::$Src<$src_frac>>::MIN.to_bits()..=<crate::$Src<$src_frac>>::MAX.to_bits()
+            {
+                let src = crate::$Src::<$src_frac>::from_bits(bits);
+                let (num, den) = if shift >= 0 {
+                    (i128::from(bits), 1i128 << shift)
+                } else {
+                    (i128::from(bits) << -shift, 1)
+                };
+                for mode in ALL_MODES {
+                    let exact = round_ratio(num, den, mode);
+                    let wrapped = crate::$Dst::<$dst_frac>::from_bits(exact as $DstInner);
+                    let overflow = i128::try_from(wrapped.to_bits()) != Ok(exact);
+                    assert_eq!(
+                        src.overflowing_to_num_round::<crate::$Dst<$dst_frac>>(mode),
+                        (wrapped, overflow),
+                        "{src} {mode:?}"
+                    );
+                    assert_eq!(
+                        crate::$Dst::<$dst_frac>::overflowing_from_num_round(src, mode),
+                        (wrapped, overflow),
+                        "{src} {mode:?}"
+                    );
+                    if $dst_frac == 0 {
+                        assert_eq!(
+                            src.overflowing_to_num_round::<$DstInner>(mode),
+                            (wrapped.to_bits(), overflow),
+                            "{src} {mode:?}"
+                        );
+                    }
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn num_round_fixed() {
+        check_num_round!(FixedI8<6>, FixedI8<2>, i8);
+        check_num_round!(FixedI8<6>, FixedU8<0>, u8);
+        check_num_round!(FixedI8<5>, FixedI8<0>, i8);
+        check_num_round!(FixedU8<7>, FixedI8<0>, i8);
+        check_num_round!(FixedU8<8>, FixedU8<0>, u8);
+        check_num_round!(FixedI8<3>, FixedI8<{ -2 }>, i8);
+        check_num_round!(FixedI16<12>, FixedI8<2>, i8);
+        check_num_round!(FixedU16<16>, FixedU8<4>, u8);
+        check_num_round!(FixedI16<3>, FixedU32<6>, u32);
+        check_num_round!(FixedI16<15>, FixedI128<0>, i128);
+        check_num_round!(FixedU16<10>, FixedU128<0>, u128);
+        check_num_round!(FixedI8<7>, FixedI8<{ -10 }>, i8);
+    }
+
+    #[test]
+    fn num_round_wide() {
+        use crate::{rounding::RoundingMode, FixedI128, FixedU128};
+        // 2^-129 is less than half the smallest I0F128 step
+        let tiny = FixedI128::<128>::from_bits(1);
+        assert_eq!(tiny.to_num_round::<FixedI128<0>>(RoundingMode::Ceil), 1);
+        assert_eq!(
+            tiny.to_num_round::<FixedI128<0>>(RoundingMode::HalfAwayFromZero),
+            0
+        );
+        assert_eq!((-tiny).to_num_round::<i128>(RoundingMode::Floor), -1);
+        let half = FixedU128::<128>::from_bits(1 << 127);
+        assert_eq!(half.to_num_round::<u8>(RoundingMode::HalfEven), 0);
+        assert_eq!(half.to_num_round::<u8>(RoundingMode::HalfAwayFromZero), 1);
+        // 2^127 - 0.5 rounded up does not fit in i128
+        let max = FixedU128::<1>::MAX;
+        assert_eq!(
+            max.overflowing_to_num_round::<i128>(RoundingMode::Ceil),
+            (i128::MIN, true)
+        );
+        assert_eq!(
+            max.overflowing_to_num_round::<i128>(RoundingMode::Floor),
+            (i128::MAX, false)
+        );
+    }
+
+    #[test]
+    fn num_round_float() {
+        use crate::{
+            rounding::tests::ALL_MODES, rounding::RoundingMode, FixedI32, FixedI8, FixedU128,
+        };
+        for k in -600i32..=600 {
+            let f = k as f32 / 64.0;
+            for mode in ALL_MODES {
+                let exact = crate::rounding::tests::round_ratio(k.into(), 16, mode);
+                let wrapped = FixedI8::<2>::from_bits(exact as i8);
+                let overflow = i128::from(wrapped.to_bits()) != exact;
+                assert_eq!(
+                    FixedI8::<2>::overflowing_from_num_round(f, mode),
+                    (wrapped, overflow),
+                    "{f} {mode:?}"
+                );
+            }
+        }
+
+        // 2^24 + 1 lies halfway between two f32 values
+        let odd = FixedI32::<0>::from_bits((1 << 24) + 1);
+        let below = 16_777_216f32;
+        let above = 16_777_218f32;
+        assert_eq!(odd.to_num_round::<f32>(RoundingMode::Floor), below);
+        assert_eq!(odd.to_num_round::<f32>(RoundingMode::Ceil), above);
+        assert_eq!(odd.to_num_round::<f32>(RoundingMode::HalfEven), below);
+        assert_eq!(
+            odd.to_num_round::<f32>(RoundingMode::HalfAwayFromZero),
+            above
+        );
+        assert_eq!(odd.to_num_round::<f32>(RoundingMode::HalfToZero), below);
+        assert_eq!((-odd).to_num_round::<f32>(RoundingMode::Floor), -above);
+        assert_eq!(
+            (-odd).to_num_round::<f32>(RoundingMode::AwayFromZero),
+            -above
+        );
+        assert_eq!((-odd).to_num_round::<f32>(RoundingMode::ToZero), -below);
+
+        // u128::MAX is larger than f32::MAX
+        let max = FixedU128::<0>::MAX;
+        assert_eq!(max.to_num_round::<f32>(RoundingMode::ToZero), f32::MAX);
+        assert_eq!(
+            max.to_num_round::<f32>(RoundingMode::HalfEven),
+            f32::INFINITY
+        );
+
+        // subnormal results keep track of discarded bits
+        let tiny = FixedI32::<31>::from_bits(3);
+        let tiny_f16 = tiny.to_num_round::<half::f16>(RoundingMode::Ceil);
+        assert_eq!(tiny_f16, half::f16::from_bits(1));
+        assert_eq!(
+            tiny.to_num_round::<half::f16>(RoundingMode::Floor),
+            half::f16::ZERO
+        );
+
+        assert_eq!(
+            FixedI8::<2>::checked_from_num_round(f32::NAN, RoundingMode::Floor),
+            None
+        );
+        assert_eq!(
+            FixedI8::<2>::checked_from_num_round(f64::INFINITY, RoundingMode::Floor),
+            None
+        );
+    }
+
+    macro_rules! check_requantize {
+        ($Fixed:ident, $Inner:ident, $src:tt, $($dst:tt),*) => {{
+            use crate::rounding::tests::{round_ratio, ALL_MODES};
+            type Src = $Fixed<$src>;
+            for bits in $Inner::MIN..=$Inner::MAX {
+                let x = Src::from_bits(bits);
+                for mode in ALL_MODES {
+                    $(
+                        let shift = $src - $dst;
+                        let exact = if shift > 0 {
+                            round_ratio(bits.into(), 1 << shift, mode)
+                        } else {
+                            i128::from(bits) << -shift
+                        };
+                        let wrapped = exact as $Inner;
+                        let overflow = i128::from(wrapped) != exact;
+                        let ans = x.overflowing_requantize::<$dst>(mode);
+                        assert_eq!(
+                            (ans.0.to_bits(), ans.1),
+                            (wrapped, overflow),
+                            "{bits} {mode:?} {}",
+                            $dst
+                        );
+                        let saturated = if !overflow {
+                            wrapped
+                        } else if exact < 0 {
+                            $Inner::MIN
+                        } else {
+                            $Inner::MAX
+                        };
+                        let ans = x.saturating_requantize::<$dst>(mode);
+                        assert_eq!(ans.to_bits(), saturated, "{bits} {mode:?} {}", $dst);
+                    )*
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn requantize() {
+        use crate::{FixedI128, FixedI32, FixedI8, FixedU8};
+        check_requantize!(FixedI8, i8, 4, 0, 1, 3, 4, 6, 9);
+        check_requantize!(FixedI8, i8, 8, { -3 }, { -1 }, 0, 1, 7, 8);
+        check_requantize!(
+            FixedI8,
+            i8,
+            { -2 },
+            { -12 },
+            { -11 },
+            { -10 },
+            { -9 },
+            { -3 },
+            0
+        );
+        check_requantize!(FixedU8, u8, 4, 0, 1, 3, 4, 6, 9);
+        check_requantize!(FixedU8, u8, 8, { -3 }, { -1 }, 0, 1, 7, 8);
+        check_requantize!(
+            FixedU8,
+            u8,
+            { -2 },
+            { -12 },
+            { -11 },
+            { -10 },
+            { -9 },
+            { -3 },
+            0
+        );
+
+        // const evaluation
+        const X: FixedI32<31> = FixedI32::<31>::lit("-0.75");
+        const Y: FixedI32<15> = X.requantize(crate::RoundingMode::HalfEven);
+        assert_eq!(Y, -0.75);
+        const Z: FixedI32<20> =
+            FixedI32::<31>::MAX.saturating_requantize(crate::RoundingMode::Ceil);
+        assert_eq!(Z, 1);
+        let huge = FixedI128::<{ i32::MAX }>::from_bits(-1);
+        let floor = huge.requantize::<{ i32::MIN }>(crate::RoundingMode::Floor);
+        assert_eq!(floor.to_bits(), -1);
+        let ceil = huge.requantize::<{ i32::MIN }>(crate::RoundingMode::Ceil);
+        assert_eq!(ceil.to_bits(), 0);
+    }
+
+    // Requantizes from FixedI64<32> to FixedI32<16> and from FixedI32<31> to
+    // FixedI16<15>, comparing with exact rounding followed by range checks.
+    #[test]
+    fn requantize_width() {
+        use crate::rounding::tests::{round_ratio, ALL_MODES};
+        use crate::{FixedI16, FixedI32, FixedI64};
+        let samples: [i64; 12] = [
+            0,
+            1,
+            -1,
+            0x8000,
+            -0x8000,
+            0x1_8000,
+            -0x1_8000,
+            0x1234_5678,
+            -0x1234_5678,
+            0x7FFF_7FFF_8000,
+            0x7FFF_FFFF_8000,
+            -0x8000_0000_8000,
+        ];
+        for mode in ALL_MODES {
+            for sample in samples.iter().copied().chain([i64::MIN, i64::MAX]) {
+                let exact = round_ratio(sample.into(), 1 << 16, mode);
+                let x = FixedI64::<32>::from_bits(sample);
+                let (ans, overflow) = x.overflowing_requantize_i32::<16>(mode);
+                let fits = i32::try_from(exact).is_ok();
+                assert_eq!(ans.to_bits(), exact as i32, "{sample} {mode:?}");
+                assert_eq!(overflow, !fits, "{sample} {mode:?}");
+                let sat = exact.clamp(i32::MIN.into(), i32::MAX.into()) as i32;
+                let ans = x.saturating_requantize_i32::<16>(mode);
+                assert_eq!(ans.to_bits(), sat, "{sample} {mode:?}");
+                assert_eq!(x.checked_requantize_i32::<16>(mode).is_some(), fits);
+
+                let sample = sample as i32;
+                let exact = round_ratio(sample.into(), 1 << 16, mode);
+                let x = FixedI32::<31>::from_bits(sample);
+                let (ans, overflow) = x.overflowing_requantize_i16::<15>(mode);
+                let fits = i16::try_from(exact).is_ok();
+                assert_eq!(ans.to_bits(), exact as i16, "{sample} {mode:?}");
+                assert_eq!(overflow, !fits, "{sample} {mode:?}");
+                let sat = exact.clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+                let ans = x.saturating_requantize_i16::<15>(mode);
+                assert_eq!(ans.to_bits(), sat, "{sample} {mode:?}");
+            }
+        }
+
+        // Q31 to Q15 is rounded at the dropped bits, and clamped at 1
+        const Q31: FixedI32<31> = FixedI32::<31>::lit("0.499993");
+        const Q15: FixedI16<15> = Q31.requantize_i16(crate::RoundingMode::HalfEven);
+        assert_eq!(Q15, FixedI16::<15>::from_bits(0x4000));
+        let max = FixedI32::<31>::MAX.saturating_requantize_i16::<15>(crate::RoundingMode::Ceil);
+        assert_eq!(max, FixedI16::<15>::MAX);
+        let min = FixedI32::<31>::MIN.saturating_requantize_i16::<15>(crate::RoundingMode::Floor);
+        assert_eq!(min, FixedI16::<15>::MIN);
+        // widening is exact, and can also overflow
+        let x = FixedI16::<15>::from_bits(-0x1235);
+        assert_eq!(
+            x.requantize_i32::<31>(crate::RoundingMode::Floor).to_bits(),
+            -0x1235 << 16
+        );
+        let y = FixedI16::<0>::from_bits(-3);
+        assert!(
+            y.overflowing_requantize_i64::<62>(crate::RoundingMode::Floor)
+                .1
+        );
+        assert_eq!(
+            y.saturating_requantize_i64::<62>(crate::RoundingMode::Floor),
+            FixedI64::<62>::MIN
+        );
+    }
+
+    #[test]
+    fn to_num_round_saturating() {
+        use crate::RoundingMode;
+        // an accumulator with more integer and fractional bits
+        let acc = I32F32::from_num(40000.3);
+        assert_eq!(
+            acc.saturating_to_num_round::<I16F16>(RoundingMode::HalfEven),
+            I16F16::MAX
+        );
+        assert_eq!(
+            (-acc).saturating_to_num_round::<I16F16>(RoundingMode::HalfEven),
+            I16F16::MIN
+        );
+        // I16F16::MAX + DELTA / 2 only overflows when rounded up
+        let edge = I32F32::from_num(I16F16::MAX) + I32F32::from_bits(1 << 15);
+        assert_eq!(
+            edge.overflowing_to_num_round::<I16F16>(RoundingMode::Ceil),
+            (I16F16::MIN, true)
+        );
+        assert_eq!(
+            edge.saturating_to_num_round::<I16F16>(RoundingMode::Ceil),
+            I16F16::MAX
+        );
+        assert_eq!(
+            edge.wrapping_to_num_round::<I16F16>(RoundingMode::Ceil),
+            I16F16::MIN
+        );
+        assert_eq!(
+            edge.unwrapped_to_num_round::<I16F16>(RoundingMode::Floor),
+            I16F16::MAX
+        );
+        let below = I32F32::from_num(I16F16::MIN) - I32F32::DELTA;
+        assert_eq!(
+            below.saturating_to_num_round::<I16F16>(RoundingMode::Floor),
+            I16F16::MIN
+        );
+        assert_eq!(
+            below.saturating_to_num_round::<I16F16>(RoundingMode::Ceil),
+            I16F16::MIN
+        );
+        assert_eq!(
+            I8F8::from_num(-0.5).saturating_to_num_round::<U8F8>(RoundingMode::Floor),
+            U8F8::ZERO
+        );
+        assert_eq!(
+            I8F8::from_num(-0.5).saturating_to_num_round::<u8>(RoundingMode::Floor),
+            0
+        );
+    }
+
+    #[test]
+    fn num_stochastic() {
+        use crate::{
+            rounding::tests::{round_ratio, Sequence},
+            rounding::RoundingMode,
+            FixedI16, FixedI8, FixedU8,
+        };
+        // Sequence(&[0]) always rounds up and Sequence(&[!0]) always rounds
+        // down, and exact values must not request any random bits
+        for bits in i16::MIN..=i16::MAX {
+            let src = FixedI16::<8>::from_bits(bits);
+            let f = bits as f32 / 256.0;
+            for (mode, random) in [(RoundingMode::Ceil, 0), (RoundingMode::Floor, !0)] {
+                let rounded = round_ratio(bits.into(), 64, mode);
+                let wrapped = FixedI8::<2>::from_bits(rounded as i8);
+                let expected = (wrapped, i128::from(wrapped.to_bits()) != rounded);
+                let seq: &[u64] = if bits % 64 == 0 { &[] } else { &[random] };
+                assert_eq!(
+                    FixedI8::<2>::overflowing_from_num_stochastic(src, &mut Sequence(seq)),
+                    expected,
+                    "{src} {mode:?}"
+                );
+                assert_eq!(
+                    FixedI8::<2>::overflowing_from_num_stochastic(f, &mut Sequence(seq)),
+                    expected,
+                    "{f} {mode:?}"
+                );
+                let rounded = round_ratio(bits.into(), 4, mode);
+                let wrapped = FixedI16::<{ -2 }>::from_bits(rounded as i16);
+                let seq: &[u64] = if bits % 4 == 0 { &[] } else { &[random] };
+                assert_eq!(
+                    FixedI16::<{ -2 }>::overflowing_from_num_stochastic(bits, &mut Sequence(seq)),
+                    (wrapped, false),
+                    "{bits} {mode:?}"
+                );
+            }
+        }
+        assert_eq!(
+            FixedI8::<2>::checked_from_num_stochastic(f32::NAN, &mut Sequence(&[])),
+            None
+        );
+
+        // 0.3 lies between 0.25 and 0.5, so it is rounded up with a
+        // probability of 0.2 and the mean of the rounded values is 0.3
+        let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+        let mut sum = 0.0;
+        for _ in 0..10_000 {
+            sum += FixedU8::<2>::from_num_stochastic(0.3, &mut rng).to_num::<f64>();
+        }
+        assert!(
+            (sum / 10_000.0 - 0.3).abs() < 0.005,
+            "mean {}",
+            sum / 10_000.0
+        );
+
+        // 0.05 × 0.05 is less than the DELTA of I8F8, so plain multiplication
+        // always returns zero
+        let small = I8F8::from_num(0.05);
+        let exact = small.to_num::<f64>() * small.to_num::<f64>();
+        assert_eq!(small * small, 0);
+        let mut sum = 0.0;
+        for _ in 0..10_000 {
+            sum += small.mul_stochastic(small, &mut rng).to_num::<f64>();
+        }
+        assert!(
+            (sum / 10_000.0 - exact).abs() < 0.0002,
+            "mean {}",
+            sum / 10_000.0
+        );
+    }
+
+    struct XorShift(u64);
+
+    impl crate::traits::RandomBits for XorShift {
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+    }
 }
@@ -16,6 +16,7 @@
 use crate::{
     debug_hex::{self, IsDebugHex},
     int_helper,
+    rounding::{Discarded, RoundingMode},
     types::extra::{If, True},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
@@ -138,7 +139,12 @@ impl Buffer {
     ) -> FmtResult {
         let has_exp = matches!(format, Format::UpExp | Format::LowExp);
 
-        let added_ms_digit = self.round_and_trim(format.max_digit(), truncation);
+        let added_ms_digit = self.round_and_trim(
+            format.max_digit(),
+            truncation,
+            is_neg,
+            RoundingMode::HalfEven,
+        );
 
         // check to see if rounding increased a digit and we are printing with exponent
         if added_ms_digit.0 && has_exp {
@@ -152,14 +158,25 @@ impl Buffer {
         self.pad_and_print(is_neg, format.prefix(), fmt)
     }
 
-    // rounds, and then trims trailing zeros from frac.
-    fn round_and_trim(&mut self, max: u8, truncation: Truncation) -> RoundingAddedMSDigit {
+    // rounds the magnitude according to mode, and then trims trailing zeros
+    // from frac.
+    fn round_and_trim(
+        &mut self,
+        max: u8,
+        truncation: Truncation,
+        is_neg: bool,
+        mode: RoundingMode,
+    ) -> RoundingAddedMSDigit {
         let len = 1 + self.int_digits + self.frac_digits;
 
-        // round up if cropped is greater than tie, or if it is tie and current is odd
         let is_odd = self.digits[len - 1] & 1 != 0;
-        let round_up =
-            truncation == Truncation::GreaterTie || (truncation == Truncation::Tie && is_odd);
+        let discarded = match truncation {
+            Truncation::Zero => Discarded::Zero,
+            Truncation::LessTie => Discarded::LessThanHalf,
+            Truncation::Tie => Discarded::Half,
+            Truncation::GreaterTie => Discarded::GreaterThanHalf,
+        };
+        let round_up = mode.increment(is_neg, is_odd, discarded);
         if round_up {
             let mut incremented_zero_at = None;
             for (index, b) in self.digits[0..len].iter_mut().enumerate().rev() {
@@ -531,7 +548,8 @@ where
             *b = Mul10::mul10_assign(&mut frac);
 
             // Check if very close to zero, to avoid things like 0.19999999 and 0.20000001.
-            if auto_prec && frac < Self::from(10) || Self::wrapping_neg(frac) < Self::from(10) {
+            // With a specified precision, all digits are exact.
+            if auto_prec && (frac < Self::from(10) || Self::wrapping_neg(frac) < Self::from(10)) {
                 buf.frac_digits = i + 1;
                 break;
             }
@@ -674,6 +692,57 @@ fn fmt_dec<U: FmtHelper>(
     buf.finish(format, neg, truncation, fmt)
 }
 
+// Rounds the value to dp decimal places according to mode, and passes the
+// decimal string of the rounded value to f. The string has no exponent and can
+// have leading zeros.
+fn with_dp_str<U: FmtHelper, T>(
+    (neg, abs): (bool, U),
+    frac_nbits: u32,
+    dp: usize,
+    mode: RoundingMode,
+    f: impl FnOnce(&str) -> T,
+) -> T {
+    let (int, frac) = if frac_nbits == 0 {
+        (abs, U::ZERO)
+    } else if frac_nbits == U::BITS {
+        (U::ZERO, abs)
+    } else {
+        (abs >> frac_nbits, abs << (U::BITS - frac_nbits))
+    };
+    let int_used_nbits = FmtHelper::int_used_nbits(int);
+    let frac_used_nbits = FmtHelper::frac_used_nbits(frac);
+    let int_max_len = ceil_log10_2_times(int_used_nbits);
+    let mut buf = Buffer::new(int_max_len, frac_used_nbits);
+    let int_sig_digits = FmtHelper::write_int_dec(int, int_used_nbits, &mut buf);
+    let frac_format = DecFracFormat {
+        int_sig_digits,
+        has_exp: false,
+        precision: Some(dp),
+    };
+    let truncation = FmtHelper::write_frac_dec(frac, frac_nbits, frac_format, &mut buf);
+    buf.round_and_trim(Format::Dec.max_digit(), truncation, neg, mode);
+    buf.encode_digits(false);
+
+    // sign, 129 digits and point
+    let mut bytes = [0u8; 131];
+    let mut len = 0;
+    if neg {
+        bytes[0] = b'-';
+        len = 1;
+    }
+    let int_end = 1 + buf.int_digits;
+    bytes[len..len + int_end].copy_from_slice(&buf.digits[..int_end]);
+    len += int_end;
+    if buf.frac_digits > 0 {
+        bytes[len] = b'.';
+        len += 1;
+        let frac_bytes = &buf.digits[int_end..int_end + buf.frac_digits];
+        bytes[len..len + buf.frac_digits].copy_from_slice(frac_bytes);
+        len += buf.frac_digits;
+    }
+    f(str::from_utf8(&bytes[..len]).unwrap())
+}
+
 fn fmt_radix2<U: FmtHelper>(
     (neg, int, frac): (bool, U, U),
     format: Format,
@@ -698,6 +767,21 @@ fn fmt_radix2<U: FmtHelper>(
 
 macro_rules! impl_fmt {
     ($Fixed:ident($nbits:expr, $Inner:ident)) => {
+        impl<const FRAC: i32> $Fixed<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= $nbits) }>: True,
+        {
+            pub(crate) fn with_dp_str<T>(
+                self,
+                dp: u32,
+                mode: RoundingMode,
+                f: impl FnOnce(&str) -> T,
+            ) -> T {
+                let neg_abs = int_helper::$Inner::neg_abs(self.to_bits());
+                with_dp_str(neg_abs, Self::FRAC_BITS as u32, dp as usize, mode, f)
+            }
+        }
+
         impl<const FRAC: i32> Display for $Fixed<FRAC>
         where
             If<{ (0 <= FRAC) & (FRAC <= $nbits) }>: True,
@@ -854,7 +938,8 @@ impl Mul10 for u128 {
 
 #[cfg(test)]
 mod tests {
-    use crate::{display, types::*};
+    use crate::rounding::tests::{round_ratio, ALL_MODES};
+    use crate::{display, types::*, FixedI16, FixedI8, FixedU16, FixedU8, RoundingMode};
     use std::format;
     #[cfg(not(feature = "std"))]
     use std::string::{String, ToString};
@@ -1214,4 +1299,86 @@ mod tests {
         assert_eq!(format!("{:e}", I0F64::DELTA * 3), "1.6e-19");
         assert_eq!(format!("{:e}", I0F64::DELTA * 4), "2e-19");
     }
+
+    #[test]
+    fn precision_rounds_once() {
+        // 51/256 = 0.19921875 must not be rounded to 0.2 before padding
+        assert_eq!(format!("{:.5}", U0F8::from_bits(51)), "0.19922");
+        assert_eq!(format!("{:.5}", I0F8::from_bits(-51)), "-0.19922");
+        assert_eq!(format!("{:.1}", U0F8::from_bits(51)), "0.2");
+    }
+
+    // Checks round_dp against exact rational rounding: the decimal is rounded
+    // according to mode, then the nearest representable value is expected.
+    macro_rules! check_round_dp {
+        ($Fixed:ident, $Inner:ident, $frac:expr, $step:expr) => {{
+            type F = $Fixed<$frac>;
+            let one = 1i128 << $frac;
+            for bits in ($Inner::MIN..=$Inner::MAX).step_by($step) {
+                let x = F::from_bits(bits);
+                for dp in 0..$frac + 2 {
+                    let pow = 10i128.pow(dp);
+                    for mode in ALL_MODES {
+                        let dec = round_ratio(i128::from(bits) * pow, one, mode);
+                        let exact = round_ratio(dec * one, pow, RoundingMode::HalfEven);
+                        let overflow =
+                            exact < i128::from($Inner::MIN) || exact > i128::from($Inner::MAX);
+                        let (ans, o) = x.overflowing_round_dp(dp, mode);
+                        assert_eq!(
+                            (ans.to_bits(), o),
+                            (exact as $Inner, overflow),
+                            "{x} {dp} {mode:?}"
+                        );
+                        // the decimal is displayed exactly if it is unique
+                        if !overflow && pow < one {
+                            let s = ans.to_string();
+                            let (int, frac) = s.split_once('.').unwrap_or((&s, ""));
+                            let digits = format!("{int}{frac:0<width$}", width = dp as usize);
+                            assert_eq!(digits.parse::<i128>(), Ok(dec), "{s} {dp}");
+                        }
+                    }
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn round_dp() {
+        check_round_dp!(FixedI8, i8, 0, 1);
+        check_round_dp!(FixedI8, i8, 5, 1);
+        check_round_dp!(FixedU8, u8, 8, 1);
+        check_round_dp!(FixedI16, i16, 7, 37);
+        check_round_dp!(FixedU16, u16, 12, 41);
+
+        let money = I32F32::from_num(12.346);
+        assert_eq!(
+            money.round_dp(2, RoundingMode::HalfEven).to_string(),
+            "12.35"
+        );
+        assert_eq!(money.floor_dp(2).to_string(), "12.34");
+        assert_eq!(money.ceil_dp(2).to_string(), "12.35");
+        assert_eq!((-money).floor_dp(2).to_string(), "-12.35");
+        assert_eq!((-money).ceil_dp(2).to_string(), "-12.34");
+        assert_eq!(money.round_dp(9, RoundingMode::Floor), money.floor_dp(9));
+        assert_eq!(
+            I32F32::MAX.checked_round_dp(0, RoundingMode::HalfEven),
+            None
+        );
+        assert_eq!(
+            I32F32::MAX.saturating_round_dp(0, RoundingMode::HalfEven),
+            I32F32::MAX
+        );
+        assert_eq!(
+            I32F32::MIN.saturating_round_dp(0, RoundingMode::Floor),
+            I32F32::MIN
+        );
+        assert_eq!(
+            I32F32::MIN.checked_round_dp(0, RoundingMode::Floor),
+            Some(I32F32::MIN)
+        );
+        // 2^-128 ≈ 2.9e-39 is closer to 0 than to 1e-38 ≈ 3.4 × 2^-128
+        let tiny = I0F128::DELTA;
+        assert_eq!(tiny.round_dp(38, RoundingMode::HalfEven), I0F128::ZERO);
+        assert_eq!(tiny.round_dp(38, RoundingMode::Ceil).to_bits(), 3);
+    }
 }
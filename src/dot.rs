@@ -0,0 +1,560 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Exact sums and differences of products, which are rounded only once when the
+// sum is converted back to a fixed-point number.
+//
+// The accumulator stores the value ext × 2^256 + word, where word is an
+// unsigned 256-bit number and ext is a small signed number. The product of two
+// 128-bit numbers fits in 256 bits, so ext only counts the carries and borrows,
+// and the sum cannot overflow unless there are more than 2^31 terms.
+//
+// The accumulator is also used for polynomial evaluation using Horner's method.
+// There the accumulator has 128 more fractional bits than x, and its magnitude
+// must be less than 2^256. In each step the accumulator is multiplied by x and
+// the product is truncated back to that precision, then the next coefficient
+// is added. Only the final result is rounded to the precision of x.
+//
+// The product of the 256-bit magnitude a = hi × 2^128 + lo and x has 384 bits,
+// but since x has f ≤ 128 fractional bits,
+//
+//     floor(a × x / 2^f) = hi × x × 2^(128 - f) + floor(lo × x / 2^f),
+//
+// so both partial products fit in 256 bits.
+
+use crate::int256::{self, U256};
+use crate::traits::Fixed;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Acc {
+    ext: i32,
+    word: U256,
+}
+
+impl Acc {
+    pub const ZERO: Acc = Acc {
+        ext: 0,
+        word: U256 { lo: 0, hi: 0 },
+    };
+
+    // Adds or subtracts a term with the two's-complement representation word,
+    // where neg is the sign of the term, which has the value word - 2^256 if
+    // neg is true.
+    #[inline]
+    const fn add_word(self, word: U256, neg: bool, sub: bool) -> Acc {
+        if sub {
+            let (word, borrow) = int256::overflowing_sub_u256(self.word, word);
+            Acc {
+                ext: self.ext + neg as i32 - borrow as i32,
+                word,
+            }
+        } else {
+            let (word, carry) = int256::overflowing_add_u256(self.word, word);
+            Acc {
+                ext: self.ext + carry as i32 - neg as i32,
+                word,
+            }
+        }
+    }
+
+    // Adds a × b to the sum, or subtracts it if sub is true.
+    #[inline]
+    pub const fn add_prod_i64(self, a: i64, b: i64, sub: bool) -> Acc {
+        let prod = a as i128 * b as i128;
+        let word = U256 {
+            lo: prod as u128,
+            hi: (prod >> 127) as u128,
+        };
+        self.add_word(word, prod < 0, sub)
+    }
+
+    // Adds a × b to the sum, or subtracts it if sub is true.
+    #[inline]
+    pub const fn add_prod_u64(self, a: u64, b: u64, sub: bool) -> Acc {
+        let word = U256 {
+            lo: a as u128 * b as u128,
+            hi: 0,
+        };
+        self.add_word(word, false, sub)
+    }
+
+    // Adds a × b to the sum, or subtracts it if sub is true.
+    #[inline]
+    pub const fn add_prod_i128(self, a: i128, b: i128, sub: bool) -> Acc {
+        let prod = int256::wide_mul_i128(a, b);
+        self.add_word(int256::i256_wrapping_as_u256(prod), prod.hi < 0, sub)
+    }
+
+    // Adds a × b to the sum, or subtracts it if sub is true.
+    #[inline]
+    pub const fn add_prod_u128(self, a: u128, b: u128, sub: bool) -> Acc {
+        self.add_word(int256::wide_mul_u128(a, b), false, sub)
+    }
+
+    // Returns the sign and the magnitude of the sum, or None if the magnitude
+    // is 2^256 or more.
+    #[inline]
+    const fn sign_abs(self) -> Option<(bool, U256)> {
+        match self.ext {
+            0 => Some((false, self.word)),
+            -1 if self.word.lo != 0 || self.word.hi != 0 => {
+                Some((true, int256::wrapping_neg_u256(self.word)))
+            }
+            _ => None,
+        }
+    }
+
+    #[inline]
+    const fn from_sign_abs(neg: bool, abs: U256) -> Acc {
+        if neg && (abs.lo != 0 || abs.hi != 0) {
+            Acc {
+                ext: -1,
+                word: int256::wrapping_neg_u256(abs),
+            }
+        } else {
+            Acc { ext: 0, word: abs }
+        }
+    }
+
+    // Returns the sum multiplied by x and shifted right by frac_nbits ≤ 128,
+    // with the magnitude truncated, and whether the magnitude of the sum or of
+    // the result is 2^256 or more.
+    pub const fn mul_shr(self, x_neg: bool, x_abs: u128, frac_nbits: u32) -> (Acc, bool) {
+        let Some((neg, abs)) = self.sign_abs() else {
+            return (Acc::ZERO, true);
+        };
+        let hi = int256::wide_mul_u128(abs.hi, x_abs);
+        let lo = int256::wide_mul_u128(abs.lo, x_abs);
+        let sh = 128 - frac_nbits;
+        let (hi, overflow1) = if sh == 0 {
+            (hi, false)
+        } else if sh == 128 {
+            (U256 { lo: 0, hi: hi.lo }, hi.hi != 0)
+        } else {
+            let shifted = U256 {
+                lo: hi.lo << sh,
+                hi: (hi.hi << sh) | (hi.lo >> (128 - sh)),
+            };
+            (shifted, hi.hi >> (128 - sh) != 0)
+        };
+        let lo = int256::shr_u256(lo, frac_nbits, false);
+        let (abs, overflow2) = int256::overflowing_add_u256(hi, lo);
+        (Acc::from_sign_abs(neg != x_neg, abs), overflow1 | overflow2)
+    }
+
+    // Adds c × 2^shift to the sum, and returns whether c × 2^shift is 2^256 or
+    // more. Bits of c below 2^-shift are truncated.
+    pub const fn add_shl(self, c_neg: bool, c_abs: u128, shift: i32) -> (Acc, bool) {
+        let (c, overflow) = if shift >= 256 {
+            (U256 { lo: 0, hi: 0 }, c_abs != 0)
+        } else if shift >= 128 {
+            let sh = shift - 128;
+            let hi = c_abs << sh;
+            (U256 { lo: 0, hi }, hi >> sh != c_abs)
+        } else if shift > 0 {
+            let c = U256 {
+                lo: c_abs << shift,
+                hi: c_abs >> (128 - shift),
+            };
+            (c, false)
+        } else if shift > -128 {
+            let c = U256 {
+                lo: c_abs >> -shift,
+                hi: 0,
+            };
+            (c, false)
+        } else {
+            (U256 { lo: 0, hi: 0 }, false)
+        };
+        (self.add_word(c, false, c_neg), overflow)
+    }
+
+    // Returns true if the sum fits in a signed or unsigned integer with nbits
+    // bits, where 1 ≤ nbits ≤ 128.
+    #[inline]
+    const fn fits(self, nbits: u32, signed: bool) -> bool {
+        if signed {
+            let top = (self.word.lo as i128) >> (nbits - 1);
+            match self.ext {
+                0 => self.word.hi == 0 && top == 0,
+                -1 => self.word.hi == u128::MAX && top == -1,
+                _ => false,
+            }
+        } else {
+            self.ext == 0 && self.word.hi == 0 && (nbits == 128 || self.word.lo >> nbits == 0)
+        }
+    }
+
+    // Returns the sum shifted right by frac_nbits and rounded down.
+    //
+    // The returned values are the low 128 bits of the result, whether the
+    // result overflows a signed or unsigned integer with nbits bits, and
+    // whether the sum is negative, which gives the direction of overflow.
+    pub const fn narrow(self, frac_nbits: i32, nbits: u32, signed: bool) -> (u128, bool, bool) {
+        let neg = self.ext < 0;
+        if frac_nbits < 0 {
+            // the result is the sum shifted left, so it overflows exactly when
+            // the sum does not fit in nbits - shift bits
+            let shift = frac_nbits.unsigned_abs();
+            if shift >= nbits {
+                let zero = self.ext == 0 && self.word.lo == 0 && self.word.hi == 0;
+                return (0, !zero, neg);
+            }
+            return (
+                self.word.lo << shift,
+                !self.fits(nbits - shift, signed),
+                neg,
+            );
+        }
+        let shifted = if frac_nbits == 0 {
+            self
+        } else if frac_nbits < 256 {
+            let shift = frac_nbits as u32;
+            let ext_word = U256 {
+                lo: self.ext as u128,
+                hi: (self.ext >> 31) as u128,
+            };
+            let lo_part = int256::shr_u256(self.word, shift, false);
+            let hi_part = int256::shl_u256(ext_word, 256 - shift);
+            Acc {
+                ext: self.ext >> if shift < 32 { shift } else { 31 },
+                word: U256 {
+                    lo: lo_part.lo | hi_part.lo,
+                    hi: lo_part.hi | hi_part.hi,
+                },
+            }
+        } else {
+            // only ext remains, and it is a small number
+            let shift = frac_nbits - 256;
+            let val = self.ext >> if shift < 32 { shift } else { 31 };
+            Acc {
+                ext: val >> 31,
+                word: U256 {
+                    lo: val as u128,
+                    hi: (val >> 31) as u128,
+                },
+            }
+        };
+        (shifted.word.lo, !shifted.fits(nbits, signed), neg)
+    }
+
+    // Like narrow, but the result is rounded to the nearest, with ties rounded
+    // to even, and 1 ≤ frac_nbits ≤ 128.
+    pub const fn narrow_round(
+        self,
+        frac_nbits: u32,
+        nbits: u32,
+        signed: bool,
+    ) -> (u128, bool, bool) {
+        let (rest, odd, unit) = if frac_nbits == 128 {
+            (self.word.lo, self.word.hi & 1 != 0, U256 { lo: 0, hi: 1 })
+        } else {
+            let unit = 1 << frac_nbits;
+            let odd = self.word.lo & unit != 0;
+            (self.word.lo & (unit - 1), odd, U256 { lo: unit, hi: 0 })
+        };
+        let half = 1 << (frac_nbits - 1);
+        let round_up = rest > half || (rest == half && odd);
+        let acc = if round_up {
+            self.add_word(unit, false, false)
+        } else {
+            self
+        };
+        acc.narrow(frac_nbits as i32, nbits, signed)
+    }
+}
+
+// Saturates a wrapped result, where neg is the sign of the exact result.
+#[inline]
+pub(crate) fn saturate<F: Fixed>(wrapped: F, overflow: bool, neg: bool) -> F {
+    match (overflow, neg) {
+        (false, _) => wrapped,
+        (true, false) => F::MAX,
+        (true, true) => F::MIN,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::dot::Acc;
+    use crate::types::{I8F8, U8F8};
+    use crate::{FixedI128, FixedI16, FixedU128, FixedU64};
+    use std::format;
+
+    // Bits of the 16-bit operands used by the product tests of the complex,
+    // vector and matrix types.
+    pub(crate) const PROD_BITS: [i16; 13] = [
+        0, 1, -1, 2, 0x7F, -0x80, 0x1234, -0x4321, 0x7FFF, -0x8000, 0x55, -0x2AA, 0x100,
+    ];
+
+    // Rounds down a sum of products of FixedI16<FRAC> numbers, where num is
+    // the exact sum of the products of their bits, so that the sum is
+    // num × 2^-2FRAC. Returns the wrapped result, whether it overflows, and
+    // the saturated result.
+    pub(crate) fn round_prods<const FRAC: i32>(num: i64) -> (FixedI16<FRAC>, bool, FixedI16<FRAC>) {
+        let val = if FRAC >= 0 {
+            num >> FRAC.min(63)
+        } else {
+            num << -FRAC
+        };
+        let sat = val.clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+        (
+            FixedI16::from_bits(val as i16),
+            val != i64::from(val as i16),
+            FixedI16::from_bits(sat),
+        )
+    }
+
+    // Computes the reference result for narrow using i128 arithmetic, which
+    // is enough for sums of products of 32-bit numbers.
+    fn reference(sum: i128, frac_nbits: i32, nbits: u32, signed: bool) -> (u128, bool, bool) {
+        let val = if frac_nbits >= 127 {
+            sum >> 127
+        } else if frac_nbits >= 0 {
+            sum >> frac_nbits
+        } else {
+            let shift = frac_nbits.unsigned_abs();
+            let val = if shift >= 128 { 0 } else { sum << shift };
+            if sum != 0 && (shift >= 128 || val >> shift != sum) {
+                // overflows i128, so it only fits if it fits u128
+                let fits_u128 = sum > 0 && shift < 128 && (val as u128) >> shift == sum as u128;
+                let overflow = signed || nbits < 128 || !fits_u128;
+                return (val as u128, overflow, sum < 0);
+            }
+            val
+        };
+        let overflow = match (signed, nbits) {
+            (true, 128) => false,
+            (true, _) => val < -(1 << (nbits - 1)) || val >= 1 << (nbits - 1),
+            (false, 128) => val < 0,
+            (false, _) => val < 0 || val >= 1 << nbits,
+        };
+        (val as u128, overflow, sum < 0)
+    }
+
+    fn mask(bits: u128, nbits: u32) -> u128 {
+        if nbits == 128 {
+            bits
+        } else {
+            bits & ((1 << nbits) - 1)
+        }
+    }
+
+    #[test]
+    fn narrow() {
+        let terms: [(i64, i64, bool); 5] = [
+            (0x7FFF_FFFF, 0x7FFF_FFFF, false),
+            (-0x8000_0000, 0x7FFF_FFFF, true),
+            (-0x8000_0000, -0x8000_0000, false),
+            (12_345, -678, false),
+            (-3, 5, true),
+        ];
+        for len in 0..=terms.len() {
+            let mut acc = Acc::ZERO;
+            let mut sum = 0i128;
+            for &(a, b, sub) in &terms[..len] {
+                acc = acc.add_prod_i64(a, b, sub);
+                let prod = i128::from(a) * i128::from(b);
+                sum += if sub { -prod } else { prod };
+            }
+            for frac_nbits in [
+                -300, -129, -64, -63, -10, -1, 0, 1, 5, 31, 64, 127, 200, 256, 300,
+            ] {
+                for nbits in [8, 16, 32, 64, 128] {
+                    for signed in [false, true] {
+                        let (bits, overflow, neg) = acc.narrow(frac_nbits, nbits, signed);
+                        let expected = reference(sum, frac_nbits, nbits, signed);
+                        let msg = format!("{sum} >> {frac_nbits} in {nbits} bits, signed {signed}");
+                        assert_eq!(mask(bits, nbits), mask(expected.0, nbits), "{msg}");
+                        assert_eq!(overflow, expected.1, "{msg}");
+                        assert_eq!(neg, expected.2, "{msg}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wide_terms() {
+        // (2^127 - 1)^2 + 2^254 > 2^255, which needs the extension
+        let max = i128::MAX;
+        let acc =
+            Acc::ZERO
+                .add_prod_i128(max, max, false)
+                .add_prod_i128(i128::MIN, i128::MIN, false);
+        assert_eq!(acc.narrow(254, 128, true), (1, false, false));
+        assert_eq!(acc.narrow(253, 2, true), (3, true, false));
+        let acc = acc.add_prod_i128(i128::MIN, i128::MIN, true);
+        assert_eq!(acc.narrow(127, 128, true), (max as u128 - 1, false, false));
+        assert_eq!(acc.narrow(128, 128, true), (max as u128 >> 1, false, false));
+        // the difference of two large products is exact
+        let acc = Acc::ZERO
+            .add_prod_i128(max, max, false)
+            .add_prod_i128(max - 1, max, true)
+            .add_prod_i128(-1, -1, true);
+        assert_eq!(acc.narrow(0, 128, true), (max as u128 - 1, false, false));
+        let umax = u128::MAX;
+        let acc = Acc::ZERO
+            .add_prod_u128(umax, umax, false)
+            .add_prod_u128(umax, umax, false);
+        // 2 × (2^128 - 1)^2 = 2^257 - 2^130 + 2
+        assert_eq!(acc.narrow(256, 8, false), (1, false, false));
+        assert_eq!(acc.narrow(129, 128, false), (umax - 1, false, false));
+        assert_eq!(acc.narrow(128, 128, false), (umax - 3, true, false));
+        let acc = acc
+            .add_prod_u128(umax, umax, true)
+            .add_prod_u128(umax, umax, true)
+            .add_prod_u128(1, 1, true);
+        assert_eq!(acc.narrow(0, 128, false), (umax, true, true));
+        assert_eq!(acc.narrow(300, 128, true), (umax, false, true));
+        assert_eq!(acc.narrow(-5, 128, true), (umax << 5, false, true));
+    }
+
+    // Checks poly_eval against exact evaluation with integer arithmetic. With x having
+    // 8 fractional bits and coefficients having cf fractional bits, p(x) =
+    // num / 2^(cf + 8d), where d is the degree.
+    fn check_poly_i8f8<const CF: i32>(x: I8F8, coeffs: &[FixedI16<CF>]) {
+        let d = coeffs.len() as i32 - 1;
+        let xb = i128::from(x.to_bits());
+        let mut num = 0i128;
+        for (i, c) in coeffs.iter().enumerate().rev() {
+            num = num * xb + (i128::from(c.to_bits()) << (8 * (d - i as i32)));
+        }
+        let (val, overflow) = x.overflowing_poly_eval(coeffs);
+        let exact = num as f64 / f64::from(CF + 8 * d).exp2();
+        if exact.abs() >= 128.0 {
+            assert!(overflow, "x {x} coeffs {coeffs:?}");
+            return;
+        }
+        assert!(!overflow, "x {x} coeffs {coeffs:?}");
+        // compare val with num using the denominator 2^(cf + 8d)
+        let sh = CF + 8 * (d - 1);
+        let (val, num, sh) = if sh >= 0 {
+            (i128::from(val.to_bits()) << sh, num, sh)
+        } else {
+            (i128::from(val.to_bits()), num << -sh, 0)
+        };
+        // half an ulp, and the intermediate truncations magnified by |x|^d
+        let slack = i128::from((d + 1) * 64) << (sh - 16).max(0);
+        assert!(
+            (val - num).abs() <= (1 << sh) / 2 + slack,
+            "x {x} coeffs {coeffs:?}"
+        );
+    }
+
+    #[test]
+    fn poly_i8f8() {
+        let coeffs: [[i16; 4]; 4] = [
+            [0x0100, 0x0080, 0x0040, 0x0020],
+            [-0x0333, 0x1234, -0x0777, 0x0111],
+            [0x7fff, -0x8000, 0x7fff, -0x8000],
+            [0x0001, -0x0003, 0x0005, -0x0007],
+        ];
+        for bits in (-0x0400..0x0400).step_by(7) {
+            let x = I8F8::from_bits(bits);
+            for c in &coeffs {
+                let c8 = c.map(FixedI16::<8>::from_bits);
+                let c12 = c.map(FixedI16::<12>::from_bits);
+                let c0 = c.map(|b| FixedI16::<0>::from_bits(b >> 8));
+                for len in 1..=4 {
+                    check_poly_i8f8(x, &c8[..len]);
+                    check_poly_i8f8(x, &c12[..len]);
+                    check_poly_i8f8(x, &c0[..len]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn poly_rounding() {
+        let empty: [U8F8; 0] = [];
+        assert_eq!(U8F8::ONE.poly_eval(&empty), 0);
+
+        // 2^-9 is a tie which is rounded to even, 3 × 2^-9 is rounded up
+        let x = I8F8::from_bits(1);
+        let half = [FixedI16::<9>::ZERO, FixedI16::<9>::from_num(0.5)];
+        assert_eq!(x.poly_eval(&half), 0);
+        let three_halves = [FixedI16::<9>::ZERO, FixedI16::<9>::from_num(1.5)];
+        assert_eq!(x.poly_eval(&three_halves), I8F8::from_bits(2));
+        assert_eq!((-x).poly_eval(&three_halves), I8F8::from_bits(-2));
+
+        // (x - 1)²
+        let x = I8F8::from_num(-3.25);
+        let coeffs = [FixedI16::<8>::ONE, FixedI16::from_num(-2), FixedI16::ONE];
+        assert_eq!(x.poly_eval(&coeffs), I8F8::from_num(18.0625));
+
+        // unsigned intermediate values can exceed the signed range
+        let x = FixedU64::<0>::from_bits(1 << 40);
+        let coeffs = [
+            FixedU64::<0>::from_bits(5),
+            FixedU64::ZERO,
+            FixedU64::from_bits(1 << 20),
+        ];
+        assert_eq!(
+            x.overflowing_poly_eval(&coeffs),
+            (FixedU64::<0>::from_bits(5), true)
+        );
+        let coeffs = [FixedU64::<0>::from_bits(5), FixedU64::from_bits(1 << 20)];
+        assert_eq!(
+            x.poly_eval(&coeffs),
+            FixedU64::<0>::from_bits((1 << 60) + 5)
+        );
+    }
+
+    #[test]
+    fn poly_wide() {
+        // With few significant bits the result is exact.
+        let x = FixedI128::<100>::from_num(-1.375);
+        let coeffs = [
+            FixedI128::<40>::from_num(0.75),
+            FixedI128::from_num(-2.5),
+            FixedI128::from_num(0.125),
+            FixedI128::from_num(1),
+        ];
+        // 0.75 + 3.4375 + 0.236328125 - 2.599609375
+        assert_eq!(x.poly_eval(&coeffs), FixedI128::<100>::from_num(1.82421875));
+
+        let x = FixedU128::<128>::from_num(0.5);
+        let coeffs = [FixedU128::<128>::ZERO, FixedU128::from_num(0.5)];
+        assert_eq!(x.poly_eval(&coeffs), FixedU128::<128>::from_num(0.25));
+        let coeffs = [FixedU128::<0>::ONE];
+        assert_eq!(x.checked_poly_eval(&coeffs), None);
+
+        // x³ - 1 for x = 2^40 with no fractional bits
+        let x = FixedI128::<0>::from_bits(1 << 40);
+        let coeffs = [
+            FixedI128::<0>::from_bits(-1),
+            FixedI128::ZERO,
+            FixedI128::ZERO,
+            FixedI128::ONE,
+        ];
+        assert_eq!(
+            x.poly_eval(&coeffs),
+            FixedI128::<0>::from_bits((1 << 120) - 1)
+        );
+        let coeffs = [FixedI128::<0>::ZERO, FixedI128::ZERO, FixedI128::ONE];
+        assert_eq!(
+            x.checked_poly_eval(&coeffs),
+            Some(FixedI128::<0>::from_bits(1 << 80))
+        );
+        let coeffs = [
+            FixedI128::<0>::ZERO,
+            FixedI128::ZERO,
+            FixedI128::ZERO,
+            FixedI128::from_bits(1 << 8),
+        ];
+        assert_eq!(x.checked_poly_eval(&coeffs), None);
+    }
+}
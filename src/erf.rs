@@ -0,0 +1,152 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::consts;
+use crate::exp;
+use crate::real::Real;
+
+// For any argument larger than this in magnitude, erfc |x| < 2^-140, so that
+// erf x rounds to ±1 for any fixed-point number.
+const ERF_LIMIT: u32 = 10;
+
+pub const fn erf(x: Real) -> Real {
+    if x.mant == 0 {
+        return Real::ZERO;
+    }
+    let abs = if x.cmp_abs(Real::from_u32(ERF_LIMIT)).is_ge() {
+        Real::ONE
+    } else {
+        // erf x = 2/√π × x e^(-x²) × (1 + 2x²/3 + (2x²)²/(3·5) + …)
+        //
+        // All the terms are positive so there is no cancellation. Rounding
+        // errors in x² mostly cancel out, as the sum grows like e^(x²).
+        let a = x.abs();
+        let a2 = a.mul(a);
+        let two_a2 = a2.mul_pow2(1);
+        let mut term = a;
+        let mut sum = a;
+        let mut k = 1;
+        // the terms increase up to k ≈ x², so the loop can only stop once they
+        // are decreasing
+        loop {
+            term = term.mul(two_a2).div(Real::from_u32(2 * k + 1));
+            sum = sum.add(term);
+            if term.mul_pow2(130).cmp_abs(sum).is_lt() {
+                break;
+            }
+            k += 1;
+        }
+        let frac_2_sqrt_pi = Real::from_u128(false, consts::FRAC_2_SQRT_PI.to_bits(), 127);
+        frac_2_sqrt_pi.mul(exp::exp(a2.neg())).mul(sum)
+    };
+    if x.neg {
+        abs.neg()
+    } else {
+        abs
+    }
+}
+
+pub const fn erfc(x: Real) -> Real {
+    // erf x is computed with a small absolute error, which is enough as
+    // results are rounded to at most 128 fractional bits
+    Real::ONE.sub(erf(x))
+}
+
+pub const fn gelu(x: Real) -> Real {
+    // x Φ(x) = x/2 × (1 + erf(x/√2))
+    let frac_1_sqrt_2 = Real::from_u128(false, consts::FRAC_1_SQRT_2.to_bits(), 128);
+    x.mul_pow2(-1).mul(Real::ONE.add(erf(x.mul(frac_1_sqrt_2))))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::erf;
+    use crate::real;
+    use crate::types::I16F16;
+
+    // erf computed in f64 with the same positive series, which is accurate
+    // enough for checking results with 16 fractional bits
+    fn erf_f64(x: f64) -> f64 {
+        let a = x.abs();
+        let mut term = a;
+        let mut sum = a;
+        let mut k = 1.0;
+        while term > sum * 1e-18 || k < a * a {
+            term *= 2.0 * a * a / (2.0 * k + 1.0);
+            sum += term;
+            k += 1.0;
+        }
+        let abs = (sum * (-a * a).exp() * 2.0 / core::f64::consts::PI.sqrt()).min(1.0);
+        abs.copysign(x)
+    }
+
+    #[test]
+    fn erf_i16f16() {
+        let mut bits = -0x000C_0000;
+        while bits < 0x000C_0000 {
+            let val = I16F16::from_bits(bits);
+            let f = val.to_num::<f64>();
+            let x = real::i32::from_bits(bits, 16);
+            for (r, expected) in [(erf::erf(x), erf_f64(f)), (erf::erfc(x), 1.0 - erf_f64(f))] {
+                let (bits, overflow) = real::i32::to_bits(r, 16);
+                assert!(!overflow);
+                let diff = (bits as f64 / 65536.0 - expected).abs();
+                assert!(diff <= 0.5 / 65536.0 + 1e-12, "{val} {bits}");
+            }
+            bits += 0x0000_0357;
+        }
+    }
+
+    #[test]
+    fn gelu_i16f16() {
+        let mut bits = -0x0010_0000;
+        while bits < 0x0010_0000 {
+            let val = I16F16::from_bits(bits);
+            let f = val.to_num::<f64>();
+            let x = real::i32::from_bits(bits, 16);
+            let expected = f / 2.0 * (1.0 + erf_f64(f / 2f64.sqrt()));
+            let (gelu, overflow) = real::i32::to_bits(erf::gelu(x), 16);
+            assert!(!overflow);
+            let diff = (gelu as f64 / 65536.0 - expected).abs();
+            assert!(diff <= 0.5 / 65536.0 + 1e-12 * f.abs(), "{val} {gelu}");
+            bits += 0x0000_0753;
+        }
+    }
+
+    #[test]
+    fn erf_wide() {
+        // erf 1 = 0.8427007929497148693412206350826092592960...
+        let x = real::i8::from_bits(1, 0);
+        let (bits, _) = real::u128::to_bits(erf::erf(x), 128);
+        let expected = 0xD7BB_3D3A_0844_5636_8088_7EDD_8693_79D3u128;
+        assert!(bits.abs_diff(expected) <= 256);
+
+        // erf x = 2x/√π (1 - x²/3 + …) for small x
+        let x = real::u128::from_bits(1, 100);
+        let (bits, _) = real::u128::to_bits(erf::erf(x), 128);
+        let expected = crate::consts::FRAC_2_SQRT_PI.to_bits() >> 99;
+        assert!(bits.abs_diff(expected) <= 1);
+
+        // erfc 9 = 4.1370317465138102e-37, which is 140.78 × 2^-128; the
+        // absolute error is still much smaller than 2^-120
+        let x = real::i8::from_bits(9, 0);
+        let (bits, _) = real::u128::to_bits(erf::erfc(x), 128);
+        assert!(bits.abs_diff(141) <= 64);
+
+        let x = real::i8::from_bits(-10, 0);
+        assert_eq!(real::i8::to_bits(erf::erf(x), 0), (-1, false));
+        assert_eq!(real::u8::to_bits(erf::erfc(x), 6), (128, false));
+    }
+}
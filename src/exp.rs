@@ -0,0 +1,335 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::consts;
+use crate::ln;
+use crate::powi;
+use crate::real::Real;
+
+// ln 2 with 120 fractional bits, so that multiplying it by an integer with
+// less than 8 significant bits is exact
+const LN_2_HI: u128 = consts::LN_2.to_bits() & !0xFF;
+// ln 2 - LN_2_HI with 248 fractional bits
+const LN_2_LO: u128 = 0xAF40_F343_2672_98B6_2D8A_0D17_5B8B_AAFA;
+
+// Number of terms used in the Taylor series for exp(r) - 1 with |r| ≤ 0.5. The
+// first term that is not used is < 2^-130 relative to the result.
+const EXP_M1_TERMS: u32 = 30;
+
+// Any argument larger than this in magnitude gives a result that is too large
+// or too small for any fixed-point number.
+const EXP_LIMIT: u32 = 128;
+const EXP2_LIMIT: u32 = 256;
+
+// Returned for results that are too large for any fixed-point number.
+const HUGE: Real = Real {
+    neg: false,
+    mant: 1 << 127,
+    exp: 256,
+};
+// Returned for results that are too small for any fixed-point number, but
+// still positive.
+const TINY: Real = Real {
+    neg: false,
+    mant: 1 << 127,
+    exp: -512,
+};
+
+// |r| ≤ 0.5
+const fn exp_m1_small(r: Real) -> Real {
+    // exp(r) - 1 = r (1 + r/2 (1 + r/3 (1 + …)))
+    let mut acc = Real::ONE;
+    let mut k = EXP_M1_TERMS;
+    while k > 1 {
+        acc = Real::ONE.add(r.mul(acc).div(Real::from_u32(k)));
+        k -= 1;
+    }
+    r.mul(acc)
+}
+
+// |x| must be small enough for the result to fit in an i32.
+const fn round_to_i32(x: Real) -> i32 {
+    let (abs, _) = x.to_abs_u128(0);
+    if x.neg {
+        -(abs as i32)
+    } else {
+        abs as i32
+    }
+}
+
+pub const fn exp(x: Real) -> Real {
+    if x.cmp_abs(Real::from_u32(EXP_LIMIT)).is_gt() {
+        return if x.neg { TINY } else { HUGE };
+    }
+    // x = n ln 2 + r, where |r| ≤ ln 2 / 2 (approximately)
+    let n = round_to_i32(x.mul(Real::from_u128(false, consts::LOG2_E.to_bits(), 127)));
+    let abs_n = n.unsigned_abs() as u128;
+    // n × LN_2_HI is exact, and so is the subtraction as it cancels the
+    // most significant bits of x
+    let hi = Real::from_u128(n < 0, abs_n * (LN_2_HI >> 8), 120);
+    let lo = Real::from_u128(n < 0, abs_n, 0).mul(Real::from_u128(false, LN_2_LO, 248));
+    let r = x.sub(hi).sub(lo);
+    Real::ONE.add(exp_m1_small(r)).mul_pow2(n)
+}
+
+pub const fn exp2(x: Real) -> Real {
+    if x.cmp_abs(Real::from_u32(EXP2_LIMIT)).is_gt() {
+        return if x.neg { TINY } else { HUGE };
+    }
+    // x = n + f, where |f| ≤ 0.5; the subtraction is exact
+    let n = round_to_i32(x);
+    let f = x.sub(Real::from_u128(n < 0, n.unsigned_abs() as u128, 0));
+    let r = f.mul(Real::from_u128(false, consts::LN_2.to_bits(), 128));
+    Real::ONE.add(exp_m1_small(r)).mul_pow2(n)
+}
+
+pub const fn exp_m1(x: Real) -> Real {
+    // for small |x|, evaluate the series directly to avoid cancellation
+    if x.cmp_abs(Real::from_u128(false, 1, 1)).is_le() {
+        exp_m1_small(x)
+    } else {
+        exp(x).sub(Real::ONE)
+    }
+}
+
+pub const fn sinh(x: Real) -> Real {
+    // sinh |x| = (e^|x| - e^-|x|)/2 = (m + m/(m + 1))/2, where m = e^|x| - 1,
+    // which avoids cancellation for small |x|
+    let m = exp_m1(x.abs());
+    let abs = m.add(m.div(m.add(Real::ONE))).mul_pow2(-1);
+    if x.neg {
+        abs.neg()
+    } else {
+        abs
+    }
+}
+
+pub const fn cosh(x: Real) -> Real {
+    let e = exp(x.abs());
+    e.add(e.recip()).mul_pow2(-1)
+}
+
+pub const fn tanh(x: Real) -> Real {
+    // tanh |x| = m/(m + 2), where m = e^(2|x|) - 1
+    let m = exp_m1(x.abs().mul_pow2(1));
+    let abs = m.div(m.add(Real::from_u32(2)));
+    if x.neg {
+        abs.neg()
+    } else {
+        abs
+    }
+}
+
+pub const fn sigmoid(x: Real) -> Real {
+    // 1/(1 + e^-x), which keeps a small relative error for negative x
+    Real::ONE.add(exp(x.neg())).recip()
+}
+
+pub const fn softplus(x: Real) -> Real {
+    // ln(1 + e^x) = max(x, 0) + ln(1 + e^-|x|), which avoids e^x for large x
+    let t = ln::ln(Real::ONE.add(exp(x.abs().neg())));
+    if x.neg {
+        t
+    } else {
+        x.add(t)
+    }
+}
+
+// Returns x^y and whether x is negative while y is not an integer, in which
+// case zero is returned. x must not be zero if y < 0.
+pub const fn powf(x: Real, y: Real) -> (Real, bool) {
+    // use powi for small integer exponents, as it is exact when possible
+    if let Some(n) = y.to_i32_exact() {
+        return (powi::powi(x, n).to_real(), false);
+    }
+    if x.mant == 0 {
+        return (Real::ZERO, false);
+    }
+    if x.neg && !y.is_int() {
+        return (Real::ZERO, true);
+    }
+    let abs = exp2(y.mul(ln::log2(x.abs())));
+    if x.neg && y.is_odd_int() {
+        (abs.neg(), false)
+    } else {
+        (abs, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::exp;
+    use crate::real;
+    use crate::types::{I16F16, I8F24, U0F128};
+
+    #[test]
+    fn exp_i16f16() {
+        let mut bits = -0x000B_0000;
+        while bits < 0x000B_0000 {
+            let val = I16F16::from_bits(bits);
+            let f = val.to_num::<f64>();
+            let x = real::i32::from_bits(bits, 16);
+            for (r, expected) in [
+                (exp::exp(x), f.exp()),
+                (exp::exp2(x), f.exp2()),
+                (exp::exp_m1(x), f.exp_m1()),
+            ] {
+                let (bits, overflow) = real::i64::to_bits(r, 16);
+                assert!(!overflow);
+                let diff = (bits as f64 / 65536.0 - expected).abs();
+                assert!(
+                    diff <= 0.5 / 65536.0 + 1e-12 * expected.abs(),
+                    "{val} {bits}"
+                );
+            }
+            bits += 0x0000_1357;
+        }
+    }
+
+    #[test]
+    fn hyperbolic_i16f16() {
+        let mut bits = -0x000B_0000;
+        while bits < 0x000B_0000 {
+            let val = I16F16::from_bits(bits);
+            let f = val.to_num::<f64>();
+            let x = real::i32::from_bits(bits, 16);
+            for (r, expected) in [
+                (exp::sinh(x), f.sinh()),
+                (exp::cosh(x), f.cosh()),
+                (exp::tanh(x), f.tanh()),
+            ] {
+                let (bits, overflow) = real::i64::to_bits(r, 16);
+                assert!(!overflow);
+                let diff = (bits as f64 / 65536.0 - expected).abs();
+                assert!(
+                    diff <= 0.5 / 65536.0 + 1e-12 * expected.abs(),
+                    "{val} {bits}"
+                );
+            }
+            bits += 0x0000_1357;
+        }
+    }
+
+    #[test]
+    fn sigmoid_softplus_i16f16() {
+        let mut bits = -0x0040_0000;
+        while bits < 0x0040_0000 {
+            let val = I16F16::from_bits(bits);
+            let f = val.to_num::<f64>();
+            let x = real::i32::from_bits(bits, 16);
+            for (r, expected) in [
+                (exp::sigmoid(x), 1.0 / (1.0 + (-f).exp())),
+                (exp::softplus(x), f.max(0.0) + (-f.abs()).exp().ln_1p()),
+            ] {
+                let (bits, overflow) = real::i64::to_bits(r, 16);
+                assert!(!overflow);
+                let diff = (bits as f64 / 65536.0 - expected).abs();
+                assert!(
+                    diff <= 0.5 / 65536.0 + 1e-12 * expected.abs(),
+                    "{val} {bits}"
+                );
+            }
+            bits += 0x0000_3579;
+        }
+
+        // sigmoid -100 = e^-100 (1 - e^-100 + …) keeps its relative precision
+        let x = real::i8::from_bits(-100, 0);
+        let (bits, _) = real::u128::to_bits(exp::sigmoid(x), 200);
+        let (expected, _) = real::u128::to_bits(exp::exp(x), 200);
+        assert!(bits.abs_diff(expected) <= 4);
+    }
+
+    #[test]
+    fn powf_i16f16() {
+        let bases = [0.0, 0.001, 0.5, 1.0, 1.5, 2.0, 10.0, 255.5];
+        let exps = [-2.5, -1.0, -0.4545, 0.0, 0.4545, 1.0, 2.2, 3.0];
+        for b in bases {
+            for e in exps {
+                let b = I16F16::from_num(b);
+                let e = I16F16::from_num(e);
+                let (fb, fe) = (b.to_num::<f64>(), e.to_num::<f64>());
+                let expected = fb.powf(fe);
+                if fb == 0.0 && fe < 0.0 || expected >= 32768.0 {
+                    continue;
+                }
+                let x = real::i32::from_bits(b.to_bits(), 16);
+                let y = real::i32::from_bits(e.to_bits(), 16);
+                let (pow, domain_error) = exp::powf(x, y);
+                assert!(!domain_error);
+                let (bits, overflow) = real::i32::to_bits(pow, 16);
+                assert!(!overflow);
+                let diff = (bits as f64 / 65536.0 - expected).abs();
+                assert!(diff <= 0.5 / 65536.0 + 1e-12 * expected, "{b} {e} {bits}");
+            }
+        }
+
+        let minus_two = real::i8::from_bits(-2, 0);
+        let three = real::i8::from_bits(3, 0);
+        let half = real::u8::from_bits(1, 1);
+        let (pow, domain_error) = exp::powf(minus_two, three);
+        assert_eq!(
+            (real::i8::to_bits(pow, 0), domain_error),
+            ((-8, false), false)
+        );
+        assert!(exp::powf(minus_two, half).1);
+        // (-2)^(2^40) has an even integer exponent that does not fit in i32
+        let (pow, domain_error) = exp::powf(minus_two, real::i64::from_bits(1 << 40, 0));
+        assert!(!pow.neg && !domain_error);
+    }
+
+    #[test]
+    fn exp_limits() {
+        let x = real::i8::from_bits(i8::MAX, 0);
+        assert!(real::u128::to_bits(exp::exp(x), 0).1);
+        assert!(real::i128::to_bits(exp::sinh(x.neg()), 0).1);
+        assert!(real::u128::to_bits(exp::cosh(x.neg()), 0).1);
+        assert_eq!(real::i8::to_bits(exp::tanh(x), 0), (1, false));
+        assert_eq!(
+            real::i128::to_bits(exp::tanh(x.neg()), 120),
+            (-1 << 120, false)
+        );
+        assert!(real::u128::to_bits(exp::exp2(real::i16::from_bits(128, 0)), 0).1);
+        assert_eq!(real::u128::to_bits(exp::exp(x.neg()), 128), (0, false));
+        assert_eq!(real::i128::to_bits(exp::exp_m1(x.neg()), 0), (-1, false));
+
+        // exp2(127) = 2^127
+        let x = real::i8::from_bits(127, 0);
+        assert_eq!(real::u128::to_bits(exp::exp2(x), 0), (1 << 127, false));
+
+        // exp2(8.5) = 2^8 × √2
+        let x = real::i32::from_bits(I8F24::from_num(8.5).to_bits(), 24);
+        let (bits, _) = real::i64::to_bits(exp::exp2(x), 24);
+        let expected = (256.0 * 2f64.sqrt() * 2f64.powi(24)).round() as i64;
+        assert!(bits.abs_diff(expected) <= 1);
+    }
+
+    #[test]
+    fn exp_wide() {
+        // exp(-1) = 0.3678794411...
+        let x = real::i8::from_bits(-1, 0);
+        let (bits, _) = real::u128::to_bits(exp::exp(x), 128);
+        let expected = U0F128::from_bits(0x5E2D_58D8_B3BC_DF1A_BADE_C782_9054_F90E);
+        assert!(bits.abs_diff(expected.to_bits()) <= 256);
+
+        // exp(2^-100) - 1 = 2^-100 + 2^-201 + …
+        let x = real::u128::from_bits(1, 100);
+        let (bits, _) = real::u128::to_bits(exp::exp_m1(x), 128);
+        assert_eq!(bits, 1 << 28);
+        let (bits, _) = real::i128::to_bits(exp::sinh(x.neg()), 127);
+        assert_eq!(bits, -(1 << 27));
+        let (bits, _) = real::i128::to_bits(exp::tanh(x), 127);
+        assert_eq!(bits, 1 << 27);
+    }
+}
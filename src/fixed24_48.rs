@@ -0,0 +1,1744 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    dot::Acc,
+    helpers::Sealed,
+    traits::{
+        copy_bytes, Fixed, FixedBits, FixedOptionalArbitrary, FixedOptionalBorsh, FixedOptionalNum,
+        FixedOptionalSerde, FixedSigned, FixedUnsigned, FromFixed, RandomBits, ToFixed,
+    },
+    types::extra::{If, True},
+    FixedI32, FixedI64, FixedU32, FixedU64, RoundingMode, F128,
+};
+use az::{WrappingCast, WrappingCastFrom};
+use core::{
+    cmp::Ordering,
+    fmt::{
+        Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult,
+        UpperExp, UpperHex,
+    },
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
+    mem::size_of,
+    num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+        SubAssign,
+    },
+};
+use half::{bf16, f16};
+
+macro_rules! fixed24_48 {
+    (
+        description = $description:literal,
+        {Self, Inner, NonZeroInner} = {$Self:ident, $Inner:ident, $NonZeroInner:ident},
+        {Signed, Unsigned} = {$ISelf:ident, $USelf:ident},
+        Signedness = $Signedness:ident,
+        {nbits, nbytes} = {$nbits:literal, $nbytes:literal},
+        {Wide, wide_nbits, wide_nbytes} = {$Wide:ident, $wide_nbits:literal, $wide_nbytes:literal},
+        {min_bits, max_bits} = {$min_bits:literal, $max_bits:literal},
+        {range_min, range_max} = {$range_min:literal, $range_max:literal},
+        {bits, be_bytes, le_bytes} = {$bits:literal, $be_bytes:literal, $le_bytes:literal},
+        one_half_le = $one_half_le:literal,
+    ) => {
+        comment! {
+            $description, " fixed-point number with `FRAC` fractional bits,
+stored in ", stringify!($wide_nbytes), " bytes.
+
+The number has ", stringify!($nbits), " bits, of which <i>f</i>&nbsp;=&nbsp;`FRAC`
+are fractional bits and ", stringify!($nbits), "&nbsp;&minus;&nbsp;<i>f</i> are
+integer bits. The value <i>x</i> can lie in the range ", $range_min,
+            "&nbsp;≤&nbsp;<i>x</i>&nbsp;<&nbsp;", $range_max, ".
+
+The number is stored in [`", stringify!($Inner), "`], of which only the low ",
+            stringify!($nbits), " bits are significant; [`to_bits`] returns them ",
+            if_signed_unsigned!($Signedness, "sign", "zero"), " extended. Every bit
+pattern of the underlying integer is a valid number, so the type implements the
+[*bytemuck*] traits like the other fixed-point numbers.
+
+Arithmetic is performed with the same overflow semantics as ",
+            stringify!($nbits), "-bit hardware: wrapping operations wrap modulo
+2<sup>", stringify!($nbits), "</sup>, saturating operations saturate to
+[`MIN`] and [`MAX`] of this type, and operators panic on overflow when debug
+assertions are enabled and wrap otherwise.
+
+The inherent methods [`to_le_bytes`], [`from_le_bytes`] and their big-endian
+and native-endian variants work on ", stringify!($nbytes), " bytes, which is
+suitable for ", stringify!($nbits), "-bit data such as audio samples or the words
+of a DSP. The byte methods of the [`Fixed`] trait work on all
+", stringify!($wide_nbytes), " bytes of the underlying integer.
+
+Internally, operations are computed using [`", stringify!($Wide), "`], which
+can be obtained losslessly using [`From`]. This type has a reduced set of
+inherent methods: conversions using [`from_num`] and [`to_num`], conversions
+from and to bytes, addition, subtraction, multiplication, division and negation
+with their checked, saturating, wrapping, unwrapped and overflowing variants,
+comparisons and formatting. Other operations are available through the
+[`Fixed`] and [`", if_signed_unsigned!($Signedness, "FixedSigned", "FixedUnsigned"),
+            "`] traits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Wide), "};
+type Fix = ", stringify!($Self), "<8>;
+// wraps at ", stringify!($nbits), " bits, not at ", stringify!($wide_nbits), " bits
+assert_eq!(Fix::MAX.wrapping_add(Fix::DELTA), Fix::MIN);
+assert_eq!(Fix::MAX.saturating_add(Fix::DELTA), Fix::MAX);
+let x = Fix::from_num(1.5);
+assert_eq!(x.to_le_bytes(), ", $one_half_le, ");
+assert_eq!(", stringify!($Wide), "::from(x), ", stringify!($Wide), "::<8>::from_num(1.5));
+assert_eq!(x.to_string(), \"1.5\");
+```
+
+[`Fixed`]: crate::traits::Fixed
+[`FixedSigned`]: crate::traits::FixedSigned
+[`FixedUnsigned`]: crate::traits::FixedUnsigned
+[`MAX`]: Self::MAX
+[`MIN`]: Self::MIN
+[`from_le_bytes`]: Self::from_le_bytes
+[`from_num`]: Self::from_num
+[`to_bits`]: Self::to_bits
+[`to_le_bytes`]: Self::to_le_bytes
+[`to_num`]: Self::to_num
+[*bytemuck*]: https://docs.rs/bytemuck/^1/bytemuck/index.html
+";
+            #[repr(transparent)]
+            pub struct $Self<const FRAC: i32> {
+                bits: $Inner,
+            }
+        }
+
+        impl<const FRAC: i32> Clone for $Self<FRAC> {
+            #[inline]
+            fn clone(&self) -> $Self<FRAC> {
+                *self
+            }
+        }
+
+        impl<const FRAC: i32> Copy for $Self<FRAC> {}
+
+        impl<const FRAC: i32> Default for $Self<FRAC> {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<const FRAC: i32> Hash for $Self<FRAC> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.to_bits().hash(state);
+            }
+        }
+
+        impl<const FRAC: i32> PartialEq for $Self<FRAC> {
+            #[inline]
+            fn eq(&self, rhs: &$Self<FRAC>) -> bool {
+                self.to_bits() == rhs.to_bits()
+            }
+        }
+
+        impl<const FRAC: i32> Eq for $Self<FRAC> {}
+
+        impl<const FRAC: i32> PartialOrd for $Self<FRAC> {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Self<FRAC>) -> Option<Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl<const FRAC: i32> Ord for $Self<FRAC> {
+            #[inline]
+            fn cmp(&self, rhs: &$Self<FRAC>) -> Ordering {
+                self.to_bits().cmp(&rhs.to_bits())
+            }
+        }
+
+        impl<const FRAC: i32> $Self<FRAC> {
+            // the number of bits, and the number of unused high bits in the
+            // underlying integer
+            const NBITS: u32 = $nbits;
+            const PAD: u32 = $wide_nbits - $nbits;
+
+            comment! {
+                "Zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO, Fix::from_bits(0));
+```
+";
+                pub const ZERO: $Self<FRAC> = $Self::from_bits(0);
+            }
+
+            comment! {
+                "The difference between any two successive representable numbers, <i>Δ</i>.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA, Fix::from_bits(1));
+```
+";
+                pub const DELTA: $Self<FRAC> = $Self::from_bits(1);
+            }
+
+            comment! {
+                "The smallest value that can be represented.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MIN, Fix::from_bits(", $min_bits, "));
+```
+";
+                pub const MIN: $Self<FRAC> = $Self::from_bits($Inner::MIN >> Self::PAD);
+            }
+
+            comment! {
+                "The largest value that can be represented.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX, Fix::from_bits(", $max_bits, "));
+```
+";
+                pub const MAX: $Self<FRAC> = $Self::from_bits($Inner::MAX >> Self::PAD);
+            }
+
+            comment! {
+                "The number of integer bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<6>;
+assert_eq!(Fix::INT_NBITS, ", stringify!($nbits), " - 6);
+```
+";
+                pub const INT_NBITS: u32 = ($nbits - FRAC) as u32;
+            }
+
+            comment! {
+                "The number of fractional bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<6>;
+assert_eq!(Fix::FRAC_NBITS, 6);
+```
+";
+                pub const FRAC_NBITS: u32 = FRAC as u32;
+            }
+
+            comment! {
+                "Creates a fixed-point number that has a bitwise representation
+identical to the given integer.
+
+Only the low ", stringify!($nbits), " bits of the integer are used, so the
+integer must lie in the range of [`to_bits`].
+
+# Panics
+
+When debug assertions are enabled, panics if `bits` does not fit in ",
+                stringify!($nbits), " bits. When debug assertions are not enabled, the wrapped
+value is returned, which is the same as the value returned by
+[`wrapping_from_bits`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 0010.0000 = 2
+assert_eq!(Fix::from_bits(0b10_0000), Fix::from_num(2));
+```
+
+[`to_bits`]: Self::to_bits
+[`wrapping_from_bits`]: Self::wrapping_from_bits
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn from_bits(bits: $Inner) -> $Self<FRAC> {
+                    let (ans, overflow) = $Self::overflowing_from_bits(bits);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its bitwise representation
+if `bits` fits in ", stringify!($nbits), " bits, otherwise returns [`None`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::checked_from_bits(", $max_bits, "), Some(Fix::MAX));
+assert_eq!(Fix::checked_from_bits(", $max_bits, " + 1), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_from_bits(bits: $Inner) -> Option<$Self<FRAC>> {
+                    match $Self::overflowing_from_bits(bits) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from the low ", stringify!($nbits), "
+bits of `bits`, wrapping if it does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::wrapping_from_bits(", $max_bits, " + 1), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_from_bits(bits: $Inner) -> $Self<FRAC> {
+                    $Self::overflowing_from_bits(bits).0
+                }
+            }
+
+            #[inline]
+            const fn overflowing_from_bits(bits: $Inner) -> ($Self<FRAC>, bool) {
+                let wrapped = (bits << Self::PAD) >> Self::PAD;
+                ($Self { bits: wrapped }, wrapped != bits)
+            }
+
+            comment! {
+                "Creates an integer that has a bitwise representation identical
+to the given fixed-point number.
+
+The integer is ",
+                if_signed_unsigned!($Signedness, "sign-extended", "zero-extended"),
+                " from ", stringify!($nbits), " bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 2 is 0010.0000
+assert_eq!(Fix::from_num(2).to_bits(), 0b10_0000);
+assert_eq!(Fix::MAX.to_bits(), ", $max_bits, ");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_bits(self) -> $Inner {
+                    (self.bits << Self::PAD) >> Self::PAD
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its representation as a byte
+array in big endian.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_be_bytes(", $be_bytes, "),
+    Fix::from_bits(", $bits, ")
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_be_bytes(bytes: [u8; $nbytes]) -> $Self<FRAC> {
+                    let mut le = [0; $nbytes];
+                    let mut i = 0;
+                    while i < $nbytes {
+                        le[i] = bytes[$nbytes - 1 - i];
+                        i += 1;
+                    }
+                    $Self::from_le_bytes(le)
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its representation as a byte
+array in little endian.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_le_bytes(", $le_bytes, "),
+    Fix::from_bits(", $bits, ")
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_le_bytes(bytes: [u8; $nbytes]) -> $Self<FRAC> {
+                    let mut wide = [0; $wide_nbytes];
+                    let mut i = 0;
+                    while i < $nbytes {
+                        wide[i] = bytes[i];
+                        i += 1;
+                    }
+                    $Self::wrapping_from_bits($Inner::from_le_bytes(wide))
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its representation as a byte
+array in native endian.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    if cfg!(target_endian = \"big\") {
+        Fix::from_ne_bytes(", $be_bytes, ")
+    } else {
+        Fix::from_ne_bytes(", $le_bytes, ")
+    },
+    Fix::from_bits(", $bits, ")
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_ne_bytes(bytes: [u8; $nbytes]) -> $Self<FRAC> {
+                    if cfg!(target_endian = "big") {
+                        $Self::from_be_bytes(bytes)
+                    } else {
+                        $Self::from_le_bytes(bytes)
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this fixed-point number
+as a byte array in big-endian byte order.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let val = Fix::from_bits(", $bits, ");
+assert_eq!(val.to_be_bytes(), ", $be_bytes, ");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_be_bytes(self) -> [u8; $nbytes] {
+                    let le = self.to_le_bytes();
+                    let mut be = [0; $nbytes];
+                    let mut i = 0;
+                    while i < $nbytes {
+                        be[i] = le[$nbytes - 1 - i];
+                        i += 1;
+                    }
+                    be
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this fixed-point number
+as a byte array in little-endian byte order.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let val = Fix::from_bits(", $bits, ");
+assert_eq!(val.to_le_bytes(), ", $le_bytes, ");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_le_bytes(self) -> [u8; $nbytes] {
+                    let wide = self.to_bits().to_le_bytes();
+                    let mut le = [0; $nbytes];
+                    let mut i = 0;
+                    while i < $nbytes {
+                        le[i] = wide[i];
+                        i += 1;
+                    }
+                    le
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this fixed-point number
+as a byte array in native byte order.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let val = Fix::from_bits(", $bits, ");
+assert_eq!(
+    val.to_ne_bytes(),
+    if cfg!(target_endian = \"big\") {
+        ", $be_bytes, "
+    } else {
+        ", $le_bytes, "
+    }
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_ne_bytes(self) -> [u8; $nbytes] {
+                    if cfg!(target_endian = "big") {
+                        self.to_be_bytes()
+                    } else {
+                        self.to_le_bytes()
+                    }
+                }
+            }
+
+            #[inline]
+            const fn fixed(self) -> $Wide<FRAC> {
+                $Wide::from_bits(self.to_bits())
+            }
+
+            #[inline]
+            const fn overflowing_from_fixed_bits(fixed: $Wide<FRAC>) -> ($Self<FRAC>, bool) {
+                $Self::overflowing_from_bits(fixed.to_bits())
+            }
+
+            #[inline]
+            const fn saturating_from_fixed_bits(fixed: $Wide<FRAC>) -> $Self<FRAC> {
+                let bits = fixed.to_bits();
+                if bits < $Self::<FRAC>::MIN.to_bits() {
+                    $Self::MIN
+                } else if bits > $Self::<FRAC>::MAX.to_bits() {
+                    $Self::MAX
+                } else {
+                    $Self { bits }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number.
+
+The other number can be a primitive integer or floating-point number, or
+another fixed-point number. Rounding and overflow work as in the
+[`from_num`] method of [`", stringify!($Wide), "`], except that the
+result has to fit in ", stringify!($nbits), " bits.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit. When debug
+assertions are not enabled, the wrapped value can be returned, but it is not
+considered a breaking change if in the future it panics; if wrapping is required
+use [`wrapping_from_num`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3), Fix::from_bits(0b11_0000));
+assert_eq!(Fix::from_num(1.75), Fix::from_bits(0b1_1100));
+```
+
+[`from_num`]: ", stringify!($Wide), "::from_num
+[`wrapping_from_num`]: Self::wrapping_from_num
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                pub fn from_num<Src: ToFixed>(src: Src) -> $Self<FRAC> {
+                    let (ans, overflow) = $Self::overflowing_from_num(src);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number if it fits,
+otherwise returns [`None`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::checked_from_num(3), Some(Fix::from_bits(0b11_0000)));
+assert_eq!(Fix::checked_from_num(1i64 << ", stringify!($nbits), "), None);
+```
+";
+                #[inline]
+                pub fn checked_from_num<Src: ToFixed>(src: Src) -> Option<$Self<FRAC>> {
+                    match $Self::overflowing_from_num(src) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number, saturating if
+it does not fit.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::saturating_from_num(3), Fix::from_bits(0b11_0000));
+assert_eq!(Fix::saturating_from_num(1e20), Fix::MAX);
+assert_eq!(Fix::saturating_from_num(-1e20), Fix::MIN);
+```
+
+[NaN]: f64::is_nan
+";
+                #[inline]
+                #[track_caller]
+                pub fn saturating_from_num<Src: ToFixed>(src: Src) -> $Self<FRAC> {
+                    $Self::saturating_from_fixed_bits(src.saturating_to_fixed())
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number, wrapping the
+value on overflow.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::wrapping_from_num(3), Fix::from_bits(0b11_0000));
+// only the low ", stringify!($nbits), " bits are kept
+assert_eq!(Fix::wrapping_from_num(", $max_bits, "i64 + 1), Fix::ZERO);
+```
+
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                pub fn wrapping_from_num<Src: ToFixed>(src: Src) -> $Self<FRAC> {
+                    $Self::overflowing_from_num(src).0
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number, panicking on
+overflow.
+
+# Panics
+
+Panics if the value does not fit, even when debug assertions are not enabled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::unwrapped_from_num(3), Fix::from_bits(0b11_0000));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::unwrapped_from_num(1i64 << ", stringify!($nbits), ");
+```
+";
+                #[inline]
+                #[track_caller]
+                pub fn unwrapped_from_num<Src: ToFixed>(src: Src) -> $Self<FRAC> {
+                    match $Self::overflowing_from_num(src) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::overflowing_from_num(3), (Fix::from_bits(0b11_0000), false));
+assert_eq!(Fix::overflowing_from_num(", $max_bits, "i64 + 1), (Fix::ZERO, true));
+```
+
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                pub fn overflowing_from_num<Src: ToFixed>(src: Src) -> ($Self<FRAC>, bool) {
+                    let (fixed, fixed_overflow) = src.overflowing_to_fixed();
+                    let (ans, overflow) = $Self::overflowing_from_fixed_bits(fixed);
+                    (ans, fixed_overflow || overflow)
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number.
+
+The other number can be a primitive integer or floating-point number, or
+another fixed-point number. This is the same as converting
+[`", stringify!($Wide), "`] using its [`to_num`] method.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit. When debug
+assertions are not enabled, the wrapped value can be returned, but it is not
+considered a breaking change if in the future it panics; if wrapping is required
+use [`wrapping_to_num`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3.75).to_num::<i32>(), 3);
+assert_eq!(Fix::from_num(3.75).to_num::<f32>(), 3.75);
+```
+
+[`to_num`]: ", stringify!($Wide), "::to_num
+[`wrapping_to_num`]: Self::wrapping_to_num
+";
+                #[inline]
+                #[track_caller]
+                pub fn to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number if it fits,
+otherwise returns [`None`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3.75).checked_to_num::<u8>(), Some(3));
+assert_eq!(Fix::MAX.checked_to_num::<u8>(), None);
+```
+";
+                #[inline]
+                pub fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> {
+                    Dst::checked_from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number, saturating the
+value on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3.75).saturating_to_num::<u8>(), 3);
+assert_eq!(Fix::MAX.saturating_to_num::<u8>(), u8::MAX);
+```
+";
+                #[inline]
+                pub fn saturating_to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::saturating_from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number, wrapping the
+value on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3.75).wrapping_to_num::<u8>(), 3);
+assert_eq!(Fix::from_num(258).wrapping_to_num::<u8>(), 2);
+```
+";
+                #[inline]
+                pub fn wrapping_to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::wrapping_from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number, panicking on
+overflow.
+
+# Panics
+
+Panics if the value does not fit, even when debug assertions are not enabled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3.75).unwrapped_to_num::<u8>(), 3);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_to_num::<u8>();
+```
+";
+                #[inline]
+                #[track_caller]
+                pub fn unwrapped_to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::unwrapped_from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number.
+
+Returns a [tuple] of the number and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3.75).overflowing_to_num::<u8>(), (3, false));
+assert_eq!(Fix::from_num(258).overflowing_to_num::<u8>(), (2, true));
+```
+";
+                #[inline]
+                pub fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) {
+                    Dst::overflowing_from_fixed(self.fixed())
+                }
+            }
+
+            narrow_op! {
+                Self = $Self<FRAC>,
+                fn = [pub const fn],
+                example = "<4>",
+                {wide, overflowing_narrow, saturating_narrow} =
+                    {fixed, overflowing_from_fixed_bits, saturating_from_fixed_bits},
+                {op, result} = {"addition", "sum"},
+                {checked, saturating, wrapping, unwrapped, overflowing} =
+                    {checked_add, saturating_add, wrapping_add, unwrapped_add, overflowing_add},
+                {lhs, rhs, ans} = {"1.5", "0.25", "1.75"},
+                {over_lhs, over_rhs, sat, wrap} = {"Fix::MAX", "Fix::DELTA", "Fix::MAX", "Fix::MIN"},
+                panics = "",
+            }
+
+            narrow_op! {
+                Self = $Self<FRAC>,
+                fn = [pub const fn],
+                example = "<4>",
+                {wide, overflowing_narrow, saturating_narrow} =
+                    {fixed, overflowing_from_fixed_bits, saturating_from_fixed_bits},
+                {op, result} = {"subtraction", "difference"},
+                {checked, saturating, wrapping, unwrapped, overflowing} =
+                    {checked_sub, saturating_sub, wrapping_sub, unwrapped_sub, overflowing_sub},
+                {lhs, rhs, ans} = {"1.5", "0.25", "1.25"},
+                {over_lhs, over_rhs, sat, wrap} = {"Fix::MIN", "Fix::DELTA", "Fix::MIN", "Fix::MAX"},
+                panics = "",
+            }
+
+            narrow_op! {
+                Self = $Self<FRAC>,
+                fn = [pub const fn],
+                example = "<4>",
+                {wide, overflowing_narrow, saturating_narrow} =
+                    {fixed, overflowing_from_fixed_bits, saturating_from_fixed_bits},
+                {op, result} = {"multiplication", "product"},
+                {checked, saturating, wrapping, unwrapped, overflowing} =
+                    {checked_mul, saturating_mul, wrapping_mul, unwrapped_mul, overflowing_mul},
+                {lhs, rhs, ans} = {"1.5", "0.25", "0.375"},
+                {over_lhs, over_rhs, sat, wrap} = {
+                    "Fix::MAX",
+                    "Fix::from_num(2)",
+                    "Fix::MAX",
+                    "Fix::wrapping_from_bits(Fix::MAX.to_bits() * 2)"
+                },
+                panics = "",
+            }
+
+            comment! {
+                "Checked negation. Returns the negated value, or [`None`] on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).checked_neg(), Some(Fix::from_num(-5)));
+assert_eq!(Fix::MIN.checked_neg(), None);",
+                    "assert_eq!(Fix::ZERO.checked_neg(), Some(Fix::ZERO));
+assert_eq!(Fix::from_num(5).checked_neg(), None);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_neg(self) -> Option<$Self<FRAC>> {
+                    match self.overflowing_neg() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating negation. Returns the negated value, saturating on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "This method always returns zero.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).saturating_neg(), Fix::from_num(-5));
+assert_eq!(Fix::MIN.saturating_neg(), Fix::MAX);",
+                    "assert_eq!(Fix::ZERO.saturating_neg(), Fix::ZERO);
+assert_eq!(Fix::from_num(5).saturating_neg(), Fix::ZERO);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_neg(self) -> $Self<FRAC> {
+                    $Self::saturating_from_fixed_bits(self.fixed().saturating_neg())
+                }
+            }
+
+            comment! {
+                "Wrapping negation. Returns the negated value, wrapping on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).wrapping_neg(), Fix::from_num(-5));
+assert_eq!(Fix::MIN.wrapping_neg(), Fix::MIN);",
+                    "assert_eq!(Fix::ZERO.wrapping_neg(), Fix::ZERO);
+assert_eq!(Fix::DELTA.wrapping_neg(), Fix::MAX);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_neg(self) -> $Self<FRAC> {
+                    self.overflowing_neg().0
+                }
+            }
+
+            comment! {
+                "Unwrapped negation. Returns the negated value, panicking on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).unwrapped_neg(), Fix::from_num(-5));",
+                    "assert_eq!(Fix::ZERO.unwrapped_neg(), Fix::ZERO);",
+                ),
+                "
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = ",
+                if_signed_unsigned!($Signedness, "Fix::MIN", "Fix::DELTA"),
+                ".unwrapped_neg();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_neg(self) -> $Self<FRAC> {
+                    match self.overflowing_neg() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing negation.
+
+Returns a [tuple] of the negated value and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).overflowing_neg(), (Fix::from_num(-5), false));
+assert_eq!(Fix::MIN.overflowing_neg(), (Fix::MIN, true));",
+                    "assert_eq!(Fix::ZERO.overflowing_neg(), (Fix::ZERO, false));
+assert_eq!(Fix::DELTA.overflowing_neg(), (Fix::MAX, true));",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_neg(self) -> ($Self<FRAC>, bool) {
+                    let (fixed, fixed_overflow) = self.fixed().overflowing_neg();
+                    let (ans, overflow) = $Self::overflowing_from_fixed_bits(fixed);
+                    (ans, fixed_overflow || overflow)
+                }
+            }
+        }
+
+        impl<const FRAC: i32> $Self<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= $wide_nbits) }>: True,
+        {
+            narrow_op! {
+                Self = $Self<FRAC>,
+                fn = [pub const fn],
+                example = "<4>",
+                {wide, overflowing_narrow, saturating_narrow} =
+                    {fixed, overflowing_from_fixed_bits, saturating_from_fixed_bits},
+                {op, result} = {"division", "quotient"},
+                {checked, saturating, wrapping, unwrapped, overflowing} =
+                    {checked_div, saturating_div, wrapping_div, unwrapped_div, overflowing_div},
+                {lhs, rhs, ans} = {"1.5", "0.25", "6"},
+                {over_lhs, over_rhs, sat, wrap} = {
+                    "Fix::MAX",
+                    "Fix::from_num(0.5)",
+                    "Fix::MAX",
+                    "Fix::wrapping_from_bits(Fix::MAX.to_bits() * 2)"
+                },
+                panics = "
+# Panics
+
+Panics if the divisor is zero.
+",
+            }
+        }
+
+        impl<const FRAC: i32> From<$Self<FRAC>> for $Wide<FRAC> {
+            /// Converts a fixed-point number to the wider type. This
+            /// conversion is lossless.
+            #[inline]
+            fn from(src: $Self<FRAC>) -> $Wide<FRAC> {
+                src.fixed()
+            }
+        }
+
+        narrow_op_trait! { $Self<FRAC>, where []; Add add, AddAssign add_assign, overflowing_add }
+        narrow_op_trait! { $Self<FRAC>, where []; Sub sub, SubAssign sub_assign, overflowing_sub }
+        narrow_op_trait! { $Self<FRAC>, where []; Mul mul, MulAssign mul_assign, overflowing_mul }
+        narrow_op_trait! {
+            $Self<FRAC>, where [If<{ (0 <= FRAC) & (FRAC <= $wide_nbits) }>: True,];
+            Div div, DivAssign div_assign, overflowing_div
+        }
+
+        impl<const FRAC: i32> Neg for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> $Self<FRAC> {
+                let (ans, overflow) = self.overflowing_neg();
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        narrow_fmt! {
+            $Self<FRAC>, where [If<{ (0 <= FRAC) & (FRAC <= $wide_nbits) }>: True,], fixed;
+            Display Binary Octal LowerHex UpperHex LowerExp UpperExp
+        }
+
+        narrow_fixed! {
+            Self = $Self<FRAC>, where [];
+            Signedness = $Signedness,
+            {Bits, NonZeroBits, Fixed} = {$Inner, $NonZeroInner, $Wide<FRAC>},
+            {Signed, Unsigned} = {$ISelf<FRAC>, $USelf<FRAC>},
+            {TRY_ONE, TRY_NEG_ONE} = {
+                if 0 <= FRAC && FRAC < if_signed_unsigned!($Signedness, $nbits - 1, $nbits) {
+                    Some($Self { bits: 1 << FRAC })
+                } else {
+                    None
+                },
+                if 0 <= FRAC && FRAC < $nbits {
+                    Some($Self { bits: -1 << FRAC })
+                } else {
+                    None
+                }
+            },
+            // The byte array methods of the Fixed trait work on the whole
+            // underlying integer, unlike the inherent methods.
+            bytes = {
+                #[inline]
+                #[track_caller]
+                fn from_be_bytes(bytes: [u8; size_of::<Self>()]) -> Self {
+                    $Self::from_bits($Inner::from_be_bytes(copy_bytes(bytes)))
+                }
+                #[inline]
+                #[track_caller]
+                fn from_le_bytes(bytes: [u8; size_of::<Self>()]) -> Self {
+                    $Self::from_bits($Inner::from_le_bytes(copy_bytes(bytes)))
+                }
+                #[inline]
+                #[track_caller]
+                fn from_ne_bytes(bytes: [u8; size_of::<Self>()]) -> Self {
+                    $Self::from_bits($Inner::from_ne_bytes(copy_bytes(bytes)))
+                }
+                #[inline]
+                fn to_be_bytes(self) -> [u8; size_of::<Self>()] {
+                    copy_bytes(self.to_bits().to_be_bytes())
+                }
+                #[inline]
+                fn to_le_bytes(self) -> [u8; size_of::<Self>()] {
+                    copy_bytes(self.to_bits().to_le_bytes())
+                }
+                #[inline]
+                fn to_ne_bytes(self) -> [u8; size_of::<Self>()] {
+                    copy_bytes(self.to_bits().to_ne_bytes())
+                }
+            },
+        }
+
+        impl<const FRAC: i32> Mul<$Inner> for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            #[track_caller]
+            fn mul(self, rhs: $Inner) -> $Self<FRAC> {
+                let (ans, overflow) = self.overflowing_mul_int(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const FRAC: i32> MulAssign<$Inner> for $Self<FRAC> {
+            #[inline]
+            #[track_caller]
+            fn mul_assign(&mut self, rhs: $Inner) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const FRAC: i32> Div<$Inner> for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            #[track_caller]
+            fn div(self, rhs: $Inner) -> $Self<FRAC> {
+                let (ans, overflow) = self.overflowing_div_int(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const FRAC: i32> DivAssign<$Inner> for $Self<FRAC> {
+            #[inline]
+            #[track_caller]
+            fn div_assign(&mut self, rhs: $Inner) {
+                *self = *self / rhs;
+            }
+        }
+
+        if_unsigned! {
+            $Signedness;
+            impl<const FRAC: i32> Div<$NonZeroInner> for $Self<FRAC> {
+                type Output = $Self<FRAC>;
+                #[inline]
+                fn div(self, rhs: $NonZeroInner) -> $Self<FRAC> {
+                    self / rhs.get()
+                }
+            }
+
+            impl<const FRAC: i32> DivAssign<$NonZeroInner> for $Self<FRAC> {
+                #[inline]
+                fn div_assign(&mut self, rhs: $NonZeroInner) {
+                    *self = *self / rhs;
+                }
+            }
+        }
+    };
+}
+
+fixed24_48! {
+    description = "A 24-bit signed",
+    {Self, Inner, NonZeroInner} = {FixedI24, i32, NonZeroI32},
+    {Signed, Unsigned} = {FixedI24, FixedU24},
+    Signedness = Signed,
+    {nbits, nbytes} = {24, 3},
+    {Wide, wide_nbits, wide_nbytes} = {FixedI32, 32, 4},
+    {min_bits, max_bits} = {"-0x80_0000", "0x7F_FFFF"},
+    {range_min, range_max} = {"&minus;2<sup>23</sup>/2<sup><i>f</i></sup>", "2<sup>23</sup>/2<sup><i>f</i></sup>"},
+    {bits, be_bytes, le_bytes} = {"0x12_3456", "[0x12, 0x34, 0x56]", "[0x56, 0x34, 0x12]"},
+    one_half_le = "[0x80, 0x01, 0x00]",
+}
+fixed24_48! {
+    description = "A 24-bit unsigned",
+    {Self, Inner, NonZeroInner} = {FixedU24, u32, NonZeroU32},
+    {Signed, Unsigned} = {FixedI24, FixedU24},
+    Signedness = Unsigned,
+    {nbits, nbytes} = {24, 3},
+    {Wide, wide_nbits, wide_nbytes} = {FixedU32, 32, 4},
+    {min_bits, max_bits} = {"0", "0xFF_FFFF"},
+    {range_min, range_max} = {"0", "2<sup>24</sup>/2<sup><i>f</i></sup>"},
+    {bits, be_bytes, le_bytes} = {"0x12_3456", "[0x12, 0x34, 0x56]", "[0x56, 0x34, 0x12]"},
+    one_half_le = "[0x80, 0x01, 0x00]",
+}
+fixed24_48! {
+    description = "A 48-bit signed",
+    {Self, Inner, NonZeroInner} = {FixedI48, i64, NonZeroI64},
+    {Signed, Unsigned} = {FixedI48, FixedU48},
+    Signedness = Signed,
+    {nbits, nbytes} = {48, 6},
+    {Wide, wide_nbits, wide_nbytes} = {FixedI64, 64, 8},
+    {min_bits, max_bits} = {"-0x8000_0000_0000", "0x7FFF_FFFF_FFFF"},
+    {range_min, range_max} = {"&minus;2<sup>47</sup>/2<sup><i>f</i></sup>", "2<sup>47</sup>/2<sup><i>f</i></sup>"},
+    {bits, be_bytes, le_bytes} = {
+        "0x1234_5678_9ABC",
+        "[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]",
+        "[0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]"
+    },
+    one_half_le = "[0x80, 0x01, 0x00, 0x00, 0x00, 0x00]",
+}
+fixed24_48! {
+    description = "A 48-bit unsigned",
+    {Self, Inner, NonZeroInner} = {FixedU48, u64, NonZeroU64},
+    {Signed, Unsigned} = {FixedI48, FixedU48},
+    Signedness = Unsigned,
+    {nbits, nbytes} = {48, 6},
+    {Wide, wide_nbits, wide_nbytes} = {FixedU64, 64, 8},
+    {min_bits, max_bits} = {"0", "0xFFFF_FFFF_FFFF"},
+    {range_min, range_max} = {"0", "2<sup>48</sup>/2<sup><i>f</i></sup>"},
+    {bits, be_bytes, le_bytes} = {
+        "0x1234_5678_9ABC",
+        "[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]",
+        "[0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]"
+    },
+    one_half_le = "[0x80, 0x01, 0x00, 0x00, 0x00, 0x00]",
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FixedI24, FixedI32, FixedI48, FixedU24, FixedU48};
+    use bytemuck::TransparentWrapper;
+    use std::{format, string::ToString};
+
+    // Wraps x to 24 bits, sign-extended if signed.
+    fn wrap24(x: i64, signed: bool) -> i64 {
+        if signed {
+            (x << 40) >> 40
+        } else {
+            x & 0xFF_FFFF
+        }
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<FixedI24<0>>(), 4);
+        assert_eq!(core::mem::size_of::<[FixedU48<0>; 4]>(), 32);
+        let samples: [FixedI24<23>; 2] = bytemuck::cast([0x40_0000i32, -0x40_0000]);
+        assert_eq!(samples[0].to_num::<f64>(), 0.5);
+        assert_eq!(samples[1].to_num::<f64>(), -0.5);
+        // the bits that do not fit in 24 bits are ignored
+        let x: FixedI24<0> = bytemuck::cast(0x7F80_0000i32);
+        assert_eq!(x, FixedI24::MIN);
+        assert_eq!(x.to_bits(), -0x80_0000);
+        let y = FixedU24::<0>::wrap(0xFF00_0001);
+        assert_eq!(y, FixedU24::DELTA);
+        assert_eq!(y.to_num::<u32>(), 1);
+        assert_eq!(y + y, FixedU24::from_num(2));
+        assert_eq!(!y, FixedU24::MAX - FixedU24::DELTA);
+    }
+
+    #[test]
+    fn bits_bytes() {
+        type I = FixedI24<0>;
+        type U = FixedU24<0>;
+        for bits in [-0x80_0000, -0x12_3456, -1, 0, 1, 0x12_3456, 0x7F_FFFF] {
+            let x = I::from_bits(bits);
+            assert_eq!(x.to_bits(), bits);
+            assert_eq!(I::from_le_bytes(x.to_le_bytes()), x);
+            assert_eq!(I::from_be_bytes(x.to_be_bytes()), x);
+            assert_eq!(I::from_ne_bytes(x.to_ne_bytes()), x);
+            assert_eq!(
+                x.to_le_bytes(),
+                <[u8; 3]>::try_from(&bits.to_le_bytes()[..3]).unwrap()
+            );
+        }
+        assert_eq!(I::from_le_bytes([0xFF, 0xFF, 0x80]).to_bits(), -0x7F_0001);
+        assert_eq!(U::from_le_bytes([0xFF, 0xFF, 0x80]).to_bits(), 0x80_FFFF);
+        assert_eq!(I::checked_from_bits(0x80_0000), None);
+        assert_eq!(I::checked_from_bits(-0x80_0001), None);
+        assert_eq!(U::checked_from_bits(0x100_0000), None);
+        assert_eq!(I::wrapping_from_bits(0x80_0000), I::MIN);
+        assert_eq!(U::wrapping_from_bits(0x123_4567).to_bits(), 0x23_4567);
+        let x = FixedI48::<0>::from_bits(-0x1234_5678_9ABC);
+        assert_eq!(x.to_be_bytes(), [0xED, 0xCB, 0xA9, 0x87, 0x65, 0x44]);
+        assert_eq!(FixedI48::<0>::from_be_bytes(x.to_be_bytes()), x);
+        assert_eq!(FixedU48::<0>::MAX.to_bits(), 0xFFFF_FFFF_FFFF);
+    }
+
+    // Checks the binary operations against 64-bit integer arithmetic wrapped
+    // to 24 bits, with 8 fractional bits.
+    macro_rules! check_ops {
+        ($Fix:ident, $signed:expr, $vals:expr) => {{
+            type F = $Fix<8>;
+            let (min, max) = (i64::from(F::MIN.to_bits()), i64::from(F::MAX.to_bits()));
+            for a in $vals {
+                for b in $vals {
+                    let (x, y) = (F::from_bits(a), F::from_bits(b));
+                    let (a, b) = (i64::from(a), i64::from(b));
+                    let div = (b != 0).then(|| (a << 8) / b);
+                    let exact = [
+                        ('+', Some(a + b)),
+                        ('-', Some(a - b)),
+                        ('*', Some((a * b) >> 8)),
+                        ('/', div),
+                    ];
+                    for (op, exact) in exact {
+                        let Some(exact) = exact else { continue };
+                        let (wrapped, overflowing, checked, saturating) = match op {
+                            '+' => (
+                                x.wrapping_add(y),
+                                x.overflowing_add(y),
+                                x.checked_add(y),
+                                x.saturating_add(y),
+                            ),
+                            '-' => (
+                                x.wrapping_sub(y),
+                                x.overflowing_sub(y),
+                                x.checked_sub(y),
+                                x.saturating_sub(y),
+                            ),
+                            '*' => (
+                                x.wrapping_mul(y),
+                                x.overflowing_mul(y),
+                                x.checked_mul(y),
+                                x.saturating_mul(y),
+                            ),
+                            _ => (
+                                x.wrapping_div(y),
+                                x.overflowing_div(y),
+                                x.checked_div(y),
+                                x.saturating_div(y),
+                            ),
+                        };
+                        let fits = (min..=max).contains(&exact);
+                        assert_eq!(
+                            i64::from(wrapped.to_bits()),
+                            wrap24(exact, $signed),
+                            "{a} {op} {b}"
+                        );
+                        assert_eq!(overflowing, (wrapped, !fits), "{a} {op} {b}");
+                        assert_eq!(checked, fits.then_some(wrapped), "{a} {op} {b}");
+                        let sat = i64::from(saturating.to_bits());
+                        assert_eq!(sat, exact.clamp(min, max), "{a} {op} {b}");
+                    }
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn ops_signed() {
+        check_ops!(
+            FixedI24,
+            true,
+            [
+                -0x80_0000, -0x7F_FFFF, -0x40_0000, -0x1234, -0x100, -0x80, -1, 0, 1, 0x80, 0x100,
+                0x1234, 0x3F_FFFF, 0x40_0000, 0x7F_FFFF,
+            ]
+        );
+    }
+
+    #[test]
+    fn ops_unsigned() {
+        check_ops!(
+            FixedU24,
+            false,
+            [0u32, 1, 0x80, 0x100, 0x1234, 0x7F_FFFF, 0x80_0000, 0xFF_FFFF]
+        );
+    }
+
+    #[test]
+    fn neg() {
+        type I = FixedI24<0>;
+        type U = FixedU24<0>;
+        assert_eq!(I::MIN.overflowing_neg(), (I::MIN, true));
+        assert_eq!(I::MIN.saturating_neg(), I::MAX);
+        assert_eq!(I::MAX.checked_neg(), Some(I::MIN + I::DELTA));
+        assert_eq!(-I::from_num(5), I::from_num(-5));
+        assert_eq!(U::ZERO.overflowing_neg(), (U::ZERO, false));
+        assert_eq!(U::DELTA.overflowing_neg(), (U::MAX, true));
+        assert_eq!(U::DELTA.saturating_neg(), U::ZERO);
+        assert_eq!(U::DELTA.checked_neg(), None);
+    }
+
+    #[test]
+    fn from_to_num() {
+        type I = FixedI24<8>;
+        assert_eq!(I::from_num(-1.5).to_bits(), -0x180);
+        assert_eq!(I::checked_from_num(0x8000), None);
+        assert_eq!(I::checked_from_num(0x7FFF), Some(I::from_bits(0x7F_FF00)));
+        assert_eq!(I::saturating_from_num(1e10), I::MAX);
+        assert_eq!(I::saturating_from_num(i64::MIN), I::MIN);
+        // wraps at 24 bits even when the value fits in 32 bits
+        assert_eq!(I::overflowing_from_num(0x8000), (I::MIN, true));
+        assert_eq!(I::wrapping_from_num(0x1_0001), I::from_num(1));
+        // 1e10 × 2^8 = 0x254_0BE4_0000, and 0xE4_0000 is negative in 24 bits
+        assert_eq!(I::wrapping_from_num(1e10), I::from_bits(-0x1C_0000));
+        assert_eq!(
+            I::from_num(FixedI32::<16>::from_num(2.25)),
+            I::from_num(2.25)
+        );
+        assert_eq!(
+            I::overflowing_from_num(FixedI32::<8>::MAX),
+            (I::wrapping_from_bits(-1), true)
+        );
+        let x = I::from_num(-2.75);
+        assert_eq!(x.to_num::<i8>(), -3);
+        assert_eq!(x.to_num::<f32>(), -2.75);
+        assert_eq!(x.to_num::<FixedI48<20>>(), FixedI48::<20>::from_num(-2.75));
+        assert_eq!(FixedI32::<4>::from_num(x), FixedI32::<4>::from_num(-2.75));
+        assert_eq!(I::MAX.overflowing_to_num::<i8>(), (-1, true));
+        assert_eq!(
+            FixedU24::<0>::from_num(x.saturating_to_num::<u8>()),
+            FixedU24::ZERO
+        );
+    }
+
+    #[test]
+    fn fixed_trait() {
+        use crate::traits::{Fixed, FixedSigned, FixedUnsigned};
+
+        fn int_bits<F: Fixed>() -> i32 {
+            F::INT_BITS
+        }
+        fn abs<F: FixedSigned>(x: F) -> F::Unsigned {
+            x.unsigned_abs()
+        }
+        fn is_pow2<F: FixedUnsigned>(x: F) -> bool {
+            x.is_power_of_two()
+        }
+
+        type I = FixedI24<8>;
+        type U = FixedU48<8>;
+        assert_eq!(int_bits::<I>(), 16);
+        assert_eq!(int_bits::<U>(), 40);
+        assert_eq!(<I as Fixed>::TRY_ONE, Some(I::from_num(1)));
+        assert_eq!(<FixedI24<23> as Fixed>::TRY_ONE, None);
+        assert_eq!(
+            <FixedU24<23> as Fixed>::TRY_ONE,
+            Some(FixedU24::from_num(1))
+        );
+        assert_eq!(
+            <FixedI24<23> as FixedSigned>::TRY_NEG_ONE,
+            Some(FixedI24::from_num(-1))
+        );
+
+        // bit operations work on 24 bits, not 32 bits
+        let x = I::from_bits(-0x80_0000);
+        assert_eq!(Fixed::count_ones(x), 1);
+        assert_eq!(Fixed::leading_ones(I::from_bits(-1)), 24);
+        assert_eq!(Fixed::leading_zeros(I::ZERO), 24);
+        assert_eq!(Fixed::rotate_left(x, 1), I::from_bits(1));
+        assert_eq!(Fixed::reverse_bits(I::from_bits(1)), x);
+        assert_eq!(!I::ZERO, I::from_bits(-1));
+        assert_eq!(!FixedU24::<0>::ZERO, FixedU24::MAX);
+        assert_eq!(I::from_bits(0x40_0000) << 1u32, x);
+        assert_eq!(x >> 4u32, I::from_bits(-0x8_0000));
+
+        // arithmetic overflows at 24 bits
+        let big = I::from_num(0x4000);
+        assert_eq!(Fixed::checked_mul_int(big, 2), None);
+        assert_eq!(Fixed::wrapping_mul_int(big, 2), I::MIN);
+        assert_eq!(Fixed::saturating_mul_int(big, 2), I::MAX);
+        assert_eq!(FixedSigned::overflowing_abs(I::MIN), (I::MIN, true));
+        assert_eq!(abs(I::MIN), FixedU24::<8>::from_num(0x8000));
+        assert_eq!(FixedSigned::signum(I::from_num(-3)), I::from_num(-1));
+        assert_eq!(
+            Fixed::checked_rem(I::from_num(7), I::from_num(2)),
+            Some(I::from_num(1))
+        );
+        assert_eq!(Fixed::int(I::from_num(-2.5)), I::from_num(-3));
+        assert_eq!(Fixed::round(I::from_num(2.5)), I::from_num(3));
+        assert_eq!(Fixed::checked_round(I::MAX), None);
+        assert_eq!(Fixed::mean(I::MAX, I::MAX), I::MAX);
+
+        assert!(is_pow2(U::from_num(0.5)));
+        assert_eq!(
+            FixedUnsigned::checked_next_power_of_two(U::from_num(3)),
+            Some(U::from_num(4))
+        );
+        assert_eq!(FixedUnsigned::checked_next_power_of_two(U::MAX), None);
+        assert_eq!(Fixed::wrapping_add(U::MAX, U::DELTA), U::ZERO);
+
+        // the trait byte methods cover the whole underlying integer
+        assert_eq!(<I as Fixed>::to_le_bytes(x), (-0x80_0000i32).to_le_bytes());
+        assert_eq!(
+            <I as Fixed>::from_be_bytes((-1i32).to_be_bytes()),
+            I::from_bits(-1)
+        );
+        assert_eq!(I::to_le_bytes(x), [0, 0, 0x80]);
+
+        let v = [I::from_num(1.5), I::from_num(2)];
+        assert_eq!(v.iter().sum::<I>(), I::from_num(3.5));
+        assert_eq!(v.iter().product::<I>(), I::from_num(3));
+        assert!(I::from_num(2) > 1.5f32);
+    }
+
+    #[test]
+    fn format() {
+        let x = FixedI24::<8>::from_num(-2.75);
+        assert_eq!(x.to_string(), "-2.75");
+        assert_eq!(format!("{x:?}"), "-2.75");
+        assert_eq!(format!("{x:b}"), "-10.11");
+        assert_eq!(format!("{x:X}"), "-2.C");
+        assert_eq!(format!("{:e}", FixedU48::<16>::from_num(1500)), "1.5e3");
+        assert_eq!(format!("{:.1}", FixedU24::<8>::from_num(0.25)), "0.2");
+    }
+}
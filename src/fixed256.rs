@@ -0,0 +1,5824 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    from_str::{self, ParseFixedError},
+    int256::{self, I256, U256},
+    rounding::Discarded,
+    traits::{Fixed, FromFixed, ToFixed},
+    types::extra::{If, True},
+    FixedI128, FixedU128, RoundingMode,
+};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
+    hash::{Hash, Hasher},
+    num::{NonZeroI128, NonZeroU128},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
+    str::{self, FromStr},
+};
+
+// Shifts raw right by shift, where neg is the sign of raw, and returns the
+// result rounded toward −∞ together with the discarded part.
+const fn shr_floor(raw: U256, neg: bool, shift: u64) -> (U256, Discarded) {
+    let fill = if neg { u128::MAX } else { 0 };
+    if shift > 256 {
+        // for negative numbers, there are extra discarded sign bits
+        let discarded = if raw.lo == 0 && raw.hi == 0 {
+            Discarded::Zero
+        } else if neg {
+            Discarded::GreaterThanHalf
+        } else {
+            Discarded::LessThanHalf
+        };
+        return (U256 { lo: fill, hi: fill }, discarded);
+    }
+    // rem holds the discarded bits, aligned so that its most significant bit
+    // is worth half
+    let (floor, rem) = if shift == 256 {
+        (U256 { lo: fill, hi: fill }, raw)
+    } else {
+        let shift = shift as u32;
+        (
+            int256::shr_u256(raw, shift, neg),
+            int256::shl_u256(raw, 256 - shift),
+        )
+    };
+    let half_hi = 1 << 127;
+    let discarded = if rem.lo == 0 && rem.hi == 0 {
+        Discarded::Zero
+    } else if rem.lo == 0 && rem.hi == half_hi {
+        Discarded::Half
+    } else if rem.hi & half_hi != 0 {
+        Discarded::GreaterThanHalf
+    } else {
+        Discarded::LessThanHalf
+    };
+    (floor, discarded)
+}
+
+// Splits 32 bytes into the first 16 and the last 16.
+const fn split_bytes(bytes: [u8; 32]) -> ([u8; 16], [u8; 16]) {
+    let mut first = [0; 16];
+    let mut last = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        first[i] = bytes[i];
+        last[i] = bytes[i + 16];
+        i += 1;
+    }
+    (first, last)
+}
+
+// Concatenates two arrays of 16 bytes.
+const fn concat_bytes(first: [u8; 16], last: [u8; 16]) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    let mut i = 0;
+    while i < 16 {
+        bytes[i] = first[i];
+        bytes[i + 16] = last[i];
+        i += 1;
+    }
+    bytes
+}
+
+// The 128-bit words of a 512-bit number, least significant first.
+type Words = [u128; 4];
+
+// Returns the 512-bit product of two unsigned 256-bit numbers.
+const fn wide_mul_u256(a: U256, b: U256) -> Words {
+    let ll = int256::wide_mul_u128(a.lo, b.lo);
+    let lh = int256::wide_mul_u128(a.lo, b.hi);
+    let hl = int256::wide_mul_u128(a.hi, b.lo);
+    let hh = int256::wide_mul_u128(a.hi, b.hi);
+    let (w1, carry1_a) = ll.hi.overflowing_add(lh.lo);
+    let (w1, carry1_b) = w1.overflowing_add(hl.lo);
+    let (w2, carry2_a) = lh.hi.overflowing_add(hl.hi);
+    let (w2, carry2_b) = w2.overflowing_add(hh.lo);
+    let (w2, carry2_c) = w2.overflowing_add(carry1_a as u128 + carry1_b as u128);
+    // the product is less than 2^512, so this cannot overflow
+    let w3 = hh.hi + carry2_a as u128 + carry2_b as u128 + carry2_c as u128;
+    [ll.lo, w1, w2, w3]
+}
+
+// Returns word index of words, where the words below the number are zero and
+// the words above it are fill.
+const fn word(words: Words, fill: u128, index: i64) -> u128 {
+    if index < 0 {
+        0
+    } else if index >= 4 {
+        fill
+    } else {
+        words[index as usize]
+    }
+}
+
+// Returns the 256 bits of words starting at bit offset, which can lie outside
+// the number.
+const fn window(words: Words, fill: u128, offset: i64) -> U256 {
+    let index = offset.div_euclid(128);
+    let sh = offset.rem_euclid(128) as u32;
+    let w0 = word(words, fill, index);
+    let w1 = word(words, fill, index + 1);
+    if sh == 0 {
+        return U256 { lo: w0, hi: w1 };
+    }
+    let w2 = word(words, fill, index + 2);
+    U256 {
+        lo: (w0 >> sh) | (w1 << (128 - sh)),
+        hi: (w1 >> sh) | (w2 << (128 - sh)),
+    }
+}
+
+// Returns true if all the bits of words from bit offset upwards are equal to
+// fill.
+const fn is_fill_from(words: Words, fill: u128, offset: i64) -> bool {
+    if offset < 0 {
+        // the bits below the number are zero
+        return fill == 0 && words[0] == 0 && words[1] == 0 && words[2] == 0 && words[3] == 0;
+    }
+    let lo = window(words, fill, offset);
+    let hi = window(words, fill, offset + 256);
+    lo.lo == fill && lo.hi == fill && hi.lo == fill && hi.hi == fill
+}
+
+// Divides n << frac_nbits by d, where d is not zero and frac_nbits ≤ 256, and
+// returns the quotient rounded toward zero and wrapped to 256 bits, together
+// with whether the quotient overflows 256 bits.
+const fn div_shl_u256(n: U256, d: U256, frac_nbits: u32) -> (U256, bool) {
+    let n_words = [n.lo, n.hi, 0, 0];
+    let mut numer = [0; 4];
+    let mut i = 0;
+    while i < 4 {
+        numer[i] = window(n_words, 0, 128 * i as i64 - frac_nbits as i64).lo;
+        i += 1;
+    }
+    let mut quot = [0; 4];
+    if d.hi == 0 {
+        let Some(d) = NonZeroU128::new(d.lo) else {
+            unreachable!();
+        };
+        // long division by 128-bit words, where each partial quotient fits in
+        // 128 bits as the remainder is smaller than d
+        let mut rem = 0;
+        let mut i = 4;
+        while i > 0 {
+            i -= 1;
+            let (q, r) = int256::div_rem_u256_u128(
+                U256 {
+                    lo: numer[i],
+                    hi: rem,
+                },
+                d,
+            );
+            quot[i] = q.lo;
+            rem = r;
+        }
+        let quot_lo = U256 {
+            lo: quot[0],
+            hi: quot[1],
+        };
+        return (quot_lo, quot[2] != 0 || quot[3] != 0);
+    }
+    // bitwise long division, where the remainder can have 257 bits
+    let mut rem = U256 { lo: 0, hi: 0 };
+    let mut bit = 512;
+    while bit > 0 {
+        bit -= 1;
+        let (index, sh) = (bit / 128, bit % 128);
+        let carry = rem.hi >> 127 != 0;
+        rem = U256 {
+            lo: (rem.lo << 1) | ((numer[index] >> sh) & 1),
+            hi: (rem.hi << 1) | (rem.lo >> 127),
+        };
+        if carry || rem.hi > d.hi || (rem.hi == d.hi && rem.lo >= d.lo) {
+            rem = int256::wrapping_sub_u256(rem, d);
+            quot[index] |= 1 << sh;
+        }
+    }
+    let quot_lo = U256 {
+        lo: quot[0],
+        hi: quot[1],
+    };
+    (quot_lo, quot[2] != 0 || quot[3] != 0)
+}
+
+// The 128-bit fixed-point number that holds the most significant bits of a
+// 256-bit number when converting it to other numbers.
+#[derive(Clone, Copy)]
+enum NumWindow {
+    // the fractional bits are kept
+    Frac,
+    // the fractional bits are kept, in an unsigned number
+    FracUnsigned,
+    // only the integer bits are kept
+    Int,
+    // only the integer bits are kept, in an unsigned number
+    IntUnsigned,
+    // the lowest 64 fractional bits are discarded
+    Frac64,
+    // the lowest 128 fractional bits are discarded
+    Frac128,
+}
+
+// Converts a NumWindow and its bits to a number using $conv, a generic
+// function that takes a fixed-point number.
+macro_rules! from_num_window {
+    ($window:expr, $Self:ident, $Narrow:ident, $conv:expr) => {{
+        let (window, bits) = $window;
+        match window {
+            NumWindow::Frac => ($conv)($Narrow::<FRAC>::from_bits(bits as _)),
+            NumWindow::FracUnsigned => ($conv)(FixedU128::<FRAC>::from_bits(bits)),
+            NumWindow::Int => ($conv)($Narrow::<0>::from_bits(bits as _)),
+            NumWindow::IntUnsigned => ($conv)(FixedU128::<0>::from_bits(bits)),
+            NumWindow::Frac64 => ($conv)($Self::<FRAC>::frac64_window(bits)),
+            NumWindow::Frac128 => ($conv)($Self::<FRAC>::frac128_window(bits)),
+        }
+    }};
+}
+
+// Writes into a byte buffer, failing if the buffer is full.
+struct BufWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for BufWriter<'_> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// Writes the exact decimal value of abs / 2^frac_nbits, where frac_nbits ≤ 256.
+//
+// The integer part has at most 78 digits. Every fractional bit adds one
+// decimal digit, and the digits are produced 19 at a time, so there are at
+// most 14 × 19 = 266 fractional digits before trimming.
+fn fmt_dec(neg: bool, abs: U256, frac_nbits: u32, f: &mut Formatter) -> FmtResult {
+    const E38: u128 = 10u128.pow(38);
+    const E38_NZ: NonZeroU128 = match NonZeroU128::new(E38) {
+        Some(e38) => e38,
+        None => unreachable!(),
+    };
+    const E19: u128 = 10u128.pow(19);
+    let (mut int, mut frac) = if frac_nbits == 0 {
+        (abs, U256 { lo: 0, hi: 0 })
+    } else if frac_nbits == 256 {
+        (U256 { lo: 0, hi: 0 }, abs)
+    } else {
+        (
+            int256::shr_u256(abs, frac_nbits, false),
+            int256::shl_u256(abs, 256 - frac_nbits),
+        )
+    };
+
+    let mut bytes = [0u8; 78 + 1 + 266];
+    let mut w = BufWriter {
+        buf: &mut bytes,
+        len: 0,
+    };
+
+    // split the integer part into chunks of 38 digits, least significant first
+    let mut chunks = [0u128; 3];
+    let mut num_chunks = 0;
+    loop {
+        let (q, r) = int256::div_rem_u256_u128(int, E38_NZ);
+        chunks[num_chunks] = r;
+        num_chunks += 1;
+        int = q;
+        if int.lo == 0 && int.hi == 0 {
+            break;
+        }
+    }
+    write!(w, "{}", chunks[num_chunks - 1])?;
+    for &chunk in chunks[..num_chunks - 1].iter().rev() {
+        write!(w, "{chunk:038}")?;
+    }
+
+    if frac.lo != 0 || frac.hi != 0 {
+        w.write_char('.')?;
+        while frac.lo != 0 || frac.hi != 0 {
+            let lo_prod = int256::wide_mul_u128(frac.lo, E19);
+            let hi_prod = int256::wide_mul_u128(frac.hi, E19);
+            let (hi, carry) = hi_prod.lo.overflowing_add(lo_prod.hi);
+            let digits = hi_prod.hi + u128::from(carry);
+            frac = U256 { lo: lo_prod.lo, hi };
+            write!(w, "{digits:019}")?;
+        }
+        while w.buf[w.len - 1] == b'0' {
+            w.len -= 1;
+        }
+    }
+
+    let len = w.len;
+    let s = str::from_utf8(&bytes[..len]).map_err(|_| core::fmt::Error)?;
+    f.pad_integral(!neg, "", s)
+}
+
+macro_rules! fixed256 {
+    (
+        description = $description:literal,
+        {Self, Bits, Hi} = {$Self:ident, $Bits:ident, $Hi:ident},
+        Signedness = $Signedness:ident,
+        {Narrow, NarrowInner} = {$Narrow:ident, $NarrowInner:ident},
+    ) => {
+        comment! {
+            $description, " fixed-point number with `FRAC` fractional bits.
+
+The number has 256 bits, of which <i>f</i>&nbsp;=&nbsp;`FRAC` are fractional
+bits and 256&nbsp;&minus;&nbsp;<i>f</i> are integer bits. The value <i>x</i> can
+lie in the range ",
+            if_signed_unsigned!(
+                $Signedness,
+                "&minus;2<sup>255</sup>/2<sup><i>f</i></sup>",
+                "0",
+            ),
+            "&nbsp;≤&nbsp;<i>x</i>&nbsp;<&nbsp;2<sup>",
+            if_signed_unsigned!($Signedness, "255", "256"),
+            "</sup>/2<sup><i>f</i></sup>.
+
+This type is mainly meant to hold the exact results of the [`wide_mul`] and
+[`wide_div`] methods of [`", stringify!($Narrow), "`]. It supports the
+exact operations of the other fixed-point numbers: addition, subtraction,
+",
+            if_signed_else_empty_str! { $Signedness; "negation, absolute value, " },
+            "multiplication, division, shifts, rounding to an integer and their
+checked, saturating, wrapping, unwrapped and overflowing variants, bitwise
+operations and bit counting, conversion to and from bytes, comparisons,
+formatting, parsing, conversion from [`", stringify!($Narrow), "`] using
+[`From`], conversion back to [`", stringify!($Narrow), "`] with rounding using
+[`narrow_round`] and its variants, and conversion from and to other numbers
+using [`from_num`] and [`to_num`].
+
+It does not implement the [`Fixed`] trait, because the trait requires the bits
+to be stored in a primitive integer that can be reinterpreted using
+[`bytemuck`], and 256-bit primitive integers are not available. For the same
+reason, it has no `from_bits` and `to_bits` methods; [`from_hi_lo`] and
+[`to_hi_lo`] are used instead. Mathematical functions such as square roots,
+exponentials and trigonometric functions are not provided, since they are
+computed internally with 128-bit significands, which would not make use of the extra
+precision; use [`narrow_round`] to convert to [`", stringify!($Narrow), "`]
+first.
+
+[`Display`] prints all the digits of the exact value and does not support
+precision.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Narrow), ", RoundingMode};
+let a = ", stringify!($Narrow), "::<64>::from_num(1.5);
+let b = ", stringify!($Narrow), "::<64>::from_num(2.25);
+let prod: ", stringify!($Self), "<128> = a.wide_mul(b);
+assert_eq!(prod.to_string(), \"3.375\");
+let sum = prod + ", stringify!($Self), "::from(", stringify!($Narrow), "::<128>::DELTA);
+let expected = ", stringify!($Narrow), "::<64>::from_num(3.375) + ", stringify!($Narrow), "::DELTA;
+assert_eq!(sum.narrow_round::<64>(RoundingMode::Ceil), expected);
+```
+
+[`Display`]: core::fmt::Display
+[`Fixed`]: crate::traits::Fixed
+[`bytemuck`]: https://docs.rs/bytemuck/^1/bytemuck/index.html
+[`from_hi_lo`]: Self::from_hi_lo
+[`from_num`]: Self::from_num
+[`narrow_round`]: Self::narrow_round
+[`to_hi_lo`]: Self::to_hi_lo
+[`to_num`]: Self::to_num
+[`wide_div`]: ", stringify!($Narrow), "::wide_div
+[`wide_mul`]: ", stringify!($Narrow), "::wide_mul
+";
+            #[repr(transparent)]
+            pub struct $Self<const FRAC: i32> {
+                bits: $Bits,
+            }
+        }
+
+        impl<const FRAC: i32> Clone for $Self<FRAC> {
+            #[inline]
+            fn clone(&self) -> $Self<FRAC> {
+                *self
+            }
+        }
+
+        impl<const FRAC: i32> Copy for $Self<FRAC> {}
+
+        impl<const FRAC: i32> Default for $Self<FRAC> {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<const FRAC: i32> Hash for $Self<FRAC> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.bits.hi.hash(state);
+                self.bits.lo.hash(state);
+            }
+        }
+
+        impl<const FRAC: i32> PartialEq for $Self<FRAC> {
+            #[inline]
+            fn eq(&self, rhs: &$Self<FRAC>) -> bool {
+                self.bits.hi == rhs.bits.hi && self.bits.lo == rhs.bits.lo
+            }
+        }
+
+        impl<const FRAC: i32> Eq for $Self<FRAC> {}
+
+        impl<const FRAC: i32> PartialOrd for $Self<FRAC> {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Self<FRAC>) -> Option<Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl<const FRAC: i32> Ord for $Self<FRAC> {
+            #[inline]
+            fn cmp(&self, rhs: &$Self<FRAC>) -> Ordering {
+                (self.bits.hi, self.bits.lo).cmp(&(rhs.bits.hi, rhs.bits.lo))
+            }
+        }
+
+        impl<const FRAC: i32> $Self<FRAC> {
+            comment! {
+                "Zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO, Fix::from_hi_lo(0, 0));
+```
+";
+                pub const ZERO: $Self<FRAC> = $Self::from_hi_lo(0, 0);
+            }
+
+            comment! {
+                "The difference between any two successive representable numbers, <i>Δ</i>.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA, Fix::from_hi_lo(0, 1));
+```
+";
+                pub const DELTA: $Self<FRAC> = $Self::from_hi_lo(0, 1);
+            }
+
+            comment! {
+                "The smallest value that can be represented.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MIN, Fix::from_hi_lo(", stringify!($Hi), "::MIN, 0));
+```
+";
+                pub const MIN: $Self<FRAC> = $Self::from_hi_lo($Hi::MIN, 0);
+            }
+
+            comment! {
+                "The largest value that can be represented.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX, Fix::from_hi_lo(", stringify!($Hi), "::MAX, u128::MAX));
+```
+";
+                pub const MAX: $Self<FRAC> = $Self::from_hi_lo($Hi::MAX, u128::MAX);
+            }
+
+            comment! {
+                "The number of integer bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<6>;
+assert_eq!(Fix::INT_NBITS, 256 - 6);
+```
+";
+                pub const INT_NBITS: u32 = (256 - FRAC) as u32;
+            }
+
+            comment! {
+                "The number of fractional bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<6>;
+assert_eq!(Fix::FRAC_NBITS, 6);
+```
+";
+                pub const FRAC_NBITS: u32 = FRAC as u32;
+            }
+
+            comment! {
+                "Creates a fixed-point number from its underlying bits, given
+as the high 128 bits `hi` and the low 128 bits `lo`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<128>;
+// 2.5 is 2 × 2^128 + 2^127 in bits
+assert_eq!(Fix::from_hi_lo(2, 1 << 127).to_string(), \"2.5\");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_hi_lo(hi: $Hi, lo: u128) -> $Self<FRAC> {
+                    $Self {
+                        bits: $Bits { lo, hi },
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the underlying bits of the fixed-point number as the
+high 128 bits and the low 128 bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Narrow), "};
+let x = ", stringify!($Self), "::from(", stringify!($Narrow), "::<4>::from_num(2.5));
+assert_eq!(x.to_hi_lo(), (0, 0b10_1000));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_hi_lo(self) -> ($Hi, u128) {
+                    (self.bits.hi, self.bits.lo)
+                }
+            }
+
+            #[inline]
+            const fn to_raw(self) -> U256 {
+                if_signed_unsigned!(
+                    $Signedness,
+                    int256::i256_wrapping_as_u256(self.bits),
+                    self.bits,
+                )
+            }
+
+            comment! {
+                "Returns [`true`] if the number is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert!(Fix::ZERO.is_zero());
+assert!(!Fix::DELTA.is_zero());
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn is_zero(self) -> bool {
+                    self.bits.hi == 0 && self.bits.lo == 0
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+
+                /// Returns [`true`] if the number is &gt;&nbsp;0.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::FixedI256;
+                /// type Fix = FixedI256<4>;
+                /// assert!(Fix::DELTA.is_positive());
+                /// assert!(!Fix::ZERO.is_positive());
+                /// assert!(!(-Fix::DELTA).is_positive());
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn is_positive(self) -> bool {
+                    self.bits.hi > 0 || (self.bits.hi == 0 && self.bits.lo != 0)
+                }
+
+                /// Returns [`true`] if the number is &lt;&nbsp;0.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::FixedI256;
+                /// type Fix = FixedI256<4>;
+                /// assert!(!Fix::DELTA.is_negative());
+                /// assert!(!Fix::ZERO.is_negative());
+                /// assert!((-Fix::DELTA).is_negative());
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn is_negative(self) -> bool {
+                    self.bits.hi < 0
+                }
+            }
+
+            comment! {
+                "Checked addition. Returns the sum, or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA.checked_add(Fix::DELTA), Some(Fix::from_hi_lo(0, 2)));
+assert_eq!(Fix::MAX.checked_add(Fix::DELTA), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_add(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    match self.overflowing_add(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating addition. Returns the sum, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.saturating_add(Fix::DELTA), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_add(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.overflowing_add(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if if_signed_unsigned!($Signedness, rhs.is_negative(), false) {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping addition. Returns the sum, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.wrapping_add(Fix::DELTA), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_add(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    self.overflowing_add(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped addition. Returns the sum, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA.unwrapped_add(Fix::DELTA), Fix::from_hi_lo(0, 2));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_add(Fix::DELTA);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_add(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.overflowing_add(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing addition.
+
+Returns a [tuple] of the sum and a [`bool`] indicating whether an overflow has
+occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.overflowing_add(Fix::DELTA), (Fix::DELTA, false));
+assert_eq!(Fix::MAX.overflowing_add(Fix::DELTA), (Fix::MIN, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_add(self, rhs: $Self<FRAC>) -> ($Self<FRAC>, bool) {
+                    let (bits, overflow) = if_signed_unsigned!(
+                        $Signedness,
+                        int256::overflowing_add_i256(self.bits, rhs.bits),
+                        int256::overflowing_add_u256(self.bits, rhs.bits),
+                    );
+                    ($Self { bits }, overflow)
+                }
+            }
+
+            comment! {
+                "Checked subtraction. Returns the difference, or [`None`] on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA.checked_sub(Fix::DELTA), Some(Fix::ZERO));
+assert_eq!(Fix::MIN.checked_sub(Fix::DELTA), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sub(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    match self.overflowing_sub(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating subtraction. Returns the difference, saturating on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MIN.saturating_sub(Fix::DELTA), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sub(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.overflowing_sub(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if if_signed_unsigned!($Signedness, rhs.is_negative(), false) {
+                                $Self::MAX
+                            } else {
+                                $Self::MIN
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping subtraction. Returns the difference, wrapping on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MIN.wrapping_sub(Fix::DELTA), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sub(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    self.overflowing_sub(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped subtraction. Returns the difference, panicking on
+overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA.unwrapped_sub(Fix::DELTA), Fix::ZERO);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MIN.unwrapped_sub(Fix::DELTA);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sub(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.overflowing_sub(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing subtraction.
+
+Returns a [tuple] of the difference and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA.overflowing_sub(Fix::DELTA), (Fix::ZERO, false));
+assert_eq!(Fix::MIN.overflowing_sub(Fix::DELTA), (Fix::MAX, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sub(self, rhs: $Self<FRAC>) -> ($Self<FRAC>, bool) {
+                    let (bits, overflow) = if_signed_unsigned!(
+                        $Signedness,
+                        int256::overflowing_sub_i256(self.bits, rhs.bits),
+                        int256::overflowing_sub_u256(self.bits, rhs.bits),
+                    );
+                    ($Self { bits }, overflow)
+                }
+            }
+
+            comment! {
+                "Checked negation. Returns the negated value, or [`None`] on
+overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_neg(), Some(Fix::ZERO));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MIN.checked_neg(), None);",
+                    "assert_eq!(Fix::DELTA.checked_neg(), None);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_neg(self) -> Option<$Self<FRAC>> {
+                    match self.overflowing_neg() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating negation. Returns the negated value, saturating on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MIN.saturating_neg(), Fix::MAX);",
+                    "assert_eq!(Fix::DELTA.saturating_neg(), Fix::ZERO);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_neg(self) -> $Self<FRAC> {
+                    match self.overflowing_neg() {
+                        (ans, false) => ans,
+                        (_, true) => if_signed_unsigned!($Signedness, $Self::MAX, $Self::ZERO),
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping negation. Returns the negated value, wrapping on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MIN.wrapping_neg(), Fix::MIN);",
+                    "assert_eq!(Fix::DELTA.wrapping_neg(), Fix::MAX);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_neg(self) -> $Self<FRAC> {
+                    self.overflowing_neg().0
+                }
+            }
+
+            comment! {
+                "Unwrapped negation. Returns the negated value, panicking on
+overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.unwrapped_neg(), Fix::ZERO);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "let _overflow = Fix::MIN.unwrapped_neg();",
+                    "let _overflow = Fix::DELTA.unwrapped_neg();",
+                ),
+                "
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_neg(self) -> $Self<FRAC> {
+                    match self.overflowing_neg() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing negation.
+
+Returns a [tuple] of the negated value and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::DELTA.overflowing_neg(), (-Fix::DELTA, false));
+assert_eq!(Fix::MIN.overflowing_neg(), (Fix::MIN, true));",
+                    "assert_eq!(Fix::ZERO.overflowing_neg(), (Fix::ZERO, false));
+assert_eq!(Fix::DELTA.overflowing_neg(), (Fix::MAX, true));",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_neg(self) -> ($Self<FRAC>, bool) {
+                    if_signed_unsigned!(
+                        $Signedness,
+                        $Self::ZERO.overflowing_sub(self),
+                        (
+                            $Self {
+                                bits: int256::wrapping_neg_u256(self.bits),
+                            },
+                            !self.is_zero(),
+                        ),
+                    )
+                }
+            }
+
+            // Returns true if the raw bits fit in the narrow type.
+            #[inline]
+            const fn raw_fits_narrow(raw: U256) -> bool {
+                if_signed_unsigned!(
+                    $Signedness,
+                    raw.hi == ((raw.lo as i128) >> 127) as u128,
+                    raw.hi == 0,
+                )
+            }
+
+            comment! {
+                "Converts to [`", stringify!($Narrow), "`] with `DST_FRAC`
+fractional bits, rounding according to the given [`RoundingMode`].
+
+This is typically used to bring the exact result of [`wide_mul`] back to the
+original type, so that the product is rounded only once.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_narrow_round`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Narrow), ", RoundingMode};
+type Fix = ", stringify!($Narrow), "<2>;
+// 1.25 × 1.75 = 2.1875 lies between 2 and 2.25
+let prod = Fix::from_num(1.25).wide_mul(Fix::from_num(1.75));
+assert_eq!(prod.narrow_round::<2>(RoundingMode::Floor), 2);
+assert_eq!(prod.narrow_round::<2>(RoundingMode::HalfEven), 2.25);
+```
+
+[`wide_mul`]: ", stringify!($Narrow), "::wide_mul
+[`wrapping_narrow_round`]: Self::wrapping_narrow_round
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn narrow_round<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> $Narrow<DST_FRAC> {
+                    let (ans, overflow) = self.overflowing_narrow_round(mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Converts to [`", stringify!($Narrow), "`] with `DST_FRAC`
+fractional bits, rounding according to the given [`RoundingMode`]. Returns
+[`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Narrow), ", RoundingMode};
+type Fix = ", stringify!($Narrow), "<2>;
+let prod = Fix::from_num(1.25).wide_mul(Fix::from_num(1.75));
+assert_eq!(prod.checked_narrow_round::<2>(RoundingMode::Ceil), Some(Fix::from_num(2.25)));
+let large = ", stringify!($Self), "::<2>::MAX;
+assert_eq!(large.checked_narrow_round::<2>(RoundingMode::Ceil), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_narrow_round<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> Option<$Narrow<DST_FRAC>> {
+                    match self.overflowing_narrow_round(mode) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Converts to [`", stringify!($Narrow), "`] with `DST_FRAC`
+fractional bits, rounding according to the given [`RoundingMode`] and
+saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Narrow), ", RoundingMode};
+type Fix = ", stringify!($Narrow), "<2>;
+let large = ", stringify!($Self), "::<2>::MAX;
+assert_eq!(large.saturating_narrow_round::<2>(RoundingMode::Ceil), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_narrow_round<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> $Narrow<DST_FRAC> {
+                    match self.overflowing_narrow_round(mode) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if if_signed_unsigned!($Signedness, self.is_negative(), false) {
+                                $Narrow::MIN
+                            } else {
+                                $Narrow::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Converts to [`", stringify!($Narrow), "`] with `DST_FRAC`
+fractional bits, rounding according to the given [`RoundingMode`] and
+wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Narrow), ", RoundingMode};
+type Fix = ", stringify!($Narrow), "<2>;
+// the low 128 bits are kept
+let large = ", stringify!($Self), "::<2>::from_hi_lo(7, 0b1001);
+assert_eq!(large.wrapping_narrow_round::<2>(RoundingMode::Floor), Fix::from_bits(0b1001));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_narrow_round<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> $Narrow<DST_FRAC> {
+                    self.overflowing_narrow_round(mode).0
+                }
+            }
+
+            comment! {
+                "Converts to [`", stringify!($Narrow), "`] with `DST_FRAC`
+fractional bits, rounding according to the given [`RoundingMode`] and
+panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Narrow), ", RoundingMode};
+type Fix = ", stringify!($Narrow), "<2>;
+let prod = Fix::from_num(1.25).wide_mul(Fix::from_num(1.75));
+assert_eq!(prod.unwrapped_narrow_round::<2>(RoundingMode::Floor), 2);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+let large = ", stringify!($Self), "::<2>::MAX;
+let _overflow = large.unwrapped_narrow_round::<2>(RoundingMode::Floor);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_narrow_round<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> $Narrow<DST_FRAC> {
+                    match self.overflowing_narrow_round(mode) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Converts to [`", stringify!($Narrow), "`] with `DST_FRAC`
+fractional bits, rounding according to the given [`RoundingMode`].
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Narrow), ", RoundingMode};
+type Fix = ", stringify!($Narrow), "<2>;
+let prod = Fix::from_num(1.25).wide_mul(Fix::from_num(1.75));
+assert_eq!(
+    prod.overflowing_narrow_round::<2>(RoundingMode::Ceil),
+    (Fix::from_num(2.25), false)
+);
+let large = ", stringify!($Self), "::<2>::from_hi_lo(7, 0b1001);
+assert_eq!(
+    large.overflowing_narrow_round::<2>(RoundingMode::Floor),
+    (Fix::from_bits(0b1001), true)
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_narrow_round<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> ($Narrow<DST_FRAC>, bool) {
+                    let raw = self.to_raw();
+                    let neg = if_signed_unsigned!($Signedness, self.is_negative(), false);
+                    let shift = FRAC as i64 - DST_FRAC as i64;
+                    if shift <= 0 {
+                        let shift = -shift;
+                        let fits = $Self::<FRAC>::raw_fits_narrow(raw);
+                        let narrow = raw.lo as $NarrowInner;
+                        if shift >= 128 {
+                            return ($Narrow::ZERO, !self.is_zero());
+                        }
+                        let shifted = narrow << shift;
+                        let overflow = !fits || (shifted >> shift) != narrow;
+                        return ($Narrow::from_bits(shifted), overflow);
+                    }
+                    let (floor, discarded) = shr_floor(raw, neg, shift as u64);
+                    let ans = if mode.increment_floor(neg, floor.lo & 1 != 0, discarded) {
+                        // floor is smaller than MAX as at least one bit was discarded
+                        int256::wrapping_add_u256_u128(floor, 1)
+                    } else {
+                        floor
+                    };
+                    let fits = $Self::<FRAC>::raw_fits_narrow(ans);
+                    ($Narrow::from_bits(ans.lo as $NarrowInner), !fits)
+                }
+            }
+        }
+
+        impl<const FRAC: i32> $Self<FRAC> {
+            comment! {
+                "Creates a fixed-point number from its representation
+as a byte array in big endian.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let mut bytes = [0; 32];
+bytes[15] = 7;
+bytes[31] = 0x12;
+assert_eq!(Fix::from_be_bytes(bytes), Fix::from_hi_lo(7, 0x12));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_be_bytes(bytes: [u8; 32]) -> $Self<FRAC> {
+                    let (hi, lo) = split_bytes(bytes);
+                    $Self::from_raw(U256 {
+                        lo: u128::from_be_bytes(lo),
+                        hi: u128::from_be_bytes(hi),
+                    })
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its representation
+as a byte array in little endian.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let mut bytes = [0; 32];
+bytes[0] = 0x12;
+bytes[16] = 7;
+assert_eq!(Fix::from_le_bytes(bytes), Fix::from_hi_lo(7, 0x12));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_le_bytes(bytes: [u8; 32]) -> $Self<FRAC> {
+                    let (lo, hi) = split_bytes(bytes);
+                    $Self::from_raw(U256 {
+                        lo: u128::from_le_bytes(lo),
+                        hi: u128::from_le_bytes(hi),
+                    })
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its representation
+as a byte array in native endian.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let val = Fix::from_hi_lo(7, 0x12);
+assert_eq!(Fix::from_ne_bytes(val.to_ne_bytes()), val);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_ne_bytes(bytes: [u8; 32]) -> $Self<FRAC> {
+                    if cfg!(target_endian = "big") {
+                        $Self::from_be_bytes(bytes)
+                    } else {
+                        $Self::from_le_bytes(bytes)
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this fixed-point
+number as a byte array in big-endian byte order.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let bytes = Fix::from_hi_lo(7, 0x12).to_be_bytes();
+assert_eq!(bytes[15], 7);
+assert_eq!(bytes[31], 0x12);
+assert_eq!(bytes.iter().filter(|&&b| b != 0).count(), 2);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_be_bytes(self) -> [u8; 32] {
+                    let raw = self.to_raw();
+                    concat_bytes(raw.hi.to_be_bytes(), raw.lo.to_be_bytes())
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this fixed-point
+number as a byte array in little-endian byte order.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let bytes = Fix::from_hi_lo(7, 0x12).to_le_bytes();
+assert_eq!(bytes[0], 0x12);
+assert_eq!(bytes[16], 7);
+assert_eq!(bytes.iter().filter(|&&b| b != 0).count(), 2);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_le_bytes(self) -> [u8; 32] {
+                    let raw = self.to_raw();
+                    concat_bytes(raw.lo.to_le_bytes(), raw.hi.to_le_bytes())
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this fixed-point
+number as a byte array in native byte order.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let val = Fix::from_hi_lo(7, 0x12);
+assert_eq!(
+    val.to_ne_bytes(),
+    if cfg!(target_endian = \"big\") {
+        val.to_be_bytes()
+    } else {
+        val.to_le_bytes()
+    }
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_ne_bytes(self) -> [u8; 32] {
+                    if cfg!(target_endian = "big") {
+                        self.to_be_bytes()
+                    } else {
+                        self.to_le_bytes()
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the number of ones in the binary
+representation.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let f = Fix::from_hi_lo(0b101, 0b11_0010);
+assert_eq!(f.count_ones(), 5);
+```
+";
+                #[inline]
+                #[doc(alias("popcount", "popcnt"))]
+                #[must_use]
+                pub const fn count_ones(self) -> u32 {
+                    let raw = self.to_raw();
+                    raw.hi.count_ones() + raw.lo.count_ones()
+                }
+            }
+
+            comment! {
+                "Returns the number of zeros in the binary
+representation.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let f = Fix::from_hi_lo(0b101, 0b11_0010);
+assert_eq!(f.count_zeros(), 256 - 5);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn count_zeros(self) -> u32 {
+                    let raw = self.to_raw();
+                    raw.hi.count_zeros() + raw.lo.count_zeros()
+                }
+            }
+
+            comment! {
+                "Returns the number of leading ones in the binary
+representation.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_hi_lo(0, 0b11_0010).leading_ones(), 0);
+assert_eq!(Fix::MAX.leading_ones(), ",
+                if_signed_unsigned!($Signedness, "0", "256"),
+                ");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn leading_ones(self) -> u32 {
+                    let raw = self.to_raw();
+                    if raw.hi == u128::MAX {
+                        128 + raw.lo.leading_ones()
+                    } else {
+                        raw.hi.leading_ones()
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the number of leading zeros in the binary
+representation.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_hi_lo(0, 0b11_0010).leading_zeros(), 256 - 6);
+assert_eq!(Fix::from_hi_lo(1, 0).leading_zeros(), 127);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn leading_zeros(self) -> u32 {
+                    let raw = self.to_raw();
+                    if raw.hi == 0 {
+                        128 + raw.lo.leading_zeros()
+                    } else {
+                        raw.hi.leading_zeros()
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the number of trailing ones in the binary
+representation.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_hi_lo(0, 0b101_0111).trailing_ones(), 3);
+assert_eq!(Fix::from_hi_lo(1, u128::MAX).trailing_ones(), 129);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn trailing_ones(self) -> u32 {
+                    let raw = self.to_raw();
+                    if raw.lo == u128::MAX {
+                        128 + raw.hi.trailing_ones()
+                    } else {
+                        raw.lo.trailing_ones()
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the number of trailing zeros in the binary
+representation.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_hi_lo(0, 0b101_0000).trailing_zeros(), 4);
+assert_eq!(Fix::from_hi_lo(2, 0).trailing_zeros(), 129);
+assert_eq!(Fix::ZERO.trailing_zeros(), 256);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn trailing_zeros(self) -> u32 {
+                    let raw = self.to_raw();
+                    if raw.lo == 0 {
+                        128 + raw.hi.trailing_zeros()
+                    } else {
+                        raw.lo.trailing_zeros()
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+
+                /// Returns the absolute value.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, panics if the result
+                /// does not fit. When debug assertions are not enabled, the
+                /// wrapped value can be returned, but it is not considered a
+                /// breaking change if in the future it panics; if wrapping is
+                /// required use [`wrapping_abs`] instead.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::FixedI256;
+                /// type Fix = FixedI256<4>;
+                /// let five = Fix::from_num(5);
+                /// let minus_five = Fix::from_num(-5);
+                /// assert_eq!(five.abs(), five);
+                /// assert_eq!(minus_five.abs(), five);
+                /// ```
+                ///
+                /// [`wrapping_abs`]: Self::wrapping_abs
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn abs(self) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_abs();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+
+                /// Returns the absolute value using an unsigned type without
+                /// any wrapping or panicking.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::{FixedI256, FixedU256};
+                /// type Fix = FixedI256<4>;
+                /// type UFix = FixedU256<4>;
+                /// assert_eq!(Fix::from_num(-5).unsigned_abs(), UFix::from_num(5));
+                /// // min_as_unsigned has only highest bit set
+                /// let min_as_unsigned = UFix::from_hi_lo(1 << 127, 0);
+                /// assert_eq!(Fix::MIN.unsigned_abs(), min_as_unsigned);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn unsigned_abs(self) -> FixedU256<FRAC> {
+                    let raw = self.to_raw();
+                    let abs = if self.is_negative() {
+                        int256::wrapping_neg_u256(raw)
+                    } else {
+                        raw
+                    };
+                    FixedU256::from_hi_lo(abs.hi, abs.lo)
+                }
+
+                /// Checked absolute value. Returns the absolute value, or
+                /// [`None`] on overflow.
+                ///
+                /// Overflow can only occur when trying to find the absolute
+                /// value of the minimum value.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::FixedI256;
+                /// type Fix = FixedI256<4>;
+                /// assert_eq!(Fix::from_num(-5).checked_abs(), Some(Fix::from_num(5)));
+                /// assert_eq!(Fix::MIN.checked_abs(), None);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn checked_abs(self) -> Option<$Self<FRAC>> {
+                    match self.overflowing_abs() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+
+                /// Saturating absolute value. Returns the absolute value,
+                /// saturating on overflow.
+                ///
+                /// Overflow can only occur when trying to find the absolute
+                /// value of the minimum value.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::FixedI256;
+                /// type Fix = FixedI256<4>;
+                /// assert_eq!(Fix::from_num(-5).saturating_abs(), Fix::from_num(5));
+                /// assert_eq!(Fix::MIN.saturating_abs(), Fix::MAX);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn saturating_abs(self) -> $Self<FRAC> {
+                    match self.overflowing_abs() {
+                        (ans, false) => ans,
+                        (_, true) => $Self::MAX,
+                    }
+                }
+
+                /// Wrapping absolute value. Returns the absolute value,
+                /// wrapping on overflow.
+                ///
+                /// Overflow can only occur when trying to find the absolute
+                /// value of the minimum value.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::FixedI256;
+                /// type Fix = FixedI256<4>;
+                /// assert_eq!(Fix::from_num(-5).wrapping_abs(), Fix::from_num(5));
+                /// assert_eq!(Fix::MIN.wrapping_abs(), Fix::MIN);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_abs(self) -> $Self<FRAC> {
+                    self.overflowing_abs().0
+                }
+
+                /// Unwrapped absolute value. Returns the absolute value,
+                /// panicking on overflow.
+                ///
+                /// Overflow can only occur when trying to find the absolute
+                /// value of the minimum value.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the result does not fit.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::FixedI256;
+                /// type Fix = FixedI256<4>;
+                /// assert_eq!(Fix::from_num(-5).unwrapped_abs(), Fix::from_num(5));
+                /// ```
+                ///
+                /// The following panics because of overflow.
+                ///
+                /// ```rust,should_panic
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::FixedI256;
+                /// type Fix = FixedI256<4>;
+                /// let _overflow = Fix::MIN.unwrapped_abs();
+                /// ```
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_abs(self) -> $Self<FRAC> {
+                    match self.overflowing_abs() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+
+                /// Overflowing absolute value.
+                ///
+                /// Returns a [tuple] of the absolute value and a [`bool`]
+                /// indicating whether an overflow has occurred. On overflow,
+                /// the wrapped value is returned.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                /// use fixed::FixedI256;
+                /// type Fix = FixedI256<4>;
+                /// assert_eq!(Fix::from_num(-5).overflowing_abs(), (Fix::from_num(5), false));
+                /// assert_eq!(Fix::MIN.overflowing_abs(), (Fix::MIN, true));
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_abs(self) -> ($Self<FRAC>, bool) {
+                    if self.is_negative() {
+                        self.overflowing_neg()
+                    } else {
+                        (self, false)
+                    }
+                }
+            }
+        }
+
+        impl<const FRAC: i32> $Self<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= 256) }>: True,
+        {
+            // Returns the mask of the fractional bits.
+            #[inline]
+            const fn frac_mask() -> U256 {
+                if FRAC == 256 {
+                    U256 {
+                        lo: u128::MAX,
+                        hi: u128::MAX,
+                    }
+                } else {
+                    int256::wrapping_sub_u256(
+                        int256::shl_u256(U256 { lo: 1, hi: 0 }, FRAC as u32),
+                        U256 { lo: 1, hi: 0 },
+                    )
+                }
+            }
+
+            // Rounds to an integer according to mode, returning the wrapped
+            // result and whether it overflows.
+            const fn overflowing_round_mode(self, mode: RoundingMode) -> ($Self<FRAC>, bool) {
+                if FRAC == 0 {
+                    return (self, false);
+                }
+                let raw = self.to_raw();
+                let neg = if_signed_unsigned!($Signedness, self.is_negative(), false);
+                let (floor, discarded) = shr_floor(raw, neg, FRAC as u64);
+                // floor has at least one bit less than 256, so this cannot wrap
+                let int = if mode.increment_floor(neg, floor.lo & 1 != 0, discarded) {
+                    int256::wrapping_add_u256_u128(floor, 1)
+                } else {
+                    floor
+                };
+                if FRAC == 256 {
+                    // the only representable integer is zero
+                    return ($Self::ZERO, int.lo != 0 || int.hi != 0);
+                }
+                let bits = int256::shl_u256(int, FRAC as u32);
+                let fill = if_signed_unsigned!($Signedness, (bits.hi as i128) < 0, false);
+                let back = int256::shr_u256(bits, FRAC as u32, fill);
+                (
+                    $Self::from_raw(bits),
+                    back.lo != int.lo || back.hi != int.hi,
+                )
+            }
+
+            comment! {
+                "Returns the integer part.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Note that since the numbers are stored in two’s
+complement, negative numbers with non-zero fractional parts will be
+rounded towards &minus;∞, except in the case where there are no integer
+bits, that is `FixedI256<256>`, where the return value is always zero.",
+                    "Note that for unsigned numbers, this is equivalent to [`floor`].",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let two_and_quarter = Fix::from_num(2.25);
+assert_eq!(two_and_quarter.int(), Fix::from_num(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-two_and_quarter).int(), Fix::from_num(-3));
+",
+                },
+                "```
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "
+[`floor`]: Self::floor
+"
+                };
+                #[inline]
+                #[must_use]
+                pub const fn int(self) -> $Self<FRAC> {
+                    let raw = self.to_raw();
+                    let mask = $Self::<FRAC>::frac_mask();
+                    $Self::from_raw(U256 {
+                        lo: raw.lo & !mask.lo,
+                        hi: raw.hi & !mask.hi,
+                    })
+                }
+            }
+
+            comment! {
+                "Returns the fractional part.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Note that since the numbers are stored in two’s
+complement, the returned fraction will be non-negative for negative
+numbers, except in the case where there are no integer bits, that is
+`FixedI256<256>`, where the return value is always equal to `self`.
+
+",
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let two_and_quarter = Fix::from_num(2.25);
+assert_eq!(two_and_quarter.frac(), Fix::from_num(0.25));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-two_and_quarter).frac(), Fix::from_num(0.75));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn frac(self) -> $Self<FRAC> {
+                    let raw = self.to_raw();
+                    let mask = $Self::<FRAC>::frac_mask();
+                    $Self::from_raw(U256 {
+                        lo: raw.lo & mask.lo,
+                        hi: raw.hi & mask.hi,
+                    })
+                }
+            }
+
+            comment! {
+                "Rounds to the next integer towards 0.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Note that for negative numbers, this is different
+from truncating/discarding the fractional bits. This is because in
+two’s-complement representations, the value of all the bits except for
+the most significant bit is positive; discarding positive bits would
+round towards &minus;∞ unlike this method which rounds towards zero.",
+                    "Note that for unsigned numbers, this is equivalent to [`floor`].",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.1).round_to_zero(), Fix::from_num(2));
+assert_eq!(Fix::from_num(2.9).round_to_zero(), Fix::from_num(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.1).round_to_zero(), Fix::from_num(-2));
+assert_eq!(Fix::from_num(-2.9).round_to_zero(), Fix::from_num(-2));
+",
+                },
+                "```
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "
+[`floor`]: Self::floor
+"
+                };
+                #[inline]
+                #[must_use]
+                pub const fn round_to_zero(self) -> $Self<FRAC> {
+                    // rounding toward zero never increases the magnitude
+                    self.overflowing_round_mode(RoundingMode::ToZero).0
+                }
+            }
+
+            comment! {
+                "Rounds to the next integer towards +∞.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped result can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_ceil`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).ceil(), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).ceil(), Fix::from_num(-2));
+",
+                },
+                "```
+
+[`wrapping_ceil`]: Self::wrapping_ceil
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn ceil(self) -> $Self<FRAC> {
+                    let (ceil, overflow) = self.overflowing_ceil();
+                    debug_assert!(!overflow, "overflow");
+                    ceil
+                }
+            }
+
+            comment! {
+                "Rounds to the next integer towards &minus;∞.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped result can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_floor`] instead.
+
+Overflow can only occur when there are zero integer bits.
+
+",
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).floor(), Fix::from_num(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).floor(), Fix::from_num(-3));
+",
+                },
+                "```
+
+[`wrapping_floor`]: Self::wrapping_floor
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn floor(self) -> $Self<FRAC> {
+                    let (floor, overflow) = self.overflowing_floor();
+                    debug_assert!(!overflow, "overflow");
+                    floor
+                }
+            }
+
+            comment! {
+                "Rounds to the nearest integer, with ties rounded away
+from zero.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped result can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_round`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).round(), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).round(), Fix::from_num(-3));
+",
+                },
+                "```
+
+[`wrapping_round`]: Self::wrapping_round
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn round(self) -> $Self<FRAC> {
+                    let (round, overflow) = self.overflowing_round();
+                    debug_assert!(!overflow, "overflow");
+                    round
+                }
+            }
+
+            comment! {
+                "Rounds to the nearest integer, with ties rounded to even.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped result can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_round_ties_to_even`]
+instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).round_ties_to_even(), Fix::from_num(2));
+assert_eq!(Fix::from_num(3.5).round_ties_to_even(), Fix::from_num(4));
+```
+
+[`wrapping_round_ties_to_even`]: Self::wrapping_round_ties_to_even
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                #[doc(alias("round_ties_even"))]
+                pub const fn round_ties_to_even(self) -> $Self<FRAC> {
+                    let (round, overflow) = self.overflowing_round_ties_to_even();
+                    debug_assert!(!overflow, "overflow");
+                    round
+                }
+            }
+
+            comment! {
+                "Checked ceil. Rounds to the next integer towards +∞,
+returning [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).checked_ceil(), Some(Fix::from_num(3)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).checked_ceil(), Some(Fix::from_num(-2)));
+",
+                },
+                "assert!(Fix::MAX.checked_ceil().is_none());
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_ceil(self) -> Option<$Self<FRAC>> {
+                    match self.overflowing_ceil() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked floor. Rounds to the next integer towards &minus;∞.",
+                if_signed_unsigned!(
+                    $Signedness,
+                    " Returns [`None`] on overflow.
+
+Overflow can only occur when there are zero integer bits.",
+                    "
+
+Always returns [`Some`] for unsigned values.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).checked_floor(), Some(Fix::from_num(2)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).checked_floor(), Some(Fix::from_num(-3)));
+type AllFrac = ", stringify!($Self), "<256>;
+assert!(AllFrac::MIN.checked_floor().is_none());
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_floor(self) -> Option<$Self<FRAC>> {
+                    match self.overflowing_floor() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked round. Rounds to the nearest integer, with ties
+rounded away from zero, returning [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).checked_round(), Some(Fix::from_num(3)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).checked_round(), Some(Fix::from_num(-3)));
+",
+                },
+                "assert!(Fix::MAX.checked_round().is_none());
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_round(self) -> Option<$Self<FRAC>> {
+                    match self.overflowing_round() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked round. Rounds to the nearest integer, with ties
+rounded to even, returning [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).checked_round_ties_to_even(), Some(Fix::from_num(2)));
+assert_eq!(Fix::from_num(3.5).checked_round_ties_to_even(), Some(Fix::from_num(4)));
+assert!(Fix::MAX.checked_round_ties_to_even().is_none());
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_round_ties_to_even(self) -> Option<$Self<FRAC>> {
+                    match self.overflowing_round_ties_to_even() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating ceil. Rounds to the next integer towards +∞,
+saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).saturating_ceil(), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).saturating_ceil(), Fix::from_num(-2));
+",
+                },
+                "assert_eq!(Fix::MAX.saturating_ceil(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_ceil(self) -> $Self<FRAC> {
+                    match self.overflowing_ceil() {
+                        (ans, false) => ans,
+                        (_, true) => $Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating floor. Rounds to the next integer towards &minus;∞",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", saturating on overflow.
+
+Overflow can only occur when there are zero integer bits.",
+                    ". Cannot overflow for unsigned values.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).saturating_floor(), Fix::from_num(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).saturating_floor(), Fix::from_num(-3));
+type AllFrac = ", stringify!($Self), "<256>;
+assert_eq!(AllFrac::MIN.saturating_floor(), AllFrac::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_floor(self) -> $Self<FRAC> {
+                    match self.overflowing_floor() {
+                        (ans, false) => ans,
+                        (_, true) => $Self::MIN,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating round. Rounds to the nearest integer, with
+ties rounded away from zero, and saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).saturating_round(), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).saturating_round(), Fix::from_num(-3));
+",
+                },
+                "assert_eq!(Fix::MAX.saturating_round(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_round(self) -> $Self<FRAC> {
+                    match self.overflowing_round() {
+                        (ans, false) => ans,
+                        (_, true) => self.saturated(),
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating round. Rounds to the nearest integer, with
+ties rounded to even, and saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).saturating_round_ties_to_even(), Fix::from_num(2));
+assert_eq!(Fix::from_num(3.5).saturating_round_ties_to_even(), Fix::from_num(4));
+assert_eq!(Fix::MAX.saturating_round_ties_to_even(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_round_ties_to_even(self) -> $Self<FRAC> {
+                    match self.overflowing_round_ties_to_even() {
+                        (ans, false) => ans,
+                        (_, true) => self.saturated(),
+                    }
+                }
+            }
+
+            // Returns MIN for negative numbers and MAX otherwise, which is
+            // the saturated result when rounding to the nearest overflows.
+            #[inline]
+            const fn saturated(self) -> $Self<FRAC> {
+                if if_signed_unsigned!($Signedness, self.is_negative(), false) {
+                    $Self::MIN
+                } else {
+                    $Self::MAX
+                }
+            }
+
+            comment! {
+                "Wrapping ceil. Rounds to the next integer towards +∞,
+wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).wrapping_ceil(), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).wrapping_ceil(), Fix::from_num(-2));
+",
+                },
+                "assert_eq!(Fix::MAX.wrapping_ceil(), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_ceil(self) -> $Self<FRAC> {
+                    self.overflowing_ceil().0
+                }
+            }
+
+            comment! {
+                "Wrapping floor. Rounds to the next integer towards &minus;∞",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", wrapping on overflow.
+
+Overflow can only occur when there are zero integer bits.",
+                    ". Cannot overflow for unsigned values.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).wrapping_floor(), Fix::from_num(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).wrapping_floor(), Fix::from_num(-3));
+type AllFrac = ", stringify!($Self), "<256>;
+assert_eq!(AllFrac::MIN.wrapping_floor(), AllFrac::ZERO);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_floor(self) -> $Self<FRAC> {
+                    self.overflowing_floor().0
+                }
+            }
+
+            comment! {
+                "Wrapping round. Rounds to the next integer to the
+nearest, with ties rounded away from zero, and wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).wrapping_round(), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).wrapping_round(), Fix::from_num(-3));
+",
+                },
+                "assert_eq!(Fix::MAX.wrapping_round(), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_round(self) -> $Self<FRAC> {
+                    self.overflowing_round().0
+                }
+            }
+
+            comment! {
+                "Wrapping round. Rounds to the next integer to the
+nearest, with ties rounded to even, and wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).wrapping_round_ties_to_even(), Fix::from_num(2));
+assert_eq!(Fix::from_num(3.5).wrapping_round_ties_to_even(), Fix::from_num(4));
+assert_eq!(Fix::MAX.wrapping_round_ties_to_even(), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_round_ties_to_even(self) -> $Self<FRAC> {
+                    self.overflowing_round_ties_to_even().0
+                }
+            }
+
+            comment! {
+                "Unwrapped ceil. Rounds to the next integer towards +∞,
+panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).unwrapped_ceil(), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).unwrapped_ceil(), Fix::from_num(-2));
+",
+                },
+                "```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_ceil();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_ceil(self) -> $Self<FRAC> {
+                    match self.overflowing_ceil() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped floor. Rounds to the next integer towards &minus;∞",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", panicking on overflow.
+
+Overflow can only occur when there are zero integer bits.
+
+# Panics
+
+Panics if the result does not fit.",
+                    ". Cannot overflow for unsigned values.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).unwrapped_floor(), Fix::from_num(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).unwrapped_floor(), Fix::from_num(-3));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<256>;
+let _overflow = AllFrac::MIN.unwrapped_floor();
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_floor(self) -> $Self<FRAC> {
+                    match self.overflowing_floor() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped round. Rounds to the next integer to the
+nearest, with ties rounded away from zero, and panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).unwrapped_round(), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).unwrapped_round(), Fix::from_num(-3));
+",
+                },
+                "```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_round();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_round(self) -> $Self<FRAC> {
+                    match self.overflowing_round() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped round. Rounds to the next integer to the
+nearest, with ties rounded to even, and panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).unwrapped_round_ties_to_even(), Fix::from_num(2));
+assert_eq!(Fix::from_num(3.5).unwrapped_round_ties_to_even(), Fix::from_num(4));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_round_ties_to_even();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_round_ties_to_even(self) -> $Self<FRAC> {
+                    match self.overflowing_round_ties_to_even() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing ceil. Rounds to the next integer towards +∞.
+
+Returns a [tuple] of the fixed-point number and a [`bool`], indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).overflowing_ceil(), (Fix::from_num(3), false));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).overflowing_ceil(), (Fix::from_num(-2), false));
+"
+                },
+                "assert_eq!(Fix::MAX.overflowing_ceil(), (Fix::MIN, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_ceil(self) -> ($Self<FRAC>, bool) {
+                    self.overflowing_round_mode(RoundingMode::Ceil)
+                }
+            }
+
+            comment! {
+                "Overflowing floor. Rounds to the next integer towards &minus;∞.
+
+Returns a [tuple] of the fixed-point number and ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "a [`bool`], indicating whether an overflow has
+occurred. On overflow, the wrapped value is returned. Overflow can only
+occur when there are zero integer bits.",
+                    "[`false`].",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).overflowing_floor(), (Fix::from_num(2), false));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).overflowing_floor(), (Fix::from_num(-3), false));
+type AllFrac = ", stringify!($Self), "<256>;
+assert_eq!(AllFrac::MIN.overflowing_floor(), (AllFrac::ZERO, true));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_floor(self) -> ($Self<FRAC>, bool) {
+                    self.overflowing_round_mode(RoundingMode::Floor)
+                }
+            }
+
+            comment! {
+                "Overflowing round. Rounds to the next integer to the
+nearest, with ties rounded away from zero.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).overflowing_round(), (Fix::from_num(3), false));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.5).overflowing_round(), (Fix::from_num(-3), false));
+",
+                },
+                "assert_eq!(Fix::MAX.overflowing_round(), (Fix::MIN, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_round(self) -> ($Self<FRAC>, bool) {
+                    self.overflowing_round_mode(RoundingMode::HalfAwayFromZero)
+                }
+            }
+
+            comment! {
+                "Overflowing round. Rounds to the next integer to the
+nearest, with ties rounded to even.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).overflowing_round_ties_to_even(), (Fix::from_num(2), false));
+assert_eq!(Fix::from_num(3.5).overflowing_round_ties_to_even(), (Fix::from_num(4), false));
+assert_eq!(Fix::MAX.overflowing_round_ties_to_even(), (Fix::MIN, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_round_ties_to_even(self) -> ($Self<FRAC>, bool) {
+                    self.overflowing_round_mode(RoundingMode::HalfEven)
+                }
+            }
+        }
+
+        impl<const FRAC: i32> $Self<FRAC> {
+            #[inline]
+            const fn from_raw(raw: U256) -> $Self<FRAC> {
+                $Self {
+                    bits: if_signed_unsigned!(
+                        $Signedness,
+                        int256::u256_wrapping_as_i256(raw),
+                        raw,
+                    ),
+                }
+            }
+
+            comment! {
+                "Checked multiplication. Returns the product, or [`None`] on
+overflow.
+
+The product is rounded toward &minus;∞, like multiplication using the `*`
+operator.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<128>;
+let a = Fix::from_num(0.75);
+assert_eq!(a.checked_mul(a), Some(Fix::from_num(0.5625)));
+assert_eq!(Fix::MAX.checked_mul(Fix::MAX), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_mul(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    match self.overflowing_mul(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating multiplication. Returns the product, saturating on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.saturating_mul(Fix::MAX), Fix::MAX);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MAX.saturating_mul(-Fix::MAX), Fix::MIN);
+",
+                    "",
+                ),
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_mul(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.overflowing_mul(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            let neg = if_signed_unsigned!(
+                                $Signedness,
+                                self.is_negative() != rhs.is_negative(),
+                                false,
+                            );
+                            if neg {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping multiplication. Returns the product, wrapping on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<0>;
+// ",
+                if_signed_unsigned!($Signedness, "-2^255", "2^255"),
+                " × 2 wraps to 0
+let large = Fix::from_hi_lo(1 << 127, 0);
+assert_eq!(large.wrapping_mul(Fix::from_hi_lo(0, 2)), Fix::ZERO);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_mul(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    self.overflowing_mul(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped multiplication. Returns the product, panicking on
+overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<0>;
+let three = Fix::from_hi_lo(0, 3);
+assert_eq!(three.unwrapped_mul(three), Fix::from_hi_lo(0, 9));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_mul(Fix::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_mul(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.overflowing_mul(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing multiplication.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The product is rounded toward &minus;∞, like multiplication using the `*`
+operator.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<0>;
+let three = Fix::from_hi_lo(0, 3);
+assert_eq!(three.overflowing_mul(three), (Fix::from_hi_lo(0, 9), false));
+let large = Fix::from_hi_lo(1 << 127, 0);
+assert_eq!(large.overflowing_mul(Fix::from_hi_lo(0, 2)), (Fix::ZERO, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_mul(self, rhs: $Self<FRAC>) -> ($Self<FRAC>, bool) {
+                    let (lhs_raw, rhs_raw) = (self.to_raw(), rhs.to_raw());
+                    let words = wide_mul_u256(lhs_raw, rhs_raw);
+                    let (words, fill) = if_signed_unsigned!(
+                        $Signedness,
+                        {
+                            // a negative operand was treated as 2^256 too
+                            // large, so 2^256 × the other operand is removed
+                            // from the high half
+                            let mut hi = U256 {
+                                lo: words[2],
+                                hi: words[3],
+                            };
+                            if self.is_negative() {
+                                hi = int256::wrapping_sub_u256(hi, rhs_raw);
+                            }
+                            if rhs.is_negative() {
+                                hi = int256::wrapping_sub_u256(hi, lhs_raw);
+                            }
+                            let fill = if (hi.hi as i128) < 0 { u128::MAX } else { 0 };
+                            ([words[0], words[1], hi.lo, hi.hi], fill)
+                        },
+                        (words, 0),
+                    );
+                    let ans = window(words, fill, FRAC as i64);
+                    let sign_offset = if_signed_unsigned!($Signedness, 255, 256);
+                    let fits = is_fill_from(words, fill, FRAC as i64 + sign_offset);
+                    ($Self::from_raw(ans), !fits)
+                }
+            }
+
+            comment! {
+                "Checked shift left. Returns the shifted number, or [`None`] if
+`rhs`&nbsp;≥&nbsp;256.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA.checked_shl(130), Some(Fix::from_hi_lo(4, 0)));
+assert_eq!(Fix::DELTA.checked_shl(256), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_shl(self, rhs: u32) -> Option<$Self<FRAC>> {
+                    match self.overflowing_shl(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping shift left. Wraps `rhs` if `rhs`&nbsp;≥&nbsp;256,
+then shifts and returns the number.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA.wrapping_shl(256 + 3), Fix::from_hi_lo(0, 8));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_shl(self, rhs: u32) -> $Self<FRAC> {
+                    self.overflowing_shl(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped shift left. Panics if `rhs`&nbsp;≥&nbsp;256.
+
+# Panics
+
+Panics if `rhs`&nbsp;≥&nbsp;256.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA.unwrapped_shl(3), Fix::from_hi_lo(0, 8));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::DELTA.unwrapped_shl(256);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_shl(self, rhs: u32) -> $Self<FRAC> {
+                    match self.overflowing_shl(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing shift left.
+
+Returns a [tuple] of the shifted value and a [`bool`] indicating whether
+`rhs`&nbsp;≥&nbsp;256. On overflow `rhs` is wrapped before the shift operation.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::DELTA.overflowing_shl(3), (Fix::from_hi_lo(0, 8), false));
+assert_eq!(Fix::DELTA.overflowing_shl(256 + 3), (Fix::from_hi_lo(0, 8), true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_shl(self, rhs: u32) -> ($Self<FRAC>, bool) {
+                    let raw = int256::shl_u256(self.to_raw(), rhs % 256);
+                    ($Self::from_raw(raw), rhs >= 256)
+                }
+            }
+
+            comment! {
+                "Checked shift right. Returns the shifted number, or [`None`]
+if `rhs`&nbsp;≥&nbsp;256.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_hi_lo(4, 0).checked_shr(130), Some(Fix::DELTA));
+assert_eq!(Fix::DELTA.checked_shr(256), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_shr(self, rhs: u32) -> Option<$Self<FRAC>> {
+                    match self.overflowing_shr(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping shift right. Wraps `rhs` if `rhs`&nbsp;≥&nbsp;256,
+then shifts and returns the number.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_hi_lo(0, 8).wrapping_shr(256 + 3), Fix::DELTA);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_shr(self, rhs: u32) -> $Self<FRAC> {
+                    self.overflowing_shr(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped shift right. Panics if `rhs`&nbsp;≥&nbsp;256.
+
+# Panics
+
+Panics if `rhs`&nbsp;≥&nbsp;256.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_hi_lo(0, 8).unwrapped_shr(3), Fix::DELTA);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::DELTA.unwrapped_shr(256);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_shr(self, rhs: u32) -> $Self<FRAC> {
+                    match self.overflowing_shr(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing shift right.
+
+Returns a [tuple] of the shifted value and a [`bool`] indicating whether
+`rhs`&nbsp;≥&nbsp;256. On overflow `rhs` is wrapped before the shift operation.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_hi_lo(0, 8).overflowing_shr(3), (Fix::DELTA, false));
+assert_eq!(Fix::from_hi_lo(0, 8).overflowing_shr(256 + 3), (Fix::DELTA, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_shr(self, rhs: u32) -> ($Self<FRAC>, bool) {
+                    let neg = if_signed_unsigned!($Signedness, self.is_negative(), false);
+                    let raw = int256::shr_u256(self.to_raw(), rhs % 256, neg);
+                    ($Self::from_raw(raw), rhs >= 256)
+                }
+            }
+        }
+
+        impl<const FRAC: i32> $Self<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= 256) }>: True,
+        {
+            comment! {
+                "Checked division. Returns the quotient, or [`None`] if the
+divisor is zero or on overflow.
+
+The quotient is rounded toward zero, like division using the `/` operator.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<128>;
+let a = Fix::from_num(0.75);
+let b = Fix::from_num(0.25);
+assert_eq!(b.checked_div(a).unwrap().to_string().len(), \"0.\".len() + 128);
+assert_eq!(a.checked_div(Fix::ZERO), None);
+assert_eq!(Fix::MAX.checked_div(b), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_div(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    if rhs.is_zero() {
+                        return None;
+                    }
+                    match self.overflowing_div(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating division. Returns the quotient, saturating on
+overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.saturating_div(Fix::DELTA), Fix::MAX);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MAX.saturating_div(-Fix::DELTA), Fix::MIN);
+",
+                    "",
+                ),
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_div(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.overflowing_div(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            let neg = if_signed_unsigned!(
+                                $Signedness,
+                                self.is_negative() != rhs.is_negative(),
+                                false,
+                            );
+                            if neg {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping division. Returns the quotient, wrapping on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// MAX / 0.5 is 2 × MAX, which wraps to -2 × DELTA
+let half = Fix::from_hi_lo(0, 1 << 3);
+assert_eq!(Fix::MAX.wrapping_div(half), ",
+                if_signed_unsigned!($Signedness, "-Fix::DELTA - Fix::DELTA", "Fix::MAX - Fix::DELTA"),
+                ");
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_div(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    self.overflowing_div(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped division. Returns the quotient, panicking on
+overflow.
+
+# Panics
+
+Panics if the divisor is zero or if the division results in overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let half = Fix::from_hi_lo(0, 1 << 3);
+assert_eq!(Fix::DELTA.unwrapped_div(half), Fix::from_hi_lo(0, 2));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_div(Fix::DELTA);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_div(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.overflowing_div(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing division.
+
+Returns a [tuple] of the quotient and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The quotient is rounded toward zero, like division using the `/` operator.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let half = Fix::from_hi_lo(0, 1 << 3);
+assert_eq!(Fix::DELTA.overflowing_div(half), (Fix::from_hi_lo(0, 2), false));
+assert_eq!(
+    Fix::MAX.overflowing_div(half),
+    (",
+                if_signed_unsigned!($Signedness, "-Fix::DELTA - Fix::DELTA", "Fix::MAX - Fix::DELTA"),
+                ", true)
+);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_div(self, rhs: $Self<FRAC>) -> ($Self<FRAC>, bool) {
+                    if rhs.is_zero() {
+                        panic!("division by zero");
+                    }
+                    let lhs_neg = if_signed_unsigned!($Signedness, self.is_negative(), false);
+                    let rhs_neg = if_signed_unsigned!($Signedness, rhs.is_negative(), false);
+                    let (lhs_raw, rhs_raw) = (self.to_raw(), rhs.to_raw());
+                    let lhs_abs = if lhs_neg {
+                        int256::wrapping_neg_u256(lhs_raw)
+                    } else {
+                        lhs_raw
+                    };
+                    let rhs_abs = if rhs_neg {
+                        int256::wrapping_neg_u256(rhs_raw)
+                    } else {
+                        rhs_raw
+                    };
+                    let (abs, overflow) = div_shl_u256(lhs_abs, rhs_abs, FRAC as u32);
+                    let neg = lhs_neg != rhs_neg;
+                    let raw = if neg {
+                        int256::wrapping_neg_u256(abs)
+                    } else {
+                        abs
+                    };
+                    let fits = $Self::<FRAC>::abs_fits(neg, abs);
+                    ($Self::from_raw(raw), overflow || !fits)
+                }
+            }
+
+            // Returns true if the value with sign neg and absolute value abs
+            // fits.
+            #[inline]
+            const fn abs_fits(neg: bool, abs: U256) -> bool {
+                if neg && abs.lo == 0 && abs.hi == 0 {
+                    return true;
+                }
+                if_signed_unsigned!(
+                    $Signedness,
+                    {
+                        let msb = 1 << 127;
+                        if neg {
+                            abs.hi < msb || (abs.hi == msb && abs.lo == 0)
+                        } else {
+                            abs.hi < msb
+                        }
+                    },
+                    !neg,
+                )
+            }
+
+            // Converts the sign, absolute value and overflow returned by the
+            // 256-bit parser.
+            #[inline]
+            const fn from_parsed(
+                parsed: Result<(bool, U256, bool), ParseFixedError>,
+            ) -> Result<($Self<FRAC>, bool), ParseFixedError> {
+                let (neg, abs, overflow) = match parsed {
+                    Ok(o) => o,
+                    Err(e) => return Err(e),
+                };
+                let raw = if neg {
+                    int256::wrapping_neg_u256(abs)
+                } else {
+                    abs
+                };
+                let fits = $Self::<FRAC>::abs_fits(neg, abs);
+                Ok(($Self::from_raw(raw), overflow || !fits))
+            }
+
+            comment! {
+                "Parses a string slice containing decimal digits to return a
+fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 1.75 is 1.11 in binary
+assert_eq!(Fix::from_str(\"1.75\"), Ok(Fix::from_hi_lo(0, 0b111 << (4 - 2))));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_str(\"-1.75\"), Ok(-Fix::from_hi_lo(0, 0b111 << (4 - 2))));
+",
+                    "",
+                ),
+                "```
+";
+                #[inline]
+                pub const fn from_str(src: &str) -> Result<$Self<FRAC>, ParseFixedError> {
+                    match $Self::overflowing_from_str(src) {
+                        Ok((val, false)) => Ok(val),
+                        Ok((_, true)) => Err(from_str::u256::OVERFLOW),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing decimal digits to return a
+fixed-point number, rounded according to the given [`RoundingMode`].
+
+This is like [`from_str`][Self::from_str], which always rounds to the nearest
+with ties rounded to even, except that it rounds according to `mode`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// 0.1 lies between 0.0625 and 0.125
+let floor = Fix::from_str_round(\"0.1\", RoundingMode::Floor);
+assert_eq!(floor, Ok(Fix::from_hi_lo(0, 1)));
+let ceil = Fix::from_str_round(\"0.1\", RoundingMode::Ceil);
+assert_eq!(ceil, Ok(Fix::from_hi_lo(0, 2)));
+```
+";
+                #[inline]
+                pub const fn from_str_round(
+                    src: &str,
+                    mode: RoundingMode,
+                ) -> Result<$Self<FRAC>, ParseFixedError> {
+                    let parsed = from_str::u256::from_str(src.as_bytes(), FRAC as u32, mode);
+                    match $Self::from_parsed(parsed) {
+                        Ok((val, false)) => Ok(val),
+                        Ok((_, true)) => Err(from_str::u256::OVERFLOW),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing decimal digits to return a
+fixed-point number, saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<250>;
+assert_eq!(Fix::saturating_from_str(\"9999\"), Ok(Fix::MAX));
+assert_eq!(Fix::saturating_from_str(\"-9999\"), Ok(Fix::MIN));
+```
+";
+                #[inline]
+                pub const fn saturating_from_str(
+                    src: &str,
+                ) -> Result<$Self<FRAC>, ParseFixedError> {
+                    match $Self::overflowing_from_str(src) {
+                        Ok((val, false)) => Ok(val),
+                        Ok((_, true)) => {
+                            let starts_with_minus = matches!(src.as_bytes().first(), Some(b'-'));
+                            if starts_with_minus {
+                                Ok($Self::MIN)
+                            } else {
+                                Ok($Self::MAX)
+                            }
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing decimal digits to return a
+fixed-point number, wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<252>;
+// 25.5 is 11001.1 in binary, and only the last 4 integer bits are kept
+let wrapped = Fix::from_hi_lo(0b1001_1 << 123, 0);
+assert_eq!(Fix::wrapping_from_str(\"25.5\"), Ok(wrapped));
+```
+";
+                #[inline]
+                pub const fn wrapping_from_str(src: &str) -> Result<$Self<FRAC>, ParseFixedError> {
+                    match $Self::overflowing_from_str(src) {
+                        Ok((val, _)) => Ok(val),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing decimal digits to return a
+fixed-point number, panicking on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the value does not fit or if there is a parsing error.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 1.75 is 1.11 in binary
+let f = Fix::unwrapped_from_str(\"1.75\");
+assert_eq!(f, Fix::from_hi_lo(0, 0b111 << (4 - 2)));
+```
+
+The following panics because of a parsing error.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _error = Fix::unwrapped_from_str(\"1.75.\");
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_from_str(src: &str) -> $Self<FRAC> {
+                    match $Self::from_str(src) {
+                        Ok(o) => o,
+                        Err(e) => panic!("{}", e.message()),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing decimal digits to return a
+fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<252>;
+let one = Fix::from_hi_lo(1 << 124, 0);
+assert_eq!(Fix::overflowing_from_str(\"1\"), Ok((one, false)));
+// 17 is 10001 in binary, and only the last 4 integer bits are kept
+assert_eq!(Fix::overflowing_from_str(\"17\"), Ok((one, true)));
+```
+";
+                #[inline]
+                pub const fn overflowing_from_str(
+                    src: &str,
+                ) -> Result<($Self<FRAC>, bool), ParseFixedError> {
+                    let parsed = from_str::u256::from_str(
+                        src.as_bytes(),
+                        FRAC as u32,
+                        RoundingMode::HalfEven,
+                    );
+                    $Self::from_parsed(parsed)
+                }
+            }
+        }
+
+        impl<const FRAC: i32> $Self<FRAC>
+        where
+            $Narrow<{ FRAC - 64 }>: Sized,
+            $Narrow<{ FRAC - 128 }>: Fixed,
+        {
+            comment! {
+                "Creates a fixed-point number from another number.
+
+The other number can be:
+
+  * A fixed-point number. Any extra fractional bits are discarded, which rounds
+    towards &minus;∞.
+  * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`],
+    [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or [`usize`].
+  * A floating-point number of type [`f16`], [`bf16`], [`f32`], [`f64`] or
+    [`F128`]. For this conversion, the method rounds to the nearest, with ties
+    rounding to even.
+  * Any other number `src` for which [`ToFixed`] is implemented and which
+    rounds in the same way.
+
+The conversion is done through 128-bit fixed-point numbers holding the high and
+the low 128 bits, so `src` has to implement [`Copy`].
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_from_num`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Self), "};
+type Fix = ", stringify!($Self), "<4>;
+
+// 1.75 is 1.11 in binary
+let src = I16F16::from_bits(0b111 << (16 - 2));
+assert_eq!(Fix::from_num(src), Fix::from_hi_lo(0, 0b111 << (4 - 2)));
+assert_eq!(Fix::from_num(3i32), Fix::from_hi_lo(0, 3 << 4));
+assert_eq!(Fix::from_num(1.75f32), Fix::from_hi_lo(0, 0b111 << (4 - 2)));
+// u128::MAX × 2^4 does not fit in 128 bits
+assert_eq!(Fix::from_num(u128::MAX), Fix::from_hi_lo(0xF, !0xF));
+```
+
+[`F128`]: crate::F128
+[`ToFixed`]: crate::traits::ToFixed
+[`bf16`]: half::bf16
+[`f16`]: half::f16
+[`wrapping_from_num`]: Self::wrapping_from_num
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn from_num<Src: ToFixed + Copy>(src: Src) -> $Self<FRAC> {
+                    let (ans, overflow) = $Self::overflowing_from_num(src);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number if it fits,
+otherwise returns [`None`].
+
+The other number can be any number accepted by [`from_num`]. For
+floating-point numbers, [`None`] is returned if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<200>;
+assert_eq!(Fix::checked_from_num(1.5), Some(Fix::from_hi_lo(3 << 71, 0)));
+assert_eq!(Fix::checked_from_num(1e100), None);
+assert_eq!(Fix::checked_from_num(f64::NAN), None);
+```
+
+[`from_num`]: Self::from_num
+[finite]: f64::is_finite
+";
+                #[inline]
+                pub fn checked_from_num<Src: ToFixed + Copy>(src: Src) -> Option<$Self<FRAC>> {
+                    // non-finite values give None for both rounding modes
+                    let floor = src.checked_to_fixed_round::<$Narrow<{ FRAC - 128 }>>(RoundingMode::Floor);
+                    let ceil = src.checked_to_fixed_round::<$Narrow<{ FRAC - 128 }>>(RoundingMode::Ceil);
+                    if floor.is_none() && ceil.is_none() {
+                        return None;
+                    }
+                    match $Self::overflowing_from_num(src) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number, saturating
+if it does not fit.
+
+The other number can be any number accepted by [`from_num`].
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<200>;
+assert_eq!(Fix::saturating_from_num(1e100), Fix::MAX);
+assert_eq!(Fix::saturating_from_num(f64::NEG_INFINITY), Fix::MIN);
+```
+
+[NaN]: f64::is_nan
+[`from_num`]: Self::from_num
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn saturating_from_num<Src: ToFixed + Copy>(src: Src) -> $Self<FRAC> {
+                    match $Self::checked_from_num(src) {
+                        Some(ans) => ans,
+                        None => {
+                            let hi = src.saturating_to_fixed::<$Narrow<{ FRAC - 128 }>>();
+                            let neg = if_signed_unsigned!($Signedness, hi.is_negative(), hi.is_zero());
+                            if neg {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number, wrapping
+the value on overflow.
+
+The other number can be any number accepted by [`from_num`].
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<252>;
+// 25.5 is 11001.1 in binary, and only the last 4 integer bits are kept
+let wrapped = Fix::from_hi_lo(0b1001_1 << 123, 0);
+assert_eq!(Fix::wrapping_from_num(25.5), wrapped);
+```
+
+[`from_num`]: Self::from_num
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn wrapping_from_num<Src: ToFixed + Copy>(src: Src) -> $Self<FRAC> {
+                    $Self::overflowing_from_num(src).0
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number, panicking on
+overflow.
+
+The other number can be any number accepted by [`from_num`].
+
+# Panics
+
+Panics if the value does not fit.
+
+For floating-point numbers, also panics if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::unwrapped_from_num(3i32), Fix::from_hi_lo(0, 3 << 4));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<252>;
+let _overflow = Fix::unwrapped_from_num(25.5);
+```
+
+[`from_num`]: Self::from_num
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn unwrapped_from_num<Src: ToFixed + Copy>(src: Src) -> $Self<FRAC> {
+                    match $Self::overflowing_from_num(src) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+
+The other number can be any number accepted by [`from_num`].
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<252>;
+let one = Fix::from_hi_lo(1 << 124, 0);
+assert_eq!(Fix::overflowing_from_num(1), (one, false));
+// 17 is 10001 in binary, and only the last 4 integer bits are kept
+assert_eq!(Fix::overflowing_from_num(17), (one, true));
+```
+
+[`from_num`]: Self::from_num
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn overflowing_from_num<Src: ToFixed + Copy>(src: Src) -> ($Self<FRAC>, bool) {
+                    let (hi, hi_overflow) =
+                        src.overflowing_to_fixed_round::<$Narrow<{ FRAC - 128 }>>(RoundingMode::Floor);
+                    let lo_floor = src
+                        .overflowing_to_fixed_round::<FixedU128<FRAC>>(RoundingMode::Floor)
+                        .0
+                        .to_bits();
+                    let lo = src.overflowing_to_fixed::<FixedU128<FRAC>>().0.to_bits();
+                    // rounding to the nearest can only round the floor up by one
+                    let carry = lo == 0 && lo_floor == u128::MAX;
+                    let (hi, carry_overflow) = hi.to_bits().overflowing_add($Hi::from(carry));
+                    // If the floor of the high part overflows but the carry
+                    // wraps it back, the result is the ceil of the high part.
+                    let overflow = if hi_overflow && carry_overflow {
+                        src.overflowing_to_fixed_round::<$Narrow<{ FRAC - 128 }>>(RoundingMode::Ceil)
+                            .1
+                    } else {
+                        hi_overflow || carry_overflow
+                    };
+                    ($Self::from_hi_lo(hi, lo), overflow)
+                }
+            }
+
+            // Returns the 128-bit fixed-point number that keeps the most
+            // significant bits.
+            #[inline]
+            const fn num_window(self) -> (NumWindow, u128) {
+                let raw = self.to_raw();
+                let neg = if_signed_unsigned!($Signedness, self.is_negative(), false);
+                let frac = NumWindow::Frac;
+                let frac_uns = Some(NumWindow::FracUnsigned);
+                if let Some(w) = $Self::<FRAC>::fit_window(raw, neg, 0, frac, frac_uns) {
+                    return w;
+                }
+                let int = NumWindow::Int;
+                let int_uns = Some(NumWindow::IntUnsigned);
+                if 0 < FRAC && FRAC < 64 {
+                    if let Some(w) = $Self::<FRAC>::fit_window(raw, neg, FRAC as u32, int, int_uns) {
+                        return w;
+                    }
+                }
+                if let Some(w) = $Self::<FRAC>::fit_window(raw, neg, 64, NumWindow::Frac64, None) {
+                    return w;
+                }
+                if 64 <= FRAC && FRAC < 128 {
+                    if let Some(w) = $Self::<FRAC>::fit_window(raw, neg, FRAC as u32, int, int_uns) {
+                        return w;
+                    }
+                }
+                (NumWindow::Frac128, raw.hi)
+            }
+
+            #[inline]
+            const fn frac64_window(bits: u128) -> $Narrow<{ FRAC - 64 }> {
+                $Narrow::from_bits(bits as $NarrowInner)
+            }
+
+            #[inline]
+            const fn frac128_window(bits: u128) -> $Narrow<{ FRAC - 128 }> {
+                $Narrow::from_bits(bits as $NarrowInner)
+            }
+
+            // Returns the bits of raw >> shift if they fit in the narrow type, or
+            // in an unsigned narrow type if unsigned_window is not None.
+            #[inline]
+            const fn fit_window(
+                raw: U256,
+                neg: bool,
+                shift: u32,
+                window: NumWindow,
+                unsigned_window: Option<NumWindow>,
+            ) -> Option<(NumWindow, u128)> {
+                let shifted = int256::shr_u256(raw, shift, neg);
+                if $Self::<FRAC>::raw_fits_narrow(shifted) {
+                    return Some((window, shifted.lo));
+                }
+                match unsigned_window {
+                    Some(unsigned_window) if shifted.hi == 0 => Some((unsigned_window, shifted.lo)),
+                    _ => None,
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number.
+
+The other number can be:
+
+  * Another fixed-point number. Any extra fractional bits are discarded, which
+    rounds towards &minus;∞.
+  * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`],
+    [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or [`usize`]. Any fractional
+    bits are discarded, which rounds towards &minus;∞.
+  * A floating-point number of type [`f16`], [`bf16`], [`f32`], [`f64`] or
+    [`F128`]. For this conversion, the method rounds to the nearest, with ties
+    rounding to even.
+  * Any other type `Dst` for which [`FromFixed`] is implemented.
+
+The conversion is done through a 128-bit fixed-point number. If the value fits
+in a 128-bit number with `FRAC` fractional bits, the conversion is exact.
+Otherwise the 128-bit number keeps the integer bits and as many of the most
+significant fractional bits as fit, or at least the 64 most significant bits of
+the value, and the other bits are discarded, rounding towards &minus;∞. So
+integer and fixed-point results are exact whenever the destination can hold
+them, but floating-point results can differ from the correctly rounded value
+by one unit in the last place, as they can be rounded twice.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_to_num`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I30F2, ", stringify!($Self), "};
+type Fix = ", stringify!($Self), "<4>;
+
+// 1.75 is 1.11 in binary
+let src = Fix::from_hi_lo(0, 0b111 << (4 - 2));
+assert_eq!(src.to_num::<I30F2>(), I30F2::from_bits(0b111));
+assert_eq!(src.to_num::<i32>(), 1);
+assert_eq!(src.to_num::<f64>(), 1.75);
+// 2^240 does not fit in 128 bits, but it is converted exactly
+let large = Fix::from_hi_lo(1 << 116, 0);
+assert_eq!(large.to_num::<f64>(), 2f64.powi(240));
+```
+
+[`F128`]: crate::F128
+[`FromFixed`]: crate::traits::FromFixed
+[`bf16`]: half::bf16
+[`f16`]: half::f16
+[`wrapping_to_num`]: Self::wrapping_to_num
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn to_num<Dst: FromFixed>(self) -> Dst
+                {
+                    from_num_window!(self.num_window(), $Self, $Narrow, Dst::from_fixed)
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number if it fits,
+otherwise returns [`None`].
+
+The other number can be any number accepted by [`to_num`], and the value is
+rounded in the same way.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let src = Fix::from_hi_lo(0, 0b111 << (4 - 2));
+assert_eq!(src.checked_to_num::<i32>(), Some(1));
+assert_eq!(Fix::MAX.checked_to_num::<i128>(), None);
+```
+
+[`to_num`]: Self::to_num
+";
+                #[inline]
+                pub fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst>
+                {
+                    from_num_window!(self.num_window(), $Self, $Narrow, Dst::checked_from_fixed)
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number, saturating
+the value if it does not fit.
+
+The other number can be any number accepted by [`to_num`], and the value is
+rounded in the same way.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.saturating_to_num::<i128>(), i128::MAX);
+assert_eq!(Fix::MIN.saturating_to_num::<u128>(), 0);
+```
+
+[`to_num`]: Self::to_num
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_to_num<Dst: FromFixed>(self) -> Dst
+                {
+                    from_num_window!(self.num_window(), $Self, $Narrow, Dst::saturating_from_fixed)
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number, wrapping the
+value on overflow.
+
+The other number can be any number accepted by [`to_num`], and the value is
+rounded in the same way.
+
+If the integer part of the value does not fit in 128 bits, some of its least
+significant bits have already been discarded, so the wrapped result is not
+meaningful.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 257.5 wraps to 1.5
+let src = Fix::from_hi_lo(0, 0b1_0000_0001_1 << 3);
+assert_eq!(src.wrapping_to_num::<u8>(), 1);
+```
+
+[`to_num`]: Self::to_num
+";
+                #[inline]
+                #[must_use]
+                pub fn wrapping_to_num<Dst: FromFixed>(self) -> Dst
+                {
+                    from_num_window!(self.num_window(), $Self, $Narrow, Dst::wrapping_from_fixed)
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number, panicking on
+overflow.
+
+The other number can be any number accepted by [`to_num`], and the value is
+rounded in the same way.
+
+# Panics
+
+Panics if the value does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let src = Fix::from_hi_lo(0, 0b111 << (4 - 2));
+assert_eq!(src.unwrapped_to_num::<i32>(), 1);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_to_num::<i128>();
+```
+
+[`to_num`]: Self::to_num
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn unwrapped_to_num<Dst: FromFixed>(self) -> Dst
+                {
+                    from_num_window!(self.num_window(), $Self, $Narrow, Dst::unwrapped_from_fixed)
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number.
+
+Returns a [tuple] of the number and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The other number can be any number accepted by [`to_num`], and the value is
+rounded in the same way. As with [`wrapping_to_num`], the wrapped value is not
+meaningful if the integer part does not fit in 128 bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 257.5 wraps to 1.5
+let src = Fix::from_hi_lo(0, 0b1_0000_0001_1 << 3);
+assert_eq!(src.overflowing_to_num::<u8>(), (1, true));
+assert_eq!(src.overflowing_to_num::<u16>(), (257, false));
+```
+
+[`to_num`]: Self::to_num
+[`wrapping_to_num`]: Self::wrapping_to_num
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool)
+                {
+                    from_num_window!(self.num_window(), $Self, $Narrow, Dst::overflowing_from_fixed)
+                }
+            }
+        }
+
+        impl<const FRAC: i32> From<$Narrow<FRAC>> for $Self<FRAC> {
+            /// Converts a fixed-point number to the wider type. This conversion
+            /// is lossless.
+            #[inline]
+            fn from(src: $Narrow<FRAC>) -> $Self<FRAC> {
+                let bits = src.to_bits();
+                let hi = if_signed_unsigned!($Signedness, bits >> 127, 0);
+                $Self::from_hi_lo(hi, bits as u128)
+            }
+        }
+
+        impl<const FRAC: i32> Add for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            #[track_caller]
+            fn add(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                let (ans, overflow) = self.overflowing_add(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const FRAC: i32> AddAssign for $Self<FRAC> {
+            #[inline]
+            #[track_caller]
+            fn add_assign(&mut self, rhs: $Self<FRAC>) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<const FRAC: i32> Sub for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            #[track_caller]
+            fn sub(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                let (ans, overflow) = self.overflowing_sub(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const FRAC: i32> SubAssign for $Self<FRAC> {
+            #[inline]
+            #[track_caller]
+            fn sub_assign(&mut self, rhs: $Self<FRAC>) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<const FRAC: i32> Mul for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            #[track_caller]
+            fn mul(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                let (ans, overflow) = self.overflowing_mul(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const FRAC: i32> MulAssign for $Self<FRAC> {
+            #[inline]
+            #[track_caller]
+            fn mul_assign(&mut self, rhs: $Self<FRAC>) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const FRAC: i32> Div for $Self<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= 256) }>: True,
+        {
+            type Output = $Self<FRAC>;
+            #[inline]
+            #[track_caller]
+            fn div(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                let (ans, overflow) = self.overflowing_div(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const FRAC: i32> DivAssign for $Self<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= 256) }>: True,
+        {
+            #[inline]
+            #[track_caller]
+            fn div_assign(&mut self, rhs: $Self<FRAC>) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<const FRAC: i32> Shl<u32> for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            #[track_caller]
+            fn shl(self, rhs: u32) -> $Self<FRAC> {
+                let (ans, overflow) = self.overflowing_shl(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const FRAC: i32> ShlAssign<u32> for $Self<FRAC> {
+            #[inline]
+            #[track_caller]
+            fn shl_assign(&mut self, rhs: u32) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl<const FRAC: i32> Shr<u32> for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            #[track_caller]
+            fn shr(self, rhs: u32) -> $Self<FRAC> {
+                let (ans, overflow) = self.overflowing_shr(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const FRAC: i32> ShrAssign<u32> for $Self<FRAC> {
+            #[inline]
+            #[track_caller]
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = *self >> rhs;
+            }
+        }
+
+        impl<const FRAC: i32> Not for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            fn not(self) -> $Self<FRAC> {
+                $Self::from_hi_lo(!self.bits.hi, !self.bits.lo)
+            }
+        }
+
+        impl<const FRAC: i32> BitAnd for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            fn bitand(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                $Self::from_hi_lo(self.bits.hi & rhs.bits.hi, self.bits.lo & rhs.bits.lo)
+            }
+        }
+
+        impl<const FRAC: i32> BitAndAssign for $Self<FRAC> {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: $Self<FRAC>) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl<const FRAC: i32> BitOr for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            fn bitor(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                $Self::from_hi_lo(self.bits.hi | rhs.bits.hi, self.bits.lo | rhs.bits.lo)
+            }
+        }
+
+        impl<const FRAC: i32> BitOrAssign for $Self<FRAC> {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: $Self<FRAC>) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl<const FRAC: i32> BitXor for $Self<FRAC> {
+            type Output = $Self<FRAC>;
+            #[inline]
+            fn bitxor(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                $Self::from_hi_lo(self.bits.hi ^ rhs.bits.hi, self.bits.lo ^ rhs.bits.lo)
+            }
+        }
+
+        impl<const FRAC: i32> BitXorAssign for $Self<FRAC> {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: $Self<FRAC>) {
+                *self = *self ^ rhs;
+            }
+        }
+
+        impl<const FRAC: i32> FromStr for $Self<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= 256) }>: True,
+        {
+            type Err = ParseFixedError;
+            /// Parses a string slice to return a fixed-point number.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::from_str(s)
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+            impl<const FRAC: i32> Neg for $Self<FRAC> {
+                type Output = $Self<FRAC>;
+                #[inline]
+                #[track_caller]
+                fn neg(self) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_neg();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+        }
+
+        impl<const FRAC: i32> Display for $Self<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= 256) }>: True,
+        {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                let neg = if_signed_unsigned!($Signedness, self.is_negative(), false);
+                let raw = self.to_raw();
+                let abs = if neg { int256::wrapping_neg_u256(raw) } else { raw };
+                fmt_dec(neg, abs, FRAC as u32, f)
+            }
+        }
+
+        impl<const FRAC: i32> Debug for $Self<FRAC> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                let neg = if_signed_unsigned!($Signedness, self.is_negative(), false);
+                let raw = self.to_raw();
+                let abs = if neg { int256::wrapping_neg_u256(raw) } else { raw };
+                if (0..=256).contains(&FRAC) {
+                    return fmt_dec(neg, abs, FRAC as u32, f);
+                }
+                f.write_str("(")?;
+                fmt_dec(neg, abs, 0, f)?;
+                if FRAC < 0 {
+                    write!(f, " << {})", FRAC.wrapping_neg() as u32)
+                } else {
+                    write!(f, " >> {})", FRAC)
+                }
+            }
+        }
+    };
+}
+
+fixed256! {
+    description = "A 256-bit signed",
+    {Self, Bits, Hi} = {FixedI256, I256, i128},
+    Signedness = Signed,
+    {Narrow, NarrowInner} = {FixedI128, i128},
+}
+fixed256! {
+    description = "A 256-bit unsigned",
+    {Self, Bits, Hi} = {FixedU256, U256, u128},
+    Signedness = Unsigned,
+    {Narrow, NarrowInner} = {FixedU128, u128},
+}
+
+/// Wide multiplication and division, which return 256-bit numbers.
+impl<const FRAC: i32> FixedI128<FRAC> {
+    /// Multiplies two fixed-point numbers and returns a wider type to retain
+    /// all precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>
+    /// integer bits, and `rhs` has <i>g</i> fractional bits and
+    /// 128&nbsp;&minus;&nbsp;<i>g</i> integer bits, then the returned
+    /// fixed-point number will have <i>f</i>&nbsp;+&nbsp;<i>g</i> fractional
+    /// bits and 256&nbsp;&minus;&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>
+    /// integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I64F64, FixedI256};
+    /// // the product of two I64F64 numbers is exact
+    /// let prod: FixedI256<128> = I64F64::DELTA.wide_mul(I64F64::DELTA);
+    /// assert_eq!(prod, FixedI256::<128>::DELTA);
+    /// let prod = I64F64::from_num(-1.25).wide_mul(I64F64::from_num(0.5));
+    /// assert_eq!(prod.to_string(), "-0.625");
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn wide_mul<const RHS_FRAC: i32>(
+        self,
+        rhs: FixedI128<RHS_FRAC>,
+    ) -> FixedI256<{ FRAC + RHS_FRAC }> {
+        let prod = int256::wide_mul_i128(self.to_bits(), rhs.to_bits());
+        FixedI256::from_hi_lo(prod.hi, prod.lo)
+    }
+
+    /// Multiplies an unsigned fixed-point number and returns a wider signed
+    /// type to retain all precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>
+    /// integer bits, and `rhs` has <i>g</i> fractional bits and
+    /// 128&nbsp;&minus;&nbsp;<i>g</i> integer bits, then the returned
+    /// fixed-point number will have <i>f</i>&nbsp;+&nbsp;<i>g</i> fractional
+    /// bits and 256&nbsp;&minus;&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>
+    /// integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{FixedI128, FixedU128};
+    /// // decimal: -1.25 × 1.0625 = -1.328_125
+    /// // binary: -1.01 × 1.0001 = -1.010101
+    /// let a = FixedI128::<2>::from_num(-1.25);
+    /// let b = FixedU128::<4>::from_num(1.0625);
+    /// assert_eq!(a.wide_mul_unsigned(b).to_string(), "-1.328125");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn wide_mul_unsigned<const RHS_FRAC: i32>(
+        self,
+        rhs: FixedU128<RHS_FRAC>,
+    ) -> FixedI256<{ FRAC + RHS_FRAC }> {
+        let lhs = self.to_bits();
+        let rhs_signed = rhs.to_bits() as i128;
+        let mut prod = int256::wide_mul_i128(lhs, rhs_signed);
+        if rhs_signed < 0 {
+            // rhs msb treated as -2^127 instead of +2^127, so error in rhs is
+            // -2^128, and error in prod is -2^128 × lhs
+            prod.hi = prod.hi.wrapping_add(lhs);
+        }
+        FixedI256::from_hi_lo(prod.hi, prod.lo)
+    }
+
+    /// Divides two fixed-point numbers and returns a wider type to retain more
+    /// precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>
+    /// integer bits, and `rhs` has <i>g</i> fractional bits and
+    /// 128&nbsp;&minus;&nbsp;<i>g</i> integer bits, then the returned
+    /// fixed-point number will have 128&nbsp;+&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>
+    /// fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>&nbsp;+&nbsp;<i>g</i>
+    /// integer bits.
+    ///
+    /// **Warning:** While most cases of overflow are avoided using this method,
+    /// dividing [`MIN`][Self::MIN] by <code>-[DELTA][Self::DELTA]</code> will
+    /// still result in panic due to overflow. The alternative
+    /// [`wide_sdiv`][Self::wide_sdiv] method avoids this by sacrificing one
+    /// fractional bit in the return type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or on overflow. Overflow can only occur
+    /// when dividing [`MIN`][Self::MIN] by <code>-[DELTA][Self::DELTA]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{FixedI128, FixedI256};
+    /// // decimal: 4.625 / 0.03125 = 148
+    /// // binary: 100.101 / 0.00001 = 10010100
+    /// let a = FixedI128::<3>::from_num(4.625);
+    /// let b = FixedI128::<5>::from_num(0.03125);
+    /// let ans: FixedI256<126> = a.wide_div(b);
+    /// assert_eq!(ans.to_string(), "148");
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::FixedI128;
+    /// type Fix = FixedI128<4>;
+    /// let _overflow = Fix::MIN.wide_div(-Fix::DELTA);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn wide_div<const RHS_FRAC: i32>(
+        self,
+        rhs: FixedI128<RHS_FRAC>,
+    ) -> FixedI256<{ 128 + FRAC - RHS_FRAC }> {
+        let lhs = self.to_bits();
+        let Some(rhs) = NonZeroI128::new(rhs.to_bits()) else {
+            panic!("division by zero");
+        };
+        if lhs == i128::MIN && rhs.get() == -1 {
+            panic!("overflow");
+        }
+        let n = I256 { lo: 0, hi: lhs };
+        let (q, _) = int256::div_rem_i256_i128_no_overflow(n, rhs);
+        FixedI256::from_hi_lo(q.hi, q.lo)
+    }
+
+    /// Divides two fixed-point numbers and returns a wider type to retain more
+    /// precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>
+    /// integer bits, and `rhs` has <i>g</i> fractional bits and
+    /// 128&nbsp;&minus;&nbsp;<i>g</i> integer bits, then the returned
+    /// fixed-point number will have 127&nbsp;+&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>
+    /// fractional bits and 129&nbsp;&minus;&nbsp;<i>f</i>&nbsp;+&nbsp;<i>g</i>
+    /// integer bits.
+    ///
+    /// This is similar to the [`wide_div`][Self::wide_div] method but
+    /// sacrifices one fractional bit to avoid overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{FixedI128, FixedI256};
+    /// type Fix = FixedI128<4>;
+    /// // MIN / -DELTA = 2^123 / 2^-4 = 2^127 does not overflow
+    /// let ans: FixedI256<127> = Fix::MIN.wide_sdiv(-Fix::DELTA);
+    /// assert_eq!(ans.to_hi_lo(), (1 << 126, 0));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub const fn wide_sdiv<const RHS_FRAC: i32>(
+        self,
+        rhs: FixedI128<RHS_FRAC>,
+    ) -> FixedI256<{ 127 + FRAC - RHS_FRAC }> {
+        let lhs = self.to_bits();
+        let Some(rhs) = NonZeroI128::new(rhs.to_bits()) else {
+            panic!("division by zero");
+        };
+        let n = I256 {
+            lo: (lhs as u128) << 127,
+            hi: lhs >> 1,
+        };
+        let (q, _) = int256::div_rem_i256_i128_no_overflow(n, rhs);
+        FixedI256::from_hi_lo(q.hi, q.lo)
+    }
+
+    /// Divides by an unsigned fixed-point number and returns a wider signed
+    /// type to retain more precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>
+    /// integer bits, and `rhs` has <i>g</i> fractional bits and
+    /// 128&nbsp;&minus;&nbsp;<i>g</i> integer bits, then the returned
+    /// fixed-point number will have 128&nbsp;+&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>
+    /// fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>&nbsp;+&nbsp;<i>g</i>
+    /// integer bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{FixedI128, FixedI256, FixedU128};
+    /// // decimal: -4.625 / 0.03125 = -148
+    /// // binary: -100.101 / 0.00001 = -10010100
+    /// let a = FixedI128::<3>::from_num(-4.625);
+    /// let b = FixedU128::<5>::from_num(0.03125);
+    /// let ans: FixedI256<126> = a.wide_div_unsigned(b);
+    /// assert_eq!(ans.to_string(), "-148");
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub const fn wide_div_unsigned<const RHS_FRAC: i32>(
+        self,
+        rhs: FixedU128<RHS_FRAC>,
+    ) -> FixedI256<{ 128 + FRAC - RHS_FRAC }> {
+        let lhs = self.to_bits();
+        let Some(rhs) = NonZeroU128::new(rhs.to_bits()) else {
+            panic!("division by zero");
+        };
+        let n = U256 {
+            lo: 0,
+            hi: lhs.unsigned_abs(),
+        };
+        let (q, _) = int256::div_rem_u256_u128(n, rhs);
+        // the quotient magnitude is at most 2^255, which can be negated
+        let q = if lhs < 0 {
+            int256::wrapping_neg_u256(q)
+        } else {
+            q
+        };
+        FixedI256::from_hi_lo(q.hi as i128, q.lo)
+    }
+}
+
+/// Wide multiplication and division, which return 256-bit numbers.
+impl<const FRAC: i32> FixedU128<FRAC> {
+    /// Multiplies two fixed-point numbers and returns a wider type to retain
+    /// all precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>
+    /// integer bits, and `rhs` has <i>g</i> fractional bits and
+    /// 128&nbsp;&minus;&nbsp;<i>g</i> integer bits, then the returned
+    /// fixed-point number will have <i>f</i>&nbsp;+&nbsp;<i>g</i> fractional
+    /// bits and 256&nbsp;&minus;&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>
+    /// integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::FixedU128;
+    /// // decimal: 1.25 × 1.0625 = 1.328_125
+    /// // binary: 1.01 × 1.0001 = 1.010101
+    /// let a = FixedU128::<2>::from_num(1.25);
+    /// let b = FixedU128::<4>::from_num(1.0625);
+    /// assert_eq!(a.wide_mul(b).to_string(), "1.328125");
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn wide_mul<const RHS_FRAC: i32>(
+        self,
+        rhs: FixedU128<RHS_FRAC>,
+    ) -> FixedU256<{ FRAC + RHS_FRAC }> {
+        let prod = int256::wide_mul_u128(self.to_bits(), rhs.to_bits());
+        FixedU256::from_hi_lo(prod.hi, prod.lo)
+    }
+
+    /// Multiplies a signed fixed-point number and returns a wider signed type
+    /// to retain all precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>
+    /// integer bits, and `rhs` has <i>g</i> fractional bits and
+    /// 128&nbsp;&minus;&nbsp;<i>g</i> integer bits, then the returned
+    /// fixed-point number will have <i>f</i>&nbsp;+&nbsp;<i>g</i> fractional
+    /// bits and 256&nbsp;&minus;&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>
+    /// integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{FixedI128, FixedU128};
+    /// // decimal: 1.25 × -1.0625 = -1.328_125
+    /// // binary: 1.01 × -1.0001 = -1.010101
+    /// let a = FixedU128::<2>::from_num(1.25);
+    /// let b = FixedI128::<4>::from_num(-1.0625);
+    /// assert_eq!(a.wide_mul_signed(b).to_string(), "-1.328125");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn wide_mul_signed<const RHS_FRAC: i32>(
+        self,
+        rhs: FixedI128<RHS_FRAC>,
+    ) -> FixedI256<{ FRAC + RHS_FRAC }> {
+        let lhs_signed = self.to_bits() as i128;
+        let rhs = rhs.to_bits();
+        let mut prod = int256::wide_mul_i128(lhs_signed, rhs);
+        if lhs_signed < 0 {
+            // lhs msb treated as -2^127 instead of +2^127, so error in lhs is
+            // -2^128, and error in prod is -2^128 × rhs
+            prod.hi = prod.hi.wrapping_add(rhs);
+        }
+        FixedI256::from_hi_lo(prod.hi, prod.lo)
+    }
+
+    /// Divides two fixed-point numbers and returns a wider type to retain more
+    /// precision.
+    ///
+    /// If `self` has <i>f</i> fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>
+    /// integer bits, and `rhs` has <i>g</i> fractional bits and
+    /// 128&nbsp;&minus;&nbsp;<i>g</i> integer bits, then the returned
+    /// fixed-point number will have 128&nbsp;+&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>
+    /// fractional bits and 128&nbsp;&minus;&nbsp;<i>f</i>&nbsp;+&nbsp;<i>g</i>
+    /// integer bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{FixedU128, FixedU256};
+    /// // decimal: 4.625 / 0.03125 = 148
+    /// // binary: 100.101 / 0.00001 = 10010100
+    /// let a = FixedU128::<3>::from_num(4.625);
+    /// let b = FixedU128::<5>::from_num(0.03125);
+    /// let ans: FixedU256<126> = a.wide_div(b);
+    /// assert_eq!(ans.to_string(), "148");
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn wide_div<const RHS_FRAC: i32>(
+        self,
+        rhs: FixedU128<RHS_FRAC>,
+    ) -> FixedU256<{ 128 + FRAC - RHS_FRAC }> {
+        let Some(rhs) = NonZeroU128::new(rhs.to_bits()) else {
+            panic!("division by zero");
+        };
+        let n = U256 {
+            lo: 0,
+            hi: self.to_bits(),
+        };
+        let (q, _) = int256::div_rem_u256_u128(n, rhs);
+        FixedU256::from_hi_lo(q.hi, q.lo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rounding::tests::{round_ratio, ALL_MODES};
+    use crate::types::{I64F64, U64F64};
+    use crate::{FixedI128, FixedI256, FixedU128, FixedU256, RoundingMode};
+    use std::format;
+    #[cfg(not(feature = "std"))]
+    use std::string::ToString;
+
+    #[test]
+    fn wide_mul_exact() {
+        let vals = [
+            i128::MIN,
+            i128::MIN + 1,
+            -(1 << 64) - 3,
+            -1,
+            0,
+            1,
+            (1 << 64) + 5,
+            0x1234_5678_9ABC_DEF0_0FED_CBA9_8765_4321,
+            i128::MAX,
+        ];
+        for a in vals {
+            for b in vals {
+                let prod = I64F64::from_bits(a).wide_mul(I64F64::from_bits(b));
+                let (hi, lo) = prod.to_hi_lo();
+                // the low part is the product modulo 2^128
+                assert_eq!(lo, a.wrapping_mul(b) as u128, "{a} {b}");
+                let neg = (a < 0) != (b < 0) && a != 0 && b != 0;
+                assert_eq!(hi < 0, neg, "{a} {b}");
+                let (uhi, ulo) = U64F64::from_bits(a as u128)
+                    .wide_mul(U64F64::from_bits(b as u128))
+                    .to_hi_lo();
+                assert_eq!(ulo, lo);
+                // the signed and unsigned products differ by the corrections
+                // for the sign bits
+                let mut expected_hi = uhi as i128;
+                if a < 0 {
+                    expected_hi = expected_hi.wrapping_sub(b);
+                }
+                if b < 0 {
+                    expected_hi = expected_hi.wrapping_sub(a);
+                }
+                assert_eq!(hi, expected_hi, "{a} {b}");
+                let mixed =
+                    FixedI128::<64>::from_bits(a).wide_mul_unsigned(U64F64::from_bits(b as u128));
+                let expected_hi = if a < 0 {
+                    (uhi as i128).wrapping_sub(b)
+                } else {
+                    uhi as i128
+                };
+                assert_eq!(mixed.to_hi_lo(), (expected_hi, lo), "{a} {b}");
+                let mixed = U64F64::from_bits(a as u128).wide_mul_signed(I64F64::from_bits(b));
+                let expected_hi = if b < 0 {
+                    (uhi as i128).wrapping_sub(a)
+                } else {
+                    uhi as i128
+                };
+                assert_eq!(mixed.to_hi_lo(), (expected_hi, lo), "{a} {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn wide_div() {
+        for a in -300i128..=300 {
+            for b in [-7i128, -3, -1, 1, 2, 5, 64, 1000] {
+                // truncating twice toward zero is the same as truncating once
+                let exact = (a << 16) / b;
+                let q = FixedI128::<0>::from_bits(a).wide_div(FixedI128::<0>::from_bits(b));
+                let narrow = q.narrow_round::<16>(RoundingMode::ToZero);
+                assert_eq!(narrow.to_bits(), exact, "{a} {b}");
+                let s = FixedI128::<0>::from_bits(a).wide_sdiv(FixedI128::<0>::from_bits(b));
+                let narrow = s.narrow_round::<16>(RoundingMode::ToZero);
+                assert_eq!(narrow.to_bits(), exact, "{a} {b}");
+                if b > 0 {
+                    let u = FixedI128::<0>::from_bits(a)
+                        .wide_div_unsigned(FixedU128::<0>::from_bits(b as u128));
+                    assert_eq!(u, q);
+                }
+            }
+        }
+        let q = FixedU128::<0>::MAX.wide_div(FixedU128::<0>::from_bits(1));
+        assert_eq!(q.to_hi_lo(), (u128::MAX, 0));
+        let q = FixedI128::<0>::MIN.wide_div_unsigned(FixedU128::<0>::from_bits(1));
+        assert_eq!(q, FixedI256::MIN);
+    }
+
+    #[test]
+    fn mul() {
+        let vals = [
+            i128::MIN,
+            i128::MIN + 1,
+            -(1 << 64) - 3,
+            -1,
+            0,
+            1,
+            (1 << 64) + 5,
+            0x1234_5678_9ABC_DEF0_0FED_CBA9_8765_4321,
+            i128::MAX,
+        ];
+        for a in vals {
+            for b in vals {
+                let (fa, fb) = (I64F64::from_bits(a), I64F64::from_bits(b));
+                // the exact product shifted right by 64 is floored and fits
+                let exact = fa.wide_mul(fb) >> 64;
+                let (hi, lo) = exact.to_hi_lo();
+                let expected = FixedI256::<64>::from_hi_lo(hi, lo);
+                let prod = FixedI256::from(fa).overflowing_mul(FixedI256::from(fb));
+                assert_eq!(prod, (expected, false), "{a} {b}");
+                let (ua, ub) = (U64F64::from_bits(a as u128), U64F64::from_bits(b as u128));
+                let (hi, lo) = (ua.wide_mul(ub) >> 64).to_hi_lo();
+                let expected = FixedU256::<64>::from_hi_lo(hi, lo);
+                let prod = FixedU256::from(ua).overflowing_mul(FixedU256::from(ub));
+                assert_eq!(prod, (expected, false), "{a} {b}");
+            }
+        }
+
+        type I = FixedI256<0>;
+        type U = FixedU256<0>;
+        let minus_one = -I::DELTA;
+        assert_eq!(I::MIN.overflowing_mul(minus_one), (I::MIN, true));
+        assert_eq!(
+            I::MAX.overflowing_mul(minus_one),
+            (I::MIN + I::DELTA, false)
+        );
+        assert_eq!(I::MAX.saturating_mul(I::MAX), I::MAX);
+        assert_eq!(I::MIN.saturating_mul(I::MAX), I::MIN);
+        assert_eq!(I::MIN.checked_mul(I::MIN), None);
+        assert_eq!(U::MAX.overflowing_mul(U::MAX), (U::DELTA, true));
+        assert_eq!(U::MAX.saturating_mul(U::from_hi_lo(0, 2)), U::MAX);
+        let half_max = U::from_hi_lo(u128::MAX >> 1, u128::MAX);
+        assert_eq!(
+            half_max.checked_mul(U::from_hi_lo(0, 2)),
+            Some(U::MAX - U::DELTA)
+        );
+
+        // fractional bits outside 0 to 256
+        let d = FixedI256::<{ -4 }>::DELTA;
+        assert_eq!(d * d, FixedI256::from_hi_lo(0, 16));
+        let d = FixedU256::<300>::DELTA;
+        assert_eq!(d * d, FixedU256::ZERO);
+        let m = FixedU256::<300>::MAX;
+        let expected = FixedU256::from_hi_lo(0xF_FFFF_FFFF_FFFF_FFFF_FFFF, u128::MAX);
+        assert_eq!(m.overflowing_mul(m), (expected, false));
+        // flooring rounds toward −∞
+        let small = FixedI256::<300>::from_hi_lo(0, 1 << 100);
+        assert_eq!(small * -small, -FixedI256::DELTA);
+    }
+
+    #[test]
+    fn div() {
+        let vals = [
+            i128::MIN,
+            i128::MIN + 1,
+            -(1 << 64) - 3,
+            -7,
+            -1,
+            0,
+            1,
+            5,
+            (1 << 64) + 5,
+            0x1234_5678_9ABC_DEF0_0FED_CBA9_8765_4321,
+            i128::MAX,
+        ];
+        for a in vals {
+            for b in vals {
+                if b == 0 {
+                    continue;
+                }
+                let (fa, fb) = (I64F64::from_bits(a), I64F64::from_bits(b));
+                let q = FixedI256::from(fa).checked_div(FixedI256::from(fb));
+                if let Some(expected) = fa.checked_div(fb) {
+                    assert_eq!(q, Some(FixedI256::from(expected)), "{a} {b}");
+                }
+                let (ua, ub) = (U64F64::from_bits(a as u128), U64F64::from_bits(b as u128));
+                let q = FixedU256::from(ua).checked_div(FixedU256::from(ub));
+                if let Some(expected) = ua.checked_div(ub) {
+                    assert_eq!(q, Some(FixedU256::from(expected)), "{a} {b}");
+                }
+            }
+        }
+
+        // divisors wider than 128 bits
+        type U = FixedU256<0>;
+        let n = U::from_hi_lo(0x1234_5678_9ABC_DEF0_0FED_CBA9_8765_4321, 0xDEAD_BEEF);
+        for d in [
+            U::from_hi_lo(1, 0),
+            U::from_hi_lo(4, 3),
+            U::from_hi_lo(0x1234_5678, u128::MAX),
+            n,
+            U::MAX,
+        ] {
+            let q = n / d;
+            let r = n - q * d;
+            assert!(r < d, "{n:?} {d:?}");
+        }
+        assert_eq!(U::MAX.overflowing_div(U::DELTA), (U::MAX, false));
+        let x = FixedU256::<256>::from_hi_lo(1 << 126, 0);
+        assert_eq!(x / (x + x), FixedU256::from_hi_lo(1 << 127, 0));
+        assert_eq!(x.overflowing_div(x), (FixedU256::ZERO, true));
+
+        type I = FixedI256<0>;
+        assert_eq!(I::MIN.overflowing_div(-I::DELTA), (I::MIN, true));
+        assert_eq!(I::MIN.overflowing_div(I::DELTA), (I::MIN, false));
+        assert_eq!(I::MAX.saturating_div(-FixedI256::<0>::DELTA), -I::MAX);
+        assert_eq!(I::MAX.checked_div(I::ZERO), None);
+        let seven = I::from_hi_lo(0, 7);
+        let two = I::from_hi_lo(0, 2);
+        // division rounds toward zero
+        assert_eq!(seven / two, I::from_hi_lo(0, 3));
+        assert_eq!(-seven / two, -I::from_hi_lo(0, 3));
+        let signed = FixedI256::<4>::from_hi_lo(0, 1 << 3);
+        let twice_min = FixedI256::<4>::MIN.overflowing_div(signed);
+        assert_eq!(twice_min, (FixedI256::ZERO, true));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn div_by_zero() {
+        let _ = FixedU256::<4>::MAX / FixedU256::ZERO;
+    }
+
+    #[test]
+    fn shifts() {
+        type I = FixedI256<4>;
+        let x = I::from_hi_lo(-3, 5);
+        assert_eq!(x << 1, I::from_hi_lo(-6, 10));
+        assert_eq!(x >> 128, I::from_hi_lo(-1, -3i128 as u128));
+        assert_eq!(x.checked_shl(256), None);
+        assert_eq!(x.overflowing_shr(256 + 1), (x >> 1, true));
+        assert_eq!(I::MIN >> 255, -I::DELTA);
+        assert_eq!(FixedU256::<4>::MAX >> 255, FixedU256::DELTA);
+        assert_eq!(
+            FixedU256::<4>::DELTA.wrapping_shl(256 + 255),
+            FixedU256::from_hi_lo(1 << 127, 0)
+        );
+        let mut y = x;
+        y <<= 3;
+        y >>= 3;
+        assert_eq!(y, x);
+    }
+
+    #[test]
+    fn from_to_num() {
+        let vals = [
+            i128::MIN,
+            -(1 << 64) - 3,
+            -1,
+            0,
+            1,
+            0x1234_5678_9ABC_DEF0_0FED_CBA9_8765_4321,
+            i128::MAX,
+        ];
+        for a in vals {
+            let f = I64F64::from_bits(a);
+            let wide = FixedI256::<64>::from_num(f);
+            assert_eq!(wide, FixedI256::from(f));
+            assert_eq!(wide.to_num::<I64F64>(), f);
+            assert_eq!(FixedI256::<100>::from_num(a).to_num::<i128>(), a);
+            assert_eq!(
+                FixedI256::<{ -10 }>::from_num(a).to_num::<i128>(),
+                a >> 10 << 10
+            );
+            let u = U64F64::from_bits(a as u128);
+            let wide = FixedU256::<64>::from_num(u);
+            assert_eq!(wide, FixedU256::from(u));
+            assert_eq!(wide.to_num::<U64F64>(), u);
+            let expected = u64::try_from(a as u128).ok();
+            assert_eq!(
+                FixedU256::<100>::from_num(a as u128).checked_to_num::<u64>(),
+                expected
+            );
+        }
+
+        // floats round to the nearest with ties to even
+        type I = FixedI256<0>;
+        assert_eq!(I::from_num(2.5), I::from_hi_lo(0, 2));
+        assert_eq!(I::from_num(3.5), I::from_hi_lo(0, 4));
+        assert_eq!(I::from_num(-2.5), -I::from_hi_lo(0, 2));
+        assert_eq!(I::from_num(-0.25), I::ZERO);
+        assert_eq!(I::from_num(-0.75), -I::DELTA);
+        // the floor of the high part is negative, but the carry from rounding
+        // the low part brings it back to zero
+        assert_eq!(FixedU256::<0>::from_num(-0.25), FixedU256::ZERO);
+        assert_eq!(
+            FixedU256::<0>::checked_from_num(-0.25),
+            Some(FixedU256::ZERO)
+        );
+        assert_eq!(FixedU256::<0>::checked_from_num(-0.75), None);
+
+        let p200 = 2f64.powi(200);
+        assert_eq!(
+            FixedU256::<0>::from_num(p200),
+            FixedU256::from_hi_lo(1 << 72, 0)
+        );
+        assert_eq!(I::from_num(-p200), I::from_hi_lo(-1 << 72, 0));
+        assert_eq!(I::from_num(-p200).to_num::<f64>(), -p200);
+        let frac = FixedU256::<100>::from_num(1.0 / 3.0);
+        assert_eq!(frac.to_num::<f64>(), 1.0 / 3.0);
+        // more than 128 significant bits, needing the window 64 bits higher
+        let x = FixedU256::<0>::from_hi_lo(1 << 22, 1 << 100);
+        assert_eq!(x.to_num::<f64>(), 2f64.powi(150) + 2f64.powi(100));
+        let x = I::from_hi_lo(-(1 << 22), 1 << 100);
+        assert_eq!(x.to_num::<f64>(), -2f64.powi(150) + 2f64.powi(100));
+
+        // integer parts that need all 128 bits of an unsigned number
+        let x = FixedI256::<10>::from_hi_lo(1 << 9, 5 << 10 | 1 << 9);
+        assert_eq!(x.to_num::<u128>(), (1 << 127) + 5);
+        assert_eq!(x.checked_to_num::<i128>(), None);
+        assert_eq!(x.saturating_to_num::<i128>(), i128::MAX);
+        assert_eq!(x.wrapping_to_num::<u8>(), 5);
+        assert_eq!(FixedI256::<10>::MIN.saturating_to_num::<i64>(), i64::MIN);
+
+        // overflow
+        type Small = FixedI256<250>;
+        assert_eq!(Small::checked_from_num(100), None);
+        assert_eq!(Small::overflowing_from_num(64), (Small::ZERO, true));
+        assert_eq!(Small::saturating_from_num(-100), Small::MIN);
+        assert_eq!(Small::saturating_from_num(f64::INFINITY), Small::MAX);
+        assert_eq!(Small::checked_from_num(f64::NAN), None);
+        assert_eq!(Small::checked_from_num(31.5), Some(Small::from_num(31.5)));
+        assert_eq!(FixedU256::<0>::saturating_from_num(-1), FixedU256::ZERO);
+    }
+
+    #[test]
+    fn from_str() {
+        let strs = [
+            "0",
+            "-0",
+            "1.5",
+            "-1.5",
+            "0.1",
+            "-0.1",
+            "123.456",
+            "1.5e2",
+            "-2.5e-3",
+            "0.3333333333333333333333333333333333333333333333333333333333333333333333",
+            "9223372036854775807.5",
+            "-9223372036854775808",
+        ];
+        for s in strs {
+            let narrow = I64F64::from_str(s).unwrap();
+            assert_eq!(
+                FixedI256::<64>::from_str(s),
+                Ok(FixedI256::from(narrow)),
+                "{s}"
+            );
+            let parsed: FixedI256<64> = s.parse().unwrap();
+            assert_eq!(parsed, FixedI256::from(narrow), "{s}");
+            if let Ok(narrow) = U64F64::from_str(s) {
+                assert_eq!(
+                    FixedU256::<64>::from_str(s),
+                    Ok(FixedU256::from(narrow)),
+                    "{s}"
+                );
+            }
+        }
+
+        // 0.1 × 2^256 rounded to the nearest
+        let tenth = FixedU256::<256>::from_hi_lo(
+            0x1999_9999_9999_9999_9999_9999_9999_9999,
+            0x9999_9999_9999_9999_9999_9999_9999_999A,
+        );
+        assert_eq!(FixedU256::<256>::from_str("0.1"), Ok(tenth));
+        // 0.1 × 2^200 rounded down
+        let tenth = FixedU256::<200>::from_hi_lo(
+            0x19_9999_9999_9999_9999,
+            0x9999_9999_9999_9999_9999_9999_9999_9999,
+        );
+        assert_eq!(
+            FixedU256::<200>::from_str_round("0.1", RoundingMode::Floor),
+            Ok(tenth)
+        );
+        let ceil = FixedU256::<200>::from_str_round("0.1", RoundingMode::Ceil);
+        assert_eq!(ceil, Ok(tenth + FixedU256::DELTA));
+
+        type U = FixedU256<0>;
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        assert_eq!(U::from_str(max), Ok(U::MAX));
+        let over = "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert_eq!(U::overflowing_from_str(over), Ok((U::ZERO, true)));
+        assert_eq!(U::saturating_from_str(over), Ok(U::MAX));
+        assert!(U::from_str(over).is_err());
+        assert_eq!(U::from_str("2.5"), Ok(U::from_hi_lo(0, 2)));
+        assert_eq!(U::from_str("3.5"), Ok(U::from_hi_lo(0, 4)));
+        assert_eq!(
+            U::from_str("2.5000000000000000000001"),
+            Ok(U::from_hi_lo(0, 3))
+        );
+        assert_eq!(U::overflowing_from_str("-1"), Ok((U::MAX, true)));
+        assert!(U::from_str("1..5").is_err());
+
+        type I = FixedI256<256>;
+        assert_eq!(I::from_str("-0.5"), Ok(I::MIN));
+        assert_eq!(I::overflowing_from_str("0.5"), Ok((I::MIN, true)));
+        assert_eq!(I::saturating_from_str("0.5"), Ok(I::MAX));
+        assert_eq!(
+            I::wrapping_from_str("0.75"),
+            Ok(I::from_hi_lo(-1 << 126, 0))
+        );
+    }
+
+    #[test]
+    fn add_sub_neg() {
+        type I = FixedI256<0>;
+        type U = FixedU256<0>;
+        assert_eq!(I::MAX.overflowing_add(I::DELTA), (I::MIN, true));
+        assert_eq!(I::MIN.overflowing_add(-I::DELTA), (I::MAX, true));
+        assert_eq!(
+            I::from_hi_lo(0, u128::MAX).overflowing_add(I::DELTA),
+            (I::from_hi_lo(1, 0), false)
+        );
+        assert_eq!(
+            I::from_hi_lo(-1, 0).overflowing_add(I::from_hi_lo(0, u128::MAX)),
+            (-I::DELTA, false)
+        );
+        assert_eq!(I::MIN.overflowing_sub(I::DELTA), (I::MAX, true));
+        assert_eq!(I::MAX.overflowing_sub(-I::DELTA), (I::MIN, true));
+        assert_eq!(
+            I::from_hi_lo(1, 0).overflowing_sub(I::DELTA),
+            (I::from_hi_lo(0, u128::MAX), false)
+        );
+        assert_eq!(I::MIN.overflowing_neg(), (I::MIN, true));
+        assert_eq!(I::MAX.overflowing_neg(), (I::MIN + I::DELTA, false));
+        assert_eq!(I::MAX.saturating_add(I::MAX), I::MAX);
+        assert_eq!(I::MIN.saturating_add(I::MIN), I::MIN);
+        assert_eq!(I::MIN.saturating_sub(I::MAX), I::MIN);
+        assert_eq!(I::MAX.saturating_sub(I::MIN), I::MAX);
+        assert_eq!(U::MAX.overflowing_add(U::DELTA), (U::ZERO, true));
+        assert_eq!(U::ZERO.overflowing_sub(U::DELTA), (U::MAX, true));
+        assert_eq!(
+            U::from_hi_lo(1, 0).overflowing_sub(U::DELTA),
+            (U::from_hi_lo(0, u128::MAX), false)
+        );
+        assert!(I::MIN < -I::DELTA && -I::DELTA < I::ZERO && I::ZERO < I::MAX);
+        assert!(U::from_hi_lo(0, u128::MAX) < U::from_hi_lo(1, 0));
+    }
+
+    #[test]
+    fn narrow_round() {
+        // exhaustive over small values with every shift around the boundaries
+        for bits in -40i128..=40 {
+            let x = FixedI256::<8>::from(FixedI128::<8>::from_bits(bits));
+            for mode in ALL_MODES {
+                let ans = x.overflowing_narrow_round::<5>(mode);
+                let expected = round_ratio(bits, 8, mode);
+                assert_eq!(
+                    (ans.0.to_bits(), ans.1),
+                    (expected, false),
+                    "{bits} {mode:?}"
+                );
+                let ans = x.overflowing_narrow_round::<{ 8 - 300 }>(mode);
+                // all bits are discarded, and the discarded part is tiny
+                let expected = match mode {
+                    RoundingMode::Floor => -i128::from(bits < 0),
+                    RoundingMode::Ceil => i128::from(bits > 0),
+                    RoundingMode::AwayFromZero => bits.signum(),
+                    _ => 0,
+                };
+                assert_eq!(
+                    (ans.0.to_bits(), ans.1),
+                    (expected, false),
+                    "{bits} {mode:?}"
+                );
+            }
+            let ans = x.overflowing_narrow_round::<10>(RoundingMode::Floor);
+            assert_eq!(ans, (FixedI128::from_bits(bits << 2), false));
+        }
+        // values just outside the narrow range
+        let above = FixedI256::<0>::from_hi_lo(0, 1 << 127);
+        assert_eq!(
+            above.overflowing_narrow_round::<0>(RoundingMode::Floor),
+            (FixedI128::MIN, true)
+        );
+        assert_eq!(
+            above.overflowing_narrow_round::<{ -1 }>(RoundingMode::Floor),
+            (FixedI128::from_bits(1 << 126), false)
+        );
+        assert_eq!(
+            above.saturating_narrow_round::<0>(RoundingMode::Floor),
+            FixedI128::<0>::MAX
+        );
+        let below = -above - FixedI256::DELTA;
+        assert_eq!(
+            below.saturating_narrow_round::<0>(RoundingMode::Floor),
+            FixedI128::<0>::MIN
+        );
+        // rounding up can overflow
+        let max = FixedI256::<1>::from_hi_lo(0, u128::MAX);
+        assert_eq!(
+            max.overflowing_narrow_round::<0>(RoundingMode::Ceil),
+            (FixedI128::MIN, true)
+        );
+        // exact product of two I64F64 numbers rounded once
+        let a = I64F64::from_bits(3);
+        let b = I64F64::from_bits(1 << 63);
+        let prod = a.wide_mul(b);
+        assert_eq!(
+            prod.narrow_round::<64>(RoundingMode::HalfEven),
+            I64F64::from_bits(2)
+        );
+        assert_eq!(
+            prod.narrow_round::<64>(RoundingMode::HalfToZero),
+            I64F64::from_bits(1)
+        );
+        assert_eq!(
+            (-prod).narrow_round::<64>(RoundingMode::Floor),
+            I64F64::from_bits(-2)
+        );
+        let u = FixedU256::<4>::from_hi_lo(16, 0b11000);
+        assert_eq!(
+            u.overflowing_narrow_round::<0>(RoundingMode::HalfEven),
+            (FixedU128::from_bits(2), true)
+        );
+    }
+
+    #[test]
+    fn round_int() {
+        // small values agree with the 128-bit rounding methods
+        for bits in -40i128..=40 {
+            let narrow = FixedI128::<3>::from_bits(bits);
+            let x = FixedI256::from(narrow);
+            assert_eq!(x.int(), narrow.int().into(), "{bits}");
+            assert_eq!(x.frac(), narrow.frac().into(), "{bits}");
+            assert_eq!(x.round_to_zero(), narrow.round_to_zero().into(), "{bits}");
+            assert_eq!(x.floor(), narrow.floor().into(), "{bits}");
+            assert_eq!(x.ceil(), narrow.ceil().into(), "{bits}");
+            assert_eq!(x.round(), narrow.round().into(), "{bits}");
+            assert_eq!(
+                x.round_ties_to_even(),
+                narrow.round_ties_to_even().into(),
+                "{bits}"
+            );
+            let unarrow = FixedU128::<3>::from_bits(bits.unsigned_abs());
+            let u = FixedU256::from(unarrow);
+            assert_eq!(u.ceil(), unarrow.ceil().into(), "{bits}");
+            assert_eq!(u.round(), unarrow.round().into(), "{bits}");
+            assert_eq!(
+                u.round_ties_to_even(),
+                unarrow.round_ties_to_even().into(),
+                "{bits}"
+            );
+        }
+        // the fractional bits span both words
+        let x = FixedI256::<130>::from_hi_lo(-5, 1);
+        assert_eq!(x.floor(), FixedI256::from_hi_lo(-8, 0));
+        assert_eq!(x.ceil(), FixedI256::from_hi_lo(-4, 0));
+        assert_eq!(x.round_to_zero(), FixedI256::from_hi_lo(-4, 0));
+        assert_eq!(x.frac(), FixedI256::from_hi_lo(3, 1));
+        // the tie is in the high word
+        let tie = FixedU256::<130>::from_hi_lo(6, 0);
+        assert_eq!(tie.round(), FixedU256::from_hi_lo(8, 0));
+        assert_eq!(tie.round_ties_to_even(), FixedU256::from_hi_lo(8, 0));
+        let tie = FixedU256::<130>::from_hi_lo(2, 0);
+        assert_eq!(tie.round_ties_to_even(), FixedU256::ZERO);
+        // no integer bits
+        type I = FixedI256<256>;
+        type U = FixedU256<256>;
+        assert_eq!(I::MIN.overflowing_floor(), (I::ZERO, true));
+        assert_eq!(I::MIN.overflowing_round(), (I::ZERO, true));
+        assert_eq!(I::MIN.overflowing_round_ties_to_even(), (I::ZERO, false));
+        assert_eq!(I::MIN.overflowing_ceil(), (I::ZERO, false));
+        assert_eq!((-I::DELTA).overflowing_ceil(), (I::ZERO, false));
+        assert_eq!(I::DELTA.overflowing_ceil(), (I::ZERO, true));
+        assert_eq!(I::MIN.int(), I::ZERO);
+        assert_eq!(I::MIN.frac(), I::MIN);
+        assert_eq!(U::MAX.overflowing_floor(), (U::ZERO, false));
+        assert_eq!(U::MAX.overflowing_round(), (U::ZERO, true));
+        assert_eq!(U::DELTA.overflowing_round(), (U::ZERO, false));
+        assert_eq!(U::DELTA.saturating_ceil(), U::MAX);
+        // no fractional bits
+        let x = FixedI256::<0>::from_hi_lo(-3, 5);
+        assert_eq!(x.overflowing_round(), (x, false));
+        assert_eq!(x.frac(), FixedI256::ZERO);
+        // saturation when rounding to the nearest
+        assert_eq!(FixedI256::<1>::MAX.saturating_round(), FixedI256::MAX);
+        assert_eq!(FixedI256::<1>::MIN.saturating_round(), FixedI256::MIN);
+        assert_eq!(I::MIN.saturating_round(), I::MIN);
+    }
+
+    #[test]
+    fn bits_bytes_abs() {
+        let x = FixedI256::<4>::from_hi_lo(-2, 0x0102);
+        let be = x.to_be_bytes();
+        assert_eq!(be[..16], (-2i128).to_be_bytes());
+        assert_eq!(be[16..], 0x0102u128.to_be_bytes());
+        let le = x.to_le_bytes();
+        assert_eq!(le[..16], 0x0102u128.to_le_bytes());
+        assert_eq!(le[16..], (-2i128).to_le_bytes());
+        assert_eq!(FixedI256::from_be_bytes(be), x);
+        assert_eq!(FixedI256::from_le_bytes(le), x);
+        assert_eq!(FixedI256::from_ne_bytes(x.to_ne_bytes()), x);
+        let u = FixedU256::<4>::from_be_bytes(be);
+        assert_eq!(u.to_hi_lo(), ((-2i128) as u128, 0x0102));
+        assert_eq!(u.to_le_bytes(), le);
+
+        assert_eq!(x.count_ones(), 127 + 2);
+        assert_eq!(x.count_zeros(), 256 - 129);
+        assert_eq!(x.leading_ones(), 127);
+        assert_eq!(x.leading_zeros(), 0);
+        assert_eq!(x.trailing_zeros(), 1);
+        assert_eq!(FixedU256::<4>::from_hi_lo(0, 1).leading_zeros(), 255);
+        assert_eq!(FixedU256::<4>::from_hi_lo(4, 0).trailing_zeros(), 130);
+        assert_eq!(FixedU256::<4>::MAX.leading_ones(), 256);
+        assert_eq!(FixedU256::<4>::MAX.trailing_ones(), 256);
+        assert_eq!(FixedU256::<4>::ZERO.leading_zeros(), 256);
+
+        let y = FixedI256::<4>::from_hi_lo(3, 0xFF00);
+        assert_eq!(!x, FixedI256::from_hi_lo(1, !0x0102));
+        assert_eq!(x & y, FixedI256::from_hi_lo(2, 0x0100));
+        assert_eq!(x | y, FixedI256::from_hi_lo(-1, 0xFF02));
+        assert_eq!(x ^ y, FixedI256::from_hi_lo(-3, 0xFE02));
+        let mut z = x;
+        z &= y;
+        z |= FixedI256::DELTA;
+        z ^= FixedI256::from_hi_lo(0, 3);
+        assert_eq!(z, FixedI256::from_hi_lo(2, 0x0102));
+
+        assert_eq!(x.overflowing_abs(), (-x, false));
+        assert_eq!(x.unsigned_abs().to_hi_lo(), (1, 0u128.wrapping_sub(0x0102)));
+        assert_eq!((-x).abs(), -x);
+        type I = FixedI256<0>;
+        assert_eq!(I::MIN.overflowing_abs(), (I::MIN, true));
+        assert_eq!(I::MIN.checked_abs(), None);
+        assert_eq!(I::MIN.saturating_abs(), I::MAX);
+        assert_eq!(I::MIN.unsigned_abs().to_hi_lo(), (1 << 127, 0));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(FixedI256::<0>::ZERO.to_string(), "0");
+        assert_eq!(
+            FixedI256::<0>::MIN.to_string(),
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+        assert_eq!(
+            FixedU256::<0>::MAX.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(FixedU256::<256>::DELTA.to_string().len(), "0.".len() + 256);
+        assert_eq!(FixedI256::<256>::MIN.to_string(), "-0.5");
+        assert_eq!(
+            FixedI256::<128>::from_hi_lo(-3, 1 << 126).to_string(),
+            "-2.75"
+        );
+        let x = FixedI256::<64>::from(I64F64::from_num(12.25));
+        assert_eq!(format!("{x:>8}"), "   12.25");
+        assert_eq!(format!("{:+}", FixedU256::<1>::from_hi_lo(0, 3)), "+1.5");
+        assert_eq!(
+            format!("{:08}", FixedI256::<1>::from_hi_lo(-1, !2)),
+            "-00001.5"
+        );
+        assert_eq!(format!("{:?}", FixedI256::<{ -2 }>::DELTA), "(1 << 2)");
+        assert_eq!(format!("{:?}", FixedU256::<300>::DELTA), "(1 >> 300)");
+        assert_eq!(format!("{:?}", FixedI256::<2>::from_hi_lo(-1, !0)), "-0.25");
+    }
+}
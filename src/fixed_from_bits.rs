@@ -14,8 +14,11 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
-    traits::FixedBits, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16,
-    FixedU32, FixedU64, FixedU8,
+    int_helper,
+    rounding::{Discarded, RoundingMode},
+    traits::FixedBits,
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
 };
 use core::mem;
 
@@ -45,6 +48,37 @@ pub(crate) fn src_shift(dst_frac: i32, src_frac: i32, src_bits: u32) -> Shift {
     }
 }
 
+// Truncates abs by shift bits, returning the truncated magnitude and the
+// discarded part.
+#[inline]
+fn truncate_u128(abs: u128, shift: u32) -> (u128, Discarded) {
+    const HALF: u128 = 1 << 127;
+    let (trunc, rem) = if shift == 0 {
+        return (abs, Discarded::Zero);
+    } else if shift < 128 {
+        (abs >> shift, abs << (128 - shift))
+    } else if shift == 128 {
+        (0, abs)
+    } else {
+        let discarded = if abs == 0 {
+            Discarded::Zero
+        } else {
+            Discarded::LessThanHalf
+        };
+        return (0, discarded);
+    };
+    let discarded = if rem == 0 {
+        Discarded::Zero
+    } else if rem < HALF {
+        Discarded::LessThanHalf
+    } else if rem == HALF {
+        Discarded::Half
+    } else {
+        Discarded::GreaterThanHalf
+    };
+    (trunc, discarded)
+}
+
 macro_rules! impl_fixed_from_bits {
     ($Fixed:ident($Inner:ident, $nbits:expr), $InnerI:ident, $InnerU:ident) => {
         impl<const FRAC: i32> $Fixed<FRAC> {
@@ -81,6 +115,11 @@ macro_rules! impl_fixed_from_bits {
                         // the cast would then overflow.
                         // Casting first would produce unsigned 01.00 which can then be shifted.
                         let (cast, overflow1): ($Inner, bool) = src.overflowing_cast();
+                        // dst can be narrower than src, so all its bits can be
+                        // shifted out
+                        if shift >= $nbits {
+                            return ($Fixed::ZERO, overflow1 || cast != 0);
+                        }
                         let shifted = cast << shift;
                         let overflow2 = (shifted >> shift) != cast;
                         ($Fixed::from_bits(shifted), overflow1 || overflow2)
@@ -101,6 +140,35 @@ macro_rules! impl_fixed_from_bits {
                     }
                 }
             }
+
+            pub(crate) fn fixed_from_bits_round<Src>(
+                src: Src,
+                src_frac: i32,
+                mode: RoundingMode,
+            ) -> ($Fixed<FRAC>, bool)
+            where
+                Src: FixedBits,
+            {
+                if src_frac <= FRAC {
+                    return $Fixed::fixed_from_bits(src, src_frac);
+                }
+                let src_is_signed = !Src::overflowing_cast_from(-1i8).1;
+                let (neg, abs) = if src_is_signed {
+                    let (val, overflow): (i128, bool) = src.overflowing_cast();
+                    debug_assert!(!overflow);
+                    (val < 0, val.unsigned_abs())
+                } else {
+                    let (val, overflow): (u128, bool) = src.overflowing_cast();
+                    debug_assert!(!overflow);
+                    (false, val)
+                };
+                let shift = i64::from(src_frac) - i64::from(FRAC);
+                let shift = if shift > 129 { 129 } else { shift as u32 };
+                let (trunc, discarded) = truncate_u128(abs, shift);
+                let (abs, overflow1) = mode.round_u128(neg, trunc, discarded);
+                let (bits, overflow2) = int_helper::$Inner::overflowing_from_neg_abs(neg, abs);
+                ($Fixed::from_bits(bits), overflow1 || overflow2)
+            }
         }
     };
 }
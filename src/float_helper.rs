@@ -18,7 +18,9 @@ macro_rules! make_helper {
         #[allow(non_snake_case)]
         pub mod $Float {
             use crate::{
+                arith,
                 fixed_from_bits::{self, Shift},
+                rounding::{Discarded, RoundingMode},
                 traits::Fixed,
             };
             use az::OverflowingCastFrom;
@@ -30,6 +32,7 @@ macro_rules! make_helper {
                 neg: bool,
                 abs: $Bits,
                 frac_bits: i32,
+                mode: RoundingMode,
             ) -> $Float {
                 debug_assert!(abs.leading_zeros() == 0);
 
@@ -39,15 +42,28 @@ macro_rules! make_helper {
                 let mut mantissa = abs << 1;
                 let exponent = ($Bits::BITS as i32 - 1).saturating_sub(frac_bits);
                 let biased_exponent = if exponent > EXP_MAX {
-                    return $Float::from_bits(EXP_MASK | bits_sign);
+                    // infinity, or the largest finite number if the mode
+                    // rounds toward zero
+                    return if mode.increment(neg, true, Discarded::GreaterThanHalf) {
+                        $Float::from_bits(EXP_MASK | bits_sign)
+                    } else {
+                        $Float::from_bits((EXP_MASK - 1) | bits_sign)
+                    };
                 } else if exponent < EXP_MIN {
                     let lost_prec = EXP_MIN - exponent;
                     if lost_prec as u32 >= $Bits::BITS {
-                        mantissa = 0;
+                        // only keep a sticky bit, which is below the mid bit
+                        mantissa = 1;
                     } else {
                         // reinsert implicit one for subnormals (SIGN_MASK is most significant bit)
                         mantissa = (mantissa >> 1) | SIGN_MASK;
-                        mantissa >>= lost_prec - 1;
+                        // keep any shifted out bits as a sticky bit, which is below the mid bit
+                        let shift = lost_prec as u32 - 1;
+                        let sticky = shift > 0 && mantissa & ((1 << shift) - 1) != 0;
+                        mantissa >>= shift;
+                        if sticky {
+                            mantissa |= 1;
+                        }
                     }
                     0
                 } else {
@@ -57,14 +73,13 @@ macro_rules! make_helper {
                 let round_up = {
                     let mid_bit = SIGN_MASK >> (PREC - 1);
                     let lower_bits = mid_bit - 1;
-                    if mantissa & mid_bit == 0 {
-                        false
-                    } else if mantissa & lower_bits != 0 {
-                        true
-                    } else {
-                        // round to even
-                        mantissa & (mid_bit << 1) != 0
-                    }
+                    let discarded = match (mantissa & mid_bit != 0, mantissa & lower_bits != 0) {
+                        (false, false) => Discarded::Zero,
+                        (false, true) => Discarded::LessThanHalf,
+                        (true, false) => Discarded::Half,
+                        (true, true) => Discarded::GreaterThanHalf,
+                    };
+                    mode.increment(neg, mantissa & (mid_bit << 1) != 0, discarded)
                 };
                 let bits_exp = biased_exponent << (PREC - 1);
                 let bits_mantissa = mantissa >> ($Bits::BITS - (PREC - 1));
@@ -75,7 +90,35 @@ macro_rules! make_helper {
                 $Float::from_bits(bits_sign | bits_exp_mantissa)
             }
 
-            pub fn overflowing_to_fixed<Dst: Fixed>(src: $Float) -> (Dst, bool) {
+            // Returns the bits discarded when src is converted to a fixed-point
+            // number with dst_frac fractional bits, as returned by
+            // arith::discarded_bits for the two's complement representation.
+            pub fn discarded_bits(src: $Float, dst_frac: i32) -> u128 {
+                let (neg, abs, src_frac) = match kind(src) {
+                    Kind::NaN => panic!("NaN"),
+                    Kind::Infinite { .. } => panic!("infinite"),
+                    Kind::Finite {
+                        neg,
+                        abs,
+                        frac_bits,
+                    } => (neg, abs, frac_bits),
+                };
+                let shift = i64::from(src_frac) - i64::from(dst_frac);
+                if shift <= 0 {
+                    return 0;
+                }
+                let shift = if shift > 384 { 384 } else { shift as u32 };
+                // abs is never zero when neg is true
+                let abs = u128::from(abs);
+                let (lo, ext) = if neg {
+                    (abs.wrapping_neg(), u128::MAX)
+                } else {
+                    (abs, 0)
+                };
+                arith::discarded_bits(ext, lo, ext, shift)
+            }
+
+            pub fn overflowing_to_fixed<Dst: Fixed>(src: $Float, mode: RoundingMode) -> (Dst, bool) {
                 // the most significant bits of src are zero, because of the
                 // sign bit and exponent bits in floating-point representations
                 let (neg, mut abs, mut src_frac) = match kind(src) {
@@ -96,16 +139,37 @@ macro_rules! make_helper {
                         let will_be_lsb = 1 << shift;
                         let removed_bits = abs & (will_be_lsb - 1);
                         let tie = will_be_lsb >> 1;
-                        if removed_bits > tie || (removed_bits == tie && (abs & will_be_lsb) != 0) {
-                            // either closer to next up (> tie)
-                            // or tie and currently odd (prospective lsb != 0)
-                            // so we round up
-                            abs += will_be_lsb;
-                        }
+                        let discarded = if removed_bits == 0 {
+                            Discarded::Zero
+                        } else if removed_bits < tie {
+                            Discarded::LessThanHalf
+                        } else if removed_bits == tie {
+                            Discarded::Half
+                        } else {
+                            Discarded::GreaterThanHalf
+                        };
                         abs >>= shift;
+                        // abs cannot overflow as its most significant bits are zero
+                        if mode.increment(neg, abs & 1 != 0, discarded) {
+                            abs += 1;
+                        }
                         src_frac -= shift as i32;
                     }
-                    Shift::RightAll => return (Dst::ZERO, false),
+                    Shift::RightAll => {
+                        // the most significant bit of abs is zero, so less
+                        // than half of the least significant bit of Dst is
+                        // discarded
+                        let discarded = if abs == 0 {
+                            Discarded::Zero
+                        } else {
+                            Discarded::LessThanHalf
+                        };
+                        if !mode.increment(neg, false, discarded) {
+                            return (Dst::ZERO, false);
+                        }
+                        abs = 1;
+                        src_frac = Dst::FRAC_BITS;
+                    }
                     _ => {}
                 }
 
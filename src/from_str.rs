@@ -15,6 +15,7 @@
 
 use crate::{
     bytes::{DigitsExp, DigitsUnds},
+    rounding::Discarded,
     types::extra::{If, True},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
@@ -39,36 +40,35 @@ use std::error::Error;
 //
 //     0 ≤ val ≤ 10^DEC - 1, 0 ≤ nbits ≤ BIN
 //
-// We can either floor the result or round to the nearest, with ties rounded to
-// even. If rounding results in more than nbits bits, returns None.
+// The result is floored, and the discarded part is returned too, so that the
+// caller can round the result using any rounding mode.
 //
 // Examples: (for DEC = 3, BIN = 8)
 //
-//    dec_to_bin(999, 8, Round::Floor) -> floor(999 × 256 / 1000) -> 255 -> Some(255)
-//    dec_to_bin(999, 8, Round::Nearest) -> floor(999 × 256 / 1000 + 0.5) -> 256 -> None
-//    dec_to_bin(999, 5, Round::Floor) -> floor(999 × 32 / 1000) -> 31 -> Some(31)
-//    dec_to_bin(999, 5, Round::Nearest) -> floor(999 × 32 / 1000 + 0.5) -> 32 -> None
-//    dec_to_bin(499, 0, Round::Floor) -> floor(499 / 1000) -> 0 -> Some(0)
-//    dec_to_bin(499, 0, Round::Nearest) -> floor(499 / 1000 + 0.5) -> 0 -> Some(0)
-//    dec_to_bin(500, 0, Round::Nearest) -> floor(500 / 1000 + 0.5) -> 1 -> None
+//    dec_to_bin(999, 8) -> floor(999 × 256 / 1000) -> 255, discarded 0.744 -> (255, GreaterThanHalf)
+//    dec_to_bin(999, 5) -> floor(999 × 32 / 1000) -> 31, discarded 0.968 -> (31, GreaterThanHalf)
+//    dec_to_bin(499, 0) -> floor(499 / 1000) -> 0, discarded 0.499 -> (0, LessThanHalf)
+//    dec_to_bin(500, 0) -> floor(500 / 1000) -> 0, discarded 0.5 -> (0, Half)
+//    dec_to_bin(125, 3) -> floor(125 × 8 / 1000) -> 1, discarded 0 -> (1, Zero)
 //
 // For flooring:
 //
-//     floor(val × 2^nbits / 10^3) = floor(val × 2^(nbits - 3) / 5^3)
+//     floor(val × 2^nbits / 10^3) = floor((val × 2^(nbits - 2)) / (2 × 5^3))
 //
-// For rounding:
-//
-//     floor(val × 2^nbits / 10^3 + 0.5) = floor((val × 2^(nbits - 2) + 5^3) / (2 × 5^3))
+// and the discarded part is compared to one half by comparing the remainder to
+// 5^3.
 //
 // Using integer arithmetic, this is equal to:
 //
-//     ((val << 6 >> (8 - nbits)) + if rounding { 125 } else { 0 }) / 250
+//     (val << 6 >> (8 - nbits)) / 250
 //
 // Note that val << 6 cannot overflow u16, as val < 1000 and 1000 × 2^6 < 2^16.
+// The right shift can discard some bits, which makes the discarded part a
+// little larger than the remainder.
 //
 // In general:
 //
-//     ((val << (BIN - DEC + 1) >> (8 - nbits)) + if rounding { 5^DEC } else { 0 }) / (2 × 5^DEC)
+//     (val << (BIN - DEC + 1) >> (8 - nbits)) / (2 × 5^DEC)
 //
 // And we ensure that 10^DEC × 2^(BIN - DEC + 1) < 2^(2 × BIN), which simplifies to
 //
@@ -92,9 +92,14 @@ use std::error::Error;
 //       - pub const fn saturating_from_str_radix
 //       - pub const fn wrapping_from_str_radix
 //       - pub const fn overflowing_from_str_radix
+//       - pub const fn from_str_radix_round
+//       - pub const fn overflowing_from_str_radix_round
 macro_rules! all {
     ($Single:ident) => {
-        use crate::from_str::{ParseErrorKind, ParseFixedError, Sep};
+        use crate::{
+            from_str::{ParseErrorKind, ParseFixedError, Sep},
+            rounding::RoundingMode,
+        };
 
         #[inline]
         pub const fn from_str_radix(
@@ -152,9 +157,35 @@ macro_rules! all {
             s: &str,
             radix: u32,
             frac_nbits: u32,
+        ) -> Result<($Single, bool), ParseFixedError> {
+            overflowing_from_str_radix_round(s, radix, frac_nbits, RoundingMode::HalfEven)
+        }
+
+        #[inline]
+        pub const fn from_str_radix_round(
+            s: &str,
+            radix: u32,
+            frac_nbits: u32,
+            mode: RoundingMode,
+        ) -> Result<$Single, ParseFixedError> {
+            match overflowing_from_str_radix_round(s, radix, frac_nbits, mode) {
+                Ok((val, false)) => Ok(val),
+                Ok((_, true)) => Err(ParseFixedError {
+                    kind: ParseErrorKind::Overflow,
+                }),
+                Err(e) => Err(e),
+            }
+        }
+
+        #[inline]
+        pub const fn overflowing_from_str_radix_round(
+            s: &str,
+            radix: u32,
+            frac_nbits: u32,
+            mode: RoundingMode,
         ) -> Result<($Single, bool), ParseFixedError> {
             let bytes = s.as_bytes();
-            match from_str(bytes, radix, Sep::Error, frac_nbits) {
+            match from_str(bytes, radix, Sep::Error, frac_nbits, mode) {
                 Ok(val) => Ok(val),
                 Err(kind) => Err(ParseFixedError { kind }),
             }
@@ -179,6 +210,7 @@ macro_rules! signed {
                 radix: u32,
                 sep: Sep,
                 frac_nbits: u32,
+                mode: RoundingMode,
             ) -> Result<($Single, bool), ParseErrorKind> {
                 let (neg, abs, mut overflow) = match crate::from_str::$Uns::get_int_frac(
                     bytes,
@@ -186,6 +218,7 @@ macro_rules! signed {
                     sep,
                     $Single::BITS - frac_nbits,
                     frac_nbits,
+                    mode,
                 ) {
                     Ok((neg, abs, overflow)) => (neg, abs, overflow),
                     Err(e) => return Err(e),
@@ -252,13 +285,16 @@ signed! { i128, u128 }
 //       - const fn hex_str_frac_to_bin
 //       - pub(super) const fn dec_str_int_to_bin
 //       - const fn dec_str_frac_to_bin
+//       - const fn cmp_dec_frac
 //       - const fn from_byte
 //       - pub(super) const fn is_odd
 macro_rules! unsigned {
     ($Uns:ident $(, $Half:ident)?) => {
-        use crate::from_str::{
-            frac_is_half, parse_bounds, unchecked_hex_digit, BitExp, DigitsExp, Parse, Round,
+        use crate::{
+            from_str::{discarded, parse_bounds, unchecked_hex_digit, BitExp, DigitsExp, Parse},
+            rounding::Discarded,
         };
+        use core::cmp::Ordering;
 
         all! { $Uns }
 
@@ -267,9 +303,10 @@ macro_rules! unsigned {
             radix: u32,
             sep: Sep,
             frac_nbits: u32,
+            mode: RoundingMode,
         ) -> Result<($Uns, bool), ParseErrorKind> {
             let (neg, abs, mut overflow) =
-                match get_int_frac(bytes, radix, sep, $Uns::BITS - frac_nbits, frac_nbits) {
+                match get_int_frac(bytes, radix, sep, $Uns::BITS - frac_nbits, frac_nbits, mode) {
                     Ok((neg, abs, overflow)) => (neg, abs, overflow),
                     Err(e) => return Err(e),
                 };
@@ -314,7 +351,7 @@ macro_rules! unsigned {
             if let Some((b'-' | b'+', _)) = bytes.split_first() {
                 return Err(ParseErrorKind::MisplacedSign);
             }
-            match from_str(bytes, radix, Sep::Skip, frac_nbits) {
+            match from_str(bytes, radix, Sep::Skip, frac_nbits, RoundingMode::HalfEven) {
                 Ok((val, false)) => Ok(val),
                 Ok((_, true)) => Err(ParseErrorKind::Overflow),
                 Err(e) => Err(e),
@@ -327,6 +364,7 @@ macro_rules! unsigned {
             sep: Sep,
             int_nbits: u32,
             frac_nbits: u32,
+            mode: RoundingMode,
         ) -> Result<(bool, $Uns, bool), ParseErrorKind> {
             let Parse {
                 neg,
@@ -338,23 +376,12 @@ macro_rules! unsigned {
                 Err(e) => return Err(e),
             };
             let (int_val, mut overflow) = get_int(int, radix, int_nbits, bit_exp);
-            let (frac_val, frac_overflow) = match get_frac(frac, radix, frac_nbits, bit_exp) {
-                Some(val) => (val, false),
-                None => (0, true),
-            };
+            let (frac_val, discarded) = get_frac(frac, radix, frac_nbits, bit_exp);
             let mut val = int_val | frac_val;
-            // frac_overflow does not catch the case where:
-            //  1. int is odd
-            //  2. frac_nbits is 0
-            //  3. frac_bytes is exactly half, e.g. "5" for decimal
-            // In this case, get_frac returns 0.5 rounded to even 0.0,
-            // as it does not have a way to know that int is odd.
-            if frac_overflow || (is_odd(int_val) && frac_nbits == 0 && frac_is_half(frac, radix)) {
-                let (new_val, new_overflow) = if int_nbits == 0 {
-                    (val, true)
-                } else {
-                    val.overflowing_add(1 << frac_nbits)
-                };
+            // Rounding is done here rather than in get_frac, as when frac_nbits
+            // is 0 the parity of the result depends on int.
+            if mode.increment(neg, is_odd(val), discarded) {
+                let (new_val, new_overflow) = val.overflowing_add(1);
                 if new_overflow {
                     overflow = true;
                 }
@@ -407,18 +434,17 @@ macro_rules! unsigned {
             radix: u32,
             nbits: u32,
             bit_exp: Option<BitExp>,
-        ) -> Option<$Uns> {
+        ) -> ($Uns, Discarded) {
             $(
                 if nbits <= $Half::BITS {
-                    return match crate::from_str::$Half::get_frac(frac, radix, nbits, bit_exp) {
-                        Some(half) => Some(half as $Uns),
-                        None => None,
-                    };
+                    let (half, discarded) =
+                        crate::from_str::$Half::get_frac(frac, radix, nbits, bit_exp);
+                    return (half as $Uns, discarded);
                 }
             )?
 
             if frac.is_empty() {
-                return Some(0);
+                return (0, Discarded::Zero);
             }
             match radix {
                 2 => bin_str_frac_to_bin(frac, nbits),
@@ -449,7 +475,7 @@ macro_rules! unsigned {
             (acc, overflow)
         }
 
-        const fn bin_str_frac_to_bin(digits: DigitsExp, nbits: u32) -> Option<$Uns> {
+        const fn bin_str_frac_to_bin(digits: DigitsExp, nbits: u32) -> ($Uns, Discarded) {
             let mut rem_bits = nbits;
             let mut acc = 0;
             let mut rem_digits = digits;
@@ -458,25 +484,14 @@ macro_rules! unsigned {
 
                 let val = digit - b'0';
                 if rem_bits < 1 {
-                    if val != 0 {
-                        // half bit is true, round up if we have more
-                        // significant bits or currently acc is odd
-                        if !rem_digits.is_empty() || is_odd(acc) {
-                            acc = match acc.checked_add(1) {
-                                Some(acc) => acc,
-                                None => return None,
-                            };
-                        }
-                    }
-                    if nbits != $Uns::BITS && acc >> nbits != 0 {
-                        return None;
-                    }
-                    return Some(acc);
+                    // val is the half bit, and since zeros are trimmed, any
+                    // remaining digits are not all zero
+                    return (acc, discarded(val != 0, !rem_digits.is_empty()));
                 }
                 acc = (acc << 1) + from_byte(val);
                 rem_bits -= 1;
             }
-            Some(acc << rem_bits)
+            (acc << rem_bits, Discarded::Zero)
         }
 
         const fn oct_str_int_to_bin(digits: DigitsExp, bit_exp: Option<BitExp>) -> ($Uns, bool) {
@@ -524,7 +539,7 @@ macro_rules! unsigned {
             digits: DigitsExp,
             nbits: u32,
             bit_exp: Option<BitExp>,
-        ) -> Option<$Uns> {
+        ) -> ($Uns, Discarded) {
             let mut rem_bits = nbits;
             let mut acc = 0;
             let mut rem_digits = digits;
@@ -545,26 +560,15 @@ macro_rules! unsigned {
                 if rem_bits < val_bits {
                     acc = (acc << rem_bits) + from_byte(val >> (3 - rem_bits));
                     let half = 1 << (2 - rem_bits);
-                    if val & half != 0 {
-                        // half bit is true, round up if we have more
-                        // significant bits or currently acc is odd
-                        if val & (half - 1) != 0 || !rem_digits.is_empty() || is_odd(acc) {
-                            acc = match acc.checked_add(1) {
-                                Some(acc) => acc,
-                                None => return None,
-                            };
-                        }
-                    }
-                    if nbits != $Uns::BITS && acc >> nbits != 0 {
-                        return None;
-                    }
-                    return Some(acc);
+                    // since zeros are trimmed, any remaining digits are not all zero
+                    let rest = val & (half - 1) != 0 || !rem_digits.is_empty();
+                    return (acc, discarded(val & half != 0, rest));
                 }
                 acc = (acc << 3) + from_byte(val);
                 rem_bits -= val_bits;
                 val_bits = 3;
             }
-            Some(acc << rem_bits)
+            (acc << rem_bits, Discarded::Zero)
         }
 
         const fn hex_str_int_to_bin(digits: DigitsExp, bit_exp: Option<BitExp>) -> ($Uns, bool) {
@@ -612,7 +616,7 @@ macro_rules! unsigned {
             digits: DigitsExp,
             nbits: u32,
             bit_exp: Option<BitExp>,
-        ) -> Option<$Uns> {
+        ) -> ($Uns, Discarded) {
             let mut rem_bits = nbits;
             let mut acc = 0;
             let mut rem_digits = digits;
@@ -633,26 +637,15 @@ macro_rules! unsigned {
                 if rem_bits < val_bits {
                     acc = (acc << rem_bits) + from_byte(val >> (4 - rem_bits));
                     let half = 1 << (3 - rem_bits);
-                    if val & half != 0 {
-                        // half bit is true, round up if we have more
-                        // significant bits or currently acc is odd
-                        if val & (half - 1) != 0 || !rem_digits.is_empty() || is_odd(acc) {
-                            acc = match acc.checked_add(1) {
-                                Some(acc) => acc,
-                                None => return None,
-                            };
-                        }
-                    }
-                    if nbits != $Uns::BITS && acc >> nbits != 0 {
-                        return None;
-                    }
-                    return Some(acc);
+                    // since zeros are trimmed, any remaining digits are not all zero
+                    let rest = val & (half - 1) != 0 || !rem_digits.is_empty();
+                    return (acc, discarded(val & half != 0, rest));
                 }
                 acc = (acc << 4) + from_byte(val);
                 rem_bits -= val_bits;
                 val_bits = 4;
             }
-            Some(acc << rem_bits)
+            (acc << rem_bits, Discarded::Zero)
         }
 
         pub(super) const fn dec_str_int_to_bin(digits: DigitsExp) -> ($Uns, bool) {
@@ -676,71 +669,79 @@ macro_rules! unsigned {
             (acc, overflow)
         }
 
-        const fn dec_str_frac_to_bin(digits: DigitsExp, nbits: u32) -> Option<$Uns> {
+        const fn dec_str_frac_to_bin(digits: DigitsExp, nbits: u32) -> ($Uns, Discarded) {
             let (val, is_short) = parse_is_short(digits);
             let one: $Uns = 1;
             let dump_bits = $Uns::BITS - nbits;
-            // if is_short, dec_to_bin can round and give correct answer immediately
-            let round = if is_short {
-                Round::Nearest
-            } else {
-                Round::Floor
-            };
-            let Some(floor) = dec_to_bin(val, nbits, round) else {
-                return None;
-            };
+            let (floor, discarded) = dec_to_bin(val, nbits);
+            // if is_short, dec_to_bin used all the digits and is exact
             if is_short {
-                return Some(floor);
+                return (floor, discarded);
             }
-            // since !is_short, we have a floor and we have to check whether
-            // we need to increment
-
-            // add_5 is to add rounding when all bits are used
-            let (mut boundary, mut add_5) = if nbits == 0 {
-                (one << ($Uns::BITS - 1), false)
+            // since !is_short, the ignored digits are not all zero, so the value
+            // is larger than val, but by less than half a bit; so the floor is
+            // either floor or floor + 1, and we have to compare the digits to
+            // find the floor and the discarded part
+            let cmp_half = if nbits == 0 {
+                cmp_dec_frac(digits, one << ($Uns::BITS - 1), false)
             } else if dump_bits == 0 {
-                (floor, true)
+                cmp_dec_frac(digits, floor, true)
             } else {
-                ((floor << dump_bits) + (one << (dump_bits - 1)), false)
+                cmp_dec_frac(digits, (floor << dump_bits) + (one << (dump_bits - 1)), false)
             };
-            let mut tie = true;
+            match cmp_half {
+                Ordering::Less => return (floor, Discarded::LessThanHalf),
+                Ordering::Equal => return (floor, Discarded::Half),
+                Ordering::Greater => {}
+            }
+            // if floor + 1 does not fit, it is 1.0, which is larger than the digits
+            let next_up_is_one = if dump_bits == 0 {
+                floor == $Uns::MAX
+            } else {
+                (floor + 1) >> nbits != 0
+            };
+            if next_up_is_one {
+                return (floor, Discarded::GreaterThanHalf);
+            }
+            match cmp_dec_frac(digits, (floor + 1) << dump_bits, false) {
+                Ordering::Less => (floor, Discarded::GreaterThanHalf),
+                Ordering::Equal => (floor + 1, Discarded::Zero),
+                Ordering::Greater => (floor + 1, Discarded::LessThanHalf),
+            }
+        }
+
+        // Compares the decimal fraction digits to boundary / 2^BITS, with an
+        // extra 1 / 2^(BITS + 1) added if add_half is true.
+        const fn cmp_dec_frac(digits: DigitsExp, mut boundary: $Uns, mut add_half: bool) -> Ordering {
             let mut rem_digits = digits;
             while let Some((digit, rem)) = rem_digits.split_first() {
                 rem_digits = rem;
 
-                if !add_5 && boundary == 0 {
+                if !add_half && boundary == 0 {
                     // since zeros are trimmed, there must be some digit > 0 eventually
-                    tie = false;
-                    break;
+                    return Ordering::Greater;
                 }
                 let (prod, mut boundary_digit) = mul10_overflow(boundary);
                 boundary = prod;
-                if add_5 {
+                if add_half {
                     let (wrapped, overflow) = boundary.overflowing_add(5);
                     boundary = wrapped;
                     if overflow {
                         boundary_digit += 1;
                     }
-                    add_5 = false;
+                    add_half = false;
                 }
                 if digit - b'0' < boundary_digit {
-                    return Some(floor);
+                    return Ordering::Less;
                 }
                 if digit - b'0' > boundary_digit {
-                    tie = false;
-                    break;
+                    return Ordering::Greater;
                 }
             }
-            if tie && !is_odd(floor) {
-                return Some(floor);
-            }
-            let Some(next_up) = floor.checked_add(1) else  {
-                return None;
-            };
-            if dump_bits != 0 && next_up >> nbits != 0 {
-                None
+            if !add_half && boundary == 0 {
+                Ordering::Equal
             } else {
-                Some(next_up)
+                Ordering::Less
             }
         }
 
@@ -773,39 +774,16 @@ macro_rules! unsigned_not_u128 {
                 (prod as $Single, (prod >> <$Single>::BITS) as u8)
             }
 
-            pub(super) const fn dec_to_bin(
-                val: $Double,
-                nbits: u32,
-                round: Round,
-            ) -> Option<$Single> {
+            pub(super) const fn dec_to_bin(val: $Double, nbits: u32) -> ($Single, Discarded) {
                 debug_assert!(val < $Double::pow(10, $dec));
                 debug_assert!(nbits <= $bin);
                 let fives = $Double::pow(5, $dec);
                 let denom = fives * 2;
-                let mut numer = val << ($bin - $dec + 1) >> ($bin - nbits);
-                match round {
-                    Round::Nearest => {
-                        // Round up, then round back down if we had a tie and the result is odd.
-                        numer += fives;
-                        // If unrounded division == 1 exactly, we actually have a tie at upper
-                        // bound, which is rounded up to 1.0. This is even in all cases except
-                        // when nbits == 0, in which case we must round it back down to 0.
-                        if numer >> nbits >= denom {
-                            // 0.5 exactly is 10^$dec / 2 = 5^dec * 2^dec / 2 = fives << ($dec - 1)
-                            return if nbits == 0 && val == fives << ($dec - 1) {
-                                Some(0)
-                            } else {
-                                None
-                            };
-                        }
-                    }
-                    Round::Floor => {}
-                }
-                let (mut div, tie) = (numer / denom, numer % denom == 0);
-                if tie && crate::from_str::$Double::is_odd(div) {
-                    div -= 1;
-                }
-                Some(div as $Single)
+                let shl = val << ($bin - $dec + 1);
+                let numer = shl >> ($bin - nbits);
+                let lost = shl & ((1 << ($bin - nbits)) - 1) != 0;
+                let (div, rem) = (numer / denom, numer % denom);
+                (div as $Single, discarded(rem >= fives, rem % fives != 0 || lost))
             }
 
             const fn parse_is_short(digits: DigitsExp) -> ($Double, bool) {
@@ -851,11 +829,7 @@ pub mod u128 {
         )
     }
 
-    pub(super) const fn dec_to_bin(
-        (hi, lo): (u128, u128),
-        nbits: u32,
-        round: Round,
-    ) -> Option<u128> {
+    pub(super) const fn dec_to_bin((hi, lo): (u128, u128), nbits: u32) -> (u128, Discarded) {
         debug_assert!(hi < 10u128.pow(27));
         debug_assert!(lo < 10u128.pow(27));
         debug_assert!(nbits <= 128);
@@ -869,8 +843,10 @@ pub mod u128 {
         let (val_lo, overflow) = hi_e27.lo.overflowing_add(lo);
         let val_hi = hi_e27.hi + (overflow as u128);
         let (mut numer_lo, mut numer_hi) = (val_lo, val_hi);
+        let mut lost = false;
         if nbits < (54 - 1) {
             let shr = (54 - 1) - nbits;
+            lost = numer_lo & ((1 << shr) - 1) != 0;
             numer_lo = (numer_lo >> shr) | (numer_hi << (128 - shr));
             numer_hi >>= shr;
         } else if nbits > (54 - 1) {
@@ -878,42 +854,13 @@ pub mod u128 {
             numer_hi = (numer_hi << shl) | (numer_lo >> (128 - shl));
             numer_lo <<= shl;
         }
-        match round {
-            Round::Nearest => {
-                // Round up, then round back down if we had a tie and the result is odd.
-                let (wrapped, overflow) = numer_lo.overflowing_add(fives);
-                numer_lo = wrapped;
-                if overflow {
-                    numer_hi += 1;
-                }
-                let check_overflow = if nbits == 128 {
-                    numer_hi
-                } else if nbits == 0 {
-                    numer_lo
-                } else {
-                    (numer_lo >> nbits) | (numer_hi << (128 - nbits))
-                };
-                // If unrounded division == 1 exactly, we actually have a tie at upper
-                // bound, which is rounded up to 1.0. This is even in all cases except
-                // when nbits == 0, in which case we must round it back down to 0.
-                if check_overflow >= denom.get() {
-                    // 0.5 exactly is 10^$dec / 2 = 5^dec * 2^dec / 2 = fives << ($dec - 1)
-                    let half_hi = fives >> (128 - (54 - 1));
-                    let half_lo = fives << (54 - 1);
-                    return if nbits == 0 && val_hi == half_hi && val_lo == half_lo {
-                        Some(0)
-                    } else {
-                        None
-                    };
-                }
-            }
-            Round::Floor => {}
-        }
-        let (mut div, tie) = div_tie(numer_hi, numer_lo, denom);
-        if tie && is_odd(div) {
-            div -= 1;
-        }
-        Some(div)
+        let numer = U256 {
+            lo: numer_lo,
+            hi: numer_hi,
+        };
+        let (quot, rem) = int256::div_rem_u256_u128(numer, denom);
+        debug_assert!(quot.hi == 0);
+        (quot.lo, discarded(rem >= fives, rem % fives != 0 || lost))
     }
 
     const fn parse_is_short(digits: DigitsExp) -> ((u128, u128), bool) {
@@ -934,14 +881,197 @@ pub mod u128 {
             ((hi, lo), is_short)
         }
     }
+}
 
-    const fn div_tie(dividend_hi: u128, dividend_lo: u128, divisor: NonZeroU128) -> (u128, bool) {
-        let dividend = U256 {
-            lo: dividend_lo,
-            hi: dividend_hi,
+// Decimal parsing into 256 bits, used by FixedI256 and FixedU256.
+pub mod u256 {
+    use crate::{
+        from_str::{
+            discarded, parse_bounds, DigitsExp, Parse, ParseErrorKind, ParseFixedError, Sep,
+        },
+        int256::{self, U256},
+        rounding::{Discarded, RoundingMode},
+    };
+    use core::num::NonZeroU128;
+
+    pub const OVERFLOW: ParseFixedError = ParseFixedError {
+        kind: ParseErrorKind::Overflow,
+    };
+
+    const ZERO: U256 = U256 { lo: 0, hi: 0 };
+    const TEN: NonZeroU128 = match NonZeroU128::new(10) {
+        Some(ten) => ten,
+        None => unreachable!(),
+    };
+
+    // Parses a decimal number with frac_nbits ≤ 256 fractional bits, rounding
+    // according to mode. Returns the sign, the absolute value wrapped to 256
+    // bits, and whether the absolute value overflows 256 bits.
+    pub const fn from_str(
+        bytes: &[u8],
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> Result<(bool, U256, bool), ParseFixedError> {
+        debug_assert!(frac_nbits <= 256);
+        let Parse { neg, int, frac, .. } = match parse_bounds(bytes, 10, Sep::Error) {
+            Ok(o) => o,
+            Err(kind) => return Err(ParseFixedError { kind }),
         };
-        let (quot, rem) = int256::div_rem_u256_u128(dividend, divisor);
-        (quot.lo, rem == 0)
+        let (int_val, mut overflow) = dec_str_int_to_bin(int);
+        let (frac_val, discarded) = dec_str_frac_to_bin(frac, frac_nbits);
+        let mut val = if frac_nbits == 0 {
+            int_val
+        } else if frac_nbits == 256 {
+            if !is_zero(int_val) {
+                overflow = true;
+            }
+            frac_val
+        } else {
+            if !is_zero(int256::shr_u256(int_val, 256 - frac_nbits, false)) {
+                overflow = true;
+            }
+            let int_val = int256::shl_u256(int_val, frac_nbits);
+            U256 {
+                lo: int_val.lo | frac_val.lo,
+                hi: int_val.hi | frac_val.hi,
+            }
+        };
+        if mode.increment(neg, val.lo & 1 != 0, discarded) {
+            let (new_val, new_overflow) = int256::overflowing_add_u256(val, U256 { lo: 1, hi: 0 });
+            if new_overflow {
+                overflow = true;
+            }
+            val = new_val;
+        }
+        Ok((neg, val, overflow))
+    }
+
+    const fn is_zero(a: U256) -> bool {
+        a.lo == 0 && a.hi == 0
+    }
+
+    // Returns a × 10 + digit, and whether it overflows.
+    const fn mul10_add(a: U256, digit: u8) -> (U256, bool) {
+        let lo = int256::wide_mul_u128(a.lo, 10);
+        let hi = int256::wide_mul_u128(a.hi, 10);
+        let (hi_lo, carry) = hi.lo.overflowing_add(lo.hi);
+        let prod = U256 {
+            lo: lo.lo,
+            hi: hi_lo,
+        };
+        let (sum, sum_overflow) = int256::overflowing_add_u256(
+            prod,
+            U256 {
+                lo: digit as u128,
+                hi: 0,
+            },
+        );
+        (sum, hi.hi != 0 || carry || sum_overflow)
+    }
+
+    const fn dec_str_int_to_bin(digits: DigitsExp) -> (U256, bool) {
+        let max_effective_len = 256;
+        let (digits, mut overflow) = if digits.len() > max_effective_len {
+            let (_, last_max_effective_len) = digits.split_at(digits.len() - max_effective_len);
+            (last_max_effective_len, true)
+        } else {
+            (digits, false)
+        };
+        let mut acc = ZERO;
+        let mut rem_digits = digits;
+        while let Some((digit, rem)) = rem_digits.split_first() {
+            rem_digits = rem;
+
+            let (new_acc, digit_overflow) = mul10_add(acc, digit - b'0');
+            if digit_overflow {
+                overflow = true;
+            }
+            acc = new_acc;
+        }
+        (acc, overflow)
+    }
+
+    // Converts the fractional digits 0.d₁d₂…dₘ to nbits ≤ 256 fractional bits,
+    // flooring and returning the discarded part.
+    //
+    // Starting with X = 0, the digits are processed from the last to the first
+    // using X ← floor((dₖ × 2^nbits + X) / 10), which gives X = floor(f × 2^nbits)
+    // for the fraction f. The remainders rₖ of these divisions are the decimal
+    // digits of the discarded part, that is the discarded part is 0.r₁r₂…rₘ.
+    //
+    // Only the first nbits + 1 digits are needed: the digits after them change
+    // the value by less than 2^-(nbits + 1), so they cannot move it across a
+    // multiple of 2^-(nbits + 1). They can only make the discarded part a little
+    // larger, which is covered by the sticky flag.
+    const fn dec_str_frac_to_bin(digits: DigitsExp, nbits: u32) -> (U256, Discarded) {
+        if digits.is_empty() {
+            return (ZERO, Discarded::Zero);
+        }
+        let mut buf = [0u8; 257];
+        let max_len = nbits as usize + 1;
+        let (digits, mut rest) = if digits.len() > max_len {
+            (digits.split_at(max_len).0, true)
+        } else {
+            (digits, false)
+        };
+        let mut len = 0;
+        let mut rem_digits = digits;
+        while let Some((digit, rem)) = rem_digits.split_first() {
+            rem_digits = rem;
+
+            buf[len] = digit - b'0';
+            len += 1;
+        }
+        let mut acc = ZERO;
+        let mut r = 0;
+        while len > 0 {
+            len -= 1;
+            if r != 0 {
+                rest = true;
+            }
+            // numer = buf[len] × 2^nbits + acc, with top holding bits 256 and up
+            let digit = buf[len] as u128;
+            let (top, shifted) = if nbits == 256 {
+                (digit, ZERO)
+            } else if nbits > 252 {
+                (
+                    digit >> (256 - nbits),
+                    int256::shl_u256(U256 { lo: digit, hi: 0 }, nbits),
+                )
+            } else {
+                (0, int256::shl_u256(U256 { lo: digit, hi: 0 }, nbits))
+            };
+            let (low, carry) = int256::overflowing_add_u256(shifted, acc);
+            let top = top + carry as u128;
+            // top < 10, so each partial quotient fits in 128 bits
+            let (q_hi, r_hi) = int256::div_rem_u256_u128(
+                U256 {
+                    lo: low.hi,
+                    hi: top,
+                },
+                TEN,
+            );
+            let (q_lo, r_lo) = int256::div_rem_u256_u128(
+                U256 {
+                    lo: low.lo,
+                    hi: r_hi,
+                },
+                TEN,
+            );
+            acc = U256 {
+                lo: q_lo.lo,
+                hi: q_hi.lo,
+            };
+            r = r_lo;
+        }
+        let discarded = if r == 5 {
+            discarded(true, rest)
+        } else if r > 5 {
+            Discarded::GreaterThanHalf
+        } else {
+            discarded(false, r != 0 || rest)
+        };
+        (acc, discarded)
     }
 }
 
@@ -953,10 +1083,15 @@ const fn unchecked_hex_digit(byte: u8) -> u8 {
     (byte & 0x0f) + if byte >= 0x40 { 9 } else { 0 }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum Round {
-    Nearest,
-    Floor,
+// Returns the discarded part from its most significant bit and from whether any
+// of the other discarded bits are set.
+const fn discarded(half: bool, rest: bool) -> Discarded {
+    match (half, rest) {
+        (false, false) => Discarded::Zero,
+        (false, true) => Discarded::LessThanHalf,
+        (true, false) => Discarded::Half,
+        (true, true) => Discarded::GreaterThanHalf,
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -1278,15 +1413,6 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
     })
 }
 
-const fn frac_is_half(digits: DigitsExp, radix: u32) -> bool {
-    // since zeros are trimmed, when the value is one half there has to be
-    // exatly one digit, and rest has to be empty
-    match digits.split_first() {
-        Some((digit, rest)) => digit - b'0' == (radix as u8) / 2 && rest.is_empty(),
-        None => false,
-    }
-}
-
 macro_rules! impl_from_str {
     ($Fixed:ident, $nbits:expr) => {
         impl<const FRAC: i32> FromStr for $Fixed<FRAC>
@@ -1319,13 +1445,27 @@ impl_from_str! { FixedU128, 128 }
 mod tests {
     use crate::{
         bytes::DigitsExp,
-        from_str::{self, parse_bounds, Parse, ParseErrorKind, ParseFixedError, Round, Sep},
+        from_str::{self, parse_bounds, Parse, ParseErrorKind, ParseFixedError, Sep},
+        rounding::{Discarded, RoundingMode},
         types::*,
     };
     use std::format;
     #[cfg(not(feature = "std"))]
     use std::string::{String, ToString};
 
+    #[test]
+    fn long_fraction() {
+        // 0.0058 lies between 1/256 = 0.00390625 and 2/256 = 0.0078125, but
+        // closer to the lower bound; the digits run out before it is known
+        // which side of the midpoint 0.005859375 the value is on.
+        assert_eq!(U0F8::from_str("0.0058"), Ok(U0F8::from_bits(1)));
+        assert_eq!(U0F8::from_str("0.005859374"), Ok(U0F8::from_bits(1)));
+        assert_eq!(U0F8::from_str("0.005859375"), Ok(U0F8::from_bits(2)));
+        assert_eq!(U0F8::from_str("0.005859376"), Ok(U0F8::from_bits(2)));
+        assert_eq!(U0F8::from_str("0.006"), Ok(U0F8::from_bits(2)));
+        assert_eq!(I8F8::from_str("-0.0058"), Ok(I8F8::from_bits(-1)));
+    }
+
     #[test]
     fn overflowing() {
         let overflow = ParseFixedError {
@@ -1412,14 +1552,22 @@ mod tests {
         );
     }
 
+    // Rounds the result of dec_to_bin to the nearest, with ties rounded to
+    // even, returning None if the result overflows u128.
+    fn nearest((floor, discarded): (u128, Discarded)) -> Option<u128> {
+        let increment = RoundingMode::HalfEven.increment(false, floor % 2 != 0, discarded);
+        floor.checked_add(u128::from(increment))
+    }
+
     #[test]
     fn check_dec_8() {
         let two_pow = 8f64.exp2();
         let limit = 1000;
         for i in 0..limit {
-            let ans = from_str::u8::dec_to_bin(i, 8, Round::Nearest);
+            let (floor, discarded) = from_str::u8::dec_to_bin(i, 8);
+            let ans = nearest((floor.into(), discarded));
             let approx = two_pow * f64::from(i) / f64::from(limit);
-            let error = (ans.map_or(two_pow, f64::from) - approx).abs();
+            let error = (ans.map_or(two_pow, |x| x as f64) - approx).abs();
             assert!(
                 error <= 0.5,
                 "i {i} ans {ans:?}  approx {approx} error {error}"
@@ -1432,9 +1580,10 @@ mod tests {
         let two_pow = 16f64.exp2();
         let limit = 1_000_000;
         for i in 0..limit {
-            let ans = from_str::u16::dec_to_bin(i, 16, Round::Nearest);
+            let (floor, discarded) = from_str::u16::dec_to_bin(i, 16);
+            let ans = nearest((floor.into(), discarded));
             let approx = two_pow * f64::from(i) / f64::from(limit);
-            let error = (ans.map_or(two_pow, f64::from) - approx).abs();
+            let error = (ans.map_or(two_pow, |x| x as f64) - approx).abs();
             assert!(
                 error <= 0.5,
                 "i {i} ans {ans:?}  approx {approx} error {error}"
@@ -1457,9 +1606,10 @@ mod tests {
                 limit / 2 + iter,
                 limit - iter - 1,
             ] {
-                let ans = from_str::u32::dec_to_bin(i, 32, Round::Nearest);
+                let (floor, discarded) = from_str::u32::dec_to_bin(i, 32);
+                let ans = nearest((floor.into(), discarded));
                 let approx = two_pow * i as f64 / limit as f64;
-                let error = (ans.map_or(two_pow, f64::from) - approx).abs();
+                let error = (ans.map_or(two_pow, |x| x as f64) - approx).abs();
                 assert!(
                     error <= 0.5,
                     "i {i} ans {ans:?}  approx {approx} error {error}"
@@ -1483,7 +1633,8 @@ mod tests {
                 limit / 2 + iter,
                 limit - iter - 1,
             ] {
-                let ans = from_str::u64::dec_to_bin(i, 64, Round::Nearest);
+                let (floor, discarded) = from_str::u64::dec_to_bin(i, 64);
+                let ans = nearest((floor.into(), discarded));
                 let approx = two_pow * i as f64 / limit as f64;
                 let error = (ans.map_or(two_pow, |x| x as f64) - approx).abs();
                 assert!(
@@ -1498,29 +1649,28 @@ mod tests {
     fn check_dec_128() {
         let nines = 10u128.pow(27) - 1;
         let zeros = 0;
-        let too_big = from_str::u128::dec_to_bin((nines, nines), 128, Round::Nearest);
+        let too_big = nearest(from_str::u128::dec_to_bin((nines, nines), 128));
         assert_eq!(too_big, None);
-        let big = from_str::u128::dec_to_bin((nines, zeros), 128, Round::Nearest);
+        let big = nearest(from_str::u128::dec_to_bin((nines, zeros), 128));
         assert_eq!(
             big,
             Some(340_282_366_920_938_463_463_374_607_091_485_844_535)
         );
-        let small = from_str::u128::dec_to_bin((zeros, nines), 128, Round::Nearest);
+        let small = nearest(from_str::u128::dec_to_bin((zeros, nines), 128));
         assert_eq!(small, Some(340_282_366_921));
-        let zero = from_str::u128::dec_to_bin((zeros, zeros), 128, Round::Nearest);
+        let zero = nearest(from_str::u128::dec_to_bin((zeros, zeros), 128));
         assert_eq!(zero, Some(0));
-        let x = from_str::u128::dec_to_bin(
+        let x = nearest(from_str::u128::dec_to_bin(
             (
                 123_456_789_012_345_678_901_234_567,
                 987_654_321_098_765_432_109_876_543,
             ),
             128,
-            Round::Nearest,
-        );
+        ));
         assert_eq!(x, Some(42_010_168_377_579_896_403_540_037_811_203_677_112));
 
         let eights = 888_888_888_888_888_888_888_888_888;
-        let narrow = from_str::u128::dec_to_bin((eights, zeros), 40, Round::Nearest);
+        let narrow = nearest(from_str::u128::dec_to_bin((eights, zeros), 40));
         assert_eq!(narrow, Some(977_343_669_134));
     }
 
@@ -2830,4 +2980,86 @@ mod tests {
             assert_eq!(I15F17::from_str(&fix_neg_str9).unwrap(), fix_neg);
         }
     }
+
+    macro_rules! check_from_str_round {
+        ($Fixed:ident, $Inner:ident, $frac:expr, $ndigits:expr, $max:expr, $step:expr) => {{
+            use crate::rounding::tests::{round_ratio, ALL_MODES};
+            use crate::$Fixed;
+            type F = $Fixed<$frac>;
+            let scale = 10i128.pow($ndigits);
+            let (num_mul, den) = (1i128 << $frac, scale);
+            let mut n = 0i128;
+            while n <= $max {
+                let int = n / scale;
+                let frac = n % scale;
+                let width = $ndigits as usize;
+                for neg in [false, true] {
+                    if neg && $Inner::MIN == 0 {
+                        continue;
+                    }
+                    let sign = if neg { "-" } else { "" };
+                    let s = format!("{sign}{int}.{frac:0width$}");
+                    let signed = if neg { -n } else { n };
+                    for mode in ALL_MODES {
+                        let exact = round_ratio(signed * num_mul, den, mode);
+                        let wrapped = exact as $Inner;
+                        let overflow = i128::try_from(wrapped).unwrap() != exact;
+                        assert_eq!(
+                            F::overflowing_from_str_round(&s, mode),
+                            Ok((F::from_bits(wrapped), overflow)),
+                            "{s} {mode:?}"
+                        );
+                    }
+                }
+                n += $step;
+            }
+        }};
+    }
+
+    #[test]
+    fn from_str_round() {
+        check_from_str_round!(FixedU8, u8, 4, 3, 20_000, 1);
+        check_from_str_round!(FixedI8, i8, 4, 3, 10_000, 1);
+        check_from_str_round!(FixedU8, u8, 0, 2, 30_000, 1);
+        check_from_str_round!(FixedU16, u16, 9, 6, 200_000_000, 9_991);
+        check_from_str_round!(FixedI16, i16, 16, 9, 1_000_000_000, 49_999);
+        check_from_str_round!(
+            FixedU32,
+            u32,
+            20,
+            20,
+            5 * 10i128.pow(23),
+            49_999_999_999_999_999_997
+        );
+        check_from_str_round!(FixedI64, i64, 60, 15, 10i128.pow(16), 999_999_999_997);
+    }
+
+    #[test]
+    fn from_str_round_long_fraction() {
+        type F = crate::FixedU8<8>;
+        let floor = F::from_str_round("0.00781249999999999999999", RoundingMode::Floor);
+        assert_eq!(floor, Ok(F::from_bits(1)));
+        let ceil = F::from_str_round("0.00390625000000000000001", RoundingMode::Ceil);
+        assert_eq!(ceil, Ok(F::from_bits(2)));
+        let exact = F::from_str_round("0.00390625000000000000000", RoundingMode::Ceil);
+        assert_eq!(exact, Ok(F::from_bits(1)));
+
+        type G = crate::FixedI128<127>;
+        let tiny = "0.000000000000000000000000000000000000001";
+        assert_eq!(G::from_str_round(tiny, RoundingMode::Floor), Ok(G::ZERO));
+        assert_eq!(G::from_str_round(tiny, RoundingMode::Ceil), Ok(G::DELTA));
+        let neg_tiny = format!("-{tiny}");
+        assert_eq!(
+            G::from_str_round(&neg_tiny, RoundingMode::Floor),
+            Ok(-G::DELTA)
+        );
+        assert_eq!(
+            G::from_str_round(&neg_tiny, RoundingMode::ToZero),
+            Ok(G::ZERO)
+        );
+        assert_eq!(
+            G::from_str_round(&neg_tiny, RoundingMode::AwayFromZero),
+            Ok(-G::DELTA)
+        );
+    }
 }
@@ -14,25 +14,84 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    dot::Acc, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32,
+    FixedU64, FixedU8,
 };
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
 
-pub trait Sealed {}
+pub trait Sealed: Copy {
+    // Returns the sum of the products in add minus the sum of the products in
+    // sub. The sum is computed exactly and rounded down only once. Also returns
+    // whether the result overflows, in which case the wrapped value is
+    // returned, and whether the exact sum is negative.
+    fn sum_prods(add: &[(Self, Self)], sub: &[(Self, Self)]) -> (Self, bool, bool);
+}
 
 macro_rules! impl_sealed {
-    ($Fixed:ident) => {
-        impl<const FRAC: i32> Sealed for $Fixed<FRAC> {}
+    ($Fixed:ident($Inner:ident, $Signed:literal), $add_prod:ident, $Prod:ident) => {
+        impl<const FRAC: i32> Sealed for $Fixed<FRAC> {
+            fn sum_prods(add: &[(Self, Self)], sub: &[(Self, Self)]) -> (Self, bool, bool) {
+                let mut acc = Acc::ZERO;
+                for &(a, b) in add {
+                    acc = acc.$add_prod($Prod::from(a.to_bits()), $Prod::from(b.to_bits()), false);
+                }
+                for &(a, b) in sub {
+                    acc = acc.$add_prod($Prod::from(a.to_bits()), $Prod::from(b.to_bits()), true);
+                }
+                let (bits, overflow, neg) = acc.narrow(FRAC, $Inner::BITS, $Signed);
+                (Self::from_bits(bits as $Inner), overflow, neg)
+            }
+        }
     };
 }
 
-impl_sealed! { FixedI8 }
-impl_sealed! { FixedI16 }
-impl_sealed! { FixedI32 }
-impl_sealed! { FixedI64 }
-impl_sealed! { FixedI128 }
-impl_sealed! { FixedU8 }
-impl_sealed! { FixedU16 }
-impl_sealed! { FixedU32 }
-impl_sealed! { FixedU64 }
-impl_sealed! { FixedU128 }
+impl_sealed! { FixedI8(i8, true), add_prod_i64, i64 }
+impl_sealed! { FixedI16(i16, true), add_prod_i64, i64 }
+impl_sealed! { FixedI32(i32, true), add_prod_i64, i64 }
+impl_sealed! { FixedI64(i64, true), add_prod_i64, i64 }
+impl_sealed! { FixedI128(i128, true), add_prod_i128, i128 }
+impl_sealed! { FixedU8(u8, false), add_prod_u64, u64 }
+impl_sealed! { FixedU16(u16, false), add_prod_u64, u64 }
+impl_sealed! { FixedU32(u32, false), add_prod_u64, u64 }
+impl_sealed! { FixedU64(u64, false), add_prod_u64, u64 }
+impl_sealed! { FixedU128(u128, false), add_prod_u128, u128 }
+
+// The integers that can divide FixedI and FixedU numbers with the underlying
+// integer Bits. This is implemented for Bits and for its non-zero version if
+// it is unsigned, and is used so that dividing by the underlying integer does
+// not conflict with dividing by the number itself.
+pub trait Divisor: Copy {
+    type Bits;
+    fn divisor_bits(self) -> Self::Bits;
+}
+
+macro_rules! impl_divisor {
+    ($($Bits:ident $(($NonZero:ident))?),*) => { $(
+        impl Divisor for $Bits {
+            type Bits = $Bits;
+            #[inline]
+            fn divisor_bits(self) -> $Bits {
+                self
+            }
+        }
+
+        $(
+            impl Divisor for $NonZero {
+                type Bits = $Bits;
+                #[inline]
+                fn divisor_bits(self) -> $Bits {
+                    self.get()
+                }
+            }
+        )?
+    )* };
+}
+
+impl_divisor! { i8, i16, i32, i64, i128 }
+impl_divisor! {
+    u8(NonZeroU8),
+    u16(NonZeroU16),
+    u32(NonZeroU32),
+    u64(NonZeroU64),
+    u128(NonZeroU128)
+}
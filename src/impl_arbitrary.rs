@@ -14,8 +14,9 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8, Unwrapped, Wrapping,
+    types::extra::{Storage, Width},
+    FixedI, FixedI128, FixedI16, FixedI24, FixedI32, FixedI48, FixedI64, FixedI8, FixedU,
+    FixedU128, FixedU16, FixedU24, FixedU32, FixedU48, FixedU64, FixedU8, Unwrapped, Wrapping,
 };
 use arbitrary::{Arbitrary, Result as ArbitraryResult, Unstructured};
 
@@ -69,3 +70,31 @@ impl_trait! { FixedU16, 16, u16 }
 impl_trait! { FixedU32, 32, u32 }
 impl_trait! { FixedU64, 64, u64 }
 impl_trait! { FixedU128, 128, u128 }
+
+// The bits that do not fit in the number are discarded.
+macro_rules! impl_narrow {
+    ($Fixed:ident<$($Param:ident),*>, where [$($bound:tt)*]; $Bits:ty) => {
+        impl<'a, $(const $Param: i32),*> Arbitrary<'a> for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<Self> {
+                let bits = <$Bits>::arbitrary(u)?;
+                Ok(Self::wrapping_from_bits(bits))
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$Bits as Arbitrary<'a>>::size_hint(depth)
+            }
+        }
+    };
+}
+
+impl_narrow! { FixedI24<FRAC>, where []; i32 }
+impl_narrow! { FixedI48<FRAC>, where []; i64 }
+impl_narrow! { FixedU24<FRAC>, where []; u32 }
+impl_narrow! { FixedU48<FRAC>, where []; u64 }
+impl_narrow! { FixedI<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,]; <Width<{ INT + FRAC }> as Storage>::Signed }
+impl_narrow! { FixedU<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,]; <Width<{ INT + FRAC }> as Storage>::Unsigned }
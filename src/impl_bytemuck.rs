@@ -14,8 +14,10 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8, Unwrapped, Wrapping,
+    traits::FixedBits,
+    types::extra::{Storage, Width},
+    FixedI, FixedI128, FixedI16, FixedI24, FixedI32, FixedI48, FixedI64, FixedI8, FixedU,
+    FixedU128, FixedU16, FixedU24, FixedU32, FixedU48, FixedU64, FixedU8, Unwrapped, Wrapping,
 };
 use bytemuck::{Contiguous, Pod, TransparentWrapper, Zeroable};
 
@@ -63,3 +65,39 @@ unsafe_impl_traits! { FixedU16, 16, u16 }
 unsafe_impl_traits! { FixedU32, 32, u32 }
 unsafe_impl_traits! { FixedU64, 64, u64 }
 unsafe_impl_traits! { FixedU128, 128, u128 }
+
+macro_rules! unsafe_impl_narrow {
+    ($Fixed:ident<$($Param:ident),*>, where [$($bound:tt)*]; $Inner:ty) => {
+        unsafe impl<$(const $Param: i32),*> Zeroable for $Fixed<$($Param),*> where $($bound)* {}
+        unsafe impl<$(const $Param: i32),*> Pod for $Fixed<$($Param),*> where $($bound)* {}
+        unsafe impl<$(const $Param: i32),*> Contiguous for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            type Int = $Inner;
+            const MAX_VALUE: $Inner = <$Inner as FixedBits>::MAX;
+            const MIN_VALUE: $Inner = <$Inner as FixedBits>::MIN;
+        }
+        unsafe impl<$(const $Param: i32),*> TransparentWrapper<$Inner> for $Fixed<$($Param),*> where
+            $($bound)*
+        {
+        }
+    };
+}
+
+// SAFETY: the 24-bit and 48-bit fixed-point numbers, FixedI and FixedU are
+// repr(transparent) over primitive integer types which are both Pod and
+// Zeroable. Every bit pattern of the underlying integer is a valid number, as
+// the bits that do not fit in the number of bits are ignored.
+unsafe_impl_narrow! { FixedI24<FRAC>, where []; i32 }
+unsafe_impl_narrow! { FixedI48<FRAC>, where []; i64 }
+unsafe_impl_narrow! { FixedU24<FRAC>, where []; u32 }
+unsafe_impl_narrow! { FixedU48<FRAC>, where []; u64 }
+unsafe_impl_narrow! {
+    FixedI<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,];
+    <Width<{ INT + FRAC }> as Storage>::Signed
+}
+unsafe_impl_narrow! {
+    FixedU<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,];
+    <Width<{ INT + FRAC }> as Storage>::Unsigned
+}
@@ -15,9 +15,10 @@
 
 use crate::{
     consts,
-    types::extra::{If, True},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8, ParseFixedError,
+    traits::Fixed,
+    types::extra::{If, Storage, True, Width},
+    FixedI, FixedI128, FixedI16, FixedI24, FixedI32, FixedI48, FixedI64, FixedI8, FixedU,
+    FixedU128, FixedU16, FixedU24, FixedU32, FixedU48, FixedU64, FixedU8, ParseFixedError,
 };
 use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::{
@@ -551,3 +552,440 @@ impl_traits! { FixedU16, 16, 15, Unsigned }
 impl_traits! { FixedU32, 32, 31, Unsigned }
 impl_traits! { FixedU64, 64, 63, Unsigned }
 impl_traits! { FixedU128, 128, 127, Unsigned }
+
+// The methods are called through the Fixed trait, as the traits of this crate
+// have methods with the same names.
+macro_rules! impl_narrow_traits {
+    ($Fixed:ident<$($Param:ident),*>, where [$($bound:tt)*]) => {
+        impl<$(const $Param: i32),*> Bounded for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn min_value() -> Self {
+                Self::MIN
+            }
+            #[inline]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl<$(const $Param: i32),*> Zero for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                Fixed::is_zero(*self)
+            }
+        }
+
+        impl<$(const $Param: i32),*> CheckedAdd for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                Fixed::checked_add(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> CheckedSub for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                Fixed::checked_sub(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> CheckedMul for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                Fixed::checked_mul(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> CheckedRem for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn checked_rem(&self, v: &Self) -> Option<Self> {
+                Fixed::checked_rem(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> CheckedNeg for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn checked_neg(&self) -> Option<Self> {
+                Fixed::checked_neg(*self)
+            }
+        }
+
+        impl<$(const $Param: i32),*> CheckedShl for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn checked_shl(&self, rhs: u32) -> Option<Self> {
+                Fixed::checked_shl(*self, rhs)
+            }
+        }
+
+        impl<$(const $Param: i32),*> CheckedShr for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn checked_shr(&self, rhs: u32) -> Option<Self> {
+                Fixed::checked_shr(*self, rhs)
+            }
+        }
+
+        impl<$(const $Param: i32),*> SaturatingAdd for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn saturating_add(&self, v: &Self) -> Self {
+                Fixed::saturating_add(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> SaturatingSub for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn saturating_sub(&self, v: &Self) -> Self {
+                Fixed::saturating_sub(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> SaturatingMul for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn saturating_mul(&self, v: &Self) -> Self {
+                Fixed::saturating_mul(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> WrappingAdd for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn wrapping_add(&self, v: &Self) -> Self {
+                Fixed::wrapping_add(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> WrappingSub for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn wrapping_sub(&self, v: &Self) -> Self {
+                Fixed::wrapping_sub(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> WrappingMul for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                Fixed::wrapping_mul(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> WrappingNeg for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn wrapping_neg(&self) -> Self {
+                Fixed::wrapping_neg(*self)
+            }
+        }
+
+        impl<$(const $Param: i32),*> WrappingShl for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn wrapping_shl(&self, rhs: u32) -> Self {
+                Fixed::wrapping_shl(*self, rhs)
+            }
+        }
+
+        impl<$(const $Param: i32),*> WrappingShr for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn wrapping_shr(&self, rhs: u32) -> Self {
+                Fixed::wrapping_shr(*self, rhs)
+            }
+        }
+
+        impl<$(const $Param: i32),*> OverflowingAdd for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn overflowing_add(&self, v: &Self) -> (Self, bool) {
+                Fixed::overflowing_add(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> OverflowingSub for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn overflowing_sub(&self, v: &Self) -> (Self, bool) {
+                Fixed::overflowing_sub(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> OverflowingMul for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn overflowing_mul(&self, v: &Self) -> (Self, bool) {
+                Fixed::overflowing_mul(*self, *v)
+            }
+        }
+
+        impl<$(const $Param: i32),*> FloatConst for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn E() -> Self {
+                consts::E.to_num()
+            }
+            #[inline]
+            fn FRAC_1_PI() -> Self {
+                consts::PREC_FRAC_1_PI.to_num()
+            }
+            #[inline]
+            fn FRAC_1_SQRT_2() -> Self {
+                consts::FRAC_1_SQRT_2.to_num()
+            }
+            #[inline]
+            fn FRAC_2_PI() -> Self {
+                consts::FRAC_2_PI.to_num()
+            }
+            #[inline]
+            fn FRAC_2_SQRT_PI() -> Self {
+                consts::FRAC_2_SQRT_PI.to_num()
+            }
+            #[inline]
+            fn FRAC_PI_2() -> Self {
+                consts::FRAC_PI_2.to_num()
+            }
+            #[inline]
+            fn FRAC_PI_3() -> Self {
+                consts::FRAC_PI_3.to_num()
+            }
+            #[inline]
+            fn FRAC_PI_4() -> Self {
+                consts::FRAC_PI_4.to_num()
+            }
+            #[inline]
+            fn FRAC_PI_6() -> Self {
+                consts::FRAC_PI_6.to_num()
+            }
+            #[inline]
+            fn FRAC_PI_8() -> Self {
+                consts::PREC_FRAC_PI_8.to_num()
+            }
+            #[inline]
+            fn LN_10() -> Self {
+                consts::LN_10.to_num()
+            }
+            #[inline]
+            fn LN_2() -> Self {
+                consts::LN_2.to_num()
+            }
+            #[inline]
+            fn LOG10_E() -> Self {
+                consts::PREC_LOG10_E.to_num()
+            }
+            #[inline]
+            fn LOG2_E() -> Self {
+                consts::LOG2_E.to_num()
+            }
+            #[inline]
+            fn PI() -> Self {
+                consts::PI.to_num()
+            }
+            #[inline]
+            fn SQRT_2() -> Self {
+                consts::SQRT_2.to_num()
+            }
+            #[inline]
+            fn TAU() -> Self {
+                consts::TAU.to_num()
+            }
+            #[inline]
+            fn LOG10_2() -> Self {
+                consts::PREC_LOG10_2.to_num()
+            }
+            #[inline]
+            fn LOG2_10() -> Self {
+                consts::LOG2_10.to_num()
+            }
+        }
+
+        impl<$(const $Param: i32),*> ToPrimitive for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_isize(&self) -> Option<isize> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_i8(&self) -> Option<i8> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_i16(&self) -> Option<i16> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_i32(&self) -> Option<i32> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_usize(&self) -> Option<usize> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_u8(&self) -> Option<u8> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_u16(&self) -> Option<u16> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_u32(&self) -> Option<u32> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                self.checked_to_num()
+            }
+        }
+
+        impl<$(const $Param: i32),*> FromPrimitive for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_isize(n: isize) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_i8(n: i8) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_i16(n: i16) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_i32(n: i32) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_i128(n: i128) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_usize(n: usize) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_u8(n: u8) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_u16(n: u16) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_u32(n: u32) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_u128(n: u128) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_f32(n: f32) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                Self::checked_from_num(n)
+            }
+        }
+    };
+}
+
+impl_narrow_traits! { FixedI24<FRAC>, where [] }
+impl_narrow_traits! { FixedI48<FRAC>, where [] }
+impl_narrow_traits! { FixedU24<FRAC>, where [] }
+impl_narrow_traits! { FixedU48<FRAC>, where [] }
+impl_narrow_traits! { FixedI<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,] }
+impl_narrow_traits! { FixedU<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,] }
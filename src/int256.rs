@@ -361,6 +361,80 @@ pub const fn overflowing_shl_u256_into_u128(a: U256, sh: u32) -> (u128, bool) {
     }
 }
 
+#[inline]
+pub const fn i256_wrapping_as_u256(a: I256) -> U256 {
+    U256 {
+        lo: a.lo,
+        hi: a.hi as u128,
+    }
+}
+
+#[inline]
+pub const fn overflowing_sub_u256(a: U256, b: U256) -> (U256, bool) {
+    let (lo, borrow0) = a.lo.overflowing_sub(b.lo);
+    let (hi, borrow1_a) = a.hi.overflowing_sub(b.hi);
+    let (hi, borrow1_b) = hi.overflowing_sub(borrow0 as u128);
+    (U256 { lo, hi }, borrow1_a | borrow1_b)
+}
+
+#[inline]
+pub const fn overflowing_add_i256(a: I256, b: I256) -> (I256, bool) {
+    let (lo, carry) = a.lo.overflowing_add(b.lo);
+    let (hi, overflow_a) = a.hi.overflowing_add(b.hi);
+    // if both additions overflow, they overflow in opposite directions
+    let (hi, overflow_b) = hi.overflowing_add(carry as i128);
+    (I256 { lo, hi }, overflow_a != overflow_b)
+}
+
+#[inline]
+pub const fn overflowing_sub_i256(a: I256, b: I256) -> (I256, bool) {
+    let (lo, borrow) = a.lo.overflowing_sub(b.lo);
+    let (hi, overflow_a) = a.hi.overflowing_sub(b.hi);
+    // if both subtractions overflow, they overflow in opposite directions
+    let (hi, overflow_b) = hi.overflowing_sub(borrow as i128);
+    (I256 { lo, hi }, overflow_a != overflow_b)
+}
+
+// sh must be less than 256
+#[inline]
+pub const fn shl_u256(a: U256, sh: u32) -> U256 {
+    if sh == 0 {
+        a
+    } else if sh < 128 {
+        U256 {
+            lo: a.lo << sh,
+            hi: (a.hi << sh) | (a.lo >> (128 - sh)),
+        }
+    } else {
+        U256 {
+            lo: 0,
+            hi: a.lo << (sh - 128),
+        }
+    }
+}
+
+// sh must be less than 256; the vacated bits are ones if fill_ones is true,
+// which gives an arithmetic shift for negative two's-complement numbers
+#[inline]
+pub const fn shr_u256(a: U256, sh: u32, fill_ones: bool) -> U256 {
+    let fill = if fill_ones { u128::MAX } else { 0 };
+    if sh == 0 {
+        a
+    } else if sh < 128 {
+        U256 {
+            lo: (a.lo >> sh) | (a.hi << (128 - sh)),
+            hi: (a.hi >> sh) | (fill << (128 - sh)),
+        }
+    } else if sh == 128 {
+        U256 { lo: a.hi, hi: fill }
+    } else {
+        U256 {
+            lo: (a.hi >> (sh - 128)) | (fill << (256 - sh)),
+            hi: fill,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
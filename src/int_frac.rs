@@ -0,0 +1,1635 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    dot::Acc,
+    helpers::{Divisor, Sealed},
+    traits::{
+        Fixed, FixedBits, FixedOptionalArbitrary, FixedOptionalBorsh, FixedOptionalNum,
+        FixedOptionalSerde, FixedSigned, FixedUnsigned, FromFixed, RandomBits, ToFixed,
+    },
+    types::extra::{If, Storage, StorageFrac, True, Width},
+    FixedI128, FixedU128, RoundingMode, F128,
+};
+use az::{WrappingCast, WrappingCastFrom};
+use core::{
+    cmp::Ordering,
+    fmt::{
+        Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult,
+        UpperExp, UpperHex,
+    },
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+        SubAssign,
+    },
+};
+use half::{bf16, f16};
+
+// The underlying integer of a number with INT + FRAC bits.
+type SignedBits<const INT: i32, const FRAC: i32> = <Width<{ INT + FRAC }> as Storage>::Signed;
+type UnsignedBits<const INT: i32, const FRAC: i32> = <Width<{ INT + FRAC }> as Storage>::Unsigned;
+type SignedNonZeroBits<const INT: i32, const FRAC: i32> =
+    <Width<{ INT + FRAC }> as Storage>::NonZeroSigned;
+type UnsignedNonZeroBits<const INT: i32, const FRAC: i32> =
+    <Width<{ INT + FRAC }> as Storage>::NonZeroUnsigned;
+
+// The fixed-point number that is used to compute the results of operations.
+type SignedFixed<const INT: i32, const FRAC: i32> =
+    <SignedBits<INT, FRAC> as FixedBits>::Fixed<FRAC>;
+type UnsignedFixed<const INT: i32, const FRAC: i32> =
+    <UnsignedBits<INT, FRAC> as FixedBits>::Fixed<FRAC>;
+
+macro_rules! fixed_int_frac {
+    (
+        description = $description:literal,
+        {Self, Bits, Fixed, Wide} = {$Self:ident, $Bits:ident, $Fixed:ident, $Wide:ident},
+        Signedness = $Signedness:ident,
+        NonZeroBits = $NonZeroBits:ident,
+        {ZERO, ONE, TRY_ONE, MIN, MAX} =
+            {$ZERO:ident, $ONE:ident, $TRY_ONE:ident, $MIN:ident, $MAX:ident},
+    ) => {
+        comment! {
+            $description, " fixed-point number with `INT` integer bits and
+`FRAC` fractional bits.
+
+The number has <i>i</i>&nbsp;+&nbsp;<i>f</i> bits, where <i>i</i>&nbsp;=&nbsp;`INT`
+and <i>f</i>&nbsp;=&nbsp;`FRAC`, which must be from 1 to 128. The value
+<i>x</i> can lie in the range ",
+            if_signed_unsigned!(
+                $Signedness,
+                "&minus;2<sup><i>i</i>&nbsp;&minus;&nbsp;1</sup>",
+                "0",
+            ),
+            "&nbsp;≤&nbsp;<i>x</i>&nbsp;<&nbsp;2<sup>",
+            if_signed_unsigned!($Signedness, "<i>i</i>&nbsp;&minus;&nbsp;1", "<i>i</i>"),
+            "</sup>.
+
+The number is stored in the smallest primitive integer that is wide enough,
+from [`", if_signed_unsigned!($Signedness, "i8", "u8"), "`] to [`",
+            if_signed_unsigned!($Signedness, "i128", "u128"), "`], which is selected at
+compile time by the [`Storage`] trait. Only the low <i>i</i>&nbsp;+&nbsp;<i>f</i>
+bits of the underlying integer are significant, so every bit pattern is a valid
+number, and [`to_bits`] returns them ", if_signed_unsigned!($Signedness, "sign", "zero"),
+            " extended. Overflow happens at
+<i>i</i>&nbsp;+&nbsp;<i>f</i> bits and not at the width of the underlying
+integer: wrapping operations wrap modulo 2<sup><i>i</i>&nbsp;+&nbsp;<i>f</i></sup>,
+saturating operations saturate to [`MIN`] and [`MAX`] of this type, and
+operators panic on overflow when debug assertions are enabled and wrap
+otherwise.
+
+Operations are computed using the fixed-point number with the same underlying
+integer, for example [`", if_signed_unsigned!($Signedness, "FixedI16", "FixedU16"),
+            "`](crate::", if_signed_unsigned!($Signedness, "FixedI16", "FixedU16"), ")`<FRAC>` when there are from 9 to 16 bits. Division and formatting are
+computed using [`", stringify!($Wide), "`], so they require `FRAC` to be from 0
+to 128.
+
+The inherent methods are conversions using [`from_num`] and [`to_num`],
+addition, subtraction, multiplication, division and negation with their checked,
+saturating, wrapping, unwrapped and overflowing variants, comparisons and
+formatting. The other operations are provided by the [`Fixed`] trait",
+            if_signed_unsigned!($Signedness, " and the [`FixedSigned`] trait", " and the [`FixedUnsigned`] trait"),
+            ", which this type implements with overflow at
+<i>i</i>&nbsp;+&nbsp;<i>f</i> bits like the inherent methods. The bit
+manipulation methods such as [`count_ones`] and [`rotate_left`] only consider
+the <i>i</i>&nbsp;+&nbsp;<i>f</i> bits, while the byte order methods such as
+[`to_be_bytes`] operate on the whole underlying integer. It also implements the
+[`ToFixed`] and [`FromFixed`] traits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", if_signed_unsigned!($Signedness, "FixedI32", "FixedU32"), "};
+// 13 integer bits and 11 fractional bits, stored in 32 bits
+type Fix = ", stringify!($Self), "<13, 11>;
+assert_eq!(core::mem::size_of::<Fix>(), 4);
+// wraps at 24 bits, not at 32 bits
+assert_eq!(Fix::MAX.wrapping_add(Fix::DELTA), Fix::MIN);
+assert_eq!(Fix::MAX.saturating_add(Fix::DELTA), Fix::MAX);
+let x = Fix::from_num(2.5);
+assert_eq!(x.to_num::<", if_signed_unsigned!($Signedness, "FixedI32", "FixedU32"), "<11>>(), 2.5);
+assert_eq!(x.to_string(), \"2.5\");
+```
+
+Generic code can use the number through the [`Fixed`] trait.
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::Fixed, ", stringify!($Self), "};
+fn triple<F: Fixed>(x: F) -> Option<F> {
+    x.checked_mul_int(F::Bits::try_from(3).ok()?)
+}
+type Fix = ", stringify!($Self), "<7, 5>;
+assert_eq!(triple(Fix::from_num(20)), Some(Fix::from_num(60)));
+// 150 does not fit in 12 bits, even though it fits in the underlying 16 bits
+assert_eq!(triple(Fix::from_num(50)), None);
+// only the 12 bits are rotated
+assert_eq!(Fix::DELTA.rotate_right(1), Fix::DELTA.rotate_left(11));
+```
+
+[`Fixed`]: crate::traits::Fixed
+[`FixedSigned`]: crate::traits::FixedSigned
+[`FixedUnsigned`]: crate::traits::FixedUnsigned
+[`count_ones`]: crate::traits::Fixed::count_ones
+[`rotate_left`]: crate::traits::Fixed::rotate_left
+[`to_be_bytes`]: crate::traits::Fixed::to_be_bytes
+[`FromFixed`]: crate::traits::FromFixed
+[`MAX`]: Self::MAX
+[`MIN`]: Self::MIN
+[`Storage`]: crate::types::extra::Storage
+[`ToFixed`]: crate::traits::ToFixed
+[`from_num`]: Self::from_num
+[`to_bits`]: Self::to_bits
+[`to_num`]: Self::to_num
+";
+            #[repr(transparent)]
+            pub struct $Self<const INT: i32, const FRAC: i32>
+            where
+                Width<{ INT + FRAC }>: Storage,
+            {
+                bits: $Bits<INT, FRAC>,
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32> Clone for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage,
+        {
+            #[inline]
+            fn clone(&self) -> $Self<INT, FRAC> {
+                *self
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32> Copy for $Self<INT, FRAC> where
+            Width<{ INT + FRAC }>: Storage
+        {
+        }
+
+        impl<const INT: i32, const FRAC: i32> Default for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage,
+        {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32> Hash for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage,
+        {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.to_bits().hash(state);
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32> PartialEq for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage,
+        {
+            #[inline]
+            fn eq(&self, rhs: &$Self<INT, FRAC>) -> bool {
+                self.to_bits() == rhs.to_bits()
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32> Eq for $Self<INT, FRAC> where
+            Width<{ INT + FRAC }>: Storage
+        {
+        }
+
+        impl<const INT: i32, const FRAC: i32> PartialOrd for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage,
+        {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Self<INT, FRAC>) -> Option<Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32> Ord for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage,
+        {
+            #[inline]
+            fn cmp(&self, rhs: &$Self<INT, FRAC>) -> Ordering {
+                self.to_bits().cmp(&rhs.to_bits())
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32> $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage,
+        {
+            comment! {
+                "Zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::ZERO, Fix::from_bits(0));
+```
+";
+                pub const ZERO: $Self<INT, FRAC> = $Self {
+                    bits: <Width<{ INT + FRAC }> as Storage>::$ZERO,
+                };
+            }
+
+            comment! {
+                "The difference between any two successive representable numbers, <i>Δ</i>.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::DELTA, Fix::from_bits(1));
+```
+";
+                pub const DELTA: $Self<INT, FRAC> = $Self {
+                    bits: <Width<{ INT + FRAC }> as Storage>::$ONE,
+                };
+            }
+
+            comment! {
+                "The smallest value that can be represented.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::MIN, Fix::from_bits(",
+                if_signed_unsigned!($Signedness, "-1 << 13", "0"),
+                "));
+```
+";
+                pub const MIN: $Self<INT, FRAC> = $Self {
+                    bits: <Width<{ INT + FRAC }> as Storage>::$MIN,
+                };
+            }
+
+            comment! {
+                "The largest value that can be represented.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::MAX, Fix::from_bits((1 << ",
+                if_signed_unsigned!($Signedness, "13", "14"),
+                ") - 1));
+```
+";
+                pub const MAX: $Self<INT, FRAC> = $Self {
+                    bits: <Width<{ INT + FRAC }> as Storage>::$MAX,
+                };
+            }
+
+            comment! {
+                "The number of integer bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::INT_NBITS, 10);
+```
+";
+                pub const INT_NBITS: u32 = INT as u32;
+            }
+
+            comment! {
+                "The number of fractional bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::FRAC_NBITS, 4);
+```
+";
+                pub const FRAC_NBITS: u32 = FRAC as u32;
+            }
+
+            comment! {
+                "Creates a fixed-point number that has a bitwise representation
+identical to the given integer.
+
+The underlying integer is the smallest primitive integer with at least
+<i>i</i>&nbsp;+&nbsp;<i>f</i> bits, where <i>i</i>&nbsp;=&nbsp;`INT` and
+<i>f</i>&nbsp;=&nbsp;`FRAC`.
+
+# Panics
+
+When debug assertions are enabled, panics if `bits` does not fit in
+<i>i</i>&nbsp;+&nbsp;<i>f</i> bits. When debug assertions are not enabled, the
+wrapped value is returned, which is the same as the value returned by
+[`wrapping_from_bits`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+// 0010.0000 = 2
+assert_eq!(Fix::from_bits(0b10_0000), Fix::from_num(2));
+```
+
+[`wrapping_from_bits`]: Self::wrapping_from_bits
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn from_bits(bits: $Bits<INT, FRAC>) -> $Self<INT, FRAC> {
+                    let (ans, overflow) = $Self::overflowing_from_bits(bits);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its bitwise representation
+if `bits` fits in <i>i</i>&nbsp;+&nbsp;<i>f</i> bits, where
+<i>i</i>&nbsp;=&nbsp;`INT` and <i>f</i>&nbsp;=&nbsp;`FRAC`, otherwise returns
+[`None`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+let max_bits = Fix::MAX.to_bits();
+assert_eq!(Fix::checked_from_bits(max_bits), Some(Fix::MAX));
+assert_eq!(Fix::checked_from_bits(max_bits + 1), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn checked_from_bits(bits: $Bits<INT, FRAC>) -> Option<$Self<INT, FRAC>> {
+                    match $Self::overflowing_from_bits(bits) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from the low
+<i>i</i>&nbsp;+&nbsp;<i>f</i> bits of `bits`, where <i>i</i>&nbsp;=&nbsp;`INT`
+and <i>f</i>&nbsp;=&nbsp;`FRAC`, wrapping if it does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+let max_bits = Fix::MAX.to_bits();
+assert_eq!(Fix::wrapping_from_bits(max_bits + 1), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn wrapping_from_bits(bits: $Bits<INT, FRAC>) -> $Self<INT, FRAC> {
+                    $Self::overflowing_from_bits(bits).0
+                }
+            }
+
+            #[inline]
+            fn overflowing_from_bits(bits: $Bits<INT, FRAC>) -> ($Self<INT, FRAC>, bool) {
+                let pad = <Width<{ INT + FRAC }> as Storage>::PAD;
+                let wrapped = (bits << pad) >> pad;
+                ($Self { bits: wrapped }, wrapped != bits)
+            }
+
+            comment! {
+                "Creates an integer that has a bitwise representation identical
+to the given fixed-point number.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+// 2 is 0010.0000
+assert_eq!(Fix::from_num(2).to_bits(), 0b10_0000);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn to_bits(self) -> $Bits<INT, FRAC> {
+                    let pad = <Width<{ INT + FRAC }> as Storage>::PAD;
+                    (self.bits << pad) >> pad
+                }
+            }
+
+            #[inline]
+            fn fixed(self) -> $Fixed<INT, FRAC> {
+                Fixed::from_bits(self.to_bits())
+            }
+
+            #[inline]
+            fn overflowing_from_fixed_bits(fixed: $Fixed<INT, FRAC>) -> ($Self<INT, FRAC>, bool) {
+                $Self::overflowing_from_bits(fixed.to_bits())
+            }
+
+            #[inline]
+            fn saturating_from_fixed_bits(fixed: $Fixed<INT, FRAC>) -> $Self<INT, FRAC> {
+                let bits = fixed.to_bits();
+                if bits < $Self::<INT, FRAC>::MIN.to_bits() {
+                    $Self::MIN
+                } else if bits > $Self::<INT, FRAC>::MAX.to_bits() {
+                    $Self::MAX
+                } else {
+                    $Self { bits }
+                }
+            }
+
+            // The number of bits, which is from 1 to 128.
+            const NBITS: u32 = (INT + FRAC) as u32;
+
+            comment! {
+                "Creates a fixed-point number from another number.
+
+The other number can be a primitive integer or floating-point number, or
+another fixed-point number. Rounding and overflow work as in the
+[`from_num`] method of the fixed-point numbers, except that the result has to
+fit in <i>i</i>&nbsp;+&nbsp;<i>f</i> bits, where <i>i</i>&nbsp;=&nbsp;`INT` and
+<i>f</i>&nbsp;=&nbsp;`FRAC`.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit. When debug
+assertions are not enabled, the wrapped value can be returned, but it is not
+considered a breaking change if in the future it panics; if wrapping is required
+use [`wrapping_from_num`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::from_num(3), Fix::from_bits(0b11_0000));
+assert_eq!(Fix::from_num(1.75), Fix::from_bits(0b1_1100));
+```
+
+[`from_num`]: crate::FixedI32::from_num
+[`wrapping_from_num`]: Self::wrapping_from_num
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                pub fn from_num<Src: ToFixed>(src: Src) -> $Self<INT, FRAC> {
+                    let (ans, overflow) = $Self::overflowing_from_num(src);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number if it fits,
+otherwise returns [`None`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::checked_from_num(3), Some(Fix::from_bits(0b11_0000)));
+// 1024 needs 11 integer bits
+assert_eq!(Fix::checked_from_num(1024), None);
+```
+";
+                #[inline]
+                pub fn checked_from_num<Src: ToFixed>(src: Src) -> Option<$Self<INT, FRAC>> {
+                    match $Self::overflowing_from_num(src) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number, saturating if
+it does not fit.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::saturating_from_num(3), Fix::from_bits(0b11_0000));
+assert_eq!(Fix::saturating_from_num(1024), Fix::MAX);
+assert_eq!(Fix::saturating_from_num(-1024), Fix::MIN);
+```
+
+[NaN]: f64::is_nan
+";
+                #[inline]
+                #[track_caller]
+                pub fn saturating_from_num<Src: ToFixed>(src: Src) -> $Self<INT, FRAC> {
+                    $Self::saturating_from_fixed_bits(src.saturating_to_fixed())
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number, wrapping the
+value on overflow.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::wrapping_from_num(3), Fix::from_bits(0b11_0000));
+// 1024 wraps to zero with 10 integer bits
+assert_eq!(Fix::wrapping_from_num(1024), Fix::ZERO);
+```
+
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                pub fn wrapping_from_num<Src: ToFixed>(src: Src) -> $Self<INT, FRAC> {
+                    $Self::overflowing_from_num(src).0
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number, panicking on
+overflow.
+
+# Panics
+
+Panics if the value does not fit, even when debug assertions are not enabled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::unwrapped_from_num(3), Fix::from_bits(0b11_0000));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+let _overflow = Fix::unwrapped_from_num(1024);
+```
+";
+                #[inline]
+                #[track_caller]
+                pub fn unwrapped_from_num<Src: ToFixed>(src: Src) -> $Self<INT, FRAC> {
+                    match $Self::overflowing_from_num(src) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::overflowing_from_num(3), (Fix::from_bits(0b11_0000), false));
+assert_eq!(Fix::overflowing_from_num(1024), (Fix::ZERO, true));
+```
+
+[finite]: f64::is_finite
+";
+                #[inline]
+                #[track_caller]
+                pub fn overflowing_from_num<Src: ToFixed>(src: Src) -> ($Self<INT, FRAC>, bool) {
+                    let (fixed, fixed_overflow) = src.overflowing_to_fixed();
+                    let (ans, overflow) = $Self::overflowing_from_fixed_bits(fixed);
+                    (ans, fixed_overflow || overflow)
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number.
+
+The other number can be a primitive integer or floating-point number, or
+another fixed-point number.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit. When debug
+assertions are not enabled, the wrapped value can be returned, but it is not
+considered a breaking change if in the future it panics; if wrapping is required
+use [`wrapping_to_num`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::from_num(3.75).to_num::<i32>(), 3);
+assert_eq!(Fix::from_num(3.75).to_num::<f32>(), 3.75);
+```
+
+[`wrapping_to_num`]: Self::wrapping_to_num
+";
+                #[inline]
+                #[track_caller]
+                pub fn to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number if it fits,
+otherwise returns [`None`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::from_num(3.75).checked_to_num::<u8>(), Some(3));
+assert_eq!(Fix::MAX.checked_to_num::<u8>(), None);
+```
+";
+                #[inline]
+                pub fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> {
+                    Dst::checked_from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number, saturating the
+value on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::from_num(3.75).saturating_to_num::<u8>(), 3);
+assert_eq!(Fix::MAX.saturating_to_num::<u8>(), u8::MAX);
+```
+";
+                #[inline]
+                pub fn saturating_to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::saturating_from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number, wrapping the
+value on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::from_num(3.75).wrapping_to_num::<u8>(), 3);
+assert_eq!(Fix::from_num(258).wrapping_to_num::<u8>(), 2);
+```
+";
+                #[inline]
+                pub fn wrapping_to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::wrapping_from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number, panicking on
+overflow.
+
+# Panics
+
+Panics if the value does not fit, even when debug assertions are not enabled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::from_num(3.75).unwrapped_to_num::<u8>(), 3);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+let _overflow = Fix::MAX.unwrapped_to_num::<u8>();
+```
+";
+                #[inline]
+                #[track_caller]
+                pub fn unwrapped_to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::unwrapped_from_fixed(self.fixed())
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number.
+
+Returns a [tuple] of the number and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+assert_eq!(Fix::from_num(3.75).overflowing_to_num::<u8>(), (3, false));
+assert_eq!(Fix::from_num(258).overflowing_to_num::<u8>(), (2, true));
+```
+";
+                #[inline]
+                pub fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) {
+                    Dst::overflowing_from_fixed(self.fixed())
+                }
+            }
+
+            narrow_op! {
+                Self = $Self<INT, FRAC>,
+                fn = [pub fn],
+                example = "<10, 4>",
+                {wide, overflowing_narrow, saturating_narrow} =
+                    {fixed, overflowing_from_fixed_bits, saturating_from_fixed_bits},
+                {op, result} = {"addition", "sum"},
+                {checked, saturating, wrapping, unwrapped, overflowing} =
+                    {checked_add, saturating_add, wrapping_add, unwrapped_add, overflowing_add},
+                {lhs, rhs, ans} = {"1.5", "0.25", "1.75"},
+                {over_lhs, over_rhs, sat, wrap} = {"Fix::MAX", "Fix::DELTA", "Fix::MAX", "Fix::MIN"},
+                panics = "",
+            }
+
+            narrow_op! {
+                Self = $Self<INT, FRAC>,
+                fn = [pub fn],
+                example = "<10, 4>",
+                {wide, overflowing_narrow, saturating_narrow} =
+                    {fixed, overflowing_from_fixed_bits, saturating_from_fixed_bits},
+                {op, result} = {"subtraction", "difference"},
+                {checked, saturating, wrapping, unwrapped, overflowing} =
+                    {checked_sub, saturating_sub, wrapping_sub, unwrapped_sub, overflowing_sub},
+                {lhs, rhs, ans} = {"1.5", "0.25", "1.25"},
+                {over_lhs, over_rhs, sat, wrap} = {"Fix::MIN", "Fix::DELTA", "Fix::MIN", "Fix::MAX"},
+                panics = "",
+            }
+
+            narrow_op! {
+                Self = $Self<INT, FRAC>,
+                fn = [pub fn],
+                example = "<10, 4>",
+                {wide, overflowing_narrow, saturating_narrow} =
+                    {fixed, overflowing_from_fixed_bits, saturating_from_fixed_bits},
+                {op, result} = {"multiplication", "product"},
+                {checked, saturating, wrapping, unwrapped, overflowing} =
+                    {checked_mul, saturating_mul, wrapping_mul, unwrapped_mul, overflowing_mul},
+                {lhs, rhs, ans} = {"1.5", "0.25", "0.375"},
+                {over_lhs, over_rhs, sat, wrap} = {
+                    "Fix::MAX",
+                    "Fix::from_num(2)",
+                    "Fix::MAX",
+                    "Fix::wrapping_from_bits(Fix::MAX.to_bits() * 2)"
+                },
+                panics = "",
+            }
+
+            comment! {
+                "Checked negation. Returns the negated value, or [`None`] on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).checked_neg(), Some(Fix::from_num(-5)));
+assert_eq!(Fix::MIN.checked_neg(), None);",
+                    "assert_eq!(Fix::ZERO.checked_neg(), Some(Fix::ZERO));
+assert_eq!(Fix::from_num(5).checked_neg(), None);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn checked_neg(self) -> Option<$Self<INT, FRAC>> {
+                    match self.overflowing_neg() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating negation. Returns the negated value, saturating on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "This method always returns zero.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).saturating_neg(), Fix::from_num(-5));
+assert_eq!(Fix::MIN.saturating_neg(), Fix::MAX);",
+                    "assert_eq!(Fix::ZERO.saturating_neg(), Fix::ZERO);
+assert_eq!(Fix::from_num(5).saturating_neg(), Fix::ZERO);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_neg(self) -> $Self<INT, FRAC> {
+                    $Self::saturating_from_fixed_bits(self.fixed().saturating_neg())
+                }
+            }
+
+            comment! {
+                "Wrapping negation. Returns the negated value, wrapping on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).wrapping_neg(), Fix::from_num(-5));
+assert_eq!(Fix::MIN.wrapping_neg(), Fix::MIN);",
+                    "assert_eq!(Fix::ZERO.wrapping_neg(), Fix::ZERO);
+assert_eq!(Fix::DELTA.wrapping_neg(), Fix::MAX);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn wrapping_neg(self) -> $Self<INT, FRAC> {
+                    self.overflowing_neg().0
+                }
+            }
+
+            comment! {
+                "Unwrapped negation. Returns the negated value, panicking on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).unwrapped_neg(), Fix::from_num(-5));",
+                    "assert_eq!(Fix::ZERO.unwrapped_neg(), Fix::ZERO);",
+                ),
+                "
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+let _overflow = ",
+                if_signed_unsigned!($Signedness, "Fix::MIN", "Fix::DELTA"),
+                ".unwrapped_neg();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn unwrapped_neg(self) -> $Self<INT, FRAC> {
+                    match self.overflowing_neg() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing negation.
+
+Returns a [tuple] of the negated value and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<10, 4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).overflowing_neg(), (Fix::from_num(-5), false));
+assert_eq!(Fix::MIN.overflowing_neg(), (Fix::MIN, true));",
+                    "assert_eq!(Fix::ZERO.overflowing_neg(), (Fix::ZERO, false));
+assert_eq!(Fix::DELTA.overflowing_neg(), (Fix::MAX, true));",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_neg(self) -> ($Self<INT, FRAC>, bool) {
+                    let (fixed, fixed_overflow) = self.fixed().overflowing_neg();
+                    let (ans, overflow) = $Self::overflowing_from_fixed_bits(fixed);
+                    (ans, fixed_overflow || overflow)
+                }
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32> $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage,
+            If<{ (0 <= FRAC) & (FRAC <= 128) }>: True,
+        {
+            // Division is computed using the 128-bit number, as the bound on
+            // the number of fractional bits of the underlying fixed-point
+            // number cannot be expressed.
+            #[inline]
+            fn wide(self) -> $Wide<FRAC> {
+                self.to_num()
+            }
+
+            narrow_op! {
+                Self = $Self<INT, FRAC>,
+                fn = [pub fn],
+                example = "<10, 4>",
+                {wide, overflowing_narrow, saturating_narrow} =
+                    {wide, overflowing_from_num, saturating_from_num},
+                {op, result} = {"division", "quotient"},
+                {checked, saturating, wrapping, unwrapped, overflowing} =
+                    {checked_div, saturating_div, wrapping_div, unwrapped_div, overflowing_div},
+                {lhs, rhs, ans} = {"1.5", "0.25", "6"},
+                {over_lhs, over_rhs, sat, wrap} = {
+                    "Fix::MAX",
+                    "Fix::from_num(0.5)",
+                    "Fix::MAX",
+                    "Fix::wrapping_from_bits(Fix::MAX.to_bits() * 2)"
+                },
+                panics = "
+# Panics
+
+Panics if the divisor is zero.
+",
+            }
+        }
+
+        narrow_op_trait! {
+            $Self<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,];
+            Add add, AddAssign add_assign, overflowing_add
+        }
+        narrow_op_trait! {
+            $Self<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,];
+            Sub sub, SubAssign sub_assign, overflowing_sub
+        }
+        narrow_op_trait! {
+            $Self<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,];
+            Mul mul, MulAssign mul_assign, overflowing_mul
+        }
+        narrow_op_trait! {
+            $Self<INT, FRAC>, where [
+                Width<{ INT + FRAC }>: Storage,
+                If<{ (0 <= FRAC) & (FRAC <= 128) }>: True,
+            ];
+            Div div, DivAssign div_assign, overflowing_div
+        }
+
+        impl<const INT: i32, const FRAC: i32> Neg for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage,
+        {
+            type Output = $Self<INT, FRAC>;
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> $Self<INT, FRAC> {
+                let (ans, overflow) = self.overflowing_neg();
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        narrow_fmt! {
+            $Self<INT, FRAC>, where [
+                Width<{ INT + FRAC }>: Storage,
+                If<{ (0 <= FRAC) & (FRAC <= 128) }>: True,
+            ], wide;
+            Display Binary Octal LowerHex UpperHex LowerExp UpperExp
+        }
+
+        narrow_fixed! {
+            Self = $Self<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,];
+            Signedness = $Signedness,
+            {Bits, NonZeroBits, Fixed} =
+                {$Bits<INT, FRAC>, $NonZeroBits<INT, FRAC>, $Fixed<INT, FRAC>},
+            {Signed, Unsigned} = {FixedI<INT, FRAC>, FixedU<INT, FRAC>},
+            {TRY_ONE, TRY_NEG_ONE} = {
+                match <<Width<{ INT + FRAC }> as Storage>::Frac<FRAC> as StorageFrac>::$TRY_ONE {
+                    Some(bits) => Some($Self { bits }),
+                    None => None,
+                },
+                match <<Width<{ INT + FRAC }> as Storage>::Frac<FRAC> as StorageFrac>::SIGNED_TRY_NEG_ONE {
+                    Some(bits) => Some($Self { bits }),
+                    None => None,
+                }
+            },
+            bytes = {},
+        }
+
+
+        // Multiplication and division by the underlying integer are implemented
+        // generically with the integer constrained by the Storage bound, so
+        // that they do not conflict with the operations on the number itself.
+        // The bounds of the underlying integer are not known through the
+        // constraint, so they are repeated.
+        impl<const INT: i32, const FRAC: i32, Rhs> Mul<Rhs> for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage<$Signedness = Rhs>,
+            Rhs: FixedBits + Divisor<Bits = Rhs>,
+        {
+            type Output = $Self<INT, FRAC>;
+            #[inline]
+            #[track_caller]
+            fn mul(self, rhs: Rhs) -> $Self<INT, FRAC> {
+                let (ans, overflow) = self.overflowing_mul_int(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32, Rhs> MulAssign<Rhs> for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage<$Signedness = Rhs>,
+            Rhs: FixedBits + Divisor<Bits = Rhs>,
+        {
+            #[inline]
+            #[track_caller]
+            fn mul_assign(&mut self, rhs: Rhs) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32, Rhs: Divisor> Div<Rhs> for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage<$Signedness = Rhs::Bits>,
+            Rhs::Bits: FixedBits + Divisor<Bits = Rhs::Bits>,
+        {
+            type Output = $Self<INT, FRAC>;
+            #[inline]
+            #[track_caller]
+            fn div(self, rhs: Rhs) -> $Self<INT, FRAC> {
+                let (ans, overflow) = self.overflowing_div_int(rhs.divisor_bits());
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<const INT: i32, const FRAC: i32, Rhs: Divisor> DivAssign<Rhs> for $Self<INT, FRAC>
+        where
+            Width<{ INT + FRAC }>: Storage<$Signedness = Rhs::Bits>,
+            Rhs::Bits: FixedBits + Divisor<Bits = Rhs::Bits>,
+        {
+            #[inline]
+            #[track_caller]
+            fn div_assign(&mut self, rhs: Rhs) {
+                *self = *self / rhs;
+            }
+        }
+    };
+}
+
+fixed_int_frac! {
+    description = "A signed",
+    {Self, Bits, Fixed, Wide} = {FixedI, SignedBits, SignedFixed, FixedI128},
+    Signedness = Signed,
+    NonZeroBits = SignedNonZeroBits,
+    {ZERO, ONE, TRY_ONE, MIN, MAX} =
+        {SIGNED_ZERO, SIGNED_ONE, SIGNED_TRY_ONE, SIGNED_MIN, SIGNED_MAX},
+}
+fixed_int_frac! {
+    description = "An unsigned",
+    {Self, Bits, Fixed, Wide} = {FixedU, UnsignedBits, UnsignedFixed, FixedU128},
+    Signedness = Unsigned,
+    NonZeroBits = UnsignedNonZeroBits,
+    {ZERO, ONE, TRY_ONE, MIN, MAX} =
+        {UNSIGNED_ZERO, UNSIGNED_ONE, UNSIGNED_TRY_ONE, UNSIGNED_ZERO, UNSIGNED_MAX},
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        traits::{Fixed, FixedSigned, FixedUnsigned},
+        FixedI, FixedI16, FixedI32, FixedU, FixedU128,
+    };
+    use std::{format, string::ToString};
+
+    #[test]
+    fn storage() {
+        assert_eq!(core::mem::size_of::<FixedI<1, 0>>(), 1);
+        assert_eq!(core::mem::size_of::<FixedU<5, 3>>(), 1);
+        assert_eq!(core::mem::size_of::<FixedI<5, 4>>(), 2);
+        assert_eq!(core::mem::size_of::<FixedI<13, 11>>(), 4);
+        assert_eq!(core::mem::size_of::<FixedU<0, 33>>(), 8);
+        assert_eq!(core::mem::size_of::<FixedI<100, 28>>(), 16);
+        assert_eq!(FixedI::<1, 0>::MIN.to_bits(), -1);
+        assert_eq!(FixedI::<1, 0>::MAX.to_bits(), 0);
+        assert_eq!(FixedU::<1, 0>::MAX.to_bits(), 1);
+        assert_eq!(FixedI::<13, 11>::MIN.to_bits(), -1 << 23);
+        assert_eq!(FixedU::<64, 64>::MAX.to_bits(), u128::MAX);
+        assert_eq!(FixedI::<{ -2 }, 10>::MAX.to_bits(), i8::MAX);
+    }
+
+    #[test]
+    fn bits() {
+        type I = FixedI<7, 5>;
+        type U = FixedU<7, 5>;
+        assert_eq!(I::checked_from_bits(0x7FF), Some(I::MAX));
+        assert_eq!(I::checked_from_bits(0x800), None);
+        assert_eq!(I::checked_from_bits(-0x800), Some(I::MIN));
+        assert_eq!(I::checked_from_bits(-0x801), None);
+        assert_eq!(I::wrapping_from_bits(0x1234).to_bits(), 0x234);
+        assert_eq!(I::wrapping_from_bits(0x1834).to_bits(), -0x7CC);
+        assert_eq!(U::checked_from_bits(0x1000), None);
+        assert_eq!(U::wrapping_from_bits(0xF234).to_bits(), 0x234);
+        // the bits that do not fit in INT + FRAC bits are ignored
+        let x: I = bytemuck::cast(0x7800i16);
+        assert_eq!(x, I::MIN);
+        assert_eq!(x.to_bits(), -0x800);
+        assert_eq!(bytemuck::cast::<u16, U>(0xF001), U::DELTA);
+    }
+
+    // Checks the binary operations against 64-bit integer arithmetic wrapped
+    // to 12 bits, with 5 fractional bits.
+    macro_rules! check_ops {
+        ($Fix:ident, $signed:expr) => {{
+            type F = $Fix<7, 5>;
+            let (min, max) = (i64::from(F::MIN.to_bits()), i64::from(F::MAX.to_bits()));
+            let wrap = |x: i64| if $signed { (x << 52) >> 52 } else { x & 0xFFF };
+            for a in (min..=max)
+                .step_by(13)
+                .chain([min, max, min + 1, max - 1, 0, 1])
+            {
+                for b in (min..=max).step_by(29).chain([min, max, -1, 0, 1, 32]) {
+                    if !(min..=max).contains(&b) {
+                        continue;
+                    }
+                    let x = F::from_num(a as f64 / 32.0);
+                    let y = F::from_num(b as f64 / 32.0);
+                    let div = (b != 0).then(|| (a << 5) / b);
+                    let exact = [
+                        ('+', Some(a + b)),
+                        ('-', Some(a - b)),
+                        ('*', Some((a * b) >> 5)),
+                        ('/', div),
+                    ];
+                    for (op, exact) in exact {
+                        let Some(exact) = exact else { continue };
+                        let (wrapped, overflowing, checked, saturating) = match op {
+                            '+' => (
+                                x.wrapping_add(y),
+                                x.overflowing_add(y),
+                                x.checked_add(y),
+                                x.saturating_add(y),
+                            ),
+                            '-' => (
+                                x.wrapping_sub(y),
+                                x.overflowing_sub(y),
+                                x.checked_sub(y),
+                                x.saturating_sub(y),
+                            ),
+                            '*' => (
+                                x.wrapping_mul(y),
+                                x.overflowing_mul(y),
+                                x.checked_mul(y),
+                                x.saturating_mul(y),
+                            ),
+                            _ => (
+                                x.wrapping_div(y),
+                                x.overflowing_div(y),
+                                x.checked_div(y),
+                                x.saturating_div(y),
+                            ),
+                        };
+                        let fits = (min..=max).contains(&exact);
+                        let wrapped_bits = i64::from(wrapped.to_bits());
+                        assert_eq!(wrapped_bits, wrap(exact), "{a} {op} {b}");
+                        assert_eq!(overflowing, (wrapped, !fits), "{a} {op} {b}");
+                        assert_eq!(checked, fits.then_some(wrapped), "{a} {op} {b}");
+                        let sat = i64::from(saturating.to_bits());
+                        assert_eq!(sat, exact.clamp(min, max), "{a} {op} {b}");
+                    }
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn ops() {
+        check_ops!(FixedI, true);
+        check_ops!(FixedU, false);
+    }
+
+    #[test]
+    fn full_width() {
+        type U = FixedU<64, 64>;
+        assert_eq!(U::MAX.overflowing_add(U::DELTA), (U::ZERO, true));
+        assert_eq!(U::MAX.saturating_mul(U::from_num(2)), U::MAX);
+        assert_eq!(U::from_num(3) / U::from_num(2), U::from_num(1.5));
+        assert_eq!(U::MAX.to_num::<FixedU128<64>>(), FixedU128::<64>::MAX);
+        type I = FixedI<1, 0>;
+        assert_eq!(I::MIN.overflowing_neg(), (I::MIN, true));
+        assert_eq!(I::MIN.saturating_neg(), I::ZERO);
+        assert_eq!(I::MIN.overflowing_add(I::MIN), (I::ZERO, true));
+        assert_eq!(I::MIN.overflowing_mul(I::MIN), (I::MIN, true));
+    }
+
+    #[test]
+    fn neg() {
+        type I = FixedI<7, 5>;
+        type U = FixedU<7, 5>;
+        assert_eq!(I::MIN.overflowing_neg(), (I::MIN, true));
+        assert_eq!(I::MIN.saturating_neg(), I::MAX);
+        assert_eq!(-I::from_num(5), I::from_num(-5));
+        assert_eq!(U::DELTA.overflowing_neg(), (U::MAX, true));
+        assert_eq!(U::DELTA.checked_neg(), None);
+    }
+
+    #[test]
+    fn from_to_num() {
+        type I = FixedI<13, 11>;
+        assert_eq!(I::from_num(-1.5).to_bits(), -0xC00);
+        assert_eq!(I::checked_from_num(4096), None);
+        assert_eq!(I::checked_from_num(-4096), Some(I::MIN));
+        assert_eq!(I::saturating_from_num(1e10), I::MAX);
+        // wraps at 24 bits even though the value fits in the underlying i32
+        assert_eq!(I::overflowing_from_num(4096), (I::MIN, true));
+        assert_eq!(I::wrapping_from_num(8193), I::from_num(1));
+        assert_eq!(
+            I::from_num(FixedI16::<4>::from_num(2.25)),
+            I::from_num(2.25)
+        );
+        let x = I::from_num(-2.75);
+        assert_eq!(x.to_num::<i8>(), -3);
+        assert_eq!(x.to_num::<f32>(), -2.75);
+        assert_eq!(x.to_num::<FixedI<4, 4>>(), FixedI::<4, 4>::from_num(-2.75));
+        assert_eq!(FixedI32::<4>::from_num(x), FixedI32::<4>::from_num(-2.75));
+        assert_eq!(I::MAX.overflowing_to_num::<i8>(), (-1, true));
+    }
+
+    #[test]
+    fn format() {
+        let x = FixedI::<13, 11>::from_num(-2.75);
+        assert_eq!(x.to_string(), "-2.75");
+        assert_eq!(format!("{x:?}"), "-2.75");
+        assert_eq!(format!("{x:b}"), "-10.11");
+        assert_eq!(format!("{:X}", FixedU::<4, 4>::from_num(10.5)), "A.8");
+        assert_eq!(format!("{:.1}", FixedU::<8, 8>::from_num(0.25)), "0.2");
+    }
+
+    // Only uses the Fixed trait, so it checks that the trait is implemented.
+    fn sum_int_times<F: Fixed>(a: F, b: F, int: F::Bits) -> (F, bool) {
+        let (prod, overflow) = a.overflowing_mul_int(int);
+        let (sum, overflow2) = prod.overflowing_add(b);
+        (sum, overflow | overflow2)
+    }
+
+    #[test]
+    fn fixed_trait() {
+        type I = FixedI<7, 5>;
+        type U = FixedU<7, 5>;
+        let (a, b) = (I::from_num(1.5), I::from_num(-0.25));
+        assert_eq!(sum_int_times(a, b, 3), (I::from_num(4.25), false));
+        assert_eq!(sum_int_times(a, b, 50), (I::from_num(-53.25), true));
+        assert_eq!(sum_int_times(U::DELTA, U::ZERO, 4095), (U::MAX, false));
+        assert_eq!(<I as Fixed>::TRY_ONE, Some(I::from_num(1)));
+        assert_eq!(<FixedI<1, 4> as Fixed>::TRY_ONE, None);
+        assert_eq!(<FixedU<1, 4> as Fixed>::TRY_ONE.unwrap().to_bits(), 0x10);
+        assert_eq!(
+            <FixedI<1, 4> as FixedSigned>::TRY_NEG_ONE
+                .unwrap()
+                .to_bits(),
+            -0x10
+        );
+        assert_eq!(<I as Fixed>::INT_BITS, 7);
+        assert_eq!(<U as Fixed>::FRAC_BITS, 5);
+        assert_eq!(I::from_num(3) * 4, I::from_num(12));
+        assert_eq!(
+            U::from_num(3) / core::num::NonZeroU16::new(4).unwrap(),
+            0.75
+        );
+        assert_eq!(I::from_num(-3) / 4i16, -0.75);
+        assert_eq!(I::from_num(3.5) % I::from_num(2), 1.5);
+        assert_eq!(!I::ZERO, -I::DELTA);
+        assert_eq!(!U::ZERO, U::MAX);
+        assert!(I::from_num(2.5) > 2);
+        assert!(U::from_num(2.5) < 2.75f32);
+        let nums = [I::from_num(1.5), I::from_num(2), I::from_num(-3)];
+        assert_eq!(nums.iter().copied().sum::<I>(), 0.5);
+        assert_eq!(nums.iter().copied().product::<I>(), -9);
+        assert_eq!(core::iter::empty::<I>().product::<I>(), 1);
+    }
+
+    #[test]
+    fn fixed_trait_narrow() {
+        type I = FixedI<7, 5>;
+        type U = FixedU<7, 5>;
+        // methods computed by the underlying FixedI16 overflow at 12 bits
+        assert_eq!(I::MAX.overflowing_ceil(), (I::MIN, true));
+        assert_eq!(
+            I::from_num(40).overflowing_mul_int(2),
+            (I::from_num(-48), true)
+        );
+        assert_eq!(I::from_num(40).checked_dist(I::from_num(-40)), None);
+        let hypot = FixedI16::<5>::from_num(40).hypot(FixedI16::<5>::from_num(60));
+        assert_eq!(
+            I::from_num(40).overflowing_hypot(I::from_num(60)),
+            (I::wrapping_from_num(hypot), true)
+        );
+        assert_eq!(
+            I::from_num(40).overflowing_mul_add(FixedI16::<0>::from_num(2), I::ZERO),
+            (I::from_num(-48), true)
+        );
+        assert_eq!(
+            I::from_num(50).checked_next_multiple_of(I::from_num(48)),
+            None
+        );
+        // bit methods only consider the 12 bits
+        assert_eq!(I::from_num(-1).count_ones(), 7);
+        assert_eq!(I::from_num(-1).count_zeros(), 5);
+        assert_eq!(I::from_num(-1).leading_ones(), 7);
+        assert_eq!(I::DELTA.leading_zeros(), 11);
+        assert_eq!(I::ZERO.trailing_zeros(), 12);
+        assert_eq!(U::MAX.trailing_ones(), 12);
+        assert_eq!(I::DELTA.reverse_bits(), I::MIN);
+        assert_eq!(U::MAX.reverse_bits(), U::MAX);
+        assert_eq!(I::MIN.rotate_left(1), I::DELTA);
+        assert_eq!(I::DELTA.rotate_right(1), I::MIN);
+        assert_eq!(I::DELTA.rotate_right(13), I::MIN);
+        assert_eq!(I::from_num(-1).to_be_bytes(), [0xFF, 0xE0]);
+        assert_eq!(I::from_le_bytes([0xE0, 0xFF]), -1);
+        assert_eq!(U::from_num(1).to_le_bytes(), [0x20, 0x00]);
+        // shifts overflow at 12 bits
+        assert_eq!(I::DELTA.overflowing_shl(11), (I::MIN, false));
+        assert_eq!(I::DELTA.overflowing_shl(12), (I::DELTA, true));
+        assert_eq!(I::MIN.overflowing_shr(13), (I::MIN >> 1u8, true));
+        assert_eq!(I::DELTA.checked_shl(12), None);
+        assert_eq!(U::MAX >> 11, U::DELTA);
+        assert_eq!(U::MAX << 11i64, U::from_bits(0x800));
+    }
+
+    #[test]
+    fn fixed_signed() {
+        type I = FixedI<7, 5>;
+        type U = FixedU<7, 5>;
+        assert_eq!(I::from_num(-1).signed_bits(), 6);
+        assert_eq!(I::MIN.signed_bits(), 12);
+        assert_eq!(I::MIN.overflowing_abs(), (I::MIN, true));
+        assert_eq!(I::MIN.unsigned_abs(), U::from_num(64));
+        assert_eq!(I::MIN.unsigned_dist(I::MAX), U::MAX);
+        assert_eq!(I::from_num(-5).signum(), -1);
+        assert_eq!(
+            FixedI::<1, 4>::DELTA.overflowing_signum(),
+            (FixedI::<1, 4>::MIN, true)
+        );
+        assert_eq!(
+            FixedI::<1, 4>::DELTA.saturating_signum(),
+            FixedI::<1, 4>::MAX
+        );
+        assert_eq!(
+            FixedI::<0, 4>::MIN.overflowing_signum(),
+            (FixedI::<0, 4>::ZERO, true)
+        );
+        assert_eq!(I::MAX.overflowing_add_unsigned(U::DELTA), (I::MIN, true));
+        assert_eq!(I::MIN.overflowing_add_unsigned(U::MAX), (I::MAX, false));
+        assert_eq!(I::MIN.checked_sub_unsigned(U::DELTA), None);
+        assert_eq!(I::MAX.saturating_sub_unsigned(U::MAX), I::MIN);
+    }
+
+    #[test]
+    fn fixed_unsigned() {
+        type I = FixedI<7, 5>;
+        type U = FixedU<7, 5>;
+        assert_eq!(U::MAX.significant_bits(), 12);
+        assert!(U::from_num(2).is_power_of_two());
+        assert_eq!(U::MAX.highest_one(), U::from_num(64));
+        assert_eq!(U::from_num(33).next_power_of_two(), 64);
+        assert_eq!(U::from_num(65).checked_next_power_of_two(), None);
+        assert_eq!(U::from_num(65).wrapping_next_power_of_two(), U::ZERO);
+        assert_eq!(
+            U::MAX.overflowing_add_signed(-I::DELTA),
+            (U::MAX - U::DELTA, false)
+        );
+        assert_eq!(U::ZERO.overflowing_add_signed(-I::DELTA), (U::MAX, true));
+        assert_eq!(
+            U::ZERO.overflowing_sub_signed(I::MIN),
+            (U::from_num(64), false)
+        );
+        assert_eq!(U::MAX.saturating_sub_signed(I::MIN), U::MAX);
+        assert_eq!(U::ZERO.saturating_add_signed(I::MIN), U::ZERO);
+    }
+
+    #[test]
+    fn sum_prods() {
+        type I = FixedI<7, 5>;
+        let mut acc = I::from_num(60);
+        // 60 + 1.5 * 4 does not fit in 12 bits
+        acc.wrapping_mul_acc(FixedI16::<5>::from_num(1.5), FixedI16::<0>::from_num(4));
+        assert_eq!(acc, I::wrapping_from_num(66));
+        let (a, b) = (FixedI16::<5>::from_num(1.5), FixedI16::<0>::from_num(4));
+        assert!(I::from_num(60).overflowing_mul_acc(a, b));
+        let mut acc = I::from_num(60);
+        assert!(!acc.overflowing_mul_acc(-a, b));
+        assert_eq!(acc, 54);
+    }
+}
@@ -20,6 +20,17 @@ macro_rules! make_helper {
             pub const fn neg_abs(val: $i) -> (bool, $u) {
                 (val.is_negative(), val.unsigned_abs())
             }
+
+            // Returns the wrapped value and whether it overflows.
+            #[inline]
+            pub const fn overflowing_from_neg_abs(neg: bool, abs: u128) -> ($i, bool) {
+                if neg {
+                    let max_abs = $i::MIN.unsigned_abs() as u128;
+                    ((abs as $i).wrapping_neg(), abs > max_abs)
+                } else {
+                    (abs as $i, abs > $i::MAX as u128)
+                }
+            }
         }
 
         pub mod $u {
@@ -27,6 +38,16 @@ macro_rules! make_helper {
             pub fn neg_abs(val: $u) -> (bool, $u) {
                 (false, val)
             }
+
+            // Returns the wrapped value and whether it overflows.
+            #[inline]
+            pub const fn overflowing_from_neg_abs(neg: bool, abs: u128) -> ($u, bool) {
+                if neg {
+                    ((abs as $u).wrapping_neg(), abs != 0)
+                } else {
+                    (abs as $u, abs > $u::MAX as u128)
+                }
+            }
         }
     };
 }
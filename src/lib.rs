@@ -67,14 +67,39 @@ cannot be represented exactly, as it is 1/10<sup>3</sup>. It is binary fractions
 like 1/2<sup>4</sup> (0.0625) that can be represented exactly, provided there
 are enough fractional bits.
 
-This crate does *not* provide general analytic functions.
-
-  * No algebraic functions are provided, for example no `pow`.
-  * No trigonometric functions are provided, for example no `sin` or `cos`.
-  * No other transcendental functions are provided, for example no `log` or
-    `exp`.
-
-These functions are not provided because different implementations can have
+This crate provides only some analytic functions. They are computed using only
+integer arithmetic, so the results are deterministic and do not depend on the
+platform.
+
+  * The integer power `powi` is provided. It rounds only once at the end, so
+    unlike repeated multiplication it does not accumulate rounding errors.
+  * The power `powf` with a fixed-point exponent is provided. A negative base
+    is only supported with an integer exponent.
+  * The trigonometric functions `sin`, `cos` and `tan` are provided, as well
+    as `sin_cos` which computes both the sine and cosine at once.
+  * The inverse trigonometric functions `asin`, `acos` and `atan` are
+    provided, as well as `atan2` which computes the four-quadrant arctangent
+    with a caller-chosen number of fractional bits for the angle.
+  * The exponential functions `exp`, `exp2` and `exp_m1` are provided.
+  * The hyperbolic functions `sinh`, `cosh` and `tanh` and their inverses
+    `asinh`, `acosh` and `atanh` are provided. `tanh` never overflows, even
+    when there are no integer bits.
+  * The error function `erf` and the complementary error function `erfc` are
+    provided.
+  * The activation functions `sigmoid`, `softplus` and `gelu` are provided,
+    as well as `softmax` which works on a slice. They saturate instead of
+    overflowing, and `sigmoid` can return a number with no integer bits.
+  * The table-driven `sin_lut` and `cos_lut` interpolate linearly in a lookup
+    table generated at compile time, trading accuracy for speed. The table
+    size is a const generic parameter, and the error bound for each size is
+    documented.
+  * Polynomials can be evaluated using `poly_eval`, which keeps intermediate
+    values with 128 extra fractional bits, so that only the final result is
+    rounded to the precision of the fixed-point number.
+  * The logarithms `ln`, `log2` and `log10` are provided. They return a signed
+    fixed-point number with a caller-chosen number of fractional bits.
+
+Other functions are not provided because different implementations can have
 different trade-offs, for example trading some correctness for speed.
 Implementations can be provided in other crates.
 
@@ -415,6 +440,8 @@ extern crate std;
 
 #[macro_use]
 mod macros;
+#[macro_use]
+mod macros_narrow;
 
 mod arith;
 #[cfg(feature = "borsh")]
@@ -423,11 +450,17 @@ mod bytes;
 mod cast;
 mod cmp;
 mod cmp_fixed;
+mod complex;
 pub mod consts;
 mod convert;
 mod debug_hex;
 mod display;
+mod dot;
+mod erf;
+mod exp;
 pub mod f128;
+mod fixed24_48;
+mod fixed256;
 mod fixed_from_bits;
 mod float_helper;
 mod from_str;
@@ -439,31 +472,51 @@ mod impl_bytemuck;
 #[cfg(feature = "num-traits")]
 mod impl_num_traits;
 mod int256;
+mod int_frac;
 mod int_helper;
 mod inv_lerp;
 mod lerp;
+mod ln;
 mod log;
 mod log10;
+mod lut;
+mod matrix;
+mod powi;
 mod prim_traits;
+mod real;
+mod root;
+mod rounding;
 mod saturating;
 #[cfg(feature = "serde")]
 mod serdeize;
 mod sqrt;
 pub mod traits;
 mod traits_bits;
+mod trig;
 pub mod types;
 mod unwrapped;
+mod vector;
 mod wrapping;
 
 #[cfg(feature = "num-traits")]
 pub use crate::impl_num_traits::RadixParseFixedError;
 pub use crate::{
-    f128::private::F128, from_str::ParseFixedError, saturating::Saturating, unwrapped::Unwrapped,
+    complex::Complex,
+    f128::private::F128,
+    fixed24_48::{FixedI24, FixedI48, FixedU24, FixedU48},
+    fixed256::{FixedI256, FixedU256},
+    from_str::ParseFixedError,
+    int_frac::{FixedI, FixedU},
+    matrix::{Mat2, Mat3, Mat4},
+    rounding::RoundingMode,
+    saturating::Saturating,
+    unwrapped::Unwrapped,
+    vector::{Vec2, Vec3},
     wrapping::Wrapping,
 };
 use crate::{
     log::Base,
-    traits::{FromFixed, ToFixed},
+    traits::{FromFixed, RandomBits, ToFixed},
     types::extra::{If, True},
 };
 use core::hash::{Hash, Hasher};
@@ -515,6 +568,8 @@ mod macros_no_frac;
 #[macro_use]
 mod macros_frac;
 #[macro_use]
+mod macros_math;
+#[macro_use]
 mod macros_const;
 
 macro_rules! fixed {
@@ -657,6 +712,14 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
             {USelf, UInner} = {$USelf, $UInner},
             NonZeroUInner = $NonZeroUInner,
         }
+        // analytic functions that require FRAC bounds
+        fixed_math! {
+            {Self, Inner} = {$Self, $Inner},
+            Signedness = $Signedness,
+            {nm1, n} = {$nm1, $n},
+            {ISelf, IInner} = {$ISelf, $IInner},
+            {USelf, UInner} = {$USelf, $UInner},
+        }
         fixed_const! {
             Self = $Self,
             Signedness = $Signedness,
@@ -843,6 +906,156 @@ fixed! {
     },
 }
 
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedI8, i8},
+    Signedness = Signed,
+    dst_n = 8,
+    [
+        requantize_i8,
+        checked_requantize_i8,
+        saturating_requantize_i8,
+        wrapping_requantize_i8,
+        unwrapped_requantize_i8,
+        overflowing_requantize_i8,
+    ],
+    from = [FixedI16(i16, 16), FixedI32(i32, 32), FixedI64(i64, 64), FixedI128(i128, 128)],
+}
+
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedI16, i16},
+    Signedness = Signed,
+    dst_n = 16,
+    [
+        requantize_i16,
+        checked_requantize_i16,
+        saturating_requantize_i16,
+        wrapping_requantize_i16,
+        unwrapped_requantize_i16,
+        overflowing_requantize_i16,
+    ],
+    from = [FixedI8(i8, 8), FixedI32(i32, 32), FixedI64(i64, 64), FixedI128(i128, 128)],
+}
+
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedI32, i32},
+    Signedness = Signed,
+    dst_n = 32,
+    [
+        requantize_i32,
+        checked_requantize_i32,
+        saturating_requantize_i32,
+        wrapping_requantize_i32,
+        unwrapped_requantize_i32,
+        overflowing_requantize_i32,
+    ],
+    from = [FixedI8(i8, 8), FixedI16(i16, 16), FixedI64(i64, 64), FixedI128(i128, 128)],
+}
+
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedI64, i64},
+    Signedness = Signed,
+    dst_n = 64,
+    [
+        requantize_i64,
+        checked_requantize_i64,
+        saturating_requantize_i64,
+        wrapping_requantize_i64,
+        unwrapped_requantize_i64,
+        overflowing_requantize_i64,
+    ],
+    from = [FixedI8(i8, 8), FixedI16(i16, 16), FixedI32(i32, 32), FixedI128(i128, 128)],
+}
+
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedI128, i128},
+    Signedness = Signed,
+    dst_n = 128,
+    [
+        requantize_i128,
+        checked_requantize_i128,
+        saturating_requantize_i128,
+        wrapping_requantize_i128,
+        unwrapped_requantize_i128,
+        overflowing_requantize_i128,
+    ],
+    from = [FixedI8(i8, 8), FixedI16(i16, 16), FixedI32(i32, 32), FixedI64(i64, 64)],
+}
+
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedU8, u8},
+    Signedness = Unsigned,
+    dst_n = 8,
+    [
+        requantize_u8,
+        checked_requantize_u8,
+        saturating_requantize_u8,
+        wrapping_requantize_u8,
+        unwrapped_requantize_u8,
+        overflowing_requantize_u8,
+    ],
+    from = [FixedU16(u16, 16), FixedU32(u32, 32), FixedU64(u64, 64), FixedU128(u128, 128)],
+}
+
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedU16, u16},
+    Signedness = Unsigned,
+    dst_n = 16,
+    [
+        requantize_u16,
+        checked_requantize_u16,
+        saturating_requantize_u16,
+        wrapping_requantize_u16,
+        unwrapped_requantize_u16,
+        overflowing_requantize_u16,
+    ],
+    from = [FixedU8(u8, 8), FixedU32(u32, 32), FixedU64(u64, 64), FixedU128(u128, 128)],
+}
+
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedU32, u32},
+    Signedness = Unsigned,
+    dst_n = 32,
+    [
+        requantize_u32,
+        checked_requantize_u32,
+        saturating_requantize_u32,
+        wrapping_requantize_u32,
+        unwrapped_requantize_u32,
+        overflowing_requantize_u32,
+    ],
+    from = [FixedU8(u8, 8), FixedU16(u16, 16), FixedU64(u64, 64), FixedU128(u128, 128)],
+}
+
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedU64, u64},
+    Signedness = Unsigned,
+    dst_n = 64,
+    [
+        requantize_u64,
+        checked_requantize_u64,
+        saturating_requantize_u64,
+        wrapping_requantize_u64,
+        unwrapped_requantize_u64,
+        overflowing_requantize_u64,
+    ],
+    from = [FixedU8(u8, 8), FixedU16(u16, 16), FixedU32(u32, 32), FixedU128(u128, 128)],
+}
+
+fixed_requantize_to! {
+    {Dst, DstInner} = {FixedU128, u128},
+    Signedness = Unsigned,
+    dst_n = 128,
+    [
+        requantize_u128,
+        checked_requantize_u128,
+        saturating_requantize_u128,
+        wrapping_requantize_u128,
+        unwrapped_requantize_u128,
+        overflowing_requantize_u128,
+    ],
+    from = [FixedU8(u8, 8), FixedU16(u16, 16), FixedU32(u32, 32), FixedU64(u64, 64)],
+}
+
 /// These are doc tests that should not appear in the docs, but are useful as
 /// doc tests can check to ensure compilation failure.
 ///
@@ -0,0 +1,201 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::consts;
+use crate::real::Real;
+
+// Number of terms used in the series for atanh(s) with |s| ≤ 0.1716. The first
+// term that is not used is < 2^-130 relative to the result.
+const LN_TERMS: u32 = 26;
+
+// x > 0, returns (e, m) such that x = m × 2^e and 1/√2 ≤ m ≤ √2
+const fn split(x: Real) -> (i32, Real) {
+    debug_assert!(!x.neg && x.mant != 0);
+    let (e, exp) = if x.mant > consts::SQRT_2.to_bits() {
+        (x.exp + 128, -128)
+    } else {
+        (x.exp + 127, -127)
+    };
+    (e, Real { exp, ..x })
+}
+
+// |s| ≤ 0.1716
+const fn atanh_small(s: Real) -> Real {
+    // atanh s = s + s³/3 + s⁵/5 + …
+    let s2 = s.mul(s);
+    let mut acc = Real::from_u32(2 * LN_TERMS + 1).recip();
+    let mut k = LN_TERMS;
+    while k > 0 {
+        k -= 1;
+        acc = Real::from_u32(2 * k + 1).recip().add(s2.mul(acc));
+    }
+    s.mul(acc)
+}
+
+// 1/√2 ≤ m ≤ √2
+const fn ln_near_one(m: Real) -> Real {
+    // ln m = 2 atanh s, where s = (m - 1)/(m + 1)
+    let s = m.sub(Real::ONE).div(m.add(Real::ONE));
+    atanh_small(s).mul_pow2(1)
+}
+
+const fn from_i32(val: i32) -> Real {
+    Real::from_u128(val < 0, val.unsigned_abs() as u128, 0)
+}
+
+// x > 0
+pub const fn ln(x: Real) -> Real {
+    let (e, m) = split(x);
+    let ln_2 = Real::from_u128(false, consts::LN_2.to_bits(), 128);
+    from_i32(e).mul(ln_2).add(ln_near_one(m))
+}
+
+// x > 0
+pub const fn log2(x: Real) -> Real {
+    let (e, m) = split(x);
+    let log2_e = Real::from_u128(false, consts::LOG2_E.to_bits(), 127);
+    from_i32(e).add(ln_near_one(m).mul(log2_e))
+}
+
+// x > 0
+pub const fn log10(x: Real) -> Real {
+    let log10_e = Real::from_u128(false, consts::LOG10_E.to_bits(), 128);
+    ln(x).mul(log10_e)
+}
+
+pub const fn asinh(x: Real) -> Real {
+    // asinh |x| = ln(|x| + √(x² + 1))
+    let a = x.abs();
+    let abs = ln(a.add(a.mul(a).add(Real::ONE).sqrt()));
+    if x.neg {
+        abs.neg()
+    } else {
+        abs
+    }
+}
+
+// Returns None if x < 1.
+pub const fn acosh(x: Real) -> Option<Real> {
+    if x.neg || x.cmp_abs(Real::ONE).is_lt() {
+        return None;
+    }
+    // acosh x = ln(x + √(x² - 1)), where x² - 1 = (x - 1)(x + 1) avoids
+    // cancellation near 1
+    Some(ln(x.add(x.sub(Real::ONE).mul(x.add(Real::ONE)).sqrt())))
+}
+
+// Returns None if |x| ≥ 1.
+pub const fn atanh(x: Real) -> Option<Real> {
+    if x.cmp_abs(Real::ONE).is_ge() {
+        return None;
+    }
+    // the series is used directly for small |x| to keep the relative error small
+    if x.cmp_abs(Real::from_u128(false, 1, 3)).is_le() {
+        return Some(atanh_small(x));
+    }
+    // atanh x = ln((1 + x)/(1 - x))/2
+    Some(ln(Real::ONE.add(x).div(Real::ONE.sub(x))).mul_pow2(-1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ln;
+    use crate::real;
+    use crate::types::{I16F16, U16F16};
+
+    #[test]
+    fn ln_u16f16() {
+        let mut bits = 1u32;
+        while bits < u32::MAX - u32::MAX / 64 - 1 {
+            let f = U16F16::from_bits(bits).to_num::<f64>();
+            let x = real::u32::from_bits(bits, 16);
+            for (r, expected) in [
+                (ln::ln(x), f.ln()),
+                (ln::log2(x), f.log2()),
+                (ln::log10(x), f.log10()),
+            ] {
+                let (bits, overflow) = real::i32::to_bits(r, 16);
+                assert!(!overflow);
+                let diff = (bits as f64 / 65536.0 - expected).abs();
+                assert!(diff <= 0.5 / 65536.0 + 1e-12, "{f} {bits}");
+            }
+            bits += bits / 64 + 1;
+        }
+    }
+
+    #[test]
+    fn log_exact() {
+        for i in -16..16 {
+            let x = real::u128::from_bits(1, -i);
+            assert_eq!(real::i32::to_bits(ln::log2(x), 16), (i << 16, false));
+        }
+        let hundred = real::u8::from_bits(100, 0);
+        let (bits, _) = real::i128::to_bits(ln::log10(hundred), 120);
+        assert!(bits.abs_diff(2 << 120) <= 1);
+        let (bits, _) = real::i32::to_bits(ln::ln(real::u8::from_bits(1, 0)), 16);
+        assert_eq!(I16F16::from_bits(bits), 0);
+    }
+
+    #[test]
+    fn inverse_hyperbolic_i16f16() {
+        let mut bits = i32::MIN;
+        while bits < i32::MAX - 0x0013_5799 {
+            let f = I16F16::from_bits(bits).to_num::<f64>();
+            let x = real::i32::from_bits(bits, 16);
+            let acosh = if f >= 1.0 {
+                Some((ln::acosh(x).unwrap(), f.acosh()))
+            } else {
+                None
+            };
+            for (r, expected) in [(ln::asinh(x), f.asinh())].into_iter().chain(acosh) {
+                let (bits, overflow) = real::i32::to_bits(r, 16);
+                assert!(!overflow);
+                let diff = (bits as f64 / 65536.0 - expected).abs();
+                assert!(diff <= 0.5 / 65536.0 + 1e-12, "{f} {bits}");
+            }
+            bits += 0x0013_5799;
+        }
+        let mut bits = -0xFFFF;
+        while bits < 0x1_0000 {
+            let f = I16F16::from_bits(bits).to_num::<f64>();
+            let (bits_r, overflow) =
+                real::i32::to_bits(ln::atanh(real::i32::from_bits(bits, 16)).unwrap(), 16);
+            assert!(!overflow);
+            let diff = (bits_r as f64 / 65536.0 - f.atanh()).abs();
+            assert!(diff <= 0.5 / 65536.0 + 1e-12, "{f} {bits_r}");
+            bits += 0x0135;
+        }
+    }
+
+    #[test]
+    fn inverse_hyperbolic_wide() {
+        // atanh and asinh of tiny values keep full precision
+        let x = real::i128::from_bits(1, 127);
+        assert_eq!(real::i128::to_bits(ln::atanh(x).unwrap(), 127), (1, false));
+        assert_eq!(real::i128::to_bits(ln::asinh(x.neg()), 127), (-1, false));
+        assert_eq!(
+            real::u128::to_bits(ln::acosh(real::u8::from_bits(1, 0)).unwrap(), 128),
+            (0, false)
+        );
+        assert!(ln::acosh(x.mul_pow2(1)).is_none());
+        assert!(ln::atanh(real::i8::from_bits(-1, 0)).is_none());
+
+        // atanh(1 - 2^-128) ≈ ln(2^129)/2 = 64.5 ln 2
+        let x = real::u128::from_bits(u128::MAX, 128);
+        let (bits, _) = real::i64::to_bits(ln::atanh(x).unwrap(), 32);
+        let expected = (64.5 * 2f64.ln() * 2f64.powi(32)).round() as i64;
+        assert!(bits.abs_diff(expected) <= 1);
+    }
+}
@@ -0,0 +1,396 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Table-driven sine and cosine. A table with N entries stores sin(τk/N) for k =
+// 0, 1, …, N - 1 with 30 fractional bits, and the functions interpolate
+// linearly between the two entries on either side of x.
+//
+// The argument is reduced by computing x/τ modulo 1 as a 64-bit fraction, the
+// turns. The top log2 N bits are the table index and the next 32 bits are the
+// position between the two entries. For types with up to 64 bits, the turns
+// are computed by multiplying the bits by 2^(128 - FRAC)/τ modulo 2^128, which
+// is precomputed for each FRAC, so only native 32-bit or 64-bit multiplications
+// are needed. Since 1/τ is stored with 130 significant bits, the reduction
+// error is less than |x| × 2^-130 + 2^-63 turns.
+//
+// Linear interpolation of a function f with step h has an error of at most
+// h²/8 × max |f''|, which is π²/(2N²) for h = τ/N and f = sin. The table
+// entries are rounded to the nearest, and the interpolation is rounded down,
+// which adds an error of less than 2^-31 + 2^-30.
+
+use crate::consts;
+use crate::int256;
+use crate::lerp;
+use crate::real;
+use crate::trig;
+
+// The tables are statics so that there is only one copy of each table, which is
+// stored in flash memory on embedded targets. Since a static cannot depend on a
+// generic parameter, there is one static for each supported size.
+static SIN_4: [i32; 4] = build::<4>();
+static SIN_8: [i32; 8] = build::<8>();
+static SIN_16: [i32; 16] = build::<16>();
+static SIN_32: [i32; 32] = build::<32>();
+static SIN_64: [i32; 64] = build::<64>();
+static SIN_128: [i32; 128] = build::<128>();
+static SIN_256: [i32; 256] = build::<256>();
+static SIN_512: [i32; 512] = build::<512>();
+static SIN_1024: [i32; 1024] = build::<1024>();
+static SIN_2048: [i32; 2048] = build::<2048>();
+static SIN_4096: [i32; 4096] = build::<4096>();
+static SIN_8192: [i32; 8192] = build::<8192>();
+static SIN_16384: [i32; 16384] = build::<16384>();
+static SIN_32768: [i32; 32768] = build::<32768>();
+static SIN_65536: [i32; 65536] = build::<65536>();
+
+struct SinTable<const N: usize>;
+
+impl<const N: usize> SinTable<N> {
+    // The constant is a reference to the static, so all uses of the constant
+    // refer to the same table.
+    const TABLE: &'static [i32] = match N {
+        4 => &SIN_4,
+        8 => &SIN_8,
+        16 => &SIN_16,
+        32 => &SIN_32,
+        64 => &SIN_64,
+        128 => &SIN_128,
+        256 => &SIN_256,
+        512 => &SIN_512,
+        1024 => &SIN_1024,
+        2048 => &SIN_2048,
+        4096 => &SIN_4096,
+        8192 => &SIN_8192,
+        16384 => &SIN_16384,
+        32768 => &SIN_32768,
+        65536 => &SIN_65536,
+        _ => panic!("the table size must be a power of two from 4 to 65536"),
+    };
+}
+
+// Rounds to the nearest, with 62 fractional bits.
+const fn mul_62(a: i128, b: i128) -> i128 {
+    (a * b + (1 << 61)) >> 62
+}
+
+const fn build<const N: usize>() -> [i32; N] {
+    assert!(
+        N.is_power_of_two() && N >= 4,
+        "the table size must be a power of two that is at least 4"
+    );
+    // The entries are generated by repeated rotation by τ/N, using 62
+    // fractional bits so that the accumulated error stays well below 2^-31.
+    let tau = real::Real::from_u128(false, consts::TAU.to_bits(), 125);
+    let (step_sin, step_cos) = trig::sin_cos(tau.mul_pow2(-(N.trailing_zeros() as i32)));
+    let step_sin = real::i128::to_bits(step_sin, 62).0;
+    let step_cos = real::i128::to_bits(step_cos, 62).0;
+    let mut table = [0; N];
+    let mut sin = 0i128;
+    let mut cos = 1i128 << 62;
+    let mut k = 0;
+    // Only the first quarter is computed, the rest is filled by symmetry.
+    while k <= N / 4 {
+        let entry = ((sin + (1 << 31)) >> 32) as i32;
+        table[k] = entry;
+        table[N / 2 - k] = entry;
+        table[N / 2 + k] = -entry;
+        table[(N - k) % N] = -entry;
+        let next_sin = mul_62(sin, step_cos) + mul_62(cos, step_sin);
+        cos = mul_62(cos, step_cos) - mul_62(sin, step_sin);
+        sin = next_sin;
+        k += 1;
+    }
+    table
+}
+
+struct FracOneTau<const FRAC: i32>;
+
+impl<const FRAC: i32> FracOneTau<FRAC> {
+    // 2^(128 - FRAC)/τ modulo 2^128, as the high and low 64-bit words.
+    const WORDS: (u64, u64) = {
+        let prec = consts::PREC_FRAC_1_TAU.to_bits();
+        let shift = FRAC as i64 + 2;
+        let words = if shift >= 128 || shift <= -128 {
+            0
+        } else if shift >= 0 {
+            prec >> shift
+        } else {
+            prec << -shift
+        };
+        ((words >> 64) as u64, words as u64)
+    };
+}
+
+// Returns abs × 2^-FRAC/τ modulo 1 with 64 fractional bits, using 32-bit
+// multiplications with 64-bit products.
+const fn turns_u32<const FRAC: i32>(abs: u32) -> u64 {
+    let (hi, lo) = FracOneTau::<FRAC>::WORDS;
+    let abs = abs as u64;
+    // the high 64 bits of abs × lo
+    let carry = (abs * (lo >> 32) + ((abs * (lo & 0xFFFF_FFFF)) >> 32)) >> 32;
+    // abs × hi modulo 2^64
+    let prod = (abs * (hi & 0xFFFF_FFFF)).wrapping_add((abs * (hi >> 32)) << 32);
+    prod.wrapping_add(carry)
+}
+
+// Returns abs × 2^-FRAC/τ modulo 1 with 64 fractional bits, using 64-bit
+// multiplications with 128-bit products.
+const fn turns_u64<const FRAC: i32>(abs: u64) -> u64 {
+    let (hi, lo) = FracOneTau::<FRAC>::WORDS;
+    let carry = ((abs as u128 * lo as u128) >> 64) as u64;
+    abs.wrapping_mul(hi).wrapping_add(carry)
+}
+
+// Returns abs × 2^-FRAC/τ modulo 1 with 64 fractional bits.
+const fn turns_u128<const FRAC: i32>(abs: u128) -> u64 {
+    // abs × 2^-FRAC/τ = abs × (2^130/τ) × 2^(-FRAC - 130)
+    let prod = int256::wide_mul_u128(abs, consts::PREC_FRAC_1_TAU.to_bits());
+    let shift = 66 + FRAC as i64;
+    if shift >= 256 {
+        0
+    } else if shift >= 128 {
+        (prod.hi >> (shift - 128)) as u64
+    } else if shift > 0 {
+        ((prod.hi << (128 - shift)) | (prod.lo >> shift)) as u64
+    } else if shift > -64 {
+        (prod.lo << -shift) as u64
+    } else {
+        0
+    }
+}
+
+// Returns sin(τ × turns / 2^64) with 30 fractional bits.
+const fn interpolate<const N: usize>(turns: u64) -> i64 {
+    let table = SinTable::<N>::TABLE;
+    let index_bits = N.trailing_zeros();
+    let index = (turns >> (64 - index_bits)) as usize;
+    let next = (index + 1) % N;
+    let ratio = ((turns << index_bits) >> 32) as i64;
+    lerp::i64(ratio, table[index] as i64, table[next] as i64, 32).0
+}
+
+// Returns sin(τ × turns / 2^64) with 30 fractional bits.
+pub const fn sin<const N: usize>(turns: u64) -> i64 {
+    interpolate::<N>(turns)
+}
+
+// Returns cos(τ × turns / 2^64) with 30 fractional bits.
+pub const fn cos<const N: usize>(turns: u64) -> i64 {
+    // cos x = sin(x + τ/4)
+    interpolate::<N>(turns.wrapping_add(1 << 62))
+}
+
+// Rounds val × 2^(frac - 30) to the nearest, with ties rounded to even, where
+// |val| ≤ 2^30. Returns the value wrapped to 128 bits, and whether it does not
+// fit in i128 if negative or in u128 if not negative.
+const fn to_i128(val: i64, frac: i32) -> (i128, bool) {
+    let shift = frac as i64 - 30;
+    if shift >= 128 {
+        (0, val != 0)
+    } else if shift >= 0 {
+        let wide = (val as i128) << shift;
+        let back = if val < 0 {
+            (wide >> shift) as i64
+        } else {
+            ((wide as u128) >> shift) as i64
+        };
+        (wide, back != val)
+    } else if shift < -32 {
+        (0, false)
+    } else {
+        let rsh = -shift;
+        let int = val >> rsh;
+        let rest = val - (int << rsh);
+        let half = 1 << (rsh - 1);
+        let round_up = rest > half || (rest == half && (int & 1) != 0);
+        ((int + round_up as i64) as i128, false)
+    }
+}
+
+macro_rules! impl_lut {
+    ($i:ident, $u:ident, $turns:ident, $Abs:ident) => {
+        pub mod $i {
+            use crate::lut;
+
+            // Returns x/τ modulo 1 with 64 fractional bits.
+            #[inline]
+            pub const fn turns<const FRAC: i32>(bits: $i) -> u64 {
+                let turns = lut::$turns::<FRAC>(bits.unsigned_abs() as $Abs);
+                if bits < 0 {
+                    turns.wrapping_neg()
+                } else {
+                    turns
+                }
+            }
+
+            // Converts a value with 30 fractional bits, rounding to the
+            // nearest with ties rounded to even. Returns the wrapped value and
+            // whether overflow occurred.
+            #[inline]
+            pub const fn to_bits(val: i64, frac: i32) -> ($i, bool) {
+                let (wide, overflow) = lut::to_i128(val, frac);
+                let bits = wide as $i;
+                (
+                    bits,
+                    overflow || bits as i128 != wide || (val >= 0 && wide < 0),
+                )
+            }
+        }
+
+        pub mod $u {
+            use crate::lut;
+
+            // Returns x/τ modulo 1 with 64 fractional bits.
+            #[inline]
+            pub const fn turns<const FRAC: i32>(bits: $u) -> u64 {
+                lut::$turns::<FRAC>(bits as $Abs)
+            }
+
+            // Converts a value with 30 fractional bits, rounding to the
+            // nearest with ties rounded to even. Returns the wrapped value and
+            // whether overflow occurred.
+            #[inline]
+            pub const fn to_bits(val: i64, frac: i32) -> ($u, bool) {
+                let (wide, overflow) = lut::to_i128(val, frac);
+                let bits = wide as $u;
+                (
+                    bits,
+                    overflow || (val < 0 && wide < 0) || bits as i128 != wide,
+                )
+            }
+        }
+    };
+}
+
+impl_lut! { i8, u8, turns_u32, u32 }
+impl_lut! { i16, u16, turns_u32, u32 }
+impl_lut! { i32, u32, turns_u32, u32 }
+impl_lut! { i64, u64, turns_u64, u64 }
+impl_lut! { i128, u128, turns_u128, u128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::lut::{self, SinTable, SIN_4096};
+    use crate::real;
+    use crate::trig;
+    use core::f64::consts::{PI, TAU};
+
+    fn max_error<const N: usize, const FRAC: i32>() -> f64 {
+        let mut max = 0f64;
+        let mut bits = -20i64 << FRAC;
+        while bits < 20 << FRAC {
+            let turns = lut::i64::turns::<FRAC>(bits);
+            let f = bits as f64 / (FRAC as f64).exp2();
+            for (val, expected) in [
+                (lut::sin::<N>(turns), f.sin()),
+                (lut::cos::<N>(turns), f.cos()),
+            ] {
+                let err = (val as f64 / 2f64.powi(30) - expected).abs();
+                max = max.max(err);
+            }
+            bits += 0x1357;
+        }
+        max
+    }
+
+    fn bound(n: usize) -> f64 {
+        PI * PI / (2.0 * (n * n) as f64) + 2f64.powi(-31) + 2f64.powi(-30)
+    }
+
+    #[test]
+    fn tables() {
+        for (k, &entry) in SinTable::<16>::TABLE.iter().enumerate() {
+            let expected = (TAU * k as f64 / 16.0).sin() * 2f64.powi(30);
+            assert_eq!(entry, expected.round() as i32, "k {k}");
+        }
+        for (k, &entry) in SinTable::<4096>::TABLE.iter().enumerate() {
+            let expected = (TAU * k as f64 / 4096.0).sin() * 2f64.powi(30);
+            assert!((entry as f64 - expected).abs() <= 0.5 + 1e-6, "k {k}");
+        }
+        assert_eq!(*SinTable::<4>::TABLE, [0, 1 << 30, 0, -1 << 30]);
+        // the constant refers to the static instead of to a copy
+        assert_eq!(SinTable::<4096>::TABLE.as_ptr(), SIN_4096.as_ptr());
+    }
+
+    #[test]
+    fn interpolation_error() {
+        let err16 = max_error::<16, 20>();
+        assert!(err16 <= bound(16) && err16 > 0.9 * bound(16));
+        assert!(max_error::<256, 20>() <= bound(256));
+        assert!(max_error::<4096, 24>() <= bound(4096));
+        assert!(max_error::<65536, 24>() <= bound(65536));
+    }
+
+    fn check_turns<const FRAC: i32>() {
+        for bits in [1, 0x1357, 0x7FFF_FFFF, 0x8000_0000, 0xFFFF_FFFF] {
+            // the reference uses the 256-bit product
+            let expected = lut::turns_u128::<FRAC>(bits.into());
+            assert_eq!(
+                lut::turns_u32::<FRAC>(bits),
+                lut::turns_u64::<FRAC>(bits.into())
+            );
+            let err = lut::turns_u32::<FRAC>(bits).wrapping_sub(expected) as i64;
+            assert!(err.unsigned_abs() <= 2, "bits {bits:#x} frac {FRAC}");
+        }
+    }
+
+    #[test]
+    fn reduction() {
+        check_turns::<-40>();
+        check_turns::<-3>();
+        check_turns::<0>();
+        check_turns::<16>();
+        check_turns::<31>();
+        check_turns::<32>();
+        check_turns::<100>();
+
+        // 1/τ turns is 1/(2π)
+        let turns = lut::i32::turns::<16>(1 << 16);
+        let expected = 2f64.powi(64) / TAU;
+        assert!((turns as f64 - expected).abs() <= 2f64.powi(12));
+        assert_eq!(lut::i32::turns::<16>(-1 << 16), turns.wrapping_neg());
+    }
+
+    #[test]
+    fn exact_values() {
+        let zero = lut::i32::turns::<16>(0);
+        assert_eq!(lut::i32::to_bits(lut::sin::<64>(zero), 30), (0, false));
+        assert_eq!(
+            lut::i32::to_bits(lut::cos::<64>(zero), 30),
+            (1 << 30, false)
+        );
+        assert_eq!(
+            lut::i32::to_bits(lut::cos::<64>(zero), 31),
+            (i32::MIN, true)
+        );
+        assert_eq!(
+            lut::u32::to_bits(lut::cos::<64>(zero), 31),
+            (1 << 31, false)
+        );
+        assert_eq!(lut::u8::to_bits(-1 << 27, 2), (0, false));
+        assert_eq!(lut::u8::to_bits(-3 << 27, 2), (254, true));
+        assert_eq!(lut::i128::to_bits(1 << 30, 127), (i128::MIN, true));
+        assert_eq!(lut::u128::to_bits(1 << 30, 127), (1 << 127, false));
+
+        // |x| ≥ 2^64 is still reduced correctly
+        let large = 3i128 << 100;
+        for bits in [large, -large] {
+            let val = lut::sin::<4096>(lut::i128::turns::<36>(bits));
+            let x = real::i128::from_bits(bits, 36);
+            let (expected, _) = real::i64::to_bits(trig::sin(x), 30);
+            assert!(val.abs_diff(expected) as f64 <= bound(4096) * 2f64.powi(30));
+        }
+    }
+}
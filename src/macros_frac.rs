@@ -356,6 +356,48 @@ assert_eq!(Fix::from_str_hex(".01C@+2"), Ok(Fix::from_num(1.75)));
                 }
             }
 
+            comment! {
+                "Parses a string slice containing decimal digits to return a fixed-point number,
+rounded according to the given [`RoundingMode`].
+
+This is like [`from_str`][Self::from_str], which always rounds to the nearest
+with ties rounded to even, except that it rounds according to `mode`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), r#"<4>;
+// 0.1 lies between 0.0625 and 0.125
+assert_eq!(Fix::from_str_round("0.1", RoundingMode::Floor), Ok(Fix::from_num(0.0625)));
+assert_eq!(Fix::from_str_round("0.1", RoundingMode::Ceil), Ok(Fix::from_num(0.125)));
+"#,
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    r#"assert_eq!(Fix::from_str_round("-0.1", RoundingMode::Floor), Ok(Fix::from_num(-0.125)));
+assert_eq!(Fix::from_str_round("-0.1", RoundingMode::ToZero), Ok(Fix::from_num(-0.0625)));
+"#,
+                },
+                r#"// 0.09375 lies halfway between 0.0625 and 0.125
+assert_eq!(Fix::from_str_round("0.09375", RoundingMode::HalfEven), Ok(Fix::from_num(0.125)));
+assert_eq!(Fix::from_str_round("0.09375", RoundingMode::HalfToZero), Ok(Fix::from_num(0.0625)));
+```
+"#;
+                #[inline]
+                pub const fn from_str_round(
+                    src: &str,
+                    mode: RoundingMode,
+                ) -> Result<$Self<FRAC>, ParseFixedError> {
+                    match from_str::$Inner::from_str_radix_round(src, 10, FRAC as u32, mode) {
+                        Ok(bits) => Ok($Self::from_bits(bits)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
             comment! {
                 "Parses a string slice containing decimal digits to return a fixed-point number,
 saturating on overflow.
@@ -1006,6 +1048,55 @@ assert_eq!(U8F8::overflowing_from_str_hex("C0F.FE"), Ok((check, true)));
                 }
             }
 
+            comment! {
+                "Parses a string slice containing decimal digits to return a fixed-point number,
+rounded according to the given [`RoundingMode`].
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+This is like [`overflowing_from_str`][Self::overflowing_from_str], which always
+rounds to the nearest with ties rounded to even, except that it rounds
+according to `mode`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), r#"<4>;
+assert_eq!(
+    Fix::overflowing_from_str_round("0.1", RoundingMode::Ceil),
+    Ok((Fix::from_num(0.125), false))
+);
+// slightly more than MAX is rounded up to MAX + DELTA, which wraps to MIN
+let max_plus = format!("{}1", Fix::MAX);
+assert_eq!(
+    Fix::overflowing_from_str_round(&max_plus, RoundingMode::Ceil),
+    Ok((Fix::MIN, true))
+);
+```
+"#;
+                #[inline]
+                pub const fn overflowing_from_str_round(
+                    src: &str,
+                    mode: RoundingMode,
+                ) -> Result<($Self<FRAC>, bool), ParseFixedError> {
+                    match from_str::$Inner::overflowing_from_str_radix_round(
+                        src,
+                        10,
+                        FRAC as u32,
+                        mode,
+                    ) {
+                        Ok((bits, overflow)) => Ok(($Self::from_bits(bits), overflow)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
             comment! {
                 "Integer base-10 logarithm, rounded down.
 
@@ -1171,6 +1262,107 @@ assert_eq!(Fix::from_num(0.1875).checked_int_log(5), Some(-2));
                 }
             }
 
+            comment! {
+                "Multiplication, rounded according to the given [`RoundingMode`].
+
+Unlike multiplication using the `*` operator, which rounds toward &minus;∞,
+this method rounds according to `mode`.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the
+multiplication overflows. When debug assertions are not enabled, the
+wrapped value can be returned, but it is not considered a breaking
+change if in the future it panics; if wrapping is required use
+[`wrapping_mul_round`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.5625 = 0.84375 lies halfway between 0.8125 and 0.875
+let (a, b) = (Fix::from_num(1.5), Fix::from_num(0.5625));
+assert_eq!(a.mul_round(b, RoundingMode::Floor), Fix::from_num(0.8125));
+assert_eq!(a.mul_round(b, RoundingMode::HalfEven), Fix::from_num(0.875));
+assert_eq!(a.mul_round(b, RoundingMode::HalfToZero), Fix::from_num(0.8125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).mul_round(b, RoundingMode::Floor), Fix::from_num(-0.875));
+assert_eq!((-a).mul_round(b, RoundingMode::ToZero), Fix::from_num(-0.8125));
+",
+                },
+                "```
+
+[`wrapping_mul_round`]: Self::wrapping_mul_round
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn mul_round(self, rhs: $Self<FRAC>, mode: RoundingMode) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_mul_round(rhs, mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Division, rounded according to the given [`RoundingMode`].
+
+Unlike division using the `/` operator, which rounds toward zero, this method
+rounds according to `mode`.
+
+# Panics
+
+Panics if the divisor is zero.
+
+When debug assertions are enabled, this method also panics if the
+division overflows. When debug assertions are not enabled, the
+wrapped value can be returned, but it is not considered a breaking
+change if in the future it panics; if wrapping is required use
+[`wrapping_div_round`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1.25 / 3 = 0.416… lies between 0.25 and 0.5
+let (a, b) = (Fix::from_num(1.25), Fix::from_num(3));
+assert_eq!(a.div_round(b, RoundingMode::Floor), Fix::from_num(0.25));
+assert_eq!(a.div_round(b, RoundingMode::Ceil), Fix::from_num(0.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).div_round(b, RoundingMode::Floor), Fix::from_num(-0.5));
+assert_eq!((-a).div_round(b, RoundingMode::ToZero), Fix::from_num(-0.25));
+",
+                },
+                "// 0.75 / 6 = 0.125 lies halfway between 0 and 0.25
+let (c, d) = (Fix::from_num(0.75), Fix::from_num(6));
+assert_eq!(c.div_round(d, RoundingMode::HalfEven), Fix::ZERO);
+assert_eq!(c.div_round(d, RoundingMode::HalfAwayFromZero), Fix::from_num(0.25));
+```
+
+[`wrapping_div_round`]: Self::wrapping_div_round
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn div_round(self, rhs: $Self<FRAC>, mode: RoundingMode) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_div_round(rhs, mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
             comment! {
                 "Returns the reciprocal (inverse) of the fixed-point number, 1/`self`.
 
@@ -1207,6 +1399,63 @@ assert_eq!(Fix::from_num(2).recip(), Fix::from_num(0.5));
                 }
             }
 
+            comment! {
+                "Returns the reciprocal (inverse) of the fixed-point number, 1/`self`,
+rounded according to the given [`RoundingMode`].
+
+Unlike [`recip`][Self::recip], which always rounds toward zero, this method
+rounds according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+reciprocal, and the two bounds are equal if the exact reciprocal is
+representable.
+
+# Panics
+
+Panics if the fixed-point number is zero.
+
+When debug assertions are enabled, this method also panics if the
+reciprocal overflows. When debug assertions are not enabled, the
+wrapped value can be returned, but it is not considered a breaking
+change if in the future it panics; if wrapping is required use
+[`wrapping_recip_round`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1/3 lies between 0.25 and 0.5
+let three = Fix::from_num(3);
+assert_eq!(three.recip_round(RoundingMode::Floor), Fix::from_num(0.25));
+assert_eq!(three.recip_round(RoundingMode::Ceil), Fix::from_num(0.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-three).recip_round(RoundingMode::Floor), Fix::from_num(-0.5));
+assert_eq!((-three).recip_round(RoundingMode::ToZero), Fix::from_num(-0.25));
+",
+                },
+                "// 1/8 lies halfway between 0 and 0.25
+let eight = Fix::from_num(8);
+assert_eq!(eight.recip_round(RoundingMode::HalfEven), Fix::ZERO);
+assert_eq!(eight.recip_round(RoundingMode::HalfAwayFromZero), Fix::from_num(0.25));
+```
+
+[`wrapping_recip_round`]: Self::wrapping_recip_round
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn recip_round(self, mode: RoundingMode) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_recip_round(mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
             comment! {
                 "Euclidean division.
 
@@ -1383,67 +1632,105 @@ assert_eq!(Fix::from_num(2).sqrt(), Fix::SQRT_2);
             }
 
             comment! {
-                "Linear interpolation between `start` and `end`.
+                "Returns the square root, rounded according to the given [`RoundingMode`].
 
-Returns
-`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
-is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
-linear interpolation for all other values of `self`. Linear extrapolation is
-performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+Unlike [`sqrt`][Self::sqrt], which always rounds down, this method rounds
+according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+square root, and the two bounds are equal if the exact square root is
+representable. The exact square root is never halfway between two
+representable values, so the two nearest modes give the same result.
 
-# Panics
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when there are no integer bits and the input value is
+≥&nbsp;0.25, or when there is one integer bit and the result is rounded up to
+one.
 
-When debug assertions are enabled, this method panics if the result overflows.
-When debug assertions are not enabled, the wrapped value can be returned, but it
-is not considered a breaking change if in the future it panics; if wrapping is
-required use [`wrapping_lerp`] instead.
+",
+                    "Overflow can only occur when there are no integer bits and the result is
+rounded up to one.
 
-# Examples
+",
+                ),
+                if_signed_unsigned!(
+                    $Signedness,
+                    "# Panics
+
+Panics if the number is negative.
+
+When debug assertions are enabled, this method also panics if the square root
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_sqrt_round`] instead.
+
+",
+                    "# Panics
+
+When debug assertions are enabled, this method panics if the square root
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_sqrt_round`] instead.
+
+",
+                ),
+                "# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
-use fixed::", stringify!($Self), ";
+use fixed::{", stringify!($Self), ", RoundingMode};
 type Fix = ", stringify!($Self), "<4>;
-let start = Fix::from_num(2);
-let end = Fix::from_num(3.5);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-1.0).lerp(start, end), 0.5);
-",
-                },
-                "assert_eq!(Fix::from_num(0.0).lerp(start, end), 2);
-assert_eq!(Fix::from_num(0.5).lerp(start, end), 2.75);
-assert_eq!(Fix::from_num(1.0).lerp(start, end), 3.5);
-assert_eq!(Fix::from_num(2.0).lerp(start, end), 5);
+// √2 lies between SQRT_2 and SQRT_2 + DELTA
+let two = Fix::from_num(2);
+assert_eq!(two.sqrt_round(RoundingMode::Floor), Fix::SQRT_2);
+assert_eq!(two.sqrt_round(RoundingMode::Ceil), Fix::SQRT_2 + Fix::DELTA);
+// exact square roots are not rounded
+assert_eq!(Fix::from_num(2.25).sqrt_round(RoundingMode::Ceil), Fix::from_num(1.5));
 ```
 
-[`wrapping_lerp`]: Self::wrapping_lerp
+[`wrapping_sqrt_round`]: Self::wrapping_sqrt_round
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use]
-                pub const fn lerp<const RANGE_FRAC: i32>(
-                    self,
-                    start: $Self<RANGE_FRAC>,
-                    end: $Self<RANGE_FRAC>,
-                ) -> $Self<RANGE_FRAC> {
-                    let (ans, overflow) = lerp::$Inner(
-                        self.to_bits(),
-                        start.to_bits(),
-                        end.to_bits(),
-                        FRAC as u32,
-                    );
+                pub const fn sqrt_round(self, mode: RoundingMode) -> Self {
+                    let (val, overflow) = self.overflowing_sqrt_round(mode);
                     debug_assert!(!overflow, "overflow");
-                    $Self::from_bits(ans)
+                    val
                 }
             }
 
             comment! {
-                "Checked division. Returns the quotient, or [`None`] if
-the divisor is zero or on overflow.
+                "Returns the cube root.
+
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;∛`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, overflow occurs for an input value ≥&nbsp;0.125 or
+&lt;&nbsp;&minus;0.125.
+
+"
+                },
+                "# Panics
+
+When debug assertions are enabled, this method panics if the cube root
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_cbrt`] instead.
 
 # Examples
 
@@ -1453,24 +1740,459 @@ the divisor is zero or on overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::MAX.checked_div(Fix::ONE), Some(Fix::MAX));
-assert_eq!(Fix::MAX.checked_div(Fix::ONE / 2), None);
-```
+assert_eq!(Fix::from_num(0.125).cbrt(), 0.5);
+// ∛2 = 1.2599, which is rounded down to 1.25
+assert_eq!(Fix::from_num(2).cbrt(), 1.25);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// ∛−2 = −1.2599, which is rounded down to −1.3125
+assert_eq!(Fix::from_num(-2).cbrt(), -1.3125);
+",
+                },
+                "```
+
+[`wrapping_cbrt`]: Self::wrapping_cbrt
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_div(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    if rhs.to_bits() == 0 {
-                        return None;
-                    }
-                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), FRAC as u32)
-                    {
+                #[track_caller]
+                #[must_use]
+                pub const fn cbrt(self) -> Self {
+                    let (val, overflow) = self.overflowing_cbrt();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Returns the <i>n</i>th root.
+
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;`self`<sup>1/`n`</sup>&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, overflow occurs for an input value
+≥&nbsp;2<sup>&minus;`n`</sup>, or for odd `n`, for an input value
+&lt;&nbsp;&minus;2<sup>&minus;`n`</sup>.
+
+"
+                },
+                "# Panics
+
+Panics if `n` is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", or if the number is negative and `n` is even"
+                },
+                ".
+
+When debug assertions are enabled, this method also panics if the root
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_nth_root`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.0625).nth_root(4), 0.5);
+assert_eq!(Fix::from_num(2).nth_root(2), Fix::from_num(2).sqrt());
+assert_eq!(Fix::from_num(2).nth_root(1), 2);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-0.125).nth_root(3), -0.5);
+",
+                },
+                "```
+
+[`wrapping_nth_root`]: Self::wrapping_nth_root
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn nth_root(self, n: u32) -> Self {
+                    let (val, overflow) = self.overflowing_nth_root(n);
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Returns the reciprocal square root, 1/√`self`.
+
+The result has `RET_FRAC` fractional bits, which can be different from the
+number of fractional bits of `self`. It is computed directly rather than by
+taking the reciprocal of the square root, and it is rounded down, so that the
+error is &lt;&nbsp;`DELTA` of the result type. That is,
+result&nbsp;≤&nbsp;1/√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+# Panics
+
+Panics if the number is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or negative"
+                },
+                ".
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_rsqrt`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let r: Fix = Fix::from_num(0.25).rsqrt();
+assert_eq!(r, 2);
+
+// 1/√2 = 0.7071, which is rounded down to 0.703125 with 6 fractional bits
+type Out = ", stringify!($Self), "<6>;
+let r: Out = Fix::from_num(2).rsqrt();
+assert_eq!(r, Out::from_num(0.703125));
+```
+
+[`wrapping_rsqrt`]: Self::wrapping_rsqrt
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn rsqrt<const RET_FRAC: i32>(self) -> $Self<RET_FRAC> {
+                    let (val, overflow) = self.overflowing_rsqrt();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Linear interpolation between `start` and `end`.
+
+Returns
+`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
+is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
+linear interpolation for all other values of `self`. Linear extrapolation is
+performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_lerp`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let start = Fix::from_num(2);
+let end = Fix::from_num(3.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.0).lerp(start, end), 0.5);
+",
+                },
+                "assert_eq!(Fix::from_num(0.0).lerp(start, end), 2);
+assert_eq!(Fix::from_num(0.5).lerp(start, end), 2.75);
+assert_eq!(Fix::from_num(1.0).lerp(start, end), 3.5);
+assert_eq!(Fix::from_num(2.0).lerp(start, end), 5);
+```
+
+[`wrapping_lerp`]: Self::wrapping_lerp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn lerp<const RANGE_FRAC: i32>(
+                    self,
+                    start: $Self<RANGE_FRAC>,
+                    end: $Self<RANGE_FRAC>,
+                ) -> $Self<RANGE_FRAC> {
+                    let (ans, overflow) = lerp::$Inner(
+                        self.to_bits(),
+                        start.to_bits(),
+                        end.to_bits(),
+                        FRAC as u32,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    $Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Rounds to `dp` decimal places according to the given
+[`RoundingMode`].
+
+The value is first rounded to a multiple of 10<sup>&minus;`dp`</sup>, and
+then the representable value nearest to that multiple is returned, with ties
+rounded to even. Since multiples of 10<sup>&minus;`dp`</sup> are usually not
+representable exactly, the result is generally not an exact multiple. When
+[`DELTA`] is smaller than 10<sup>&minus;`dp`</sup>, the result is displayed
+as the rounded decimal number, so it round-trips through [`Display`].
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_round_dp`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<6>;
+// 1.359375 rounded to one decimal place is 1.4, which is not representable
+let x = Fix::from_num(1.359375);
+let rounded = x.round_dp(1, RoundingMode::HalfEven);
+assert_eq!(rounded, Fix::from_num(1.4));
+assert_eq!(rounded.to_string(), \"1.4\");
+assert_eq!(x.round_dp(2, RoundingMode::HalfEven).to_string(), \"1.36\");
+assert_eq!(x.round_dp(0, RoundingMode::ToZero), 1);
+```
+
+[`DELTA`]: Self::DELTA
+[`Display`]: core::fmt::Display
+[`wrapping_round_dp`]: Self::wrapping_round_dp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn round_dp(self, dp: u32, mode: RoundingMode) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_round_dp(dp, mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Rounds down to `dp` decimal places.
+
+This is equivalent to
+<code>self.[round\\_dp][Self::round_dp]\\(dp, [RoundingMode]::[Floor][RoundingMode::Floor])</code>;
+use [`checked_round_dp`] or another variant of [`round_dp`] if the result
+can overflow.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<6>;
+let x = Fix::from_num(1.359375);
+assert_eq!(x.floor_dp(1).to_string(), \"1.3\");
+assert_eq!(x.floor_dp(0), 1);
+```
+
+[`checked_round_dp`]: Self::checked_round_dp
+[`round_dp`]: Self::round_dp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn floor_dp(self, dp: u32) -> $Self<FRAC> {
+                    self.round_dp(dp, RoundingMode::Floor)
+                }
+            }
+
+            comment! {
+                "Rounds up to `dp` decimal places.
+
+This is equivalent to
+<code>self.[round\\_dp][Self::round_dp]\\(dp, [RoundingMode]::[Ceil][RoundingMode::Ceil])</code>;
+use [`checked_round_dp`] or another variant of [`round_dp`] if the result
+can overflow.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<6>;
+let x = Fix::from_num(1.359375);
+assert_eq!(x.ceil_dp(1).to_string(), \"1.4\");
+assert_eq!(Fix::from_num(1.25).ceil_dp(1).to_string(), \"1.3\");
+```
+
+[`checked_round_dp`]: Self::checked_round_dp
+[`round_dp`]: Self::round_dp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn ceil_dp(self, dp: u32) -> $Self<FRAC> {
+                    self.round_dp(dp, RoundingMode::Ceil)
+                }
+            }
+
+            comment! {
+                "Checked division. Returns the quotient, or [`None`] if
+the divisor is zero or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.checked_div(Fix::ONE), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_div(Fix::ONE / 2), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    if rhs.to_bits() == 0 {
+                        return None;
+                    }
+                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), FRAC as u32)
+                    {
                         (ans, false) => Some(Self::from_bits(ans)),
                         (_, true) => None,
                     }
                 }
             }
 
+            comment! {
+                "Checked multiplication, rounded according to the given
+[`RoundingMode`]. Returns the product, or [`None`] on overflow.
+
+Unlike multiplication using the `*` operator, which rounds toward &minus;∞,
+this method rounds according to `mode`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.5625 = 0.84375 lies halfway between 0.8125 and 0.875
+let (a, b) = (Fix::from_num(1.5), Fix::from_num(0.5625));
+assert_eq!(a.checked_mul_round(b, RoundingMode::Floor), Some(Fix::from_num(0.8125)));
+assert_eq!(a.checked_mul_round(b, RoundingMode::HalfEven), Some(Fix::from_num(0.875)));
+assert_eq!(a.checked_mul_round(b, RoundingMode::HalfToZero), Some(Fix::from_num(0.8125)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).checked_mul_round(b, RoundingMode::Floor), Some(Fix::from_num(-0.875)));
+assert_eq!((-a).checked_mul_round(b, RoundingMode::ToZero), Some(Fix::from_num(-0.8125)));
+",
+                },
+                "assert_eq!(Fix::MAX.checked_mul_round(Fix::from_num(2), RoundingMode::Floor), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_mul_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> Option<$Self<FRAC>> {
+                    match self.overflowing_mul_round(rhs, mode) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked division, rounded according to the given [`RoundingMode`].
+Returns the quotient, or [`None`] if the divisor is zero or on overflow.
+
+Unlike division using the `/` operator, which rounds toward zero, this method
+rounds according to `mode`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1.25 / 3 = 0.416… lies between 0.25 and 0.5
+let (a, b) = (Fix::from_num(1.25), Fix::from_num(3));
+assert_eq!(a.checked_div_round(b, RoundingMode::Floor), Some(Fix::from_num(0.25)));
+assert_eq!(a.checked_div_round(b, RoundingMode::Ceil), Some(Fix::from_num(0.5)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).checked_div_round(b, RoundingMode::Floor), Some(Fix::from_num(-0.5)));
+assert_eq!((-a).checked_div_round(b, RoundingMode::ToZero), Some(Fix::from_num(-0.25)));
+",
+                },
+                "// 0.75 / 6 = 0.125 lies halfway between 0 and 0.25
+let (c, d) = (Fix::from_num(0.75), Fix::from_num(6));
+assert_eq!(c.checked_div_round(d, RoundingMode::HalfEven), Some(Fix::ZERO));
+assert_eq!(c.checked_div_round(d, RoundingMode::HalfAwayFromZero), Some(Fix::from_num(0.25)));
+assert_eq!(Fix::MAX.checked_div_round(Fix::from_num(0.5), RoundingMode::Floor), None);
+assert_eq!(Fix::ONE.checked_div_round(Fix::ZERO, RoundingMode::Floor), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_div_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> Option<$Self<FRAC>> {
+                    if rhs.to_bits() == 0 {
+                        return None;
+                    }
+                    match self.overflowing_div_round(rhs, mode) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
             comment! {
                 "Checked reciprocal. Returns the reciprocal, or
 [`None`] if `self` is zero or on overflow.
@@ -1501,6 +2223,60 @@ assert_eq!(Fix::ZERO.checked_recip(), None);
                 }
             }
 
+            comment! {
+                "Checked reciprocal, rounded according to the given [`RoundingMode`].
+Returns the reciprocal, or [`None`] if `self` is zero or on overflow.
+
+Unlike [`recip`][Self::recip], which always rounds toward zero, this method
+rounds according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+reciprocal, and the two bounds are equal if the exact reciprocal is
+representable.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1/3 lies between 0.25 and 0.5
+let three = Fix::from_num(3);
+assert_eq!(three.checked_recip_round(RoundingMode::Floor), Some(Fix::from_num(0.25)));
+assert_eq!(three.checked_recip_round(RoundingMode::Ceil), Some(Fix::from_num(0.5)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-three).checked_recip_round(RoundingMode::Floor), Some(Fix::from_num(-0.5)));
+assert_eq!((-three).checked_recip_round(RoundingMode::ToZero), Some(Fix::from_num(-0.25)));
+",
+                },
+                "// 1/8 lies halfway between 0 and 0.25
+let eight = Fix::from_num(8);
+assert_eq!(eight.checked_recip_round(RoundingMode::HalfEven), Some(Fix::ZERO));
+assert_eq!(eight.checked_recip_round(RoundingMode::HalfAwayFromZero), Some(Fix::from_num(0.25)));
+
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+assert_eq!(Small::from_num(0.25).checked_recip_round(RoundingMode::Floor), None);
+assert_eq!(Small::ZERO.checked_recip_round(RoundingMode::Floor), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_recip_round(self, mode: RoundingMode) -> Option<$Self<FRAC>> {
+                    if self.to_bits() == 0 {
+                        None
+                    } else {
+                        match self.overflowing_recip_round(mode) {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Checked Euclidean division. Returns the quotient, or
 [`None`] if the divisor is zero or on overflow.
@@ -1609,15 +2385,292 @@ assert_eq!(Fix::from_num(3.75).checked_rem_int(0), None);
             }
 
             comment! {
-                "Checked Euclidean division by an integer. Returns the
-quotient, or [`None`] if the divisor is zero",
+                "Checked Euclidean division by an integer. Returns the
+quotient, or [`None`] if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or if the division results in overflow",
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(2), Some(Fix::from_num(3)));
+assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.checked_div_euclid_int(-1), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div_euclid_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
+                    let q = match self.checked_div_int(rhs) {
+                        Some(s) => s.round_to_zero(),
+                        None => return None,
+                    };
+                    if_signed! {
+                        $Signedness;
+                        if self.unwrapped_rem_int(rhs).is_negative() {
+                            let Some(neg_one) = Self::TRY_NEG_ONE else {
+                                return None;
+                            };
+                            return if rhs.is_positive() {
+                                q.checked_add(neg_one)
+                            } else {
+                                q.checked_sub(neg_one)
+                            };
+                        }
+                    }
+                    Some(q)
+                }
+            }
+
+            comment! {
+                "Checked remainder for Euclidean division by an integer.
+Returns the remainder, or [`None`] if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or if the remainder results in overflow",
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<", $nm4, ">;
+assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(2), Some(Fix::from_num(1.5)));
+assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(2), Some(Fix::from_num(0.5)));
+// -8 ≤ Fix < 8, so the answer 12.5 overflows
+assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(20), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_rem_euclid_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
+                    if_signed! {
+                        $Signedness;
+                        let Some(rem) = self.checked_rem_int(rhs) else {
+                            return None;
+                        };
+                        if !rem.is_negative() {
+                            return Some(rem);
+                        }
+                        // Work in unsigned.
+                        // Answer required is |rhs| - |rem|, but rhs is int, rem is fixed.
+                        // INT_BITS == 0 is a special case, as fraction can be negative.
+                        if Self::INT_BITS == 0 {
+                            // -0.5 <= rem < 0, so euclidean remainder is in the range
+                            // 0.5 <= answer < 1, which does not fit.
+                            return None;
+                        }
+                        let rhs_abs = rhs.wrapping_abs() as $UInner;
+                        let remb = rem.to_bits();
+                        let remb_abs = remb.wrapping_neg() as $UInner;
+                        let rem_int_abs = remb_abs >> Self::FRAC_BITS;
+                        let rem_frac = remb & Self::FRAC_MASK;
+                        let ans_int = rhs_abs - rem_int_abs - if rem_frac > 0 { 1 } else { 0 };
+                        let ansb_abs = if ans_int == 0 {
+                            0
+                        } else if Self::FRAC_BITS as u32 <= ans_int.leading_zeros() {
+                            ans_int << Self::FRAC_BITS
+                        } else {
+                            return None
+                        };
+                        let ansb = ansb_abs as $Inner;
+                        if ansb.is_negative() {
+                            return None;
+                        }
+                        Some(Self::from_bits(ansb | rem_frac))
+                    }
+                    if_unsigned! {
+                        $Signedness;
+                        self.checked_rem_int(rhs)
+                    }
+                }
+            }
+
+            comment! {
+                "Checked square root. ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Returns [`None`] for negative numbers and on overflow.",
+                    "Always returns the square root for unsigned numbers."
+                ),
+                "
+
+This method uses an iterative method, with up to ", $n, " iterations for
+ [`", stringify!($Self), "`]. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method returns [`None`] for an input value ≥&nbsp;0.25.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).checked_sqrt(), Some(Fix::SQRT_2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_sqrt(), None);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::from_num(0.25).checked_sqrt(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sqrt(self) -> Option<Self> {
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() {
+                            return None;
+                        }
+                    }
+                    match self.overflowing_sqrt() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked square root, rounded according to the given [`RoundingMode`].
+Returns [`None`] for negative numbers or on overflow.
+
+Unlike [`sqrt`][Self::sqrt], which always rounds down, this method rounds
+according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+square root, and the two bounds are equal if the exact square root is
+representable. The exact square root is never halfway between two
+representable values, so the two nearest modes give the same result.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when there are no integer bits and the input value is
+≥&nbsp;0.25, or when there is one integer bit and the result is rounded up to
+one.
+
+",
+                    "Overflow can only occur when there are no integer bits and the result is
+rounded up to one.
+
+",
+                ),
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// √2 lies between SQRT_2 and SQRT_2 + DELTA
+let two = Fix::from_num(2);
+assert_eq!(two.checked_sqrt_round(RoundingMode::Floor), Some(Fix::SQRT_2));
+assert_eq!(two.checked_sqrt_round(RoundingMode::Ceil), Some(Fix::SQRT_2 + Fix::DELTA));
+// exact square roots are not rounded
+assert_eq!(Fix::from_num(2.25).checked_sqrt_round(RoundingMode::Ceil), Some(Fix::from_num(1.5)));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+",
+                    ),
+                    concat!(
+                        "
+// no integer bits
+type Small = ", stringify!($Self), "<", $n, ">;
+",
+                    ),
+                ),
+                "assert_eq!(Small::MAX.checked_sqrt_round(RoundingMode::Floor), Some(Small::MAX));
+assert_eq!(Small::MAX.checked_sqrt_round(RoundingMode::Ceil), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sqrt_round(self, mode: RoundingMode) -> Option<Self> {
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() {
+                            return None;
+                        }
+                    }
+                    match self.overflowing_sqrt_round(mode) {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked cube root. ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Returns [`None`] on overflow.",
+                    "Always returns the cube root for unsigned numbers."
+                ),
+                "
+
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;∛`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    " or if the division results in overflow",
+                    " For negative numbers, this means that the result is rounded
+away from zero."
                 },
-                ".
+                "
 
-# Examples
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method returns [`None`] for an input value ≥&nbsp;0.125 or
+&lt;&nbsp;&minus;0.125.
+
+"
+                },
+                "# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -1625,138 +2678,112 @@ quotient, or [`None`] if the divisor is zero",
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(2), Some(Fix::from_num(3)));
-assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(0), None);
+assert_eq!(Fix::from_num(0.125).checked_cbrt(), Some(Fix::from_num(0.5)));
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::MIN.checked_div_euclid_int(-1), None);
+                    "assert_eq!(Fix::from_num(-0.125).checked_cbrt(), Some(Fix::from_num(-0.5)));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::from_num(0.125).checked_cbrt(), None);
 ",
                 },
                 "```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_div_euclid_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
-                    let q = match self.checked_div_int(rhs) {
-                        Some(s) => s.round_to_zero(),
-                        None => return None,
-                    };
-                    if_signed! {
-                        $Signedness;
-                        if self.unwrapped_rem_int(rhs).is_negative() {
-                            let Some(neg_one) = Self::TRY_NEG_ONE else {
-                                return None;
-                            };
-                            return if rhs.is_positive() {
-                                q.checked_add(neg_one)
-                            } else {
-                                q.checked_sub(neg_one)
-                            };
-                        }
+                #[must_use]
+                pub const fn checked_cbrt(self) -> Option<Self> {
+                    match self.overflowing_cbrt() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
                     }
-                    Some(q)
                 }
             }
 
             comment! {
-                "Checked remainder for Euclidean division by an integer.
-Returns the remainder, or [`None`] if the divisor is zero",
+                "Checked <i>n</i>th root. Returns [`None`] if `n` is zero",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    " or if the remainder results in overflow",
+                    ", if the number is negative and `n` is even, or on overflow"
                 },
                 ".
 
-# Examples
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;`self`<sup>1/`n`</sup>&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method returns [`None`] for an input value
+≥&nbsp;2<sup>&minus;`n`</sup>, or for odd `n`, for an input value
+&lt;&nbsp;&minus;2<sup>&minus;`n`</sup>.
+
+"
+                },
+                "# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<", $nm4, ">;
-assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(2), Some(Fix::from_num(1.5)));
-assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(0), None);
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.0625).checked_nth_root(4), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::from_num(2).checked_nth_root(0), None);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(2), Some(Fix::from_num(0.5)));
-// -8 ≤ Fix < 8, so the answer 12.5 overflows
-assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(20), None);
+                    "assert_eq!(Fix::from_num(-1).checked_nth_root(2), None);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::from_num(0.25).checked_nth_root(2), None);
 ",
                 },
                 "```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_rem_euclid_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
+                #[must_use]
+                pub const fn checked_nth_root(self, n: u32) -> Option<Self> {
+                    if n == 0 {
+                        return None;
+                    }
                     if_signed! {
                         $Signedness;
-                        let Some(rem) = self.checked_rem_int(rhs) else {
-                            return None;
-                        };
-                        if !rem.is_negative() {
-                            return Some(rem);
-                        }
-                        // Work in unsigned.
-                        // Answer required is |rhs| - |rem|, but rhs is int, rem is fixed.
-                        // INT_BITS == 0 is a special case, as fraction can be negative.
-                        if Self::INT_BITS == 0 {
-                            // -0.5 <= rem < 0, so euclidean remainder is in the range
-                            // 0.5 <= answer < 1, which does not fit.
-                            return None;
-                        }
-                        let rhs_abs = rhs.wrapping_abs() as $UInner;
-                        let remb = rem.to_bits();
-                        let remb_abs = remb.wrapping_neg() as $UInner;
-                        let rem_int_abs = remb_abs >> Self::FRAC_BITS;
-                        let rem_frac = remb & Self::FRAC_MASK;
-                        let ans_int = rhs_abs - rem_int_abs - if rem_frac > 0 { 1 } else { 0 };
-                        let ansb_abs = if ans_int == 0 {
-                            0
-                        } else if Self::FRAC_BITS as u32 <= ans_int.leading_zeros() {
-                            ans_int << Self::FRAC_BITS
-                        } else {
-                            return None
-                        };
-                        let ansb = ansb_abs as $Inner;
-                        if ansb.is_negative() {
+                        if self.is_negative() && n % 2 == 0 {
                             return None;
                         }
-                        Some(Self::from_bits(ansb | rem_frac))
                     }
-                    if_unsigned! {
-                        $Signedness;
-                        self.checked_rem_int(rhs)
+                    match self.overflowing_nth_root(n) {
+                        (val, false) => Some(val),
+                        (_, true) => None,
                     }
                 }
             }
 
             comment! {
-                "Checked square root. ",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "Returns [`None`] for negative numbers and on overflow.",
-                    "Always returns the square root for unsigned numbers."
-                ),
-                "
-
-This method uses an iterative method, with up to ", $n, " iterations for
- [`", stringify!($Self), "`]. The result is rounded down, and the error is
-&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
-result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
-
-",
+                "Checked reciprocal square root. Returns [`None`] if the number
+is zero",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "Overflow can only occur when there are no integer bits and
-the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
-In this case, the method returns [`None`] for an input value ≥&nbsp;0.25.
-
-"
+                    " or negative"
                 },
-                "# Examples
+                ", or on overflow.
+
+The result has `RET_FRAC` fractional bits, which can be different from the
+number of fractional bits of `self`. It is computed directly rather than by
+taking the reciprocal of the square root, and it is rounded down, so that the
+error is &lt;&nbsp;`DELTA` of the result type. That is,
+result&nbsp;≤&nbsp;1/√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -1764,28 +2791,36 @@ In this case, the method returns [`None`] for an input value ≥&nbsp;0.25.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(2).checked_sqrt(), Some(Fix::SQRT_2));
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+let r: Option<Fix> = Fix::from_num(0.25).checked_rsqrt();
+assert_eq!(r, Some(Fix::from_num(2)));
+let r: Option<Fix> = Fix::ZERO.checked_rsqrt();
+assert_eq!(r, None);
+let r: Option<Small> = Fix::from_num(0.25).checked_rsqrt();
+assert_eq!(r, None);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-1).checked_sqrt(), None);
-
-type AllFrac = ", stringify!($Self), "<", $n, ">;
-assert_eq!(AllFrac::from_num(0.25).checked_sqrt(), None);
+                    "let r: Option<Fix> = Fix::from_num(-1).checked_rsqrt();
+assert_eq!(r, None);
 ",
                 },
                 "```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn checked_sqrt(self) -> Option<Self> {
+                pub const fn checked_rsqrt<const RET_FRAC: i32>(self) -> Option<$Self<RET_FRAC>> {
+                    if self.to_bits() == 0 {
+                        return None;
+                    }
                     if_signed! {
                         $Signedness;
                         if self.is_negative() {
                             return None;
                         }
                     }
-                    match self.overflowing_sqrt() {
+                    match self.overflowing_rsqrt() {
                         (val, false) => Some(val),
                         (_, true) => None,
                     }
@@ -1829,7 +2864,145 @@ assert_eq!(Fix::from_num(1.5).checked_lerp(Fix::ZERO, Fix::MAX), None);
             }
 
             comment! {
-                "Saturating division. Returns the quotient, saturating on overflow.
+                "Checked rounding to `dp` decimal places. Rounds according to
+the given [`RoundingMode`], returning [`None`] on overflow.
+
+See [`round_dp`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<6>;
+// 1.359375 rounded to one decimal place is 1.4, which is not representable
+let x = Fix::from_num(1.359375);
+assert_eq!(x.checked_round_dp(1, RoundingMode::HalfEven), Some(Fix::from_num(1.4)));
+assert_eq!(Fix::MAX.checked_round_dp(1, RoundingMode::Ceil), None);
+```
+
+[`round_dp`]: Self::round_dp
+";
+                #[inline]
+                #[must_use]
+                pub fn checked_round_dp(self, dp: u32, mode: RoundingMode) -> Option<$Self<FRAC>> {
+                    match self.overflowing_round_dp(dp, mode) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating division. Returns the quotient, saturating on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let one_half = Fix::ONE / 2;
+assert_eq!(Fix::ONE.saturating_div(Fix::from_num(2)), one_half);
+assert_eq!(Fix::MAX.saturating_div(one_half), Fix::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_div(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), FRAC as u32)
+                    {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() != rhs.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating multiplication, rounded according to the given
+[`RoundingMode`]. Returns the product, saturating on overflow.
+
+Unlike multiplication using the `*` operator, which rounds toward &minus;∞,
+this method rounds according to `mode`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.5625 = 0.84375 lies halfway between 0.8125 and 0.875
+let (a, b) = (Fix::from_num(1.5), Fix::from_num(0.5625));
+assert_eq!(a.saturating_mul_round(b, RoundingMode::Floor), Fix::from_num(0.8125));
+assert_eq!(a.saturating_mul_round(b, RoundingMode::HalfEven), Fix::from_num(0.875));
+assert_eq!(a.saturating_mul_round(b, RoundingMode::HalfToZero), Fix::from_num(0.8125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).saturating_mul_round(b, RoundingMode::Floor), Fix::from_num(-0.875));
+assert_eq!((-a).saturating_mul_round(b, RoundingMode::ToZero), Fix::from_num(-0.8125));
+",
+                },
+                "assert_eq!(Fix::MAX.saturating_mul_round(Fix::from_num(2), RoundingMode::Floor), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MAX.saturating_mul_round(Fix::from_num(-2), RoundingMode::Floor), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_mul_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> $Self<FRAC> {
+                    match self.overflowing_mul_round(rhs, mode) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() != rhs.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating division, rounded according to the given [`RoundingMode`].
+Returns the quotient, saturating on overflow.
+
+Unlike division using the `/` operator, which rounds toward zero, this method
+rounds according to `mode`.
 
 # Panics
 
@@ -1841,20 +3014,42 @@ Panics if the divisor is zero.
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
-use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<4>;
-let one_half = Fix::ONE / 2;
-assert_eq!(Fix::ONE.saturating_div(Fix::from_num(2)), one_half);
-assert_eq!(Fix::MAX.saturating_div(one_half), Fix::MAX);
-```
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1.25 / 3 = 0.416… lies between 0.25 and 0.5
+let (a, b) = (Fix::from_num(1.25), Fix::from_num(3));
+assert_eq!(a.saturating_div_round(b, RoundingMode::Floor), Fix::from_num(0.25));
+assert_eq!(a.saturating_div_round(b, RoundingMode::Ceil), Fix::from_num(0.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).saturating_div_round(b, RoundingMode::Floor), Fix::from_num(-0.5));
+assert_eq!((-a).saturating_div_round(b, RoundingMode::ToZero), Fix::from_num(-0.25));
+",
+                },
+                "// 0.75 / 6 = 0.125 lies halfway between 0 and 0.25
+let (c, d) = (Fix::from_num(0.75), Fix::from_num(6));
+assert_eq!(c.saturating_div_round(d, RoundingMode::HalfEven), Fix::ZERO);
+assert_eq!(c.saturating_div_round(d, RoundingMode::HalfAwayFromZero), Fix::from_num(0.25));
+assert_eq!(Fix::MAX.saturating_div_round(Fix::from_num(0.5), RoundingMode::Floor), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MAX.saturating_div_round(Fix::from_num(-0.5), RoundingMode::Floor), Fix::MIN);
+",
+                },
+                "```
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_div(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), FRAC as u32)
-                    {
-                        (ans, false) => Self::from_bits(ans),
+                #[must_use]
+                pub const fn saturating_div_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> $Self<FRAC> {
+                    match self.overflowing_div_round(rhs, mode) {
+                        (ans, false) => ans,
                         (_, true) => {
                             if_signed_unsigned!(
                                 $Signedness,
@@ -1917,6 +3112,76 @@ assert_eq!(Fix::from_num(0.25).saturating_recip(), Fix::MAX);
                 }
             }
 
+            comment! {
+                "Saturating reciprocal, rounded according to the given [`RoundingMode`].
+Returns the reciprocal, saturating on overflow.
+
+Unlike [`recip`][Self::recip], which always rounds toward zero, this method
+rounds according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+reciprocal, and the two bounds are equal if the exact reciprocal is
+representable.
+
+# Panics
+
+Panics if the fixed-point number is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1/3 lies between 0.25 and 0.5
+let three = Fix::from_num(3);
+assert_eq!(three.saturating_recip_round(RoundingMode::Floor), Fix::from_num(0.25));
+assert_eq!(three.saturating_recip_round(RoundingMode::Ceil), Fix::from_num(0.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-three).saturating_recip_round(RoundingMode::Floor), Fix::from_num(-0.5));
+assert_eq!((-three).saturating_recip_round(RoundingMode::ToZero), Fix::from_num(-0.25));
+",
+                },
+                "// 1/8 lies halfway between 0 and 0.25
+let eight = Fix::from_num(8);
+assert_eq!(eight.saturating_recip_round(RoundingMode::HalfEven), Fix::ZERO);
+assert_eq!(eight.saturating_recip_round(RoundingMode::HalfAwayFromZero), Fix::from_num(0.25));
+
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+assert_eq!(Small::from_num(0.25).saturating_recip_round(RoundingMode::Floor), Small::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Small::from_num(-0.25).saturating_recip_round(RoundingMode::Floor), Small::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_recip_round(self, mode: RoundingMode) -> $Self<FRAC> {
+                    match self.overflowing_recip_round(mode) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Saturating Euclidean division. Returns the quotient,
 saturating on overflow.
@@ -2108,10 +3373,263 @@ assert_eq!(AllFrac::from_num(0.25).saturating_sqrt(), AllFrac::MAX);
 ";
                 #[inline]
                 #[must_use]
-                pub const fn saturating_sqrt(self) -> Self {
-                    match self.overflowing_sqrt() {
+                pub const fn saturating_sqrt(self) -> Self {
+                    match self.overflowing_sqrt() {
+                        (val, false) => val,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating square root, rounded according to the given [`RoundingMode`].
+Returns the square root, saturating on overflow.
+
+Unlike [`sqrt`][Self::sqrt], which always rounds down, this method rounds
+according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+square root, and the two bounds are equal if the exact square root is
+representable. The exact square root is never halfway between two
+representable values, so the two nearest modes give the same result.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when there are no integer bits and the input value is
+≥&nbsp;0.25, or when there is one integer bit and the result is rounded up to
+one.
+
+",
+                    "Overflow can only occur when there are no integer bits and the result is
+rounded up to one.
+
+",
+                ),
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// √2 lies between SQRT_2 and SQRT_2 + DELTA
+let two = Fix::from_num(2);
+assert_eq!(two.saturating_sqrt_round(RoundingMode::Floor), Fix::SQRT_2);
+assert_eq!(two.saturating_sqrt_round(RoundingMode::Ceil), Fix::SQRT_2 + Fix::DELTA);
+// exact square roots are not rounded
+assert_eq!(Fix::from_num(2.25).saturating_sqrt_round(RoundingMode::Ceil), Fix::from_num(1.5));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+",
+                    ),
+                    concat!(
+                        "
+// no integer bits
+type Small = ", stringify!($Self), "<", $n, ">;
+",
+                    ),
+                ),
+                "assert_eq!(Small::MAX.saturating_sqrt_round(RoundingMode::Ceil), Small::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_sqrt_round(self, mode: RoundingMode) -> Self {
+                    match self.overflowing_sqrt_round(mode) {
+                        (val, false) => val,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the cube root, saturating on overflow.",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    " Can never overflow for unsigned numbers."
+                },
+                "
+
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;∛`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method saturates for an input value ≥&nbsp;0.125 or
+&lt;&nbsp;&minus;0.125.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.125).saturating_cbrt(), 0.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::from_num(0.125).saturating_cbrt(), AllFrac::MAX);
+assert_eq!(AllFrac::from_num(-0.25).saturating_cbrt(), AllFrac::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_cbrt(self) -> Self {
+                    self.saturating_nth_root(3)
+                }
+            }
+
+            comment! {
+                "Returns the <i>n</i>th root, saturating on overflow.",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    " Can never overflow for unsigned numbers."
+                },
+                "
+
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;`self`<sup>1/`n`</sup>&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method saturates for an input value
+≥&nbsp;2<sup>&minus;`n`</sup>, or for odd `n`, for an input value
+&lt;&nbsp;&minus;2<sup>&minus;`n`</sup>.
+
+"
+                },
+                "# Panics
+
+Panics if `n` is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", or if the number is negative and `n` is even"
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.0625).saturating_nth_root(4), 0.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::from_num(0.25).saturating_nth_root(2), AllFrac::MAX);
+assert_eq!(AllFrac::from_num(-0.25).saturating_nth_root(3), AllFrac::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_nth_root(self, n: u32) -> Self {
+                    match self.overflowing_nth_root(n) {
+                        (val, false) => val,
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the reciprocal square root, saturating on overflow.
+
+The result has `RET_FRAC` fractional bits, which can be different from the
+number of fractional bits of `self`. It is computed directly rather than by
+taking the reciprocal of the square root, and it is rounded down, so that the
+error is &lt;&nbsp;`DELTA` of the result type. That is,
+result&nbsp;≤&nbsp;1/√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+# Panics
+
+Panics if the number is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or negative"
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+let r: Fix = Fix::from_num(0.25).saturating_rsqrt();
+assert_eq!(r, 2);
+let r: Small = Fix::from_num(0.25).saturating_rsqrt();
+assert_eq!(r, Small::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_rsqrt<const RET_FRAC: i32>(self) -> $Self<RET_FRAC> {
+                    match self.overflowing_rsqrt() {
                         (val, false) => val,
-                        (_, true) => Self::MAX,
+                        (_, true) => $Self::MAX,
                     }
                 }
             }
@@ -2173,6 +3691,44 @@ assert_eq!(Fix::from_num(3.0).saturating_lerp(Fix::MAX, Fix::ZERO), Fix::MIN);
                 }
             }
 
+            comment! {
+                "Saturating rounding to `dp` decimal places. Rounds according
+to the given [`RoundingMode`], saturating on overflow.
+
+See [`round_dp`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<6>;
+// 1.359375 rounded to one decimal place is 1.4, which is not representable
+let x = Fix::from_num(1.359375);
+assert_eq!(x.saturating_round_dp(1, RoundingMode::HalfEven), Fix::from_num(1.4));
+assert_eq!(Fix::MAX.saturating_round_dp(1, RoundingMode::Ceil), Fix::MAX);
+```
+
+[`round_dp`]: Self::round_dp
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_round_dp(self, dp: u32, mode: RoundingMode) -> $Self<FRAC> {
+                    match self.overflowing_round_dp(dp, mode) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if if_signed_unsigned!($Signedness, self.is_negative(), false) {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Wrapping division. Returns the quotient, wrapping on overflow.
 
@@ -2205,6 +3761,100 @@ assert_eq!(Fix::MAX.wrapping_div(quarter), wrapped);
                 }
             }
 
+            comment! {
+                "Wrapping multiplication, rounded according to the given
+[`RoundingMode`]. Returns the product, wrapping on overflow.
+
+Unlike multiplication using the `*` operator, which rounds toward &minus;∞,
+this method rounds according to `mode`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.5625 = 0.84375 lies halfway between 0.8125 and 0.875
+let (a, b) = (Fix::from_num(1.5), Fix::from_num(0.5625));
+assert_eq!(a.wrapping_mul_round(b, RoundingMode::Floor), Fix::from_num(0.8125));
+assert_eq!(a.wrapping_mul_round(b, RoundingMode::HalfEven), Fix::from_num(0.875));
+assert_eq!(a.wrapping_mul_round(b, RoundingMode::HalfToZero), Fix::from_num(0.8125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).wrapping_mul_round(b, RoundingMode::Floor), Fix::from_num(-0.875));
+assert_eq!((-a).wrapping_mul_round(b, RoundingMode::ToZero), Fix::from_num(-0.8125));
+",
+                },
+                "let two = Fix::from_num(2);
+assert_eq!(Fix::MAX.wrapping_mul_round(two, RoundingMode::Floor), Fix::MAX.wrapping_mul(two));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_mul_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> $Self<FRAC> {
+                    let (ans, _) = self.overflowing_mul_round(rhs, mode);
+                    ans
+                }
+            }
+
+            comment! {
+                "Wrapping division, rounded according to the given [`RoundingMode`].
+Returns the quotient, wrapping on overflow.
+
+Unlike division using the `/` operator, which rounds toward zero, this method
+rounds according to `mode`.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1.25 / 3 = 0.416… lies between 0.25 and 0.5
+let (a, b) = (Fix::from_num(1.25), Fix::from_num(3));
+assert_eq!(a.wrapping_div_round(b, RoundingMode::Floor), Fix::from_num(0.25));
+assert_eq!(a.wrapping_div_round(b, RoundingMode::Ceil), Fix::from_num(0.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).wrapping_div_round(b, RoundingMode::Floor), Fix::from_num(-0.5));
+assert_eq!((-a).wrapping_div_round(b, RoundingMode::ToZero), Fix::from_num(-0.25));
+",
+                },
+                "// 0.75 / 6 = 0.125 lies halfway between 0 and 0.25
+let (c, d) = (Fix::from_num(0.75), Fix::from_num(6));
+assert_eq!(c.wrapping_div_round(d, RoundingMode::HalfEven), Fix::ZERO);
+assert_eq!(c.wrapping_div_round(d, RoundingMode::HalfAwayFromZero), Fix::from_num(0.25));
+let half = Fix::from_num(0.5);
+assert_eq!(Fix::MAX.wrapping_div_round(half, RoundingMode::Floor), Fix::MAX.wrapping_div(half));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_div_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> $Self<FRAC> {
+                    let (ans, _) = self.overflowing_div_round(rhs, mode);
+                    ans
+                }
+            }
+
             comment! {
                 "Wrapping reciprocal. Returns the reciprocal,
 wrapping on overflow.
@@ -2234,6 +3884,58 @@ assert_eq!(Fix::from_num(0.25).wrapping_recip(), Fix::ZERO);
                 }
             }
 
+            comment! {
+                "Wrapping reciprocal, rounded according to the given [`RoundingMode`].
+Returns the reciprocal, wrapping on overflow.
+
+Unlike [`recip`][Self::recip], which always rounds toward zero, this method
+rounds according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+reciprocal, and the two bounds are equal if the exact reciprocal is
+representable.
+
+# Panics
+
+Panics if the fixed-point number is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1/3 lies between 0.25 and 0.5
+let three = Fix::from_num(3);
+assert_eq!(three.wrapping_recip_round(RoundingMode::Floor), Fix::from_num(0.25));
+assert_eq!(three.wrapping_recip_round(RoundingMode::Ceil), Fix::from_num(0.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-three).wrapping_recip_round(RoundingMode::Floor), Fix::from_num(-0.5));
+assert_eq!((-three).wrapping_recip_round(RoundingMode::ToZero), Fix::from_num(-0.25));
+",
+                },
+                "// 1/8 lies halfway between 0 and 0.25
+let eight = Fix::from_num(8);
+assert_eq!(eight.wrapping_recip_round(RoundingMode::HalfEven), Fix::ZERO);
+assert_eq!(eight.wrapping_recip_round(RoundingMode::HalfAwayFromZero), Fix::from_num(0.25));
+
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+assert_eq!(Small::from_num(0.25).wrapping_recip_round(RoundingMode::Floor), Small::ZERO);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_recip_round(self, mode: RoundingMode) -> $Self<FRAC> {
+                    let (ans, _) = self.overflowing_recip_round(mode);
+                    ans
+                }
+            }
+
             comment! {
                 "Wrapping Euclidean division. Returns the quotient, wrapping on overflow.
 
@@ -2255,133 +3957,322 @@ assert_eq!(Fix::MAX.wrapping_div_euclid(Fix::from_num(0.25)), wrapped);
 ```
 ";
                 #[inline]
-                #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_div_euclid(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    self.overflowing_div_euclid(rhs).0
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_div_euclid(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    self.overflowing_div_euclid(rhs).0
+                }
+            }
+
+            comment! {
+                "Wrapping Euclidean division by an integer. Returns the quotient",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", wrapping on overflow.
+
+Overflow can only occur when dividing the minimum value by &minus;1.",
+                    ".
+
+Can never overflow for unsigned values.",
+                ),
+                "
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(7.5).wrapping_div_euclid_int(2), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).wrapping_div_euclid_int(2), Fix::from_num(-4));
+let wrapped = Fix::MIN.round_to_zero();
+assert_eq!(Fix::MIN.wrapping_div_euclid_int(-1), wrapped);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_div_euclid_int(self, rhs: $Inner) -> $Self<FRAC> {
+                    self.overflowing_div_euclid_int(rhs).0
+                }
+            }
+
+            comment! {
+                "Wrapping remainder for Euclidean division by an integer. Returns the remainder",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", wrapping on overflow.
+
+Note that while remainder for Euclidean division cannot be negative,
+the wrapped value can be negative.",
+                    ".
+
+Can never overflow for unsigned values.",
+                ),
+                "
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<", $nm4, ">;
+assert_eq!(Fix::from_num(7.5).wrapping_rem_euclid_int(2), Fix::from_num(1.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(2), Fix::from_num(0.5));
+// -8 ≤ Fix < 8, so the answer 12.5 wraps to -3.5
+assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(20), Fix::from_num(-3.5));
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_rem_euclid_int(self, rhs: $Inner) -> $Self<FRAC> {
+                    self.overflowing_rem_euclid_int(rhs).0
+                }
+            }
+
+            comment! {
+                "Returns the square root, wrapping on overflow.",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    " Can never overflow for unsigned numbers."
+                },
+                "
+
+This method uses an iterative method, with up to ", $n, " iterations for
+ [`", stringify!($Self), "`]. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method returns the wrapped answer for an input value ≥&nbsp;0.25.
+
+"
+                },
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).wrapping_sqrt(), Fix::SQRT_2);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::from_num(0.25).wrapping_sqrt(), AllFrac::from_num(-0.5));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sqrt(self) -> Self {
+                    self.overflowing_sqrt().0
                 }
             }
 
             comment! {
-                "Wrapping Euclidean division by an integer. Returns the quotient",
+                "Wrapping square root, rounded according to the given [`RoundingMode`].
+Returns the square root, wrapping on overflow.
+
+Unlike [`sqrt`][Self::sqrt], which always rounds down, this method rounds
+according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+square root, and the two bounds are equal if the exact square root is
+representable. The exact square root is never halfway between two
+representable values, so the two nearest modes give the same result.
+
+",
                 if_signed_unsigned!(
                     $Signedness,
-                    ", wrapping on overflow.
+                    "Overflow can only occur when there are no integer bits and the input value is
+≥&nbsp;0.25, or when there is one integer bit and the result is rounded up to
+one.
 
-Overflow can only occur when dividing the minimum value by &minus;1.",
-                    ".
+",
+                    "Overflow can only occur when there are no integer bits and the result is
+rounded up to one.
 
-Can never overflow for unsigned values.",
+",
                 ),
-                "
-
-# Panics
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
 
-Panics if the divisor is zero.
+Panics if the number is negative.
 
-# Examples
+"
+                },
+                "# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
-use fixed::", stringify!($Self), ";
+use fixed::{", stringify!($Self), ", RoundingMode};
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(7.5).wrapping_div_euclid_int(2), Fix::from_num(3));
+// √2 lies between SQRT_2 and SQRT_2 + DELTA
+let two = Fix::from_num(2);
+assert_eq!(two.wrapping_sqrt_round(RoundingMode::Floor), Fix::SQRT_2);
+assert_eq!(two.wrapping_sqrt_round(RoundingMode::Ceil), Fix::SQRT_2 + Fix::DELTA);
+// exact square roots are not rounded
+assert_eq!(Fix::from_num(2.25).wrapping_sqrt_round(RoundingMode::Ceil), Fix::from_num(1.5));
 ",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).wrapping_div_euclid_int(2), Fix::from_num(-4));
-let wrapped = Fix::MIN.round_to_zero();
-assert_eq!(Fix::MIN.wrapping_div_euclid_int(-1), wrapped);
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
 ",
-                },
-                "```
+                    ),
+                    concat!(
+                        "
+// no integer bits
+type Small = ", stringify!($Self), "<", $n, ">;
+",
+                    ),
+                ),
+                "assert_eq!(Small::MAX.wrapping_sqrt_round(RoundingMode::Ceil), Small::MIN);
+```
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_div_euclid_int(self, rhs: $Inner) -> $Self<FRAC> {
-                    self.overflowing_div_euclid_int(rhs).0
+                #[must_use]
+                pub const fn wrapping_sqrt_round(self, mode: RoundingMode) -> Self {
+                    self.overflowing_sqrt_round(mode).0
                 }
             }
 
             comment! {
-                "Wrapping remainder for Euclidean division by an integer. Returns the remainder",
-                if_signed_unsigned!(
-                    $Signedness,
-                    ", wrapping on overflow.
-
-Note that while remainder for Euclidean division cannot be negative,
-the wrapped value can be negative.",
-                    ".
-
-Can never overflow for unsigned values.",
-                ),
+                "Returns the cube root, wrapping on overflow.",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    " Can never overflow for unsigned numbers."
+                },
                 "
 
-# Panics
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;∛`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
 
-Panics if the divisor is zero.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method returns the wrapped answer for an input value ≥&nbsp;0.125 or
+&lt;&nbsp;&minus;0.125.
 
-# Examples
+"
+                },
+                "# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<", $nm4, ">;
-assert_eq!(Fix::from_num(7.5).wrapping_rem_euclid_int(2), Fix::from_num(1.5));
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.125).wrapping_cbrt(), 0.5);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(2), Fix::from_num(0.5));
-// -8 ≤ Fix < 8, so the answer 12.5 wraps to -3.5
-assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(20), Fix::from_num(-3.5));
+                    "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::from_num(0.125).wrapping_cbrt(), AllFrac::from_num(-0.5));
 ",
                 },
                 "```
 ";
                 #[inline]
-                #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_rem_euclid_int(self, rhs: $Inner) -> $Self<FRAC> {
-                    self.overflowing_rem_euclid_int(rhs).0
+                #[must_use]
+                pub const fn wrapping_cbrt(self) -> Self {
+                    self.overflowing_cbrt().0
                 }
             }
 
             comment! {
-                "Returns the square root, wrapping on overflow.",
+                "Returns the <i>n</i>th root, wrapping on overflow.",
                 if_unsigned_else_empty_str! {
                     $Signedness;
                     " Can never overflow for unsigned numbers."
                 },
                 "
 
-This method uses an iterative method, with up to ", $n, " iterations for
- [`", stringify!($Self), "`]. The result is rounded down, and the error is
-&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
-result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;`self`<sup>1/`n`</sup>&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
 
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "Overflow can only occur when there are no integer bits and
 the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
-In this case, the method returns the wrapped answer for an input value ≥&nbsp;0.25.
+In this case, the method returns the wrapped answer for an input value
+≥&nbsp;2<sup>&minus;`n`</sup>, or for odd `n`, for an input value
+&lt;&nbsp;&minus;2<sup>&minus;`n`</sup>.
 
 "
                 },
+                "# Panics
+
+Panics if `n` is zero",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "# Panics
-
-Panics if the number is negative.
-
-"
+                    ", or if the number is negative and `n` is even"
                 },
-                "# Examples
+                ".
+
+# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -2389,21 +4280,64 @@ Panics if the number is negative.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(2).wrapping_sqrt(), Fix::SQRT_2);
+assert_eq!(Fix::from_num(0.0625).wrapping_nth_root(4), 0.5);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "
 type AllFrac = ", stringify!($Self), "<", $n, ">;
-assert_eq!(AllFrac::from_num(0.25).wrapping_sqrt(), AllFrac::from_num(-0.5));
+assert_eq!(AllFrac::from_num(0.25).wrapping_nth_root(2), AllFrac::from_num(-0.5));
 ",
                 },
                 "```
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use]
-                pub const fn wrapping_sqrt(self) -> Self {
-                    self.overflowing_sqrt().0
+                pub const fn wrapping_nth_root(self, n: u32) -> Self {
+                    self.overflowing_nth_root(n).0
+                }
+            }
+
+            comment! {
+                "Returns the reciprocal square root, wrapping on overflow.
+
+The result has `RET_FRAC` fractional bits, which can be different from the
+number of fractional bits of `self`. It is computed directly rather than by
+taking the reciprocal of the square root, and it is rounded down, so that the
+error is &lt;&nbsp;`DELTA` of the result type. That is,
+result&nbsp;≤&nbsp;1/√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+# Panics
+
+Panics if the number is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or negative"
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+let r: Fix = Fix::from_num(0.25).wrapping_rsqrt();
+assert_eq!(r, 2);
+let r: Small = Fix::from_num(0.25).wrapping_rsqrt();
+assert_eq!(r, Small::ZERO);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_rsqrt<const RET_FRAC: i32>(self) -> $Self<RET_FRAC> {
+                    self.overflowing_rsqrt().0
                 }
             }
 
@@ -2446,11 +4380,147 @@ assert_eq!(
             }
 
             comment! {
-                "Unwrapped division. Returns the quotient, panicking on overflow.
+                "Wrapping rounding to `dp` decimal places. Rounds according to
+the given [`RoundingMode`], wrapping on overflow.
+
+See [`round_dp`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<6>;
+// 1.359375 rounded to one decimal place is 1.4, which is not representable
+let x = Fix::from_num(1.359375);
+assert_eq!(x.wrapping_round_dp(1, RoundingMode::HalfEven), Fix::from_num(1.4));
+assert_eq!(Fix::MAX.wrapping_round_dp(1, RoundingMode::Ceil), ",
+                if_signed_unsigned!($Signedness, "Fix::MIN", "Fix::ZERO"),
+                ");
+```
+
+[`round_dp`]: Self::round_dp
+";
+                #[inline]
+                #[must_use]
+                pub fn wrapping_round_dp(self, dp: u32, mode: RoundingMode) -> $Self<FRAC> {
+                    self.overflowing_round_dp(dp, mode).0
+                }
+            }
+
+            comment! {
+                "Unwrapped division. Returns the quotient, panicking on overflow.
+
+# Panics
+
+Panics if the divisor is zero or if the division results in overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let one_point_5 = Fix::from_bits(0b11 << (4 - 1));
+assert_eq!(Fix::from_num(3).unwrapped_div(Fix::from_num(2)), one_point_5);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let quarter = Fix::ONE / 4;
+let _overflow = Fix::MAX.unwrapped_div(quarter);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_div(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.overflowing_div(rhs) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped multiplication, rounded according to the given
+[`RoundingMode`]. Returns the product, panicking on overflow.
+
+Unlike multiplication using the `*` operator, which rounds toward &minus;∞,
+this method rounds according to `mode`.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.5625 = 0.84375 lies halfway between 0.8125 and 0.875
+let (a, b) = (Fix::from_num(1.5), Fix::from_num(0.5625));
+assert_eq!(a.unwrapped_mul_round(b, RoundingMode::Floor), Fix::from_num(0.8125));
+assert_eq!(a.unwrapped_mul_round(b, RoundingMode::HalfEven), Fix::from_num(0.875));
+assert_eq!(a.unwrapped_mul_round(b, RoundingMode::HalfToZero), Fix::from_num(0.8125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).unwrapped_mul_round(b, RoundingMode::Floor), Fix::from_num(-0.875));
+assert_eq!((-a).unwrapped_mul_round(b, RoundingMode::ToZero), Fix::from_num(-0.8125));
+",
+                },
+                "```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_mul_round(Fix::from_num(2), RoundingMode::Floor);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_mul_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> $Self<FRAC> {
+                    match self.overflowing_mul_round(rhs, mode) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped division, rounded according to the given [`RoundingMode`].
+Returns the quotient, panicking on overflow.
+
+Unlike division using the `/` operator, which rounds toward zero, this method
+rounds according to `mode`.
 
 # Panics
 
-Panics if the divisor is zero or if the division results in overflow.
+Panics if the divisor is zero or if the result does not fit.
 
 # Examples
 
@@ -2458,29 +4528,45 @@ Panics if the divisor is zero or if the division results in overflow.
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
-use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<4>;
-let one_point_5 = Fix::from_bits(0b11 << (4 - 1));
-assert_eq!(Fix::from_num(3).unwrapped_div(Fix::from_num(2)), one_point_5);
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1.25 / 3 = 0.416… lies between 0.25 and 0.5
+let (a, b) = (Fix::from_num(1.25), Fix::from_num(3));
+assert_eq!(a.unwrapped_div_round(b, RoundingMode::Floor), Fix::from_num(0.25));
+assert_eq!(a.unwrapped_div_round(b, RoundingMode::Ceil), Fix::from_num(0.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).unwrapped_div_round(b, RoundingMode::Floor), Fix::from_num(-0.5));
+assert_eq!((-a).unwrapped_div_round(b, RoundingMode::ToZero), Fix::from_num(-0.25));
+",
+                },
+                "// 0.75 / 6 = 0.125 lies halfway between 0 and 0.25
+let (c, d) = (Fix::from_num(0.75), Fix::from_num(6));
+assert_eq!(c.unwrapped_div_round(d, RoundingMode::HalfEven), Fix::ZERO);
+assert_eq!(c.unwrapped_div_round(d, RoundingMode::HalfAwayFromZero), Fix::from_num(0.25));
 ```
 
 The following panics because of overflow.
 
-```rust,should_panic
+```should_panic
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
-use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<4>;
-let quarter = Fix::ONE / 4;
-let _overflow = Fix::MAX.unwrapped_div(quarter);
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+let _overflow = Fix::MAX.unwrapped_div_round(Fix::from_num(0.5), RoundingMode::Floor);
 ```
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_div(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    match self.overflowing_div(rhs) {
+                #[must_use]
+                pub const fn unwrapped_div_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> $Self<FRAC> {
+                    match self.overflowing_div_round(rhs, mode) {
                         (_, true) => panic!("overflow"),
                         (ans, false) => ans,
                     }
@@ -2517,6 +4603,68 @@ assert_eq!(Fix::from_num(0.25).unwrapped_recip(), Fix::from_num(4));
                 }
             }
 
+            comment! {
+                "Unwrapped reciprocal, rounded according to the given [`RoundingMode`].
+Returns the reciprocal, panicking on overflow.
+
+Unlike [`recip`][Self::recip], which always rounds toward zero, this method
+rounds according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+reciprocal, and the two bounds are equal if the exact reciprocal is
+representable.
+
+# Panics
+
+Panics if the fixed-point number is zero or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1/3 lies between 0.25 and 0.5
+let three = Fix::from_num(3);
+assert_eq!(three.unwrapped_recip_round(RoundingMode::Floor), Fix::from_num(0.25));
+assert_eq!(three.unwrapped_recip_round(RoundingMode::Ceil), Fix::from_num(0.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-three).unwrapped_recip_round(RoundingMode::Floor), Fix::from_num(-0.5));
+assert_eq!((-three).unwrapped_recip_round(RoundingMode::ToZero), Fix::from_num(-0.25));
+",
+                },
+                "// 1/8 lies halfway between 0 and 0.25
+let eight = Fix::from_num(8);
+assert_eq!(eight.unwrapped_recip_round(RoundingMode::HalfEven), Fix::ZERO);
+assert_eq!(eight.unwrapped_recip_round(RoundingMode::HalfAwayFromZero), Fix::from_num(0.25));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+let _overflow = Small::from_num(0.25).unwrapped_recip_round(RoundingMode::Floor);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_recip_round(self, mode: RoundingMode) -> $Self<FRAC> {
+                    match self.overflowing_recip_round(mode) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
             comment! {
                 "Unwrapped Euclidean division. Returns the quotient, panicking on overflow.
 
@@ -2669,18 +4817,265 @@ Note that while remainder for Euclidean division cannot be negative,
 the wrapped value can be negative.",
                     ".
 
-Can never overflow for unsigned values.",
-                ),
+Can never overflow for unsigned values.",
+                ),
+                "
+
+# Panics
+
+Panics if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or if the division results in overflow",
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<", $nm4, ">;
+assert_eq!(Fix::from_num(7.5).unwrapped_rem_euclid_int(2), Fix::from_num(1.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).unwrapped_rem_euclid_int(2), Fix::from_num(0.5));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<", $nm4, ">;
+// -8 ≤ Fix < 8, so the answer 12.5 overflows
+let _overflow = Fix::from_num(-7.5).unwrapped_rem_euclid_int(20);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_rem_euclid_int(self, rhs: $Inner) -> $Self<FRAC> {
+                    match self.overflowing_rem_euclid_int(rhs) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the square root",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", panicking for negative numbers and on overflow.",
+                    ". Can never overflow for unsigned numbers.",
+                ),
+                "
+
+This method uses an iterative method, with up to ", $n, " iterations for
+[`", stringify!($Self), "`]. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method panics for an input value ≥&nbsp;0.25.
+
+"
+                },
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative and on overflow.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).unwrapped_sqrt(), Fix::SQRT_2);
+```
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+The following panics because the input value is negative.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _sqrt_neg = Fix::from_num(-1).unwrapped_sqrt();
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::from_num(0.25).unwrapped_sqrt();
+```
+",
+                };
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sqrt(self) -> Self {
+                    match self.overflowing_sqrt() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped square root, rounded according to the given [`RoundingMode`].
+Returns the square root, panicking on overflow.
+
+Unlike [`sqrt`][Self::sqrt], which always rounds down, this method rounds
+according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+square root, and the two bounds are equal if the exact square root is
+representable. The exact square root is never halfway between two
+representable values, so the two nearest modes give the same result.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when there are no integer bits and the input value is
+≥&nbsp;0.25, or when there is one integer bit and the result is rounded up to
+one.
+
+",
+                    "Overflow can only occur when there are no integer bits and the result is
+rounded up to one.
+
+",
+                ),
+                "# Panics
+
+Panics if the number is negative or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// √2 lies between SQRT_2 and SQRT_2 + DELTA
+let two = Fix::from_num(2);
+assert_eq!(two.unwrapped_sqrt_round(RoundingMode::Floor), Fix::SQRT_2);
+assert_eq!(two.unwrapped_sqrt_round(RoundingMode::Ceil), Fix::SQRT_2 + Fix::DELTA);
+// exact square roots are not rounded
+assert_eq!(Fix::from_num(2.25).unwrapped_sqrt_round(RoundingMode::Ceil), Fix::from_num(1.5));
+```
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+The following panics because the input value is negative.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+let _sqrt_neg = Fix::from_num(-1).unwrapped_sqrt_round(RoundingMode::Floor);
+```
+",
+                },
+                "
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+",
+                    ),
+                    concat!(
+                        "
+// no integer bits
+type Small = ", stringify!($Self), "<", $n, ">;
+",
+                    ),
+                ),
+                "let _overflow = Small::MAX.unwrapped_sqrt_round(RoundingMode::Ceil);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sqrt_round(self, mode: RoundingMode) -> Self {
+                    match self.overflowing_sqrt_round(mode) {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the cube root, panicking on overflow.",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    " Can never overflow for unsigned numbers."
+                },
                 "
 
-# Panics
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;∛`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
 
-Panics if the divisor is zero",
+",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    " or if the division results in overflow",
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method panics for an input value ≥&nbsp;0.125 or
+&lt;&nbsp;&minus;0.125.
+
+"
                 },
-                ".
+                "# Panics
+
+Panics if the result does not fit.
 
 # Examples
 
@@ -2689,71 +5084,71 @@ Panics if the divisor is zero",
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<", $nm4, ">;
-assert_eq!(Fix::from_num(7.5).unwrapped_rem_euclid_int(2), Fix::from_num(1.5));
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.125).unwrapped_cbrt(), 0.5);
+```
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).unwrapped_rem_euclid_int(2), Fix::from_num(0.5));
-```
-
+                    "
 The following panics because of overflow.
 
-```rust,should_panic
+```should_panic
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<", $nm4, ">;
-// -8 ≤ Fix < 8, so the answer 12.5 overflows
-let _overflow = Fix::from_num(-7.5).unwrapped_rem_euclid_int(20);
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::from_num(0.125).unwrapped_cbrt();
+```
 ",
-                },
-                "```
-";
+                };
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_rem_euclid_int(self, rhs: $Inner) -> $Self<FRAC> {
-                    match self.overflowing_rem_euclid_int(rhs) {
-                        (_, true) => panic!("overflow"),
-                        (ans, false) => ans,
-                    }
+                #[must_use]
+                pub const fn unwrapped_cbrt(self) -> Self {
+                    self.unwrapped_nth_root(3)
                 }
             }
 
             comment! {
-                "Returns the square root",
-                if_signed_unsigned!(
-                    $Signedness,
-                    ", panicking for negative numbers and on overflow.",
-                    ". Can never overflow for unsigned numbers.",
-                ),
+                "Returns the <i>n</i>th root, panicking on overflow.",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    " Can never overflow for unsigned numbers."
+                },
                 "
 
-This method uses an iterative method, with up to ", $n, " iterations for
-[`", stringify!($Self), "`]. The result is rounded down, and the error is
-&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
-result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;`self`<sup>1/`n`</sup>&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
 
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "Overflow can only occur when there are no integer bits and
 the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
-In this case, the method panics for an input value ≥&nbsp;0.25.
+In this case, the method panics for an input value
+≥&nbsp;2<sup>&minus;`n`</sup>, or for odd `n`, for an input value
+&lt;&nbsp;&minus;2<sup>&minus;`n`</sup>.
 
 "
                 },
+                "# Panics
+
+Panics if `n` is zero",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "# Panics
-
-Panics if the number is negative and on overflow.
-
-"
+                    ", or if the number is negative and `n` is even"
                 },
-                "# Examples
+                ", or if the result does not fit.
+
+# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -2761,21 +5156,74 @@ Panics if the number is negative and on overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(2).unwrapped_sqrt(), Fix::SQRT_2);
+assert_eq!(Fix::from_num(0.0625).unwrapped_nth_root(4), 0.5);
+```
+
+The following panics because `n` is zero.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _zeroth = Fix::from_num(2).unwrapped_nth_root(0);
 ```
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "
-The following panics because the input value is negative.
+The following panics because of overflow.
 
 ```should_panic
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::from_num(0.25).unwrapped_nth_root(2);
+```
+",
+                };
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_nth_root(self, n: u32) -> Self {
+                    match self.overflowing_nth_root(n) {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the reciprocal square root, panicking on overflow.
+
+The result has `RET_FRAC` fractional bits, which can be different from the
+number of fractional bits of `self`. It is computed directly rather than by
+taking the reciprocal of the square root, and it is rounded down, so that the
+error is &lt;&nbsp;`DELTA` of the result type. That is,
+result&nbsp;≤&nbsp;1/√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+# Panics
+
+Panics if the number is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or negative"
+                },
+                ", or if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let _sqrt_neg = Fix::from_num(-1).unwrapped_sqrt();
+let r: Fix = Fix::from_num(0.25).unwrapped_rsqrt();
+assert_eq!(r, 2);
 ```
 
 The following panics because of overflow.
@@ -2785,16 +5233,17 @@ The following panics because of overflow.
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type AllFrac = ", stringify!($Self), "<", $n, ">;
-let _overflow = AllFrac::from_num(0.25).unwrapped_sqrt();
+type Fix = ", stringify!($Self), "<4>;
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+let _overflow: Small = Fix::from_num(0.25).unwrapped_rsqrt();
 ```
-",
-                };
+";
                 #[inline]
                 #[track_caller]
                 #[must_use]
-                pub const fn unwrapped_sqrt(self) -> Self {
-                    match self.overflowing_sqrt() {
+                pub const fn unwrapped_rsqrt<const RET_FRAC: i32>(self) -> $Self<RET_FRAC> {
+                    match self.overflowing_rsqrt() {
                         (val, false) => val,
                         (_, true) => panic!("overflow"),
                     }
@@ -2852,6 +5301,53 @@ let _overflow = Fix::from_num(1.5).unwrapped_lerp(Fix::ZERO, Fix::MAX);
                 }
             }
 
+            comment! {
+                "Unwrapped rounding to `dp` decimal places. Rounds according
+to the given [`RoundingMode`], panicking on overflow.
+
+See [`round_dp`] for how the value is rounded.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<6>;
+// 1.359375 rounded to one decimal place is 1.4, which is not representable
+let x = Fix::from_num(1.359375);
+assert_eq!(x.unwrapped_round_dp(1, RoundingMode::HalfEven), Fix::from_num(1.4));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<6>;
+let _overflow = Fix::MAX.unwrapped_round_dp(1, RoundingMode::Ceil);
+```
+
+[`round_dp`]: Self::round_dp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn unwrapped_round_dp(self, dp: u32, mode: RoundingMode) -> $Self<FRAC> {
+                    match self.overflowing_round_dp(dp, mode) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
             comment! {
                 "Overflowing division.
 
@@ -2887,6 +5383,141 @@ assert_eq!(Fix::MAX.overflowing_div(quarter), (wrapped, true));
                 }
             }
 
+            comment! {
+                "Overflowing multiplication, rounded according to the given [`RoundingMode`].
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+Unlike multiplication using the `*` operator, which rounds toward &minus;∞,
+this method rounds according to `mode`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.5625 = 0.84375 lies halfway between 0.8125 and 0.875
+let (a, b) = (Fix::from_num(1.5), Fix::from_num(0.5625));
+assert_eq!(a.overflowing_mul_round(b, RoundingMode::Floor), (Fix::from_num(0.8125), false));
+assert_eq!(a.overflowing_mul_round(b, RoundingMode::HalfEven), (Fix::from_num(0.875), false));
+assert_eq!(a.overflowing_mul_round(b, RoundingMode::HalfToZero), (Fix::from_num(0.8125), false));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).overflowing_mul_round(b, RoundingMode::Floor), (Fix::from_num(-0.875), false));
+assert_eq!((-a).overflowing_mul_round(b, RoundingMode::ToZero), (Fix::from_num(-0.8125), false));
+",
+                },
+                "let two = Fix::from_num(2);
+assert_eq!(
+    Fix::MAX.overflowing_mul_round(two, RoundingMode::Floor),
+    (Fix::MAX.wrapping_mul(two), true)
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_mul_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> ($Self<FRAC>, bool) {
+                    let (lhs_neg, lhs_abs) = if_signed_unsigned!(
+                        $Signedness,
+                        int_helper::$Inner::neg_abs(self.to_bits()),
+                        (false, self.to_bits()),
+                    );
+                    let (rhs_neg, rhs_abs) = if_signed_unsigned!(
+                        $Signedness,
+                        int_helper::$Inner::neg_abs(rhs.to_bits()),
+                        (false, rhs.to_bits()),
+                    );
+                    let neg = lhs_neg != rhs_neg;
+                    let (abs, overflow1, discarded) =
+                        arith::mul_u128(lhs_abs as u128, rhs_abs as u128, FRAC as u32);
+                    let (abs, overflow2) = mode.round_u128(neg, abs, discarded);
+                    let (bits, overflow3) = int_helper::$Inner::overflowing_from_neg_abs(neg, abs);
+                    ($Self::from_bits(bits), overflow1 | overflow2 | overflow3)
+                }
+            }
+
+            comment! {
+                "Overflowing division, rounded according to the given [`RoundingMode`].
+
+Returns a [tuple] of the quotient and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+Unlike division using the `/` operator, which rounds toward zero, this method
+rounds according to `mode`.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1.25 / 3 = 0.416… lies between 0.25 and 0.5
+let (a, b) = (Fix::from_num(1.25), Fix::from_num(3));
+assert_eq!(a.overflowing_div_round(b, RoundingMode::Floor), (Fix::from_num(0.25), false));
+assert_eq!(a.overflowing_div_round(b, RoundingMode::Ceil), (Fix::from_num(0.5), false));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).overflowing_div_round(b, RoundingMode::Floor), (Fix::from_num(-0.5), false));
+assert_eq!((-a).overflowing_div_round(b, RoundingMode::ToZero), (Fix::from_num(-0.25), false));
+",
+                },
+                "// 0.75 / 6 = 0.125 lies halfway between 0 and 0.25
+let (c, d) = (Fix::from_num(0.75), Fix::from_num(6));
+assert_eq!(c.overflowing_div_round(d, RoundingMode::HalfEven), (Fix::ZERO, false));
+assert_eq!(c.overflowing_div_round(d, RoundingMode::HalfAwayFromZero), (Fix::from_num(0.25), false));
+let half = Fix::from_num(0.5);
+assert_eq!(
+    Fix::MAX.overflowing_div_round(half, RoundingMode::Floor),
+    (Fix::MAX.wrapping_div(half), true)
+);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_div_round(
+                    self,
+                    rhs: $Self<FRAC>,
+                    mode: RoundingMode,
+                ) -> ($Self<FRAC>, bool) {
+                    let (lhs_neg, lhs_abs) = if_signed_unsigned!(
+                        $Signedness,
+                        int_helper::$Inner::neg_abs(self.to_bits()),
+                        (false, self.to_bits()),
+                    );
+                    let (rhs_neg, rhs_abs) = if_signed_unsigned!(
+                        $Signedness,
+                        int_helper::$Inner::neg_abs(rhs.to_bits()),
+                        (false, rhs.to_bits()),
+                    );
+                    let neg = lhs_neg != rhs_neg;
+                    let Some(rhs_abs) = NonZeroU128::new(rhs_abs as u128) else {
+                        panic!("division by zero");
+                    };
+                    let (abs, overflow1, discarded) =
+                        arith::div_u128(lhs_abs as u128, rhs_abs, FRAC as u32);
+                    let (abs, overflow2) = mode.round_u128(neg, abs, discarded);
+                    let (bits, overflow3) = int_helper::$Inner::overflowing_from_neg_abs(neg, abs);
+                    ($Self::from_bits(bits), overflow1 | overflow2 | overflow3)
+                }
+            }
+
             comment! {
                 "Overflowing reciprocal.
 
@@ -2947,6 +5578,73 @@ assert_eq!(Small::from_num(0.25).overflowing_recip(), (Small::ZERO, true));
                 }
             }
 
+            comment! {
+                "Overflowing reciprocal, rounded according to the given [`RoundingMode`].
+
+Returns a [tuple] of the reciprocal and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+
+Unlike [`recip`][Self::recip], which always rounds toward zero, this method
+rounds according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+reciprocal, and the two bounds are equal if the exact reciprocal is
+representable.
+
+# Panics
+
+Panics if the fixed-point number is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+// 1/3 lies between 0.25 and 0.5
+let three = Fix::from_num(3);
+assert_eq!(three.overflowing_recip_round(RoundingMode::Floor), (Fix::from_num(0.25), false));
+assert_eq!(three.overflowing_recip_round(RoundingMode::Ceil), (Fix::from_num(0.5), false));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-three).overflowing_recip_round(RoundingMode::Floor), (Fix::from_num(-0.5), false));
+assert_eq!((-three).overflowing_recip_round(RoundingMode::ToZero), (Fix::from_num(-0.25), false));
+",
+                },
+                "// 1/8 lies halfway between 0 and 0.25
+let eight = Fix::from_num(8);
+assert_eq!(eight.overflowing_recip_round(RoundingMode::HalfEven), (Fix::ZERO, false));
+assert_eq!(eight.overflowing_recip_round(RoundingMode::HalfAwayFromZero), (Fix::from_num(0.25), false));
+
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+assert_eq!(
+    Small::from_num(0.25).overflowing_recip_round(RoundingMode::Floor),
+    (Small::ZERO, true)
+);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_recip_round(self, mode: RoundingMode) -> ($Self<FRAC>, bool) {
+                    let (neg, abs) = if_signed_unsigned!(
+                        $Signedness,
+                        int_helper::$Inner::neg_abs(self.to_bits()),
+                        (false, self.to_bits()),
+                    );
+                    let Some(nz) = NonZeroU128::new(abs as u128) else {
+                        panic!("division by zero");
+                    };
+                    let (abs, overflow1, discarded) = arith::recip_u128(nz, FRAC as u32);
+                    let (abs, overflow2) = mode.round_u128(neg, abs, discarded);
+                    let (bits, overflow3) = int_helper::$Inner::overflowing_from_neg_abs(neg, abs);
+                    (Self::from_bits(bits), overflow1 | overflow2 | overflow3)
+                }
+            }
+
             comment! {
                 "Overflowing Euclidean division.
 
@@ -3223,6 +5921,334 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Overflowing square root, rounded according to the given [`RoundingMode`].
+
+Returns a [tuple] of the square root and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+Unlike [`sqrt`][Self::sqrt], which always rounds down, this method rounds
+according to `mode`. In particular, [`Floor`][RoundingMode::Floor] and
+[`Ceil`][RoundingMode::Ceil] give a lower and an upper bound for the exact
+square root, and the two bounds are equal if the exact square root is
+representable. The exact square root is never halfway between two
+representable values, so the two nearest modes give the same result.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when there are no integer bits and the input value is
+≥&nbsp;0.25, or when there is one integer bit and the result is rounded up to
+one.
+
+",
+                    "Overflow can only occur when there are no integer bits and the result is
+rounded up to one.
+
+",
+                ),
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+// √2 lies between SQRT_2 and SQRT_2 + DELTA
+let two = Fix::from_num(2);
+assert_eq!(two.overflowing_sqrt_round(RoundingMode::Floor), (Fix::SQRT_2, false));
+assert_eq!(two.overflowing_sqrt_round(RoundingMode::Ceil), (Fix::SQRT_2 + Fix::DELTA, false));
+// exact square roots are not rounded
+assert_eq!(Fix::from_num(2.25).overflowing_sqrt_round(RoundingMode::Ceil), (Fix::from_num(1.5), false));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+",
+                    ),
+                    concat!(
+                        "
+// no integer bits
+type Small = ", stringify!($Self), "<", $n, ">;
+",
+                    ),
+                ),
+                "assert_eq!(Small::MAX.overflowing_sqrt_round(RoundingMode::Floor), (Small::MAX, false));
+assert_eq!(Small::MAX.overflowing_sqrt_round(RoundingMode::Ceil), (Small::MIN, true));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_sqrt_round(self, mode: RoundingMode) -> (Self, bool) {
+                    if_signed_unsigned!(
+                        $Signedness,
+                        {
+                            if self.is_negative() {
+                                panic!("square root of negative number");
+                            }
+                            let u = $USelf::<FRAC>::from_bits(self.to_bits() as $UInner);
+                            let (u, overflow) = u.overflowing_sqrt_round(mode);
+                            let s = $Self::from_bits(u.to_bits() as $Inner);
+                            (s, overflow | s.is_negative())
+                        },
+                        {
+                            let Some(nz) = $NonZeroUInner::new(self.to_bits()) else {
+                                return (Self::ZERO, false);
+                            };
+                            let root = sqrt::$UInner(nz, FRAC as u32);
+                            let discarded =
+                                sqrt::discarded(self.to_bits() as u128, FRAC as u32, root as u128);
+                            if mode.increment(false, root % 2 != 0, discarded) {
+                                let (root, overflow) = root.overflowing_add(1);
+                                (Self::from_bits(root), overflow)
+                            } else {
+                                (Self::from_bits(root), false)
+                            }
+                        }
+                    )
+                }
+            }
+
+            comment! {
+                "Returns the cube root.
+
+Returns a [tuple] of the result and ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "a [`bool`] indicating whether an overflow has occurred. On
+overflow, the wrapped value is returned.",
+                    "[`false`], since this can never overflow for unsigned numbers.",
+                ),
+                "
+
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;∛`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, overflow occurs for an input value ≥&nbsp;0.125 or
+&lt;&nbsp;&minus;0.125.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(0.125).overflowing_cbrt(),
+    (Fix::from_num(0.5), false)
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(
+    AllFrac::from_num(0.125).overflowing_cbrt(),
+    (AllFrac::from_num(-0.5), true)
+);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_cbrt(self) -> (Self, bool) {
+                    self.overflowing_nth_root(3)
+                }
+            }
+
+            comment! {
+                "Returns the <i>n</i>th root.
+
+Returns a [tuple] of the result and ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "a [`bool`] indicating whether an overflow has occurred. On
+overflow, the wrapped value is returned.",
+                    "[`false`], since this can never overflow for unsigned numbers.",
+                ),
+                "
+
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;`self`<sup>1/`n`</sup>&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " For negative numbers, this means that the result is rounded
+away from zero."
+                },
+                "
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, overflow occurs for an input value
+≥&nbsp;2<sup>&minus;`n`</sup>, or for odd `n`, for an input value
+&lt;&nbsp;&minus;2<sup>&minus;`n`</sup>.
+
+"
+                },
+                "# Panics
+
+Panics if `n` is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", or if the number is negative and `n` is even"
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(0.0625).overflowing_nth_root(4),
+    (Fix::from_num(0.5), false)
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(
+    AllFrac::from_num(0.25).overflowing_nth_root(2),
+    (AllFrac::from_num(-0.5), true)
+);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_nth_root(self, n: u32) -> (Self, bool) {
+                    if n == 0 {
+                        panic!("zeroth root");
+                    }
+                    if_signed_unsigned!(
+                        $Signedness,
+                        {
+                            let neg = self.is_negative();
+                            if neg && n % 2 == 0 {
+                                panic!("even root of negative number");
+                            }
+                            let abs = self.to_bits().unsigned_abs() as u128;
+                            let Some(nz) = NonZeroU128::new(abs) else {
+                                return (Self::ZERO, false);
+                            };
+                            let (root, exact) = root::u128(nz, FRAC as u32, n);
+                            if neg {
+                                // round down, that is away from zero
+                                let abs_root = if exact { root } else { root + 1 };
+                                let max = $Inner::MIN.unsigned_abs() as u128;
+                                let bits = (abs_root as $Inner).wrapping_neg();
+                                (Self::from_bits(bits), abs_root > max)
+                            } else {
+                                (Self::from_bits(root as $Inner), root > $Inner::MAX as u128)
+                            }
+                        },
+                        {
+                            let Some(nz) = NonZeroU128::new(self.to_bits() as u128) else {
+                                return (Self::ZERO, false);
+                            };
+                            let (root, _) = root::u128(nz, FRAC as u32, n);
+                            (Self::from_bits(root as $Inner), false)
+                        }
+                    )
+                }
+            }
+
+            comment! {
+                "Returns the reciprocal square root.
+
+Returns a [tuple] of the result and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The result has `RET_FRAC` fractional bits, which can be different from the
+number of fractional bits of `self`. It is computed directly rather than by
+taking the reciprocal of the square root, and it is rounded down, so that the
+error is &lt;&nbsp;`DELTA` of the result type. That is,
+result&nbsp;≤&nbsp;1/√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+# Panics
+
+Panics if the number is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or negative"
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// only one integer bit
+type Small = ", stringify!($Self), "<", $nm1, ">;
+let r: (Fix, bool) = Fix::from_num(0.25).overflowing_rsqrt();
+assert_eq!(r, (Fix::from_num(2), false));
+let r: (Small, bool) = Fix::from_num(0.25).overflowing_rsqrt();
+assert_eq!(r, (Small::ZERO, true));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_rsqrt<const RET_FRAC: i32>(self) -> ($Self<RET_FRAC>, bool) {
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() {
+                            panic!("square root of negative number");
+                        }
+                    }
+                    let Some(nz) = NonZeroU128::new(self.to_bits() as u128) else {
+                        panic!("division by zero");
+                    };
+                    let (ans, overflow) = sqrt::rsqrt_u128(nz, FRAC as u32, RET_FRAC);
+                    (
+                        $Self::from_bits(ans as $Inner),
+                        overflow || ans > $Inner::MAX as u128,
+                    )
+                }
+            }
+
             comment! {
                 "Overflowing linear interpolation between `start` and `end`.
 
@@ -3266,6 +6292,57 @@ assert_eq!(
                     }
                 }
             }
+
+            comment! {
+                "Overflowing rounding to `dp` decimal places. Rounds according
+to the given [`RoundingMode`].
+
+Returns a [tuple] of the rounded value and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`round_dp`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<6>;
+// 1.359375 rounded to one decimal place is 1.4, which is not representable
+let x = Fix::from_num(1.359375);
+assert_eq!(
+    x.overflowing_round_dp(1, RoundingMode::HalfEven),
+    (Fix::from_num(1.4), false)
+);
+assert_eq!(
+    Fix::MAX.overflowing_round_dp(1, RoundingMode::Ceil),
+    (",
+                if_signed_unsigned!($Signedness, "Fix::MIN", "Fix::ZERO"),
+                ", true)
+);
+```
+
+[`round_dp`]: Self::round_dp
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_round_dp(self, dp: u32, mode: RoundingMode) -> ($Self<FRAC>, bool) {
+                    // Every multiple of 2^-FRAC is also a multiple of 10^-dp.
+                    if dp >= FRAC as u32 {
+                        return (self, false);
+                    }
+                    // The decimal string is the exact rounded value, which is
+                    // then parsed to the nearest representable value.
+                    self.with_dp_str(dp, mode, |s| {
+                        match $Self::overflowing_from_str_round(s, RoundingMode::HalfEven) {
+                            Ok(ans) => ans,
+                            Err(_) => unreachable!(),
+                        }
+                    })
+                }
+            }
         }
     };
 }
@@ -836,6 +836,627 @@ assert_eq!(one_point_625.overflowing_to_num::<f32>(), (1.625f32, false));
             }
         }
 
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+according to the given [`RoundingMode`].
+
+This is like [`from_num`], but any extra fractional bits of
+fixed-point numbers and of floating-point numbers are rounded
+according to `mode` instead of using the default rounding of each
+type. Integers only need rounding if `FRAC` is negative.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`overflowing_from_num_round`]
+instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, "#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+
+// 1.625 is 1.101 in binary, which lies between 1.5 and 1.75
+let src = I16F16::from_num(1.625);
+assert_eq!(Fix::from_num_round(src, RoundingMode::Floor), Fix::from_num(1.5));
+assert_eq!(Fix::from_num_round(src, RoundingMode::Ceil), Fix::from_num(1.75));
+assert_eq!(Fix::from_num_round(src, RoundingMode::HalfEven), Fix::from_num(1.5));
+assert_eq!(Fix::from_num_round(src, RoundingMode::HalfAwayFromZero), Fix::from_num(1.75));
+
+assert_eq!(Fix::from_num_round(1.1f32, RoundingMode::Ceil), Fix::from_num(1.25));
+assert_eq!(Fix::from_num_round(",
+            if_signed_unsigned!(
+                $Signedness,
+                "-1.1f64, RoundingMode::ToZero), Fix::from_num(-1",
+                "1.1f64, RoundingMode::ToZero), Fix::from_num(1",
+            ),
+            "));
+```
+
+[`from_num`]: Self::from_num
+[`overflowing_from_num_round`]: Self::overflowing_from_num_round
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> $Self<FRAC> {
+                src.to_fixed_round(mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+according to the given [`RoundingMode`].
+
+This is like [`to_num`], but any fractional bits that do not fit in
+the destination are rounded according to `mode` instead of using the
+default rounding of each type.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`overflowing_to_num_round`]
+instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I30F2, "#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_num(2.5);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::Floor), 2);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::Ceil), 3);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::HalfEven), 2);
+assert_eq!(",
+            if_signed_unsigned!(
+                $Signedness,
+                "(-two_point_5).to_num_round::<i64>(RoundingMode::HalfAwayFromZero), -3",
+                "two_point_5.to_num_round::<i64>(RoundingMode::HalfAwayFromZero), 3",
+            ),
+            ");
+
+// 1.3125 is 1.0101 in binary, which lies between 1.25 and 1.5
+let src = Fix::from_num(1.3125);
+assert_eq!(src.to_num_round::<I30F2>(RoundingMode::ToZero), I30F2::from_num(1.25));
+assert_eq!(src.to_num_round::<I30F2>(RoundingMode::AwayFromZero), I30F2::from_num(1.5));
+```
+
+[`overflowing_to_num_round`]: Self::overflowing_to_num_round
+[`to_num`]: Self::to_num
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number if it
+fits, rounding according to the given [`RoundingMode`], otherwise
+returns [`None`].
+
+See [`from_num_round`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+assert_eq!(
+    Fix::checked_from_num_round(1.1f32, RoundingMode::Ceil),
+    Some(Fix::from_num(1.25))
+);
+let too_large = ", stringify!($Inner), "::MAX;
+assert!(Fix::checked_from_num_round(too_large, RoundingMode::Floor).is_none());
+assert!(Fix::checked_from_num_round(std::f64::NAN, RoundingMode::Floor).is_none());
+```
+
+[`from_num_round`]: Self::from_num_round
+";
+            #[inline]
+            pub fn checked_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> Option<$Self<FRAC>> {
+                src.checked_to_fixed_round(mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number if it
+fits, rounding according to the given [`RoundingMode`], otherwise
+returns [`None`].
+
+See [`to_num_round`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).checked_to_num_round::<u8>(RoundingMode::Ceil), Some(3));
+",
+            if_signed_unsigned!(
+                $Signedness,
+                concat!(
+                    "// -0.5 rounded down is -1, which does not fit in u", $n, "
+let x = ", stringify!($Self), "::<1>::from_num(-0.5);
+assert!(x.checked_to_num_round::<u", $n, ">(RoundingMode::Floor).is_none());
+assert_eq!(x.checked_to_num_round::<u", $n, ">(RoundingMode::Ceil), Some(0));
+",
+                ),
+                concat!(
+                    "// the maximum rounded up does not fit in i", $n, "
+let max = ", stringify!($Self), "::<1>::MAX;
+assert!(max.checked_to_num_round::<i", $n, ">(RoundingMode::Ceil).is_none());
+assert_eq!(
+    max.checked_to_num_round::<i", $n, ">(RoundingMode::Floor),
+    Some(i", $n, "::MAX)
+);
+",
+                ),
+            ),
+            "```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            #[must_use]
+            pub fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst> {
+                Dst::checked_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+according to the given [`RoundingMode`] and saturating if the value does not fit.
+
+See [`to_num_round`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).saturating_to_num_round::<u8>(RoundingMode::Ceil), 3);
+",
+            if_signed_unsigned!(
+                $Signedness,
+                concat!(
+                    "// -0.5 rounded down is -1, which saturates to zero in u", $n, "
+let x = ", stringify!($Self), "::<1>::from_num(-0.5);
+assert_eq!(x.saturating_to_num_round::<u", $n, ">(RoundingMode::Floor), 0);
+",
+                ),
+                concat!(
+                    "// the maximum rounded up saturates to i", $n, "::MAX
+let max = ", stringify!($Self), "::<1>::MAX;
+assert_eq!(
+    max.saturating_to_num_round::<i", $n, ">(RoundingMode::Ceil),
+    i", $n, "::MAX
+);
+",
+                ),
+            ),
+            "```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            #[must_use]
+            pub fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::saturating_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+according to the given [`RoundingMode`] and wrapping if the value does not fit.
+
+See [`to_num_round`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).wrapping_to_num_round::<u8>(RoundingMode::Ceil), 3);
+",
+            if_signed_unsigned!(
+                $Signedness,
+                concat!(
+                    "// -0.5 rounded down is -1, which wraps to u", $n, "::MAX
+let x = ", stringify!($Self), "::<1>::from_num(-0.5);
+assert_eq!(
+    x.wrapping_to_num_round::<u", $n, ">(RoundingMode::Floor),
+    u", $n, "::MAX
+);
+",
+                ),
+                concat!(
+                    "// the maximum rounded up wraps to i", $n, "::MIN
+let max = ", stringify!($Self), "::<1>::MAX;
+assert_eq!(
+    max.wrapping_to_num_round::<i", $n, ">(RoundingMode::Ceil),
+    i", $n, "::MIN
+);
+",
+                ),
+            ),
+            "```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            #[must_use]
+            pub fn wrapping_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::wrapping_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+according to the given [`RoundingMode`] and panicking if the value does not fit.
+
+See [`to_num_round`] for how the value is rounded.
+
+# Panics
+
+Panics if the value does not fit, even when debug assertions are not
+enabled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).unwrapped_to_num_round::<u8>(RoundingMode::Ceil), 3);
+",
+            if_signed_unsigned!(
+                $Signedness,
+                concat!(
+                    "// -0.5 rounded up is 0, which fits in u", $n, "
+let x = ", stringify!($Self), "::<1>::from_num(-0.5);
+assert_eq!(x.unwrapped_to_num_round::<u", $n, ">(RoundingMode::Ceil), 0);
+",
+                ),
+                concat!(
+                    "// the maximum rounded down fits in i", $n, "
+let max = ", stringify!($Self), "::<1>::MAX;
+assert_eq!(
+    max.unwrapped_to_num_round::<i", $n, ">(RoundingMode::Floor),
+    i", $n, "::MAX
+);
+",
+                ),
+            ),
+            "```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+",
+            if_signed_unsigned!(
+                $Signedness,
+                concat!(
+                    "let x = ", stringify!($Self), "::<1>::from_num(-0.5);
+let _overflow = x.unwrapped_to_num_round::<u", $n, ">(RoundingMode::Floor);
+",
+                ),
+                concat!(
+                    "let max = ", stringify!($Self), "::<1>::MAX;
+let _overflow = max.unwrapped_to_num_round::<i", $n, ">(RoundingMode::Ceil);
+",
+                ),
+            ),
+            "```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn unwrapped_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::unwrapped_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+according to the given [`RoundingMode`].
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`from_num_round`] for how the value is rounded.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<2>;
+assert_eq!(
+    Fix::overflowing_from_num_round(1.1f32, RoundingMode::Ceil),
+    (Fix::from_num(1.25), false)
+);
+let large = ", stringify!($Inner), "::MAX;
+let wrapped = Fix::from_bits(large << 2);
+assert_eq!(
+    Fix::overflowing_from_num_round(large, RoundingMode::Floor),
+    (wrapped, true)
+);
+```
+
+[`from_num_round`]: Self::from_num_round
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn overflowing_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> ($Self<FRAC>, bool) {
+                src.overflowing_to_fixed_round(mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+according to the given [`RoundingMode`].
+
+Returns a [tuple] of the number and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`to_num_round`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(2.5).overflowing_to_num_round::<u8>(RoundingMode::Ceil),
+    (3, false)
+);
+",
+            if_signed_unsigned!(
+                $Signedness,
+                concat!(
+                    "// -0.5 rounded down is -1, which wraps to u", $n, "::MAX
+let x = ", stringify!($Self), "::<1>::from_num(-0.5);
+assert_eq!(
+    x.overflowing_to_num_round::<u", $n, ">(RoundingMode::Floor),
+    (u", $n, "::MAX, true)
+);
+",
+                ),
+                concat!(
+                    "// the maximum rounded up wraps to i", $n, "::MIN
+let max = ", stringify!($Self), "::<1>::MAX;
+assert_eq!(
+    max.overflowing_to_num_round::<i", $n, ">(RoundingMode::Ceil),
+    (i", $n, "::MIN, true)
+);
+",
+                ),
+            ),
+            "```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            #[must_use]
+            pub fn overflowing_to_num_round<Dst: FromFixed>(
+                self,
+                mode: RoundingMode,
+            ) -> (Dst, bool) {
+                Dst::overflowing_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+stochastically.
+
+Any extra fractional bits are rounded up with a probability equal to
+the fraction of the least significant bit that is discarded, and
+rounded down otherwise, so that on average the rounded number is equal
+to the source number. The random bits are taken from `rng`, see
+[`RandomBits`]. Values that are exactly representable do not consume
+any random bits.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use
+[`overflowing_from_num_stochastic`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, types::I16F16, "#, stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<2>;
+
+// 1.625 is 1.101 in binary, which lies between 1.5 and 1.75
+let src = I16F16::from_num(1.625);
+assert_eq!(Fix::from_num_stochastic(src, &mut Same(0)), Fix::from_num(1.75));
+assert_eq!(Fix::from_num_stochastic(src, &mut Same(!0)), Fix::from_num(1.5));
+assert_eq!(Fix::from_num_stochastic(1.1f32, &mut Same(0)), Fix::from_num(1.25));
+```
+
+[`RandomBits`]: crate::traits::RandomBits
+[`overflowing_from_num_stochastic`]: Self::overflowing_from_num_stochastic
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+                src: Src,
+                rng: &mut R,
+            ) -> $Self<FRAC> {
+                src.to_fixed_stochastic(rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number if it
+fits, rounding stochastically, otherwise returns [`None`].
+
+See [`from_num_stochastic`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, "#, stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<2>;
+assert_eq!(
+    Fix::checked_from_num_stochastic(1.1f32, &mut Same(0)),
+    Some(Fix::from_num(1.25))
+);
+let too_large = ", stringify!($Inner), "::MAX;
+assert!(Fix::checked_from_num_stochastic(too_large, &mut Same(0)).is_none());
+assert!(Fix::checked_from_num_stochastic(std::f64::NAN, &mut Same(0)).is_none());
+```
+
+[`from_num_stochastic`]: Self::from_num_stochastic
+";
+            #[inline]
+            pub fn checked_from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+                src: Src,
+                rng: &mut R,
+            ) -> Option<$Self<FRAC>> {
+                src.checked_to_fixed_stochastic(rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number,
+rounding stochastically.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`from_num_stochastic`] for how the value is rounded.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, "#, stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<2>;
+assert_eq!(
+    Fix::overflowing_from_num_stochastic(1.1f32, &mut Same(0)),
+    (Fix::from_num(1.25), false)
+);
+let large = ", stringify!($Inner), "::MAX;
+let wrapped = Fix::from_bits(large << 2);
+assert_eq!(
+    Fix::overflowing_from_num_stochastic(large, &mut Same(0)),
+    (wrapped, true)
+);
+```
+
+[`from_num_stochastic`]: Self::from_num_stochastic
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn overflowing_from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+                src: Src,
+                rng: &mut R,
+            ) -> ($Self<FRAC>, bool) {
+                src.overflowing_to_fixed_stochastic(rng)
+            }
+        }
+
         /// Creates a fixed-point number from a fixed-point number with the same
         /// underlying integer type. Usable in constant context.
         ///
@@ -955,5 +1576,631 @@ assert_eq!(one_point_625.overflowing_to_num::<f32>(), (1.625f32, false));
         pub const fn const_from_int(src: $Inner) -> $Self<FRAC> {
             Self::const_from_fixed($Self::<0>::from_bits(src))
         }
+
+        comment! {
+            r#"Converts to a fixed-point number with the same underlying
+integer type and `DST_FRAC` fractional bits, rounding according to the
+given [`RoundingMode`]. Usable in constant context.
+
+This is like [`to_num_round`] with
+<code>[", stringify!($Self), "]&lt;DST_FRAC></code> as its generic
+parameter, but can also be used in constant context. To convert to a
+fixed-point number with a different underlying integer type, use
+[`to_num_round`] or [`saturating_to_num_round`].
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_requantize`]
+instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Self), "<2>;
+
+// 1.375 is 1.011 in binary, which lies halfway between 1.25 and 1.5
+const X: Src = Src::lit(\"1.375\");
+const FLOOR: Dst = X.requantize(RoundingMode::Floor);
+assert_eq!(FLOOR, 1.25);
+assert_eq!(X.requantize::<2>(RoundingMode::HalfEven), 1.5);
+assert_eq!(X.requantize::<2>(RoundingMode::HalfToZero), 1.25);
+// increasing the number of fractional bits is exact
+assert_eq!(Dst::from_num(1.5).requantize::<4>(RoundingMode::Floor), 1.5);
+```
+
+[`saturating_to_num_round`]: Self::saturating_to_num_round
+[`to_num_round`]: Self::to_num_round
+[`wrapping_requantize`]: Self::wrapping_requantize
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn requantize<const DST_FRAC: i32>(
+                self,
+                mode: RoundingMode,
+            ) -> $Self<DST_FRAC> {
+                let (ans, overflow) = self.overflowing_requantize(mode);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        comment! {
+            r#"Converts to a fixed-point number with the same underlying
+integer type and `DST_FRAC` fractional bits if it fits, rounding
+according to the given [`RoundingMode`], otherwise returns [`None`].
+Usable in constant context.
+
+See [`requantize`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Self), "<2>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(X.checked_requantize::<2>(RoundingMode::Ceil), Some(Dst::lit(\"1.5\")));
+assert_eq!(Dst::MAX.checked_requantize::<4>(RoundingMode::Floor), None);
+```
+
+[`requantize`]: Self::requantize
+";
+            #[inline]
+            #[must_use]
+            pub const fn checked_requantize<const DST_FRAC: i32>(
+                self,
+                mode: RoundingMode,
+            ) -> Option<$Self<DST_FRAC>> {
+                match self.overflowing_requantize(mode) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        comment! {
+            r#"Converts to a fixed-point number with the same underlying
+integer type and `DST_FRAC` fractional bits, rounding according to the
+given [`RoundingMode`] and saturating if the value does not fit. Usable
+in constant context.
+
+See [`requantize`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Self), "<2>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(X.saturating_requantize::<2>(RoundingMode::Ceil), Dst::lit(\"1.5\"));
+assert_eq!(Dst::MAX.saturating_requantize::<4>(RoundingMode::Floor), Src::MAX);
+",
+            if_signed_unsigned!(
+                $Signedness,
+                "assert_eq!(Dst::MIN.saturating_requantize::<4>(RoundingMode::Floor), Src::MIN);
+",
+                "",
+            ),
+            "```
+
+[`requantize`]: Self::requantize
+";
+            #[inline]
+            #[must_use]
+            pub const fn saturating_requantize<const DST_FRAC: i32>(
+                self,
+                mode: RoundingMode,
+            ) -> $Self<DST_FRAC> {
+                match self.overflowing_requantize(mode) {
+                    (ans, false) => ans,
+                    (_, true) => {
+                        if if_signed_unsigned!($Signedness, self.is_negative(), false) {
+                            $Self::MIN
+                        } else {
+                            $Self::MAX
+                        }
+                    }
+                }
+            }
+        }
+
+        comment! {
+            r#"Converts to a fixed-point number with the same underlying
+integer type and `DST_FRAC` fractional bits, rounding according to the
+given [`RoundingMode`] and wrapping if the value does not fit. Usable
+in constant context.
+
+See [`requantize`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Self), "<2>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(X.wrapping_requantize::<2>(RoundingMode::Ceil), Dst::lit(\"1.5\"));
+let wrapped = Src::from_bits(Dst::MAX.to_bits() << 2);
+assert_eq!(Dst::MAX.wrapping_requantize::<4>(RoundingMode::Floor), wrapped);
+```
+
+[`requantize`]: Self::requantize
+";
+            #[inline]
+            #[must_use]
+            pub const fn wrapping_requantize<const DST_FRAC: i32>(
+                self,
+                mode: RoundingMode,
+            ) -> $Self<DST_FRAC> {
+                self.overflowing_requantize(mode).0
+            }
+        }
+
+        comment! {
+            r#"Converts to a fixed-point number with the same underlying
+integer type and `DST_FRAC` fractional bits, rounding according to the
+given [`RoundingMode`] and panicking if the value does not fit. Usable
+in constant context.
+
+See [`requantize`] for how the value is rounded.
+
+# Panics
+
+Panics if the value does not fit, even when debug assertions are not
+enabled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Self), "<2>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(X.unwrapped_requantize::<2>(RoundingMode::Ceil), Dst::lit(\"1.5\"));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Dst = ", stringify!($Self), "<2>;
+let _overflow = Dst::MAX.unwrapped_requantize::<4>(RoundingMode::Floor);
+```
+
+[`requantize`]: Self::requantize
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn unwrapped_requantize<const DST_FRAC: i32>(
+                self,
+                mode: RoundingMode,
+            ) -> $Self<DST_FRAC> {
+                match self.overflowing_requantize(mode) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+
+        comment! {
+            r#"Converts to a fixed-point number with the same underlying
+integer type and `DST_FRAC` fractional bits, rounding according to the
+given [`RoundingMode`]. Usable in constant context.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`requantize`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Self), "<2>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(
+    X.overflowing_requantize::<2>(RoundingMode::Ceil),
+    (Dst::lit(\"1.5\"), false)
+);
+let wrapped = Src::from_bits(Dst::MAX.to_bits() << 2);
+assert_eq!(
+    Dst::MAX.overflowing_requantize::<4>(RoundingMode::Floor),
+    (wrapped, true)
+);
+```
+
+[`requantize`]: Self::requantize
+";
+            #[inline]
+            #[must_use]
+            pub const fn overflowing_requantize<const DST_FRAC: i32>(
+                self,
+                mode: RoundingMode,
+            ) -> ($Self<DST_FRAC>, bool) {
+                let nbits = $n;
+                let bits = self.to_bits();
+                let neg = if_signed_unsigned!($Signedness, bits < 0, false);
+                let shift = FRAC as i64 - DST_FRAC as i64;
+                if shift <= 0 {
+                    let shift = -shift;
+                    if shift >= nbits {
+                        return ($Self::ZERO, bits != 0);
+                    }
+                    let shifted = bits << shift;
+                    return ($Self::from_bits(shifted), (shifted >> shift) != bits);
+                }
+                // floor is the value rounded toward -∞ and rem holds the
+                // discarded bits of the two's complement representation,
+                // aligned so that its most significant bit is worth half.
+                let (floor, rem) = if shift < nbits {
+                    (bits >> shift, bits << (nbits - shift))
+                } else {
+                    (bits >> (nbits - 1) >> 1, bits)
+                };
+                let half: $Inner = 1 << (nbits - 1);
+                let discarded = if rem == 0 {
+                    rounding::Discarded::Zero
+                } else if shift > nbits {
+                    // rem is much smaller than half for positive numbers,
+                    // while for negative numbers there are extra discarded
+                    // sign bits
+                    if neg {
+                        rounding::Discarded::GreaterThanHalf
+                    } else {
+                        rounding::Discarded::LessThanHalf
+                    }
+                } else if rem == half {
+                    rounding::Discarded::Half
+                } else if rem & half != 0 {
+                    rounding::Discarded::GreaterThanHalf
+                } else {
+                    rounding::Discarded::LessThanHalf
+                };
+                let ans = if mode.increment_floor(neg, floor & 1 != 0, discarded) {
+                    // floor is smaller than MAX as at least one bit was discarded
+                    floor + 1
+                } else {
+                    floor
+                };
+                ($Self::from_bits(ans), false)
+            }
+        }
     };
 }
+
+// Conversions to fixed-point numbers with the underlying integer type $DstInner
+// from fixed-point numbers with a different width but the same signedness.
+macro_rules! fixed_requantize_to {
+    (
+        {Dst, DstInner} = {$Dst:ident, $DstInner:ident},
+        Signedness = $Signedness:ident,
+        dst_n = $dst_n:literal,
+        [
+            $requantize:ident, $checked:ident, $saturating:ident,
+            $wrapping:ident, $unwrapped:ident, $overflowing:ident $(,)?
+        ],
+        from = [$($Self:ident($Inner:ident, $n:literal)),* $(,)?],
+    ) => { $(
+        impl<const FRAC: i32> $Self<FRAC> {
+            comment! {
+                r#"Converts to a fixed-point number with the underlying
+integer type [`"#, stringify!($DstInner), "`] and `DST_FRAC` fractional
+bits, rounding according to the given [`RoundingMode`]. Usable in
+constant context.
+
+This is like [`requantize`] but changes the width of the number. The
+value is rounded only once at the discarded fractional bits, and then
+checked against the range of the destination. If the value does not fit
+in [`", stringify!($Dst), "`], it is treated the same way as by
+[`requantize`].
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`", stringify!($wrapping), "`]
+instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Dst), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Dst), "<2>;
+
+// 1.375 is 1.011 in binary, which lies halfway between 1.25 and 1.5
+const X: Src = Src::lit(\"1.375\");
+const FLOOR: Dst = X.", stringify!($requantize), "(RoundingMode::Floor);
+assert_eq!(FLOOR, 1.25);
+assert_eq!(X.", stringify!($requantize), "::<2>(RoundingMode::HalfEven), 1.5);
+assert_eq!(X.", stringify!($requantize), "::<2>(RoundingMode::HalfToZero), 1.25);
+```
+
+[`", stringify!($wrapping), "`]: Self::", stringify!($wrapping), "
+[`requantize`]: Self::requantize
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn $requantize<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> $Dst<DST_FRAC> {
+                    let (ans, overflow) = self.$overflowing(mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                r#"Converts to a fixed-point number with the underlying
+integer type [`"#, stringify!($DstInner), "`] and `DST_FRAC` fractional
+bits if it fits, rounding according to the given [`RoundingMode`],
+otherwise returns [`None`]. Usable in constant context.
+
+See [`", stringify!($requantize), "`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Dst), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Dst), "<{ ", $dst_n, " - 2 }>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(X.", stringify!($checked), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Ceil), Some(Dst::lit(\"1.375\")));
+assert_eq!(Src::MAX.", stringify!($checked), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Floor), None);
+```
+
+[`", stringify!($requantize), "`]: Self::", stringify!($requantize), "
+";
+                #[inline]
+                #[must_use]
+                pub const fn $checked<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> Option<$Dst<DST_FRAC>> {
+                    match self.$overflowing(mode) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                r#"Converts to a fixed-point number with the underlying
+integer type [`"#, stringify!($DstInner), "`] and `DST_FRAC` fractional
+bits, rounding according to the given [`RoundingMode`] and saturating if
+the value does not fit. Usable in constant context.
+
+See [`", stringify!($requantize), "`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Dst), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Dst), "<{ ", $dst_n, " - 2 }>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(X.", stringify!($saturating), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Ceil), Dst::lit(\"1.375\"));
+assert_eq!(Src::MAX.", stringify!($saturating), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Floor), Dst::MAX);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "assert_eq!(Src::MIN.", stringify!($saturating), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Floor), Dst::MIN);
+",
+                    ),
+                    "",
+                ),
+                "```
+
+[`", stringify!($requantize), "`]: Self::", stringify!($requantize), "
+";
+                #[inline]
+                #[must_use]
+                pub const fn $saturating<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> $Dst<DST_FRAC> {
+                    match self.$overflowing(mode) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if if_signed_unsigned!($Signedness, self.is_negative(), false) {
+                                $Dst::MIN
+                            } else {
+                                $Dst::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                r#"Converts to a fixed-point number with the underlying
+integer type [`"#, stringify!($DstInner), "`] and `DST_FRAC` fractional
+bits, rounding according to the given [`RoundingMode`] and wrapping if
+the value does not fit. Usable in constant context.
+
+See [`", stringify!($requantize), "`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Dst), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Dst), "<{ ", $dst_n, " - 2 }>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(X.", stringify!($wrapping), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Ceil), Dst::lit(\"1.375\"));
+// 5.5 wraps to 1.5 as Dst has only two integer bits
+let y = Src::from_num(5.5);
+assert_eq!(y.", stringify!($wrapping), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Floor), 1.5);
+```
+
+[`", stringify!($requantize), "`]: Self::", stringify!($requantize), "
+";
+                #[inline]
+                #[must_use]
+                pub const fn $wrapping<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> $Dst<DST_FRAC> {
+                    self.$overflowing(mode).0
+                }
+            }
+
+            comment! {
+                r#"Converts to a fixed-point number with the underlying
+integer type [`"#, stringify!($DstInner), "`] and `DST_FRAC` fractional
+bits, rounding according to the given [`RoundingMode`] and panicking if
+the value does not fit. Usable in constant context.
+
+See [`", stringify!($requantize), "`] for how the value is rounded.
+
+# Panics
+
+Panics if the value does not fit, even when debug assertions are not
+enabled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Dst), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Dst), "<{ ", $dst_n, " - 2 }>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(X.", stringify!($unwrapped), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Ceil), Dst::lit(\"1.375\"));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+let _overflow = Src::MAX.", stringify!($unwrapped), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Floor);
+```
+
+[`", stringify!($requantize), "`]: Self::", stringify!($requantize), "
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn $unwrapped<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> $Dst<DST_FRAC> {
+                    match self.$overflowing(mode) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                r#"Converts to a fixed-point number with the underlying
+integer type [`"#, stringify!($DstInner), "`] and `DST_FRAC` fractional
+bits, rounding according to the given [`RoundingMode`]. Usable in
+constant context.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`", stringify!($requantize), "`] for how the value is rounded.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Dst), ", RoundingMode};
+type Src = ", stringify!($Self), "<4>;
+type Dst = ", stringify!($Dst), "<{ ", $dst_n, " - 2 }>;
+const X: Src = Src::lit(\"1.375\");
+assert_eq!(
+    X.", stringify!($overflowing), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Ceil),
+    (Dst::lit(\"1.375\"), false)
+);
+let y = Src::from_num(5.5);
+assert_eq!(
+    y.", stringify!($overflowing), "::<{ ", $dst_n, " - 2 }>(RoundingMode::Floor),
+    (Dst::lit(\"1.5\"), true)
+);
+```
+
+[`", stringify!($requantize), "`]: Self::", stringify!($requantize), "
+";
+                #[inline]
+                #[must_use]
+                pub const fn $overflowing<const DST_FRAC: i32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> ($Dst<DST_FRAC>, bool) {
+                    if $n < $dst_n {
+                        // widening is exact, so requantize in the wider type
+                        let wide = $Dst::<FRAC>::from_bits(self.to_bits() as $DstInner);
+                        wide.overflowing_requantize(mode)
+                    } else {
+                        // requantizing in the wider type wraps modulo a
+                        // multiple of the narrower modulus, so narrowing
+                        // the wrapped value gives the correct wrapped value
+                        let (ans, overflow) = self.overflowing_requantize::<DST_FRAC>(mode);
+                        let bits = ans.to_bits() as $DstInner;
+                        let narrow_overflow = bits as $Inner != ans.to_bits();
+                        ($Dst::from_bits(bits), overflow || narrow_overflow)
+                    }
+                }
+            }
+        }
+    )* };
+}
@@ -0,0 +1,5697 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+macro_rules! fixed_math {
+    (
+        {Self, Inner} = {$Self:ident, $Inner:ident},
+        Signedness = $Signedness:ident,
+        {nm1, n} = {$nm1:literal, $n:literal},
+        {ISelf, IInner} = {$ISelf:ident, $IInner:ident},
+        {USelf, UInner} = {$USelf:ident, $UInner:ident},
+    ) => {
+        /// The items in this block are implemented for
+        #[doc = concat!("0&nbsp;≤&nbsp;`FRAC`&nbsp;≤&nbsp;", $n, ".")]
+        ///
+        /// All the functions are computed using only integer arithmetic, so
+        /// the results are deterministic and do not depend on the platform.
+        /// Intermediate values are computed with more than 120 bits of
+        /// precision and the result is rounded to the nearest, so the error is
+        /// less than [`DELTA`][Self::DELTA] for numbers with up to 120
+        /// fractional bits, and less than 2<sup>&minus;120</sup> otherwise. The
+        /// exceptions are the table-driven [`sin_lut`][Self::sin_lut] and
+        /// [`cos_lut`][Self::cos_lut], which trade accuracy for speed.
+        impl<const FRAC: i32> $Self<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= $n) }>: True,
+        {
+            comment! {
+                "Sine, with `self` in radians.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Overflow occurs when the sine is negative.
+
+"
+                },
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since |sin&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|, this method
+never overflows.
+
+",
+                    "# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_sin`] instead.
+
+",
+                ),
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.sin(), 0);
+assert_eq!(Fix::FRAC_PI_2.sin(), 1);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-Fix::FRAC_PI_2).sin(), -1);
+",
+                },
+                "```
+
+[`wrapping_sin`]: Self::wrapping_sin
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn sin(self) -> Self {
+                    let (val, overflow) = self.overflowing_sin();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked sine, with `self` in radians. Returns [`None`] on
+overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since |sin&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|, this method
+never returns [`None`].",
+                    "Overflow occurs when the sine is negative.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.checked_sin(), Some(Fix::ONE));
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(4).checked_sin(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sin(self) -> Option<Self> {
+                    match self.overflowing_sin() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Sine, with `self` in radians, saturating on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since |sin&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|, this method
+never saturates.",
+                    "Overflow occurs when the sine is negative, in which case
+zero is returned.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.saturating_sin(), 1);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(4).saturating_sin(), 0);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sin(self) -> Self {
+                    let sin = trig::sin(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    match real::$Inner::to_bits(sin, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if sin.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Sine, with `self` in radians, wrapping on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since |sin&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|, this method
+never wraps.",
+                    "Overflow occurs when the sine is negative.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.wrapping_sin(), 1);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "// sin 4 = −0.7568, which is rounded to −0.75
+assert_eq!(
+    Fix::from_num(4).wrapping_sin(),
+    Fix::ZERO.wrapping_sub(Fix::from_num(0.75))
+);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sin(self) -> Self {
+                    self.overflowing_sin().0
+                }
+            }
+
+            comment! {
+                "Sine, with `self` in radians, panicking on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since |sin&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|, this method
+never panics.",
+                    "# Panics
+
+Panics if the sine is negative.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.unwrapped_sin(), 1);
+```
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::from_num(4).unwrapped_sin();
+```
+",
+                };
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sin(self) -> Self {
+                    match self.overflowing_sin() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing sine, with `self` in radians.
+
+Returns a [tuple] of the sine and a [`bool`] indicating whether an overflow has
+occurred. On overflow, the wrapped value is returned.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since |sin&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|, this method
+never overflows.",
+                    "Overflow occurs when the sine is negative.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.overflowing_sin(), (Fix::ONE, false));
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(
+    Fix::from_num(4).overflowing_sin(),
+    (Fix::ZERO.wrapping_sub(Fix::from_num(0.75)), true)
+);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sin(self) -> (Self, bool) {
+                    let sin = trig::sin(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    let (bits, overflow) = real::$Inner::to_bits(sin, FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Cosine, with `self` in radians.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "Overflow can only occur when there are less than two
+integer bits, that is for `", stringify!($Self), "<", $nm1, ">` and
+`", stringify!($Self), "<", $n, ">`, as then 1 cannot be represented.
+
+"
+                    ),
+                    concat!(
+                        "Overflow occurs when the cosine is negative, or for
+`", stringify!($Self), "<", $n, ">` when the result is rounded to 1.
+
+"
+                    ),
+                ),
+                "# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_cos`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.cos(), 1);
+assert_eq!(Fix::FRAC_PI_2.cos(), 0);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::PI.cos(), -1);
+",
+                },
+                "```
+
+[`wrapping_cos`]: Self::wrapping_cos
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn cos(self) -> Self {
+                    let (val, overflow) = self.overflowing_cos();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked cosine, with `self` in radians. Returns [`None`] on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_cos(), Some(Fix::ONE));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.checked_cos(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_cos(self) -> Option<Self> {
+                    match self.overflowing_cos() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Cosine, with `self` in radians, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.saturating_cos(), 1);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::PI.saturating_cos(), 0);
+",
+                },
+                "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.saturating_cos(), AllFrac::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_cos(self) -> Self {
+                    let cos = trig::cos(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    match real::$Inner::to_bits(cos, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if cos.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Cosine, with `self` in radians, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.wrapping_cos(), 1);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.wrapping_cos(), 0);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_cos(self) -> Self {
+                    self.overflowing_cos().0
+                }
+            }
+
+            comment! {
+                "Cosine, with `self` in radians, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.unwrapped_cos(), 1);
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::ZERO.unwrapped_cos();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_cos(self) -> Self {
+                    match self.overflowing_cos() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing cosine, with `self` in radians.
+
+Returns a [tuple] of the cosine and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.overflowing_cos(), (Fix::ONE, false));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.overflowing_cos(), (AllFrac::ZERO, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_cos(self) -> (Self, bool) {
+                    let cos = trig::cos(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    let (bits, overflow) = real::$Inner::to_bits(cos, FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Simultaneous sine and cosine, with `self` in radians.
+
+Returns a [tuple] of the sine and the cosine. This is faster than calling
+[`sin`][Self::sin] and [`cos`][Self::cos] separately, and the results are
+identical.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the sine or the cosine
+overflows. When debug assertions are not enabled, the wrapped values can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_sin_cos`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.sin_cos(), (Fix::ZERO, Fix::ONE));
+assert_eq!(Fix::FRAC_PI_2.sin_cos(), (Fix::ONE, Fix::ZERO));
+```
+
+[`wrapping_sin_cos`]: Self::wrapping_sin_cos
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn sin_cos(self) -> (Self, Self) {
+                    let (val, overflow) = self.overflowing_sin_cos();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked simultaneous sine and cosine, with `self` in radians.
+Returns [`None`] if either the sine or the cosine overflows.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_sin_cos(), Some((Fix::ZERO, Fix::ONE)));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.checked_sin_cos(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sin_cos(self) -> Option<(Self, Self)> {
+                    match self.overflowing_sin_cos() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Simultaneous sine and cosine, with `self` in radians,
+saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.saturating_sin_cos(), (Fix::ZERO, Fix::ONE));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.saturating_sin_cos(), (AllFrac::ZERO, AllFrac::MAX));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sin_cos(self) -> (Self, Self) {
+                    let (sin, cos) = trig::sin_cos(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    let sin = match real::$Inner::to_bits(sin, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if sin.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    };
+                    let cos = match real::$Inner::to_bits(cos, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if cos.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    };
+                    (sin, cos)
+                }
+            }
+
+            comment! {
+                "Simultaneous sine and cosine, with `self` in radians, wrapping
+on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.wrapping_sin_cos(), (Fix::ZERO, Fix::ONE));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.wrapping_sin_cos(), (AllFrac::ZERO, AllFrac::ZERO));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sin_cos(self) -> (Self, Self) {
+                    self.overflowing_sin_cos().0
+                }
+            }
+
+            comment! {
+                "Simultaneous sine and cosine, with `self` in radians,
+panicking on overflow.
+
+# Panics
+
+Panics if the sine or the cosine does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.unwrapped_sin_cos(), (Fix::ZERO, Fix::ONE));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::ZERO.unwrapped_sin_cos();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sin_cos(self) -> (Self, Self) {
+                    match self.overflowing_sin_cos() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing simultaneous sine and cosine, with `self` in
+radians.
+
+Returns a [tuple] of the sine and cosine [tuple] and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped values are returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.overflowing_sin_cos(), ((Fix::ZERO, Fix::ONE), false));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(
+    AllFrac::ZERO.overflowing_sin_cos(),
+    ((AllFrac::ZERO, AllFrac::ZERO), true)
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sin_cos(self) -> ((Self, Self), bool) {
+                    let (sin, cos) = trig::sin_cos(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    let (sin, sin_overflow) = real::$Inner::to_bits(sin, FRAC);
+                    let (cos, cos_overflow) = real::$Inner::to_bits(cos, FRAC);
+                    (
+                        (Self::from_bits(sin), Self::from_bits(cos)),
+                        sin_overflow | cos_overflow,
+                    )
+                }
+            }
+
+            comment! {
+                "Tangent, with `self` in radians.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Overflow also occurs when the tangent is negative.
+
+"
+                },
+                "# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_tan`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.tan(), 0);
+// tan 1 = 1.5574, which is rounded to 1.5625
+assert_eq!(Fix::ONE.tan(), Fix::from_num(1.5625));
+```
+
+[`wrapping_tan`]: Self::wrapping_tan
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn tan(self) -> Self {
+                    let (val, overflow) = self.overflowing_tan();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked tangent, with `self` in radians. Returns [`None`] on
+overflow.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Overflow also occurs when the tangent is negative.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_tan(), Some(Fix::from_num(1.5625)));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::MAX.checked_tan(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_tan(self) -> Option<Self> {
+                    match self.overflowing_tan() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Tangent, with `self` in radians, saturating on overflow.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Overflow also occurs when the tangent is negative.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.saturating_tan(), Fix::from_num(1.5625));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::MAX.saturating_tan(), AllFrac::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_tan(self) -> Self {
+                    let tan = trig::tan(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    match real::$Inner::to_bits(tan, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if tan.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Tangent, with `self` in radians, wrapping on overflow.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Overflow also occurs when the tangent is negative.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.wrapping_tan(), Fix::from_num(1.5625));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let (wrapped, overflow) = AllFrac::MAX.overflowing_tan();
+assert!(overflow);
+assert_eq!(AllFrac::MAX.wrapping_tan(), wrapped);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_tan(self) -> Self {
+                    self.overflowing_tan().0
+                }
+            }
+
+            comment! {
+                "Tangent, with `self` in radians, panicking on overflow.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Overflow also occurs when the tangent is negative.
+
+"
+                },
+                "# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.unwrapped_tan(), Fix::from_num(1.5625));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::MAX.unwrapped_tan();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_tan(self) -> Self {
+                    match self.overflowing_tan() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing tangent, with `self` in radians.
+
+Returns a [tuple] of the tangent and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Overflow also occurs when the tangent is negative.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.overflowing_tan(), (Fix::from_num(1.5625), false));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert!(AllFrac::MAX.overflowing_tan().1);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_tan(self) -> (Self, bool) {
+                    let tan = trig::tan(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    let (bits, overflow) = real::$Inner::to_bits(tan, FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Arctangent, returning the angle in radians.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+Since |atan&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|, this method never
+overflows, so there are no checked, saturating, wrapping, unwrapped or
+overflowing versions.
+
+For the four-quadrant arctangent, see [`atan2`][Self::atan2].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.atan(), 0);
+// atan 1 = π/4 = 0.7854, which is rounded to 0.8125
+assert_eq!(Fix::ONE.atan(), Fix::from_num(0.8125));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn atan(self) -> Self {
+                    let atan = trig::atan(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    Self::from_bits(real::$Inner::to_bits(atan, FRAC).0)
+                }
+            }
+
+            comment! {
+                "Arcsine, returning the angle in radians.
+
+The result is rounded to the nearest, so like for [`sqrt`][Self::sqrt], the
+error is less than [`DELTA`][Self::DELTA].
+
+The domain is ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                ". Inputs outside the domain are reported like overflow, so
+that [`checked_asin`][Self::checked_asin] returns [`None`] and
+[`overflowing_asin`][Self::overflowing_asin] returns `true`.
+
+# Panics
+
+When debug assertions are enabled, this method panics if `self` is outside the
+domain or if the result overflows. When debug assertions are not enabled, the
+arcsine of the nearest end of the domain is used for inputs outside the domain,
+and the wrapped value is returned on overflow, but it is not considered a
+breaking change if in the future it panics; if wrapping is required use
+[`wrapping_asin`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.asin(), 0);
+assert_eq!(Fix::ONE.asin(), Fix::FRAC_PI_2);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-Fix::ONE).asin(), -Fix::FRAC_PI_2);
+",
+                },
+                "```
+
+[`wrapping_asin`]: Self::wrapping_asin
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn asin(self) -> Self {
+                    let (val, overflow) = self.overflowing_asin();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked arcsine, returning the angle in radians. Returns
+[`None`] if `self` is outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_asin(), Some(Fix::FRAC_PI_2));
+// 2 is outside the domain
+assert_eq!(Fix::from_num(2).checked_asin(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_asin(self) -> Option<Self> {
+                    match self.overflowing_asin() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Arcsine, returning the angle in radians, saturating on
+overflow.
+
+Inputs outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " are treated as the nearest end of the domain.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.saturating_asin(), Fix::FRAC_PI_2);
+// 2 is outside the domain, so it is treated as 1
+assert_eq!(Fix::from_num(2).saturating_asin(), Fix::FRAC_PI_2);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::MAX.saturating_asin(), AllFrac::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_asin(self) -> Self {
+                    let (asin, _) = trig::asin(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    match real::$Inner::to_bits(asin, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if asin.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Arcsine, returning the angle in radians, wrapping on
+overflow.
+
+Inputs outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " are treated as the nearest end of the domain.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.wrapping_asin(), Fix::FRAC_PI_2);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let (wrapped, overflow) = AllFrac::MAX.overflowing_asin();
+assert!(overflow);
+assert_eq!(AllFrac::MAX.wrapping_asin(), wrapped);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_asin(self) -> Self {
+                    self.overflowing_asin().0
+                }
+            }
+
+            comment! {
+                "Arcsine, returning the angle in radians, panicking on
+overflow.
+
+# Panics
+
+Panics if `self` is outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " or if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.unwrapped_asin(), Fix::FRAC_PI_2);
+```
+
+The following panics because 2 is outside the domain.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _domain_error = Fix::from_num(2).unwrapped_asin();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_asin(self) -> Self {
+                    match self.overflowing_asin() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing arcsine, returning the angle in radians.
+
+Returns a [tuple] of the arcsine and a [`bool`] indicating whether `self` is
+outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " or an overflow has occurred. For inputs outside the domain,
+the arcsine of the nearest end of the domain is used. On overflow, the wrapped
+value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.overflowing_asin(), (Fix::FRAC_PI_2, false));
+// 2 is outside the domain, so it is treated as 1
+assert_eq!(Fix::from_num(2).overflowing_asin(), (Fix::FRAC_PI_2, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_asin(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let (asin, domain_error) = trig::asin(x);
+                    let (bits, overflow) = real::$Inner::to_bits(asin, FRAC);
+                    (Self::from_bits(bits), domain_error | overflow)
+                }
+            }
+
+            comment! {
+                "Arccosine, returning the angle in radians.
+
+The result is rounded to the nearest, so like for [`sqrt`][Self::sqrt], the
+error is less than [`DELTA`][Self::DELTA].
+
+The domain is ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                ". Inputs outside the domain are reported like overflow, so
+that [`checked_acos`][Self::checked_acos] returns [`None`] and
+[`overflowing_acos`][Self::overflowing_acos] returns `true`.
+
+# Panics
+
+When debug assertions are enabled, this method panics if `self` is outside the
+domain or if the result overflows. When debug assertions are not enabled, the
+arccosine of the nearest end of the domain is used for inputs outside the domain,
+and the wrapped value is returned on overflow, but it is not considered a
+breaking change if in the future it panics; if wrapping is required use
+[`wrapping_acos`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.acos(), 0);
+assert_eq!(Fix::ZERO.acos(), Fix::FRAC_PI_2);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-Fix::ONE).acos(), Fix::PI);
+",
+                },
+                "```
+
+[`wrapping_acos`]: Self::wrapping_acos
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn acos(self) -> Self {
+                    let (val, overflow) = self.overflowing_acos();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked arccosine, returning the angle in radians. Returns
+[`None`] if `self` is outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_acos(), Some(Fix::FRAC_PI_2));
+// 2 is outside the domain
+assert_eq!(Fix::from_num(2).checked_acos(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_acos(self) -> Option<Self> {
+                    match self.overflowing_acos() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Arccosine, returning the angle in radians, saturating on
+overflow.
+
+Inputs outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " are treated as the nearest end of the domain.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.saturating_acos(), Fix::FRAC_PI_2);
+// 2 is outside the domain, so it is treated as 1
+assert_eq!(Fix::from_num(2).saturating_acos(), 0);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+// acos 0 = π/2, which does not fit
+assert_eq!(AllFrac::ZERO.saturating_acos(), AllFrac::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_acos(self) -> Self {
+                    let (acos, _) = trig::acos(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    match real::$Inner::to_bits(acos, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if acos.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Arccosine, returning the angle in radians, wrapping on
+overflow.
+
+Inputs outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " are treated as the nearest end of the domain.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.wrapping_acos(), Fix::FRAC_PI_2);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let (wrapped, overflow) = AllFrac::ZERO.overflowing_acos();
+assert!(overflow);
+assert_eq!(AllFrac::ZERO.wrapping_acos(), wrapped);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_acos(self) -> Self {
+                    self.overflowing_acos().0
+                }
+            }
+
+            comment! {
+                "Arccosine, returning the angle in radians, panicking on
+overflow.
+
+# Panics
+
+Panics if `self` is outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " or if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.unwrapped_acos(), Fix::FRAC_PI_2);
+```
+
+The following panics because 2 is outside the domain.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _domain_error = Fix::from_num(2).unwrapped_acos();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_acos(self) -> Self {
+                    match self.overflowing_acos() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing arccosine, returning the angle in radians.
+
+Returns a [tuple] of the arccosine and a [`bool`] indicating whether `self` is
+outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1",
+                ),
+                " or an overflow has occurred. For inputs outside the domain,
+the arccosine of the nearest end of the domain is used. On overflow, the wrapped
+value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.overflowing_acos(), (Fix::FRAC_PI_2, false));
+// 2 is outside the domain, so it is treated as 1
+assert_eq!(Fix::from_num(2).overflowing_acos(), (Fix::ZERO, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_acos(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let (acos, domain_error) = trig::acos(x);
+                    let (bits, overflow) = real::$Inner::to_bits(acos, FRAC);
+                    (Self::from_bits(bits), domain_error | overflow)
+                }
+            }
+
+            comment! {
+                "Exponential, <i>e</i><sup>`self`</sup>.
+
+The result is rounded to the nearest. The error is less than
+[`DELTA`][Self::DELTA] as long as the result has no more than 120 significant
+bits; otherwise the relative error is less than 2<sup>&minus;120</sup>.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_exp`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.exp(), 1);
+// e = 2.7183, which is rounded to 2.6875
+assert_eq!(Fix::ONE.exp(), Fix::from_num(2.6875));
+```
+
+[`wrapping_exp`]: Self::wrapping_exp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn exp(self) -> Self {
+                    let (val, overflow) = self.overflowing_exp();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked exponential. Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_exp(), Some(Fix::ONE));
+assert_eq!(Fix::MAX.checked_exp(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_exp(self) -> Option<Self> {
+                    match self.overflowing_exp() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Exponential, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.saturating_exp(), Fix::ONE);
+assert_eq!(Fix::MAX.saturating_exp(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_exp(self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let r = exp::exp(x);
+                    match real::$Inner::to_bits(r, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if r.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Exponential, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.wrapping_exp(), Fix::ONE);
+let (wrapped, overflow) = Fix::MAX.overflowing_exp();
+assert!(overflow);
+assert_eq!(Fix::MAX.wrapping_exp(), wrapped);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_exp(self) -> Self {
+                    self.overflowing_exp().0
+                }
+            }
+
+            comment! {
+                "Exponential, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.unwrapped_exp(), Fix::ONE);
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_exp();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_exp(self) -> Self {
+                    match self.overflowing_exp() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing exponential.
+
+Returns a [tuple] of the exponential and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.overflowing_exp(), (Fix::ONE, false));
+assert!(Fix::MAX.overflowing_exp().1);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_exp(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let (bits, overflow) = real::$Inner::to_bits(exp::exp(x), FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Base-2 exponential, 2<sup>`self`</sup>.
+
+The result is rounded to the nearest. The error is less than
+[`DELTA`][Self::DELTA] as long as the result has no more than 120 significant
+bits; otherwise the relative error is less than 2<sup>&minus;120</sup>.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_exp2`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).exp2(), 4);
+// √2 = 1.4142, which is rounded to 1.4375
+assert_eq!(Fix::from_num(0.5).exp2(), Fix::from_num(1.4375));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).exp2(), 0.5);
+",
+                },
+                "```
+
+[`wrapping_exp2`]: Self::wrapping_exp2
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn exp2(self) -> Self {
+                    let (val, overflow) = self.overflowing_exp2();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked base-2 exponential. Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_exp2(), Some(Fix::ONE));
+assert_eq!(Fix::MAX.checked_exp2(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_exp2(self) -> Option<Self> {
+                    match self.overflowing_exp2() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Base-2 exponential, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.saturating_exp2(), Fix::ONE);
+assert_eq!(Fix::MAX.saturating_exp2(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_exp2(self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let r = exp::exp2(x);
+                    match real::$Inner::to_bits(r, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if r.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Base-2 exponential, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.wrapping_exp2(), Fix::ONE);
+let (wrapped, overflow) = Fix::MAX.overflowing_exp2();
+assert!(overflow);
+assert_eq!(Fix::MAX.wrapping_exp2(), wrapped);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_exp2(self) -> Self {
+                    self.overflowing_exp2().0
+                }
+            }
+
+            comment! {
+                "Base-2 exponential, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.unwrapped_exp2(), Fix::ONE);
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_exp2();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_exp2(self) -> Self {
+                    match self.overflowing_exp2() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing base-2 exponential.
+
+Returns a [tuple] of the base-2 exponential and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.overflowing_exp2(), (Fix::ONE, false));
+assert!(Fix::MAX.overflowing_exp2().1);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_exp2(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let (bits, overflow) = real::$Inner::to_bits(exp::exp2(x), FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Exponential minus one, <i>e</i><sup>`self`</sup>&nbsp;&minus;&nbsp;1.
+
+The result is rounded to the nearest. The error is less than
+[`DELTA`][Self::DELTA] as long as the result has no more than 120 significant
+bits; otherwise the relative error is less than 2<sup>&minus;120</sup>.
+
+This can be used even when <i>e</i><sup>`self`</sup> itself does not fit, for
+example for small values of `self` when there are less than two integer bits.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_exp_m1`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.exp_m1(), 0);
+// e − 1 = 1.7183, which is rounded to 1.6875
+assert_eq!(Fix::ONE.exp_m1(), Fix::from_num(1.6875));
+```
+
+[`wrapping_exp_m1`]: Self::wrapping_exp_m1
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn exp_m1(self) -> Self {
+                    let (val, overflow) = self.overflowing_exp_m1();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked exponential minus one. Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_exp_m1(), Some(Fix::ZERO));
+assert_eq!(Fix::MAX.checked_exp_m1(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_exp_m1(self) -> Option<Self> {
+                    match self.overflowing_exp_m1() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Exponential minus one, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.saturating_exp_m1(), Fix::ZERO);
+assert_eq!(Fix::MAX.saturating_exp_m1(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_exp_m1(self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let r = exp::exp_m1(x);
+                    match real::$Inner::to_bits(r, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if r.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Exponential minus one, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.wrapping_exp_m1(), Fix::ZERO);
+let (wrapped, overflow) = Fix::MAX.overflowing_exp_m1();
+assert!(overflow);
+assert_eq!(Fix::MAX.wrapping_exp_m1(), wrapped);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_exp_m1(self) -> Self {
+                    self.overflowing_exp_m1().0
+                }
+            }
+
+            comment! {
+                "Exponential minus one, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.unwrapped_exp_m1(), Fix::ZERO);
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_exp_m1();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_exp_m1(self) -> Self {
+                    match self.overflowing_exp_m1() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing exponential minus one.
+
+Returns a [tuple] of the exponential minus one and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.overflowing_exp_m1(), (Fix::ZERO, false));
+assert!(Fix::MAX.overflowing_exp_m1().1);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_exp_m1(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let (bits, overflow) = real::$Inner::to_bits(exp::exp_m1(x), FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Natural logarithm, returned with `RET_FRAC` fractional bits.
+
+The result is a signed fixed-point number with the same number of bits as
+`self`, that is a [`", stringify!($ISelf), "`]`<RET_FRAC>`, where the number of
+fractional bits `RET_FRAC` is chosen by the caller.
+
+The result is rounded to the nearest. The error is less than
+[`DELTA`][Self::DELTA] of the result type as long as the result has no more
+than 120 significant bits; otherwise the relative error is less than
+2<sup>&minus;120</sup>.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_ln`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// ln 2 = 0.6931, which is rounded to 0.6875
+let log: Log = Fix::from_num(2).ln();
+assert_eq!(log, Log::from_num(0.6875));
+```
+
+[`wrapping_ln`]: Self::wrapping_ln
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn ln<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    let (val, overflow) = self.overflowing_ln();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked natural logarithm, returned with `RET_FRAC` fractional bits.
+Returns [`None`] if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or on overflow.
+
+See [`ln`][Self::ln] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// ln 2 = 0.6931, which is rounded to 0.6875
+let log: Option<Log> = Fix::from_num(2).checked_ln();
+assert_eq!(log, Some(Log::from_num(0.6875)));
+assert_eq!(Fix::ZERO.checked_ln::<4>(), None);
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let overflow: Option<AllFracLog> = Fix::from_num(4).checked_ln();
+assert_eq!(overflow, None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_ln<const RET_FRAC: i32>(self) -> Option<$ISelf<RET_FRAC>> {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if x.neg || x.mant == 0 {
+                        return None;
+                    }
+                    match real::$IInner::to_bits(ln::ln(x), RET_FRAC) {
+                        (bits, false) => Some($ISelf::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Natural logarithm, returned with `RET_FRAC` fractional bits, saturating
+on overflow.
+
+See [`ln`][Self::ln] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// ln 2 = 0.6931, which is rounded to 0.6875
+let log: Log = Fix::from_num(2).saturating_ln();
+assert_eq!(log, Log::from_num(0.6875));
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let saturated: AllFracLog = Fix::from_num(4).saturating_ln();
+assert_eq!(saturated, AllFracLog::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_ln<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if x.neg || x.mant == 0 {
+                        panic!("log of non-positive number");
+                    }
+                    let log = ln::ln(x);
+                    match real::$IInner::to_bits(log, RET_FRAC) {
+                        (bits, false) => $ISelf::from_bits(bits),
+                        (_, true) if log.neg => $ISelf::MIN,
+                        (_, true) => $ISelf::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Natural logarithm, returned with `RET_FRAC` fractional bits, wrapping on
+overflow.
+
+See [`ln`][Self::ln] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// ln 2 = 0.6931, which is rounded to 0.6875
+let log: Log = Fix::from_num(2).wrapping_ln();
+assert_eq!(log, Log::from_num(0.6875));
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let (wrapped, overflow): (AllFracLog, bool) = Fix::from_num(4).overflowing_ln();
+assert!(overflow);
+let wrapping: AllFracLog = Fix::from_num(4).wrapping_ln();
+assert_eq!(wrapping, wrapped);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_ln<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    self.overflowing_ln().0
+                }
+            }
+
+            comment! {
+                "Natural logarithm, returned with `RET_FRAC` fractional bits, panicking on
+overflow.
+
+See [`ln`][Self::ln] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// ln 2 = 0.6931, which is rounded to 0.6875
+let log: Log = Fix::from_num(2).unwrapped_ln();
+assert_eq!(log, Log::from_num(0.6875));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let _overflow: AllFracLog = Fix::from_num(4).unwrapped_ln();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_ln<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    match self.overflowing_ln() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing natural logarithm, returned with `RET_FRAC` fractional bits.
+
+Returns a [tuple] of the logarithm and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+See [`ln`][Self::ln] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// ln 2 = 0.6931, which is rounded to 0.6875
+let (log, overflow): (Log, bool) = Fix::from_num(2).overflowing_ln();
+assert_eq!(log, Log::from_num(0.6875));
+assert!(!overflow);
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let (_, overflow): (AllFracLog, bool) = Fix::from_num(4).overflowing_ln();
+assert!(overflow);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_ln<const RET_FRAC: i32>(self) -> ($ISelf<RET_FRAC>, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if x.neg || x.mant == 0 {
+                        panic!("log of non-positive number");
+                    }
+                    let (bits, overflow) = real::$IInner::to_bits(ln::ln(x), RET_FRAC);
+                    ($ISelf::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Base-2 logarithm, returned with `RET_FRAC` fractional bits.
+
+The result is a signed fixed-point number with the same number of bits as
+`self`, that is a [`", stringify!($ISelf), "`]`<RET_FRAC>`, where the number of
+fractional bits `RET_FRAC` is chosen by the caller.
+
+The result is rounded to the nearest. The error is less than
+[`DELTA`][Self::DELTA] of the result type as long as the result has no more
+than 120 significant bits; otherwise the relative error is less than
+2<sup>&minus;120</sup>.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_log2`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+let log: Log = Fix::from_num(0.25).log2();
+assert_eq!(log, -2);
+```
+
+[`wrapping_log2`]: Self::wrapping_log2
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn log2<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    let (val, overflow) = self.overflowing_log2();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked base-2 logarithm, returned with `RET_FRAC` fractional bits.
+Returns [`None`] if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or on overflow.
+
+See [`log2`][Self::log2] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+let log: Option<Log> = Fix::from_num(0.25).checked_log2();
+assert_eq!(log, Some(Log::from_num(-2)));
+assert_eq!(Fix::ZERO.checked_log2::<4>(), None);
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let overflow: Option<AllFracLog> = Fix::from_num(4).checked_log2();
+assert_eq!(overflow, None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_log2<const RET_FRAC: i32>(self) -> Option<$ISelf<RET_FRAC>> {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if x.neg || x.mant == 0 {
+                        return None;
+                    }
+                    match real::$IInner::to_bits(ln::log2(x), RET_FRAC) {
+                        (bits, false) => Some($ISelf::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Base-2 logarithm, returned with `RET_FRAC` fractional bits, saturating
+on overflow.
+
+See [`log2`][Self::log2] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+let log: Log = Fix::from_num(0.25).saturating_log2();
+assert_eq!(log, -2);
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let saturated: AllFracLog = Fix::from_num(4).saturating_log2();
+assert_eq!(saturated, AllFracLog::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_log2<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if x.neg || x.mant == 0 {
+                        panic!("log of non-positive number");
+                    }
+                    let log = ln::log2(x);
+                    match real::$IInner::to_bits(log, RET_FRAC) {
+                        (bits, false) => $ISelf::from_bits(bits),
+                        (_, true) if log.neg => $ISelf::MIN,
+                        (_, true) => $ISelf::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Base-2 logarithm, returned with `RET_FRAC` fractional bits, wrapping on
+overflow.
+
+See [`log2`][Self::log2] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+let log: Log = Fix::from_num(0.25).wrapping_log2();
+assert_eq!(log, -2);
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let (wrapped, overflow): (AllFracLog, bool) = Fix::from_num(4).overflowing_log2();
+assert!(overflow);
+let wrapping: AllFracLog = Fix::from_num(4).wrapping_log2();
+assert_eq!(wrapping, wrapped);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_log2<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    self.overflowing_log2().0
+                }
+            }
+
+            comment! {
+                "Base-2 logarithm, returned with `RET_FRAC` fractional bits, panicking on
+overflow.
+
+See [`log2`][Self::log2] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+let log: Log = Fix::from_num(0.25).unwrapped_log2();
+assert_eq!(log, -2);
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let _overflow: AllFracLog = Fix::from_num(4).unwrapped_log2();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_log2<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    match self.overflowing_log2() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing base-2 logarithm, returned with `RET_FRAC` fractional bits.
+
+Returns a [tuple] of the logarithm and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+See [`log2`][Self::log2] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+let (log, overflow): (Log, bool) = Fix::from_num(0.25).overflowing_log2();
+assert_eq!(log, -2);
+assert!(!overflow);
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let (_, overflow): (AllFracLog, bool) = Fix::from_num(4).overflowing_log2();
+assert!(overflow);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_log2<const RET_FRAC: i32>(self) -> ($ISelf<RET_FRAC>, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if x.neg || x.mant == 0 {
+                        panic!("log of non-positive number");
+                    }
+                    let (bits, overflow) = real::$IInner::to_bits(ln::log2(x), RET_FRAC);
+                    ($ISelf::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Base-10 logarithm, returned with `RET_FRAC` fractional bits.
+
+The result is a signed fixed-point number with the same number of bits as
+`self`, that is a [`", stringify!($ISelf), "`]`<RET_FRAC>`, where the number of
+fractional bits `RET_FRAC` is chosen by the caller.
+
+The result is rounded to the nearest. The error is less than
+[`DELTA`][Self::DELTA] of the result type as long as the result has no more
+than 120 significant bits; otherwise the relative error is less than
+2<sup>&minus;120</sup>.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_log10`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// log10 2 = 0.3010, which is rounded to 0.3125
+let log: Log = Fix::from_num(2).log10();
+assert_eq!(log, Log::from_num(0.3125));
+```
+
+[`wrapping_log10`]: Self::wrapping_log10
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn log10<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    let (val, overflow) = self.overflowing_log10();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked base-10 logarithm, returned with `RET_FRAC` fractional bits.
+Returns [`None`] if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or on overflow.
+
+See [`log10`][Self::log10] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// log10 2 = 0.3010, which is rounded to 0.3125
+let log: Option<Log> = Fix::from_num(2).checked_log10();
+assert_eq!(log, Some(Log::from_num(0.3125)));
+assert_eq!(Fix::ZERO.checked_log10::<4>(), None);
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let overflow: Option<AllFracLog> = Fix::from_num(4).checked_log10();
+assert_eq!(overflow, None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_log10<const RET_FRAC: i32>(self) -> Option<$ISelf<RET_FRAC>> {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if x.neg || x.mant == 0 {
+                        return None;
+                    }
+                    match real::$IInner::to_bits(ln::log10(x), RET_FRAC) {
+                        (bits, false) => Some($ISelf::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Base-10 logarithm, returned with `RET_FRAC` fractional bits, saturating
+on overflow.
+
+See [`log10`][Self::log10] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// log10 2 = 0.3010, which is rounded to 0.3125
+let log: Log = Fix::from_num(2).saturating_log10();
+assert_eq!(log, Log::from_num(0.3125));
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let saturated: AllFracLog = Fix::from_num(4).saturating_log10();
+assert_eq!(saturated, AllFracLog::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_log10<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if x.neg || x.mant == 0 {
+                        panic!("log of non-positive number");
+                    }
+                    let log = ln::log10(x);
+                    match real::$IInner::to_bits(log, RET_FRAC) {
+                        (bits, false) => $ISelf::from_bits(bits),
+                        (_, true) if log.neg => $ISelf::MIN,
+                        (_, true) => $ISelf::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Base-10 logarithm, returned with `RET_FRAC` fractional bits, wrapping on
+overflow.
+
+See [`log10`][Self::log10] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// log10 2 = 0.3010, which is rounded to 0.3125
+let log: Log = Fix::from_num(2).wrapping_log10();
+assert_eq!(log, Log::from_num(0.3125));
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let (wrapped, overflow): (AllFracLog, bool) = Fix::from_num(4).overflowing_log10();
+assert!(overflow);
+let wrapping: AllFracLog = Fix::from_num(4).wrapping_log10();
+assert_eq!(wrapping, wrapped);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_log10<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    self.overflowing_log10().0
+                }
+            }
+
+            comment! {
+                "Base-10 logarithm, returned with `RET_FRAC` fractional bits, panicking on
+overflow.
+
+See [`log10`][Self::log10] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// log10 2 = 0.3010, which is rounded to 0.3125
+let log: Log = Fix::from_num(2).unwrapped_log10();
+assert_eq!(log, Log::from_num(0.3125));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let _overflow: AllFracLog = Fix::from_num(4).unwrapped_log10();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_log10<const RET_FRAC: i32>(self) -> $ISelf<RET_FRAC> {
+                    match self.overflowing_log10() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing base-10 logarithm, returned with `RET_FRAC` fractional bits.
+
+Returns a [tuple] of the logarithm and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+See [`log10`][Self::log10] for details.
+
+# Panics
+
+Panics if `self` is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::", stringify!($Self), ";"),
+                    concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+type Log = ", stringify!($ISelf), "<4>;
+// log10 2 = 0.3010, which is rounded to 0.3125
+let (log, overflow): (Log, bool) = Fix::from_num(2).overflowing_log10();
+assert_eq!(log, Log::from_num(0.3125));
+assert!(!overflow);
+type AllFracLog = ", stringify!($ISelf), "<", $n, ">;
+let (_, overflow): (AllFracLog, bool) = Fix::from_num(4).overflowing_log10();
+assert!(overflow);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_log10<const RET_FRAC: i32>(self) -> ($ISelf<RET_FRAC>, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if x.neg || x.mant == 0 {
+                        panic!("log of non-positive number");
+                    }
+                    let (bits, overflow) = real::$IInner::to_bits(ln::log10(x), RET_FRAC);
+                    ($ISelf::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Raises `self` to the integer power `exp`.
+
+Intermediate results are computed with 256-bit significands, and the result is
+rounded to the nearest only once at the end, so unlike repeated multiplication,
+rounding errors do not accumulate. Even for the largest exponents, the relative
+error before the final rounding is less than 2<sup>&minus;220</sup>, so the
+error is always less than [`DELTA`][Self::DELTA], and a result that is exactly
+representable is returned exactly.
+
+Following the convention of floating-point [`powi`][f64::powi],
+<code>0<sup>0</sup></code> is 1.
+
+# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_powi`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).powi(3), Fix::from_num(3.375));
+assert_eq!(Fix::from_num(2).powi(-2), Fix::from_num(0.25));
+assert_eq!(Fix::ZERO.powi(0), 1);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2).powi(3), -8);
+",
+                },
+                "```
+
+[`wrapping_powi`]: Self::wrapping_powi
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn powi(self, exp: i32) -> Self {
+                    let (val, overflow) = self.overflowing_powi(exp);
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked integer power. Returns [`None`] on overflow, or if
+`self` is zero and `exp` is negative.
+
+See [`powi`][Self::powi] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).checked_powi(3), Some(Fix::from_num(3.375)));
+assert_eq!(Fix::MAX.checked_powi(2), None);
+assert_eq!(Fix::ZERO.checked_powi(-1), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_powi(self, exp: i32) -> Option<Self> {
+                    if exp < 0 && self.is_zero() {
+                        return None;
+                    }
+                    match self.overflowing_powi(exp) {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating integer power. Returns `self` raised to the
+integer power `exp`, saturating on overflow.
+
+See [`powi`][Self::powi] for details.
+
+# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).saturating_powi(3), Fix::from_num(3.375));
+assert_eq!(Fix::MAX.saturating_powi(2), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_powi(3), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_powi(self, exp: i32) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if exp < 0 && x.mant == 0 {
+                        panic!("division by zero");
+                    }
+                    let pow = powi::powi(x, exp);
+                    let (abs, overflow) = pow.to_abs_u128(FRAC);
+                    match real::$Inner::from_abs(pow.neg, abs, overflow) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if pow.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping integer power. Returns `self` raised to the integer
+power `exp`, wrapping on overflow.
+
+See [`powi`][Self::powi] for details.
+
+# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).wrapping_powi(3), Fix::from_num(3.375));
+let (wrapped, overflow) = Fix::MAX.overflowing_powi(2);
+assert!(overflow);
+assert_eq!(Fix::MAX.wrapping_powi(2), wrapped);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_powi(self, exp: i32) -> Self {
+                    self.overflowing_powi(exp).0
+                }
+            }
+
+            comment! {
+                "Unwrapped integer power. Returns `self` raised to the integer
+power `exp`, panicking on overflow.
+
+See [`powi`][Self::powi] for details.
+
+# Panics
+
+Panics if the result does not fit, or if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).unwrapped_powi(3), Fix::from_num(3.375));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_powi(2);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_powi(self, exp: i32) -> Self {
+                    match self.overflowing_powi(exp) {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing integer power.
+
+Returns a [tuple] of `self` raised to the integer power `exp` and a [`bool`]
+indicating whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`powi`][Self::powi] for details.
+
+# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).overflowing_powi(3), (Fix::from_num(3.375), false));
+assert!(Fix::MAX.overflowing_powi(2).1);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_powi(self, exp: i32) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    if exp < 0 && x.mant == 0 {
+                        panic!("division by zero");
+                    }
+                    let pow = powi::powi(x, exp);
+                    let (abs, overflow) = pow.to_abs_u128(FRAC);
+                    let (bits, overflow) = real::$Inner::from_abs(pow.neg, abs, overflow);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Raises `self` to the fixed-point power `exp`.
+
+For a negative base, the result is only defined if `exp` is an integer. Other
+cases are domain errors, which are reported like overflow, with zero as the
+wrapped value.
+
+If `exp` is an integer that fits in [`i32`], the result is the same as for
+[`powi`][Self::powi]. Otherwise, the result is computed as
+2<sup>`exp`&nbsp;×&nbsp;log<sub>2</sub>&nbsp;`self`</sup>, with intermediate
+results computed with more than 120 bits of precision, and is rounded to the
+nearest. The error is less than [`DELTA`][Self::DELTA] as long as the result has
+no more than 120 significant bits; otherwise the relative error is less than
+2<sup>&minus;120</sup>.
+
+# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+When debug assertions are enabled, this method also panics if the result
+overflows",
+                if_signed_else_empty_str! { $Signedness; " or on a domain error" },
+                ". When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the future it
+panics; if wrapping is required use [`wrapping_powf`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).powf(Fix::from_num(0.5)), 2);
+// 2^1.5 = 2.8284, which is rounded to 2.8125
+assert_eq!(Fix::from_num(2).powf(Fix::from_num(1.5)), Fix::from_num(2.8125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2).powf(Fix::from_num(3)), -8);
+",
+                },
+                "```
+
+[`wrapping_powf`]: Self::wrapping_powf
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn powf(self, exp: Self) -> Self {
+                    let (val, overflow) = self.overflowing_powf(exp);
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked fixed-point power. Returns [`None`] on overflow",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", on a domain error,"
+                },
+                " or if `self` is zero and `exp` is negative.
+
+See [`powf`][Self::powf] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).checked_powf(Fix::from_num(0.5)), Some(Fix::from_num(2)));
+assert_eq!(Fix::MAX.checked_powf(Fix::from_num(2)), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// (−2)^0.5 is not a real number
+assert_eq!(Fix::from_num(-2).checked_powf(Fix::from_num(0.5)), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_powf(self, exp: Self) -> Option<Self> {
+                    if_signed! {
+                        $Signedness;
+                        if exp.is_negative() && self.is_zero() {
+                            return None;
+                        }
+                    }
+                    match self.overflowing_powf(exp) {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating fixed-point power. Returns `self` raised to the
+fixed-point power `exp`, saturating on overflow.
+
+See [`powf`][Self::powf] for details.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Zero is returned on a domain error.
+
+"
+                },
+                "# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).saturating_powf(Fix::from_num(0.5)), 2);
+assert_eq!(Fix::MAX.saturating_powf(Fix::from_num(2)), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_powf(Fix::from_num(3)), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_powf(self, exp: Self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let y = real::$Inner::from_bits(exp.to_bits(), FRAC);
+                    if x.mant == 0 && y.neg {
+                        panic!("division by zero");
+                    }
+                    if let Some(n) = y.to_i32_exact() {
+                        return self.saturating_powi(n);
+                    }
+                    let (pow, _) = exp::powf(x, y);
+                    match real::$Inner::to_bits(pow, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if pow.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping fixed-point power. Returns `self` raised to the
+fixed-point power `exp`, wrapping on overflow.
+
+See [`powf`][Self::powf] for details.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Zero is returned on a domain error.
+
+"
+                },
+                "# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).wrapping_powf(Fix::from_num(0.5)), 2);
+let (wrapped, overflow) = Fix::MAX.overflowing_powf(Fix::from_num(2));
+assert!(overflow);
+assert_eq!(Fix::MAX.wrapping_powf(Fix::from_num(2)), wrapped);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_powf(self, exp: Self) -> Self {
+                    self.overflowing_powf(exp).0
+                }
+            }
+
+            comment! {
+                "Unwrapped fixed-point power. Returns `self` raised to the
+fixed-point power `exp`, panicking on overflow.
+
+See [`powf`][Self::powf] for details.
+
+# Panics
+
+Panics if the result does not fit",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", on a domain error,"
+                },
+                " or if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).unwrapped_powf(Fix::from_num(0.5)), 2);
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_powf(Fix::from_num(2));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_powf(self, exp: Self) -> Self {
+                    match self.overflowing_powf(exp) {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing fixed-point power.
+
+Returns a [tuple] of `self` raised to the fixed-point power `exp` and a [`bool`]
+indicating whether an overflow",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or a domain error"
+                },
+                " has occurred. On overflow, the wrapped value is returned",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", and on a domain error, zero is returned"
+                },
+                ".
+
+See [`powf`][Self::powf] for details.
+
+# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(4).overflowing_powf(Fix::from_num(0.5)),
+    (Fix::from_num(2), false)
+);
+assert!(Fix::MAX.overflowing_powf(Fix::from_num(2)).1);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(
+    Fix::from_num(-2).overflowing_powf(Fix::from_num(0.5)),
+    (Fix::ZERO, true)
+);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_powf(self, exp: Self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let y = real::$Inner::from_bits(exp.to_bits(), FRAC);
+                    if x.mant == 0 && y.neg {
+                        panic!("division by zero");
+                    }
+                    if let Some(n) = y.to_i32_exact() {
+                        return self.overflowing_powi(n);
+                    }
+                    let (pow, domain_error) = exp::powf(x, y);
+                    let (bits, overflow) = real::$Inner::to_bits(pow, FRAC);
+                    (Self::from_bits(bits), domain_error | overflow)
+                }
+            }
+
+            comment! {
+                "Hyperbolic sine.
+
+The result is rounded to the nearest. The error is less than
+[`DELTA`][Self::DELTA] as long as the result has no more than 120 significant
+bits; otherwise the relative error is less than 2<sup>&minus;120</sup>.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_sinh`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.sinh(), 0);
+// sinh 1 = 1.1752, which is rounded to 1.1875
+assert_eq!(Fix::ONE.sinh(), Fix::from_num(1.1875));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-Fix::ONE).sinh(), Fix::from_num(-1.1875));
+",
+                },
+                "```
+
+[`wrapping_sinh`]: Self::wrapping_sinh
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn sinh(self) -> Self {
+                    let (val, overflow) = self.overflowing_sinh();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked hyperbolic sine. Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_sinh(), Some(Fix::from_num(1.1875)));
+assert_eq!(Fix::MAX.checked_sinh(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sinh(self) -> Option<Self> {
+                    match self.overflowing_sinh() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Hyperbolic sine, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.saturating_sinh(), Fix::from_num(1.1875));
+assert_eq!(Fix::MAX.saturating_sinh(), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_sinh(), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sinh(self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let r = exp::sinh(x);
+                    match real::$Inner::to_bits(r, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if r.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Hyperbolic sine, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.wrapping_sinh(), Fix::from_num(1.1875));
+let (wrapped, overflow) = Fix::MAX.overflowing_sinh();
+assert!(overflow);
+assert_eq!(Fix::MAX.wrapping_sinh(), wrapped);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sinh(self) -> Self {
+                    self.overflowing_sinh().0
+                }
+            }
+
+            comment! {
+                "Hyperbolic sine, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.unwrapped_sinh(), Fix::from_num(1.1875));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_sinh();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sinh(self) -> Self {
+                    match self.overflowing_sinh() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing hyperbolic sine.
+
+Returns a [tuple] of the hyperbolic sine and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.overflowing_sinh(), (Fix::from_num(1.1875), false));
+assert!(Fix::MAX.overflowing_sinh().1);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sinh(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let (bits, overflow) = real::$Inner::to_bits(exp::sinh(x), FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Hyperbolic cosine.
+
+The result is rounded to the nearest. The error is less than
+[`DELTA`][Self::DELTA] as long as the result has no more than 120 significant
+bits; otherwise the relative error is less than 2<sup>&minus;120</sup>.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_cosh`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.cosh(), 1);
+// cosh 1 = 1.5431, which is rounded to 1.5625
+assert_eq!(Fix::ONE.cosh(), Fix::from_num(1.5625));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-Fix::ONE).cosh(), Fix::from_num(1.5625));
+",
+                },
+                "```
+
+[`wrapping_cosh`]: Self::wrapping_cosh
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn cosh(self) -> Self {
+                    let (val, overflow) = self.overflowing_cosh();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked hyperbolic cosine. Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_cosh(), Some(Fix::from_num(1.5625)));
+assert_eq!(Fix::MAX.checked_cosh(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_cosh(self) -> Option<Self> {
+                    match self.overflowing_cosh() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Hyperbolic cosine, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.saturating_cosh(), Fix::from_num(1.5625));
+assert_eq!(Fix::MAX.saturating_cosh(), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_cosh(), Fix::MAX);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_cosh(self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let r = exp::cosh(x);
+                    match real::$Inner::to_bits(r, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if r.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Hyperbolic cosine, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.wrapping_cosh(), Fix::from_num(1.5625));
+let (wrapped, overflow) = Fix::MAX.overflowing_cosh();
+assert!(overflow);
+assert_eq!(Fix::MAX.wrapping_cosh(), wrapped);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_cosh(self) -> Self {
+                    self.overflowing_cosh().0
+                }
+            }
+
+            comment! {
+                "Hyperbolic cosine, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.unwrapped_cosh(), Fix::from_num(1.5625));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_cosh();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_cosh(self) -> Self {
+                    match self.overflowing_cosh() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing hyperbolic cosine.
+
+Returns a [tuple] of the hyperbolic cosine and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.overflowing_cosh(), (Fix::from_num(1.5625), false));
+assert!(Fix::MAX.overflowing_cosh().1);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_cosh(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let (bits, overflow) = real::$Inner::to_bits(exp::cosh(x), FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Hyperbolic tangent.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+Since |tanh&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>| and
+|tanh&nbsp;<i>x</i>|&nbsp;&lt;&nbsp;1, this method never overflows, even when
+there are no integer bits, so there are no checked, saturating, wrapping,
+unwrapped or overflowing versions.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.tanh(), 0);
+// tanh 1 = 0.7616, which is rounded to 0.75
+assert_eq!(Fix::ONE.tanh(), Fix::from_num(0.75));
+assert_eq!(Fix::MAX.tanh(), 1);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.tanh(), -1);
+",
+                },
+                "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert!(AllFrac::MAX.tanh() < AllFrac::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn tanh(self) -> Self {
+                    let tanh = exp::tanh(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    Self::from_bits(real::$Inner::to_bits(tanh, FRAC).0)
+                }
+            }
+
+            comment! {
+                "Inverse hyperbolic sine.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+Since |asinh&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|, this method never
+overflows, so there are no checked, saturating, wrapping, unwrapped or
+overflowing versions.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.asinh(), 0);
+// asinh 1 = 0.8814, which is rounded to 0.875
+assert_eq!(Fix::ONE.asinh(), Fix::from_num(0.875));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-Fix::ONE).asinh(), Fix::from_num(-0.875));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn asinh(self) -> Self {
+                    let asinh = ln::asinh(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    Self::from_bits(real::$Inner::to_bits(asinh, FRAC).0)
+                }
+            }
+
+            comment! {
+                "Inverse hyperbolic cosine.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+The domain is <i>x</i>&nbsp;≥&nbsp;1. Since acosh&nbsp;<i>x</i>&nbsp;&lt;&nbsp;<i>x</i>
+in the domain, this method never overflows, so the only other version is
+[`checked_acosh`][Self::checked_acosh], which returns [`None`] for inputs
+outside the domain.
+
+# Panics
+
+Panics if `self` is less than one.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.acosh(), 0);
+// acosh 2 = 1.3170, which is rounded to 1.3125
+assert_eq!(Fix::from_num(2).acosh(), Fix::from_num(1.3125));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn acosh(self) -> Self {
+                    match ln::acosh(real::$Inner::from_bits(self.to_bits(), FRAC)) {
+                        Some(acosh) => Self::from_bits(real::$Inner::to_bits(acosh, FRAC).0),
+                        None => panic!("acosh of number less than one"),
+                    }
+                }
+            }
+
+            comment! {
+                "Checked inverse hyperbolic cosine. Returns [`None`] if `self`
+is less than one.
+
+See [`acosh`][Self::acosh] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_acosh(), Some(Fix::ZERO));
+assert_eq!(Fix::from_num(0.5).checked_acosh(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_acosh(self) -> Option<Self> {
+                    match ln::acosh(real::$Inner::from_bits(self.to_bits(), FRAC)) {
+                        Some(acosh) => Some(Self::from_bits(real::$Inner::to_bits(acosh, FRAC).0)),
+                        None => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Inverse hyperbolic tangent.
+
+The result is rounded to the nearest. The error is less than
+[`DELTA`][Self::DELTA] as long as the result has no more than 120 significant
+bits; otherwise the relative error is less than 2<sup>&minus;120</sup>.
+
+The domain is ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;&lt;&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                ),
+                ".
+
+# Panics
+
+Panics if `self` is outside the domain.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_atanh`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.atanh(), 0);
+// atanh 0.5 = 0.5493, which is rounded to 0.5625
+assert_eq!(Fix::from_num(0.5).atanh(), Fix::from_num(0.5625));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-0.5).atanh(), Fix::from_num(-0.5625));
+",
+                },
+                "```
+
+[`wrapping_atanh`]: Self::wrapping_atanh
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn atanh(self) -> Self {
+                    let (val, overflow) = self.overflowing_atanh();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked inverse hyperbolic tangent. Returns [`None`] if `self`
+is outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;&lt;&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                ),
+                " or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.5).checked_atanh(), Some(Fix::from_num(0.5625)));
+// 1 is outside the domain
+assert_eq!(Fix::ONE.checked_atanh(), None);
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::MAX.checked_atanh(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_atanh(self) -> Option<Self> {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let atanh = match ln::atanh(x) {
+                        Some(atanh) => atanh,
+                        None => return None,
+                    };
+                    match real::$Inner::to_bits(atanh, FRAC) {
+                        (bits, false) => Some(Self::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Inverse hyperbolic tangent, saturating on overflow.
+
+# Panics
+
+Panics if `self` is outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;&lt;&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                ),
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.5).saturating_atanh(), Fix::from_num(0.5625));
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::MAX.saturating_atanh(), AllFrac::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(AllFrac::MIN.saturating_atanh(), AllFrac::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_atanh(self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let atanh = match ln::atanh(x) {
+                        Some(atanh) => atanh,
+                        None => panic!("atanh of number outside (-1, 1)"),
+                    };
+                    match real::$Inner::to_bits(atanh, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if atanh.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Inverse hyperbolic tangent, wrapping on overflow.
+
+# Panics
+
+Panics if `self` is outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;&lt;&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                ),
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.5).wrapping_atanh(), Fix::from_num(0.5625));
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let (wrapped, overflow) = AllFrac::MAX.overflowing_atanh();
+assert!(overflow);
+assert_eq!(AllFrac::MAX.wrapping_atanh(), wrapped);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_atanh(self) -> Self {
+                    self.overflowing_atanh().0
+                }
+            }
+
+            comment! {
+                "Inverse hyperbolic tangent, panicking on overflow.
+
+# Panics
+
+Panics if `self` is outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;&lt;&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                ),
+                " or if the result does
+not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.5).unwrapped_atanh(), Fix::from_num(0.5625));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::MAX.unwrapped_atanh();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_atanh(self) -> Self {
+                    match self.overflowing_atanh() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing inverse hyperbolic tangent.
+
+Returns a [tuple] of the inverse hyperbolic tangent and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is returned.
+
+# Panics
+
+Panics if `self` is outside the domain ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;&lt;&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                    "0&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1",
+                ),
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(0.5).overflowing_atanh(), (Fix::from_num(0.5625), false));
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert!(AllFrac::MAX.overflowing_atanh().1);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_atanh(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let atanh = match ln::atanh(x) {
+                        Some(atanh) => atanh,
+                        None => panic!("atanh of number outside (-1, 1)"),
+                    };
+                    let (bits, overflow) = real::$Inner::to_bits(atanh, FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Error function.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and the representable
+range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5. In this case,
+overflow can occur for |<i>x</i>|&nbsp;≥&nbsp;0.4769, where
+|erf&nbsp;<i>x</i>|&nbsp;≥&nbsp;0.5.
+
+",
+                },
+                "# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_erf`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.erf(), 0);
+// erf 1 = 0.8427, which is rounded to 0.8125
+assert_eq!(Fix::ONE.erf(), Fix::from_num(0.8125));
+assert_eq!(Fix::MAX.erf(), 1);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-Fix::ONE).erf(), Fix::from_num(-0.8125));
+",
+                },
+                "```
+
+[`wrapping_erf`]: Self::wrapping_erf
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn erf(self) -> Self {
+                    let (val, overflow) = self.overflowing_erf();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked error function. Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_erf(), Some(Fix::from_num(0.8125)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::MAX.checked_erf(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_erf(self) -> Option<Self> {
+                    match self.overflowing_erf() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Error function, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.saturating_erf(), Fix::from_num(0.8125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::MAX.saturating_erf(), AllFrac::MAX);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_erf(self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let r = erf::erf(x);
+                    match real::$Inner::to_bits(r, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if r.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Error function, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.wrapping_erf(), Fix::from_num(0.8125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "type AllFrac = ", stringify!($Self), "<", $n, ">;
+let (wrapped, overflow) = AllFrac::MAX.overflowing_erf();
+assert!(overflow);
+assert_eq!(AllFrac::MAX.wrapping_erf(), wrapped);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_erf(self) -> Self {
+                    self.overflowing_erf().0
+                }
+            }
+
+            comment! {
+                "Error function, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.unwrapped_erf(), Fix::from_num(0.8125));
+```
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::MAX.unwrapped_erf();
+```
+",
+                };
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_erf(self) -> Self {
+                    match self.overflowing_erf() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing error function.
+
+Returns a [tuple] of the error function and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.overflowing_erf(), (Fix::from_num(0.8125), false));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert!(AllFrac::MAX.overflowing_erf().1);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_erf(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let (bits, overflow) = real::$Inner::to_bits(erf::erf(x), FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Complementary error function, 1&nbsp;&minus;&nbsp;erf&nbsp;`self`.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since erfc&nbsp;<i>x</i>&nbsp;&gt;&nbsp;1 for negative <i>x</i> and
+approaches 2, overflow can occur when there are less than three integer bits.
+
+",
+                    "Since `self` is never negative, the result is never greater than 1, so
+overflow can only occur when there are no integer bits.
+
+",
+                ),
+                "# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_erfc`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.erfc(), 1);
+// erfc 1 = 0.1573, which is rounded to 0.1875
+assert_eq!(Fix::ONE.erfc(), Fix::from_num(0.1875));
+assert_eq!(Fix::MAX.erfc(), 0);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// erfc −1 = 1.8427, which is rounded to 1.8125
+assert_eq!((-Fix::ONE).erfc(), Fix::from_num(1.8125));
+",
+                },
+                "```
+
+[`wrapping_erfc`]: Self::wrapping_erfc
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn erfc(self) -> Self {
+                    let (val, overflow) = self.overflowing_erfc();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked complementary error function. Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_erfc(), Some(Fix::from_num(0.1875)));
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+// erfc 0 = 1 does not fit
+assert_eq!(AllFrac::ZERO.checked_erfc(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_erfc(self) -> Option<Self> {
+                    match self.overflowing_erfc() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Complementary error function, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.saturating_erfc(), Fix::from_num(0.1875));
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.saturating_erfc(), AllFrac::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_erfc(self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let r = erf::erfc(x);
+                    match real::$Inner::to_bits(r, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if r.neg => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Complementary error function, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.wrapping_erfc(), Fix::from_num(0.1875));
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let (wrapped, overflow) = AllFrac::ZERO.overflowing_erfc();
+assert!(overflow);
+assert_eq!(AllFrac::ZERO.wrapping_erfc(), wrapped);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_erfc(self) -> Self {
+                    self.overflowing_erfc().0
+                }
+            }
+
+            comment! {
+                "Complementary error function, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.unwrapped_erfc(), Fix::from_num(0.1875));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::ZERO.unwrapped_erfc();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_erfc(self) -> Self {
+                    match self.overflowing_erfc() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing complementary error function.
+
+Returns a [tuple] of the complementary error function and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.overflowing_erfc(), (Fix::from_num(0.1875), false));
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert!(AllFrac::ZERO.overflowing_erfc().1);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_erfc(self) -> (Self, bool) {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    let (bits, overflow) = real::$Inner::to_bits(erf::erfc(x), FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Logistic sigmoid, 1/(1&nbsp;+&nbsp;<i>e</i><sup>&minus;`self`</sup>),
+returned with `RET_FRAC` fractional bits.
+
+The result is an unsigned fixed-point number with the same number of bits as
+`self`, that is a [`", stringify!($USelf), "`]`<RET_FRAC>`, where the number of
+fractional bits `RET_FRAC` is chosen by the caller.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] of the result type for numbers with up to 120 fractional
+bits.
+
+Since 0&nbsp;&lt;&nbsp;sigmoid&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1, the result
+never overflows unless it is rounded up to 1 and there are no integer bits, in
+which case it saturates to [`MAX`][", stringify!($USelf), "::MAX]. This means
+that the result is always valid when `RET_FRAC` is equal to the number of bits,
+for example a sigmoid from `I8F8` into `U0F16`. Since the method saturates,
+there are no checked, saturating, wrapping, unwrapped or overflowing versions.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};"),
+                    concat!("use fixed::", stringify!($Self), ";"),
+                ),
+                "
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.sigmoid::<4>(), 0.5);
+// sigmoid 1 = 0.7311, which is rounded to 0.75
+assert_eq!(Fix::ONE.sigmoid::<4>(), 0.75);
+
+// no integer bits
+type Prob = ", stringify!($USelf), "<", $n, ">;
+let saturated: Prob = Fix::MAX.sigmoid();
+assert_eq!(saturated, Prob::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn sigmoid<const RET_FRAC: i32>(self) -> $USelf<RET_FRAC> {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    match real::$UInner::to_bits(exp::sigmoid(x), RET_FRAC) {
+                        (bits, false) => $USelf::from_bits(bits),
+                        (_, true) => $USelf::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Softplus, ln(1&nbsp;+&nbsp;<i>e</i><sup>`self`</sup>), saturating on
+overflow.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+Since softplus&nbsp;<i>x</i>&nbsp;&gt;&nbsp;0 and the result is
+only slightly larger than <i>x</i> for large <i>x</i>, it can only overflow
+when there are few integer bits, and it then saturates to [`MAX`][Self::MAX].
+Since the method saturates, there are no checked, saturating, wrapping,
+unwrapped or overflowing versions.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// softplus 0 = ln 2 = 0.6931, which is rounded to 0.6875
+assert_eq!(Fix::ZERO.softplus(), Fix::from_num(0.6875));
+// softplus 1 = 1.3133, which is rounded to 1.3125
+assert_eq!(Fix::ONE.softplus(), Fix::from_num(1.3125));
+assert_eq!(Fix::MAX.softplus(), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.softplus(), 0);
+",
+                },
+                "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::MAX.softplus(), AllFrac::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn softplus(self) -> Self {
+                    let x = real::$Inner::from_bits(self.to_bits(), FRAC);
+                    match real::$Inner::to_bits(exp::softplus(x), FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Gaussian error linear unit (GELU),
+<i>x</i>&nbsp;Φ(<i>x</i>)&nbsp;=&nbsp;<i>x</i>/2&nbsp;×&nbsp;(1&nbsp;+&nbsp;erf(<i>x</i>/√2)).
+
+This is the exact GELU using the error function, not its approximation using
+the hyperbolic tangent.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits.
+
+Since |GELU&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|, this method never
+overflows, so there are no checked, saturating, wrapping, unwrapped or
+overflowing versions.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.gelu(), 0);
+// GELU 1 = 0.8413, which is rounded to 0.8125
+assert_eq!(Fix::ONE.gelu(), Fix::from_num(0.8125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// GELU −1 = −0.1587, which is rounded to −0.1875
+assert_eq!((-Fix::ONE).gelu(), Fix::from_num(-0.1875));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn gelu(self) -> Self {
+                    let gelu = erf::gelu(real::$Inner::from_bits(self.to_bits(), FRAC));
+                    Self::from_bits(real::$Inner::to_bits(gelu, FRAC).0)
+                }
+            }
+
+            comment! {
+                "Replaces the values in a slice with their softmax.
+
+Each value <i>x</i><sub><i>i</i></sub> is replaced with
+<i>e</i><sup><i>x</i><sub><i>i</i></sub>&nbsp;&minus;&nbsp;<i>m</i></sup>&nbsp;/&nbsp;Σ<sub><i>j</i></sub>&nbsp;<i>e</i><sup><i>x</i><sub><i>j</i></sub>&nbsp;&minus;&nbsp;<i>m</i></sup>,
+where <i>m</i> is the largest value in the slice. Subtracting <i>m</i> does not
+change the result, but keeps the exponentials from overflowing.
+
+Each result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] for numbers with up to 120 fractional bits. Since each
+result is rounded separately, the results do not necessarily add up to exactly
+1. If a result does not fit, which can only happen when there are no integer
+bits, it saturates to [`MAX`][Self::MAX].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let mut values = [Fix::ZERO, Fix::ONE, Fix::from_num(2)];
+Fix::softmax(&mut values);
+// the softmax is [0.0900, 0.2447, 0.6652], which is rounded to
+// [0.0625, 0.25, 0.6875]
+assert_eq!(values, [Fix::from_num(0.0625), Fix::from_num(0.25), Fix::from_num(0.6875)]);
+```
+";
+                #[inline]
+                pub const fn softmax(values: &mut [Self]) {
+                    if values.is_empty() {
+                        return;
+                    }
+                    let mut max = values[0];
+                    let mut i = 1;
+                    while i < values.len() {
+                        if values[i].to_bits() > max.to_bits() {
+                            max = values[i];
+                        }
+                        i += 1;
+                    }
+                    let max = real::$Inner::from_bits(max.to_bits(), FRAC);
+                    // the sum is at least 1, as the largest value contributes e^0
+                    let mut sum = real::Real::ZERO;
+                    let mut i = 0;
+                    while i < values.len() {
+                        let x = real::$Inner::from_bits(values[i].to_bits(), FRAC);
+                        sum = sum.add(exp::exp(x.sub(max)));
+                        i += 1;
+                    }
+                    let mut i = 0;
+                    while i < values.len() {
+                        let x = real::$Inner::from_bits(values[i].to_bits(), FRAC);
+                        let val = exp::exp(x.sub(max)).div(sum);
+                        values[i] = match real::$Inner::to_bits(val, FRAC) {
+                            (bits, false) => Self::from_bits(bits),
+                            (_, true) => Self::MAX,
+                        };
+                        i += 1;
+                    }
+                }
+            }
+
+            comment! {
+                "Sine using a lookup table with `N` entries, with `self` in radians.
+
+This is faster than [`sin`][Self::sin] but less accurate. The table contains
+sin(τ<i>k</i>/<i>N</i>) for <i>k</i>&nbsp;=&nbsp;0,&nbsp;1,&nbsp;…,&nbsp;<i>N</i>&nbsp;&minus;&nbsp;1
+with 30 fractional bits, so it takes 4<i>N</i> bytes. It is generated at compile
+time from [`TAU`][crate::consts::TAU] and stored in a static, so on embedded
+targets it lives in flash memory. `N` must be a power of two from 4 to 65536;
+other values fail to compile.
+
+The result is interpolated linearly between the two nearest table entries and
+then rounded to the nearest. Before the final rounding, the error is less than
+π<sup>2</sup>/(2<i>N</i><sup>2</sup>)&nbsp;+&nbsp;2<sup>&minus;31</sup>&nbsp;+&nbsp;2<sup>&minus;30</sup>,
+and the argument reduction adds less than
+2<sup>&minus;60</sup>&nbsp;+&nbsp;|<i>x</i>|&nbsp;×&nbsp;2<sup>&minus;127</sup>. The
+error bounds for some table sizes are:
+
+| `N`   | error                                  |
+|-------|----------------------------------------|
+| 16    | 0.0193                                 |
+| 64    | 0.00121                                |
+| 256   | 7.54&nbsp;×&nbsp;10<sup>&minus;5</sup> |
+| 1024  | 4.71&nbsp;×&nbsp;10<sup>&minus;6</sup> |
+| 4096  | 2.96&nbsp;×&nbsp;10<sup>&minus;7</sup> |
+| 65536 | 2.55&nbsp;×&nbsp;10<sup>&minus;9</sup> |
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Overflow occurs when the sine is negative.
+
+"
+                },
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since the interpolated sine never exceeds |sin&nbsp;<i>x</i>| in
+magnitude by more than 2<sup>&minus;29</sup>, this method never overflows.
+
+",
+                    "# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_sin_lut`] instead.
+
+",
+                ),
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.sin_lut::<256>(), 0);
+assert_eq!(Fix::FRAC_PI_2.sin_lut::<256>(), 1);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-Fix::FRAC_PI_2).sin_lut::<256>(), -1);
+",
+                },
+                "```
+
+[`wrapping_sin_lut`]: Self::wrapping_sin_lut
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn sin_lut<const N: usize>(self) -> Self {
+                    let (val, overflow) = self.overflowing_sin_lut::<N>();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked sine using a lookup table, with `self` in radians.
+Returns [`None`] on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since the interpolated sine never exceeds |sin&nbsp;<i>x</i>| in
+magnitude by more than 2<sup>&minus;29</sup>, this method never returns
+[`None`].",
+                    "Overflow occurs when the sine is negative.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.checked_sin_lut::<256>(), Some(Fix::ONE));
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(4).checked_sin_lut::<256>(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sin_lut<const N: usize>(self) -> Option<Self> {
+                    match self.overflowing_sin_lut::<N>() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Sine using a lookup table, with `self` in radians, saturating
+on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since the interpolated sine never exceeds |sin&nbsp;<i>x</i>| in
+magnitude by more than 2<sup>&minus;29</sup>, this method never saturates.",
+                    "Overflow occurs when the sine is negative, in which case
+zero is returned.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.saturating_sin_lut::<256>(), 1);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(4).saturating_sin_lut::<256>(), 0);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sin_lut<const N: usize>(self) -> Self {
+                    let sin = lut::sin::<N>(lut::$Inner::turns::<FRAC>(self.to_bits()));
+                    match lut::$Inner::to_bits(sin, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if sin < 0 => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Sine using a lookup table, with `self` in radians, wrapping on
+overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since the interpolated sine never exceeds |sin&nbsp;<i>x</i>| in
+magnitude by more than 2<sup>&minus;29</sup>, this method never wraps.",
+                    "Overflow occurs when the sine is negative.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.wrapping_sin_lut::<256>(), 1);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "// sin 4 = −0.7568, which is rounded to −0.75
+assert_eq!(
+    Fix::from_num(4).wrapping_sin_lut::<256>(),
+    Fix::ZERO.wrapping_sub(Fix::from_num(0.75))
+);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sin_lut<const N: usize>(self) -> Self {
+                    self.overflowing_sin_lut::<N>().0
+                }
+            }
+
+            comment! {
+                "Sine using a lookup table, with `self` in radians, panicking
+on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since the interpolated sine never exceeds |sin&nbsp;<i>x</i>| in
+magnitude by more than 2<sup>&minus;29</sup>, this method never panics.",
+                    "# Panics
+
+Panics if the sine is negative.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.unwrapped_sin_lut::<256>(), 1);
+```
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::from_num(4).unwrapped_sin_lut::<256>();
+```
+",
+                };
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sin_lut<const N: usize>(self) -> Self {
+                    match self.overflowing_sin_lut::<N>() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing sine using a lookup table, with `self` in radians.
+
+Returns a [tuple] of the sine and a [`bool`] indicating whether an overflow has
+occurred. On overflow, the wrapped value is returned.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Since the interpolated sine never exceeds |sin&nbsp;<i>x</i>| in
+magnitude by more than 2<sup>&minus;29</sup>, this method never overflows.",
+                    "Overflow occurs when the sine is negative.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.overflowing_sin_lut::<256>(), (Fix::ONE, false));
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(
+    Fix::from_num(4).overflowing_sin_lut::<256>(),
+    (Fix::ZERO.wrapping_sub(Fix::from_num(0.75)), true)
+);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sin_lut<const N: usize>(self) -> (Self, bool) {
+                    let sin = lut::sin::<N>(lut::$Inner::turns::<FRAC>(self.to_bits()));
+                    let (bits, overflow) = lut::$Inner::to_bits(sin, FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Cosine using a lookup table with `N` entries, with `self` in radians.
+
+This is faster than [`cos`][Self::cos] but less accurate. The table and the
+error bound are the same as for [`sin_lut`][Self::sin_lut].
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "Overflow can only occur when there are less than two
+integer bits, that is for `", stringify!($Self), "<", $nm1, ">` and
+`", stringify!($Self), "<", $n, ">`, as then 1 cannot be represented.
+
+"
+                    ),
+                    concat!(
+                        "Overflow occurs when the cosine is negative, or for
+`", stringify!($Self), "<", $n, ">` when the result is rounded to 1.
+
+"
+                    ),
+                ),
+                "# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_cos_lut`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.cos_lut::<256>(), 1);
+assert_eq!(Fix::FRAC_PI_2.cos_lut::<256>(), 0);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::PI.cos_lut::<256>(), -1);
+",
+                },
+                "```
+
+[`wrapping_cos_lut`]: Self::wrapping_cos_lut
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn cos_lut<const N: usize>(self) -> Self {
+                    let (val, overflow) = self.overflowing_cos_lut::<N>();
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked cosine using a lookup table, with `self` in radians.
+Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_cos_lut::<256>(), Some(Fix::ONE));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.checked_cos_lut::<256>(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_cos_lut<const N: usize>(self) -> Option<Self> {
+                    match self.overflowing_cos_lut::<N>() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Cosine using a lookup table, with `self` in radians, saturating
+on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.saturating_cos_lut::<256>(), 1);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::PI.saturating_cos_lut::<256>(), 0);
+",
+                },
+                "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.saturating_cos_lut::<256>(), AllFrac::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_cos_lut<const N: usize>(self) -> Self {
+                    let cos = lut::cos::<N>(lut::$Inner::turns::<FRAC>(self.to_bits()));
+                    match lut::$Inner::to_bits(cos, FRAC) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) if cos < 0 => Self::MIN,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Cosine using a lookup table, with `self` in radians, wrapping on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.wrapping_cos_lut::<256>(), 1);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.wrapping_cos_lut::<256>(), 0);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_cos_lut<const N: usize>(self) -> Self {
+                    self.overflowing_cos_lut::<N>().0
+                }
+            }
+
+            comment! {
+                "Cosine using a lookup table, with `self` in radians, panicking
+on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.unwrapped_cos_lut::<256>(), 1);
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+let _overflow = AllFrac::ZERO.unwrapped_cos_lut::<256>();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_cos_lut<const N: usize>(self) -> Self {
+                    match self.overflowing_cos_lut::<N>() {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing cosine using a lookup table, with `self` in radians.
+
+Returns a [tuple] of the cosine and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.overflowing_cos_lut::<256>(), (Fix::ONE, false));
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::ZERO.overflowing_cos_lut::<256>(), (AllFrac::ZERO, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_cos_lut<const N: usize>(self) -> (Self, bool) {
+                    let cos = lut::cos::<N>(lut::$Inner::turns::<FRAC>(self.to_bits()));
+                    let (bits, overflow) = lut::$Inner::to_bits(cos, FRAC);
+                    (Self::from_bits(bits), overflow)
+                }
+            }
+
+            comment! {
+                "Evaluates a polynomial at `self` using Horner’s method.
+
+The coefficients are in ascending order, that is `coeffs[i]` is the coefficient
+of <i>x</i><sup><i>i</i></sup>, so the result is
+`coeffs[0]`&nbsp;+&nbsp;`coeffs[1]`&nbsp;×&nbsp;<i>x</i>&nbsp;+&nbsp;`coeffs[2]`&nbsp;×&nbsp;<i>x</i><sup>2</sup>&nbsp;+&nbsp;….
+The coefficients can have a different [number of fractional bits] from `self`.
+If `coeffs` is empty, the result is zero.
+
+Intermediate values are kept in a 256-bit accumulator with 128 more fractional
+bits than `self`. The product in each step, and any coefficient with more
+fractional bits than that, is truncated to that precision, and only the final
+result is rounded to the nearest. Each truncation adds an error of less than
+[`DELTA`][Self::DELTA]&nbsp;×&nbsp;2<sup>&minus;128</sup>, which is then
+multiplied by the following powers of |<i>x</i>|. So for a polynomial of degree
+<i>d</i> and |<i>x</i>|&nbsp;≤&nbsp;1, the error before the final rounding is
+less than
+(2<i>d</i>&nbsp;+&nbsp;1)&nbsp;×&nbsp;[`DELTA`][Self::DELTA]&nbsp;×&nbsp;2<sup>&minus;128</sup>,
+and the result is correctly rounded unless the exact value is that close to a
+rounding boundary. Evaluation with multiplications of `self` instead truncates
+to the precision of `self` after every step.
+
+Overflow occurs if the result does not fit. Since the magnitude of intermediate
+values must be less than 2<sup>128&nbsp;&minus;&nbsp;`FRAC`</sup>, overflow also
+occurs if an intermediate value is larger, even if the final result would fit.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Coeff = ", stringify!($Self), "<6>;
+// 1 + 0.5x + 0.25x²
+let coeffs = [Coeff::from_num(1), Coeff::from_num(0.5), Coeff::from_num(0.25)];
+assert_eq!(Fix::from_num(1.5).poly_eval(&coeffs), Fix::from_num(2.3125));
+
+// 0.25x + 0.25x² at x = 0.8125 is 0.3682, which is rounded to 0.375, while
+// evaluating with multiplications of Fix truncates twice and gives 0.3125
+let coeffs = [Coeff::ZERO, Coeff::from_num(0.25), Coeff::from_num(0.25)];
+let x = Fix::from_num(0.8125);
+assert_eq!(x.poly_eval(&coeffs), Fix::from_num(0.375));
+let quarter = Fix::from_num(0.25);
+assert_eq!((quarter * x + quarter) * x, Fix::from_num(0.3125));
+```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn poly_eval<const COEFF_FRAC: i32>(
+                    self,
+                    coeffs: &[$Self<COEFF_FRAC>],
+                ) -> Self {
+                    let (val, overflow) = self.overflowing_poly_eval(coeffs);
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
+            comment! {
+                "Checked polynomial evaluation. Evaluates a polynomial at `self`
+using Horner’s method, returning [`None`] on overflow.
+
+See [`poly_eval`][Self::poly_eval] for details.
+
+Overflow occurs if the result does not fit. Since the magnitude of intermediate
+values must be less than 2<sup>128&nbsp;&minus;&nbsp;`FRAC`</sup>, overflow also
+occurs if an intermediate value is larger, even if the final result would fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Coeff = ", stringify!($Self), "<6>;
+let coeffs = [Coeff::from_num(1), Coeff::from_num(0.5), Coeff::from_num(0.25)];
+assert_eq!(
+    Fix::from_num(1.5).checked_poly_eval(&coeffs),
+    Some(Fix::from_num(2.3125))
+);
+
+// MAX² overflows
+let square = [Coeff::ZERO, Coeff::ZERO, Coeff::from_num(1)];
+assert_eq!(Fix::MAX.checked_poly_eval(&square), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_poly_eval<const COEFF_FRAC: i32>(
+                    self,
+                    coeffs: &[$Self<COEFF_FRAC>],
+                ) -> Option<Self> {
+                    match self.overflowing_poly_eval(coeffs) {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Polynomial evaluation, panicking on overflow. Evaluates a
+polynomial at `self` using Horner’s method.
+
+See [`poly_eval`][Self::poly_eval] for details.
+
+Overflow occurs if the result does not fit. Since the magnitude of intermediate
+values must be less than 2<sup>128&nbsp;&minus;&nbsp;`FRAC`</sup>, overflow also
+occurs if an intermediate value is larger, even if the final result would fit.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Coeff = ", stringify!($Self), "<6>;
+let coeffs = [Coeff::from_num(1), Coeff::from_num(0.5), Coeff::from_num(0.25)];
+assert_eq!(Fix::from_num(1.5).unwrapped_poly_eval(&coeffs), Fix::from_num(2.3125));
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Coeff = ", stringify!($Self), "<6>;
+let square = [Coeff::ZERO, Coeff::ZERO, Coeff::from_num(1)];
+let _overflow = Fix::MAX.unwrapped_poly_eval(&square);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_poly_eval<const COEFF_FRAC: i32>(
+                    self,
+                    coeffs: &[$Self<COEFF_FRAC>],
+                ) -> Self {
+                    match self.overflowing_poly_eval(coeffs) {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing polynomial evaluation. Evaluates a polynomial at
+`self` using Horner’s method.
+
+Returns a [tuple] of the result and a [`bool`] indicating whether an overflow
+has occurred. If only the final result overflows, the wrapped value is returned.
+If an intermediate value overflows, the returned value is not meaningful.
+
+See [`poly_eval`][Self::poly_eval] for details.
+
+There are no saturating and wrapping versions of this method, since when an
+intermediate value overflows, neither the saturated nor the wrapped result is
+known.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Coeff = ", stringify!($Self), "<6>;
+let coeffs = [Coeff::from_num(1), Coeff::from_num(0.5), Coeff::from_num(0.25)];
+assert_eq!(
+    Fix::from_num(1.5).overflowing_poly_eval(&coeffs),
+    (Fix::from_num(2.3125), false)
+);
+
+let square = [Coeff::ZERO, Coeff::ZERO, Coeff::from_num(1)];
+assert!(Fix::MAX.overflowing_poly_eval(&square).1);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_poly_eval<const COEFF_FRAC: i32>(
+                    self,
+                    coeffs: &[$Self<COEFF_FRAC>],
+                ) -> (Self, bool) {
+                    let (x_neg, x_abs) = if_signed_unsigned!(
+                        $Signedness,
+                        (self.is_negative(), self.unsigned_abs().to_bits()),
+                        (false, self.to_bits()),
+                    );
+                    // the coefficients are aligned to FRAC + 128 fractional bits
+                    let shift = arith::saturating_add_sub_i32(FRAC, 128, COEFF_FRAC);
+                    let mut acc = dot::Acc::ZERO;
+                    let mut overflow = false;
+                    let mut i = coeffs.len();
+                    while i > 0 {
+                        i -= 1;
+                        let (prod, overflow1) = acc.mul_shr(x_neg, x_abs as u128, FRAC as u32);
+                        let c = coeffs[i];
+                        let (c_neg, c_abs) = if_signed_unsigned!(
+                            $Signedness,
+                            (c.is_negative(), c.unsigned_abs().to_bits()),
+                            (false, c.to_bits()),
+                        );
+                        let (sum, overflow2) = prod.add_shl(c_neg, c_abs as u128, shift);
+                        acc = sum;
+                        overflow |= overflow1 | overflow2;
+                    }
+                    let signed = if_signed_unsigned!($Signedness, true, false);
+                    let (bits, overflow1, _) = acc.narrow_round(128, $n, signed);
+                    (Self::from_bits(bits as $Inner), overflow | overflow1)
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,1695 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Generates the checked, saturating, wrapping, unwrapped and overflowing
+// variants of a binary operation. The result is computed using a wider
+// fixed-point number, and then narrowed, so that the wrapped value and the
+// overflow flag are those of the narrow number and not of the wider number.
+macro_rules! narrow_op {
+    (
+        Self = $Self:ident<$($Param:ident),*>,
+        fn = [$($fn:tt)*],
+        example = $example:literal,
+        {wide, overflowing_narrow, saturating_narrow} =
+            {$wide:ident, $overflowing_narrow:ident, $saturating_narrow:ident},
+        {op, result} = {$op:literal, $result:literal},
+        {checked, saturating, wrapping, unwrapped, overflowing} =
+            {$checked:ident, $saturating:ident, $wrapping:ident, $unwrapped:ident, $overflowing:ident},
+        {lhs, rhs, ans} = {$lhs:literal, $rhs:literal, $ans:literal},
+        {over_lhs, over_rhs, sat, wrap} = {$over_lhs:literal, $over_rhs:literal, $sat:literal, $wrap:literal},
+        panics = $panics:literal,
+    ) => {
+        comment! {
+            "Checked ", $op, ". Returns the ", $result, ", or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), $example, ";
+assert_eq!(
+    Fix::from_num(", $lhs, ").", stringify!($checked), "(Fix::from_num(", $rhs, ")),
+    Some(Fix::from_num(", $ans, "))
+);
+assert_eq!(", $over_lhs, ".", stringify!($checked), "(", $over_rhs, "), None);
+```
+";
+            #[inline]
+            #[must_use]
+            $($fn)* $checked(self, rhs: $Self<$($Param),*>) -> Option<$Self<$($Param),*>> {
+                match self.$wide().$checked(rhs.$wide()) {
+                    Some(wide) => match $Self::$overflowing_narrow(wide) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    },
+                    None => None,
+                }
+            }
+        }
+
+        comment! {
+            "Saturating ", $op, ". Returns the ", $result, ", saturating on overflow.
+",
+            $panics,
+            "
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), $example, ";
+assert_eq!(
+    Fix::from_num(", $lhs, ").", stringify!($saturating), "(Fix::from_num(", $rhs, ")),
+    Fix::from_num(", $ans, ")
+);
+assert_eq!(", $over_lhs, ".", stringify!($saturating), "(", $over_rhs, "), ", $sat, ");
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            $($fn)* $saturating(self, rhs: $Self<$($Param),*>) -> $Self<$($Param),*> {
+                $Self::$saturating_narrow(self.$wide().$saturating(rhs.$wide()))
+            }
+        }
+
+        comment! {
+            "Wrapping ", $op, ". Returns the ", $result, ", wrapping on overflow.
+",
+            $panics,
+            "
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), $example, ";
+assert_eq!(
+    Fix::from_num(", $lhs, ").", stringify!($wrapping), "(Fix::from_num(", $rhs, ")),
+    Fix::from_num(", $ans, ")
+);
+assert_eq!(", $over_lhs, ".", stringify!($wrapping), "(", $over_rhs, "), ", $wrap, ");
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            $($fn)* $wrapping(self, rhs: $Self<$($Param),*>) -> $Self<$($Param),*> {
+                self.$overflowing(rhs).0
+            }
+        }
+
+        comment! {
+            "Unwrapped ", $op, ". Returns the ", $result, ", panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+",
+            $panics,
+            "
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), $example, ";
+assert_eq!(
+    Fix::from_num(", $lhs, ").", stringify!($unwrapped), "(Fix::from_num(", $rhs, ")),
+    Fix::from_num(", $ans, ")
+);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), $example, ";
+let _overflow = ", $over_lhs, ".", stringify!($unwrapped), "(", $over_rhs, ");
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            $($fn)* $unwrapped(self, rhs: $Self<$($Param),*>) -> $Self<$($Param),*> {
+                match self.$overflowing(rhs) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+
+        comment! {
+            "Overflowing ", $op, ".
+
+Returns a [tuple] of the ", $result, " and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+",
+            $panics,
+            "
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), $example, ";
+assert_eq!(
+    Fix::from_num(", $lhs, ").", stringify!($overflowing), "(Fix::from_num(", $rhs, ")),
+    (Fix::from_num(", $ans, "), false)
+);
+assert_eq!(", $over_lhs, ".", stringify!($overflowing), "(", $over_rhs, "), (", $wrap, ", true));
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            $($fn)* $overflowing(self, rhs: $Self<$($Param),*>) -> ($Self<$($Param),*>, bool) {
+                let (wide, wide_overflow) = self.$wide().$overflowing(rhs.$wide());
+                let (ans, overflow) = $Self::$overflowing_narrow(wide);
+                (ans, wide_overflow || overflow)
+            }
+        }
+    };
+}
+
+macro_rules! narrow_op_trait {
+    (
+        $Self:ident<$($Param:ident),*>, where [$($bound:tt)*];
+        $Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $overflowing:ident
+    ) => {
+        impl<$(const $Param: i32),*> $Op for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            type Output = $Self<$($Param),*>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, rhs: $Self<$($Param),*>) -> $Self<$($Param),*> {
+                let (ans, overflow) = self.$overflowing(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<$(const $Param: i32),*> $OpAssign for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, rhs: $Self<$($Param),*>) {
+                *self = <$Self<$($Param),*> as $Op>::$op(*self, rhs);
+            }
+        }
+    };
+}
+
+// Formats using the fixed-point number returned by the `$wide` method.
+macro_rules! narrow_fmt {
+    ($Self:ident<$($Param:ident),*>, where [$($bound:tt)*], $wide:ident;) => {};
+    (
+        $Self:ident<$($Param:ident),*>, where [$($bound:tt)*], $wide:ident;
+        $Trait:ident $($Rest:ident)*
+    ) => {
+        impl<$(const $Param: i32),*> $Trait for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                $Trait::fmt(&self.$wide(), f)
+            }
+        }
+
+        narrow_fmt! { $Self<$($Param),*>, where [$($bound)*], $wide; $($Rest)* }
+    };
+}
+
+// Implements a method of the Fixed trait by calling the method with the same
+// name on the underlying fixed-point number. Except for methods of kind
+// `fixed`, the result is then narrowed, and the kind selects how overflow of
+// the narrowing is handled. Methods of kind `inherent` call the inherent
+// method with the same name instead.
+macro_rules! narrow_fixed_method {
+    (inherent fn $method:ident(self $(, $param:ident: $Param:ty)*) -> $Ret:ty) => {
+        narrow_fixed_method! { inherent fn $method[](self $(, $param: $Param)*) -> $Ret }
+    };
+    (inherent fn $method:ident[$($Gen:tt)*](self $(, $param:ident: $Param:ty)*) -> $Ret:ty) => {
+        #[inline]
+        #[track_caller]
+        fn $method<$($Gen)*>(self $(, $param: $Param)*) -> $Ret {
+            Self::$method(self $(, $param)*)
+        }
+    };
+    (inherent fn $method:ident[$($Gen:tt)*]($($param:ident: $Param:ty),*) -> $Ret:ty) => {
+        #[inline]
+        #[track_caller]
+        fn $method<$($Gen)*>($($param: $Param),*) -> $Ret {
+            Self::$method($($param),*)
+        }
+    };
+    (fixed $($rest:tt)*) => {
+        narrow_fixed_method! { @direct $($rest)* }
+    };
+    (plain $($rest:tt)*) => {
+        narrow_fixed_method! { @narrow Self, from_fixed_bits; $($rest)* }
+    };
+    (checked $($rest:tt)*) => {
+        narrow_fixed_method! { @narrow Option<Self>, checked_from_fixed_bits; $($rest)* }
+    };
+    (saturating $($rest:tt)*) => {
+        narrow_fixed_method! { @narrow Self, saturating_from_fixed_bits; $($rest)* }
+    };
+    (wrapping $($rest:tt)*) => {
+        narrow_fixed_method! { @narrow Self, wrapping_from_fixed_bits; $($rest)* }
+    };
+    (unwrapped $($rest:tt)*) => {
+        narrow_fixed_method! { @narrow Self, unwrapped_from_fixed_bits; $($rest)* }
+    };
+    (overflowing $($rest:tt)*) => {
+        narrow_fixed_method! { @narrow (Self, bool), overflowing_from_fixed_result; $($rest)* }
+    };
+    (
+        @direct fn $method:ident[$($Gen:tt)*](self $(, $param:ident: $Param:ty => $arg:expr)*)
+            -> $Ret:ty
+    ) => {
+        #[inline]
+        #[track_caller]
+        fn $method<$($Gen)*>(self $(, $param: $Param)*) -> $Ret {
+            self.fixed().$method($($arg),*)
+        }
+    };
+    (
+        @narrow $Ret:ty, $narrow:ident;
+        fn $method:ident[$($Gen:tt)*](self $(, $param:ident: $Param:ty => $arg:expr)*)
+    ) => {
+        #[inline]
+        #[track_caller]
+        fn $method<$($Gen)*>(self $(, $param: $Param)*) -> $Ret {
+            Self::$narrow(self.fixed().$method($($arg),*))
+        }
+    };
+}
+
+// Implements the plain, checked, wrapping and unwrapped variants of a method
+// from its overflowing variant, where the plain variant panics on overflow
+// only when debug assertions are enabled.
+macro_rules! narrow_from_overflowing {
+    (
+        $overflowing:ident($($param:ident: $Param:ty),*) => {
+            $plain:ident, $checked:ident, $wrapping:ident, $unwrapped:ident
+        }
+    ) => {
+        #[inline]
+        #[track_caller]
+        fn $plain(self $(, $param: $Param)*) -> Self {
+            let (ans, overflow) = self.$overflowing($($param),*);
+            debug_assert!(!overflow, "overflow");
+            ans
+        }
+        #[inline]
+        fn $checked(self $(, $param: $Param)*) -> Option<Self> {
+            match self.$overflowing($($param),*) {
+                (ans, false) => Some(ans),
+                (_, true) => None,
+            }
+        }
+        #[inline]
+        fn $wrapping(self $(, $param: $Param)*) -> Self {
+            self.$overflowing($($param),*).0
+        }
+        #[inline]
+        #[track_caller]
+        fn $unwrapped(self $(, $param: $Param)*) -> Self {
+            match self.$overflowing($($param),*) {
+                (ans, false) => ans,
+                (_, true) => panic!("overflow"),
+            }
+        }
+    };
+}
+
+// The bits returned by to_bits outside the low NBITS bits are sign or zero
+// extension, and bitwise operations preserve that, so the result is stored
+// directly.
+macro_rules! narrow_bit_op {
+    (
+        $Self:ident<$($Param:ident),*>, where [$($bound:tt)*];
+        $Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $tok:tt
+    ) => {
+        impl<$(const $Param: i32),*> $Op for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            type Output = $Self<$($Param),*>;
+            #[inline]
+            fn $op(self, rhs: $Self<$($Param),*>) -> $Self<$($Param),*> {
+                $Self {
+                    bits: self.to_bits() $tok rhs.to_bits(),
+                }
+            }
+        }
+
+        impl<$(const $Param: i32),*> $OpAssign for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: $Self<$($Param),*>) {
+                *self = <$Self<$($Param),*> as $Op>::$op(*self, rhs);
+            }
+        }
+    };
+}
+
+// Shifts by any primitive integer. Like for the primitive integers, shifting
+// by a negative amount or by NBITS bits or more overflows.
+macro_rules! narrow_shift {
+    (
+        $Self:ident<$($Param:ident),*>, where [$($bound:tt)*];
+        $Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $overflowing:ident;
+    ) => {};
+    (
+        $Self:ident<$($Param:ident),*>, where [$($bound:tt)*];
+        $Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $overflowing:ident;
+        $Rhs:ident $($Rest:ident)*
+    ) => {
+        impl<$(const $Param: i32),*> $Op<$Rhs> for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            type Output = $Self<$($Param),*>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, rhs: $Rhs) -> $Self<$($Param),*> {
+                let (ans, overflow) = match u32::try_from(rhs) {
+                    Ok(rhs) => Fixed::$overflowing(self, rhs),
+                    Err(_) => (Fixed::$overflowing(self, rhs.wrapping_cast()).0, true),
+                };
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<$(const $Param: i32),*> $OpAssign<$Rhs> for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, rhs: $Rhs) {
+                *self = <$Self<$($Param),*> as $Op<$Rhs>>::$op(*self, rhs);
+            }
+        }
+
+        narrow_shift! {
+            $Self<$($Param),*>, where [$($bound)*];
+            $Op $op, $OpAssign $op_assign, $overflowing;
+            $($Rest)*
+        }
+    };
+}
+
+// Compares with primitives by comparing the underlying fixed-point number.
+macro_rules! narrow_cmp_prim {
+    ($Self:ident<$($Param:ident),*>, where [$($bound:tt)*];) => {};
+    ($Self:ident<$($Param:ident),*>, where [$($bound:tt)*]; $Prim:ident $($Rest:ident)*) => {
+        impl<$(const $Param: i32),*> PartialEq<$Prim> for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn eq(&self, rhs: &$Prim) -> bool {
+                self.fixed() == *rhs
+            }
+        }
+
+        impl<$(const $Param: i32),*> PartialOrd<$Prim> for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Prim) -> Option<Ordering> {
+                self.fixed().partial_cmp(rhs)
+            }
+        }
+
+        narrow_cmp_prim! { $Self<$($Param),*>, where [$($bound)*]; $($Rest)* }
+    };
+}
+
+// Implements the Fixed trait together with FixedSigned or FixedUnsigned, the
+// traits that are required by Fixed, and the conversion traits for a number
+// that has NBITS bits stored in the wider underlying integer `bits`. Any bit
+// pattern is valid; the bits outside the low NBITS bits are ignored, and to_bits
+// returns the low NBITS bits sign or zero extended.
+//
+// Most operations are computed using the underlying fixed-point number, which
+// has the same underlying integer, and then narrowed. The type has to provide
+// the following:
+//
+//   * the constant NBITS, and the constants ZERO, DELTA, MIN and MAX;
+//   * the methods overflowing_from_bits, wrapping_from_bits and to_bits;
+//   * the methods fixed, which returns the underlying fixed-point number, and
+//     overflowing_from_fixed_bits and saturating_from_fixed_bits, which narrow
+//     it;
+//   * the inherent conversion, arithmetic and negation methods.
+//
+// The byte array methods of the Fixed trait can be passed in `bytes`; if
+// `bytes` is empty, the default methods of the trait are used.
+macro_rules! narrow_fixed {
+    (
+        Self = $Self:ident<$($Param:ident),*>, where [$($bound:tt)*];
+        Signedness = $Signedness:ident,
+        {Bits, NonZeroBits, Fixed} = {$Bits:ty, $NonZeroBits:ty, $Fixed:ty},
+        {Signed, Unsigned} = {$SignedSelf:ty, $UnsignedSelf:ty},
+        {TRY_ONE, TRY_NEG_ONE} = {$try_one:expr, $try_neg_one:expr},
+        bytes = {$($bytes:tt)*},
+    ) => {
+        impl<$(const $Param: i32),*> $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            #[track_caller]
+            fn from_fixed_bits(fixed: $Fixed) -> $Self<$($Param),*> {
+                let (ans, overflow) = $Self::overflowing_from_fixed_bits(fixed);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+
+            #[inline]
+            fn checked_from_fixed_bits(fixed: Option<$Fixed>) -> Option<$Self<$($Param),*>> {
+                match $Self::overflowing_from_fixed_bits(fixed?) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            #[inline]
+            fn wrapping_from_fixed_bits(fixed: $Fixed) -> $Self<$($Param),*> {
+                $Self::overflowing_from_fixed_bits(fixed).0
+            }
+
+            #[inline]
+            #[track_caller]
+            fn unwrapped_from_fixed_bits(fixed: $Fixed) -> $Self<$($Param),*> {
+                match $Self::overflowing_from_fixed_bits(fixed) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+
+            // Like overflowing_from_fixed_bits, but also includes the overflow
+            // of the operation that computed the underlying fixed-point number.
+            #[inline]
+            fn overflowing_from_fixed_result(
+                (fixed, fixed_overflow): ($Fixed, bool),
+            ) -> ($Self<$($Param),*>, bool) {
+                let (ans, overflow) = $Self::overflowing_from_fixed_bits(fixed);
+                (ans, fixed_overflow || overflow)
+            }
+
+            // Returns the low NBITS bits, which are used by the bit
+            // manipulation methods of the Fixed trait.
+            #[inline]
+            fn masked_bits(self) -> u128 {
+                let bits: u128 = self.to_bits().wrapping_cast();
+                bits & (u128::MAX >> (128 - $Self::<$($Param),*>::NBITS))
+            }
+
+            #[inline]
+            fn wrapping_from_masked_bits(bits: u128) -> $Self<$($Param),*> {
+                $Self::wrapping_from_bits(WrappingCastFrom::wrapping_cast_from(bits))
+            }
+        }
+
+        impl<$(const $Param: i32),*> Debug for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                Debug::fmt(&self.fixed(), f)
+            }
+        }
+
+        impl<$(const $Param: i32),*> FromFixed for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            /// Converts a fixed-point number.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics; if wrapping is required use
+            /// [`wrapping_from_fixed`] instead.
+            ///
+            /// [`wrapping_from_fixed`]: FromFixed::wrapping_from_fixed
+            #[inline]
+            #[track_caller]
+            fn from_fixed<F: Fixed>(src: F) -> Self {
+                let (ans, overflow) = FromFixed::overflowing_from_fixed(src);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+
+            /// Converts a fixed-point number if it fits, otherwise returns [`None`].
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            #[inline]
+            fn checked_from_fixed<F: Fixed>(src: F) -> Option<Self> {
+                match FromFixed::overflowing_from_fixed(src) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Converts a fixed-point number, saturating if it does not fit.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            #[inline]
+            fn saturating_from_fixed<F: Fixed>(src: F) -> Self {
+                $Self::saturating_from_fixed_bits(FromFixed::saturating_from_fixed(src))
+            }
+
+            /// Converts a fixed-point number, wrapping if it does not fit.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            #[inline]
+            fn wrapping_from_fixed<F: Fixed>(src: F) -> Self {
+                $Self::overflowing_from_fixed_bits(FromFixed::wrapping_from_fixed(src)).0
+            }
+
+            /// Converts a fixed-point number.
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            #[inline]
+            fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool) {
+                let (fixed, fixed_overflow) = FromFixed::overflowing_from_fixed(src);
+                let (ans, overflow) = $Self::overflowing_from_fixed_bits(fixed);
+                (ans, fixed_overflow || overflow)
+            }
+
+            /// Converts a fixed-point number, rounding according to the
+            /// given [`RoundingMode`].
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            #[inline]
+            fn overflowing_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> (Self, bool) {
+                let (fixed, fixed_overflow) = FromFixed::overflowing_from_fixed_round(src, mode);
+                let (ans, overflow) = $Self::overflowing_from_fixed_bits(fixed);
+                (ans, fixed_overflow || overflow)
+            }
+        }
+
+        impl<$(const $Param: i32),*> ToFixed for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            /// Converts a fixed-point number.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics; if wrapping is required use
+            /// [`wrapping_to_fixed`] instead.
+            ///
+            /// [`wrapping_to_fixed`]: ToFixed::wrapping_to_fixed
+            #[inline]
+            #[track_caller]
+            fn to_fixed<F: Fixed>(self) -> F {
+                self.fixed().to_fixed()
+            }
+
+            /// Converts a fixed-point number if it fits, otherwise returns [`None`].
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            #[inline]
+            fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
+                self.fixed().checked_to_fixed()
+            }
+
+            /// Converts a fixed-point number, saturating if it does not fit.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            #[inline]
+            fn saturating_to_fixed<F: Fixed>(self) -> F {
+                self.fixed().saturating_to_fixed()
+            }
+
+            /// Converts a fixed-point number, wrapping if it does not fit.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            #[inline]
+            fn wrapping_to_fixed<F: Fixed>(self) -> F {
+                self.fixed().wrapping_to_fixed()
+            }
+
+            /// Converts a fixed-point number.
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+            #[inline]
+            fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
+                self.fixed().overflowing_to_fixed()
+            }
+
+            /// Converts a fixed-point number, rounding according to the
+            /// given [`RoundingMode`].
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            #[inline]
+            fn overflowing_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> (F, bool) {
+                self.fixed().overflowing_to_fixed_round(mode)
+            }
+
+            /// Converts a fixed-point number, rounding stochastically.
+            ///
+            /// Returns a [tuple] of the value and a [`bool`] indicating whether
+            /// an overflow has occurred. On overflow, the wrapped value is
+            /// returned.
+            #[inline]
+            fn overflowing_to_fixed_stochastic<F: Fixed, R: RandomBits + ?Sized>(
+                self,
+                rng: &mut R,
+            ) -> (F, bool) {
+                self.fixed().overflowing_to_fixed_stochastic(rng)
+            }
+        }
+
+        impl<$(const $Param: i32),*> Sealed for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            fn sum_prods(add: &[(Self, Self)], sub: &[(Self, Self)]) -> (Self, bool, bool) {
+                let mut acc = Acc::ZERO;
+                for &(a, b) in add {
+                    let (a, b) = (a.to_bits().wrapping_cast(), b.to_bits().wrapping_cast());
+                    acc = if_signed_unsigned!(
+                        $Signedness,
+                        acc.add_prod_i128(a, b, false),
+                        acc.add_prod_u128(a, b, false),
+                    );
+                }
+                for &(a, b) in sub {
+                    let (a, b) = (a.to_bits().wrapping_cast(), b.to_bits().wrapping_cast());
+                    acc = if_signed_unsigned!(
+                        $Signedness,
+                        acc.add_prod_i128(a, b, true),
+                        acc.add_prod_u128(a, b, true),
+                    );
+                }
+                let signed = if_signed_unsigned!($Signedness, true, false);
+                let nbits = $Self::<$($Param),*>::NBITS;
+                let (bits, overflow, neg) = acc.narrow(FRAC, nbits, signed);
+                ($Self::wrapping_from_masked_bits(bits), overflow, neg)
+            }
+        }
+
+        impl<$(const $Param: i32),*> FixedOptionalArbitrary for $Self<$($Param),*> where
+            $($bound)*
+        {
+        }
+        impl<$(const $Param: i32),*> FixedOptionalBorsh for $Self<$($Param),*> where
+            $($bound)*
+        {
+        }
+        impl<$(const $Param: i32),*> FixedOptionalNum for $Self<$($Param),*> where
+            $($bound)*
+        {
+        }
+        impl<$(const $Param: i32),*> FixedOptionalSerde for $Self<$($Param),*> where
+            $($bound)*
+        {
+        }
+
+        impl<$(const $Param: i32),*> Fixed for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            type Bits = $Bits;
+            type NonZeroBits = $NonZeroBits;
+            type Signed = $SignedSelf;
+            type Unsigned = $UnsignedSelf;
+            const ZERO: Self = Self::ZERO;
+            const TRY_ONE: Option<Self> = $try_one;
+            const DELTA: Self = Self::DELTA;
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
+            const IS_SIGNED: bool = if_signed_unsigned!($Signedness, true, false);
+            const INT_BITS: i32 = $Self::<$($Param),*>::NBITS as i32 - FRAC;
+            const FRAC_BITS: i32 = FRAC;
+
+            #[inline]
+            #[track_caller]
+            fn from_bits(bits: Self::Bits) -> Self {
+                Self::from_bits(bits)
+            }
+            narrow_fixed_method! { inherent fn to_bits(self) -> Self::Bits }
+
+            // The byte order methods work on the whole underlying integer.
+            #[inline]
+            fn from_be(fixed: Self) -> Self {
+                $Self { bits: Fixed::from_be(fixed.fixed()).to_bits() }
+            }
+            #[inline]
+            fn from_le(fixed: Self) -> Self {
+                $Self { bits: Fixed::from_le(fixed.fixed()).to_bits() }
+            }
+            #[inline]
+            fn to_be(self) -> Self {
+                $Self { bits: self.fixed().to_be().to_bits() }
+            }
+            #[inline]
+            fn to_le(self) -> Self {
+                $Self { bits: self.fixed().to_le().to_bits() }
+            }
+            #[inline]
+            fn swap_bytes(self) -> Self {
+                $Self { bits: self.fixed().swap_bytes().to_bits() }
+            }
+            $($bytes)*
+            narrow_fixed_method! { inherent fn from_num[Src: ToFixed](src: Src) -> Self }
+            narrow_fixed_method! { inherent fn to_num[Dst: FromFixed](self) -> Dst }
+            narrow_fixed_method! { inherent fn checked_from_num[Src: ToFixed](src: Src) -> Option<Self> }
+            narrow_fixed_method! { inherent fn checked_to_num[Dst: FromFixed](self) -> Option<Dst> }
+            narrow_fixed_method! { inherent fn saturating_from_num[Src: ToFixed](src: Src) -> Self }
+            narrow_fixed_method! { inherent fn saturating_to_num[Dst: FromFixed](self) -> Dst }
+            narrow_fixed_method! { inherent fn wrapping_from_num[Src: ToFixed](src: Src) -> Self }
+            narrow_fixed_method! { inherent fn wrapping_to_num[Dst: FromFixed](self) -> Dst }
+            narrow_fixed_method! { inherent fn unwrapped_from_num[Src: ToFixed](src: Src) -> Self }
+            narrow_fixed_method! { inherent fn unwrapped_to_num[Dst: FromFixed](self) -> Dst }
+            narrow_fixed_method! {
+                inherent fn overflowing_from_num[Src: ToFixed](src: Src) -> (Self, bool)
+            }
+            narrow_fixed_method! {
+                inherent fn overflowing_to_num[Dst: FromFixed](self) -> (Dst, bool)
+            }
+            #[inline]
+            #[track_caller]
+            fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self {
+                $Self::from_fixed_bits(Fixed::from_num_round(src, mode))
+            }
+            #[inline]
+            fn checked_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Option<Self> {
+                $Self::checked_from_fixed_bits(Fixed::checked_from_num_round(src, mode))
+            }
+            #[inline]
+            fn overflowing_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> (Self, bool) {
+                $Self::overflowing_from_fixed_result(Fixed::overflowing_from_num_round(src, mode))
+            }
+            narrow_fixed_method! {
+                fixed fn to_num_round[Dst: FromFixed](self, mode: RoundingMode => mode) -> Dst
+            }
+            narrow_fixed_method! {
+                fixed fn checked_to_num_round[Dst: FromFixed](self, mode: RoundingMode => mode)
+                    -> Option<Dst>
+            }
+            narrow_fixed_method! {
+                fixed fn saturating_to_num_round[Dst: FromFixed](self, mode: RoundingMode => mode)
+                    -> Dst
+            }
+            narrow_fixed_method! {
+                fixed fn wrapping_to_num_round[Dst: FromFixed](self, mode: RoundingMode => mode)
+                    -> Dst
+            }
+            narrow_fixed_method! {
+                fixed fn unwrapped_to_num_round[Dst: FromFixed](self, mode: RoundingMode => mode)
+                    -> Dst
+            }
+            narrow_fixed_method! {
+                fixed fn overflowing_to_num_round[Dst: FromFixed](self, mode: RoundingMode => mode)
+                    -> (Dst, bool)
+            }
+            #[inline]
+            #[track_caller]
+            fn from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+                src: Src,
+                rng: &mut R,
+            ) -> Self {
+                $Self::from_fixed_bits(Fixed::from_num_stochastic(src, rng))
+            }
+            #[inline]
+            fn checked_from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+                src: Src,
+                rng: &mut R,
+            ) -> Option<Self> {
+                $Self::checked_from_fixed_bits(Fixed::checked_from_num_stochastic(src, rng))
+            }
+            #[inline]
+            fn overflowing_from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+                src: Src,
+                rng: &mut R,
+            ) -> (Self, bool) {
+                $Self::overflowing_from_fixed_result(Fixed::overflowing_from_num_stochastic(src, rng))
+            }
+
+            narrow_fixed_method! {
+                fixed fn requantize[const DST_FRAC: i32](self, mode: RoundingMode => mode)
+                    -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>
+            }
+            narrow_fixed_method! {
+                fixed fn checked_requantize[const DST_FRAC: i32](self, mode: RoundingMode => mode)
+                    -> Option<<Self::Bits as FixedBits>::Fixed<DST_FRAC>>
+            }
+            narrow_fixed_method! {
+                fixed fn saturating_requantize[const DST_FRAC: i32](self, mode: RoundingMode => mode)
+                    -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>
+            }
+            narrow_fixed_method! {
+                fixed fn wrapping_requantize[const DST_FRAC: i32](self, mode: RoundingMode => mode)
+                    -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>
+            }
+            narrow_fixed_method! {
+                fixed fn unwrapped_requantize[const DST_FRAC: i32](self, mode: RoundingMode => mode)
+                    -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>
+            }
+            narrow_fixed_method! {
+                fixed fn overflowing_requantize[const DST_FRAC: i32](self, mode: RoundingMode => mode)
+                    -> (<Self::Bits as FixedBits>::Fixed<DST_FRAC>, bool)
+            }
+
+            narrow_fixed_method! { wrapping fn int[](self) }
+            narrow_fixed_method! { wrapping fn frac[](self) }
+            narrow_fixed_method! { wrapping fn round_to_zero[](self) }
+            narrow_fixed_method! { plain fn ceil[](self) }
+            narrow_fixed_method! { plain fn floor[](self) }
+            narrow_fixed_method! { plain fn round[](self) }
+            narrow_fixed_method! { plain fn round_ties_to_even[](self) }
+            narrow_fixed_method! { checked fn checked_ceil[](self) }
+            narrow_fixed_method! { checked fn checked_floor[](self) }
+            narrow_fixed_method! { checked fn checked_round[](self) }
+            narrow_fixed_method! { checked fn checked_round_ties_to_even[](self) }
+            narrow_fixed_method! { saturating fn saturating_ceil[](self) }
+            narrow_fixed_method! { saturating fn saturating_floor[](self) }
+            narrow_fixed_method! { saturating fn saturating_round[](self) }
+            narrow_fixed_method! { saturating fn saturating_round_ties_to_even[](self) }
+            narrow_fixed_method! { wrapping fn wrapping_ceil[](self) }
+            narrow_fixed_method! { wrapping fn wrapping_floor[](self) }
+            narrow_fixed_method! { wrapping fn wrapping_round[](self) }
+            narrow_fixed_method! { wrapping fn wrapping_round_ties_to_even[](self) }
+            narrow_fixed_method! { unwrapped fn unwrapped_ceil[](self) }
+            narrow_fixed_method! { unwrapped fn unwrapped_floor[](self) }
+            narrow_fixed_method! { unwrapped fn unwrapped_round[](self) }
+            narrow_fixed_method! { unwrapped fn unwrapped_round_ties_to_even[](self) }
+            narrow_fixed_method! { overflowing fn overflowing_ceil[](self) }
+            narrow_fixed_method! { overflowing fn overflowing_floor[](self) }
+            narrow_fixed_method! { overflowing fn overflowing_round[](self) }
+            narrow_fixed_method! { overflowing fn overflowing_round_ties_to_even[](self) }
+
+            // The bit manipulation methods only consider the low NBITS bits, so
+            // they are the same as for a primitive integer with NBITS bits.
+            #[inline]
+            fn count_ones(self) -> u32 {
+                self.masked_bits().count_ones()
+            }
+            #[inline]
+            fn count_zeros(self) -> u32 {
+                $Self::<$($Param),*>::NBITS - self.count_ones()
+            }
+            #[inline]
+            fn leading_ones(self) -> u32 {
+                (self.masked_bits() << (128 - $Self::<$($Param),*>::NBITS)).leading_ones()
+            }
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                self.masked_bits().leading_zeros() - (128 - $Self::<$($Param),*>::NBITS)
+            }
+            #[inline]
+            fn trailing_ones(self) -> u32 {
+                self.masked_bits().trailing_ones()
+            }
+            #[inline]
+            fn trailing_zeros(self) -> u32 {
+                self.masked_bits().trailing_zeros().min($Self::<$($Param),*>::NBITS)
+            }
+            narrow_fixed_method! { fixed fn int_log2[](self) -> i32 }
+            narrow_fixed_method! { fixed fn checked_int_log2[](self) -> Option<i32> }
+            #[inline]
+            fn reverse_bits(self) -> Self {
+                let reversed = self.masked_bits().reverse_bits();
+                $Self::wrapping_from_masked_bits(reversed >> (128 - $Self::<$($Param),*>::NBITS))
+            }
+            #[inline]
+            fn rotate_left(self, n: u32) -> Self {
+                let nbits = $Self::<$($Param),*>::NBITS;
+                let (bits, n) = (self.masked_bits(), n % nbits);
+                if n == 0 {
+                    return $Self::wrapping_from_masked_bits(bits);
+                }
+                $Self::wrapping_from_masked_bits((bits << n) | (bits >> (nbits - n)))
+            }
+            #[inline]
+            fn rotate_right(self, n: u32) -> Self {
+                let nbits = $Self::<$($Param),*>::NBITS;
+                let (bits, n) = (self.masked_bits(), n % nbits);
+                if n == 0 {
+                    return $Self::wrapping_from_masked_bits(bits);
+                }
+                $Self::wrapping_from_masked_bits((bits >> n) | (bits << (nbits - n)))
+            }
+            narrow_fixed_method! { fixed fn is_zero[](self) -> bool }
+            narrow_fixed_method! { plain fn dist[](self, other: Self => other.fixed()) }
+            #[inline]
+            fn abs_diff(self, other: Self) -> $UnsignedSelf {
+                <$UnsignedSelf as FromFixed>::wrapping_from_fixed(self.fixed().abs_diff(other.fixed()))
+            }
+            narrow_fixed_method! { plain fn mean[](self, other: Self => other.fixed()) }
+            narrow_fixed_method! { plain fn hypot[](self, other: Self => other.fixed()) }
+            narrow_fixed_method! {
+                fixed fn atan2[const RET_FRAC: i32](self, other: Self => other.fixed())
+                    -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            narrow_fixed_method! { plain fn next_multiple_of[](self, other: Self => other.fixed()) }
+            narrow_fixed_method! {
+                plain fn mul_stochastic[R: RandomBits + ?Sized](
+                    self,
+                    rhs: Self => rhs.fixed(),
+                    rng: &mut R => rng
+                )
+            }
+            narrow_fixed_method! {
+                plain fn mul_add[const MUL_FRAC: i32](
+                    self,
+                    mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC> => mul,
+                    add: Self => add.fixed()
+                )
+            }
+            narrow_fixed_method! {
+                plain fn add_prod[const A_FRAC: i32, const B_FRAC: i32](
+                    self,
+                    a: <Self::Bits as FixedBits>::Fixed<A_FRAC> => a,
+                    b: <Self::Bits as FixedBits>::Fixed<B_FRAC> => b
+                )
+            }
+            #[inline]
+            #[track_caller]
+            fn mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
+                &mut self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) {
+                *self = Fixed::add_prod(*self, a, b);
+            }
+            narrow_fixed_method! { plain fn rem_euclid[](self, rhs: Self => rhs.fixed()) }
+
+            narrow_fixed_method! { inherent fn checked_neg(self) -> Option<Self> }
+            narrow_fixed_method! { inherent fn checked_add(self, rhs: Self) -> Option<Self> }
+            narrow_fixed_method! { inherent fn checked_sub(self, rhs: Self) -> Option<Self> }
+            narrow_fixed_method! { inherent fn checked_mul(self, rhs: Self) -> Option<Self> }
+            narrow_fixed_method! {
+                checked fn checked_mul_stochastic[R: RandomBits + ?Sized](
+                    self,
+                    rhs: Self => rhs.fixed(),
+                    rng: &mut R => rng
+                )
+            }
+            narrow_fixed_method! { checked fn checked_rem[](self, rhs: Self => rhs.fixed()) }
+            narrow_fixed_method! {
+                checked fn checked_next_multiple_of[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                checked fn checked_mul_add[const MUL_FRAC: i32](
+                    self,
+                    mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC> => mul,
+                    add: Self => add.fixed()
+                )
+            }
+            narrow_fixed_method! {
+                checked fn checked_add_prod[const A_FRAC: i32, const B_FRAC: i32](
+                    self,
+                    a: <Self::Bits as FixedBits>::Fixed<A_FRAC> => a,
+                    b: <Self::Bits as FixedBits>::Fixed<B_FRAC> => b
+                )
+            }
+            #[inline]
+            fn checked_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
+                &mut self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) -> Option<()> {
+                *self = Fixed::checked_add_prod(*self, a, b)?;
+                Some(())
+            }
+            narrow_fixed_method! { checked fn checked_rem_euclid[](self, rhs: Self => rhs.fixed()) }
+            narrow_fixed_method! { checked fn checked_mul_int[](self, rhs: Self::Bits => rhs) }
+            narrow_fixed_method! { checked fn checked_div_int[](self, rhs: Self::Bits => rhs) }
+            #[inline]
+            fn checked_shl(self, rhs: u32) -> Option<Self> {
+                match self.overflowing_shl(rhs) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+            #[inline]
+            fn checked_shr(self, rhs: u32) -> Option<Self> {
+                match self.overflowing_shr(rhs) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+            narrow_fixed_method! { checked fn checked_dist[](self, other: Self => other.fixed()) }
+            narrow_fixed_method! { checked fn checked_hypot[](self, other: Self => other.fixed()) }
+            narrow_fixed_method! {
+                fixed fn checked_atan2[const RET_FRAC: i32](self, other: Self => other.fixed())
+                    -> Option<<Self::Bits as FixedBits>::Fixed<RET_FRAC>>
+            }
+
+            narrow_fixed_method! { inherent fn saturating_neg(self) -> Self }
+            narrow_fixed_method! { inherent fn saturating_add(self, rhs: Self) -> Self }
+            narrow_fixed_method! { inherent fn saturating_sub(self, rhs: Self) -> Self }
+            narrow_fixed_method! { inherent fn saturating_mul(self, rhs: Self) -> Self }
+            narrow_fixed_method! {
+                saturating fn saturating_mul_stochastic[R: RandomBits + ?Sized](
+                    self,
+                    rhs: Self => rhs.fixed(),
+                    rng: &mut R => rng
+                )
+            }
+            narrow_fixed_method! {
+                saturating fn saturating_next_multiple_of[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                saturating fn saturating_mul_add[const MUL_FRAC: i32](
+                    self,
+                    mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC> => mul,
+                    add: Self => add.fixed()
+                )
+            }
+            narrow_fixed_method! {
+                saturating fn saturating_add_prod[const A_FRAC: i32, const B_FRAC: i32](
+                    self,
+                    a: <Self::Bits as FixedBits>::Fixed<A_FRAC> => a,
+                    b: <Self::Bits as FixedBits>::Fixed<B_FRAC> => b
+                )
+            }
+            #[inline]
+            fn saturating_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
+                &mut self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) {
+                *self = Fixed::saturating_add_prod(*self, a, b);
+            }
+            narrow_fixed_method! { saturating fn saturating_mul_int[](self, rhs: Self::Bits => rhs) }
+            narrow_fixed_method! { saturating fn saturating_div_int[](self, rhs: Self::Bits => rhs) }
+            narrow_fixed_method! {
+                saturating fn saturating_dist[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                saturating fn saturating_hypot[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                fixed fn saturating_atan2[const RET_FRAC: i32](self, other: Self => other.fixed())
+                    -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+
+            narrow_fixed_method! { inherent fn wrapping_neg(self) -> Self }
+            narrow_fixed_method! { inherent fn wrapping_add(self, rhs: Self) -> Self }
+            narrow_fixed_method! { inherent fn wrapping_sub(self, rhs: Self) -> Self }
+            narrow_fixed_method! { inherent fn wrapping_mul(self, rhs: Self) -> Self }
+            narrow_fixed_method! {
+                wrapping fn wrapping_mul_stochastic[R: RandomBits + ?Sized](
+                    self,
+                    rhs: Self => rhs.fixed(),
+                    rng: &mut R => rng
+                )
+            }
+            narrow_fixed_method! {
+                wrapping fn wrapping_next_multiple_of[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                wrapping fn wrapping_mul_add[const MUL_FRAC: i32](
+                    self,
+                    mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC> => mul,
+                    add: Self => add.fixed()
+                )
+            }
+            narrow_fixed_method! {
+                wrapping fn wrapping_add_prod[const A_FRAC: i32, const B_FRAC: i32](
+                    self,
+                    a: <Self::Bits as FixedBits>::Fixed<A_FRAC> => a,
+                    b: <Self::Bits as FixedBits>::Fixed<B_FRAC> => b
+                )
+            }
+            #[inline]
+            fn wrapping_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
+                &mut self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) {
+                *self = Fixed::wrapping_add_prod(*self, a, b);
+            }
+            narrow_fixed_method! { wrapping fn wrapping_mul_int[](self, rhs: Self::Bits => rhs) }
+            narrow_fixed_method! { wrapping fn wrapping_div_int[](self, rhs: Self::Bits => rhs) }
+            #[inline]
+            fn wrapping_shl(self, rhs: u32) -> Self {
+                self.overflowing_shl(rhs).0
+            }
+            #[inline]
+            fn wrapping_shr(self, rhs: u32) -> Self {
+                self.overflowing_shr(rhs).0
+            }
+            narrow_fixed_method! { wrapping fn wrapping_dist[](self, other: Self => other.fixed()) }
+            narrow_fixed_method! { wrapping fn wrapping_hypot[](self, other: Self => other.fixed()) }
+            narrow_fixed_method! {
+                fixed fn wrapping_atan2[const RET_FRAC: i32](self, other: Self => other.fixed())
+                    -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+
+            narrow_fixed_method! { inherent fn unwrapped_neg(self) -> Self }
+            narrow_fixed_method! { inherent fn unwrapped_add(self, rhs: Self) -> Self }
+            narrow_fixed_method! { inherent fn unwrapped_sub(self, rhs: Self) -> Self }
+            narrow_fixed_method! { inherent fn unwrapped_mul(self, rhs: Self) -> Self }
+            narrow_fixed_method! {
+                unwrapped fn unwrapped_mul_stochastic[R: RandomBits + ?Sized](
+                    self,
+                    rhs: Self => rhs.fixed(),
+                    rng: &mut R => rng
+                )
+            }
+            narrow_fixed_method! { unwrapped fn unwrapped_rem[](self, rhs: Self => rhs.fixed()) }
+            narrow_fixed_method! {
+                unwrapped fn unwrapped_next_multiple_of[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                unwrapped fn unwrapped_mul_add[const MUL_FRAC: i32](
+                    self,
+                    mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC> => mul,
+                    add: Self => add.fixed()
+                )
+            }
+            narrow_fixed_method! {
+                unwrapped fn unwrapped_add_prod[const A_FRAC: i32, const B_FRAC: i32](
+                    self,
+                    a: <Self::Bits as FixedBits>::Fixed<A_FRAC> => a,
+                    b: <Self::Bits as FixedBits>::Fixed<B_FRAC> => b
+                )
+            }
+            #[inline]
+            #[track_caller]
+            fn unwrapped_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
+                &mut self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) {
+                *self = Fixed::unwrapped_add_prod(*self, a, b);
+            }
+            narrow_fixed_method! {
+                unwrapped fn unwrapped_rem_euclid[](self, rhs: Self => rhs.fixed())
+            }
+            narrow_fixed_method! { unwrapped fn unwrapped_mul_int[](self, rhs: Self::Bits => rhs) }
+            narrow_fixed_method! { unwrapped fn unwrapped_div_int[](self, rhs: Self::Bits => rhs) }
+            #[inline]
+            #[track_caller]
+            fn unwrapped_shl(self, rhs: u32) -> Self {
+                match self.overflowing_shl(rhs) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+            #[inline]
+            #[track_caller]
+            fn unwrapped_shr(self, rhs: u32) -> Self {
+                match self.overflowing_shr(rhs) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+            narrow_fixed_method! {
+                unwrapped fn unwrapped_dist[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                unwrapped fn unwrapped_hypot[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                fixed fn unwrapped_atan2[const RET_FRAC: i32](self, other: Self => other.fixed())
+                    -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+
+            narrow_fixed_method! { inherent fn overflowing_neg(self) -> (Self, bool) }
+            narrow_fixed_method! { inherent fn overflowing_add(self, rhs: Self) -> (Self, bool) }
+            narrow_fixed_method! { inherent fn overflowing_sub(self, rhs: Self) -> (Self, bool) }
+            narrow_fixed_method! { inherent fn overflowing_mul(self, rhs: Self) -> (Self, bool) }
+            narrow_fixed_method! {
+                overflowing fn overflowing_mul_stochastic[R: RandomBits + ?Sized](
+                    self,
+                    rhs: Self => rhs.fixed(),
+                    rng: &mut R => rng
+                )
+            }
+            narrow_fixed_method! {
+                overflowing fn overflowing_next_multiple_of[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                overflowing fn overflowing_mul_add[const MUL_FRAC: i32](
+                    self,
+                    mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC> => mul,
+                    add: Self => add.fixed()
+                )
+            }
+            narrow_fixed_method! {
+                overflowing fn overflowing_add_prod[const A_FRAC: i32, const B_FRAC: i32](
+                    self,
+                    a: <Self::Bits as FixedBits>::Fixed<A_FRAC> => a,
+                    b: <Self::Bits as FixedBits>::Fixed<B_FRAC> => b
+                )
+            }
+            #[inline]
+            fn overflowing_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
+                &mut self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) -> bool {
+                let (ans, overflow) = Fixed::overflowing_add_prod(*self, a, b);
+                *self = ans;
+                overflow
+            }
+            narrow_fixed_method! {
+                overflowing fn overflowing_mul_int[](self, rhs: Self::Bits => rhs)
+            }
+            narrow_fixed_method! {
+                overflowing fn overflowing_div_int[](self, rhs: Self::Bits => rhs)
+            }
+            // Shifting by rhs ≥ NBITS overflows, and the wrapped value is
+            // shifted by rhs % NBITS.
+            #[inline]
+            fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+                let nbits = $Self::<$($Param),*>::NBITS;
+                let shifted = self.fixed().wrapping_shl(rhs % nbits);
+                ($Self::wrapping_from_fixed_bits(shifted), rhs >= nbits)
+            }
+            #[inline]
+            fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+                let nbits = $Self::<$($Param),*>::NBITS;
+                let shifted = self.fixed().wrapping_shr(rhs % nbits);
+                ($Self::wrapping_from_fixed_bits(shifted), rhs >= nbits)
+            }
+            narrow_fixed_method! {
+                overflowing fn overflowing_dist[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                overflowing fn overflowing_hypot[](self, other: Self => other.fixed())
+            }
+            narrow_fixed_method! {
+                fixed fn overflowing_atan2[const RET_FRAC: i32](self, other: Self => other.fixed())
+                    -> (<Self::Bits as FixedBits>::Fixed<RET_FRAC>, bool)
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+            impl<$(const $Param: i32),*> FixedSigned for $Self<$($Param),*>
+            where
+                $($bound)*
+            {
+                const TRY_NEG_ONE: Option<Self> = $try_neg_one;
+                #[inline]
+                fn signed_bits(self) -> u32 {
+                    let leading = if self.is_negative() {
+                        self.leading_ones()
+                    } else {
+                        self.leading_zeros()
+                    };
+                    $Self::<$($Param),*>::NBITS + 1 - leading
+                }
+                #[inline]
+                fn is_positive(self) -> bool {
+                    self > $Self::ZERO
+                }
+                #[inline]
+                fn is_negative(self) -> bool {
+                    self < $Self::ZERO
+                }
+                #[inline]
+                fn unsigned_abs(self) -> $UnsignedSelf {
+                    self.abs_diff($Self::ZERO)
+                }
+                #[inline]
+                fn unsigned_dist(self, other: Self) -> $UnsignedSelf {
+                    self.abs_diff(other)
+                }
+                narrow_from_overflowing! {
+                    overflowing_abs() => {abs, checked_abs, wrapping_abs, unwrapped_abs}
+                }
+                narrow_from_overflowing! {
+                    overflowing_signum() => {
+                        signum, checked_signum, wrapping_signum, unwrapped_signum
+                    }
+                }
+                narrow_from_overflowing! {
+                    overflowing_add_unsigned(rhs: $UnsignedSelf) => {
+                        add_unsigned,
+                        checked_add_unsigned,
+                        wrapping_add_unsigned,
+                        unwrapped_add_unsigned
+                    }
+                }
+                narrow_from_overflowing! {
+                    overflowing_sub_unsigned(rhs: $UnsignedSelf) => {
+                        sub_unsigned,
+                        checked_sub_unsigned,
+                        wrapping_sub_unsigned,
+                        unwrapped_sub_unsigned
+                    }
+                }
+                #[inline]
+                fn saturating_abs(self) -> Self {
+                    if self.is_negative() {
+                        self.saturating_neg()
+                    } else {
+                        self
+                    }
+                }
+                #[inline]
+                fn saturating_signum(self) -> Self {
+                    match self.overflowing_signum() {
+                        (ans, false) => ans,
+                        (_, true) if self.is_negative() => $Self::MIN,
+                        (_, true) => $Self::MAX,
+                    }
+                }
+                #[inline]
+                fn saturating_add_unsigned(self, rhs: $UnsignedSelf) -> Self {
+                    match self.overflowing_add_unsigned(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => $Self::MAX,
+                    }
+                }
+                #[inline]
+                fn saturating_sub_unsigned(self, rhs: $UnsignedSelf) -> Self {
+                    match self.overflowing_sub_unsigned(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => $Self::MIN,
+                    }
+                }
+                #[inline]
+                fn overflowing_abs(self) -> (Self, bool) {
+                    if self.is_negative() {
+                        self.overflowing_neg()
+                    } else {
+                        (self, false)
+                    }
+                }
+                // This matches the overflowing_signum method of the fixed-point
+                // numbers with NBITS bits.
+                #[inline]
+                fn overflowing_signum(self) -> (Self, bool) {
+                    if self.is_zero() {
+                        ($Self::ZERO, false)
+                    } else if self.is_negative() {
+                        match <Self as FixedSigned>::TRY_NEG_ONE {
+                            Some(neg_one) => (neg_one, false),
+                            None if FRAC < 0 => ($Self::DELTA.wrapping_neg(), false),
+                            None => ($Self::ZERO, true),
+                        }
+                    } else {
+                        match <Self as Fixed>::TRY_ONE {
+                            Some(one) => (one, false),
+                            None if FRAC == $Self::<$($Param),*>::NBITS as i32 - 1 => ($Self::MIN, true),
+                            None => ($Self::ZERO, true),
+                        }
+                    }
+                }
+                // The unsigned number is reinterpreted as a signed number, so
+                // adding it overflows exactly when the result of the addition
+                // does not overflow but the reinterpreted number is negative,
+                // or vice versa.
+                #[inline]
+                fn overflowing_add_unsigned(self, rhs: $UnsignedSelf) -> (Self, bool) {
+                    let rhs = $Self::wrapping_from_masked_bits(rhs.masked_bits());
+                    let (ans, overflow) = self.overflowing_add(rhs);
+                    (ans, overflow != rhs.is_negative())
+                }
+                #[inline]
+                fn overflowing_sub_unsigned(self, rhs: $UnsignedSelf) -> (Self, bool) {
+                    let rhs = $Self::wrapping_from_masked_bits(rhs.masked_bits());
+                    let (ans, overflow) = self.overflowing_sub(rhs);
+                    (ans, overflow != rhs.is_negative())
+                }
+            }
+        }
+
+        if_unsigned! {
+            $Signedness;
+            impl<$(const $Param: i32),*> $Self<$($Param),*>
+            where
+                $($bound)*
+            {
+                #[inline]
+                fn overflowing_next_power_of_two(self) -> ($Self<$($Param),*>, bool) {
+                    let max_zeros = 128 - $Self::<$($Param),*>::NBITS;
+                    match self.masked_bits().checked_next_power_of_two() {
+                        Some(bits) if bits.leading_zeros() >= max_zeros => {
+                            ($Self::wrapping_from_masked_bits(bits), false)
+                        }
+                        _ => ($Self::ZERO, true),
+                    }
+                }
+            }
+
+            impl<$(const $Param: i32),*> FixedUnsigned for $Self<$($Param),*>
+            where
+                $($bound)*
+            {
+                #[inline]
+                fn significant_bits(self) -> u32 {
+                    128 - self.masked_bits().leading_zeros()
+                }
+                #[inline]
+                fn is_power_of_two(self) -> bool {
+                    self.masked_bits().is_power_of_two()
+                }
+                #[inline]
+                fn highest_one(self) -> Self {
+                    match self.masked_bits() {
+                        0 => $Self::ZERO,
+                        bits => $Self::wrapping_from_masked_bits(1 << (127 - bits.leading_zeros())),
+                    }
+                }
+                narrow_from_overflowing! {
+                    overflowing_next_power_of_two() => {
+                        next_power_of_two,
+                        checked_next_power_of_two,
+                        wrapping_next_power_of_two,
+                        unwrapped_next_power_of_two
+                    }
+                }
+                narrow_from_overflowing! {
+                    overflowing_add_signed(rhs: $SignedSelf) => {
+                        add_signed,
+                        checked_add_signed,
+                        wrapping_add_signed,
+                        unwrapped_add_signed
+                    }
+                }
+                narrow_from_overflowing! {
+                    overflowing_sub_signed(rhs: $SignedSelf) => {
+                        sub_signed,
+                        checked_sub_signed,
+                        wrapping_sub_signed,
+                        unwrapped_sub_signed
+                    }
+                }
+                #[inline]
+                fn saturating_add_signed(self, rhs: $SignedSelf) -> Self {
+                    match self.overflowing_add_signed(rhs) {
+                        (ans, false) => ans,
+                        (_, true) if rhs.is_negative() => $Self::ZERO,
+                        (_, true) => $Self::MAX,
+                    }
+                }
+                #[inline]
+                fn saturating_sub_signed(self, rhs: $SignedSelf) -> Self {
+                    match self.overflowing_sub_signed(rhs) {
+                        (ans, false) => ans,
+                        (_, true) if rhs.is_negative() => $Self::MAX,
+                        (_, true) => $Self::ZERO,
+                    }
+                }
+                // The signed number is reinterpreted as an unsigned number, so
+                // adding it overflows exactly when the result of the addition
+                // overflows but the signed number is non-negative, or vice
+                // versa.
+                #[inline]
+                fn overflowing_add_signed(self, rhs: $SignedSelf) -> (Self, bool) {
+                    let unsigned_rhs = $Self::wrapping_from_masked_bits(rhs.masked_bits());
+                    let (ans, overflow) = self.overflowing_add(unsigned_rhs);
+                    (ans, overflow != rhs.is_negative())
+                }
+                #[inline]
+                fn overflowing_sub_signed(self, rhs: $SignedSelf) -> (Self, bool) {
+                    let unsigned_rhs = $Self::wrapping_from_masked_bits(rhs.masked_bits());
+                    let (ans, overflow) = self.overflowing_sub(unsigned_rhs);
+                    (ans, overflow != rhs.is_negative())
+                }
+            }
+        }
+
+        impl<$(const $Param: i32),*> Rem for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            type Output = $Self<$($Param),*>;
+            #[inline]
+            #[track_caller]
+            fn rem(self, rhs: $Self<$($Param),*>) -> $Self<$($Param),*> {
+                $Self::from_fixed_bits(self.fixed() % rhs.fixed())
+            }
+        }
+
+        impl<$(const $Param: i32),*> RemAssign for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            #[inline]
+            #[track_caller]
+            fn rem_assign(&mut self, rhs: $Self<$($Param),*>) {
+                *self = *self % rhs;
+            }
+        }
+
+        impl<$(const $Param: i32),*> Not for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            type Output = $Self<$($Param),*>;
+            #[inline]
+            fn not(self) -> $Self<$($Param),*> {
+                $Self::wrapping_from_bits(!self.to_bits())
+            }
+        }
+
+        narrow_bit_op! { $Self<$($Param),*>, where [$($bound)*]; BitAnd bitand, BitAndAssign bitand_assign, & }
+        narrow_bit_op! { $Self<$($Param),*>, where [$($bound)*]; BitOr bitor, BitOrAssign bitor_assign, | }
+        narrow_bit_op! { $Self<$($Param),*>, where [$($bound)*]; BitXor bitxor, BitXorAssign bitxor_assign, ^ }
+
+        narrow_shift! {
+            $Self<$($Param),*>, where [$($bound)*];
+            Shl shl, ShlAssign shl_assign, overflowing_shl;
+            i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
+        }
+        narrow_shift! {
+            $Self<$($Param),*>, where [$($bound)*];
+            Shr shr, ShrAssign shr_assign, overflowing_shr;
+            i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
+        }
+
+        impl<$(const $Param: i32),*> Sum for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            fn sum<I>(iter: I) -> $Self<$($Param),*>
+            where
+                I: Iterator<Item = $Self<$($Param),*>>,
+            {
+                iter.fold($Self::ZERO, Add::add)
+            }
+        }
+
+        impl<'a, $(const $Param: i32),*> Sum<&'a $Self<$($Param),*>> for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            fn sum<I>(iter: I) -> $Self<$($Param),*>
+            where
+                I: Iterator<Item = &'a $Self<$($Param),*>>,
+            {
+                iter.fold($Self::ZERO, |acc, &x| acc + x)
+            }
+        }
+
+        impl<$(const $Param: i32),*> Product for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            fn product<I>(mut iter: I) -> $Self<$($Param),*>
+            where
+                I: Iterator<Item = $Self<$($Param),*>>,
+            {
+                match iter.next() {
+                    None => {
+                        if FRAC < 0 || FRAC >= $Self::<$($Param),*>::NBITS as i32 {
+                            if cfg!(debug_assertions) {
+                                panic!("overflow");
+                            }
+                            $Self::ZERO
+                        } else {
+                            $Self::wrapping_from_num(1)
+                        }
+                    }
+                    Some(first) => iter.fold(first, Mul::<$Self<$($Param),*>>::mul),
+                }
+            }
+        }
+
+        impl<'a, $(const $Param: i32),*> Product<&'a $Self<$($Param),*>> for $Self<$($Param),*>
+        where
+            $($bound)*
+        {
+            fn product<I>(iter: I) -> $Self<$($Param),*>
+            where
+                I: Iterator<Item = &'a $Self<$($Param),*>>,
+            {
+                iter.copied().product()
+            }
+        }
+
+        narrow_cmp_prim! {
+            $Self<$($Param),*>, where [$($bound)*];
+            i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f16 bf16 f32 f64 F128
+        }
+    };
+}
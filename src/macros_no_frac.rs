@@ -1407,6 +1407,61 @@ let _overflow = Fix::MIN.wide_div(-Fix::DELTA);
                 }
             )?
 
+            comment! {
+                "Multiplication with stochastic rounding.
+
+Returns the product rounded up with a probability equal to the fraction
+of the least significant bit that is discarded, and rounded down
+otherwise, so that on average the rounded product is equal to the exact
+product. The random bits are taken from `rng`, see [`RandomBits`].
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use
+[`wrapping_mul_stochastic`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, ", stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.0625 = 0.09375 lies between 0.0625 and 0.125
+let (a, b) = (Fix::from_num(1.5), Fix::DELTA);
+assert_eq!(a.mul_stochastic(b, &mut Same(0)), Fix::from_num(0.125));
+assert_eq!(a.mul_stochastic(b, &mut Same(!0)), Fix::from_num(0.0625));
+```
+
+[`RandomBits`]: crate::traits::RandomBits
+[`wrapping_mul_stochastic`]: Self::wrapping_mul_stochastic
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn mul_stochastic<R: RandomBits + ?Sized>(
+                    self,
+                    rhs: $Self<FRAC>,
+                    rng: &mut R,
+                ) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_mul_stochastic(rhs, rng);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
             comment! {
                 r#"Multiply and add. Returns `self` × `mul` + `add`.
 
@@ -1883,6 +1938,68 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>).
+
+Returns the angle between the positive <i>x</i> axis and the point
+(<i>x</i>,&nbsp;<i>y</i>), ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "in the range &minus;π&nbsp;≤&nbsp;angle&nbsp;≤&nbsp;π",
+                    "which is in the range 0&nbsp;≤&nbsp;angle&nbsp;≤&nbsp;π/2 since both
+operands are non-negative",
+                ),
+                ". If both operands are zero, the angle is zero.
+
+The angle is in radians, and is returned as a fixed-point number with
+`RET_FRAC` fractional bits, which can be different from the number of
+fractional bits of the operands. Together with [`hypot`][Self::hypot], this
+converts a vector from Cartesian to polar coordinates.
+
+The result is rounded to the nearest, and the error is less than
+[`DELTA`][Self::DELTA] of the result type if `RET_FRAC`&nbsp;≤&nbsp;120.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_atan2`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Angle = ", stringify!($Self), "<", stringify!($nm3), ">;
+// atan2(1, 1) = π/4
+let angle: Angle = Fix::ONE.atan2(Fix::ONE);
+assert!(angle.dist(Angle::FRAC_PI_4) <= Angle::DELTA);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// atan2(0, −1) = π
+let angle: Angle = Fix::ZERO.atan2(-Fix::ONE);
+assert!(angle.dist(Angle::PI) <= Angle::DELTA);
+",
+                },
+                "```
+
+[`wrapping_atan2`]: Self::wrapping_atan2
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn atan2<const RET_FRAC: i32>(self, other: $Self<FRAC>) -> $Self<RET_FRAC> {
+                    let (val, overflow) = self.overflowing_atan2(other);
+                    debug_assert!(!overflow, "overflow");
+                    val
+                }
+            }
+
             comment! {
                 "Returns the smallest multiple of `other` that is ≥&nbsp;`self`",
                 if_signed_else_empty_str! {
@@ -2462,6 +2579,50 @@ assert_eq!(Fix::MAX.checked_mul(Fix::from_num(2)), None);
                 }
             }
 
+            comment! {
+                "Checked multiplication with stochastic rounding. Returns the
+product, or [`None`] on overflow.
+
+The product is rounded as in [`mul_stochastic`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, ", stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.0625 = 0.09375 lies between 0.0625 and 0.125
+let (a, b) = (Fix::from_num(1.5), Fix::DELTA);
+assert_eq!(a.checked_mul_stochastic(b, &mut Same(0)), Some(Fix::from_num(0.125)));
+assert_eq!(Fix::MAX.checked_mul_stochastic(Fix::from_num(2), &mut Same(0)), None);
+```
+
+[`mul_stochastic`]: Self::mul_stochastic
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn checked_mul_stochastic<R: RandomBits + ?Sized>(
+                    self,
+                    rhs: $Self<FRAC>,
+                    rng: &mut R,
+                ) -> Option<$Self<FRAC>> {
+                    match self.overflowing_mul_stochastic(rhs, rng) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
             comment! {
                 "Checked remainder. Returns the remainder, or [`None`] if
 the divisor is zero.
@@ -2991,6 +3152,43 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+returning [`None`] on overflow.
+
+The angle is returned in radians as a fixed-point number with `RET_FRAC`
+fractional bits. See [`atan2`][Self::atan2] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Angle = ", stringify!($Self), "<", stringify!($nm3), ">;
+let angle: Option<Angle> = Fix::ONE.checked_atan2(Fix::ONE);
+assert!(angle.unwrap().dist(Angle::FRAC_PI_4) <= Angle::DELTA);
+
+type NoInt = ", stringify!($Self), "<", stringify!($n), ">;
+// atan2(1, 0) = π/2, which does not fit
+assert_eq!(Fix::ONE.checked_atan2::<", stringify!($n), ">(Fix::ZERO), None::<NoInt>);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_atan2<const RET_FRAC: i32>(
+                    self,
+                    other: $Self<FRAC>,
+                ) -> Option<$Self<RET_FRAC>> {
+                    match self.overflowing_atan2(other) {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
             comment! {
                 "Checked next multiple of `other`. Returns the next multiple, or
 [`None`] if `other` is zero or on overflow.
@@ -3374,6 +3572,60 @@ assert_eq!(Fix::MAX.saturating_mul(Fix::from_num(2)), Fix::MAX);
                 }
             }
 
+            comment! {
+                "Saturating multiplication with stochastic rounding. Returns the
+product, saturating on overflow.
+
+The product is rounded as in [`mul_stochastic`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, ", stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.0625 = 0.09375 lies between 0.0625 and 0.125
+let (a, b) = (Fix::from_num(1.5), Fix::DELTA);
+assert_eq!(a.saturating_mul_stochastic(b, &mut Same(0)), Fix::from_num(0.125));
+assert_eq!(Fix::MAX.saturating_mul_stochastic(Fix::from_num(2), &mut Same(0)), Fix::MAX);
+```
+
+[`mul_stochastic`]: Self::mul_stochastic
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_mul_stochastic<R: RandomBits + ?Sized>(
+                    self,
+                    rhs: $Self<FRAC>,
+                    rng: &mut R,
+                ) -> $Self<FRAC> {
+                    match self.overflowing_mul_stochastic(rhs, rng) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if (self.to_bits() < 0) != (rhs.to_bits() < 0) {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
             comment! {
                 r#"Saturating multiply and add.
 Returns `self` × `mul` + `add`, saturating on overflow.
@@ -3787,6 +4039,47 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+saturating on overflow.
+
+The angle is returned in radians as a fixed-point number with `RET_FRAC`
+fractional bits. See [`atan2`][Self::atan2] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Angle = ", stringify!($Self), "<", stringify!($nm3), ">;
+let angle: Angle = Fix::ONE.saturating_atan2(Fix::ONE);
+assert!(angle.dist(Angle::FRAC_PI_4) <= Angle::DELTA);
+
+type NoInt = ", stringify!($Self), "<", stringify!($n), ">;
+// atan2(1, 0) = π/2, which does not fit
+assert_eq!(Fix::ONE.saturating_atan2::<", stringify!($n), ">(Fix::ZERO), NoInt::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_atan2<const RET_FRAC: i32>(
+                    self,
+                    other: $Self<FRAC>,
+                ) -> $Self<RET_FRAC> {
+                    let y = real::$Inner::from_bits(self.to_bits(), 0);
+                    let x = real::$Inner::from_bits(other.to_bits(), 0);
+                    let angle = trig::atan2(y, x);
+                    match real::$Inner::to_bits(angle, RET_FRAC) {
+                        (bits, false) => $Self::from_bits(bits),
+                        (_, true) if angle.neg => $Self::MIN,
+                        (_, true) => $Self::MAX,
+                    }
+                }
+            }
+
             comment! {
                 "Saturating next multiple of `other`.
 
@@ -4130,6 +4423,48 @@ assert_eq!(Fix::MAX.wrapping_mul(Fix::from_num(4)), wrapped);
                 }
             }
 
+            comment! {
+                "Wrapping multiplication with stochastic rounding. Returns the
+product, wrapping on overflow.
+
+The product is rounded as in [`mul_stochastic`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, ", stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.0625 = 0.09375 lies between 0.0625 and 0.125
+let (a, b) = (Fix::from_num(1.5), Fix::DELTA);
+assert_eq!(a.wrapping_mul_stochastic(b, &mut Same(0)), Fix::from_num(0.125));
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::MAX.wrapping_mul_stochastic(Fix::from_num(4), &mut Same(0)), wrapped);
+```
+
+[`mul_stochastic`]: Self::mul_stochastic
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn wrapping_mul_stochastic<R: RandomBits + ?Sized>(
+                    self,
+                    rhs: $Self<FRAC>,
+                    rng: &mut R,
+                ) -> $Self<FRAC> {
+                    self.overflowing_mul_stochastic(rhs, rng).0
+                }
+            }
+
             comment! {
                 r#"Wrapping multiply and add.
 Returns `self` × `mul` + `add`, wrapping on overflow.
@@ -4519,6 +4854,43 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+wrapping on overflow.
+
+The angle is returned in radians as a fixed-point number with `RET_FRAC`
+fractional bits. See [`atan2`][Self::atan2] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Angle = ", stringify!($Self), "<", stringify!($nm3), ">;
+let angle: Angle = Fix::ONE.wrapping_atan2(Fix::ONE);
+assert!(angle.dist(Angle::FRAC_PI_4) <= Angle::DELTA);
+
+type NoInt = ", stringify!($Self), "<", stringify!($n), ">;
+// atan2(1, 0) = π/2, which does not fit
+let (wrapped, overflow): (NoInt, bool) = Fix::ONE.overflowing_atan2(Fix::ZERO);
+assert!(overflow);
+let wrapping: NoInt = Fix::ONE.wrapping_atan2(Fix::ZERO);
+assert_eq!(wrapping, wrapped);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_atan2<const RET_FRAC: i32>(
+                    self,
+                    other: $Self<FRAC>,
+                ) -> $Self<RET_FRAC> {
+                    self.overflowing_atan2(other).0
+                }
+            }
+
             comment! {
                 "Wrapping next multiple of `other`.
 
@@ -4943,6 +5315,73 @@ let _overflow = Fix::MAX.unwrapped_mul(Fix::from_num(4));
                 }
             }
 
+            comment! {
+                "Unwrapped multiplication with stochastic rounding. Returns the
+product, panicking on overflow.
+
+The product is rounded as in [`mul_stochastic`].
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, ", stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.0625 = 0.09375 lies between 0.0625 and 0.125
+let (a, b) = (Fix::from_num(1.5), Fix::DELTA);
+assert_eq!(a.unwrapped_mul_stochastic(b, &mut Same(0)), Fix::from_num(0.125));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, ", stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_mul_stochastic(Fix::from_num(4), &mut Same(0));
+```
+
+[`mul_stochastic`]: Self::mul_stochastic
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn unwrapped_mul_stochastic<R: RandomBits + ?Sized>(
+                    self,
+                    rhs: $Self<FRAC>,
+                    rng: &mut R,
+                ) -> $Self<FRAC> {
+                    match self.overflowing_mul_stochastic(rhs, rng) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
             comment! {
                 "Unwrapped remainder. Returns the remainder, panicking if the divisor is zero.
 
@@ -5611,6 +6050,56 @@ let _overflow = Fix::from_num(8).unwrapped_hypot(Fix::from_num(15));
                 }
             }
 
+            comment! {
+                "Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+panicking on overflow.
+
+The angle is returned in radians as a fixed-point number with `RET_FRAC`
+fractional bits. See [`atan2`][Self::atan2] for details.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Angle = ", stringify!($Self), "<", stringify!($nm3), ">;
+let angle: Angle = Fix::ONE.unwrapped_atan2(Fix::ONE);
+assert!(angle.dist(Angle::FRAC_PI_4) <= Angle::DELTA);
+```
+
+The following panics because of overflow.
+
+```should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type NoInt = ", stringify!($Self), "<", stringify!($n), ">;
+let _overflow: NoInt = Fix::ONE.unwrapped_atan2(Fix::ZERO);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_atan2<const RET_FRAC: i32>(
+                    self,
+                    other: $Self<FRAC>,
+                ) -> $Self<RET_FRAC> {
+                    match self.overflowing_atan2(other) {
+                        (val, false) => val,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
             comment! {
                 "Returns the next multiple of `other`, panicking on overflow.
 
@@ -6090,6 +6579,62 @@ assert_eq!(Fix::MAX.overflowing_mul(Fix::from_num(4)), (wrapped, true));
                 }
             }
 
+            comment! {
+                "Overflowing multiplication with stochastic rounding.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+The product is rounded as in [`mul_stochastic`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{traits::RandomBits, ", stringify!($Self), "};
+// Same returns the same bits every time, which makes the result predictable:
+// zero bits always round up and all ones always round down.
+struct Same(u64);
+impl RandomBits for Same {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+type Fix = ", stringify!($Self), "<4>;
+// 1.5 × 0.0625 = 0.09375 lies between 0.0625 and 0.125
+let (a, b) = (Fix::from_num(1.5), Fix::DELTA);
+assert_eq!(
+    a.overflowing_mul_stochastic(b, &mut Same(0)),
+    (Fix::from_num(0.125), false)
+);
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(
+    Fix::MAX.overflowing_mul_stochastic(Fix::from_num(4), &mut Same(0)),
+    (wrapped, true)
+);
+```
+
+[`mul_stochastic`]: Self::mul_stochastic
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn overflowing_mul_stochastic<R: RandomBits + ?Sized>(
+                    self,
+                    rhs: $Self<FRAC>,
+                    rng: &mut R,
+                ) -> ($Self<FRAC>, bool) {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_round_up(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        FRAC,
+                        |discarded| rounding::random_below(rng, discarded),
+                    );
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
             comment! {
                 r#"Overflowing multiply and add.
 
@@ -6595,6 +7140,47 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>).
+
+Returns a [tuple] of the angle and a [`bool`] indicating whether an overflow has
+occurred. On overflow, the wrapped value is returned.
+
+The angle is returned in radians as a fixed-point number with `RET_FRAC`
+fractional bits. See [`atan2`][Self::atan2] for details.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Angle = ", stringify!($Self), "<", stringify!($nm3), ">;
+let (angle, overflow): (Angle, bool) = Fix::ONE.overflowing_atan2(Fix::ONE);
+assert!(angle.dist(Angle::FRAC_PI_4) <= Angle::DELTA);
+assert!(!overflow);
+
+type NoInt = ", stringify!($Self), "<", stringify!($n), ">;
+// atan2(1, 0) = π/2, which does not fit
+let (_, overflow): (NoInt, bool) = Fix::ONE.overflowing_atan2(Fix::ZERO);
+assert!(overflow);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_atan2<const RET_FRAC: i32>(
+                    self,
+                    other: $Self<FRAC>,
+                ) -> ($Self<RET_FRAC>, bool) {
+                    let y = real::$Inner::from_bits(self.to_bits(), 0);
+                    let x = real::$Inner::from_bits(other.to_bits(), 0);
+                    let (bits, overflow) = real::$Inner::to_bits(trig::atan2(y, x), RET_FRAC);
+                    ($Self::from_bits(bits), overflow)
+                }
+            }
+
             comment! {
                 "Overflowing next multiple of `other`.
 
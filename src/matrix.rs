@@ -0,0 +1,1759 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::dot::saturate;
+use crate::traits::{Fixed, FixedBoundFrac};
+use crate::vector::{Vec2, Vec3};
+use core::array;
+use core::ops::{Mul, MulAssign};
+
+// Generates the checked, wrapping and unwrapped variants of an operation from
+// its overflowing variant.
+macro_rules! mat_op {
+    (
+        $Mat:ident,
+        {op, result} = {$op:literal, $result:literal},
+        fn($arg:ident: $Arg:ty) -> $Ret:ty,
+        {checked, wrapping, unwrapped, overflowing} =
+            {$checked:ident, $wrapping:ident, $unwrapped:ident, $overflowing:ident},
+        {uses, lets, ans} = {$uses:expr, $lets:expr, $ans:expr},
+        {over_lets, wrap} = {$over_lets:expr, $wrap:expr},
+    ) => {
+        comment! {
+            "Checked ", $op, ". Returns the ", $result, ", or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", $uses, "};
+type M = ", stringify!($Mat), "<I16F16>;
+", $lets, $over_lets,
+            "assert_eq!(a.", stringify!($checked), "(b), Some(", $ans, "));
+assert_eq!(max.", stringify!($checked), "(over), None);
+```
+";
+            #[inline]
+            #[must_use]
+            pub fn $checked(self, $arg: $Arg) -> Option<$Ret> {
+                match self.$overflowing($arg) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        comment! {
+            "Wrapping ", $op, ". Returns the ", $result, ", wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", $uses, "};
+type M = ", stringify!($Mat), "<I16F16>;
+", $lets, $over_lets, "assert_eq!(a.", stringify!($wrapping), "(b), ", $ans, ");
+assert_eq!(max.", stringify!($wrapping), "(over), ", $wrap, ");
+```
+";
+            #[inline]
+            #[must_use]
+            pub fn $wrapping(self, $arg: $Arg) -> $Ret {
+                self.$overflowing($arg).0
+            }
+        }
+
+        comment! {
+            "Unwrapped ", $op, ". Returns the ", $result, ", panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", $uses, "};
+type M = ", stringify!($Mat), "<I16F16>;
+", $lets, "assert_eq!(a.", stringify!($unwrapped), "(b), ", $ans, ");
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", $uses, "};
+type M = ", stringify!($Mat), "<I16F16>;
+", $over_lets, "let _overflow = max.", stringify!($unwrapped), "(over);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn $unwrapped(self, $arg: $Arg) -> $Ret {
+                match self.$overflowing($arg) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+    };
+}
+
+// Generates the items common to all matrix types. The string parameters are
+// used in the examples: a and b are two matrices given as arrays of rows, v is
+// a vector, and the rest are the results of operations on them.
+macro_rules! fixed_mat {
+    (
+        $Mat:ident[$n:literal], $Vec:ty,
+        {vec_use, vec_new, vec_max} = {$vec_use:literal, $vec_new:literal, $vec_max:literal},
+        {a, b, v} = {$a:literal, $b:literal, $v:literal},
+        {transposed, prod, prod_vec} = {$transposed:literal, $prod:literal, $prod_vec:literal},
+        {wrapped, wrapped_vec} = {$wrapped:literal, $wrapped_vec:literal},
+    ) => {
+        impl<F: Fixed> $Mat<F> {
+            comment! {
+                "The zero matrix.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Mat), "};
+let zero = ", stringify!($Mat), "::<I16F16>::ZERO;
+assert_eq!(zero.rows, [[I16F16::ZERO; ", $n, "]; ", $n, "]);
+```
+";
+                pub const ZERO: $Mat<F> = $Mat {
+                    rows: [[F::ZERO; $n]; $n],
+                };
+            }
+
+            comment! {
+                "The identity matrix if the number type can represent one,
+otherwise [`None`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{
+    types::{I16F16, U0F16},
+    ", stringify!($Mat), ",
+};
+type M = ", stringify!($Mat), "<I16F16>;
+let a = M::from_rows(", $a, ".map(|row| row.map(I16F16::from_num)));
+let identity = M::TRY_IDENTITY.unwrap();
+assert_eq!(identity * a, a);
+assert_eq!(", stringify!($Mat), "::<U0F16>::TRY_IDENTITY, None);
+```
+";
+                pub const TRY_IDENTITY: Option<$Mat<F>> = match F::TRY_ONE {
+                    Some(one) => {
+                        let mut rows = [[F::ZERO; $n]; $n];
+                        let mut i = 0;
+                        while i < $n {
+                            rows[i][i] = one;
+                            i += 1;
+                        }
+                        Some($Mat { rows })
+                    }
+                    None => None,
+                };
+            }
+
+            comment! {
+                "Creates a matrix from its rows.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Mat), "};
+let rows = ", $a, ".map(|row| row.map(I16F16::from_num));
+let a = ", stringify!($Mat), "::from_rows(rows);
+assert_eq!(a.rows, rows);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_rows(rows: [[F; $n]; $n]) -> $Mat<F> {
+                    $Mat { rows }
+                }
+            }
+
+            comment! {
+                "Returns the transpose, that is the matrix with the rows and
+columns interchanged.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Mat), "};
+type M = ", stringify!($Mat), "<I16F16>;
+let a = M::from_rows(", $a, ".map(|row| row.map(I16F16::from_num)));
+let transposed = M::from_rows(", $transposed, ".map(|row| row.map(I16F16::from_num)));
+assert_eq!(a.transpose(), transposed);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn transpose(self) -> $Mat<F> {
+                    $Mat {
+                        rows: array::from_fn(|i| array::from_fn(|j| self.rows[j][i])),
+                    }
+                }
+            }
+
+            comment! {
+                "Multiplies the matrix by a column vector.
+
+This is equivalent to the `*` operator with a right-hand side of type `",
+                stringify!($Vec), "`.
+
+Each component of the result is the dot product of a row and the vector. The
+products are computed exactly and summed in double width, so each component is
+rounded down only once.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Mat), $vec_use, "};
+type M = ", stringify!($Mat), "<I16F16>;
+let a = M::from_rows(", $a, ".map(|row| row.map(I16F16::from_num)));
+let v = ", $vec_new, "(", $v, ".map(I16F16::from_num));
+let prod = ", $vec_new, "(", $prod_vec, ".map(I16F16::from_num));
+assert_eq!(a.mul_vec(v), prod);
+assert_eq!(a * v, prod);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn mul_vec(self, v: $Vec) -> $Vec {
+                    let (ans, overflow) = self.overflowing_mul_vec(v);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            mat_op! {
+                $Mat,
+                {op, result} = {"matrix multiplication", "product"},
+                fn(rhs: $Mat<F>) -> $Mat<F>,
+                {checked, wrapping, unwrapped, overflowing} =
+                    {checked_mul, wrapping_mul, unwrapped_mul, overflowing_mul},
+                {uses, lets, ans} = {
+                    stringify!($Mat),
+                    concat!(
+                        "let a = M::from_rows(", $a, ".map(|row| row.map(I16F16::from_num)));\n",
+                        "let b = M::from_rows(", $b, ".map(|row| row.map(I16F16::from_num)));\n",
+                    ),
+                    concat!("M::from_rows(", $prod, ".map(|row| row.map(I16F16::from_num)))")
+                },
+                {over_lets, wrap} = {
+                    concat!(
+                        "let max = M::from_rows([[I16F16::MAX; ", $n, "]; ", $n, "]);\n",
+                        "let over = max;\n",
+                    ),
+                    $wrapped
+                },
+            }
+
+            comment! {
+                "Saturating matrix multiplication. Returns the product,
+saturating each element on overflow.
+
+Each element of the product is computed exactly before it is saturated, so it
+does not saturate if only some of the intermediate products or partial sums do
+not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Mat), "};
+type M = ", stringify!($Mat), "<I16F16>;
+let a = M::from_rows(", $a, ".map(|row| row.map(I16F16::from_num)));
+let b = M::from_rows(", $b, ".map(|row| row.map(I16F16::from_num)));
+let prod = M::from_rows(", $prod, ".map(|row| row.map(I16F16::from_num)));
+assert_eq!(a.saturating_mul(b), prod);
+let max = M::from_rows([[I16F16::MAX; ", $n, "]; ", $n, "]);
+let min = M::from_rows([[I16F16::MIN; ", $n, "]; ", $n, "]);
+assert_eq!(max.saturating_mul(max), max);
+assert_eq!(max.saturating_mul(min), min);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_mul(self, rhs: $Mat<F>) -> $Mat<F> {
+                    $Mat {
+                        rows: array::from_fn(|i| {
+                            array::from_fn(|j| {
+                                let terms: [(F, F); $n] =
+                                    array::from_fn(|k| (self.rows[i][k], rhs.rows[k][j]));
+                                let (val, overflow, neg) = F::sum_prods(&terms, &[]);
+                                saturate(val, overflow, neg)
+                            })
+                        }),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing matrix multiplication.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an overflow
+has occurred in any element. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Mat), "};
+type M = ", stringify!($Mat), "<I16F16>;
+let a = M::from_rows(", $a, ".map(|row| row.map(I16F16::from_num)));
+let b = M::from_rows(", $b, ".map(|row| row.map(I16F16::from_num)));
+let prod = M::from_rows(", $prod, ".map(|row| row.map(I16F16::from_num)));
+assert_eq!(a.overflowing_mul(b), (prod, false));
+let max = M::from_rows([[I16F16::MAX; ", $n, "]; ", $n, "]);
+assert_eq!(max.overflowing_mul(max), (", $wrapped, ", true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_mul(self, rhs: $Mat<F>) -> ($Mat<F>, bool) {
+                    let mut overflow = false;
+                    let rows = array::from_fn(|i| {
+                        array::from_fn(|j| {
+                            let terms: [(F, F); $n] =
+                                array::from_fn(|k| (self.rows[i][k], rhs.rows[k][j]));
+                            let (val, o, _) = F::sum_prods(&terms, &[]);
+                            overflow |= o;
+                            val
+                        })
+                    });
+                    ($Mat { rows }, overflow)
+                }
+            }
+
+            mat_op! {
+                $Mat,
+                {op, result} = {"multiplication by a column vector", "product"},
+                fn(v: $Vec) -> $Vec,
+                {checked, wrapping, unwrapped, overflowing} =
+                    {checked_mul_vec, wrapping_mul_vec, unwrapped_mul_vec, overflowing_mul_vec},
+                {uses, lets, ans} = {
+                    concat!(stringify!($Mat), $vec_use),
+                    concat!(
+                        "let a = M::from_rows(", $a, ".map(|row| row.map(I16F16::from_num)));\n",
+                        "let b = ", $vec_new, "(", $v, ".map(I16F16::from_num));\n",
+                    ),
+                    concat!($vec_new, "(", $prod_vec, ".map(I16F16::from_num))")
+                },
+                {over_lets, wrap} = {
+                    concat!(
+                        "let max = M::from_rows([[I16F16::MAX; ", $n, "]; ", $n, "]);\n",
+                        "let over = ", $vec_new, "([I16F16::MAX; ", $n, "]);\n",
+                    ),
+                    $wrapped_vec
+                },
+            }
+
+            comment! {
+                "Saturating multiplication by a column vector. Returns the
+product, saturating each component on overflow.
+
+Each component of the product is computed exactly before it is saturated.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Mat), $vec_use, "};
+type M = ", stringify!($Mat), "<I16F16>;
+let a = M::from_rows(", $a, ".map(|row| row.map(I16F16::from_num)));
+let v = ", $vec_new, "(", $v, ".map(I16F16::from_num));
+let prod = ", $vec_new, "(", $prod_vec, ".map(I16F16::from_num));
+assert_eq!(a.saturating_mul_vec(v), prod);
+let max = M::from_rows([[I16F16::MAX; ", $n, "]; ", $n, "]);
+assert_eq!(max.saturating_mul_vec(", $vec_max, "), ", $vec_max, ");
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_mul_vec(self, v: $Vec) -> $Vec {
+                    let v: [F; $n] = v.into();
+                    let ans: [F; $n] = array::from_fn(|i| {
+                        let terms: [(F, F); $n] = array::from_fn(|k| (self.rows[i][k], v[k]));
+                        let (val, overflow, neg) = F::sum_prods(&terms, &[]);
+                        saturate(val, overflow, neg)
+                    });
+                    ans.into()
+                }
+            }
+
+            comment! {
+                "Overflowing multiplication by a column vector.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an overflow
+has occurred in any component. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Mat), $vec_use, "};
+type M = ", stringify!($Mat), "<I16F16>;
+let a = M::from_rows(", $a, ".map(|row| row.map(I16F16::from_num)));
+let v = ", $vec_new, "(", $v, ".map(I16F16::from_num));
+let prod = ", $vec_new, "(", $prod_vec, ".map(I16F16::from_num));
+assert_eq!(a.overflowing_mul_vec(v), (prod, false));
+let max = M::from_rows([[I16F16::MAX; ", $n, "]; ", $n, "]);
+assert_eq!(max.overflowing_mul_vec(", $vec_max, "), (", $wrapped_vec, ", true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_mul_vec(self, v: $Vec) -> ($Vec, bool) {
+                    let v: [F; $n] = v.into();
+                    let mut overflow = false;
+                    let ans: [F; $n] = array::from_fn(|i| {
+                        let terms: [(F, F); $n] = array::from_fn(|k| (self.rows[i][k], v[k]));
+                        let (val, o, _) = F::sum_prods(&terms, &[]);
+                        overflow |= o;
+                        val
+                    });
+                    (ans.into(), overflow)
+                }
+            }
+        }
+
+        impl<F: Fixed> Mul<$Mat<F>> for $Mat<F> {
+            type Output = $Mat<F>;
+            #[inline]
+            #[track_caller]
+            fn mul(self, rhs: $Mat<F>) -> $Mat<F> {
+                let (ans, overflow) = self.overflowing_mul(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<F: Fixed> MulAssign<$Mat<F>> for $Mat<F> {
+            #[inline]
+            #[track_caller]
+            fn mul_assign(&mut self, rhs: $Mat<F>) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<F: Fixed> Mul<$Vec> for $Mat<F> {
+            type Output = $Vec;
+            #[inline]
+            #[track_caller]
+            fn mul(self, rhs: $Vec) -> $Vec {
+                self.mul_vec(rhs)
+            }
+        }
+
+        impl<F: Fixed> From<[[F; $n]; $n]> for $Mat<F> {
+            #[inline]
+            fn from(rows: [[F; $n]; $n]) -> $Mat<F> {
+                $Mat { rows }
+            }
+        }
+
+        impl<F: Fixed> From<$Mat<F>> for [[F; $n]; $n] {
+            #[inline]
+            fn from(m: $Mat<F>) -> [[F; $n]; $n] {
+                m.rows
+            }
+        }
+    };
+}
+
+/**
+A 2×2 matrix with fixed-point elements.
+
+The elements are stored in row-major order, and vectors are treated as column
+vectors, so that a matrix is multiplied by a vector on the left. For matrix
+products, matrix-vector products and the determinant, the products of the
+elements are computed exactly and summed in double width, so that each element
+of the result is rounded down only once.
+
+The operators panic on overflow when debug assertions are enabled and wrap
+otherwise, like the operators of the fixed-point numbers.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Mat2, Vec2};
+type M = Mat2<I16F16>;
+let a = M::from_rows([[1, 2], [3, 4]].map(|row| row.map(I16F16::from_num)));
+let v = Vec2::new(I16F16::from_num(1), I16F16::from_num(-1));
+assert_eq!(a * v, Vec2::new(I16F16::from_num(-1), I16F16::from_num(-1)));
+assert_eq!(a.determinant(), -2);
+let inv = M::from_rows([[-2.0, 1.0], [1.5, -0.5]].map(|row| row.map(I16F16::from_num)));
+assert_eq!(a.checked_inverse(), Some(inv));
+assert_eq!(a * inv, M::TRY_IDENTITY.unwrap());
+```
+*/
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Mat2<F> {
+    /// The rows of the matrix.
+    pub rows: [[F; 2]; 2],
+}
+
+/**
+A 3×3 matrix with fixed-point elements.
+
+The elements are stored in row-major order, and vectors are treated as column
+vectors, so that a matrix is multiplied by a vector on the left. For matrix
+products and matrix-vector products, the products of the elements are computed
+exactly and summed in double width, so that each element of the result is
+rounded down only once.
+
+A 3×3 matrix can also represent a two-dimensional affine transform using
+homogeneous coordinates; see [`from_translation`][Mat3::from_translation],
+[`from_scale`][Mat3::from_scale], [`from_angle`][Mat3::from_angle] and
+[`transform_point`][Mat3::transform_point].
+
+The operators panic on overflow when debug assertions are enabled and wrap
+otherwise, like the operators of the fixed-point numbers.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Mat3, Vec2};
+type M = Mat3<I16F16>;
+let scale = M::from_scale(Vec2::new(I16F16::from_num(2), I16F16::from_num(0.5)));
+let translation = M::from_translation(Vec2::new(I16F16::from_num(1), I16F16::from_num(-1)));
+// scale first, then translate
+let transform = translation * scale;
+let p = Vec2::new(I16F16::from_num(3), I16F16::from_num(4));
+assert_eq!(
+    transform.transform_point(p),
+    Vec2::new(I16F16::from_num(7), I16F16::from_num(1))
+);
+assert_eq!(transform.determinant(), 1);
+assert_eq!(transform.checked_inverse().unwrap().transform_point(transform.transform_point(p)), p);
+```
+*/
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Mat3<F> {
+    /// The rows of the matrix.
+    pub rows: [[F; 3]; 3],
+}
+
+/**
+A 4×4 matrix with fixed-point elements.
+
+The elements are stored in row-major order, and vectors, which are arrays of
+four elements, are treated as column vectors, so that a matrix is multiplied by
+a vector on the left. For matrix products and matrix-vector products, the
+products of the elements are computed exactly and summed in double width, so
+that each element of the result is rounded down only once.
+
+The operators panic on overflow when debug assertions are enabled and wrap
+otherwise, like the operators of the fixed-point numbers.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Mat4};
+type M = Mat4<I16F16>;
+let a = M::from_rows(
+    [[1, 2, 0, 0], [0, 1, 0, 0], [2, 0, 1, 0], [0, 0, 0, 2]].map(|row| row.map(I16F16::from_num)),
+);
+let v = [1, 2, 3, 4].map(I16F16::from_num);
+assert_eq!(a * v, [5, 2, 5, 8].map(I16F16::from_num));
+assert_eq!(a.determinant(), 2);
+let inv = a.checked_inverse().unwrap();
+assert_eq!(inv * a, M::TRY_IDENTITY.unwrap());
+```
+*/
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Mat4<F> {
+    /// The rows of the matrix.
+    pub rows: [[F; 4]; 4],
+}
+
+fixed_mat! {
+    Mat2[2], Vec2<F>,
+    {vec_use, vec_new, vec_max} = {", Vec2", "Vec2::from", "Vec2::new(I16F16::MAX, I16F16::MAX)"},
+    {a, b, v} = {"[[1, 2], [3, 4]]", "[[0.5, -1.0], [2.0, 1.0]]", "[1, -1]"},
+    {transposed, prod, prod_vec} = {"[[1, 3], [2, 4]]", "[[4.5, 1.0], [9.5, 1.0]]", "[-1, -1]"},
+    {wrapped, wrapped_vec} = {
+        "M::from_rows([[I16F16::from_num(-2); 2]; 2])",
+        "Vec2::new(I16F16::from_num(-2), I16F16::from_num(-2))"
+    },
+}
+
+fixed_mat! {
+    Mat3[3], Vec3<F>,
+    {vec_use, vec_new, vec_max} = {
+        ", Vec3",
+        "Vec3::from",
+        "Vec3::new(I16F16::MAX, I16F16::MAX, I16F16::MAX)"
+    },
+    {a, b, v} = {
+        "[[2, 4, 0], [0, 1, 0], [2, 0, 1]]",
+        "[[0.5, 0.0, 1.0], [0.0, 2.0, 0.0], [-1.0, 0.0, 1.0]]",
+        "[1, 2, 3]"
+    },
+    {transposed, prod, prod_vec} = {
+        "[[2, 0, 2], [4, 1, 0], [0, 0, 1]]",
+        "[[1, 8, 2], [0, 2, 0], [0, 0, 3]]",
+        "[10, 2, 5]"
+    },
+    {wrapped, wrapped_vec} = {
+        "M::from_rows([[I16F16::from_num(-3); 3]; 3])",
+        "Vec3::new(I16F16::from_num(-3), I16F16::from_num(-3), I16F16::from_num(-3))"
+    },
+}
+
+fixed_mat! {
+    Mat4[4], [F; 4],
+    {vec_use, vec_new, vec_max} = {"", "<[I16F16; 4]>::from", "[I16F16::MAX; 4]"},
+    {a, b, v} = {
+        "[[1, 2, 0, 0], [0, 1, 0, 0], [2, 0, 1, 0], [0, 0, 0, 2]]",
+        "[[0.5, 0.0, 0.0, 1.0], [0.0, 2.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [-1.0, 0.0, 0.0, 1.0]]",
+        "[1, 2, 3, 4]"
+    },
+    {transposed, prod, prod_vec} = {
+        "[[1, 0, 2, 0], [2, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 2]]",
+        "[[0.5, 4.0, 0.0, 1.0], [0.0, 2.0, 0.0, 0.0], [1.0, 0.0, 1.0, 2.0], [-2.0, 0.0, 0.0, 2.0]]",
+        "[5, 2, 5, 8]"
+    },
+    {wrapped, wrapped_vec} = {
+        "M::from_rows([[I16F16::from_num(-4); 4]; 4])",
+        "[I16F16::from_num(-4); 4]"
+    },
+}
+
+impl<F: Fixed> Mat2<F> {
+    /// Returns the determinant.
+    ///
+    /// The products are computed exactly and summed in double width, so the
+    /// result is rounded down only once.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat2};
+    /// let a = Mat2::from_rows([[1, 2], [3, 4]].map(|row| row.map(I16F16::from_num)));
+    /// assert_eq!(a.determinant(), -2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn determinant(self) -> F {
+        let (ans, overflow) = self.overflowing_determinant();
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Checked determinant. Returns the determinant, or [`None`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat2};
+    /// let a = Mat2::from_rows([[1, 2], [3, 4]].map(|row| row.map(I16F16::from_num)));
+    /// assert_eq!(a.checked_determinant(), Some(I16F16::from_num(-2)));
+    /// let b = Mat2::from_rows([[I16F16::MAX, I16F16::MIN], [I16F16::MAX, I16F16::MAX]]);
+    /// assert_eq!(b.checked_determinant(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_determinant(self) -> Option<F> {
+        match self.overflowing_determinant() {
+            (ans, false) => Some(ans),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    fn overflowing_determinant(self) -> (F, bool) {
+        let [[a, b], [c, d]] = self.rows;
+        let mut overflow = false;
+        let det = sum(&[(a, d)], &[(b, c)], &mut overflow);
+        (det, overflow)
+    }
+}
+
+impl<F: Fixed> Mat3<F> {
+    /// Returns the determinant.
+    ///
+    /// The determinant is computed by expanding along the first row. Each of
+    /// the 2×2 minors and the final sum are computed exactly in double width
+    /// and rounded down once, so the minors are rounded before they are
+    /// multiplied by the elements of the first row.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result or
+    /// one of the minors overflows. When debug assertions are not enabled, the
+    /// wrapped value can be returned, but it is not considered a breaking
+    /// change if in the future it panics; if overflow needs to be detected use
+    /// [`checked_determinant`][Mat3::checked_determinant] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3};
+    /// let a = Mat3::from_rows([[2, 4, 0], [0, 1, 0], [2, 0, 1]].map(|row| row.map(I16F16::from_num)));
+    /// assert_eq!(a.determinant(), 2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn determinant(self) -> F {
+        let (ans, overflow) = self.overflowing_determinant();
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Checked determinant. Returns the determinant, or [`None`] if the
+    /// result or one of the minors overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3};
+    /// let a = Mat3::from_rows([[2, 4, 0], [0, 1, 0], [2, 0, 1]].map(|row| row.map(I16F16::from_num)));
+    /// assert_eq!(a.checked_determinant(), Some(I16F16::from_num(2)));
+    /// let max = Mat3::from_rows([[I16F16::MAX; 3]; 3]);
+    /// assert_eq!(max.checked_determinant(), Some(I16F16::ZERO));
+    /// let b = Mat3::from_rows([[200, 0, 0], [0, 200, 0], [0, 0, 1]].map(|row| row.map(I16F16::from_num)));
+    /// assert_eq!(b.checked_determinant(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_determinant(self) -> Option<F> {
+        match self.overflowing_determinant() {
+            (ans, false) => Some(ans),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    fn overflowing_determinant(self) -> (F, bool) {
+        let mut overflow = false;
+        let cofactors = self.cofactors(&mut overflow);
+        let det = self.det_from_cofactors(&cofactors, &mut overflow);
+        (det, overflow)
+    }
+
+    // Returns the cofactors, each computed exactly and rounded down once.
+    // With the rows and columns taken cyclically, the cofactor signs are
+    // included in the 2×2 determinants.
+    #[inline]
+    fn cofactors(self, overflow: &mut bool) -> [[F; 3]; 3] {
+        let m = self.rows;
+        array::from_fn(|i| {
+            let (r1, r2) = ((i + 1) % 3, (i + 2) % 3);
+            array::from_fn(|j| {
+                let (c1, c2) = ((j + 1) % 3, (j + 2) % 3);
+                sum(
+                    &[(m[r1][c1], m[r2][c2])],
+                    &[(m[r1][c2], m[r2][c1])],
+                    overflow,
+                )
+            })
+        })
+    }
+
+    #[inline]
+    fn det_from_cofactors(self, cofactors: &[[F; 3]; 3], overflow: &mut bool) -> F {
+        let m = self.rows;
+        let terms: [(F, F); 3] = array::from_fn(|j| (m[0][j], cofactors[0][j]));
+        sum(&terms, &[], overflow)
+    }
+
+    /// Creates a two-dimensional affine transform that translates points by
+    /// `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number type cannot represent one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3, Vec2};
+    /// let offset = Vec2::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// let translation = Mat3::from_translation(offset);
+    /// let p = Vec2::new(I16F16::from_num(1), I16F16::from_num(1));
+    /// assert_eq!(translation.transform_point(p), p + offset);
+    /// // vectors are not affected by translation
+    /// assert_eq!(translation.transform_vector(p), p);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn from_translation(offset: Vec2<F>) -> Mat3<F> {
+        let one = unwrap_one::<F>();
+        let zero = F::ZERO;
+        Mat3 {
+            rows: [
+                [one, zero, offset.x],
+                [zero, one, offset.y],
+                [zero, zero, one],
+            ],
+        }
+    }
+
+    /// Creates a two-dimensional affine transform that scales the <i>x</i>
+    /// and <i>y</i> coordinates by the components of `scale`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number type cannot represent one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3, Vec2};
+    /// let scale = Mat3::from_scale(Vec2::new(I16F16::from_num(2), I16F16::from_num(0.5)));
+    /// let p = Vec2::new(I16F16::from_num(3), I16F16::from_num(4));
+    /// assert_eq!(
+    ///     scale.transform_point(p),
+    ///     Vec2::new(I16F16::from_num(6), I16F16::from_num(2))
+    /// );
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn from_scale(scale: Vec2<F>) -> Mat3<F> {
+        let one = unwrap_one::<F>();
+        let zero = F::ZERO;
+        Mat3 {
+            rows: [
+                [scale.x, zero, zero],
+                [zero, scale.y, zero],
+                [zero, zero, one],
+            ],
+        }
+    }
+
+    /// Applies the matrix as a two-dimensional affine transform to a point.
+    ///
+    /// The point is treated as the homogeneous column vector
+    /// (<i>x</i>,&nbsp;<i>y</i>,&nbsp;1), and the third row of the matrix is
+    /// ignored, so the matrix is assumed to be an affine transform. Each
+    /// component of the result is computed exactly and rounded down once.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3, Vec2};
+    /// let offset = Vec2::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// let translation = Mat3::from_translation(offset);
+    /// let p = Vec2::new(I16F16::from_num(1), I16F16::from_num(1));
+    /// assert_eq!(
+    ///     translation.transform_point(p),
+    ///     Vec2::new(I16F16::from_num(2.5), I16F16::from_num(-1))
+    /// );
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn transform_point(self, point: Vec2<F>) -> Vec2<F> {
+        let (ans, overflow) = self.overflowing_transform(point, true);
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Checked affine transform of a point. Returns the transformed point, or
+    /// [`None`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3, Vec2};
+    /// let offset = Vec2::new(I16F16::MAX, I16F16::ZERO);
+    /// let translation = Mat3::from_translation(offset);
+    /// let p = Vec2::new(I16F16::from_num(-1), I16F16::from_num(1));
+    /// let moved = Vec2::new(I16F16::MAX - I16F16::from_num(1), I16F16::from_num(1));
+    /// assert_eq!(translation.checked_transform_point(p), Some(moved));
+    /// assert_eq!(translation.checked_transform_point(-p), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_transform_point(self, point: Vec2<F>) -> Option<Vec2<F>> {
+        match self.overflowing_transform(point, true) {
+            (ans, false) => Some(ans),
+            (_, true) => None,
+        }
+    }
+
+    /// Applies the matrix as a two-dimensional affine transform to a vector.
+    ///
+    /// The vector is treated as the homogeneous column vector
+    /// (<i>x</i>,&nbsp;<i>y</i>,&nbsp;0), so it is not affected by
+    /// translation, and the third row of the matrix is ignored. Each component
+    /// of the result is computed exactly and rounded down once.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3, Vec2};
+    /// let scale = Mat3::from_scale(Vec2::new(I16F16::from_num(2), I16F16::from_num(0.5)));
+    /// let offset = Vec2::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// let transform = Mat3::from_translation(offset) * scale;
+    /// let v = Vec2::new(I16F16::from_num(3), I16F16::from_num(4));
+    /// assert_eq!(
+    ///     transform.transform_vector(v),
+    ///     Vec2::new(I16F16::from_num(6), I16F16::from_num(2))
+    /// );
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn transform_vector(self, vector: Vec2<F>) -> Vec2<F> {
+        let (ans, overflow) = self.overflowing_transform(vector, false);
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Checked affine transform of a vector. Returns the transformed vector,
+    /// or [`None`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3, Vec2};
+    /// let scale = Mat3::from_scale(Vec2::new(I16F16::from_num(2), I16F16::from_num(0.5)));
+    /// let v = Vec2::new(I16F16::from_num(3), I16F16::from_num(4));
+    /// let scaled = Vec2::new(I16F16::from_num(6), I16F16::from_num(2));
+    /// assert_eq!(scale.checked_transform_vector(v), Some(scaled));
+    /// let max = Vec2::new(I16F16::MAX, I16F16::ZERO);
+    /// assert_eq!(scale.checked_transform_vector(max), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_transform_vector(self, vector: Vec2<F>) -> Option<Vec2<F>> {
+        match self.overflowing_transform(vector, false) {
+            (ans, false) => Some(ans),
+            (_, true) => None,
+        }
+    }
+
+    // Transforms a point or a vector. The translation is added exactly after
+    // the products are summed, which still rounds only once since the
+    // translation is a representable number. An intermediate sum that does
+    // not fit is not an overflow if the translation brings it back in range.
+    #[inline]
+    fn overflowing_transform(self, v: Vec2<F>, point: bool) -> (Vec2<F>, bool) {
+        let m = self.rows;
+        let mut overflow = false;
+        let [x, y] = [0, 1].map(|i| {
+            let terms = [(m[i][0], v.x), (m[i][1], v.y)];
+            match (point, F::TRY_ONE) {
+                (false, _) => {
+                    let (val, o, _) = F::sum_prods(&terms, &[]);
+                    overflow |= o;
+                    val
+                }
+                (true, Some(one)) => {
+                    let (val, o, _) = F::sum_prods(&[terms[0], terms[1], (m[i][2], one)], &[]);
+                    overflow |= o;
+                    val
+                }
+                (true, None) => {
+                    let (val, o, _) = F::sum_prods(&terms, &[]);
+                    let (val, o2) = val.overflowing_add(m[i][2]);
+                    overflow |= o | o2;
+                    val
+                }
+            }
+        });
+        (Vec2 { x, y }, overflow)
+    }
+}
+
+impl<F: Fixed> Mat4<F> {
+    /// Returns the determinant.
+    ///
+    /// The determinant is computed from the 2×2 minors of the first two rows
+    /// and the last two rows. Each of the minors and the final sum are
+    /// computed exactly in double width and rounded down once, so the minors
+    /// are rounded before they are multiplied together.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result or
+    /// one of the minors overflows. When debug assertions are not enabled, the
+    /// wrapped value can be returned, but it is not considered a breaking
+    /// change if in the future it panics; if overflow needs to be detected use
+    /// [`checked_determinant`][Mat4::checked_determinant] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat4};
+    /// let a = Mat4::from_rows(
+    ///     [[1, 2, 0, 0], [0, 1, 0, 0], [2, 0, 1, 0], [0, 0, 0, 2]].map(|row| row.map(I16F16::from_num)),
+    /// );
+    /// assert_eq!(a.determinant(), 2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn determinant(self) -> F {
+        let (ans, overflow) = self.overflowing_determinant();
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Checked determinant. Returns the determinant, or [`None`] if the
+    /// result or one of the minors overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat4};
+    /// let a = Mat4::from_rows(
+    ///     [[1, 2, 0, 0], [0, 1, 0, 0], [2, 0, 1, 0], [0, 0, 0, 2]].map(|row| row.map(I16F16::from_num)),
+    /// );
+    /// assert_eq!(a.checked_determinant(), Some(I16F16::from_num(2)));
+    /// let b = Mat4::from_rows(
+    ///     [[200, 0, 0, 0], [0, 200, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]
+    ///         .map(|row| row.map(I16F16::from_num)),
+    /// );
+    /// assert_eq!(b.checked_determinant(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_determinant(self) -> Option<F> {
+        match self.overflowing_determinant() {
+            (ans, false) => Some(ans),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    fn overflowing_determinant(self) -> (F, bool) {
+        let mut overflow = false;
+        let (s, c) = self.minors(&mut overflow);
+        let det = det_from_minors(&s, &c, &mut overflow);
+        (det, overflow)
+    }
+
+    // Returns the 2×2 minors s of the first two rows and c of the last two
+    // rows, each computed exactly and rounded down once.
+    #[inline]
+    fn minors(self, overflow: &mut bool) -> ([F; 6], [F; 6]) {
+        let m = self.rows;
+        let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        let s = pairs.map(|(j, k)| sum(&[(m[0][j], m[1][k])], &[(m[1][j], m[0][k])], overflow));
+        let c = pairs.map(|(j, k)| sum(&[(m[2][j], m[3][k])], &[(m[3][j], m[2][k])], overflow));
+        (s, c)
+    }
+}
+
+// Returns the determinant of a 4×4 matrix from its minors, which is
+// s0 c5 - s1 c4 + s2 c3 + s3 c2 - s4 c1 + s5 c0.
+#[inline]
+fn det_from_minors<F: Fixed>(s: &[F; 6], c: &[F; 6], overflow: &mut bool) -> F {
+    sum(
+        &[(s[0], c[5]), (s[2], c[3]), (s[3], c[2]), (s[5], c[0])],
+        &[(s[1], c[4]), (s[4], c[1])],
+        overflow,
+    )
+}
+
+impl<F: FixedBoundFrac> Mat2<F> {
+    /// Returns the inverse, or [`None`] if the matrix is singular or if the
+    /// inverse overflows.
+    ///
+    /// The inverse is computed by dividing the adjugate by the determinant.
+    /// The determinant is rounded down, and each element of the inverse is
+    /// then rounded towards zero by the division. A determinant that is
+    /// rounded down to zero is treated as singular.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat2};
+    /// type M = Mat2<I16F16>;
+    /// let a = M::from_rows([[1, 2], [3, 4]].map(|row| row.map(I16F16::from_num)));
+    /// let inv = M::from_rows([[-2.0, 1.0], [1.5, -0.5]].map(|row| row.map(I16F16::from_num)));
+    /// assert_eq!(a.checked_inverse(), Some(inv));
+    /// let singular = M::from_rows([[1, 2], [2, 4]].map(|row| row.map(I16F16::from_num)));
+    /// assert_eq!(singular.checked_inverse(), None);
+    /// // the determinant is 2⁻¹⁶, so the inverse does not fit
+    /// let small = M::from_rows([[I16F16::DELTA, I16F16::ZERO], [I16F16::ZERO, I16F16::ONE]]);
+    /// assert_eq!(small.checked_inverse(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_inverse(self) -> Option<Mat2<F>> {
+        let [[a, b], [c, d]] = self.rows;
+        let det = self.checked_determinant()?;
+        Some(Mat2 {
+            rows: [
+                [d.checked_div(det)?, b.checked_div(det)?.checked_neg()?],
+                [c.checked_div(det)?.checked_neg()?, a.checked_div(det)?],
+            ],
+        })
+    }
+}
+
+impl<F: FixedBoundFrac> Mat3<F> {
+    /// Returns the inverse, or [`None`] if the matrix is singular or if the
+    /// inverse or one of the intermediate values overflows.
+    ///
+    /// The inverse is computed by dividing the adjugate by the determinant.
+    /// The cofactors and the determinant are rounded down, and each element
+    /// of the inverse is then rounded towards zero by the division. A
+    /// determinant that is rounded down to zero is treated as singular.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3};
+    /// type M = Mat3<I16F16>;
+    /// let a = M::from_rows([[2, 4, 0], [0, 1, 0], [2, 0, 1]].map(|row| row.map(I16F16::from_num)));
+    /// let inv = M::from_rows(
+    ///     [[0.5, -2.0, 0.0], [0.0, 1.0, 0.0], [-1.0, 4.0, 1.0]].map(|row| row.map(I16F16::from_num)),
+    /// );
+    /// assert_eq!(a.checked_inverse(), Some(inv));
+    /// let singular = M::from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]].map(|row| row.map(I16F16::from_num)));
+    /// assert_eq!(singular.checked_inverse(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_inverse(self) -> Option<Mat3<F>> {
+        let mut overflow = false;
+        let cofactors = self.cofactors(&mut overflow);
+        let det = self.det_from_cofactors(&cofactors, &mut overflow);
+        if overflow {
+            return None;
+        }
+        let mut rows = [[F::ZERO; 3]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, elem) in row.iter_mut().enumerate() {
+                *elem = cofactors[j][i].checked_div(det)?;
+            }
+        }
+        Some(Mat3 { rows })
+    }
+
+    /// Creates a two-dimensional affine transform that rotates points
+    /// counterclockwise by `angle` radians about the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number type cannot represent one.
+    ///
+    /// When debug assertions are enabled, this method also panics if the sine
+    /// or cosine of the angle overflows, or if the negated sine overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat3, Vec2};
+    /// let rotation = Mat3::from_angle(I16F16::FRAC_PI_2);
+    /// let p = Vec2::new(I16F16::from_num(2), I16F16::ZERO);
+    /// let rotated = rotation.transform_point(p);
+    /// assert!(rotated.x.abs() <= I16F16::from_num(0.001));
+    /// assert!((rotated.y - I16F16::from_num(2)).abs() <= I16F16::from_num(0.001));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn from_angle(angle: F) -> Mat3<F> {
+        let one = unwrap_one::<F>();
+        let zero = F::ZERO;
+        let (sin, cos) = angle.sin_cos();
+        Mat3 {
+            rows: [[cos, zero - sin, zero], [sin, cos, zero], [zero, zero, one]],
+        }
+    }
+}
+
+impl<F: FixedBoundFrac> Mat4<F> {
+    /// Returns the inverse, or [`None`] if the matrix is singular or if the
+    /// inverse or one of the intermediate values overflows.
+    ///
+    /// The inverse is computed by dividing the adjugate by the determinant,
+    /// both of which are computed from the 2×2 minors of the first two rows
+    /// and the last two rows. The minors, the elements of the adjugate and the
+    /// determinant are each computed exactly in double width and rounded down
+    /// once, and each element of the inverse is then rounded towards zero by
+    /// the division. A determinant that is rounded down to zero is treated as
+    /// singular.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Mat4};
+    /// type M = Mat4<I16F16>;
+    /// let a = M::from_rows(
+    ///     [[1, 2, 0, 0], [0, 1, 0, 0], [2, 0, 1, 0], [0, 0, 0, 2]].map(|row| row.map(I16F16::from_num)),
+    /// );
+    /// let inv = M::from_rows(
+    ///     [
+    ///         [1.0, -2.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0, 0.0],
+    ///         [-2.0, 4.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 0.0, 0.5],
+    ///     ]
+    ///     .map(|row| row.map(I16F16::from_num)),
+    /// );
+    /// assert_eq!(a.checked_inverse(), Some(inv));
+    /// assert_eq!(M::ZERO.checked_inverse(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_inverse(self) -> Option<Mat4<F>> {
+        let m = self.rows;
+        let mut overflow = false;
+        let (s, c) = self.minors(&mut overflow);
+        let det = det_from_minors(&s, &c, &mut overflow);
+        let o = &mut overflow;
+        #[rustfmt::skip]
+        let adj = [
+            [
+                sum(&[(m[1][1], c[5]), (m[1][3], c[3])], &[(m[1][2], c[4])], o),
+                sum(&[(m[0][2], c[4])], &[(m[0][1], c[5]), (m[0][3], c[3])], o),
+                sum(&[(m[3][1], s[5]), (m[3][3], s[3])], &[(m[3][2], s[4])], o),
+                sum(&[(m[2][2], s[4])], &[(m[2][1], s[5]), (m[2][3], s[3])], o),
+            ],
+            [
+                sum(&[(m[1][2], c[2])], &[(m[1][0], c[5]), (m[1][3], c[1])], o),
+                sum(&[(m[0][0], c[5]), (m[0][3], c[1])], &[(m[0][2], c[2])], o),
+                sum(&[(m[3][2], s[2])], &[(m[3][0], s[5]), (m[3][3], s[1])], o),
+                sum(&[(m[2][0], s[5]), (m[2][3], s[1])], &[(m[2][2], s[2])], o),
+            ],
+            [
+                sum(&[(m[1][0], c[4]), (m[1][3], c[0])], &[(m[1][1], c[2])], o),
+                sum(&[(m[0][1], c[2])], &[(m[0][0], c[4]), (m[0][3], c[0])], o),
+                sum(&[(m[3][0], s[4]), (m[3][3], s[0])], &[(m[3][1], s[2])], o),
+                sum(&[(m[2][1], s[2])], &[(m[2][0], s[4]), (m[2][3], s[0])], o),
+            ],
+            [
+                sum(&[(m[1][1], c[1])], &[(m[1][0], c[3]), (m[1][2], c[0])], o),
+                sum(&[(m[0][0], c[3]), (m[0][2], c[0])], &[(m[0][1], c[1])], o),
+                sum(&[(m[3][1], s[1])], &[(m[3][0], s[3]), (m[3][2], s[0])], o),
+                sum(&[(m[2][0], s[3]), (m[2][2], s[0])], &[(m[2][1], s[1])], o),
+            ],
+        ];
+        if overflow {
+            return None;
+        }
+        let mut rows = [[F::ZERO; 4]; 4];
+        for (row, adj_row) in rows.iter_mut().zip(adj) {
+            for (elem, adj_elem) in row.iter_mut().zip(adj_row) {
+                *elem = adj_elem.checked_div(det)?;
+            }
+        }
+        Some(Mat4 { rows })
+    }
+}
+
+// Returns the exact sum of products rounded down once, setting overflow if it
+// does not fit.
+#[inline]
+fn sum<F: Fixed>(add: &[(F, F)], sub: &[(F, F)], overflow: &mut bool) -> F {
+    let (ans, o, _) = F::sum_prods(add, sub);
+    *overflow |= o;
+    ans
+}
+
+#[inline]
+#[track_caller]
+fn unwrap_one<F: Fixed>() -> F {
+    match F::TRY_ONE {
+        Some(one) => one,
+        None => panic!("one cannot be represented"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dot::tests::{round_prods, PROD_BITS};
+    use crate::types::{I16F16, I8F8, U8F8};
+    use crate::{FixedI128, FixedI16, FixedI32, Mat2, Mat3, Mat4, Vec2, Vec3};
+
+    // Checks matrix products against the exact products computed using integer
+    // arithmetic, with each element rounded down and wrapped to 16 bits.
+    fn check_mul<const FRAC: i32>() {
+        type F<const FRAC: i32> = FixedI16<FRAC>;
+        let values = PROD_BITS;
+        let round = round_prods::<FRAC>;
+        let len = values.len();
+        for start in 0..len {
+            let a: [[i16; 3]; 3] = core::array::from_fn(|i| {
+                core::array::from_fn(|j| values[(start + 3 * i + j) % len])
+            });
+            let b: [[i16; 3]; 3] = core::array::from_fn(|i| {
+                core::array::from_fn(|j| values[(start * 7 + 2 * i + 5 * j) % len])
+            });
+            let x = Mat3::from_rows(a.map(|row| row.map(F::<FRAC>::from_bits)));
+            let y = Mat3::from_rows(b.map(|row| row.map(F::<FRAC>::from_bits)));
+            let exact: [[i64; 3]; 3] = core::array::from_fn(|i| {
+                core::array::from_fn(|j| {
+                    (0..3)
+                        .map(|k| i64::from(a[i][k]) * i64::from(b[k][j]))
+                        .sum()
+                })
+            });
+            let mut overflow = false;
+            let mut wrapped = Mat3::<F<FRAC>>::ZERO;
+            let mut saturated = Mat3::<F<FRAC>>::ZERO;
+            for (i, row) in exact.iter().enumerate() {
+                for (j, &elem) in row.iter().enumerate() {
+                    let (val, o, sat) = round(elem);
+                    wrapped.rows[i][j] = val;
+                    saturated.rows[i][j] = sat;
+                    overflow |= o;
+                }
+            }
+            assert_eq!(x.overflowing_mul(y), (wrapped, overflow), "{x:?} × {y:?}");
+            assert_eq!(x.checked_mul(y), (!overflow).then_some(wrapped));
+            assert_eq!(x.saturating_mul(y), saturated, "{x:?} × {y:?}");
+
+            let v = Vec3::from(y.rows[0]);
+            let mut overflow = false;
+            let mut wrapped = [F::<FRAC>::ZERO; 3];
+            let mut saturated = [F::<FRAC>::ZERO; 3];
+            for i in 0..3 {
+                let exact = (0..3)
+                    .map(|k| i64::from(a[i][k]) * i64::from(b[0][k]))
+                    .sum::<i64>();
+                let (val, o, sat) = round(exact);
+                wrapped[i] = val;
+                saturated[i] = sat;
+                overflow |= o;
+            }
+            assert_eq!(x.overflowing_mul_vec(v), (Vec3::from(wrapped), overflow));
+            assert_eq!(x.saturating_mul_vec(v), Vec3::from(saturated));
+        }
+    }
+
+    #[test]
+    fn mul_rounds_once() {
+        check_mul::<0>();
+        check_mul::<8>();
+        check_mul::<15>();
+        check_mul::<16>();
+        check_mul::<20>();
+        check_mul::<{ -3 }>();
+    }
+
+    #[test]
+    fn single_rounding() {
+        // (10Δ)² = 0.390625Δ is rounded down to zero on its own, but the sum of
+        // three such products is 1.171875Δ, which is rounded down to Δ
+        let q = I8F8::from_bits(10);
+        let zero = I8F8::ZERO;
+        let a = Mat3::from_rows([[q, q, q], [zero; 3], [zero; 3]]);
+        let b = Mat3::from_rows([[q, zero, zero]; 3]);
+        let mut expected = Mat3::ZERO;
+        expected.rows[0][0] = I8F8::DELTA;
+        assert_eq!(a * b, expected);
+        assert_eq!(a * Vec3::new(q, q, q), Vec3::new(I8F8::DELTA, zero, zero));
+        assert_eq!(q * q + q * q + q * q, zero);
+        // (12Δ)² + (12Δ)² = 1.125Δ
+        let q = I8F8::from_bits(12);
+        assert_eq!(
+            Mat2::from_rows([[q, -q], [q, q]]).determinant(),
+            I8F8::DELTA
+        );
+    }
+
+    #[test]
+    fn transpose() {
+        let a = Mat4::from_rows(core::array::from_fn(|i| {
+            core::array::from_fn(|j| I8F8::from_num(4 * i + j))
+        }));
+        let t = a.transpose();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(t.rows[i][j], a.rows[j][i]);
+            }
+        }
+        assert_eq!(t.transpose(), a);
+        assert_eq!(<[[I8F8; 4]; 4]>::from(a), a.rows);
+        assert_eq!(Mat4::from(a.rows), a);
+        let mut b = Mat2::<I8F8>::TRY_IDENTITY.unwrap();
+        b *= Mat2::from_rows([[I8F8::ONE, I8F8::ONE], [I8F8::ZERO, I8F8::ONE]]);
+        assert_eq!(
+            b.transpose(),
+            Mat2::from_rows([[I8F8::ONE, I8F8::ZERO], [I8F8::ONE, I8F8::ONE]])
+        );
+    }
+
+    // Computes the exact determinant of an integer matrix by cofactor
+    // expansion along the first row.
+    fn det_ref(m: &[[i64; 4]; 4], n: usize) -> i64 {
+        if n == 1 {
+            return m[0][0];
+        }
+        let mut det = 0;
+        for j in 0..n {
+            let mut minor = [[0; 4]; 4];
+            for r in 1..n {
+                for (c, k) in (0..n).filter(|&k| k != j).enumerate() {
+                    minor[r - 1][c] = m[r][k];
+                }
+            }
+            let term = m[0][j] * det_ref(&minor, n - 1);
+            det += if j % 2 == 0 { term } else { -term };
+        }
+        det
+    }
+
+    #[test]
+    fn determinant() {
+        type F = FixedI32<0>;
+        let values = [3, -1, 4, 1, -5, 9, 2, -6, 5, 3, -5, 8, 9, 7, -9, 3, 2];
+        for start in 0..values.len() {
+            let m: [[i64; 4]; 4] = core::array::from_fn(|i| {
+                core::array::from_fn(|j| values[(start + 5 * i + j) % values.len()])
+            });
+            let a4 = Mat4::from_rows(m.map(|row| row.map(F::from_num)));
+            assert_eq!(a4.determinant(), det_ref(&m, 4), "{m:?}");
+            let a3 = Mat3::from_rows(core::array::from_fn(|i| {
+                core::array::from_fn(|j| F::from_num(m[i][j]))
+            }));
+            assert_eq!(a3.determinant(), det_ref(&m, 3), "{m:?}");
+            let a2 = Mat2::from_rows([
+                [F::from_num(m[0][0]), F::from_num(m[0][1])],
+                [F::from_num(m[1][0]), F::from_num(m[1][1])],
+            ]);
+            assert_eq!(a2.determinant(), det_ref(&m, 2), "{m:?}");
+
+            // with a determinant of ±1, the inverse is exact
+            let unit = Mat4::from_rows(
+                [[1, 2, 0, -1], [0, 1, 3, 0], [0, 0, 1, 4], [0, 0, 0, -1]]
+                    .map(|row| row.map(F::from_num)),
+            );
+            let b = a4 * unit;
+            if det_ref(&m, 4) != 0 {
+                let inv = unit.checked_inverse().unwrap();
+                assert_eq!(b * inv, a4);
+                assert_eq!(inv * unit, Mat4::TRY_IDENTITY.unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        let a = Mat3::from_rows(
+            [[4.0, -2.0, 1.0], [0.5, 3.0, -1.0], [2.0, 1.0, 2.5]]
+                .map(|row| row.map(I16F16::from_num)),
+        );
+        let inv = a.checked_inverse().unwrap();
+        let identity = Mat3::<I16F16>::TRY_IDENTITY.unwrap();
+        let prod = a * inv;
+        for i in 0..3 {
+            for j in 0..3 {
+                let err = (prod.rows[i][j] - identity.rows[i][j]).abs();
+                assert!(err <= 8 * I16F16::DELTA, "{prod:?}");
+            }
+        }
+        let b = Mat4::from_rows(
+            [
+                [4.0, -2.0, 1.0, 0.25],
+                [0.5, 3.0, -1.0, 0.0],
+                [2.0, 1.0, 2.5, -1.5],
+                [0.0, 1.0, 0.0, 2.0],
+            ]
+            .map(|row| row.map(I16F16::from_num)),
+        );
+        let inv = b.checked_inverse().unwrap();
+        let prod = inv * b;
+        let identity = Mat4::<I16F16>::TRY_IDENTITY.unwrap();
+        for i in 0..4 {
+            for j in 0..4 {
+                let err = (prod.rows[i][j] - identity.rows[i][j]).abs();
+                assert!(err <= 16 * I16F16::DELTA, "{prod:?}");
+            }
+        }
+
+        // singular matrices
+        let singular = Mat4::from_rows(
+            [[1, 2, 3, 4], [2, 4, 6, 8], [0, 1, 0, 1], [1, 0, 1, 0]]
+                .map(|row| row.map(I16F16::from_num)),
+        );
+        assert_eq!(singular.checked_determinant(), Some(I16F16::ZERO));
+        assert_eq!(singular.checked_inverse(), None);
+        assert_eq!(Mat2::<I16F16>::ZERO.checked_inverse(), None);
+        assert_eq!(Mat3::<I16F16>::ZERO.checked_inverse(), None);
+
+        // the determinant does not fit
+        let big = Mat3::from_rows(
+            [[100, 0, 0], [0, 100, 0], [0, 0, 100]].map(|row| row.map(I8F8::from_num)),
+        );
+        assert_eq!(big.checked_determinant(), None);
+        assert_eq!(big.checked_inverse(), None);
+        // the inverse does not fit
+        let small = Mat2::from_rows([[I8F8::DELTA, I8F8::ZERO], [I8F8::ZERO, I8F8::ONE]]);
+        assert_eq!(small.checked_determinant(), Some(I8F8::DELTA));
+        assert_eq!(small.checked_inverse(), None);
+
+        // the inverse of an unsigned matrix only fits if it is diagonal
+        let diag = Mat2::from_rows([[U8F8::from_num(2), U8F8::ZERO], [U8F8::ZERO, U8F8::ONE]]);
+        assert_eq!(
+            diag.checked_inverse(),
+            Some(Mat2::from_rows([
+                [U8F8::from_num(0.5), U8F8::ZERO],
+                [U8F8::ZERO, U8F8::ONE]
+            ]))
+        );
+        let upper = Mat2::from_rows([[U8F8::ONE, U8F8::ONE], [U8F8::ZERO, U8F8::ONE]]);
+        assert_eq!(upper.checked_determinant(), Some(U8F8::ONE));
+        assert_eq!(upper.checked_inverse(), None);
+    }
+
+    #[test]
+    fn near_singular_inverse() {
+        // The determinant is 2⁻¹⁰, and the inverse fits and is exact.
+        let eps = I16F16::from_num(1.0 / 1024.0);
+        let one = I16F16::ONE;
+        let a = Mat2::from_rows([[one, one], [one, one + eps]]);
+        assert_eq!(a.checked_determinant(), Some(eps));
+        let inv =
+            Mat2::from_rows([[1025, -1024], [-1024, 1024]].map(|row| row.map(I16F16::from_num)));
+        assert_eq!(a.checked_inverse(), Some(inv));
+        // With a determinant of Δ, the inverse does not fit.
+        let b = Mat2::from_rows([[one, one], [one, one + I16F16::DELTA]]);
+        assert_eq!(b.checked_determinant(), Some(I16F16::DELTA));
+        assert_eq!(b.checked_inverse(), None);
+        // The exact determinant (8Δ)² = 0.25Δ is not zero, but it is rounded
+        // down to zero, so the matrix is treated as singular even though the
+        // exact inverse would fit.
+        let q = I8F8::from_bits(8);
+        let c = Mat2::from_rows([[q, I8F8::ZERO], [I8F8::ZERO, q]]);
+        assert_eq!(c.checked_determinant(), Some(I8F8::ZERO));
+        assert_eq!(c.checked_inverse(), None);
+        // A negative exact determinant of -0.25Δ is rounded down to -Δ, so
+        // the inverse only has a quarter of the exact magnitude 32.
+        let d = Mat2::from_rows([[q, I8F8::ZERO], [I8F8::ZERO, -q]]);
+        assert_eq!(d.checked_determinant(), Some(-I8F8::DELTA));
+        let inv = Mat2::from_rows([[8, 0], [0, -8]].map(|row| row.map(I8F8::from_num)));
+        assert_eq!(d.checked_inverse(), Some(inv));
+
+        // Rows that are linearly dependent up to Δ.
+        let rows = [[2.0, -1.0, 0.5], [4.0, -2.0, 1.0], [1.0, 3.0, -2.0]];
+        let mut e = Mat3::from_rows(rows.map(|row| row.map(I16F16::from_num)));
+        assert_eq!(e.checked_determinant(), Some(I16F16::ZERO));
+        assert_eq!(e.checked_inverse(), None);
+        e.rows[1][2] += I16F16::DELTA;
+        // det = -Δ × (2 × 3 - (-1) × 1) = -7Δ, and the inverse does not fit
+        assert_eq!(e.checked_determinant(), Some(-7 * I16F16::DELTA));
+        assert_eq!(e.checked_inverse(), None);
+        let mut f = Mat4::from_rows(
+            [[1, 0, 2, 0], [0, 1, 0, 3], [1, 1, 2, 3], [0, 2, 1, 0]]
+                .map(|row| row.map(I16F16::from_num)),
+        );
+        assert_eq!(f.checked_determinant(), Some(I16F16::ZERO));
+        assert_eq!(f.checked_inverse(), None);
+        f.rows[2][0] += eps;
+        assert_eq!(f.checked_determinant(), Some(12 * eps));
+        assert!(f.checked_inverse().is_some());
+    }
+
+    #[test]
+    fn affine() {
+        let offset = Vec2::new(I16F16::from_num(3), I16F16::from_num(-1.5));
+        let scale = Vec2::new(I16F16::from_num(2), I16F16::from_num(0.25));
+        let transform = Mat3::from_translation(offset) * Mat3::from_scale(scale);
+        let p = Vec2::new(I16F16::from_num(1), I16F16::from_num(4));
+        assert_eq!(
+            transform.transform_point(p),
+            Vec2::new(I16F16::from_num(5), I16F16::from_num(-0.5))
+        );
+        assert_eq!(
+            transform.transform_vector(p),
+            Vec2::new(I16F16::from_num(2), I16F16::from_num(1))
+        );
+        let homogeneous = transform * Vec3::new(p.x, p.y, I16F16::ONE);
+        assert_eq!(
+            Vec2::new(homogeneous.x, homogeneous.y),
+            transform.transform_point(p)
+        );
+        let inv = transform.checked_inverse().unwrap();
+        assert_eq!(inv.transform_point(transform.transform_point(p)), p);
+
+        // a quarter turn, which is exact up to the rounding of sin and cos
+        let rotation = Mat3::from_angle(I16F16::FRAC_PI_2);
+        let q = rotation.transform_point(Vec2::new(I16F16::from_num(2), I16F16::ZERO));
+        assert!(q.x.abs() <= 2 * I16F16::DELTA);
+        assert!((q.y - I16F16::from_num(2)).abs() <= 2 * I16F16::DELTA);
+        assert_eq!(rotation.rows[2], [I16F16::ZERO, I16F16::ZERO, I16F16::ONE]);
+        let (sin, cos) = I16F16::from_num(0.5).sin_cos();
+        let r = Mat3::from_angle(I16F16::from_num(0.5));
+        assert_eq!(r.rows[0][..2], [cos, -sin]);
+        assert_eq!(r.rows[1][..2], [sin, cos]);
+
+        // the intermediate sum overflows, but the translation brings it back
+        let max = Mat3::from_rows([
+            [I8F8::MAX, I8F8::ZERO, I8F8::MIN],
+            [I8F8::ZERO, I8F8::ONE, I8F8::ZERO],
+            [I8F8::ZERO, I8F8::ZERO, I8F8::ONE],
+        ]);
+        let two = Vec2::new(I8F8::from_num(2), I8F8::ZERO);
+        assert_eq!(
+            max.checked_transform_point(two),
+            Some(Vec2::new(I8F8::MAX - I8F8::DELTA, I8F8::ZERO))
+        );
+        assert_eq!(max.checked_transform_vector(two), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "one cannot be represented")]
+    fn translation_without_one() {
+        use crate::types::I0F16;
+        let _ = Mat3::from_translation(Vec2::new(I0F16::ZERO, I0F16::ZERO));
+    }
+
+    #[test]
+    fn wide() {
+        type F = FixedI128<64>;
+        let big = F::from_num(1u64 << 40);
+        let a = Mat2::from_rows([[big, big + F::DELTA], [F::DELTA, F::DELTA]]);
+        let b = Mat2::from_rows([[big, F::ZERO], [-big, -F::DELTA]]);
+        let (prod, overflow) = a.overflowing_mul(b);
+        // big² - big² - Δbig fits, and Δbig - Δbig = 0 is exact
+        assert!(!overflow);
+        assert_eq!(prod.rows[0][0], -(big * F::DELTA));
+        assert_eq!(prod.rows[1][0], F::ZERO);
+        // -Δbig - Δ² and -Δ² are rounded down
+        assert_eq!(prod.rows[0][1], -(big * F::DELTA) - F::DELTA);
+        assert_eq!(prod.rows[1][1], -F::DELTA);
+        let c = Mat2::from_rows([[big, big], [-big, big]]);
+        assert_eq!(c.checked_determinant(), None);
+        let d = Mat2::from_rows([[big, F::DELTA], [F::DELTA, F::DELTA]]);
+        assert_eq!(d.determinant(), big * F::DELTA - F::DELTA);
+    }
+}
@@ -0,0 +1,308 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Integer powers using binary exponentiation with 256-bit significands, so that
+// the result can be rounded only once.
+//
+// Every product is truncated to 256 significant bits, which gives a relative
+// error less than 2^-255. The error of base^(2^k) is doubled by each later
+// squaring, so for |n| < 2^32 the relative error of x^n is less than
+// (2^32 + 64) × 2^-255 < 2^-222, which is negligible once the result is rounded
+// to at most 128 bits. Results that fit in 256 bits, which include all results
+// that are exact in a fixed-point number, are computed exactly.
+
+use crate::int256::{self, U256};
+use crate::real::Real;
+
+const MSB: u128 = 1 << 127;
+
+// The value is (-1)^neg × mant × 2^exp.
+//
+// For non-zero values, the most significant bit of mant is set. For zero, mant
+// is zero.
+#[derive(Clone, Copy, Debug)]
+pub struct Pow {
+    pub neg: bool,
+    pub mant: U256,
+    pub exp: i32,
+}
+
+// x must not be zero if n < 0
+pub const fn powi(x: Real, n: i32) -> Pow {
+    let mut acc = Pow::ONE;
+    let mut base = Pow::from_real(x);
+    let mut k = n.unsigned_abs();
+    loop {
+        if (k & 1) != 0 {
+            acc = acc.mul(base);
+        }
+        k >>= 1;
+        if k == 0 {
+            break;
+        }
+        base = base.mul(base);
+    }
+    if n < 0 {
+        acc.recip()
+    } else {
+        acc
+    }
+}
+
+impl Pow {
+    const ZERO: Pow = Pow {
+        neg: false,
+        mant: U256 { lo: 0, hi: 0 },
+        exp: 0,
+    };
+    const ONE: Pow = Pow {
+        neg: false,
+        mant: U256 { lo: 0, hi: MSB },
+        exp: -255,
+    };
+
+    const fn from_real(x: Real) -> Pow {
+        if x.mant == 0 {
+            return Pow::ZERO;
+        }
+        Pow {
+            neg: x.neg,
+            mant: U256 { lo: 0, hi: x.mant },
+            exp: x.exp - 128,
+        }
+    }
+
+    // The product is truncated to 256 significant bits.
+    const fn mul(self, rhs: Pow) -> Pow {
+        if self.mant.hi == 0 || rhs.mant.hi == 0 {
+            return Pow::ZERO;
+        }
+        let (a, b) = (self.mant, rhs.mant);
+        let ll = int256::wide_mul_u128(a.lo, b.lo);
+        let (mid, carry1) = int256::overflowing_add_u256(
+            int256::wide_mul_u128(a.lo, b.hi),
+            int256::wide_mul_u128(a.hi, b.lo),
+        );
+        let (mid, carry2) = int256::overflowing_add_u256(mid, U256 { lo: ll.hi, hi: 0 });
+        // the 512-bit product is less than 2^512, so this cannot overflow
+        let (top, _) = int256::overflowing_add_u256(
+            int256::wide_mul_u128(a.hi, b.hi),
+            U256 {
+                lo: mid.hi,
+                hi: carry1 as u128 + carry2 as u128,
+            },
+        );
+        // both significands are at least 2^255, so the product is at least 2^510
+        let (mant, exp) = if (top.hi & MSB) != 0 {
+            (top, self.exp + rhs.exp + 256)
+        } else {
+            let mant = U256 {
+                lo: top.lo << 1 | mid.lo >> 127,
+                hi: top.hi << 1 | top.lo >> 127,
+            };
+            (mant, self.exp + rhs.exp + 255)
+        };
+        Pow {
+            neg: self.neg != rhs.neg,
+            mant,
+            exp,
+        }
+        .clamp_exp()
+    }
+
+    // self must not be zero; the reciprocal is truncated to 256 significant
+    // bits.
+    const fn recip(self) -> Pow {
+        let m = self.mant;
+        if m.hi == MSB && m.lo == 0 {
+            return Pow {
+                exp: -510 - self.exp,
+                ..self
+            };
+        }
+        // floor(2^511 / m) is in the range (2^255, 2^256) since 2^255 < m < 2^256,
+        // and is computed one bit at a time keeping rem < m.
+        let mut rem = U256 { lo: 0, hi: MSB };
+        let mut q = U256 { lo: 0, hi: 0 };
+        let mut i = 0;
+        while i < 256 {
+            let carry = (rem.hi & MSB) != 0;
+            rem = U256 {
+                lo: rem.lo << 1,
+                hi: rem.hi << 1 | rem.lo >> 127,
+            };
+            q = U256 {
+                lo: q.lo << 1,
+                hi: q.hi << 1 | q.lo >> 127,
+            };
+            if carry || rem.hi > m.hi || (rem.hi == m.hi && rem.lo >= m.lo) {
+                rem = int256::wrapping_sub_u256(rem, m);
+                q.lo |= 1;
+            }
+            i += 1;
+        }
+        Pow {
+            neg: self.neg,
+            mant: q,
+            exp: -511 - self.exp,
+        }
+        .clamp_exp()
+    }
+
+    // Values with an exponent of magnitude larger than EXP_CLAMP are too large or
+    // too small for any fixed-point number, so the exponent can be clamped to
+    // avoid overflowing it.
+    const fn clamp_exp(self) -> Pow {
+        const EXP_CLAMP: i32 = 1 << 16;
+        let exp = if self.exp > EXP_CLAMP {
+            EXP_CLAMP
+        } else if self.exp < -EXP_CLAMP {
+            -EXP_CLAMP
+        } else {
+            self.exp
+        };
+        Pow { exp, ..self }
+    }
+
+    // Rounds to 128 significant bits.
+    #[inline]
+    pub const fn to_real(self) -> Real {
+        Real::from_u256(self.neg, self.mant, -self.exp)
+    }
+
+    // Returns the absolute value × 2^frac_nbits rounded to the nearest, with
+    // ties rounded to even, wrapped into 128 bits, and whether the absolute value
+    // does not fit in 128 bits.
+    pub const fn to_abs_u128(self, frac_nbits: i32) -> (u128, bool) {
+        let m = self.mant;
+        if m.hi == 0 {
+            return (0, false);
+        }
+        let sh = self.exp.saturating_add(frac_nbits);
+        if sh >= 128 {
+            return (0, true);
+        } else if sh >= 0 {
+            // the value is at least 2^255
+            return (m.lo << sh, true);
+        } else if sh < -256 {
+            return (0, false);
+        } else if sh == -256 {
+            // value is in the range [0.5, 1), and is only a tie if it is 0.5
+            return ((m.hi != MSB || m.lo != 0) as u128, false);
+        }
+        let rsh = sh.unsigned_abs();
+        // int is the integer part, and the discarded bits are rest_hi aligned to
+        // the most significant bit, followed by rest_lo
+        let (int, rest_hi, rest_lo) = if rsh < 128 {
+            let int = U256 {
+                lo: m.lo >> rsh | m.hi << (128 - rsh),
+                hi: m.hi >> rsh,
+            };
+            (int, m.lo << (128 - rsh), 0)
+        } else if rsh == 128 {
+            (U256 { lo: m.hi, hi: 0 }, m.lo, 0)
+        } else {
+            let int = U256 {
+                lo: m.hi >> (rsh - 128),
+                hi: 0,
+            };
+            (int, m.hi << (256 - rsh), m.lo)
+        };
+        let round_up = rest_hi > MSB || (rest_hi == MSB && (rest_lo != 0 || (int.lo & 1) != 0));
+        let int = if round_up {
+            int256::wrapping_add_u256_u128(int, 1)
+        } else {
+            int
+        };
+        (int.lo, int.hi != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int256::{self, U256};
+    use crate::powi;
+    use crate::real::{self, Real};
+    use crate::types::U0F128;
+    use core::num::NonZeroU128;
+
+    fn to_i32_bits(r: Real, n: i32, frac_nbits: i32) -> (i32, bool) {
+        let pow = powi::powi(r, n);
+        let (abs, overflow) = pow.to_abs_u128(frac_nbits);
+        real::i32::from_abs(pow.neg, abs, overflow)
+    }
+
+    #[test]
+    fn small() {
+        let three_halves = Real::from_u128(false, 3, 1);
+        assert_eq!(to_i32_bits(three_halves, 3, 16), (27 << 13, false));
+        assert_eq!(to_i32_bits(three_halves.neg(), -2, 16), (29127, false));
+        assert_eq!(to_i32_bits(three_halves.neg(), 3, 16), (-27 << 13, false));
+        assert_eq!(to_i32_bits(Real::ZERO, 0, 0), (1, false));
+        assert_eq!(to_i32_bits(Real::ZERO, 5, 0), (0, false));
+        // 2^-17 is a tie which is rounded to even
+        let two = Real::from_u32(2);
+        assert_eq!(to_i32_bits(two, -17, 16), (0, false));
+        assert_eq!(to_i32_bits(three_halves, -1, 16), (43691, false));
+        assert_eq!(to_i32_bits(two, 30, 0), (1 << 30, false));
+        assert!(to_i32_bits(two, 31, 0).1);
+        // 2^(2^31 - 1) and 2^-(2^31 - 1) are clamped instead of overflowing
+        let pow = powi::powi(two, i32::MAX);
+        assert!(pow.to_abs_u128(0).1);
+        let pow = powi::powi(two, -i32::MAX);
+        assert_eq!(pow.to_abs_u128(128), (0, false));
+    }
+
+    // Computes (1 - 2^-40)^n with 128 fractional bits using the binomial
+    // series, with the terms computed with 192 fractional bits.
+    fn binomial(n: u32) -> u128 {
+        let mut term = U256 { lo: 0, hi: 1 << 64 };
+        let mut pos = term;
+        let mut neg = U256 { lo: 0, hi: 0 };
+        let mut k = 1;
+        while term.lo != 0 || term.hi != 0 {
+            // term × (n - k + 1) / (k × 2^40)
+            let factor = u128::from(n - k + 1);
+            let lo = int256::wide_mul_u128(term.lo, factor);
+            let hi = int256::wide_mul_u128(term.hi, factor);
+            assert_eq!(hi.hi, 0);
+            let (prod, _) = int256::overflowing_add_u256(lo, U256 { lo: 0, hi: hi.lo });
+            let shifted = U256 {
+                lo: prod.lo >> 40 | prod.hi << 88,
+                hi: prod.hi >> 40,
+            };
+            let k_nz = NonZeroU128::new(u128::from(k)).unwrap();
+            term = int256::div_rem_u256_u128(shifted, k_nz).0;
+            if k % 2 == 0 {
+                pos = int256::overflowing_add_u256(pos, term).0;
+            } else {
+                neg = int256::overflowing_add_u256(neg, term).0;
+            }
+            k += 1;
+        }
+        let sum = int256::wrapping_sub_u256(pos, neg);
+        let rounded = int256::wrapping_add_u256_u128(sum, 1 << 63);
+        rounded.lo >> 64 | rounded.hi << 64
+    }
+
+    #[test]
+    fn large_exp() {
+        let x = U0F128::from_bits(u128::MAX << 88);
+        for n in [1 << 10, 1 << 20, (1 << 30) + 12_345, 1 << 30, i32::MAX] {
+            let expected = binomial(n as u32);
+            assert_eq!(x.powi(n), U0F128::from_bits(expected), "n {n}");
+        }
+    }
+}
@@ -15,7 +15,8 @@
 
 use crate::{
     float_helper, int_helper,
-    traits::{Fixed, FixedBits, FixedEquiv, FromFixed, ToFixed},
+    rounding::{self, RoundingMode},
+    traits::{Fixed, FixedBits, FixedEquiv, FromFixed, RandomBits, ToFixed},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8, F128,
 };
@@ -82,6 +83,30 @@ impl ToFixed for bool {
     fn unwrapped_to_fixed<F: Fixed>(self) -> F {
         ToFixed::unwrapped_to_fixed(u8::from(self))
     }
+
+    /// Converts a [`bool`] to a fixed-point number, rounding according to the
+    /// given [`RoundingMode`].
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    #[inline]
+    fn overflowing_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> (F, bool) {
+        ToFixed::overflowing_to_fixed_round(u8::from(self), mode)
+    }
+
+    /// Converts a [`bool`] to a fixed-point number, rounding stochastically.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    #[inline]
+    fn overflowing_to_fixed_stochastic<F: Fixed, R: RandomBits + ?Sized>(
+        self,
+        rng: &mut R,
+    ) -> (F, bool) {
+        ToFixed::overflowing_to_fixed_stochastic(u8::from(self), rng)
+    }
 }
 
 macro_rules! impl_int {
@@ -157,6 +182,18 @@ macro_rules! impl_int {
             fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self {
                 $AsEquiv::<0>::unwrapped_from_fixed(src).to_bits() as $Int
             }
+
+            /// Converts a fixed-point number to an integer, rounding
+            /// according to the given [`RoundingMode`].
+            ///
+            /// Returns a [tuple] of the value and a [`bool`] indicating whether
+            /// an overflow has occurred. On overflow, the wrapped value is
+            /// returned.
+            #[inline]
+            fn overflowing_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> (Self, bool) {
+                let (fixed, overflow) = $AsEquiv::<0>::overflowing_from_fixed_round(src, mode);
+                (fixed.to_bits() as $Int, overflow)
+            }
         }
 
         impl ToFixed for $Int {
@@ -217,6 +254,37 @@ macro_rules! impl_int {
             fn unwrapped_to_fixed<F: Fixed>(self) -> F {
                 $AsEquiv::<0>::from_bits(self as $IntAs).unwrapped_to_fixed()
             }
+
+            /// Converts an integer to a fixed-point number, rounding
+            /// according to the given [`RoundingMode`].
+            ///
+            /// Rounding is only needed if the fixed-point number has a
+            /// negative number of fractional bits.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a [`bool`]
+            /// indicating whether an overflow has occurred. On overflow, the
+            /// wrapped value is returned.
+            #[inline]
+            fn overflowing_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> (F, bool) {
+                $AsEquiv::<0>::from_bits(self as $IntAs).overflowing_to_fixed_round(mode)
+            }
+
+            /// Converts an integer to a fixed-point number, rounding
+            /// stochastically.
+            ///
+            /// Rounding is only needed if the fixed-point number has a
+            /// negative number of fractional bits.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a [`bool`]
+            /// indicating whether an overflow has occurred. On overflow, the
+            /// wrapped value is returned.
+            #[inline]
+            fn overflowing_to_fixed_stochastic<F: Fixed, R: RandomBits + ?Sized>(
+                self,
+                rng: &mut R,
+            ) -> (F, bool) {
+                $AsEquiv::<0>::from_bits(self as $IntAs).overflowing_to_fixed_stochastic(rng)
+            }
         }
     };
 }
@@ -351,11 +419,80 @@ macro_rules! impl_float {
             #[inline]
             #[track_caller]
             fn from_fixed<F: Fixed>(src: F) -> Self {
+                let (val, _) = FromFixed::overflowing_from_fixed_round(src, RoundingMode::HalfEven);
+                val
+            }
+
+            /// Converts a fixed-point number to a floating-point
+            /// number if it fits, otherwise returns [`None`].
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            fn checked_from_fixed<F: Fixed>(src: F) -> Option<Self> {
+                Some(FromFixed::from_fixed(src))
+            }
+
+            /// Converts a fixed-point number to a floating-point
+            /// number, saturating if it does not fit.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            fn saturating_from_fixed<F: Fixed>(src: F) -> Self {
+                FromFixed::from_fixed(src)
+            }
+
+            /// Converts a fixed-point number to a floating-point
+            /// number, wrapping if it does not fit.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            fn wrapping_from_fixed<F: Fixed>(src: F) -> Self {
+                FromFixed::from_fixed(src)
+            }
+
+            /// Converts a fixed-point number to a floating-point number.
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool) {
+                (FromFixed::from_fixed(src), false)
+            }
+
+            /// Converts a fixed-point number to a floating-point
+            /// number, panicking if it does not fit.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the value does not fit, even when debug
+            /// assertions are not enabled.
+            #[inline]
+            #[track_caller]
+            fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self {
+                FromFixed::from_fixed(src)
+            }
+
+            /// Converts a fixed-point number to a floating-point number,
+            /// rounding according to the given [`RoundingMode`].
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// If the value is too large, it is converted to infinity, or to
+            /// the largest finite value if `mode` rounds toward zero.
+            #[inline]
+            fn overflowing_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> (Self, bool) {
                 let zero = F::Bits::overflowing_cast_from(0u8).0;
                 let src = src.to_bits();
                 // handle zero early so that we can assume bits != 0
                 if src == zero {
-                    return Self::from_bits(0);
+                    return (Self::from_bits(0), false);
                 }
 
                 let src_neg_overflows = F::Bits::overflowing_cast_from(-1i8).1;
@@ -413,61 +550,10 @@ macro_rules! impl_float {
                 // Similarly we need to divide if F::FRAC_BITS is positive.
                 // That means that we can add excess_shift and F::FRAC_BITS.
                 let frac = F::FRAC_BITS.saturating_add(excess_shift);
-                float_helper::$Float::from_neg_abs(neg, abs, frac)
-            }
-
-            /// Converts a fixed-point number to a floating-point
-            /// number if it fits, otherwise returns [`None`].
-            ///
-            /// Rounding is to the nearest, with ties rounded to even.
-            #[inline]
-            fn checked_from_fixed<F: Fixed>(src: F) -> Option<Self> {
-                Some(FromFixed::from_fixed(src))
-            }
-
-            /// Converts a fixed-point number to a floating-point
-            /// number, saturating if it does not fit.
-            ///
-            /// Rounding is to the nearest, with ties rounded to even.
-            #[inline]
-            fn saturating_from_fixed<F: Fixed>(src: F) -> Self {
-                FromFixed::from_fixed(src)
-            }
-
-            /// Converts a fixed-point number to a floating-point
-            /// number, wrapping if it does not fit.
-            ///
-            /// Rounding is to the nearest, with ties rounded to even.
-            #[inline]
-            fn wrapping_from_fixed<F: Fixed>(src: F) -> Self {
-                FromFixed::from_fixed(src)
-            }
-
-            /// Converts a fixed-point number to a floating-point number.
-            ///
-            /// Returns a [tuple] of the value and a [`bool`]
-            /// indicating whether an overflow has occurred. On
-            /// overflow, the wrapped value is returned.
-            ///
-            /// Rounding is to the nearest, with ties rounded to even.
-            #[inline]
-            fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool) {
-                (FromFixed::from_fixed(src), false)
-            }
-
-            /// Converts a fixed-point number to a floating-point
-            /// number, panicking if it does not fit.
-            ///
-            /// Rounding is to the nearest, with ties rounded to even.
-            ///
-            /// # Panics
-            ///
-            /// Panics if the value does not fit, even when debug
-            /// assertions are not enabled.
-            #[inline]
-            #[track_caller]
-            fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self {
-                FromFixed::from_fixed(src)
+                (
+                    float_helper::$Float::from_neg_abs(neg, abs, frac, mode),
+                    false,
+                )
             }
         }
 
@@ -584,7 +670,7 @@ Panics if `self` is not [finite].
                 #[inline]
                 #[track_caller]
                 fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
-                    float_helper::$Float::overflowing_to_fixed(self)
+                    float_helper::$Float::overflowing_to_fixed(self, RoundingMode::HalfEven)
                 }
             }
 
@@ -610,6 +696,84 @@ when debug assertions are not enabled.
                     }
                 }
             }
+
+            /// Converts a floating-point number to a fixed-point
+            /// number if it fits, rounding according to the given
+            /// [`RoundingMode`], otherwise returns [`None`].
+            #[inline]
+            fn checked_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> Option<F> {
+                if !self.is_finite() {
+                    return None;
+                }
+                match ToFixed::overflowing_to_fixed_round(self, mode) {
+                    (wrapped, false) => Some(wrapped),
+                    (_, true) => None,
+                }
+            }
+
+            comment! {
+            "Converts a floating-point number to a fixed-point number,
+rounding according to the given [`RoundingMode`].
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Panics
+
+Panics if `self` is not [finite].
+
+[finite]: ", stringify!($Float), "::is_finite
+";
+                #[inline]
+                #[track_caller]
+                fn overflowing_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> (F, bool) {
+                    float_helper::$Float::overflowing_to_fixed(self, mode)
+                }
+            }
+
+            /// Converts a floating-point number to a fixed-point
+            /// number if it fits, rounding stochastically, otherwise
+            /// returns [`None`].
+            #[inline]
+            fn checked_to_fixed_stochastic<F: Fixed, R: RandomBits + ?Sized>(
+                self,
+                rng: &mut R,
+            ) -> Option<F> {
+                if !self.is_finite() {
+                    return None;
+                }
+                match ToFixed::overflowing_to_fixed_stochastic(self, rng) {
+                    (wrapped, false) => Some(wrapped),
+                    (_, true) => None,
+                }
+            }
+
+            comment! {
+            "Converts a floating-point number to a fixed-point number,
+rounding stochastically.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Panics
+
+Panics if `self` is not [finite].
+
+[finite]: ", stringify!($Float), "::is_finite
+";
+                #[inline]
+                #[track_caller]
+                fn overflowing_to_fixed_stochastic<F: Fixed, R: RandomBits + ?Sized>(
+                    self,
+                    rng: &mut R,
+                ) -> (F, bool) {
+                    let discarded = float_helper::$Float::discarded_bits(self, F::FRAC_BITS);
+                    let mode = rounding::stochastic_mode(rng, discarded);
+                    float_helper::$Float::overflowing_to_fixed(self, mode)
+                }
+            }
         }
     };
 }
@@ -0,0 +1,443 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Intermediate values used to evaluate analytic functions.
+//
+// A Real has a 128-bit significand and an unbounded exponent, and all the
+// operations round to the nearest, so that intermediate results have a relative
+// precision of about 2^-128. Only integer arithmetic is used, so the results
+// are deterministic and can be computed in const context.
+
+use crate::int256;
+use crate::int256::U256;
+use crate::sqrt;
+use core::cmp::Ordering;
+use core::num::NonZeroU128;
+
+// The value is (-1)^neg × mant × 2^exp.
+//
+// For non-zero values, the most significant bit of mant is set. For zero, mant
+// is zero.
+#[derive(Clone, Copy, Debug)]
+pub struct Real {
+    pub neg: bool,
+    pub mant: u128,
+    pub exp: i32,
+}
+
+const MSB: u128 = 1 << 127;
+
+impl Real {
+    pub const ZERO: Real = Real {
+        neg: false,
+        mant: 0,
+        exp: 0,
+    };
+    pub const ONE: Real = Real {
+        neg: false,
+        mant: MSB,
+        exp: -127,
+    };
+
+    // value = (-1)^neg × abs × 2^-frac_nbits, exact
+    #[inline]
+    pub const fn from_u128(neg: bool, abs: u128, frac_nbits: i32) -> Real {
+        if abs == 0 {
+            return Real::ZERO;
+        }
+        let lz = abs.leading_zeros();
+        Real {
+            neg,
+            mant: abs << lz,
+            exp: -frac_nbits - lz as i32,
+        }
+    }
+
+    // value = (-1)^neg × abs × 2^-frac_nbits, rounded to nearest
+    pub const fn from_u256(neg: bool, abs: U256, frac_nbits: i32) -> Real {
+        if abs.hi == 0 {
+            return Real::from_u128(neg, abs.lo, frac_nbits);
+        }
+        let lz = abs.hi.leading_zeros();
+        let (mut mant, rest) = if lz == 0 {
+            (abs.hi, abs.lo)
+        } else {
+            (abs.hi << lz | abs.lo >> (128 - lz), abs.lo << lz)
+        };
+        let mut exp = 128 - frac_nbits - lz as i32;
+        if rest > MSB || (rest == MSB && (mant & 1) != 0) {
+            mant = mant.wrapping_add(1);
+            if mant == 0 {
+                mant = MSB;
+                exp += 1;
+            }
+        }
+        Real { neg, mant, exp }
+    }
+
+    #[inline]
+    pub const fn from_u32(val: u32) -> Real {
+        Real::from_u128(false, val as u128, 0)
+    }
+
+    #[inline]
+    pub const fn neg(self) -> Real {
+        Real {
+            neg: !self.neg && self.mant != 0,
+            ..self
+        }
+    }
+
+    #[inline]
+    pub const fn abs(self) -> Real {
+        Real { neg: false, ..self }
+    }
+
+    pub const fn cmp_abs(self, rhs: Real) -> Ordering {
+        match (self.mant == 0, rhs.mant == 0) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+        if self.exp < rhs.exp {
+            Ordering::Less
+        } else if self.exp > rhs.exp {
+            Ordering::Greater
+        } else if self.mant < rhs.mant {
+            Ordering::Less
+        } else if self.mant > rhs.mant {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    pub const fn mul(self, rhs: Real) -> Real {
+        if self.mant == 0 || rhs.mant == 0 {
+            return Real::ZERO;
+        }
+        let prod = int256::wide_mul_u128(self.mant, rhs.mant);
+        Real::from_u256(self.neg != rhs.neg, prod, -(self.exp + rhs.exp))
+    }
+
+    // rhs must not be zero
+    pub const fn div(self, rhs: Real) -> Real {
+        let Some(d) = NonZeroU128::new(rhs.mant) else {
+            panic!("division by zero");
+        };
+        if self.mant == 0 {
+            return Real::ZERO;
+        }
+        let neg = self.neg != rhs.neg;
+        let n = U256 {
+            lo: 0,
+            hi: self.mant,
+        };
+        let (q, r) = int256::div_rem_u256_u128(n, d);
+        let exp = self.exp - rhs.exp - 128;
+        if q.hi != 0 {
+            return Real::from_u256(neg, q, -exp);
+        }
+        // the quotient has exactly 128 significant bits, so round using remainder
+        let mut mant = q.lo;
+        let mut exp = exp;
+        if r >= d.get() - r {
+            mant = mant.wrapping_add(1);
+            if mant == 0 {
+                mant = MSB;
+                exp += 1;
+            }
+        }
+        Real { neg, mant, exp }
+    }
+
+    // multiplies by 2^n, exact
+    #[inline]
+    pub const fn mul_pow2(self, n: i32) -> Real {
+        if self.mant == 0 {
+            return self;
+        }
+        Real {
+            exp: self.exp + n,
+            ..self
+        }
+    }
+
+    #[inline]
+    pub const fn recip(self) -> Real {
+        Real::ONE.div(self)
+    }
+
+    pub const fn is_int(self) -> bool {
+        if self.mant == 0 || self.exp >= 0 {
+            true
+        } else if self.exp <= -128 {
+            false
+        } else {
+            self.mant.trailing_zeros() >= self.exp.unsigned_abs()
+        }
+    }
+
+    // Returns Some if self is an integer that fits in an i32.
+    pub const fn to_i32_exact(self) -> Option<i32> {
+        if self.mant == 0 {
+            return Some(0);
+        }
+        if !self.is_int() || self.exp > -96 {
+            return None;
+        }
+        // self.exp ≤ -96, so abs < 2^32
+        let abs = self.mant >> self.exp.unsigned_abs();
+        if self.neg {
+            if abs <= i32::MIN.unsigned_abs() as u128 {
+                return Some((abs as u32).wrapping_neg() as i32);
+            }
+        } else if abs <= i32::MAX as u128 {
+            return Some(abs as i32);
+        }
+        None
+    }
+
+    // self must be an integer
+    pub const fn is_odd_int(self) -> bool {
+        debug_assert!(self.is_int());
+        if self.mant == 0 || self.exp > 0 {
+            false
+        } else {
+            (self.mant >> self.exp.unsigned_abs()) & 1 != 0
+        }
+    }
+
+    pub const fn add(self, rhs: Real) -> Real {
+        if rhs.mant == 0 {
+            return self;
+        }
+        if self.mant == 0 {
+            return rhs;
+        }
+        let (big, small) = if self.cmp_abs(rhs).is_lt() {
+            (rhs, self)
+        } else {
+            (self, rhs)
+        };
+        let big_wide = U256 {
+            lo: 0,
+            hi: big.mant,
+        };
+        let sh = (big.exp - small.exp) as u32;
+        let small_wide = if sh >= 256 {
+            U256 { lo: 0, hi: 0 }
+        } else {
+            int256::shr_u256(
+                U256 {
+                    lo: 0,
+                    hi: small.mant,
+                },
+                sh,
+                false,
+            )
+        };
+        let frac_nbits = 128 - big.exp;
+        if big.neg == small.neg {
+            let (sum, carry) = int256::overflowing_add_u256(big_wide, small_wide);
+            if carry {
+                let sum = U256 {
+                    lo: sum.lo >> 1 | sum.hi << 127,
+                    hi: sum.hi >> 1 | MSB,
+                };
+                Real::from_u256(big.neg, sum, frac_nbits - 1)
+            } else {
+                Real::from_u256(big.neg, sum, frac_nbits)
+            }
+        } else {
+            let diff = int256::wrapping_sub_u256(big_wide, small_wide);
+            Real::from_u256(big.neg, diff, frac_nbits)
+        }
+    }
+
+    #[inline]
+    pub const fn sub(self, rhs: Real) -> Real {
+        self.add(rhs.neg())
+    }
+
+    // self must not be negative; the result is rounded down
+    pub const fn sqrt(self) -> Real {
+        let Some(mant) = NonZeroU128::new(self.mant) else {
+            return Real::ZERO;
+        };
+        assert!(!self.neg, "square root of negative number");
+        // Use mant × 2^-128 in [0.5, 1) for even exp and mant × 2^-127 in
+        // [1, 2) for odd exp, so that the remaining power of two has an even
+        // exponent.
+        if self.exp % 2 == 0 {
+            Real::from_u128(false, sqrt::u128(mant, 128), 64 - self.exp / 2)
+        } else {
+            Real::from_u128(false, sqrt::u128(mant, 127), (127 - self.exp) / 2)
+        }
+    }
+
+    // Returns the absolute value × 2^frac_nbits rounded to the nearest, with
+    // ties rounded to even, wrapped into 128 bits, and whether the absolute value
+    // does not fit in 128 bits.
+    pub const fn to_abs_u128(self, frac_nbits: i32) -> (u128, bool) {
+        if self.mant == 0 {
+            return (0, false);
+        }
+        let sh = self.exp.saturating_add(frac_nbits);
+        if sh >= 128 {
+            (0, true)
+        } else if sh > 0 {
+            (self.mant << sh, true)
+        } else if sh == 0 {
+            (self.mant, false)
+        } else if sh < -128 {
+            (0, false)
+        } else if sh == -128 {
+            // value is in the range [0.5, 1), and is only a tie if it is 0.5
+            ((self.mant != MSB) as u128, false)
+        } else {
+            let rsh = -sh as u32;
+            let int = self.mant >> rsh;
+            let rest = self.mant << (128 - rsh);
+            let round_up = rest > MSB || (rest == MSB && (int & 1) != 0);
+            (int + round_up as u128, false)
+        }
+    }
+}
+
+macro_rules! impl_signed {
+    ($i:ident, $u:ident) => {
+        pub mod $i {
+            use crate::real::Real;
+
+            #[inline]
+            pub const fn from_bits(bits: $i, frac_nbits: i32) -> Real {
+                Real::from_u128(bits < 0, bits.unsigned_abs() as u128, frac_nbits)
+            }
+
+            // Rounds to the nearest, with ties rounded to even.
+            // Returns the wrapped value and whether overflow occurred.
+            #[inline]
+            pub const fn to_bits(r: Real, frac_nbits: i32) -> ($i, bool) {
+                let (abs, overflow) = r.to_abs_u128(frac_nbits);
+                from_abs(r.neg, abs, overflow)
+            }
+
+            // Returns the wrapped value of (-1)^neg × abs and whether overflow
+            // occurred, where overflow is set if abs was already wrapped.
+            pub const fn from_abs(neg: bool, abs: u128, overflow: bool) -> ($i, bool) {
+                if neg {
+                    let bits = (abs as $i).wrapping_neg();
+                    (bits, overflow || abs > $i::MIN.unsigned_abs() as u128)
+                } else {
+                    (abs as $i, overflow || abs > $i::MAX as u128)
+                }
+            }
+        }
+
+        pub mod $u {
+            use crate::real::Real;
+
+            #[inline]
+            pub const fn from_bits(bits: $u, frac_nbits: i32) -> Real {
+                Real::from_u128(false, bits as u128, frac_nbits)
+            }
+
+            // Rounds to the nearest, with ties rounded to even.
+            // Returns the wrapped value and whether overflow occurred.
+            #[inline]
+            pub const fn to_bits(r: Real, frac_nbits: i32) -> ($u, bool) {
+                let (abs, overflow) = r.to_abs_u128(frac_nbits);
+                from_abs(r.neg, abs, overflow)
+            }
+
+            // Returns the wrapped value of (-1)^neg × abs and whether overflow
+            // occurred, where overflow is set if abs was already wrapped.
+            pub const fn from_abs(neg: bool, abs: u128, overflow: bool) -> ($u, bool) {
+                if neg {
+                    ((abs as $u).wrapping_neg(), overflow || abs != 0)
+                } else {
+                    (abs as $u, overflow || abs > $u::MAX as u128)
+                }
+            }
+        }
+    };
+}
+
+impl_signed! { i8, u8 }
+impl_signed! { i16, u16 }
+impl_signed! { i32, u32 }
+impl_signed! { i64, u64 }
+impl_signed! { i128, u128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::real::{self, Real};
+
+    #[test]
+    fn arith() {
+        let three = Real::from_u32(3);
+        let five = Real::from_u32(5);
+        assert_eq!(real::i32::to_bits(three.add(five), 0), (8, false));
+        assert_eq!(real::i32::to_bits(three.sub(five), 0), (-2, false));
+        assert_eq!(real::i32::to_bits(three.mul(five), 0), (15, false));
+        assert_eq!(real::i32::to_bits(three.div(five), 16), (39322, false));
+        assert_eq!(three.sub(three).mant, 0);
+        assert!(three.cmp_abs(five.neg()).is_lt());
+    }
+
+    #[test]
+    fn sqrt() {
+        let two = Real::from_u32(2);
+        let nine = Real::from_u32(9);
+        assert_eq!(real::i32::to_bits(nine.sqrt(), 0), (3, false));
+        assert_eq!(real::u32::to_bits(two.sqrt(), 16), (92682, false));
+        assert_eq!(real::u32::to_bits(two.recip().sqrt(), 16), (46341, false));
+        assert_eq!(Real::ZERO.sqrt().mant, 0);
+    }
+
+    #[test]
+    fn int() {
+        let three = Real::from_u32(3);
+        let half = Real::from_u128(false, 1, 1);
+        assert!(three.is_int() && three.is_odd_int());
+        assert_eq!(three.neg().to_i32_exact(), Some(-3));
+        assert!(!three.add(half).is_int());
+        assert_eq!(three.add(half).to_i32_exact(), None);
+        assert!(!Real::from_u32(1 << 20).is_odd_int());
+        assert_eq!(
+            real::i32::from_bits(i32::MIN, 0).to_i32_exact(),
+            Some(i32::MIN)
+        );
+        assert_eq!(real::i64::from_bits(1 << 31, 0).to_i32_exact(), None);
+        assert_eq!(Real::ZERO.to_i32_exact(), Some(0));
+    }
+
+    #[test]
+    fn to_bits_rounding() {
+        let half = Real::from_u128(false, 1, 1);
+        let three_halves = Real::from_u128(false, 3, 1);
+        assert_eq!(real::i8::to_bits(half, 0), (0, false));
+        assert_eq!(real::i8::to_bits(three_halves, 0), (2, false));
+        assert_eq!(real::i8::to_bits(three_halves.neg(), 0), (-2, false));
+        assert_eq!(real::u8::to_bits(half.neg(), 0), (0, false));
+        assert_eq!(real::u8::to_bits(three_halves.neg(), 0), (254, true));
+        assert_eq!(real::i8::to_bits(Real::ONE, 7), (-128, true));
+        assert_eq!(real::i8::to_bits(Real::ONE.neg(), 7), (-128, false));
+        assert_eq!(real::u128::to_bits(Real::ONE, 128), (0, true));
+        assert_eq!(real::u128::to_bits(Real::ONE, 127), (1 << 127, false));
+    }
+}
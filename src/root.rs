@@ -0,0 +1,336 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use core::cmp::Ordering;
+use core::num::NonZeroU128;
+
+// For a value b with f fractional bits, the n-th root rounded down is the
+// largest c such that c^n ≤ b × 2^(f(n - 1)). The root is built one bit at a
+// time from the most significant bit, and each candidate c is checked by
+// computing c^n exactly using an integer with up to LIMBS 64-bit limbs.
+//
+// When c^n does not fit, low limbs are discarded, and both a lower bound
+// (discarded limbs truncated) and an upper bound (discarded limbs rounded up)
+// are computed. Since b × 2^(f(n - 1)) has at most 128 significant bits, it is
+// always stored exactly. The bounds only fail to decide the comparison when
+// c^n and b × 2^(f(n - 1)) agree in more than 1900 significant bits without
+// being equal, which can only occur for large n.
+
+const LIMBS: usize = 32;
+
+// The value is limbs × 2^(64 exp), with limbs[len - 1] != 0 unless len == 0.
+#[derive(Clone, Copy)]
+struct Big {
+    limbs: [u64; LIMBS],
+    len: usize,
+    exp: i64,
+    inexact: bool,
+}
+
+impl Big {
+    const fn from_u128(val: u128, exp: i64) -> Big {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = val as u64;
+        limbs[1] = (val >> 64) as u64;
+        let len = if limbs[1] != 0 {
+            2
+        } else if limbs[0] != 0 {
+            1
+        } else {
+            0
+        };
+        Big {
+            limbs,
+            len,
+            exp,
+            inexact: false,
+        }
+    }
+
+    // Discards low limbs so that at most LIMBS - 1 limbs remain, so that
+    // rounding up cannot overflow.
+    const fn mul(a: Big, b: Big, round_up: bool) -> Big {
+        let mut prod = [0u64; 2 * LIMBS];
+        let mut i = 0;
+        while i < a.len {
+            let mut carry = 0u128;
+            let mut j = 0;
+            while j < b.len {
+                let t = a.limbs[i] as u128 * b.limbs[j] as u128 + prod[i + j] as u128 + carry;
+                prod[i + j] = t as u64;
+                carry = t >> 64;
+                j += 1;
+            }
+            prod[i + b.len] = carry as u64;
+            i += 1;
+        }
+        let mut len = a.len + b.len;
+        while len > 0 && prod[len - 1] == 0 {
+            len -= 1;
+        }
+        let mut inexact = a.inexact | b.inexact;
+        let drop = len.saturating_sub(LIMBS - 1);
+        let mut limbs = [0u64; LIMBS];
+        let mut i = 0;
+        while i < drop {
+            if prod[i] != 0 {
+                inexact = true;
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while i < len - drop {
+            limbs[i] = prod[i + drop];
+            i += 1;
+        }
+        len -= drop;
+        if round_up && inexact {
+            let mut i = 0;
+            loop {
+                let (sum, carry) = limbs[i].overflowing_add(1);
+                limbs[i] = sum;
+                if !carry {
+                    break;
+                }
+                i += 1;
+            }
+            if i == len {
+                len += 1;
+            }
+        }
+        Big {
+            limbs,
+            len,
+            exp: a.exp + b.exp + drop as i64,
+            inexact,
+        }
+    }
+
+    const fn pow(base: u128, n: u32, round_up: bool) -> Big {
+        let base = Big::from_u128(base, 0);
+        let mut acc = base;
+        let mut bit = (u32::BITS - 1 - n.leading_zeros()) as i32 - 1;
+        while bit >= 0 {
+            acc = Big::mul(acc, acc, round_up);
+            if (n >> bit) & 1 != 0 {
+                acc = Big::mul(acc, base, round_up);
+            }
+            bit -= 1;
+        }
+        acc
+    }
+
+    const fn limb_at(&self, pos: i64) -> u64 {
+        let i = pos - self.exp;
+        if 0 <= i && i < self.len as i64 {
+            self.limbs[i as usize]
+        } else {
+            0
+        }
+    }
+
+    const fn cmp(&self, other: &Big) -> Ordering {
+        if self.len == 0 || other.len == 0 {
+            return if self.len != 0 {
+                Ordering::Greater
+            } else if other.len != 0 {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            };
+        }
+        let self_top = self.exp + self.len as i64;
+        let other_top = other.exp + other.len as i64;
+        if self_top != other_top {
+            return if self_top > other_top {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+        let bottom = if self.exp < other.exp {
+            self.exp
+        } else {
+            other.exp
+        };
+        let mut pos = self_top - 1;
+        while pos >= bottom {
+            let a = self.limb_at(pos);
+            let b = other.limb_at(pos);
+            if a != b {
+                return if a > b {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                };
+            }
+            pos -= 1;
+        }
+        Ordering::Equal
+    }
+}
+
+// Compares c^n with b × 2^(64 exp + shl), where shl < 64.
+const fn cmp_pow(c: u128, n: u32, b: u128, exp: i64, shl: u32) -> Ordering {
+    let target = if shl == 0 {
+        Big::from_u128(b, exp)
+    } else {
+        let mut target = Big::from_u128(b << shl, exp);
+        target.limbs[2] = (b >> (128 - shl)) as u64;
+        if target.limbs[2] != 0 {
+            target.len = 3;
+        }
+        target
+    };
+    let lower = Big::pow(c, n, false);
+    if !lower.inexact {
+        return lower.cmp(&target);
+    }
+    if let Ordering::Greater = lower.cmp(&target) {
+        return Ordering::Greater;
+    }
+    let upper = Big::pow(c, n, true);
+    if let Ordering::Less = upper.cmp(&target) {
+        return Ordering::Less;
+    }
+    // The bounds cannot decide; the values can only be equal if c^n has at
+    // most 128 significant bits, which is not the case as it was inexact. We
+    // treat c^n as the larger value, so that the root is rounded down.
+    Ordering::Greater
+}
+
+// Returns the n-th root rounded down, and whether the root is exact.
+//
+// n must be nonzero.
+pub const fn u128(val: NonZeroU128, frac_nbits: u32, n: u32) -> (u128, bool) {
+    debug_assert!(n != 0);
+    if n == 1 {
+        return (val.get(), true);
+    }
+    // c^n is compared with b × 2^(f(n - 1))
+    let shift = frac_nbits as u64 * (n - 1) as u64;
+    let exp = (shift / 64) as i64;
+    let shl = (shift % 64) as u32;
+
+    // If val has int_bits significant integer bits, the root has at most
+    // ceil(int_bits / n) significant integer bits.
+    let int_bits = (u128::BITS - val.leading_zeros()) as i32 - frac_nbits as i32;
+    let root_int_bits = if int_bits > 0 {
+        (int_bits as u32 - 1) / n + 1
+    } else {
+        0
+    };
+    let mut bit = (root_int_bits + frac_nbits) as i32 - 1;
+    let mut root = 0u128;
+    while bit >= 0 {
+        let candidate = root | (1 << bit);
+        match cmp_pow(candidate, n, val.get(), exp, shl) {
+            Ordering::Less => root = candidate,
+            Ordering::Equal => return (candidate, true),
+            Ordering::Greater => {}
+        }
+        bit -= 1;
+    }
+    (root, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{root, sqrt};
+    use core::num::NonZeroU128;
+
+    // exact check using u128 arithmetic, for small values
+    fn check(b: u128, frac: u32, n: u32) {
+        let (c, exact) = root::u128(NonZeroU128::new(b).unwrap(), frac, n);
+        let target = b << (frac * (n - 1));
+        let pow = |c: u128| (0..n).try_fold(1u128, |acc, _| acc.checked_mul(c));
+        let lower = pow(c).unwrap();
+        assert!(lower <= target, "b {b} frac {frac} n {n}");
+        assert_eq!(exact, lower == target, "b {b} frac {frac} n {n}");
+        if let Some(upper) = pow(c + 1) {
+            assert!(upper > target, "b {b} frac {frac} n {n}");
+        }
+    }
+
+    #[test]
+    fn exhaustive_u12() {
+        for n in 1..=5 {
+            for frac in 0..=12 {
+                for b in 1..1u128 << 12 {
+                    check(b, frac, n);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wide() {
+        for n in 2..=7 {
+            for frac in [0, 1, 5, 17] {
+                let mut b = 1u128;
+                while 128 - b.leading_zeros() + frac * (n - 1) < 128 {
+                    check(b, frac, n);
+                    check(b + 1, frac, n);
+                    b = b * 3 + 1;
+                }
+            }
+        }
+        // square roots can be checked against sqrt
+        for frac in [0, 1, 63, 64, 65, 127, 128] {
+            let mut b = 1u128;
+            while b < u128::MAX / 3 {
+                for val in [b, b + 1, b * 2] {
+                    let nz = NonZeroU128::new(val).unwrap();
+                    assert_eq!(root::u128(nz, frac, 2).0, sqrt::u128(nz, frac));
+                }
+                b = b * 3 + 1;
+            }
+        }
+
+        // (2^42 + 1)^3
+        let cube = ((1u128 << 42) + 1).pow(3);
+        let nz = NonZeroU128::new(cube).unwrap();
+        assert_eq!(root::u128(nz, 0, 3), ((1 << 42) + 1, true));
+        let nz = NonZeroU128::new(cube - 1).unwrap();
+        assert_eq!(root::u128(nz, 0, 3), (1 << 42, false));
+
+        // 1/8 with 128 fractional bits has an exact cube root 1/2
+        let nz = NonZeroU128::new(1 << 125).unwrap();
+        assert_eq!(root::u128(nz, 128, 3), (1 << 127, true));
+        let nz = NonZeroU128::new(u128::MAX).unwrap();
+        assert_eq!(root::u128(nz, 128, 3).0, u128::MAX);
+        assert_eq!(root::u128(nz, 0, 128), (1, false));
+        assert_eq!(root::u128(nz, 0, 127), (2, false));
+    }
+
+    #[test]
+    fn large_n() {
+        // 2^(1/n) with 64 fractional bits for n = 2^32 - 1, where the
+        // intermediate results do not fit exactly
+        let nz = NonZeroU128::new(2 << 64).unwrap();
+        let (c, exact) = root::u128(nz, 64, u32::MAX);
+        assert!(!exact);
+        let expected = 2f64.ln() / f64::from(u32::MAX) * 2f64.powi(64);
+        let frac = (c - (1 << 64)) as f64;
+        assert!((frac - expected).abs() < 1e-3 * expected);
+
+        // 2^(1/n) for n = 2^32 - 1 with 128 fractional bits
+        let nz = NonZeroU128::new(1 << 127).unwrap();
+        let (c, _) = root::u128(nz, 128, u32::MAX);
+        let expected = (-2f64.ln() / f64::from(u32::MAX)).exp_m1() * 2f64.powi(128);
+        let diff = (u128::MAX - c) as f64;
+        assert!((diff + expected).abs() < 1e-3 * -expected);
+    }
+}
@@ -0,0 +1,349 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::traits::RandomBits;
+
+/**
+The rounding mode used by methods such as [`from_num_round`], [`to_num_round`],
+[`mul_round`], [`div_round`] and [`from_str_round`].
+
+The mode decides how an exact result that cannot be represented is rounded to
+one of the two representable values on either side of it. Exact results are
+returned unchanged in all modes.
+
+The methods without a rounding mode parameter keep their documented rounding;
+for example multiplication rounds like [`Floor`][RoundingMode::Floor], while
+[`from_str`] rounds like [`HalfEven`][RoundingMode::HalfEven].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::U4F4, RoundingMode};
+// √2 = 1.4142…, which lies between 1.375 and 1.4375
+let two = U4F4::from_num(2);
+assert_eq!(two.sqrt_round(RoundingMode::Floor), U4F4::from_num(1.375));
+assert_eq!(two.sqrt_round(RoundingMode::Ceil), U4F4::from_num(1.4375));
+assert_eq!(two.sqrt_round(RoundingMode::HalfEven), U4F4::from_num(1.4375));
+
+// 2.5 is a tie between 2 and 3
+let x = U4F4::from_num(2.5);
+assert_eq!(x.to_num_round::<u8>(RoundingMode::HalfEven), 2);
+assert_eq!(x.to_num_round::<u8>(RoundingMode::HalfAwayFromZero), 3);
+assert_eq!(x.to_num_round::<u8>(RoundingMode::HalfToZero), 2);
+```
+
+[`div_round`]: crate::FixedI32::div_round
+[`from_num_round`]: crate::FixedI32::from_num_round
+[`from_str_round`]: crate::FixedI32::from_str_round
+[`from_str`]: crate::FixedI32::from_str
+[`mul_round`]: crate::FixedI32::mul_round
+[`to_num_round`]: crate::FixedI32::to_num_round
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds toward zero, that is the result is truncated.
+    ToZero,
+    /// Rounds away from zero.
+    AwayFromZero,
+    /// Rounds toward &minus;∞.
+    Floor,
+    /// Rounds toward +∞.
+    Ceil,
+    /// Rounds to the nearest, with ties rounded to even.
+    HalfEven,
+    /// Rounds to the nearest, with ties rounded away from zero.
+    HalfAwayFromZero,
+    /// Rounds to the nearest, with ties rounded toward zero.
+    HalfToZero,
+}
+
+// The part of an exact result that is discarded when it is truncated, relative
+// to half the value of the least significant bit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Discarded {
+    Zero,
+    LessThanHalf,
+    Half,
+    GreaterThanHalf,
+}
+
+impl RoundingMode {
+    // Returns true if the magnitude of a truncated result has to be incremented
+    // by one. neg is the sign of the exact result, and odd is true if the
+    // truncated magnitude is odd.
+    #[inline]
+    pub(crate) const fn increment(self, neg: bool, odd: bool, discarded: Discarded) -> bool {
+        if let Discarded::Zero = discarded {
+            return false;
+        }
+        match self {
+            RoundingMode::ToZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => neg,
+            RoundingMode::Ceil => !neg,
+            RoundingMode::HalfEven => match discarded {
+                Discarded::Zero | Discarded::LessThanHalf => false,
+                Discarded::Half => odd,
+                Discarded::GreaterThanHalf => true,
+            },
+            RoundingMode::HalfAwayFromZero => {
+                matches!(discarded, Discarded::Half | Discarded::GreaterThanHalf)
+            }
+            RoundingMode::HalfToZero => matches!(discarded, Discarded::GreaterThanHalf),
+        }
+    }
+
+    // Returns true if a result that was rounded toward -∞ has to be incremented
+    // by one. neg is the sign of the exact result, odd is true if the rounded
+    // down result is odd, and discarded is the part of the two's complement
+    // representation that was discarded, which is never negative.
+    #[inline]
+    pub(crate) const fn increment_floor(self, neg: bool, odd: bool, discarded: Discarded) -> bool {
+        if let Discarded::Zero = discarded {
+            return false;
+        }
+        let tie = match self {
+            RoundingMode::ToZero => return neg,
+            RoundingMode::AwayFromZero => return !neg,
+            RoundingMode::Floor => return false,
+            RoundingMode::Ceil => return true,
+            RoundingMode::HalfEven => odd,
+            RoundingMode::HalfAwayFromZero => !neg,
+            RoundingMode::HalfToZero => neg,
+        };
+        match discarded {
+            Discarded::Zero | Discarded::LessThanHalf => false,
+            Discarded::Half => tie,
+            Discarded::GreaterThanHalf => true,
+        }
+    }
+
+    // Rounds the truncated magnitude abs, returning the rounded magnitude and
+    // whether it overflows u128.
+    #[inline]
+    pub(crate) const fn round_u128(
+        self,
+        neg: bool,
+        abs: u128,
+        discarded: Discarded,
+    ) -> (u128, bool) {
+        if self.increment(neg, abs % 2 != 0, discarded) {
+            abs.overflowing_add(1)
+        } else {
+            (abs, false)
+        }
+    }
+}
+
+// Returns true with a probability of bits / 2^128. A second 64-bit random
+// number is only needed if the first one is equal to the upper half of bits.
+pub(crate) fn random_below<R: RandomBits + ?Sized>(rng: &mut R, bits: u128) -> bool {
+    if bits == 0 {
+        return false;
+    }
+    let hi = (bits >> 64) as u64;
+    let r = rng.next_u64();
+    if r != hi {
+        return r < hi;
+    }
+    let lo = bits as u64;
+    lo != 0 && rng.next_u64() < lo
+}
+
+// Returns the rounding mode for stochastic rounding, where discarded is the
+// discarded part of the two's complement representation as returned by
+// arith::discarded_bits. Rounding up with a probability equal to the discarded
+// fraction makes the expected rounded value equal to the exact value.
+pub(crate) fn stochastic_mode<R: RandomBits + ?Sized>(
+    rng: &mut R,
+    discarded: u128,
+) -> RoundingMode {
+    if random_below(rng, discarded) {
+        RoundingMode::Ceil
+    } else {
+        RoundingMode::Floor
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::rounding::{self, Discarded, RoundingMode};
+    use crate::traits::RandomBits;
+
+    // Returns the given values in order, panicking if more are requested.
+    pub(crate) struct Sequence<'a>(pub &'a [u64]);
+
+    impl RandomBits for Sequence<'_> {
+        fn next_u64(&mut self) -> u64 {
+            let (first, rest) = self
+                .0
+                .split_first()
+                .expect("too many random numbers requested");
+            self.0 = rest;
+            *first
+        }
+    }
+
+    pub(crate) const ALL_MODES: [RoundingMode; 7] = [
+        RoundingMode::ToZero,
+        RoundingMode::AwayFromZero,
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::HalfEven,
+        RoundingMode::HalfAwayFromZero,
+        RoundingMode::HalfToZero,
+    ];
+
+    // Rounds num/den to an integer, used as a reference in tests.
+    pub(crate) fn round_ratio(num: i128, den: i128, mode: RoundingMode) -> i128 {
+        assert!(den > 0);
+        let floor = num.div_euclid(den);
+        let rem = num.rem_euclid(den);
+        if rem == 0 {
+            return floor;
+        }
+        let ceil = floor + 1;
+        let nearest = match (2 * rem).cmp(&den) {
+            core::cmp::Ordering::Less => Some(floor),
+            core::cmp::Ordering::Greater => Some(ceil),
+            core::cmp::Ordering::Equal => None,
+        };
+        match mode {
+            RoundingMode::ToZero => {
+                if num < 0 {
+                    ceil
+                } else {
+                    floor
+                }
+            }
+            RoundingMode::AwayFromZero => {
+                if num < 0 {
+                    floor
+                } else {
+                    ceil
+                }
+            }
+            RoundingMode::Floor => floor,
+            RoundingMode::Ceil => ceil,
+            RoundingMode::HalfEven => nearest.unwrap_or(if floor % 2 == 0 { floor } else { ceil }),
+            RoundingMode::HalfAwayFromZero => nearest.unwrap_or(if num < 0 { floor } else { ceil }),
+            RoundingMode::HalfToZero => nearest.unwrap_or(if num < 0 { ceil } else { floor }),
+        }
+    }
+
+    #[test]
+    fn ratio() {
+        use RoundingMode::*;
+        // 2.5, -2.5, 2.25 and -2.75
+        let expected = [
+            (ToZero, [2, -2, 2, -2]),
+            (AwayFromZero, [3, -3, 3, -3]),
+            (Floor, [2, -3, 2, -3]),
+            (Ceil, [3, -2, 3, -2]),
+            (HalfEven, [2, -2, 2, -3]),
+            (HalfAwayFromZero, [3, -3, 2, -3]),
+            (HalfToZero, [2, -2, 2, -3]),
+        ];
+        for (mode, ans) in expected {
+            let vals = [(5, 2), (-5, 2), (9, 4), (-11, 4)];
+            for ((num, den), ans) in vals.into_iter().zip(ans) {
+                assert_eq!(round_ratio(num, den, mode), ans, "{num}/{den} {mode:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn increment() {
+        use Discarded::*;
+        use RoundingMode::*;
+        let discarded = [Zero, LessThanHalf, Half, GreaterThanHalf];
+        // expected increments for [positive even, positive odd, negative even, negative odd]
+        let modes = [
+            (ToZero, [[false; 4]; 4]),
+            (AwayFromZero, [[false, true, true, true]; 4]),
+            (
+                Floor,
+                [
+                    [false, false, false, false],
+                    [false, false, false, false],
+                    [false, true, true, true],
+                    [false, true, true, true],
+                ],
+            ),
+            (
+                Ceil,
+                [
+                    [false, true, true, true],
+                    [false, true, true, true],
+                    [false, false, false, false],
+                    [false, false, false, false],
+                ],
+            ),
+            (
+                HalfEven,
+                [
+                    [false, false, false, true],
+                    [false, false, true, true],
+                    [false, false, false, true],
+                    [false, false, true, true],
+                ],
+            ),
+            (HalfAwayFromZero, [[false, false, true, true]; 4]),
+            (HalfToZero, [[false, false, false, true]; 4]),
+        ];
+        for (mode, expected) in modes {
+            for (i, (neg, odd)) in [(false, false), (false, true), (true, false), (true, true)]
+                .into_iter()
+                .enumerate()
+            {
+                for (j, d) in discarded.into_iter().enumerate() {
+                    assert_eq!(
+                        mode.increment(neg, odd, d),
+                        expected[i][j],
+                        "{mode:?} neg {neg} odd {odd} {d:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random_below() {
+        // zero never rounds up and does not consume random bits
+        assert!(!rounding::random_below(&mut Sequence(&[]), 0));
+        let half = 1 << 127;
+        assert!(rounding::random_below(&mut Sequence(&[0]), half));
+        assert!(rounding::random_below(
+            &mut Sequence(&[(1 << 63) - 1]),
+            half
+        ));
+        assert!(!rounding::random_below(&mut Sequence(&[1 << 63]), half));
+        assert!(!rounding::random_below(&mut Sequence(&[!0]), half));
+        // the low half is only needed if the high half is equal
+        let bits = (5 << 64) | 3;
+        assert!(rounding::random_below(&mut Sequence(&[4]), bits));
+        assert!(!rounding::random_below(&mut Sequence(&[6]), bits));
+        assert!(rounding::random_below(&mut Sequence(&[5, 2]), bits));
+        assert!(!rounding::random_below(&mut Sequence(&[5, 3]), bits));
+        assert!(!rounding::random_below(&mut Sequence(&[5]), 5 << 64));
+        assert!(rounding::random_below(&mut Sequence(&[!0, !1]), !0));
+        assert!(!rounding::random_below(&mut Sequence(&[!0, !0]), !0));
+        let mode = rounding::stochastic_mode(&mut Sequence(&[0]), 1 << 64);
+        assert_eq!(mode, RoundingMode::Ceil);
+    }
+}
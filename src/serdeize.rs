@@ -15,6 +15,11 @@
 
 #[cfg(feature = "serde-str")]
 use crate::types::extra::{If, True};
+#[cfg(not(feature = "serde-str"))]
+use crate::{
+    types::extra::{Storage, Width},
+    FixedI, FixedI24, FixedI48, FixedU, FixedU24, FixedU48,
+};
 use crate::{
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8, Unwrapped, Wrapping,
@@ -25,7 +30,10 @@ use serde::{
 };
 #[cfg(not(feature = "serde-str"))]
 use {
-    core::fmt::{Formatter, Result as FmtResult},
+    core::{
+        fmt::{Formatter, Result as FmtResult},
+        marker::PhantomData,
+    },
     serde::{
         de::{MapAccess, SeqAccess, Visitor},
         ser::SerializeStruct,
@@ -197,6 +205,86 @@ serde_fixed! { FixedU32(32) is u32 name "FixedU32" }
 serde_fixed! { FixedU64(64) is u64 name "FixedU64" }
 serde_fixed! { FixedU128(128) is u128 name "FixedU128" }
 
+// Numbers with fewer bits than their underlying integer are serialized like the
+// other numbers, and bits that do not fit are a deserialization error. They
+// cannot be parsed from strings, so they are not serialized with the
+// `serde-str` feature.
+macro_rules! serde_narrow {
+    ($Fixed:ident<$($Param:ident),*>, where [$($bound:tt)*]; $Bits:ty, $Name:expr) => {
+        #[cfg(not(feature = "serde-str"))]
+        impl<$(const $Param: i32),*> Serialize for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let bits = self.to_bits();
+                let mut state = serializer.serialize_struct($Name, 1)?;
+                state.serialize_field("bits", &bits)?;
+                state.end()
+            }
+        }
+
+        #[cfg(not(feature = "serde-str"))]
+        impl<'de, $(const $Param: i32),*> Deserialize<'de> for $Fixed<$($Param),*>
+        where
+            $($bound)*
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct FixedVisitor<Bits>(PhantomData<Bits>);
+
+                impl<'de, Bits: Deserialize<'de>> Visitor<'de> for FixedVisitor<Bits> {
+                    type Value = Bits;
+
+                    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                        formatter.write_str("struct ")?;
+                        formatter.write_str($Name)
+                    }
+
+                    fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<Bits, V::Error> {
+                        let bits = seq
+                            .next_element()?
+                            .ok_or_else(|| DeError::invalid_length(0, &self))?;
+                        Ok(bits)
+                    }
+
+                    fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Bits, V::Error> {
+                        let mut bits = None;
+                        while let Some(key) = map.next_key()? {
+                            match key {
+                                Field::Bits => {
+                                    if bits.is_some() {
+                                        return Err(DeError::duplicate_field("bits"));
+                                    }
+                                    bits = Some(map.next_value()?);
+                                }
+                            }
+                        }
+                        let bits = bits.ok_or_else(|| DeError::missing_field("bits"))?;
+                        Ok(bits)
+                    }
+                }
+
+                let visitor = FixedVisitor::<$Bits>(PhantomData);
+                let bits = deserializer.deserialize_struct($Name, FIELDS, visitor)?;
+                $Fixed::checked_from_bits(bits).ok_or_else(|| DeError::custom("bits do not fit"))
+            }
+        }
+    };
+}
+
+serde_narrow! { FixedI24<FRAC>, where []; i32, "FixedI24" }
+serde_narrow! { FixedI48<FRAC>, where []; i64, "FixedI48" }
+serde_narrow! { FixedU24<FRAC>, where []; u32, "FixedU24" }
+serde_narrow! { FixedU48<FRAC>, where []; u64, "FixedU48" }
+serde_narrow! {
+    FixedI<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,];
+    <Width<{ INT + FRAC }> as Storage>::Signed, "FixedI"
+}
+serde_narrow! {
+    FixedU<INT, FRAC>, where [Width<{ INT + FRAC }>: Storage,];
+    <Width<{ INT + FRAC }> as Storage>::Unsigned, "FixedU"
+}
+
 #[cfg(not(feature = "serde-str"))]
 const FIELDS: &[&str] = &["bits"];
 
@@ -13,6 +13,8 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+use crate::int256::{self, U256};
+use crate::rounding::Discarded;
 use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
 
 // The mathematics below is based on the comments from FreeBSD's
@@ -240,12 +242,99 @@ impl_sqrt! { u32, NonZeroU32 }
 impl_sqrt! { u64, NonZeroU64 }
 impl_sqrt! { u128, NonZeroU128 }
 
+// Returns whether c^2 × b ≤ 2^k, where k < 384.
+const fn sq_mul_le_pow2(c: u128, b: u128, k: u32) -> bool {
+    let sq = int256::wide_mul_u128(c, c);
+    let lo = int256::wide_mul_u128(sq.lo, b);
+    let hi = int256::wide_mul_u128(sq.hi, b);
+    // the product is p2:p1:p0
+    let p0 = lo.lo;
+    let (p1, carry) = lo.hi.overflowing_add(hi.lo);
+    let p2 = hi.hi + carry as u128;
+    let (top, top_bit) = if p2 != 0 {
+        (p2, 256)
+    } else if p1 != 0 {
+        (p1, 128)
+    } else {
+        (p0, 0)
+    };
+    let nbits = top_bit + (u128::BITS - top.leading_zeros());
+    if nbits != k + 1 {
+        return nbits <= k;
+    }
+    // the product is equal to 2^k only if it is a power of two
+    top.is_power_of_two() && (top_bit < 256 || p1 == 0) && (top_bit < 128 || p0 == 0)
+}
+
+// Returns the reciprocal square root rounded down, that is the largest c such
+// that c^2 × val ≤ 2^(2 ret_frac_nbits + frac_nbits).
+//
+// If the result does not fit in u128, only its most significant bits are
+// computed, and the returned bool is true.
+pub const fn rsqrt_u128(val: NonZeroU128, frac_nbits: u32, ret_frac_nbits: i32) -> (u128, bool) {
+    let k = 2 * ret_frac_nbits as i64 + frac_nbits as i64;
+    let val_nbits = (u128::BITS - val.leading_zeros()) as i64;
+    // c^2 ≤ 2^k / val ≤ 2^(k + 1 - val_nbits), so c < 2^top
+    let top = (k + 3 - val_nbits).div_euclid(2);
+    if top <= 0 {
+        return (0, false);
+    }
+    // Shift 2^k right by 2 shift so that c < 2^128.
+    let shift = if top > 128 { top - 128 } else { 0 };
+    let k = (k - 2 * shift) as u32;
+    let mut bit = (top - shift) as i32 - 1;
+    let mut c = 0u128;
+    while bit >= 0 {
+        let candidate = c | (1 << bit);
+        if sq_mul_le_pow2(candidate, val.get(), k) {
+            c = candidate;
+        }
+        bit -= 1;
+    }
+    if shift == 0 {
+        (c, false)
+    } else if shift < 128 {
+        (c << shift, true)
+    } else {
+        (0, true)
+    }
+}
+
+// Returns how the exact square root of val with frac_nbits fractional bits
+// compares to root, which is the square root rounded down. Since (root + 1/2)^2
+// is never an integer, the exact square root never lies halfway between root
+// and root + 1.
+pub const fn discarded(val: u128, frac_nbits: u32, root: u128) -> Discarded {
+    // compare val × 2^frac_nbits with root^2 and root^2 + root
+    let scaled = if frac_nbits == 0 {
+        U256 { lo: val, hi: 0 }
+    } else if frac_nbits < 128 {
+        U256 {
+            lo: val << frac_nbits,
+            hi: val >> (128 - frac_nbits),
+        }
+    } else {
+        U256 { lo: 0, hi: val }
+    };
+    let sq = int256::wide_mul_u128(root, root);
+    if scaled.lo == sq.lo && scaled.hi == sq.hi {
+        return Discarded::Zero;
+    }
+    let (half, _) = int256::overflowing_add_u256(sq, U256 { lo: root, hi: 0 });
+    if scaled.hi > half.hi || (scaled.hi == half.hi && scaled.lo > half.lo) {
+        Discarded::GreaterThanHalf
+    } else {
+        Discarded::LessThanHalf
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-        FixedU8,
+        sqrt, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32,
+        FixedU64, FixedU8, RoundingMode,
     };
+    use core::num::NonZeroU128;
 
     macro_rules! check_sqrt {
         ($val:expr) => {{
@@ -496,4 +585,128 @@ mod tests {
                 || FixedI128::<126>::MAX.sqrt() == FixedI128::<126>::SQRT_2
         );
     }
+
+    fn check_rsqrt(val: u128, frac: u32, ret_frac: i32) {
+        let (c, overflow) = sqrt::rsqrt_u128(NonZeroU128::new(val).unwrap(), frac, ret_frac);
+        assert!(!overflow);
+        let k = 2 * ret_frac + frac as i32;
+        let prod = |c: u128| c.checked_mul(c).and_then(|sq| sq.checked_mul(val));
+        if k < 0 {
+            assert_eq!(c, 0);
+            return;
+        }
+        assert!(prod(c).unwrap() <= 1 << k, "{val} {frac} {ret_frac}");
+        if let Some(p) = prod(c + 1) {
+            assert!(p > 1 << k, "{val} {frac} {ret_frac}");
+        }
+    }
+
+    #[test]
+    fn check_rsqrt_small() {
+        for val in 1..1 << 12 {
+            for frac in 0..=12 {
+                for ret_frac in -8..=20 {
+                    check_rsqrt(val, frac, ret_frac);
+                }
+            }
+        }
+        let mut val = 1u128;
+        while val < 1 << 64 {
+            check_rsqrt(val, 32, 32);
+            check_rsqrt(val + 1, 64, 16);
+            val = val * 3 + 1;
+        }
+    }
+
+    #[test]
+    fn check_rsqrt_wide() {
+        // 1/√0.25 = 2
+        let quarter = NonZeroU128::new(1 << 126).unwrap();
+        assert_eq!(sqrt::rsqrt_u128(quarter, 128, 126), (1 << 127, false));
+        assert_eq!(sqrt::rsqrt_u128(quarter, 128, 127), (0, true));
+        // 1/√(1 − 2^−128) is slightly larger than 1
+        let max = NonZeroU128::new(u128::MAX).unwrap();
+        assert_eq!(sqrt::rsqrt_u128(max, 128, 127), (1 << 127, false));
+        assert_eq!(sqrt::rsqrt_u128(max, 128, 128), (0, true));
+        // 1/√(2^−128) = 2^64
+        let min = NonZeroU128::new(1).unwrap();
+        assert_eq!(sqrt::rsqrt_u128(min, 128, 63), (1 << 127, false));
+        assert_eq!(sqrt::rsqrt_u128(min, 128, 62), (1 << 126, false));
+        // 1/√2 with 128 fractional bits
+        let two = NonZeroU128::new(2).unwrap();
+        let rsqrt_2 = sqrt::rsqrt_u128(two, 0, 128).0;
+        assert_eq!(
+            rsqrt_2,
+            sqrt::u128(NonZeroU128::new(1 << 127).unwrap(), 128)
+        );
+    }
+
+    macro_rules! check_sqrt_round {
+        ($Fixed:ident, $Inner:ident, $frac:literal) => {{
+            use crate::rounding::tests::ALL_MODES;
+            type F = $Fixed<$frac>;
+            for bits in 0..=$Inner::MAX {
+                let x = F::from_bits(bits);
+                let exact = (bits as f64 * ($frac as f64).exp2()).sqrt();
+                // the square root of an integer is never halfway between two integers
+                for mode in ALL_MODES {
+                    let expected = match mode {
+                        RoundingMode::ToZero | RoundingMode::Floor => exact.floor(),
+                        RoundingMode::AwayFromZero | RoundingMode::Ceil => exact.ceil(),
+                        RoundingMode::HalfEven
+                        | RoundingMode::HalfAwayFromZero
+                        | RoundingMode::HalfToZero => exact.round(),
+                    };
+                    let overflow = expected > $Inner::MAX as f64;
+                    let wrapped = F::from_bits(expected as i64 as $Inner);
+                    assert_eq!(
+                        x.overflowing_sqrt_round(mode),
+                        (wrapped, overflow),
+                        "{x} {mode:?}"
+                    );
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn check_sqrt_round_8() {
+        check_sqrt_round!(FixedU8, u8, 0);
+        check_sqrt_round!(FixedU8, u8, 1);
+        check_sqrt_round!(FixedU8, u8, 7);
+        check_sqrt_round!(FixedU8, u8, 8);
+        check_sqrt_round!(FixedI8, i8, 0);
+        check_sqrt_round!(FixedI8, i8, 1);
+        check_sqrt_round!(FixedI8, i8, 7);
+        check_sqrt_round!(FixedI8, i8, 8);
+    }
+
+    #[test]
+    fn check_sqrt_round_128() {
+        let two = FixedU128::<126>::from_num(2);
+        let floor = two.sqrt_round(RoundingMode::Floor);
+        assert_eq!(floor, FixedU128::<126>::SQRT_2);
+        assert_eq!(two.sqrt_round(RoundingMode::Ceil), floor + FixedU128::DELTA);
+        let quarter = FixedU128::<128>::from_num(0.25);
+        assert_eq!(
+            quarter.sqrt_round(RoundingMode::Ceil),
+            FixedU128::<128>::from_num(0.5)
+        );
+        assert_eq!(
+            FixedU128::<128>::MAX.overflowing_sqrt_round(RoundingMode::HalfEven),
+            (FixedU128::<128>::MAX, false)
+        );
+        assert_eq!(
+            FixedU128::<128>::MAX.overflowing_sqrt_round(RoundingMode::Ceil),
+            (FixedU128::ZERO, true)
+        );
+        assert_eq!(
+            FixedI128::<127>::MAX.overflowing_sqrt_round(RoundingMode::Ceil),
+            (FixedI128::<127>::MIN, true)
+        );
+        assert_eq!(
+            FixedI128::<127>::MAX.overflowing_sqrt_round(RoundingMode::ToZero),
+            (FixedI128::<127>::MAX, false)
+        );
+    }
 }
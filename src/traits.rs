@@ -22,18 +22,21 @@ pub use crate::traits_bits::{
     FixedBitsOptionalNum, FixedBitsOptionalSerde,
 };
 use crate::{
+    arith,
     helpers::Sealed,
+    rounding,
     types::extra::{If, True},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8, ParseFixedError, F128,
+    FixedU8, ParseFixedError, RoundingMode, F128,
 };
 #[cfg(feature = "arbitrary")]
 use arbitrary::Arbitrary;
-use az::OverflowingCastFrom;
+use az::{OverflowingCastFrom, WrappingCast, WrappingCastFrom};
 #[cfg(feature = "borsh")]
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Contiguous, Pod, TransparentWrapper};
 use core::{
+    array,
     fmt::{Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex},
     hash::Hash,
     iter::{Product, Sum},
@@ -763,7 +766,17 @@ where
     /// See also
     /// <code>FixedI32::[from\_be\_bytes][FixedI32::from_be_bytes]</code> and
     /// <code>FixedU32::[from\_be\_bytes][FixedU32::from_be_bytes]</code>.
-    fn from_be_bytes(bytes: [u8; size_of::<Self>()]) -> Self;
+    //
+    // The byte methods have default implementations because FixedI and FixedU
+    // cannot implement them: the compiler cannot evaluate `size_of::<Self>()`
+    // in an impl whose bounds contain `INT + FRAC`.
+    fn from_be_bytes(bytes: [u8; size_of::<Self>()]) -> Self {
+        let mut bits = 0u128;
+        for &byte in &bytes {
+            bits = (bits << 8) | u128::from(byte);
+        }
+        Self::from_bits(Self::Bits::wrapping_cast_from(bits))
+    }
 
     /// Creates a fixed-point number from its representation as a byte
     /// array in little endian.
@@ -771,7 +784,13 @@ where
     /// See also
     /// <code>FixedI32::[from\_le\_bytes][FixedI32::from_le_bytes]</code> and
     /// <code>FixedU32::[from\_le\_bytes][FixedU32::from_le_bytes]</code>.
-    fn from_le_bytes(bytes: [u8; size_of::<Self>()]) -> Self;
+    fn from_le_bytes(bytes: [u8; size_of::<Self>()]) -> Self {
+        let mut bits = 0u128;
+        for &byte in bytes.iter().rev() {
+            bits = (bits << 8) | u128::from(byte);
+        }
+        Self::from_bits(Self::Bits::wrapping_cast_from(bits))
+    }
 
     /// Creates a fixed-point number from its representation as a byte
     /// array in native endian.
@@ -779,28 +798,46 @@ where
     /// See also
     /// <code>FixedI32::[from\_ne\_bytes][FixedI32::from_ne_bytes]</code> and
     /// <code>FixedU32::[from\_ne\_bytes][FixedU32::from_ne_bytes]</code>.
-    fn from_ne_bytes(bytes: [u8; size_of::<Self>()]) -> Self;
+    fn from_ne_bytes(bytes: [u8; size_of::<Self>()]) -> Self {
+        if cfg!(target_endian = "big") {
+            Self::from_be_bytes(bytes)
+        } else {
+            Self::from_le_bytes(bytes)
+        }
+    }
 
     /// Returns the memory representation of this fixed-point number
     /// as a byte array in big-endian byte order.
     ///
     /// See also <code>FixedI32::[to\_be\_bytes][FixedI32::to_be_bytes]</code>
     /// and <code>FixedU32::[to\_be\_bytes][FixedU32::to_be_bytes]</code>.
-    fn to_be_bytes(self) -> [u8; size_of::<Self>()];
+    fn to_be_bytes(self) -> [u8; size_of::<Self>()] {
+        let bits: u128 = self.to_bits().wrapping_cast();
+        array::from_fn(|i| (bits >> (8 * (size_of::<Self>() - 1 - i))) as u8)
+    }
 
     /// Returns the memory representation of this fixed-point number
     /// as a byte array in little-endian byte order.
     ///
     /// See also <code>FixedI32::[to\_le\_bytes][FixedI32::to_le_bytes]</code>
     /// and <code>FixedU32::[to\_le\_bytes][FixedU32::to_le_bytes]</code>.
-    fn to_le_bytes(self) -> [u8; size_of::<Self>()];
+    fn to_le_bytes(self) -> [u8; size_of::<Self>()] {
+        let bits: u128 = self.to_bits().wrapping_cast();
+        array::from_fn(|i| (bits >> (8 * i)) as u8)
+    }
 
     /// Returns the memory representation of this fixed-point number
     /// as a byte array in native byte order.
     ///
     /// See also <code>FixedI32::[to\_ne\_bytes][FixedI32::to_ne_bytes]</code>
     /// and <code>FixedU32::[to\_ne\_bytes][FixedU32::to_ne_bytes]</code>.
-    fn to_ne_bytes(self) -> [u8; size_of::<Self>()];
+    fn to_ne_bytes(self) -> [u8; size_of::<Self>()] {
+        if cfg!(target_endian = "big") {
+            self.to_be_bytes()
+        } else {
+            self.to_le_bytes()
+        }
+    }
 
     /// Creates a fixed-point number from another number.
     ///
@@ -945,6 +982,261 @@ where
     /// <code>FixedU32::[overflowing\_to\_num][FixedU32::overflowing_to_num]</code>.
     fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool);
 
+    /// Creates a fixed-point number from another number, rounding according
+    /// to the given [`RoundingMode`].
+    ///
+    /// Returns the same value as
+    /// <code>src.[to\_fixed\_round][ToFixed::to_fixed_round]\(mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_round][FixedI32::from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_round][FixedU32::from_num_round]</code>.
+    fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self;
+
+    /// Converts a fixed-point number to another number, rounding according
+    /// to the given [`RoundingMode`].
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[from\_fixed\_round][FromFixed::from_fixed_round]\(self, mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[to\_num\_round][FixedI32::to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[to\_num\_round][FixedU32::to_num_round]</code>.
+    fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst;
+
+    /// Creates a fixed-point number from another number if it fits, rounding
+    /// according to the given [`RoundingMode`], otherwise returns [`None`].
+    ///
+    /// Returns the same value as
+    /// <code>src.[checked\_to\_fixed\_round][ToFixed::checked_to_fixed_round]\(mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_from\_num\_round][FixedI32::checked_from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_from\_num\_round][FixedU32::checked_from_num_round]</code>.
+    fn checked_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Option<Self>;
+
+    /// Converts a fixed-point number to another number if it fits, rounding
+    /// according to the given [`RoundingMode`], otherwise returns [`None`].
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[checked\_from\_fixed\_round][FromFixed::checked_from_fixed_round]\(self, mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_to\_num\_round][FixedI32::checked_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_to\_num\_round][FixedU32::checked_to_num_round]</code>.
+    fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst>;
+
+    /// Converts a fixed-point number to another number, rounding according
+    /// to the given [`RoundingMode`] and saturating if it does not fit.
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[saturating\_from\_fixed\_round][FromFixed::saturating_from_fixed_round]\(self, mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_to\_num\_round][FixedI32::saturating_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_to\_num\_round][FixedU32::saturating_to_num_round]</code>.
+    fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst;
+
+    /// Converts a fixed-point number to another number, rounding according
+    /// to the given [`RoundingMode`] and wrapping if it does not fit.
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[wrapping\_from\_fixed\_round][FromFixed::wrapping_from_fixed_round]\(self, mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_to\_num\_round][FixedI32::wrapping_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_to\_num\_round][FixedU32::wrapping_to_num_round]</code>.
+    fn wrapping_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst;
+
+    /// Converts a fixed-point number to another number, rounding according
+    /// to the given [`RoundingMode`] and panicking if it does not fit.
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[unwrapped\_from\_fixed\_round][FromFixed::unwrapped_from_fixed_round]\(self, mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_to\_num\_round][FixedI32::unwrapped_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_to\_num\_round][FixedU32::unwrapped_to_num_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit, even when debug assertions are not
+    /// enabled.
+    #[track_caller]
+    fn unwrapped_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst;
+
+    /// Creates a fixed-point number from another number, rounding according
+    /// to the given [`RoundingMode`].
+    ///
+    /// Returns the same value as
+    /// <code>src.[overflowing\_to\_fixed\_round][ToFixed::overflowing_to_fixed_round]\(mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_num\_round][FixedI32::overflowing_from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_num\_round][FixedU32::overflowing_from_num_round]</code>.
+    fn overflowing_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> (Self, bool);
+
+    /// Converts a fixed-point number to another number, rounding according
+    /// to the given [`RoundingMode`].
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[overflowing\_from\_fixed\_round][FromFixed::overflowing_from_fixed_round]\(self, mode)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_to\_num\_round][FixedI32::overflowing_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_to\_num\_round][FixedU32::overflowing_to_num_round]</code>.
+    fn overflowing_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> (Dst, bool);
+
+    /// Creates a fixed-point number from another number, rounding
+    /// stochastically.
+    ///
+    /// Returns the same value as
+    /// <code>src.[to\_fixed\_stochastic][ToFixed::to_fixed_stochastic]\(rng)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_stochastic][FixedI32::from_num_stochastic]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_stochastic][FixedU32::from_num_stochastic]</code>.
+    fn from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(src: Src, rng: &mut R) -> Self;
+
+    /// Creates a fixed-point number from another number if it fits, rounding
+    /// stochastically, otherwise returns [`None`].
+    ///
+    /// Returns the same value as
+    /// <code>src.[checked\_to\_fixed\_stochastic][ToFixed::checked_to_fixed_stochastic]\(rng)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_from\_num\_stochastic][FixedI32::checked_from_num_stochastic]</code>
+    /// and
+    /// <code>FixedU32::[checked\_from\_num\_stochastic][FixedU32::checked_from_num_stochastic]</code>.
+    fn checked_from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+        src: Src,
+        rng: &mut R,
+    ) -> Option<Self>;
+
+    /// Creates a fixed-point number from another number, rounding
+    /// stochastically.
+    ///
+    /// Returns the same value as
+    /// <code>src.[overflowing\_to\_fixed\_stochastic][ToFixed::overflowing_to_fixed_stochastic]\(rng)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_num\_stochastic][FixedI32::overflowing_from_num_stochastic]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_num\_stochastic][FixedU32::overflowing_from_num_stochastic]</code>.
+    fn overflowing_from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+        src: Src,
+        rng: &mut R,
+    ) -> (Self, bool);
+
+    /// Converts to a fixed-point number with the same underlying integer type
+    /// and `DST_FRAC` fractional bits, rounding according to the given
+    /// [`RoundingMode`].
+    ///
+    /// See also
+    /// <code>FixedI32::[requantize][FixedI32::requantize]</code>
+    /// and
+    /// <code>FixedU32::[requantize][FixedU32::requantize]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does not fit.
+    #[track_caller]
+    #[must_use]
+    fn requantize<const DST_FRAC: i32>(
+        self,
+        mode: RoundingMode,
+    ) -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>;
+
+    /// Converts to a fixed-point number with the same underlying integer type
+    /// and `DST_FRAC` fractional bits if it fits, rounding according to the
+    /// given [`RoundingMode`], otherwise returns [`None`].
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_requantize][FixedI32::checked_requantize]</code>
+    /// and
+    /// <code>FixedU32::[checked\_requantize][FixedU32::checked_requantize]</code>.
+    #[must_use]
+    fn checked_requantize<const DST_FRAC: i32>(
+        self,
+        mode: RoundingMode,
+    ) -> Option<<Self::Bits as FixedBits>::Fixed<DST_FRAC>>;
+
+    /// Converts to a fixed-point number with the same underlying integer type
+    /// and `DST_FRAC` fractional bits, rounding according to the given
+    /// [`RoundingMode`] and saturating if the value does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_requantize][FixedI32::saturating_requantize]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_requantize][FixedU32::saturating_requantize]</code>.
+    #[must_use]
+    fn saturating_requantize<const DST_FRAC: i32>(
+        self,
+        mode: RoundingMode,
+    ) -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>;
+
+    /// Converts to a fixed-point number with the same underlying integer type
+    /// and `DST_FRAC` fractional bits, rounding according to the given
+    /// [`RoundingMode`] and wrapping if the value does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_requantize][FixedI32::wrapping_requantize]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_requantize][FixedU32::wrapping_requantize]</code>.
+    #[must_use]
+    fn wrapping_requantize<const DST_FRAC: i32>(
+        self,
+        mode: RoundingMode,
+    ) -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>;
+
+    /// Converts to a fixed-point number with the same underlying integer type
+    /// and `DST_FRAC` fractional bits, rounding according to the given
+    /// [`RoundingMode`] and panicking if the value does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_requantize][FixedI32::unwrapped_requantize]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_requantize][FixedU32::unwrapped_requantize]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit, even when debug assertions are not
+    /// enabled.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_requantize<const DST_FRAC: i32>(
+        self,
+        mode: RoundingMode,
+    ) -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>;
+
+    /// Converts to a fixed-point number with the same underlying integer type
+    /// and `DST_FRAC` fractional bits, rounding according to the given
+    /// [`RoundingMode`].
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_requantize][FixedI32::overflowing_requantize]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_requantize][FixedU32::overflowing_requantize]</code>.
+    #[must_use]
+    fn overflowing_requantize<const DST_FRAC: i32>(
+        self,
+        mode: RoundingMode,
+    ) -> (<Self::Bits as FixedBits>::Fixed<DST_FRAC>, bool);
+
     /// Returns the integer part.
     ///
     /// See also <code>FixedI32::[int][FixedI32::int]</code> and
@@ -1331,6 +1623,16 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn hypot(self, other: Self) -> Self;
 
+    /// Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+    /// with the angle in radians returned with `RET_FRAC` fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[atan2][FixedI32::atan2]</code> and
+    /// <code>FixedU32::[atan2][FixedU32::atan2]</code>.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn atan2<const RET_FRAC: i32>(self, other: Self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>;
+
     /// Returns the next multiple of `other`.
     ///
     /// See also
@@ -1345,6 +1647,19 @@ where
     #[must_use]
     fn next_multiple_of(self, other: Self) -> Self;
 
+    /// Multiplication with stochastic rounding.
+    ///
+    /// See also
+    /// <code>FixedI32::[mul\_stochastic][FixedI32::mul_stochastic]</code> and
+    /// <code>FixedU32::[mul\_stochastic][FixedU32::mul_stochastic]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the result overflows.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> Self;
+
     /// Multiply and add. Returns `self` × `mul` + `add`.
     ///
     /// See also <code>FixedI32::[mul\_add][FixedI32::mul_add]</code> and
@@ -1416,6 +1731,16 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_mul(self, rhs: Self) -> Option<Self>;
 
+    /// Checked multiplication with stochastic rounding. Returns the product,
+    /// or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_stochastic][FixedI32::checked_mul_stochastic]</code> and
+    /// <code>FixedU32::[checked\_mul\_stochastic][FixedU32::checked_mul_stochastic]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R)
+        -> Option<Self>;
+
     /// Checked remainder. Returns the remainder, or [`None`] if the
     /// divisor is zero.
     ///
@@ -1538,6 +1863,18 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_hypot(self, other: Self) -> Option<Self>;
 
+    /// Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+    /// returning [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_atan2][FixedI32::checked_atan2]</code> and
+    /// <code>FixedU32::[checked\_atan2][FixedU32::checked_atan2]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_atan2<const RET_FRAC: i32>(
+        self,
+        other: Self,
+    ) -> Option<<Self::Bits as FixedBits>::Fixed<RET_FRAC>>;
+
     /// Saturated negation. Returns the negated value, saturating on overflow.
     ///
     /// See also
@@ -1570,6 +1907,15 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn saturating_mul(self, rhs: Self) -> Self;
 
+    /// Saturating multiplication with stochastic rounding. Returns the
+    /// product, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_stochastic][FixedI32::saturating_mul_stochastic]</code> and
+    /// <code>FixedU32::[saturating\_mul\_stochastic][FixedU32::saturating_mul_stochastic]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> Self;
+
     /// Saturating next multiple of `other`.
     ///
     /// See also
@@ -1665,6 +2011,18 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn saturating_hypot(self, other: Self) -> Self;
 
+    /// Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_atan2][FixedI32::saturating_atan2]</code> and
+    /// <code>FixedU32::[saturating\_atan2][FixedU32::saturating_atan2]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_atan2<const RET_FRAC: i32>(
+        self,
+        other: Self,
+    ) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>;
+
     /// Wrapping negation. Returns the negated value, wrapping on overflow.
     ///
     /// See also <code>FixedI32::[wrapping\_neg][FixedI32::wrapping_neg]</code>
@@ -1693,6 +2051,15 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn wrapping_mul(self, rhs: Self) -> Self;
 
+    /// Wrapping multiplication with stochastic rounding. Returns the product,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_mul\_stochastic][FixedI32::wrapping_mul_stochastic]</code> and
+    /// <code>FixedU32::[wrapping\_mul\_stochastic][FixedU32::wrapping_mul_stochastic]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> Self;
+
     /// Wrapping next multiple of `other`.
     ///
     /// See also
@@ -1803,6 +2170,18 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn wrapping_hypot(self, other: Self) -> Self;
 
+    /// Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_atan2][FixedI32::wrapping_atan2]</code> and
+    /// <code>FixedU32::[wrapping\_atan2][FixedU32::wrapping_atan2]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_atan2<const RET_FRAC: i32>(
+        self,
+        other: Self,
+    ) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>;
+
     /// Unwrapped negation. Returns the negated value, panicking on overflow.
     ///
     /// See also
@@ -1855,6 +2234,20 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn unwrapped_mul(self, rhs: Self) -> Self;
 
+    /// Unwrapped multiplication with stochastic rounding. Returns the
+    /// product, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_mul\_stochastic][FixedI32::unwrapped_mul_stochastic]</code> and
+    /// <code>FixedU32::[unwrapped\_mul\_stochastic][FixedU32::unwrapped_mul_stochastic]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> Self;
+
     /// Unwrapped remainder. Returns the quotient, panicking if the divisor is zero.
     ///
     /// See also
@@ -2034,6 +2427,23 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn unwrapped_hypot(self, other: Self) -> Self;
 
+    /// Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+    /// panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_atan2][FixedI32::unwrapped_atan2]</code> and
+    /// <code>FixedU32::[unwrapped\_atan2][FixedU32::unwrapped_atan2]</code>.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_atan2<const RET_FRAC: i32>(
+        self,
+        other: Self,
+    ) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>;
+
     /// Overflowing negation.
     ///
     /// Returns a [tuple] of the negated value and a [`bool`],
@@ -2081,6 +2491,21 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn overflowing_mul(self, rhs: Self) -> (Self, bool);
 
+    /// Overflowing multiplication with stochastic rounding.
+    ///
+    /// Returns a [tuple] of the product and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_mul\_stochastic][FixedI32::overflowing_mul_stochastic]</code> and
+    /// <code>FixedU32::[overflowing\_mul\_stochastic][FixedU32::overflowing_mul_stochastic]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_mul_stochastic<R: RandomBits + ?Sized>(
+        self,
+        rhs: Self,
+        rng: &mut R,
+    ) -> (Self, bool);
+
     /// Overflowing next multiple of `other`.
     ///
     /// Returns a [tuple] of the next multiple and a [`bool`], indicating
@@ -2224,6 +2649,20 @@ where
     /// <code>FixedU32::[overflowing\_hypot][FixedU32::overflowing_hypot]</code>.
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn overflowing_hypot(self, other: Self) -> (Self, bool);
+
+    /// Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>).
+    ///
+    /// Returns a [tuple] of the angle and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_atan2][FixedI32::overflowing_atan2]</code> and
+    /// <code>FixedU32::[overflowing\_atan2][FixedU32::overflowing_atan2]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_atan2<const RET_FRAC: i32>(
+        self,
+        other: Self,
+    ) -> (<Self::Bits as FixedBits>::Fixed<RET_FRAC>, bool);
 }
 
 /// This trait provides methods common to fixed-point numbers where the number
@@ -2328,6 +2767,15 @@ where
     /// and <code>FixedU32::[from\_str\_hex][FixedU32::from_str_hex]</code>.
     fn from_str_hex(src: &str) -> Result<Self, ParseFixedError>;
 
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number, rounding according to the given [`RoundingMode`].
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_str\_round][FixedI32::from_str_round]</code>
+    /// and
+    /// <code>FixedU32::[from\_str\_round][FixedU32::from_str_round]</code>.
+    fn from_str_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>;
+
     /// Parses a string slice containing decimal digits to return a
     /// fixed-point number, saturating on overflow.
     ///
@@ -2540,6 +2988,22 @@ where
     /// <code>FixedU32::[overflowing\_from\_str\_hex][FixedU32::overflowing_from_str_hex]</code>.
     fn overflowing_from_str_hex(src: &str) -> Result<(Self, bool), ParseFixedError>;
 
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number, rounding according to the given [`RoundingMode`].
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_round][FixedI32::overflowing_from_str_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_round][FixedU32::overflowing_from_str_round]</code>.
+    fn overflowing_from_str_round(
+        src: &str,
+        mode: RoundingMode,
+    ) -> Result<(Self, bool), ParseFixedError>;
+
     /// Integer base-10 logarithm, rounded down.
     ///
     /// See also <code>FixedI32::[int\_log10][FixedI32::int_log10]</code> and
@@ -2597,54 +3061,156 @@ where
     #[must_use]
     fn recip(self) -> Self;
 
-    /// Euclidean division by an integer.
+    /// Multiplication, rounded according to the given [`RoundingMode`].
     ///
-    /// See also <code>FixedI32::[div\_euclid][FixedI32::div_euclid]</code> and
-    /// <code>FixedU32::[div\_euclid][FixedU32::div_euclid]</code>.
+    /// See also
+    /// <code>FixedI32::[mul\_round][FixedI32::mul_round]</code>
+    /// and
+    /// <code>FixedU32::[mul\_round][FixedU32::mul_round]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the division results in overflow.
+    /// When debug assertions are enabled, panics if the result overflows.
     #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn div_euclid(self, rhs: Self) -> Self;
+    fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
 
-    /// Euclidean division by an integer.
+    /// Division, rounded according to the given [`RoundingMode`].
     ///
     /// See also
-    /// <code>FixedI32::[div\_euclid\_int][FixedI32::div_euclid_int]</code> and
-    /// <code>FixedU32::[div\_euclid\_int][FixedU32::div_euclid_int]</code>.
+    /// <code>FixedI32::[div\_round][FixedI32::div_round]</code>
+    /// and
+    /// <code>FixedU32::[div\_round][FixedU32::div_round]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the division results in overflow.
+    /// Panics if the divisor is zero. When debug assertions are enabled, also
+    /// panics if the result overflows.
     #[track_caller]
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn div_euclid_int(self, rhs: Self::Bits) -> Self;
+    fn div_round(self, rhs: Self, mode: RoundingMode) -> Self;
 
-    /// Remainder for Euclidean division by an integer.
+    /// Returns the reciprocal, rounded according to the given [`RoundingMode`].
     ///
     /// See also
-    /// <code>FixedI32::[rem\_euclid\_int][FixedI32::rem_euclid_int]</code> and
-    /// <code>FixedU32::[rem\_euclid\_int][FixedU32::rem_euclid_int]</code>.
+    /// <code>FixedI32::[recip\_round][FixedI32::recip_round]</code>
+    /// and
+    /// <code>FixedU32::[recip\_round][FixedU32::recip_round]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the division results in overflow.
+    /// Panics if `self` is zero.
     #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn rem_euclid_int(self, rhs: Self::Bits) -> Self;
+    #[must_use]
+    fn recip_round(self, mode: RoundingMode) -> Self;
 
-    /// Returns the square root.
+    /// Euclidean division by an integer.
     ///
-    /// See also <code>FixedI32::[sqrt][FixedI32::sqrt]</code> and
-    /// <code>FixedU32::[sqrt][FixedU32::sqrt]</code>.
+    /// See also <code>FixedI32::[div\_euclid][FixedI32::div_euclid]</code> and
+    /// <code>FixedU32::[div\_euclid][FixedU32::div_euclid]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// Euclidean division by an integer.
+    ///
+    /// See also
+    /// <code>FixedI32::[div\_euclid\_int][FixedI32::div_euclid_int]</code> and
+    /// <code>FixedU32::[div\_euclid\_int][FixedU32::div_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Remainder for Euclidean division by an integer.
+    ///
+    /// See also
+    /// <code>FixedI32::[rem\_euclid\_int][FixedI32::rem_euclid_int]</code> and
+    /// <code>FixedU32::[rem\_euclid\_int][FixedU32::rem_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn rem_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Returns the square root.
+    ///
+    /// See also <code>FixedI32::[sqrt][FixedI32::sqrt]</code> and
+    /// <code>FixedU32::[sqrt][FixedU32::sqrt]</code>.
     ///
     /// # Panics
     ///
     /// Panics if the number is negative.
     fn sqrt(self) -> Self;
 
+    /// Returns the square root, rounded according to the given [`RoundingMode`].
+    ///
+    /// See also
+    /// <code>FixedI32::[sqrt\_round][FixedI32::sqrt_round]</code>
+    /// and
+    /// <code>FixedU32::[sqrt\_round][FixedU32::sqrt_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    fn sqrt_round(self, mode: RoundingMode) -> Self;
+
+    /// Returns the cube root.
+    ///
+    /// See also
+    /// <code>FixedI32::[cbrt][FixedI32::cbrt]</code> and
+    /// <code>FixedU32::[cbrt][FixedU32::cbrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn cbrt(self) -> Self;
+
+    /// Returns the <i>n</i>th root.
+    ///
+    /// See also
+    /// <code>FixedI32::[nth\_root][FixedI32::nth_root]</code> and
+    /// <code>FixedU32::[nth\_root][FixedU32::nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if the number is negative and `n` is even.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows.
+    #[track_caller]
+    #[must_use]
+    fn nth_root(self, n: u32) -> Self;
+
+    /// Returns the reciprocal square root, 1/√`self`, with `RET_FRAC`
+    /// fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[rsqrt][FixedI32::rsqrt]</code> and
+    /// <code>FixedU32::[rsqrt][FixedU32::rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows.
+    #[track_caller]
+    #[must_use]
+    fn rsqrt<const RET_FRAC: i32>(self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>;
+
     /// Linear interpolation between `start` and `end`.
     ///
     /// See also <code>FixedI32::[lerp][FixedI32::lerp]</code> and
@@ -2687,6 +3253,53 @@ where
     #[track_caller]
     fn overflowing_recip(self) -> (Self, bool);
 
+    /// Overflowing multiplication, rounded according to the given
+    /// [`RoundingMode`].
+    ///
+    /// Returns a [tuple] of the product and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_mul\_round][FixedI32::overflowing_mul_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_mul\_round][FixedU32::overflowing_mul_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool);
+
+    /// Overflowing division, rounded according to the given [`RoundingMode`].
+    ///
+    /// Returns a [tuple] of the quotient and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_div\_round][FixedI32::overflowing_div_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_div\_round][FixedU32::overflowing_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_div_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool);
+
+    /// Overflowing reciprocal, rounded according to the given [`RoundingMode`].
+    ///
+    /// Returns a [tuple] of the reciprocal of `self` and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_recip\_round][FixedI32::overflowing_recip_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_recip\_round][FixedU32::overflowing_recip_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    #[track_caller]
+    fn overflowing_recip_round(self, mode: RoundingMode) -> (Self, bool);
+
     /// Overflowing Euclidean division.
     ///
     /// Returns a [tuple] of the quotient and a [`bool`], indicating
@@ -2712,6 +3325,36 @@ where
     #[must_use]
     fn inv_lerp(self, start: Self, end: Self) -> Self;
 
+    /// Rounds to `dp` decimal places according to the given rounding mode.
+    ///
+    /// See also
+    /// <code>FixedI32::[round\_dp][FixedI32::round_dp]</code>
+    /// and
+    /// <code>FixedU32::[round\_dp][FixedU32::round_dp]</code>.
+    #[track_caller]
+    #[must_use]
+    fn round_dp(self, dp: u32, mode: RoundingMode) -> Self;
+
+    /// Rounds down to `dp` decimal places.
+    ///
+    /// See also
+    /// <code>FixedI32::[floor\_dp][FixedI32::floor_dp]</code>
+    /// and
+    /// <code>FixedU32::[floor\_dp][FixedU32::floor_dp]</code>.
+    #[track_caller]
+    #[must_use]
+    fn floor_dp(self, dp: u32) -> Self;
+
+    /// Rounds up to `dp` decimal places.
+    ///
+    /// See also
+    /// <code>FixedI32::[ceil\_dp][FixedI32::ceil_dp]</code>
+    /// and
+    /// <code>FixedU32::[ceil\_dp][FixedU32::ceil_dp]</code>.
+    #[track_caller]
+    #[must_use]
+    fn ceil_dp(self, dp: u32) -> Self;
+
     /// Checked division. Returns the quotient, or [`None`] if the
     /// divisor is zero or on overflow.
     ///
@@ -2728,6 +3371,33 @@ where
     /// <code>FixedU32::[checked\_recip][FixedU32::checked_recip]</code>.
     fn checked_recip(self) -> Option<Self>;
 
+    /// Checked multiplication, rounded according to the given
+    /// [`RoundingMode`]. Returns the product, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_round][FixedI32::checked_mul_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_round][FixedU32::checked_mul_round]</code>.
+    fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked division, rounded according to the given [`RoundingMode`].
+    /// Returns the quotient, or [`None`] if the divisor is zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_div\_round][FixedI32::checked_div_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_div\_round][FixedU32::checked_div_round]</code>.
+    fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked reciprocal, rounded according to the given [`RoundingMode`].
+    /// Returns the reciprocal, or [`None`] if `self` is zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_recip\_round][FixedI32::checked_recip_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_recip\_round][FixedU32::checked_recip_round]</code>.
+    fn checked_recip_round(self, mode: RoundingMode) -> Option<Self>;
+
     /// Checked remainder for Euclidean division. Returns the
     /// remainder, or [`None`] if the divisor is zero or the division
     /// results in overflow.
@@ -2778,6 +3448,43 @@ where
     /// and <code>FixedU32::[checked\_sqrt][FixedU32::checked_sqrt]</code>.
     fn checked_sqrt(self) -> Option<Self>;
 
+    /// Checked square root, rounded according to the given [`RoundingMode`].
+    /// Returns [`None`] for negative numbers or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sqrt\_round][FixedI32::checked_sqrt_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_sqrt\_round][FixedU32::checked_sqrt_round]</code>.
+    fn checked_sqrt_round(self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked cube root. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_cbrt][FixedI32::checked_cbrt]</code> and
+    /// <code>FixedU32::[checked\_cbrt][FixedU32::checked_cbrt]</code>.
+    #[must_use]
+    fn checked_cbrt(self) -> Option<Self>;
+
+    /// Checked <i>n</i>th root. Returns [`None`] if `n` is zero, if the number
+    /// is negative and `n` is even, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_nth\_root][FixedI32::checked_nth_root]</code> and
+    /// <code>FixedU32::[checked\_nth\_root][FixedU32::checked_nth_root]</code>.
+    #[must_use]
+    fn checked_nth_root(self, n: u32) -> Option<Self>;
+
+    /// Checked reciprocal square root. Returns [`None`] if the number is zero
+    /// or negative, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_rsqrt][FixedI32::checked_rsqrt]</code> and
+    /// <code>FixedU32::[checked\_rsqrt][FixedU32::checked_rsqrt]</code>.
+    #[must_use]
+    fn checked_rsqrt<const RET_FRAC: i32>(
+        self,
+    ) -> Option<<Self::Bits as FixedBits>::Fixed<RET_FRAC>>;
+
     /// Checked linear interpolation between `start` and `end`. Returns [`None`]
     /// on overflow.
     ///
@@ -2794,6 +3501,14 @@ where
     /// <code>FixedU32::[checked\_inv\_lerp][FixedU32::checked_inv_lerp]</code>.
     fn checked_inv_lerp(self, start: Self, end: Self) -> Option<Self>;
 
+    /// Checked rounding to `dp` decimal places. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_round\_dp][FixedI32::checked_round_dp]</code>
+    /// and
+    /// <code>FixedU32::[checked\_round\_dp][FixedU32::checked_round_dp]</code>.
+    fn checked_round_dp(self, dp: u32, mode: RoundingMode) -> Option<Self>;
+
     /// Saturating division. Returns the quotient, saturating on overflow.
     ///
     /// See also
@@ -2821,6 +3536,45 @@ where
     #[must_use]
     fn saturating_recip(self) -> Self;
 
+    /// Saturating multiplication, rounded according to the given
+    /// [`RoundingMode`]. Returns the product, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_round][FixedI32::saturating_mul_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_round][FixedU32::saturating_mul_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Saturating division, rounded according to the given [`RoundingMode`].
+    /// Returns the quotient, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_div\_round][FixedI32::saturating_div_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_div\_round][FixedU32::saturating_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_div_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Saturating reciprocal, rounded according to the given [`RoundingMode`].
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_recip\_round][FixedI32::saturating_recip_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_recip\_round][FixedU32::saturating_recip_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    #[track_caller]
+    #[must_use]
+    fn saturating_recip_round(self, mode: RoundingMode) -> Self;
+
     /// Saturating Euclidean division. Returns the quotient, saturating on overflow.
     ///
     /// See also
@@ -2876,6 +3630,53 @@ where
     /// Panics if the number is negative.
     fn saturating_sqrt(self) -> Self;
 
+    /// Returns the square root, rounded according to the given [`RoundingMode`]
+    /// and saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sqrt\_round][FixedI32::saturating_sqrt_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_sqrt\_round][FixedU32::saturating_sqrt_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    fn saturating_sqrt_round(self, mode: RoundingMode) -> Self;
+
+    /// Returns the cube root, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cbrt][FixedI32::saturating_cbrt]</code> and
+    /// <code>FixedU32::[saturating\_cbrt][FixedU32::saturating_cbrt]</code>.
+    #[must_use]
+    fn saturating_cbrt(self) -> Self;
+
+    /// Returns the <i>n</i>th root, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_nth\_root][FixedI32::saturating_nth_root]</code> and
+    /// <code>FixedU32::[saturating\_nth\_root][FixedU32::saturating_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if the number is negative and `n` is even.
+    #[track_caller]
+    #[must_use]
+    fn saturating_nth_root(self, n: u32) -> Self;
+
+    /// Returns the reciprocal square root, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_rsqrt][FixedI32::saturating_rsqrt]</code> and
+    /// <code>FixedU32::[saturating\_rsqrt][FixedU32::saturating_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    #[track_caller]
+    #[must_use]
+    fn saturating_rsqrt<const RET_FRAC: i32>(self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>;
+
     /// Linear interpolation between `start` and `end`, saturating on overflow.
     ///
     /// See also
@@ -2893,6 +3694,14 @@ where
     #[must_use]
     fn saturating_inv_lerp(self, start: Self, end: Self) -> Self;
 
+    /// Saturating rounding to `dp` decimal places, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_round\_dp][FixedI32::saturating_round_dp]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_round\_dp][FixedU32::saturating_round_dp]</code>.
+    fn saturating_round_dp(self, dp: u32, mode: RoundingMode) -> Self;
+
     /// Wrapping division. Returns the quotient, wrapping on overflow.
     ///
     /// See also <code>FixedI32::[wrapping\_div][FixedI32::wrapping_div]</code>
@@ -2918,6 +3727,45 @@ where
     #[must_use]
     fn wrapping_recip(self) -> Self;
 
+    /// Wrapping multiplication, rounded according to the given
+    /// [`RoundingMode`]. Returns the product, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_mul\_round][FixedI32::wrapping_mul_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_mul\_round][FixedU32::wrapping_mul_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Wrapping division, rounded according to the given [`RoundingMode`].
+    /// Returns the quotient, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_div\_round][FixedI32::wrapping_div_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_div\_round][FixedU32::wrapping_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_div_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Wrapping reciprocal, rounded according to the given [`RoundingMode`].
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_recip\_round][FixedI32::wrapping_recip_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_recip\_round][FixedU32::wrapping_recip_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_recip_round(self, mode: RoundingMode) -> Self;
+
     /// Wrapping Euclidean division. Returns the quotient, wrapping on overflow.
     ///
     /// See also
@@ -2959,239 +3807,2143 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
-    #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self;
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Returns the square root, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sqrt][FixedI32::wrapping_sqrt]</code> and
+    /// <code>FixedU32::[wrapping\_sqrt][FixedU32::wrapping_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    fn wrapping_sqrt(self) -> Self;
+
+    /// Returns the square root, rounded according to the given [`RoundingMode`]
+    /// and wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sqrt\_round][FixedI32::wrapping_sqrt_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_sqrt\_round][FixedU32::wrapping_sqrt_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    fn wrapping_sqrt_round(self, mode: RoundingMode) -> Self;
+
+    /// Returns the cube root, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cbrt][FixedI32::wrapping_cbrt]</code> and
+    /// <code>FixedU32::[wrapping\_cbrt][FixedU32::wrapping_cbrt]</code>.
+    #[must_use]
+    fn wrapping_cbrt(self) -> Self;
+
+    /// Returns the <i>n</i>th root, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_nth\_root][FixedI32::wrapping_nth_root]</code> and
+    /// <code>FixedU32::[wrapping\_nth\_root][FixedU32::wrapping_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if the number is negative and `n` is even.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_nth_root(self, n: u32) -> Self;
+
+    /// Returns the reciprocal square root, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_rsqrt][FixedI32::wrapping_rsqrt]</code> and
+    /// <code>FixedU32::[wrapping\_rsqrt][FixedU32::wrapping_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_rsqrt<const RET_FRAC: i32>(self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Linear interpolation between `start` and `end`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_lerp][FixedI32::wrapping_lerp]</code> and
+    /// <code>FixedU32::[wrapping\_lerp][FixedU32::wrapping_lerp]</code>.
+    #[must_use]
+    fn wrapping_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Inverse linear interpolation between `start` and `end`, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_inv\_lerp][FixedI32::wrapping_inv_lerp]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_inv\_lerp][FixedU32::wrapping_inv_lerp]</code>.
+    #[must_use]
+    fn wrapping_inv_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Wrapping rounding to `dp` decimal places, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_round\_dp][FixedI32::wrapping_round_dp]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_round\_dp][FixedU32::wrapping_round_dp]</code>.
+    fn wrapping_round_dp(self, dp: u32, mode: RoundingMode) -> Self;
+
+    /// Unwrapped division. Returns the quotient, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_div][FixedI32::unwrapped_div]</code> and
+    /// <code>FixedU32::[unwrapped\_div][FixedU32::unwrapped_div]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_div(self, rhs: Self) -> Self;
+
+    /// Unwrapped reciprocal. Returns reciprocal, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_recip][FixedI32::unwrapped_recip]</code> and
+    /// <code>FixedU32::[unwrapped\_recip][FixedU32::unwrapped_recip]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or on overflow.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_recip(self) -> Self;
+
+    /// Unwrapped multiplication, rounded according to the given
+    /// [`RoundingMode`]. Returns the product, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_mul\_round][FixedI32::unwrapped_mul_round]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_mul\_round][FixedU32::unwrapped_mul_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Unwrapped division, rounded according to the given [`RoundingMode`].
+    /// Returns the quotient, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_div\_round][FixedI32::unwrapped_div_round]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_div\_round][FixedU32::unwrapped_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_div_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Unwrapped reciprocal, rounded according to the given [`RoundingMode`].
+    /// Returns the reciprocal, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_recip\_round][FixedI32::unwrapped_recip_round]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_recip\_round][FixedU32::unwrapped_recip_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or on overflow.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_recip_round(self, mode: RoundingMode) -> Self;
+
+    /// Unwrapped Euclidean division. Returns the quotient, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_div\_euclid][FixedI32::unwrapped_div_euclid]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_div\_euclid][FixedU32::unwrapped_div_euclid]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_div_euclid(self, rhs: Self) -> Self;
+
+    /// Unwrapped remainder for division by an integer. Returns the
+    /// remainder, panicking if the divisor is zero.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_rem\_int][FixedI32::unwrapped_rem_int]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_rem\_int][FixedU32::unwrapped_rem_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_rem_int(self, rhs: Self::Bits) -> Self;
+
+    /// Unwrapped Euclidean division by an integer. Returns the
+    /// quotient, panicking on overflow.
+    ///
+    /// Overflow can only occur when dividing the minimum value by &minus;1.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_div\_euclid\_int][FixedI32::unwrapped_div_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_div\_euclid\_int][FixedU32::unwrapped_div_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_div_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Unwrapped remainder for Euclidean division by an integer.
+    /// Returns the remainder, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_rem\_euclid\_int][FixedI32::unwrapped_rem_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_rem\_euclid\_int][FixedU32::unwrapped_rem_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_rem_euclid_int(self, rhs: Self::Bits) -> Self;
+
+    /// Returns the square root, panicking if the number is negative or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sqrt][FixedI32::unwrapped_sqrt]</code> and
+    /// <code>FixedU32::[unwrapped\_sqrt][FixedU32::unwrapped_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative or on overflow.
+    fn unwrapped_sqrt(self) -> Self;
+
+    /// Returns the square root, rounded according to the given [`RoundingMode`]
+    /// and panicking if the number is negative or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sqrt\_round][FixedI32::unwrapped_sqrt_round]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_sqrt\_round][FixedU32::unwrapped_sqrt_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative or on overflow.
+    #[track_caller]
+    fn unwrapped_sqrt_round(self, mode: RoundingMode) -> Self;
+
+    /// Returns the cube root, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cbrt][FixedI32::unwrapped_cbrt]</code> and
+    /// <code>FixedU32::[unwrapped\_cbrt][FixedU32::unwrapped_cbrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_cbrt(self) -> Self;
+
+    /// Returns the <i>n</i>th root, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_nth\_root][FixedI32::unwrapped_nth_root]</code> and
+    /// <code>FixedU32::[unwrapped\_nth\_root][FixedU32::unwrapped_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, if the number is negative and `n` is even, or if
+    /// the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_nth_root(self, n: u32) -> Self;
+
+    /// Returns the reciprocal square root, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_rsqrt][FixedI32::unwrapped_rsqrt]</code> and
+    /// <code>FixedU32::[unwrapped\_rsqrt][FixedU32::unwrapped_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative, or if the result does not
+    /// fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_rsqrt<const RET_FRAC: i32>(self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Linear interpolation between `start` and `end`, panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_lerp][FixedI32::unwrapped_lerp]</code> and
+    /// <code>FixedU32::[unwrapped\_lerp][FixedU32::unwrapped_lerp]</code>.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Inverse linear interpolation between `start` and `end`, panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `start`&nbsp;=&nbsp;`end` or when the results overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_inv\_lerp][FixedI32::unwrapped_inv_lerp]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_inv\_lerp][FixedU32::unwrapped_inv_lerp]</code>.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_inv_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Rounding to `dp` decimal places, panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_round\_dp][FixedI32::unwrapped_round_dp]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_round\_dp][FixedU32::unwrapped_round_dp]</code>.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_round_dp(self, dp: u32, mode: RoundingMode) -> Self;
+
+    /// Overflowing Euclidean division by an integer.
+    ///
+    /// Returns a [tuple] of the quotient and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_div\_euclid\_int][FixedI32::overflowing_div_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_div\_euclid\_int][FixedU32::overflowing_div_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
+
+    /// Overflowing remainder for Euclidean division by an integer.
+    ///
+    /// Returns a [tuple] of the remainder and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_rem\_euclid\_int][FixedI32::overflowing_rem_euclid_int]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_rem\_euclid\_int][FixedU32::overflowing_rem_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
+
+    /// Compute the square root.
+    ///
+    /// Returns a [tuple] of the square root and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sqrt][FixedI32::overflowing_sqrt]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_sqrt][FixedU32::overflowing_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    fn overflowing_sqrt(self) -> (Self, bool);
+
+    /// Compute the square root, rounded according to the given [`RoundingMode`].
+    ///
+    /// Returns a [tuple] of the square root and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sqrt\_round][FixedI32::overflowing_sqrt_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_sqrt\_round][FixedU32::overflowing_sqrt_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    fn overflowing_sqrt_round(self, mode: RoundingMode) -> (Self, bool);
+
+    /// Returns the cube root.
+    ///
+    /// Returns a [tuple] of the cube root and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_cbrt][FixedI32::overflowing_cbrt]</code> and
+    /// <code>FixedU32::[overflowing\_cbrt][FixedU32::overflowing_cbrt]</code>.
+    #[must_use]
+    fn overflowing_cbrt(self) -> (Self, bool);
+
+    /// Returns the <i>n</i>th root.
+    ///
+    /// Returns a [tuple] of the <i>n</i>th root and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_nth\_root][FixedI32::overflowing_nth_root]</code> and
+    /// <code>FixedU32::[overflowing\_nth\_root][FixedU32::overflowing_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if the number is negative and `n` is even.
+    #[track_caller]
+    #[must_use]
+    fn overflowing_nth_root(self, n: u32) -> (Self, bool);
+
+    /// Returns the reciprocal square root.
+    ///
+    /// Returns a [tuple] of the reciprocal square root and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_rsqrt][FixedI32::overflowing_rsqrt]</code> and
+    /// <code>FixedU32::[overflowing\_rsqrt][FixedU32::overflowing_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    #[track_caller]
+    #[must_use]
+    fn overflowing_rsqrt<const RET_FRAC: i32>(
+        self,
+    ) -> (<Self::Bits as FixedBits>::Fixed<RET_FRAC>, bool);
+
+    /// Overflowing linear interpolation between `start` and `end`.
+    ///
+    /// Returns a [tuple] of the interpolated value and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_lerp][FixedI32::overflowing_lerp]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_lerp][FixedU32::overflowing_lerp]</code>.
+    fn overflowing_lerp(self, start: Self, end: Self) -> (Self, bool);
+
+    /// Overflowing inverse linear interpolation between `start` and `end`.
+    ///
+    /// Returns a [tuple] of the computed value and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_inv\_lerp][FixedI32::overflowing_inv_lerp]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_inv\_lerp][FixedU32::overflowing_inv_lerp]</code>.
+    fn overflowing_inv_lerp(self, start: Self, end: Self) -> (Self, bool);
+
+    /// Overflowing rounding to `dp` decimal places.
+    ///
+    /// Returns a [tuple] of the rounded value and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_round\_dp][FixedI32::overflowing_round_dp]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_round\_dp][FixedU32::overflowing_round_dp]</code>.
+    fn overflowing_round_dp(self, dp: u32, mode: RoundingMode) -> (Self, bool);
+
+    /// Sine, with `self` in radians.
+    ///
+    /// See also <code>FixedI32::[sin][FixedI32::sin]</code> and
+    /// <code>FixedU32::[sin][FixedU32::sin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn sin(self) -> Self;
+
+    /// Checked sine, with `self` in radians. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sin][FixedI32::checked_sin]</code>
+    /// and
+    /// <code>FixedU32::[checked\_sin][FixedU32::checked_sin]</code>.
+    #[must_use]
+    fn checked_sin(self) -> Option<Self>;
+
+    /// Sine, with `self` in radians, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sin][FixedI32::saturating_sin]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_sin][FixedU32::saturating_sin]</code>.
+    #[must_use]
+    fn saturating_sin(self) -> Self;
+
+    /// Sine, with `self` in radians, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin][FixedI32::wrapping_sin]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_sin][FixedU32::wrapping_sin]</code>.
+    #[must_use]
+    fn wrapping_sin(self) -> Self;
+
+    /// Sine, with `self` in radians, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sin][FixedI32::unwrapped_sin]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_sin][FixedU32::unwrapped_sin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_sin(self) -> Self;
+
+    /// Overflowing sine, with `self` in radians.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sin][FixedI32::overflowing_sin]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_sin][FixedU32::overflowing_sin]</code>.
+    #[must_use]
+    fn overflowing_sin(self) -> (Self, bool);
+
+    /// Cosine, with `self` in radians.
+    ///
+    /// See also <code>FixedI32::[cos][FixedI32::cos]</code> and
+    /// <code>FixedU32::[cos][FixedU32::cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn cos(self) -> Self;
+
+    /// Checked cosine, with `self` in radians. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_cos][FixedI32::checked_cos]</code>
+    /// and
+    /// <code>FixedU32::[checked\_cos][FixedU32::checked_cos]</code>.
+    #[must_use]
+    fn checked_cos(self) -> Option<Self>;
+
+    /// Cosine, with `self` in radians, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cos][FixedI32::saturating_cos]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_cos][FixedU32::saturating_cos]</code>.
+    #[must_use]
+    fn saturating_cos(self) -> Self;
+
+    /// Cosine, with `self` in radians, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cos][FixedI32::wrapping_cos]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_cos][FixedU32::wrapping_cos]</code>.
+    #[must_use]
+    fn wrapping_cos(self) -> Self;
+
+    /// Cosine, with `self` in radians, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cos][FixedI32::unwrapped_cos]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_cos][FixedU32::unwrapped_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_cos(self) -> Self;
+
+    /// Overflowing cosine, with `self` in radians.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_cos][FixedI32::overflowing_cos]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_cos][FixedU32::overflowing_cos]</code>.
+    #[must_use]
+    fn overflowing_cos(self) -> (Self, bool);
+
+    /// Simultaneous sine and cosine, with `self` in radians.
+    ///
+    /// See also <code>FixedI32::[sin\_cos][FixedI32::sin_cos]</code> and
+    /// <code>FixedU32::[sin\_cos][FixedU32::sin_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Checked simultaneous sine and cosine, with `self` in radians. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sin\_cos][FixedI32::checked_sin_cos]</code>
+    /// and
+    /// <code>FixedU32::[checked\_sin\_cos][FixedU32::checked_sin_cos]</code>.
+    #[must_use]
+    fn checked_sin_cos(self) -> Option<(Self, Self)>;
+
+    /// Simultaneous sine and cosine, with `self` in radians, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sin\_cos][FixedI32::saturating_sin_cos]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_sin\_cos][FixedU32::saturating_sin_cos]</code>.
+    #[must_use]
+    fn saturating_sin_cos(self) -> (Self, Self);
+
+    /// Simultaneous sine and cosine, with `self` in radians, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin\_cos][FixedI32::wrapping_sin_cos]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_sin\_cos][FixedU32::wrapping_sin_cos]</code>.
+    #[must_use]
+    fn wrapping_sin_cos(self) -> (Self, Self);
+
+    /// Simultaneous sine and cosine, with `self` in radians, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sin\_cos][FixedI32::unwrapped_sin_cos]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_sin\_cos][FixedU32::unwrapped_sin_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_sin_cos(self) -> (Self, Self);
+
+    /// Overflowing simultaneous sine and cosine, with `self` in radians.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sin\_cos][FixedI32::overflowing_sin_cos]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_sin\_cos][FixedU32::overflowing_sin_cos]</code>.
+    #[must_use]
+    fn overflowing_sin_cos(self) -> ((Self, Self), bool);
+
+    /// Tangent, with `self` in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[tan][FixedI32::tan]</code> and
+    /// <code>FixedU32::[tan][FixedU32::tan]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn tan(self) -> Self;
+
+    /// Checked tangent, with `self` in radians. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_tan][FixedI32::checked_tan]</code> and
+    /// <code>FixedU32::[checked\_tan][FixedU32::checked_tan]</code>.
+    #[must_use]
+    fn checked_tan(self) -> Option<Self>;
+
+    /// Tangent, with `self` in radians, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_tan][FixedI32::saturating_tan]</code> and
+    /// <code>FixedU32::[saturating\_tan][FixedU32::saturating_tan]</code>.
+    #[must_use]
+    fn saturating_tan(self) -> Self;
+
+    /// Tangent, with `self` in radians, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_tan][FixedI32::wrapping_tan]</code> and
+    /// <code>FixedU32::[wrapping\_tan][FixedU32::wrapping_tan]</code>.
+    #[must_use]
+    fn wrapping_tan(self) -> Self;
+
+    /// Tangent, with `self` in radians, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_tan][FixedI32::unwrapped_tan]</code> and
+    /// <code>FixedU32::[unwrapped\_tan][FixedU32::unwrapped_tan]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_tan(self) -> Self;
+
+    /// Overflowing tangent, with `self` in radians.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_tan][FixedI32::overflowing_tan]</code> and
+    /// <code>FixedU32::[overflowing\_tan][FixedU32::overflowing_tan]</code>.
+    #[must_use]
+    fn overflowing_tan(self) -> (Self, bool);
+
+    /// Arctangent, returning the angle in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[atan][FixedI32::atan]</code> and
+    /// <code>FixedU32::[atan][FixedU32::atan]</code>.
+    #[must_use]
+    fn atan(self) -> Self;
+
+    /// Arcsine, returning the angle in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[asin][FixedI32::asin]</code> and
+    /// <code>FixedU32::[asin][FixedU32::asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if `self` is
+    /// outside the domain or if the result overflows.
+    #[track_caller]
+    #[must_use]
+    fn asin(self) -> Self;
+
+    /// Checked arcsine, returning the angle in radians. Returns [`None`] if
+    /// `self` is outside the domain or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_asin][FixedI32::checked_asin]</code> and
+    /// <code>FixedU32::[checked\_asin][FixedU32::checked_asin]</code>.
+    #[must_use]
+    fn checked_asin(self) -> Option<Self>;
+
+    /// Arcsine, returning the angle in radians, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_asin][FixedI32::saturating_asin]</code> and
+    /// <code>FixedU32::[saturating\_asin][FixedU32::saturating_asin]</code>.
+    #[must_use]
+    fn saturating_asin(self) -> Self;
+
+    /// Arcsine, returning the angle in radians, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_asin][FixedI32::wrapping_asin]</code> and
+    /// <code>FixedU32::[wrapping\_asin][FixedU32::wrapping_asin]</code>.
+    #[must_use]
+    fn wrapping_asin(self) -> Self;
+
+    /// Arcsine, returning the angle in radians, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_asin][FixedI32::unwrapped_asin]</code> and
+    /// <code>FixedU32::[unwrapped\_asin][FixedU32::unwrapped_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the domain or if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_asin(self) -> Self;
+
+    /// Overflowing arcsine, returning the angle in radians.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether
+    /// `self` is outside the domain or an overflow has occurred. On overflow,
+    /// the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_asin][FixedI32::overflowing_asin]</code> and
+    /// <code>FixedU32::[overflowing\_asin][FixedU32::overflowing_asin]</code>.
+    #[must_use]
+    fn overflowing_asin(self) -> (Self, bool);
+
+    /// Arccosine, returning the angle in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[acos][FixedI32::acos]</code> and
+    /// <code>FixedU32::[acos][FixedU32::acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if `self` is
+    /// outside the domain or if the result overflows.
+    #[track_caller]
+    #[must_use]
+    fn acos(self) -> Self;
+
+    /// Checked arccosine, returning the angle in radians. Returns [`None`] if
+    /// `self` is outside the domain or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_acos][FixedI32::checked_acos]</code> and
+    /// <code>FixedU32::[checked\_acos][FixedU32::checked_acos]</code>.
+    #[must_use]
+    fn checked_acos(self) -> Option<Self>;
+
+    /// Arccosine, returning the angle in radians, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_acos][FixedI32::saturating_acos]</code> and
+    /// <code>FixedU32::[saturating\_acos][FixedU32::saturating_acos]</code>.
+    #[must_use]
+    fn saturating_acos(self) -> Self;
+
+    /// Arccosine, returning the angle in radians, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_acos][FixedI32::wrapping_acos]</code> and
+    /// <code>FixedU32::[wrapping\_acos][FixedU32::wrapping_acos]</code>.
+    #[must_use]
+    fn wrapping_acos(self) -> Self;
+
+    /// Arccosine, returning the angle in radians, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_acos][FixedI32::unwrapped_acos]</code> and
+    /// <code>FixedU32::[unwrapped\_acos][FixedU32::unwrapped_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the domain or if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_acos(self) -> Self;
+
+    /// Overflowing arccosine, returning the angle in radians.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether
+    /// `self` is outside the domain or an overflow has occurred. On overflow,
+    /// the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_acos][FixedI32::overflowing_acos]</code> and
+    /// <code>FixedU32::[overflowing\_acos][FixedU32::overflowing_acos]</code>.
+    #[must_use]
+    fn overflowing_acos(self) -> (Self, bool);
+
+    /// Exponential, <i>e</i><sup>`self`</sup>.
+    ///
+    /// See also
+    /// <code>FixedI32::[exp][FixedI32::exp]</code> and
+    /// <code>FixedU32::[exp][FixedU32::exp]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn exp(self) -> Self;
+
+    /// Checked exponential. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp][FixedI32::checked_exp]</code> and
+    /// <code>FixedU32::[checked\_exp][FixedU32::checked_exp]</code>.
+    #[must_use]
+    fn checked_exp(self) -> Option<Self>;
+
+    /// Exponential, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp][FixedI32::saturating_exp]</code> and
+    /// <code>FixedU32::[saturating\_exp][FixedU32::saturating_exp]</code>.
+    #[must_use]
+    fn saturating_exp(self) -> Self;
+
+    /// Exponential, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp][FixedI32::wrapping_exp]</code> and
+    /// <code>FixedU32::[wrapping\_exp][FixedU32::wrapping_exp]</code>.
+    #[must_use]
+    fn wrapping_exp(self) -> Self;
+
+    /// Exponential, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp][FixedI32::unwrapped_exp]</code> and
+    /// <code>FixedU32::[unwrapped\_exp][FixedU32::unwrapped_exp]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_exp(self) -> Self;
+
+    /// Overflowing exponential.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_exp][FixedI32::overflowing_exp]</code> and
+    /// <code>FixedU32::[overflowing\_exp][FixedU32::overflowing_exp]</code>.
+    #[must_use]
+    fn overflowing_exp(self) -> (Self, bool);
+
+    /// Base-2 exponential, 2<sup>`self`</sup>.
+    ///
+    /// See also
+    /// <code>FixedI32::[exp2][FixedI32::exp2]</code> and
+    /// <code>FixedU32::[exp2][FixedU32::exp2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn exp2(self) -> Self;
+
+    /// Checked base-2 exponential. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp2][FixedI32::checked_exp2]</code> and
+    /// <code>FixedU32::[checked\_exp2][FixedU32::checked_exp2]</code>.
+    #[must_use]
+    fn checked_exp2(self) -> Option<Self>;
+
+    /// Base-2 exponential, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp2][FixedI32::saturating_exp2]</code> and
+    /// <code>FixedU32::[saturating\_exp2][FixedU32::saturating_exp2]</code>.
+    #[must_use]
+    fn saturating_exp2(self) -> Self;
+
+    /// Base-2 exponential, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp2][FixedI32::wrapping_exp2]</code> and
+    /// <code>FixedU32::[wrapping\_exp2][FixedU32::wrapping_exp2]</code>.
+    #[must_use]
+    fn wrapping_exp2(self) -> Self;
+
+    /// Base-2 exponential, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp2][FixedI32::unwrapped_exp2]</code> and
+    /// <code>FixedU32::[unwrapped\_exp2][FixedU32::unwrapped_exp2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_exp2(self) -> Self;
+
+    /// Overflowing base-2 exponential.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_exp2][FixedI32::overflowing_exp2]</code> and
+    /// <code>FixedU32::[overflowing\_exp2][FixedU32::overflowing_exp2]</code>.
+    #[must_use]
+    fn overflowing_exp2(self) -> (Self, bool);
+
+    /// Exponential minus one, <i>e</i><sup>`self`</sup>&nbsp;&minus;&nbsp;1.
+    ///
+    /// See also
+    /// <code>FixedI32::[exp\_m1][FixedI32::exp_m1]</code> and
+    /// <code>FixedU32::[exp\_m1][FixedU32::exp_m1]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn exp_m1(self) -> Self;
+
+    /// Checked exponential minus one. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp\_m1][FixedI32::checked_exp_m1]</code> and
+    /// <code>FixedU32::[checked\_exp\_m1][FixedU32::checked_exp_m1]</code>.
+    #[must_use]
+    fn checked_exp_m1(self) -> Option<Self>;
+
+    /// Exponential minus one, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp\_m1][FixedI32::saturating_exp_m1]</code> and
+    /// <code>FixedU32::[saturating\_exp\_m1][FixedU32::saturating_exp_m1]</code>.
+    #[must_use]
+    fn saturating_exp_m1(self) -> Self;
+
+    /// Exponential minus one, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp\_m1][FixedI32::wrapping_exp_m1]</code> and
+    /// <code>FixedU32::[wrapping\_exp\_m1][FixedU32::wrapping_exp_m1]</code>.
+    #[must_use]
+    fn wrapping_exp_m1(self) -> Self;
+
+    /// Exponential minus one, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp\_m1][FixedI32::unwrapped_exp_m1]</code> and
+    /// <code>FixedU32::[unwrapped\_exp\_m1][FixedU32::unwrapped_exp_m1]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_exp_m1(self) -> Self;
+
+    /// Overflowing exponential minus one.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_exp\_m1][FixedI32::overflowing_exp_m1]</code> and
+    /// <code>FixedU32::[overflowing\_exp\_m1][FixedU32::overflowing_exp_m1]</code>.
+    #[must_use]
+    fn overflowing_exp_m1(self) -> (Self, bool);
+
+    /// Natural logarithm, returned with `RET_FRAC` fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[ln][FixedI32::ln]</code> and
+    /// <code>FixedU32::[ln][FixedU32::ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows.
+    #[track_caller]
+    #[must_use]
+    fn ln<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Checked natural logarithm, returned with `RET_FRAC` fractional bits.
+    /// Returns [`None`] if `self` is not positive or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_ln][FixedI32::checked_ln]</code> and
+    /// <code>FixedU32::[checked\_ln][FixedU32::checked_ln]</code>.
+    #[must_use]
+    fn checked_ln<const RET_FRAC: i32>(
+        self,
+    ) -> Option<<<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>>;
+
+    /// Natural logarithm, returned with `RET_FRAC` fractional bits, saturating
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_ln][FixedI32::saturating_ln]</code> and
+    /// <code>FixedU32::[saturating\_ln][FixedU32::saturating_ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn saturating_ln<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Natural logarithm, returned with `RET_FRAC` fractional bits, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_ln][FixedI32::wrapping_ln]</code> and
+    /// <code>FixedU32::[wrapping\_ln][FixedU32::wrapping_ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_ln<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Natural logarithm, returned with `RET_FRAC` fractional bits, panicking on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_ln][FixedI32::unwrapped_ln]</code> and
+    /// <code>FixedU32::[unwrapped\_ln][FixedU32::unwrapped_ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_ln<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Overflowing natural logarithm, returned with `RET_FRAC` fractional bits.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_ln][FixedI32::overflowing_ln]</code> and
+    /// <code>FixedU32::[overflowing\_ln][FixedU32::overflowing_ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn overflowing_ln<const RET_FRAC: i32>(
+        self,
+    ) -> (
+        <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>,
+        bool,
+    );
+
+    /// Base-2 logarithm, returned with `RET_FRAC` fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[log2][FixedI32::log2]</code> and
+    /// <code>FixedU32::[log2][FixedU32::log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows.
+    #[track_caller]
+    #[must_use]
+    fn log2<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Checked base-2 logarithm, returned with `RET_FRAC` fractional bits.
+    /// Returns [`None`] if `self` is not positive or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log2][FixedI32::checked_log2]</code> and
+    /// <code>FixedU32::[checked\_log2][FixedU32::checked_log2]</code>.
+    #[must_use]
+    fn checked_log2<const RET_FRAC: i32>(
+        self,
+    ) -> Option<<<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>>;
+
+    /// Base-2 logarithm, returned with `RET_FRAC` fractional bits, saturating
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_log2][FixedI32::saturating_log2]</code> and
+    /// <code>FixedU32::[saturating\_log2][FixedU32::saturating_log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn saturating_log2<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Base-2 logarithm, returned with `RET_FRAC` fractional bits, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_log2][FixedI32::wrapping_log2]</code> and
+    /// <code>FixedU32::[wrapping\_log2][FixedU32::wrapping_log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_log2<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Base-2 logarithm, returned with `RET_FRAC` fractional bits, panicking on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_log2][FixedI32::unwrapped_log2]</code> and
+    /// <code>FixedU32::[unwrapped\_log2][FixedU32::unwrapped_log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_log2<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Overflowing base-2 logarithm, returned with `RET_FRAC` fractional bits.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_log2][FixedI32::overflowing_log2]</code> and
+    /// <code>FixedU32::[overflowing\_log2][FixedU32::overflowing_log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn overflowing_log2<const RET_FRAC: i32>(
+        self,
+    ) -> (
+        <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>,
+        bool,
+    );
+
+    /// Base-10 logarithm, returned with `RET_FRAC` fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[log10][FixedI32::log10]</code> and
+    /// <code>FixedU32::[log10][FixedU32::log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows.
+    #[track_caller]
+    #[must_use]
+    fn log10<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Checked base-10 logarithm, returned with `RET_FRAC` fractional bits.
+    /// Returns [`None`] if `self` is not positive or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log10][FixedI32::checked_log10]</code> and
+    /// <code>FixedU32::[checked\_log10][FixedU32::checked_log10]</code>.
+    #[must_use]
+    fn checked_log10<const RET_FRAC: i32>(
+        self,
+    ) -> Option<<<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>>;
+
+    /// Base-10 logarithm, returned with `RET_FRAC` fractional bits, saturating
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_log10][FixedI32::saturating_log10]</code> and
+    /// <code>FixedU32::[saturating\_log10][FixedU32::saturating_log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn saturating_log10<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Base-10 logarithm, returned with `RET_FRAC` fractional bits, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_log10][FixedI32::wrapping_log10]</code> and
+    /// <code>FixedU32::[wrapping\_log10][FixedU32::wrapping_log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_log10<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Base-10 logarithm, returned with `RET_FRAC` fractional bits, panicking on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_log10][FixedI32::unwrapped_log10]</code> and
+    /// <code>FixedU32::[unwrapped\_log10][FixedU32::unwrapped_log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_log10<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
+
+    /// Overflowing base-10 logarithm, returned with `RET_FRAC` fractional bits.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_log10][FixedI32::overflowing_log10]</code> and
+    /// <code>FixedU32::[overflowing\_log10][FixedU32::overflowing_log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn overflowing_log10<const RET_FRAC: i32>(
+        self,
+    ) -> (
+        <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>,
+        bool,
+    );
+
+    /// Raises `self` to the integer power `exp`.
+    ///
+    /// See also
+    /// <code>FixedI32::[powi][FixedI32::powi]</code> and
+    /// <code>FixedU32::[powi][FixedU32::powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn powi(self, exp: i32) -> Self;
+
+    /// Checked integer power. Returns [`None`] on overflow, or if `self` is
+    /// zero and `exp` is negative.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_powi][FixedI32::checked_powi]</code> and
+    /// <code>FixedU32::[checked\_powi][FixedU32::checked_powi]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_powi(self, exp: i32) -> Option<Self>;
+
+    /// Saturating integer power, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_powi][FixedI32::saturating_powi]</code> and
+    /// <code>FixedU32::[saturating\_powi][FixedU32::saturating_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_powi(self, exp: i32) -> Self;
+
+    /// Wrapping integer power, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powi][FixedI32::wrapping_powi]</code> and
+    /// <code>FixedU32::[wrapping\_powi][FixedU32::wrapping_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_powi(self, exp: i32) -> Self;
+
+    /// Unwrapped integer power, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powi][FixedI32::unwrapped_powi]</code> and
+    /// <code>FixedU32::[unwrapped\_powi][FixedU32::unwrapped_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit, or if `self` is zero and `exp` is
+    /// negative.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_powi(self, exp: i32) -> Self;
+
+    /// Overflowing integer power.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_powi][FixedI32::overflowing_powi]</code> and
+    /// <code>FixedU32::[overflowing\_powi][FixedU32::overflowing_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_powi(self, exp: i32) -> (Self, bool);
+
+    /// Raises `self` to the fixed-point power `exp`.
+    ///
+    /// For a negative base, the result is only defined if `exp` is an integer;
+    /// other cases are domain errors.
+    ///
+    /// See also
+    /// <code>FixedI32::[powf][FixedI32::powf]</code> and
+    /// <code>FixedU32::[powf][FixedU32::powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows or on a domain error.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn powf(self, exp: Self) -> Self;
+
+    /// Checked fixed-point power. Returns [`None`] on overflow, on a domain
+    /// error, or if `self` is zero and `exp` is negative.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_powf][FixedI32::checked_powf]</code> and
+    /// <code>FixedU32::[checked\_powf][FixedU32::checked_powf]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_powf(self, exp: Self) -> Option<Self>;
+
+    /// Saturating fixed-point power, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_powf][FixedI32::saturating_powf]</code> and
+    /// <code>FixedU32::[saturating\_powf][FixedU32::saturating_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_powf(self, exp: Self) -> Self;
+
+    /// Wrapping fixed-point power, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powf][FixedI32::wrapping_powf]</code> and
+    /// <code>FixedU32::[wrapping\_powf][FixedU32::wrapping_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_powf(self, exp: Self) -> Self;
+
+    /// Unwrapped fixed-point power, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powf][FixedI32::unwrapped_powf]</code> and
+    /// <code>FixedU32::[unwrapped\_powf][FixedU32::unwrapped_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit, on a domain error, or if `self` is
+    /// zero and `exp` is negative.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_powf(self, exp: Self) -> Self;
+
+    /// Overflowing fixed-point power.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow or a domain error has occurred. On overflow, the wrapped value
+    /// is returned, and on a domain error, zero is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_powf][FixedI32::overflowing_powf]</code> and
+    /// <code>FixedU32::[overflowing\_powf][FixedU32::overflowing_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_powf(self, exp: Self) -> (Self, bool);
+
+    /// Hyperbolic sine.
+    ///
+    /// See also
+    /// <code>FixedI32::[sinh][FixedI32::sinh]</code> and
+    /// <code>FixedU32::[sinh][FixedU32::sinh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn sinh(self) -> Self;
+
+    /// Checked hyperbolic sine. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sinh][FixedI32::checked_sinh]</code> and
+    /// <code>FixedU32::[checked\_sinh][FixedU32::checked_sinh]</code>.
+    #[must_use]
+    fn checked_sinh(self) -> Option<Self>;
+
+    /// Hyperbolic sine, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sinh][FixedI32::saturating_sinh]</code> and
+    /// <code>FixedU32::[saturating\_sinh][FixedU32::saturating_sinh]</code>.
+    #[must_use]
+    fn saturating_sinh(self) -> Self;
+
+    /// Hyperbolic sine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sinh][FixedI32::wrapping_sinh]</code> and
+    /// <code>FixedU32::[wrapping\_sinh][FixedU32::wrapping_sinh]</code>.
+    #[must_use]
+    fn wrapping_sinh(self) -> Self;
+
+    /// Hyperbolic sine, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sinh][FixedI32::unwrapped_sinh]</code> and
+    /// <code>FixedU32::[unwrapped\_sinh][FixedU32::unwrapped_sinh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_sinh(self) -> Self;
+
+    /// Overflowing hyperbolic sine.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sinh][FixedI32::overflowing_sinh]</code> and
+    /// <code>FixedU32::[overflowing\_sinh][FixedU32::overflowing_sinh]</code>.
+    #[must_use]
+    fn overflowing_sinh(self) -> (Self, bool);
+
+    /// Hyperbolic cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[cosh][FixedI32::cosh]</code> and
+    /// <code>FixedU32::[cosh][FixedU32::cosh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn cosh(self) -> Self;
+
+    /// Checked hyperbolic cosine. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_cosh][FixedI32::checked_cosh]</code> and
+    /// <code>FixedU32::[checked\_cosh][FixedU32::checked_cosh]</code>.
+    #[must_use]
+    fn checked_cosh(self) -> Option<Self>;
+
+    /// Hyperbolic cosine, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cosh][FixedI32::saturating_cosh]</code> and
+    /// <code>FixedU32::[saturating\_cosh][FixedU32::saturating_cosh]</code>.
+    #[must_use]
+    fn saturating_cosh(self) -> Self;
+
+    /// Hyperbolic cosine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cosh][FixedI32::wrapping_cosh]</code> and
+    /// <code>FixedU32::[wrapping\_cosh][FixedU32::wrapping_cosh]</code>.
+    #[must_use]
+    fn wrapping_cosh(self) -> Self;
+
+    /// Hyperbolic cosine, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cosh][FixedI32::unwrapped_cosh]</code> and
+    /// <code>FixedU32::[unwrapped\_cosh][FixedU32::unwrapped_cosh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_cosh(self) -> Self;
+
+    /// Overflowing hyperbolic cosine.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_cosh][FixedI32::overflowing_cosh]</code> and
+    /// <code>FixedU32::[overflowing\_cosh][FixedU32::overflowing_cosh]</code>.
+    #[must_use]
+    fn overflowing_cosh(self) -> (Self, bool);
+
+    /// Hyperbolic tangent.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[tanh][FixedI32::tanh]</code> and
+    /// <code>FixedU32::[tanh][FixedU32::tanh]</code>.
+    #[must_use]
+    fn tanh(self) -> Self;
+
+    /// Inverse hyperbolic sine.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[asinh][FixedI32::asinh]</code> and
+    /// <code>FixedU32::[asinh][FixedU32::asinh]</code>.
+    #[must_use]
+    fn asinh(self) -> Self;
+
+    /// Inverse hyperbolic cosine.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[acosh][FixedI32::acosh]</code> and
+    /// <code>FixedU32::[acosh][FixedU32::acosh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is less than one.
+    #[track_caller]
+    #[must_use]
+    fn acosh(self) -> Self;
+
+    /// Checked inverse hyperbolic cosine. Returns [`None`] if `self` is less
+    /// than one.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_acosh][FixedI32::checked_acosh]</code> and
+    /// <code>FixedU32::[checked\_acosh][FixedU32::checked_acosh]</code>.
+    #[must_use]
+    fn checked_acosh(self) -> Option<Self>;
+
+    /// Inverse hyperbolic tangent.
+    ///
+    /// See also
+    /// <code>FixedI32::[atanh][FixedI32::atanh]</code> and
+    /// <code>FixedU32::[atanh][FixedU32::atanh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the domain
+    /// &minus;1&nbsp;&lt;&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows.
+    #[track_caller]
+    #[must_use]
+    fn atanh(self) -> Self;
+
+    /// Checked inverse hyperbolic tangent. Returns [`None`] if `self` is
+    /// outside the domain &minus;1&nbsp;&lt;&nbsp;<i>x</i>&nbsp;&lt;&nbsp;1 or
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_atanh][FixedI32::checked_atanh]</code> and
+    /// <code>FixedU32::[checked\_atanh][FixedU32::checked_atanh]</code>.
+    #[must_use]
+    fn checked_atanh(self) -> Option<Self>;
+
+    /// Inverse hyperbolic tangent, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_atanh][FixedI32::saturating_atanh]</code> and
+    /// <code>FixedU32::[saturating\_atanh][FixedU32::saturating_atanh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the domain.
+    #[track_caller]
+    #[must_use]
+    fn saturating_atanh(self) -> Self;
+
+    /// Inverse hyperbolic tangent, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_atanh][FixedI32::wrapping_atanh]</code> and
+    /// <code>FixedU32::[wrapping\_atanh][FixedU32::wrapping_atanh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the domain.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_atanh(self) -> Self;
+
+    /// Inverse hyperbolic tangent, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_atanh][FixedI32::unwrapped_atanh]</code> and
+    /// <code>FixedU32::[unwrapped\_atanh][FixedU32::unwrapped_atanh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the domain or if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_atanh(self) -> Self;
+
+    /// Overflowing inverse hyperbolic tangent.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_atanh][FixedI32::overflowing_atanh]</code> and
+    /// <code>FixedU32::[overflowing\_atanh][FixedU32::overflowing_atanh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the domain.
+    #[track_caller]
+    #[must_use]
+    fn overflowing_atanh(self) -> (Self, bool);
+
+    /// Error function.
+    ///
+    /// See also
+    /// <code>FixedI32::[erf][FixedI32::erf]</code> and
+    /// <code>FixedU32::[erf][FixedU32::erf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn erf(self) -> Self;
+
+    /// Checked error function. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_erf][FixedI32::checked_erf]</code> and
+    /// <code>FixedU32::[checked\_erf][FixedU32::checked_erf]</code>.
+    #[must_use]
+    fn checked_erf(self) -> Option<Self>;
+
+    /// Error function, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_erf][FixedI32::saturating_erf]</code> and
+    /// <code>FixedU32::[saturating\_erf][FixedU32::saturating_erf]</code>.
+    #[must_use]
+    fn saturating_erf(self) -> Self;
+
+    /// Error function, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_erf][FixedI32::wrapping_erf]</code> and
+    /// <code>FixedU32::[wrapping\_erf][FixedU32::wrapping_erf]</code>.
+    #[must_use]
+    fn wrapping_erf(self) -> Self;
+
+    /// Error function, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_erf][FixedI32::unwrapped_erf]</code> and
+    /// <code>FixedU32::[unwrapped\_erf][FixedU32::unwrapped_erf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_erf(self) -> Self;
+
+    /// Overflowing error function.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_erf][FixedI32::overflowing_erf]</code> and
+    /// <code>FixedU32::[overflowing\_erf][FixedU32::overflowing_erf]</code>.
+    #[must_use]
+    fn overflowing_erf(self) -> (Self, bool);
+
+    /// Complementary error function.
+    ///
+    /// See also
+    /// <code>FixedI32::[erfc][FixedI32::erfc]</code> and
+    /// <code>FixedU32::[erfc][FixedU32::erfc]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use]
+    fn erfc(self) -> Self;
+
+    /// Checked complementary error function. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_erfc][FixedI32::checked_erfc]</code> and
+    /// <code>FixedU32::[checked\_erfc][FixedU32::checked_erfc]</code>.
+    #[must_use]
+    fn checked_erfc(self) -> Option<Self>;
+
+    /// Complementary error function, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_erfc][FixedI32::saturating_erfc]</code> and
+    /// <code>FixedU32::[saturating\_erfc][FixedU32::saturating_erfc]</code>.
+    #[must_use]
+    fn saturating_erfc(self) -> Self;
+
+    /// Complementary error function, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_erfc][FixedI32::wrapping_erfc]</code> and
+    /// <code>FixedU32::[wrapping\_erfc][FixedU32::wrapping_erfc]</code>.
+    #[must_use]
+    fn wrapping_erfc(self) -> Self;
 
-    /// Returns the square root, wrapping on overflow.
+    /// Complementary error function, panicking on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_sqrt][FixedI32::wrapping_sqrt]</code> and
-    /// <code>FixedU32::[wrapping\_sqrt][FixedU32::wrapping_sqrt]</code>.
+    /// <code>FixedI32::[unwrapped\_erfc][FixedI32::unwrapped_erfc]</code> and
+    /// <code>FixedU32::[unwrapped\_erfc][FixedU32::unwrapped_erfc]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the number is negative.
-    fn wrapping_sqrt(self) -> Self;
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_erfc(self) -> Self;
 
-    /// Linear interpolation between `start` and `end`, wrapping on overflow.
+    /// Overflowing complementary error function.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_lerp][FixedI32::wrapping_lerp]</code> and
-    /// <code>FixedU32::[wrapping\_lerp][FixedU32::wrapping_lerp]</code>.
+    /// <code>FixedI32::[overflowing\_erfc][FixedI32::overflowing_erfc]</code> and
+    /// <code>FixedU32::[overflowing\_erfc][FixedU32::overflowing_erfc]</code>.
     #[must_use]
-    fn wrapping_lerp(self, start: Self, end: Self) -> Self;
+    fn overflowing_erfc(self) -> (Self, bool);
 
-    /// Inverse linear interpolation between `start` and `end`, wrapping on
-    /// overflow.
+    /// Logistic sigmoid, returned with `RET_FRAC` fractional bits.
+    ///
+    /// The result is unsigned, and saturates if it does not fit.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_inv\_lerp][FixedI32::wrapping_inv_lerp]</code>
-    /// and
-    /// <code>FixedU32::[wrapping\_inv\_lerp][FixedU32::wrapping_inv_lerp]</code>.
+    /// <code>FixedI32::[sigmoid][FixedI32::sigmoid]</code> and
+    /// <code>FixedU32::[sigmoid][FixedU32::sigmoid]</code>.
     #[must_use]
-    fn wrapping_inv_lerp(self, start: Self, end: Self) -> Self;
+    fn sigmoid<const RET_FRAC: i32>(
+        self,
+    ) -> <<Self::Unsigned as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>;
 
-    /// Unwrapped division. Returns the quotient, panicking on overflow.
+    /// Softplus, saturating on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_div][FixedI32::unwrapped_div]</code> and
-    /// <code>FixedU32::[unwrapped\_div][FixedU32::unwrapped_div]</code>.
+    /// <code>FixedI32::[softplus][FixedI32::softplus]</code> and
+    /// <code>FixedU32::[softplus][FixedU32::softplus]</code>.
+    #[must_use]
+    fn softplus(self) -> Self;
+
+    /// Gaussian error linear unit (GELU).
     ///
-    /// # Panics
+    /// This method never overflows.
     ///
-    /// Panics if the divisor is zero or if the result does not fit.
-    #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_div(self, rhs: Self) -> Self;
+    /// See also
+    /// <code>FixedI32::[gelu][FixedI32::gelu]</code> and
+    /// <code>FixedU32::[gelu][FixedU32::gelu]</code>.
+    #[must_use]
+    fn gelu(self) -> Self;
 
-    /// Unwrapped reciprocal. Returns reciprocal, panicking on overflow.
+    /// Replaces the values in a slice with their softmax.
+    ///
+    /// Results that do not fit saturate.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_recip][FixedI32::unwrapped_recip]</code> and
-    /// <code>FixedU32::[unwrapped\_recip][FixedU32::unwrapped_recip]</code>.
+    /// <code>FixedI32::[softmax][FixedI32::softmax]</code> and
+    /// <code>FixedU32::[softmax][FixedU32::softmax]</code>.
+    fn softmax(values: &mut [Self]);
+
+    /// Sine using a lookup table with `N` entries, with `self` in radians.
+    ///
+    /// See also <code>FixedI32::[sin\_lut][FixedI32::sin_lut]</code> and
+    /// <code>FixedU32::[sin\_lut][FixedU32::sin_lut]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if `self` is zero or on overflow.
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
     #[track_caller]
     #[must_use]
-    fn unwrapped_recip(self) -> Self;
+    fn sin_lut<const N: usize>(self) -> Self;
 
-    /// Unwrapped Euclidean division. Returns the quotient, panicking on overflow.
+    /// Checked sine using a lookup table, with `self` in radians. Returns
+    /// [`None`] on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_div\_euclid][FixedI32::unwrapped_div_euclid]</code>
+    /// <code>FixedI32::[checked\_sin\_lut][FixedI32::checked_sin_lut]</code>
     /// and
-    /// <code>FixedU32::[unwrapped\_div\_euclid][FixedU32::unwrapped_div_euclid]</code>.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the divisor is zero or if the result does not fit.
-    #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_div_euclid(self, rhs: Self) -> Self;
+    /// <code>FixedU32::[checked\_sin\_lut][FixedU32::checked_sin_lut]</code>.
+    #[must_use]
+    fn checked_sin_lut<const N: usize>(self) -> Option<Self>;
 
-    /// Unwrapped remainder for division by an integer. Returns the
-    /// remainder, panicking if the divisor is zero.
+    /// Sine using a lookup table, with `self` in radians, saturating on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_rem\_int][FixedI32::unwrapped_rem_int]</code>
+    /// <code>FixedI32::[saturating\_sin\_lut][FixedI32::saturating_sin_lut]</code>
     /// and
-    /// <code>FixedU32::[unwrapped\_rem\_int][FixedU32::unwrapped_rem_int]</code>.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the divisor is zero.
-    #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_rem_int(self, rhs: Self::Bits) -> Self;
+    /// <code>FixedU32::[saturating\_sin\_lut][FixedU32::saturating_sin_lut]</code>.
+    #[must_use]
+    fn saturating_sin_lut<const N: usize>(self) -> Self;
 
-    /// Unwrapped Euclidean division by an integer. Returns the
-    /// quotient, panicking on overflow.
+    /// Sine using a lookup table, with `self` in radians, wrapping on overflow.
     ///
-    /// Overflow can only occur when dividing the minimum value by &minus;1.
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin\_lut][FixedI32::wrapping_sin_lut]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_sin\_lut][FixedU32::wrapping_sin_lut]</code>.
+    #[must_use]
+    fn wrapping_sin_lut<const N: usize>(self) -> Self;
+
+    /// Sine using a lookup table, with `self` in radians, panicking on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_div\_euclid\_int][FixedI32::unwrapped_div_euclid_int]</code>
+    /// <code>FixedI32::[unwrapped\_sin\_lut][FixedI32::unwrapped_sin_lut]</code>
     /// and
-    /// <code>FixedU32::[unwrapped\_div\_euclid\_int][FixedU32::unwrapped_div_euclid_int]</code>.
+    /// <code>FixedU32::[unwrapped\_sin\_lut][FixedU32::unwrapped_sin_lut]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the result does not fit.
+    /// Panics if the result does not fit.
     #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_div_euclid_int(self, rhs: Self::Bits) -> Self;
+    #[must_use]
+    fn unwrapped_sin_lut<const N: usize>(self) -> Self;
 
-    /// Unwrapped remainder for Euclidean division by an integer.
-    /// Returns the remainder, panicking on overflow.
+    /// Overflowing sine using a lookup table, with `self` in radians.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_rem\_euclid\_int][FixedI32::unwrapped_rem_euclid_int]</code>
+    /// <code>FixedI32::[overflowing\_sin\_lut][FixedI32::overflowing_sin_lut]</code>
     /// and
-    /// <code>FixedU32::[unwrapped\_rem\_euclid\_int][FixedU32::unwrapped_rem_euclid_int]</code>.
+    /// <code>FixedU32::[overflowing\_sin\_lut][FixedU32::overflowing_sin_lut]</code>.
+    #[must_use]
+    fn overflowing_sin_lut<const N: usize>(self) -> (Self, bool);
+
+    /// Cosine using a lookup table with `N` entries, with `self` in radians.
+    ///
+    /// See also <code>FixedI32::[cos\_lut][FixedI32::cos_lut]</code> and
+    /// <code>FixedU32::[cos\_lut][FixedU32::cos_lut]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero or if the result does not fit.
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
     #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn unwrapped_rem_euclid_int(self, rhs: Self::Bits) -> Self;
+    #[must_use]
+    fn cos_lut<const N: usize>(self) -> Self;
 
-    /// Returns the square root, panicking if the number is negative or on overflow.
+    /// Checked cosine using a lookup table, with `self` in radians. Returns
+    /// [`None`] on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_sqrt][FixedI32::unwrapped_sqrt]</code> and
-    /// <code>FixedU32::[unwrapped\_sqrt][FixedU32::unwrapped_sqrt]</code>.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the number is negative or on overflow.
-    fn unwrapped_sqrt(self) -> Self;
+    /// <code>FixedI32::[checked\_cos\_lut][FixedI32::checked_cos_lut]</code>
+    /// and
+    /// <code>FixedU32::[checked\_cos\_lut][FixedU32::checked_cos_lut]</code>.
+    #[must_use]
+    fn checked_cos_lut<const N: usize>(self) -> Option<Self>;
 
-    /// Linear interpolation between `start` and `end`, panicking on overflow.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the result does not fit.
+    /// Cosine using a lookup table, with `self` in radians, saturating on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_lerp][FixedI32::unwrapped_lerp]</code> and
-    /// <code>FixedU32::[unwrapped\_lerp][FixedU32::unwrapped_lerp]</code>.
-    #[track_caller]
+    /// <code>FixedI32::[saturating\_cos\_lut][FixedI32::saturating_cos_lut]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_cos\_lut][FixedU32::saturating_cos_lut]</code>.
     #[must_use]
-    fn unwrapped_lerp(self, start: Self, end: Self) -> Self;
+    fn saturating_cos_lut<const N: usize>(self) -> Self;
 
-    /// Inverse linear interpolation between `start` and `end`, panicking on overflow.
-    ///
-    /// # Panics
-    ///
-    /// Panics when `start`&nbsp;=&nbsp;`end` or when the results overflows.
+    /// Cosine using a lookup table, with `self` in radians, wrapping on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_inv\_lerp][FixedI32::unwrapped_inv_lerp]</code>
+    /// <code>FixedI32::[wrapping\_cos\_lut][FixedI32::wrapping_cos_lut]</code>
     /// and
-    /// <code>FixedU32::[unwrapped\_inv\_lerp][FixedU32::unwrapped_inv_lerp]</code>.
-    #[track_caller]
+    /// <code>FixedU32::[wrapping\_cos\_lut][FixedU32::wrapping_cos_lut]</code>.
     #[must_use]
-    fn unwrapped_inv_lerp(self, start: Self, end: Self) -> Self;
+    fn wrapping_cos_lut<const N: usize>(self) -> Self;
 
-    /// Overflowing Euclidean division by an integer.
-    ///
-    /// Returns a [tuple] of the quotient and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// Cosine using a lookup table, with `self` in radians, panicking on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_div\_euclid\_int][FixedI32::overflowing_div_euclid_int]</code>
+    /// <code>FixedI32::[unwrapped\_cos\_lut][FixedI32::unwrapped_cos_lut]</code>
     /// and
-    /// <code>FixedU32::[overflowing\_div\_euclid\_int][FixedU32::overflowing_div_euclid_int]</code>.
+    /// <code>FixedU32::[unwrapped\_cos\_lut][FixedU32::unwrapped_cos_lut]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
+    /// Panics if the result does not fit.
     #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
+    #[must_use]
+    fn unwrapped_cos_lut<const N: usize>(self) -> Self;
 
-    /// Overflowing remainder for Euclidean division by an integer.
+    /// Overflowing cosine using a lookup table, with `self` in radians.
     ///
-    /// Returns a [tuple] of the remainder and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped
-    /// value is returned.
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_rem\_euclid\_int][FixedI32::overflowing_rem_euclid_int]</code>
+    /// <code>FixedI32::[overflowing\_cos\_lut][FixedI32::overflowing_cos_lut]</code>
     /// and
-    /// <code>FixedU32::[overflowing\_rem\_euclid\_int][FixedU32::overflowing_rem_euclid_int]</code>.
+    /// <code>FixedU32::[overflowing\_cos\_lut][FixedU32::overflowing_cos_lut]</code>.
+    #[must_use]
+    fn overflowing_cos_lut<const N: usize>(self) -> (Self, bool);
+
+    /// Evaluates a polynomial at `self` using Horner’s method.
+    ///
+    /// The coefficients are in ascending order, that is `coeffs[i]` is the
+    /// coefficient of <i>x</i><sup><i>i</i></sup>. Intermediate values are
+    /// kept with 128 more fractional bits than `self` and are truncated to
+    /// that precision, and only the final result is rounded to the precision
+    /// of `self`.
+    ///
+    /// See also <code>FixedI32::[poly\_eval][FixedI32::poly_eval]</code> and
+    /// <code>FixedU32::[poly\_eval][FixedU32::poly_eval]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the divisor is zero.
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
     #[track_caller]
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
+    #[must_use]
+    fn poly_eval<const COEFF_FRAC: i32>(
+        self,
+        coeffs: &[<Self::Bits as FixedBits>::Fixed<COEFF_FRAC>],
+    ) -> Self;
 
-    /// Compute the square root.
-    ///
-    /// Returns a [tuple] of the square root and a [`bool`], indicating whether
-    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    /// Checked polynomial evaluation. Returns [`None`] on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_sqrt][FixedI32::overflowing_sqrt]</code>
+    /// <code>FixedI32::[checked\_poly\_eval][FixedI32::checked_poly_eval]</code>
     /// and
-    /// <code>FixedU32::[overflowing\_sqrt][FixedU32::overflowing_sqrt]</code>.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the number is negative.
-    fn overflowing_sqrt(self) -> (Self, bool);
+    /// <code>FixedU32::[checked\_poly\_eval][FixedU32::checked_poly_eval]</code>.
+    #[must_use]
+    fn checked_poly_eval<const COEFF_FRAC: i32>(
+        self,
+        coeffs: &[<Self::Bits as FixedBits>::Fixed<COEFF_FRAC>],
+    ) -> Option<Self>;
 
-    /// Overflowing linear interpolation between `start` and `end`.
-    ///
-    /// Returns a [tuple] of the interpolated value and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped value is
-    /// returned.
+    /// Polynomial evaluation, panicking on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_lerp][FixedI32::overflowing_lerp]</code>
+    /// <code>FixedI32::[unwrapped\_poly\_eval][FixedI32::unwrapped_poly_eval]</code>
     /// and
-    /// <code>FixedU32::[overflowing\_lerp][FixedU32::overflowing_lerp]</code>.
-    fn overflowing_lerp(self, start: Self, end: Self) -> (Self, bool);
+    /// <code>FixedU32::[unwrapped\_poly\_eval][FixedU32::unwrapped_poly_eval]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_poly_eval<const COEFF_FRAC: i32>(
+        self,
+        coeffs: &[<Self::Bits as FixedBits>::Fixed<COEFF_FRAC>],
+    ) -> Self;
 
-    /// Overflowing inverse linear interpolation between `start` and `end`.
+    /// Overflowing polynomial evaluation.
     ///
-    /// Returns a [tuple] of the computed value and a [`bool`], indicating
-    /// whether an overflow has occurred. On overflow, the wrapped value is
-    /// returned.
+    /// Returns a [tuple] of the result and a [`bool`], indicating whether an
+    /// overflow has occurred. If only the final result overflows, the wrapped
+    /// value is returned.
     ///
     /// See also
-    /// <code>FixedI32::[overflowing\_inv\_lerp][FixedI32::overflowing_inv_lerp]</code>
+    /// <code>FixedI32::[overflowing\_poly\_eval][FixedI32::overflowing_poly_eval]</code>
     /// and
-    /// <code>FixedU32::[overflowing\_inv\_lerp][FixedU32::overflowing_inv_lerp]</code>.
-    fn overflowing_inv_lerp(self, start: Self, end: Self) -> (Self, bool);
+    /// <code>FixedU32::[overflowing\_poly\_eval][FixedU32::overflowing_poly_eval]</code>.
+    #[must_use]
+    fn overflowing_poly_eval<const COEFF_FRAC: i32>(
+        self,
+        coeffs: &[<Self::Bits as FixedBits>::Fixed<COEFF_FRAC>],
+    ) -> (Self, bool);
 }
 
 /// This trait provides methods common to all signed fixed-point numbers.
@@ -3850,21 +6602,117 @@ pub trait FromFixed {
     /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
     fn wrapping_from_fixed<F: Fixed>(src: F) -> Self;
 
-    /// Converts from a fixed-point number.
+    /// Converts from a fixed-point number.
+    ///
+    /// Returns a [tuple] of the value and a [`bool`] indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+    fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool)
+    where
+        Self: Sized;
+
+    /// Converts from a fixed-point number, panicking if the value
+    /// does not fit.
+    ///
+    /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit, even when debug assertions
+    /// are not enabled.
+    #[inline]
+    #[track_caller]
+    fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self
+    where
+        Self: Sized,
+    {
+        match Self::overflowing_from_fixed(src) {
+            (val, false) => val,
+            (_, true) => panic!("overflow"),
+        }
+    }
+
+    /// Converts from a fixed-point number, rounding according to the given
+    /// [`RoundingMode`].
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does
+    /// not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking
+    /// change if in the future it panics; if wrapping is required use
+    /// [`overflowing_from_fixed_round`] instead.
+    ///
+    /// [`overflowing_from_fixed_round`]: FromFixed::overflowing_from_fixed_round
+    #[inline]
+    #[track_caller]
+    fn from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self
+    where
+        Self: Sized,
+    {
+        let (wrapped, overflow) = Self::overflowing_from_fixed_round(src, mode);
+        debug_assert!(!overflow, "overflow");
+        let _ = overflow;
+        wrapped
+    }
+
+    /// Converts from a fixed-point number if it fits, rounding according to
+    /// the given [`RoundingMode`], otherwise returns [`None`].
+    #[inline]
+    fn checked_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match Self::overflowing_from_fixed_round(src, mode) {
+            (val, false) => Some(val),
+            (_, true) => None,
+        }
+    }
+
+    /// Converts from a fixed-point number, rounding according to the given
+    /// [`RoundingMode`].
     ///
     /// Returns a [tuple] of the value and a [`bool`] indicating whether
     /// an overflow has occurred. On overflow, the wrapped value is
     /// returned.
-    ///
-    /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
-    fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool)
+    fn overflowing_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> (Self, bool)
     where
         Self: Sized;
 
-    /// Converts from a fixed-point number, panicking if the value
-    /// does not fit.
+    /// Converts from a fixed-point number, rounding according to the given
+    /// [`RoundingMode`] and saturating if the value does not fit.
     ///
-    /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+    /// The default implementation calls
+    /// [`overflowing_from_fixed_round`][FromFixed::overflowing_from_fixed_round],
+    /// and on overflow returns
+    /// <code>Self::[saturating\_from\_fixed][FromFixed::saturating_from_fixed]\(src)</code>,
+    /// which saturates in the same direction since rounding cannot move a
+    /// value past the nearest representable bound.
+    #[inline]
+    fn saturating_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self
+    where
+        Self: Sized,
+    {
+        match Self::overflowing_from_fixed_round(src, mode) {
+            (val, false) => val,
+            (_, true) => Self::saturating_from_fixed(src),
+        }
+    }
+
+    /// Converts from a fixed-point number, rounding according to the given
+    /// [`RoundingMode`] and wrapping if the value does not fit.
+    #[inline]
+    fn wrapping_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self
+    where
+        Self: Sized,
+    {
+        Self::overflowing_from_fixed_round(src, mode).0
+    }
+
+    /// Converts from a fixed-point number, rounding according to the given
+    /// [`RoundingMode`] and panicking if the value does not fit.
     ///
     /// # Panics
     ///
@@ -3872,11 +6720,11 @@ pub trait FromFixed {
     /// are not enabled.
     #[inline]
     #[track_caller]
-    fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self
+    fn unwrapped_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self
     where
         Self: Sized,
     {
-        match Self::overflowing_from_fixed(src) {
+        match Self::overflowing_from_fixed_round(src, mode) {
             (val, false) => val,
             (_, true) => panic!("overflow"),
         }
@@ -3992,6 +6840,181 @@ pub trait ToFixed {
             (_, true) => panic!("overflow"),
         }
     }
+
+    /// Converts to a fixed-point number, rounding according to the given
+    /// [`RoundingMode`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is a floating-point number that is not [finite].
+    ///
+    /// When debug assertions are enabled, also panics if the value
+    /// does not fit. When debug assertions are not enabled, the
+    /// wrapped value can be returned, but it is not considered a
+    /// breaking change if in the future it panics; if wrapping is
+    /// required use [`overflowing_to_fixed_round`] instead.
+    ///
+    /// [`overflowing_to_fixed_round`]: ToFixed::overflowing_to_fixed_round
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[track_caller]
+    fn to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> F
+    where
+        Self: Sized,
+    {
+        let (wrapped, overflow) = self.overflowing_to_fixed_round(mode);
+        debug_assert!(!overflow, "overflow");
+        let _ = overflow;
+        wrapped
+    }
+
+    /// Converts to a fixed-point number if it fits, rounding according to the
+    /// given [`RoundingMode`], otherwise returns [`None`].
+    ///
+    /// The default implementation calls
+    /// [`overflowing_to_fixed_round`][ToFixed::overflowing_to_fixed_round];
+    /// implementations for floating-point numbers return [`None`] if `self`
+    /// is not [finite] instead of panicking.
+    ///
+    /// [finite]: f64::is_finite
+    #[inline]
+    fn checked_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> Option<F>
+    where
+        Self: Sized,
+    {
+        match self.overflowing_to_fixed_round(mode) {
+            (val, false) => Some(val),
+            (_, true) => None,
+        }
+    }
+
+    /// Converts to a fixed-point number, rounding according to the given
+    /// [`RoundingMode`].
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is a floating-point number that is not [finite].
+    ///
+    /// [finite]: f64::is_finite
+    fn overflowing_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> (F, bool);
+
+    /// Converts to a fixed-point number, rounding stochastically.
+    ///
+    /// The value is rounded up with a probability equal to the fraction of the
+    /// least significant bit that is discarded, otherwise it is rounded down,
+    /// so that on average the rounded value is equal to the exact value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is a floating-point number that is not [finite].
+    ///
+    /// When debug assertions are enabled, also panics if the value
+    /// does not fit. When debug assertions are not enabled, the
+    /// wrapped value can be returned, but it is not considered a
+    /// breaking change if in the future it panics; if wrapping is
+    /// required use [`overflowing_to_fixed_stochastic`] instead.
+    ///
+    /// [`overflowing_to_fixed_stochastic`]: ToFixed::overflowing_to_fixed_stochastic
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[track_caller]
+    fn to_fixed_stochastic<F: Fixed, R: RandomBits + ?Sized>(self, rng: &mut R) -> F
+    where
+        Self: Sized,
+    {
+        let (wrapped, overflow) = self.overflowing_to_fixed_stochastic(rng);
+        debug_assert!(!overflow, "overflow");
+        let _ = overflow;
+        wrapped
+    }
+
+    /// Converts to a fixed-point number if it fits, rounding stochastically,
+    /// otherwise returns [`None`].
+    ///
+    /// The default implementation calls
+    /// [`overflowing_to_fixed_stochastic`][ToFixed::overflowing_to_fixed_stochastic];
+    /// implementations for floating-point numbers return [`None`] if `self`
+    /// is not [finite] instead of panicking.
+    ///
+    /// [finite]: f64::is_finite
+    #[inline]
+    fn checked_to_fixed_stochastic<F: Fixed, R: RandomBits + ?Sized>(self, rng: &mut R) -> Option<F>
+    where
+        Self: Sized,
+    {
+        match self.overflowing_to_fixed_stochastic(rng) {
+            (val, false) => Some(val),
+            (_, true) => None,
+        }
+    }
+
+    /// Converts to a fixed-point number, rounding stochastically.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is a floating-point number that is not [finite].
+    ///
+    /// [finite]: f64::is_finite
+    fn overflowing_to_fixed_stochastic<F: Fixed, R: RandomBits + ?Sized>(
+        self,
+        rng: &mut R,
+    ) -> (F, bool);
+}
+
+/// This trait provides random bits for stochastic rounding.
+///
+/// Methods such as [`mul_stochastic`] and [`from_num_stochastic`] take a source
+/// of random bits through this trait, so that they can be used with any random
+/// number generator. The bits should be uniformly distributed; each call can
+/// use up to 128 random bits.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::traits::RandomBits;
+/// use fixed::types::I8F8;
+///
+/// // A xorshift generator, which is good enough for this example.
+/// struct XorShift(u64);
+/// impl RandomBits for XorShift {
+///     fn next_u64(&mut self) -> u64 {
+///         self.0 ^= self.0 << 13;
+///         self.0 ^= self.0 >> 7;
+///         self.0 ^= self.0 << 17;
+///         self.0
+///     }
+/// }
+///
+/// let mut rng = XorShift(0x1234_5678);
+/// // DELTA × 0.5 lies halfway between zero and DELTA, so it is rounded up
+/// // with a probability of one half
+/// let small = I8F8::DELTA;
+/// let half = I8F8::from_num(0.5);
+/// let mut ups = 0;
+/// for _ in 0..1000 {
+///     if small.mul_stochastic(half, &mut rng) == small {
+///         ups += 1;
+///     }
+/// }
+/// assert!(400 < ups && ups < 600);
+/// ```
+///
+/// [`from_num_stochastic`]: crate::FixedI32::from_num_stochastic
+/// [`mul_stochastic`]: crate::FixedI32::mul_stochastic
+pub trait RandomBits {
+    /// Returns 64 random bits.
+    fn next_u64(&mut self) -> u64;
 }
 
 /// This trait provides a way to convert a number to/from an equivalent
@@ -4074,9 +7097,9 @@ pub trait FixedEquiv {
 }
 
 macro_rules! trait_delegate {
-    (fn $method:ident($($param:ident: $Param:ty),*$(,)?) -> $Ret:ty) => {
+    (fn $method:ident($($param:ident: $Param:ty),*$(,)?) $(-> $Ret:ty)*) => {
         #[inline]
-        fn $method($($param: $Param),*) -> $Ret {
+        fn $method($($param: $Param),*) $(-> $Ret)* {
             Self::$method($($param),*)
         }
     };
@@ -4098,7 +7121,7 @@ macro_rules! trait_delegate {
     ) => {
         #[inline]
         fn $method<$(const $GEN: $GenT),*>(self $(, $param: $Param)*) -> $Ret {
-            self.$method($($param),*)
+            self.$method::<$($GEN),*>($($param),*)
         }
     };
     (
@@ -4107,7 +7130,7 @@ macro_rules! trait_delegate {
     ) => {
         #[inline]
         fn $method<$(const $GEN: $GenT),*>(&mut self $(, $param: $Param)*) $(-> $Ret)* {
-            self.$method($($param),*)
+            self.$method::<$($GEN),*>($($param),*)
         }
     };
     (fn $method:ident<$Gen:ident: $Trait:ident>($($param:ident: $Param:ty),*$(,)?) -> $Ret:ty) => {
@@ -4127,6 +7150,30 @@ macro_rules! trait_delegate {
     };
 }
 
+// The compiler does not always unify `size_of::<Self>()` with the literal
+// length of the byte arrays used by the inherent methods, so the bytes are
+// copied into an array with the other length. Both lengths are equal.
+macro_rules! trait_delegate_bytes {
+    (fn $method:ident($param:ident: $Param:ty) -> $Ret:ty) => {
+        #[inline]
+        fn $method($param: $Param) -> $Ret {
+            Self::$method(copy_bytes($param))
+        }
+    };
+    (fn $method:ident(self) -> $Ret:ty) => {
+        #[inline]
+        fn $method(self) -> $Ret {
+            copy_bytes(self.$method())
+        }
+    };
+}
+
+#[inline]
+pub(crate) fn copy_bytes<const SRC: usize, const DST: usize>(bytes: [u8; SRC]) -> [u8; DST] {
+    debug_assert!(SRC == DST);
+    array::from_fn(|i| bytes[i])
+}
+
 macro_rules! impl_fixed {
     (
         $Fixed:ident, $IFixed:ident, $UFixed:ident, $nbits:expr, $Bits:ident, $NonZeroBits:ident,
@@ -4165,12 +7212,12 @@ macro_rules! impl_fixed {
             trait_delegate! { fn to_be(self) -> Self }
             trait_delegate! { fn to_le(self) -> Self }
             trait_delegate! { fn swap_bytes(self) -> Self }
-            trait_delegate! { fn from_be_bytes(bits: [u8; size_of::<Self>()]) -> Self }
-            trait_delegate! { fn from_le_bytes(bits: [u8; size_of::<Self>()]) -> Self }
-            trait_delegate! { fn from_ne_bytes(bits: [u8; size_of::<Self>()]) -> Self }
-            trait_delegate! { fn to_be_bytes(self) -> [u8; size_of::<Self>()] }
-            trait_delegate! { fn to_le_bytes(self) -> [u8; size_of::<Self>()] }
-            trait_delegate! { fn to_ne_bytes(self) -> [u8; size_of::<Self>()] }
+            trait_delegate_bytes! { fn from_be_bytes(bits: [u8; size_of::<Self>()]) -> Self }
+            trait_delegate_bytes! { fn from_le_bytes(bits: [u8; size_of::<Self>()]) -> Self }
+            trait_delegate_bytes! { fn from_ne_bytes(bits: [u8; size_of::<Self>()]) -> Self }
+            trait_delegate_bytes! { fn to_be_bytes(self) -> [u8; size_of::<Self>()] }
+            trait_delegate_bytes! { fn to_le_bytes(self) -> [u8; size_of::<Self>()] }
+            trait_delegate_bytes! { fn to_ne_bytes(self) -> [u8; size_of::<Self>()] }
             trait_delegate! { fn from_num<Src: ToFixed>(src: Src) -> Self }
             trait_delegate! { fn to_num<Dst: FromFixed>(self) -> Dst }
             trait_delegate! { fn checked_from_num<Src: ToFixed>(val: Src) -> Option<Self> }
@@ -4183,6 +7230,65 @@ macro_rules! impl_fixed {
             trait_delegate! { fn unwrapped_to_num<Dst: FromFixed>(self) -> Dst }
             trait_delegate! { fn overflowing_from_num<Src: ToFixed>(val: Src) -> (Self, bool) }
             trait_delegate! { fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) }
+            trait_delegate! { fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self }
+            trait_delegate! { fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst }
+            trait_delegate! {
+                fn checked_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Option<Self>
+            }
+            trait_delegate! {
+                fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst>
+            }
+            trait_delegate! { fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst }
+            trait_delegate! { fn wrapping_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst }
+            trait_delegate! { fn unwrapped_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst }
+            trait_delegate! {
+                fn overflowing_from_num_round<Src: ToFixed>(
+                    src: Src,
+                    mode: RoundingMode,
+                ) -> (Self, bool)
+            }
+            trait_delegate! {
+                fn overflowing_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> (Dst, bool)
+            }
+            #[inline]
+            fn from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+                src: Src,
+                rng: &mut R,
+            ) -> Self {
+                Self::from_num_stochastic(src, rng)
+            }
+            #[inline]
+            fn checked_from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+                src: Src,
+                rng: &mut R,
+            ) -> Option<Self> {
+                Self::checked_from_num_stochastic(src, rng)
+            }
+            #[inline]
+            fn overflowing_from_num_stochastic<Src: ToFixed, R: RandomBits + ?Sized>(
+                src: Src,
+                rng: &mut R,
+            ) -> (Self, bool) {
+                Self::overflowing_from_num_stochastic(src, rng)
+            }
+            trait_delegate! {
+                fn requantize<const DST_FRAC: i32>(self, mode: RoundingMode) -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>
+            }
+            trait_delegate! {
+                fn checked_requantize<const DST_FRAC: i32>(self, mode: RoundingMode) -> Option<<Self::Bits as FixedBits>::Fixed<DST_FRAC>>
+            }
+            trait_delegate! {
+                fn saturating_requantize<const DST_FRAC: i32>(self, mode: RoundingMode) -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>
+            }
+            trait_delegate! {
+                fn wrapping_requantize<const DST_FRAC: i32>(self, mode: RoundingMode) -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>
+            }
+            trait_delegate! {
+                fn unwrapped_requantize<const DST_FRAC: i32>(self, mode: RoundingMode) -> <Self::Bits as FixedBits>::Fixed<DST_FRAC>
+            }
+            trait_delegate! {
+                fn overflowing_requantize<const DST_FRAC: i32>(self, mode: RoundingMode) -> (<Self::Bits as FixedBits>::Fixed<DST_FRAC>, bool)
+            }
             trait_delegate! { fn int(self) -> Self }
             trait_delegate! { fn frac(self) -> Self }
             trait_delegate! { fn ceil(self) -> Self }
@@ -4226,7 +7332,14 @@ macro_rules! impl_fixed {
             trait_delegate! { fn abs_diff(self, other: Self) -> Self::Unsigned }
             trait_delegate! { fn mean(self, other: Self) -> Self }
             trait_delegate! { fn hypot(self, other: Self) -> Self }
+            trait_delegate! {
+                fn atan2<const RET_FRAC: i32>(self, other: Self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
             trait_delegate! { fn next_multiple_of(self, other: Self) -> Self }
+            #[inline]
+            fn mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> Self {
+                self.mul_stochastic(rhs, rng)
+            }
             trait_delegate! { fn mul_add<const MUL_FRAC: i32>(
                 self,
                 mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
@@ -4247,6 +7360,10 @@ macro_rules! impl_fixed {
             trait_delegate! { fn checked_add(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_sub(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul(self, rhs: Self) -> Option<Self> }
+            #[inline]
+            fn checked_mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> Option<Self> {
+                self.checked_mul_stochastic(rhs, rng)
+            }
             trait_delegate! { fn checked_rem(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_next_multiple_of(self, other: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul_add<const MUL_FRAC: i32>(
@@ -4271,10 +7388,17 @@ macro_rules! impl_fixed {
             trait_delegate! { fn checked_shr(self, rhs: u32) -> Option<Self> }
             trait_delegate! { fn checked_dist(self, other: Self) -> Option<Self> }
             trait_delegate! { fn checked_hypot(self, other: Self) -> Option<Self> }
+            trait_delegate! {
+                fn checked_atan2<const RET_FRAC: i32>(self, other: Self) -> Option<<Self::Bits as FixedBits>::Fixed<RET_FRAC>>
+            }
             trait_delegate! { fn saturating_neg(self) -> Self }
             trait_delegate! { fn saturating_add(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_sub(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_mul(self, rhs: Self) -> Self }
+            #[inline]
+            fn saturating_mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> Self {
+                self.saturating_mul_stochastic(rhs, rng)
+            }
             trait_delegate! { fn saturating_next_multiple_of(self, other: Self) -> Self }
             trait_delegate! { fn saturating_mul_add<const MUL_FRAC: i32>(
                 self,
@@ -4295,10 +7419,17 @@ macro_rules! impl_fixed {
             trait_delegate! { fn saturating_div_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn saturating_dist(self, other: Self) -> Self }
             trait_delegate! { fn saturating_hypot(self, other: Self) -> Self }
+            trait_delegate! {
+                fn saturating_atan2<const RET_FRAC: i32>(self, other: Self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
             trait_delegate! { fn wrapping_neg(self) -> Self }
             trait_delegate! { fn wrapping_add(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_sub(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_mul(self, rhs: Self) -> Self }
+            #[inline]
+            fn wrapping_mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> Self {
+                self.wrapping_mul_stochastic(rhs, rng)
+            }
             trait_delegate! { fn wrapping_next_multiple_of(self, other: Self) -> Self }
             trait_delegate! { fn wrapping_mul_add<const MUL_FRAC: i32>(
                 self,
@@ -4321,10 +7452,17 @@ macro_rules! impl_fixed {
             trait_delegate! { fn wrapping_shr(self, rhs: u32) -> Self }
             trait_delegate! { fn wrapping_dist(self, other: Self) -> Self }
             trait_delegate! { fn wrapping_hypot(self, other: Self) -> Self }
+            trait_delegate! {
+                fn wrapping_atan2<const RET_FRAC: i32>(self, other: Self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
             trait_delegate! { fn unwrapped_neg(self) -> Self }
             trait_delegate! { fn unwrapped_add(self, rhs: Self) -> Self }
             trait_delegate! { fn unwrapped_sub(self, rhs: Self) -> Self }
             trait_delegate! { fn unwrapped_mul(self, rhs: Self) -> Self }
+            #[inline]
+            fn unwrapped_mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> Self {
+                self.unwrapped_mul_stochastic(rhs, rng)
+            }
             trait_delegate! { fn unwrapped_rem(self, rhs: Self) -> Self }
             trait_delegate! { fn unwrapped_next_multiple_of(self, other: Self) -> Self }
             trait_delegate! { fn unwrapped_mul_add<const MUL_FRAC: i32>(
@@ -4349,10 +7487,17 @@ macro_rules! impl_fixed {
             trait_delegate! { fn unwrapped_shr(self, rhs: u32) -> Self }
             trait_delegate! { fn unwrapped_dist(self, other: Self) -> Self }
             trait_delegate! { fn unwrapped_hypot(self, other: Self) -> Self }
+            trait_delegate! {
+                fn unwrapped_atan2<const RET_FRAC: i32>(self, other: Self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
             trait_delegate! { fn overflowing_neg(self) -> (Self, bool) }
             trait_delegate! { fn overflowing_add(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_sub(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_mul(self, rhs: Self) -> (Self, bool) }
+            #[inline]
+            fn overflowing_mul_stochastic<R: RandomBits + ?Sized>(self, rhs: Self, rng: &mut R) -> (Self, bool) {
+                self.overflowing_mul_stochastic(rhs, rng)
+            }
             trait_delegate! { fn overflowing_next_multiple_of(self, other: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_mul_add<const MUL_FRAC: i32>(
                 self,
@@ -4375,6 +7520,9 @@ macro_rules! impl_fixed {
             trait_delegate! { fn overflowing_shr(self, rhs: u32) -> (Self, bool) }
             trait_delegate! { fn overflowing_dist(self, other: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_hypot(self, other: Self) -> (Self, bool) }
+            trait_delegate! {
+                fn overflowing_atan2<const RET_FRAC: i32>(self, other: Self) -> (<Self::Bits as FixedBits>::Fixed<RET_FRAC>, bool)
+            }
         }
 
         impl<const FRAC: i32> FixedBoundFrac for $Fixed<FRAC>
@@ -4386,6 +7534,9 @@ macro_rules! impl_fixed {
             trait_delegate! { fn from_str_binary(src: &str) -> Result<Self, ParseFixedError> }
             trait_delegate! { fn from_str_octal(src: &str) -> Result<Self, ParseFixedError> }
             trait_delegate! { fn from_str_hex(src: &str) -> Result<Self, ParseFixedError> }
+            trait_delegate! {
+                fn from_str_round(src: &str, mode: RoundingMode) -> Result<Self, ParseFixedError>
+            }
             trait_delegate! {
                 fn saturating_from_str(src: &str) -> Result<Self, ParseFixedError>
             }
@@ -4426,61 +7577,316 @@ macro_rules! impl_fixed {
             trait_delegate! {
                 fn overflowing_from_str_hex(src: &str) -> Result<(Self, bool), ParseFixedError>
             }
+            trait_delegate! {
+                fn overflowing_from_str_round(
+                    src: &str,
+                    mode: RoundingMode,
+                ) -> Result<(Self, bool), ParseFixedError>
+            }
             trait_delegate! { fn int_log10(self) -> i32 }
             trait_delegate! { fn int_log(self, base: u32) -> i32 }
             trait_delegate! { fn checked_int_log10(self) -> Option<i32> }
             trait_delegate! { fn checked_int_log(self, base: u32) -> Option<i32> }
             trait_delegate! { fn recip(self) -> Self }
+            trait_delegate! { fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn recip_round(self, mode: RoundingMode) -> Self }
             trait_delegate! { fn div_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn rem_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn sqrt(self) -> Self }
+            trait_delegate! { fn sqrt_round(self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn cbrt(self) -> Self }
+            trait_delegate! { fn nth_root(self, n: u32) -> Self }
+            trait_delegate! { fn rsqrt<const RET_FRAC: i32>(self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC> }
             trait_delegate! { fn lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn inv_lerp(self, start: Self, end: Self) -> Self }
+            trait_delegate! { fn round_dp(self, dp: u32, mode: RoundingMode) -> Self }
+            trait_delegate! { fn floor_dp(self, dp: u32) -> Self }
+            trait_delegate! { fn ceil_dp(self, dp: u32) -> Self }
             trait_delegate! { fn checked_div(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_recip(self) -> Option<Self> }
+            trait_delegate! { fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_recip_round(self, mode: RoundingMode) -> Option<Self> }
             trait_delegate! { fn checked_div_euclid(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_rem_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_div_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_sqrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_sqrt_round(self, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_cbrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_nth_root(self, n: u32) -> Option<Self> }
+            trait_delegate! { fn checked_rsqrt<const RET_FRAC: i32>(self) -> Option<<Self::Bits as FixedBits>::Fixed<RET_FRAC>> }
             trait_delegate! { fn checked_lerp(self, start: Self, end: Self) -> Option<Self> }
             trait_delegate! { fn checked_inv_lerp(self, start: Self, end: Self) -> Option<Self> }
+            trait_delegate! { fn checked_round_dp(self, dp: u32, mode: RoundingMode) -> Option<Self> }
             trait_delegate! { fn saturating_div(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_recip(self) -> Self }
+            trait_delegate! { fn saturating_mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn saturating_div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn saturating_recip_round(self, mode: RoundingMode) -> Self }
             trait_delegate! { fn saturating_div_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn saturating_rem_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn saturating_sqrt(self) -> Self }
+            trait_delegate! { fn saturating_sqrt_round(self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn saturating_cbrt(self) -> Self }
+            trait_delegate! { fn saturating_nth_root(self, n: u32) -> Self }
+            trait_delegate! { fn saturating_rsqrt<const RET_FRAC: i32>(self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC> }
             trait_delegate! { fn saturating_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn saturating_inv_lerp(self, start: Self, end: Self) -> Self }
+            trait_delegate! { fn saturating_round_dp(self, dp: u32, mode: RoundingMode) -> Self }
             trait_delegate! { fn wrapping_div(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_recip(self) -> Self }
+            trait_delegate! { fn wrapping_mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn wrapping_div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn wrapping_recip_round(self, mode: RoundingMode) -> Self }
             trait_delegate! { fn wrapping_div_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_sqrt(self) -> Self }
+            trait_delegate! { fn wrapping_sqrt_round(self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn wrapping_cbrt(self) -> Self }
+            trait_delegate! { fn wrapping_nth_root(self, n: u32) -> Self }
+            trait_delegate! { fn wrapping_rsqrt<const RET_FRAC: i32>(self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC> }
             trait_delegate! { fn wrapping_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn wrapping_inv_lerp(self, start: Self, end: Self) -> Self }
+            trait_delegate! { fn wrapping_round_dp(self, dp: u32, mode: RoundingMode) -> Self }
             trait_delegate! { fn unwrapped_div(self, rhs: Self) -> Self }
             trait_delegate! { fn unwrapped_recip(self) -> Self }
+            trait_delegate! { fn unwrapped_mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn unwrapped_div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn unwrapped_recip_round(self, mode: RoundingMode) -> Self }
             trait_delegate! { fn unwrapped_div_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn unwrapped_rem_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn unwrapped_div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn unwrapped_rem_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn unwrapped_sqrt(self) -> Self }
+            trait_delegate! { fn unwrapped_sqrt_round(self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn unwrapped_cbrt(self) -> Self }
+            trait_delegate! { fn unwrapped_nth_root(self, n: u32) -> Self }
+            trait_delegate! { fn unwrapped_rsqrt<const RET_FRAC: i32>(self) -> <Self::Bits as FixedBits>::Fixed<RET_FRAC> }
             trait_delegate! { fn unwrapped_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn unwrapped_inv_lerp(self, start: Self, end: Self) -> Self }
+            trait_delegate! { fn unwrapped_round_dp(self, dp: u32, mode: RoundingMode) -> Self }
             trait_delegate! { fn overflowing_div(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_recip(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) }
+            trait_delegate! { fn overflowing_div_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) }
+            trait_delegate! { fn overflowing_recip_round(self, mode: RoundingMode) -> (Self, bool) }
             trait_delegate! { fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_sqrt(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_sqrt_round(self, mode: RoundingMode) -> (Self, bool) }
+            trait_delegate! { fn overflowing_cbrt(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_nth_root(self, n: u32) -> (Self, bool) }
+            trait_delegate! { fn overflowing_rsqrt<const RET_FRAC: i32>(self) -> (<Self::Bits as FixedBits>::Fixed<RET_FRAC>, bool) }
             trait_delegate! { fn overflowing_lerp(self, start: Self, end: Self) -> (Self, bool) }
             trait_delegate! {
                 fn overflowing_inv_lerp(self, start: Self, end: Self) -> (Self, bool)
             }
+            trait_delegate! {
+                fn overflowing_round_dp(self, dp: u32, mode: RoundingMode) -> (Self, bool)
+            }
+            trait_delegate! { fn sin(self) -> Self }
+            trait_delegate! { fn checked_sin(self) -> Option<Self> }
+            trait_delegate! { fn saturating_sin(self) -> Self }
+            trait_delegate! { fn wrapping_sin(self) -> Self }
+            trait_delegate! { fn unwrapped_sin(self) -> Self }
+            trait_delegate! { fn overflowing_sin(self) -> (Self, bool) }
+            trait_delegate! { fn cos(self) -> Self }
+            trait_delegate! { fn checked_cos(self) -> Option<Self> }
+            trait_delegate! { fn saturating_cos(self) -> Self }
+            trait_delegate! { fn wrapping_cos(self) -> Self }
+            trait_delegate! { fn unwrapped_cos(self) -> Self }
+            trait_delegate! { fn overflowing_cos(self) -> (Self, bool) }
+            trait_delegate! { fn sin_cos(self) -> (Self, Self) }
+            trait_delegate! { fn checked_sin_cos(self) -> Option<(Self, Self)> }
+            trait_delegate! { fn saturating_sin_cos(self) -> (Self, Self) }
+            trait_delegate! { fn wrapping_sin_cos(self) -> (Self, Self) }
+            trait_delegate! { fn unwrapped_sin_cos(self) -> (Self, Self) }
+            trait_delegate! { fn overflowing_sin_cos(self) -> ((Self, Self), bool) }
+            trait_delegate! { fn tan(self) -> Self }
+            trait_delegate! { fn checked_tan(self) -> Option<Self> }
+            trait_delegate! { fn saturating_tan(self) -> Self }
+            trait_delegate! { fn wrapping_tan(self) -> Self }
+            trait_delegate! { fn unwrapped_tan(self) -> Self }
+            trait_delegate! { fn overflowing_tan(self) -> (Self, bool) }
+            trait_delegate! { fn atan(self) -> Self }
+            trait_delegate! { fn asin(self) -> Self }
+            trait_delegate! { fn checked_asin(self) -> Option<Self> }
+            trait_delegate! { fn saturating_asin(self) -> Self }
+            trait_delegate! { fn wrapping_asin(self) -> Self }
+            trait_delegate! { fn unwrapped_asin(self) -> Self }
+            trait_delegate! { fn overflowing_asin(self) -> (Self, bool) }
+            trait_delegate! { fn acos(self) -> Self }
+            trait_delegate! { fn checked_acos(self) -> Option<Self> }
+            trait_delegate! { fn saturating_acos(self) -> Self }
+            trait_delegate! { fn wrapping_acos(self) -> Self }
+            trait_delegate! { fn unwrapped_acos(self) -> Self }
+            trait_delegate! { fn overflowing_acos(self) -> (Self, bool) }
+            trait_delegate! { fn exp(self) -> Self }
+            trait_delegate! { fn checked_exp(self) -> Option<Self> }
+            trait_delegate! { fn saturating_exp(self) -> Self }
+            trait_delegate! { fn wrapping_exp(self) -> Self }
+            trait_delegate! { fn unwrapped_exp(self) -> Self }
+            trait_delegate! { fn overflowing_exp(self) -> (Self, bool) }
+            trait_delegate! { fn exp2(self) -> Self }
+            trait_delegate! { fn checked_exp2(self) -> Option<Self> }
+            trait_delegate! { fn saturating_exp2(self) -> Self }
+            trait_delegate! { fn wrapping_exp2(self) -> Self }
+            trait_delegate! { fn unwrapped_exp2(self) -> Self }
+            trait_delegate! { fn overflowing_exp2(self) -> (Self, bool) }
+            trait_delegate! { fn exp_m1(self) -> Self }
+            trait_delegate! { fn checked_exp_m1(self) -> Option<Self> }
+            trait_delegate! { fn saturating_exp_m1(self) -> Self }
+            trait_delegate! { fn wrapping_exp_m1(self) -> Self }
+            trait_delegate! { fn unwrapped_exp_m1(self) -> Self }
+            trait_delegate! { fn overflowing_exp_m1(self) -> (Self, bool) }
+            trait_delegate! {
+                fn ln<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn checked_ln<const RET_FRAC: i32>(self) -> Option<<<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>>
+            }
+            trait_delegate! {
+                fn saturating_ln<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn wrapping_ln<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn unwrapped_ln<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn overflowing_ln<const RET_FRAC: i32>(self) -> (<<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>, bool)
+            }
+            trait_delegate! {
+                fn log2<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn checked_log2<const RET_FRAC: i32>(self) -> Option<<<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>>
+            }
+            trait_delegate! {
+                fn saturating_log2<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn wrapping_log2<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn unwrapped_log2<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn overflowing_log2<const RET_FRAC: i32>(self) -> (<<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>, bool)
+            }
+            trait_delegate! {
+                fn log10<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn checked_log10<const RET_FRAC: i32>(self) -> Option<<<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>>
+            }
+            trait_delegate! {
+                fn saturating_log10<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn wrapping_log10<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn unwrapped_log10<const RET_FRAC: i32>(self) -> <<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! {
+                fn overflowing_log10<const RET_FRAC: i32>(self) -> (<<Self::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>, bool)
+            }
+            trait_delegate! { fn powi(self, exp: i32) -> Self }
+            trait_delegate! { fn checked_powi(self, exp: i32) -> Option<Self> }
+            trait_delegate! { fn saturating_powi(self, exp: i32) -> Self }
+            trait_delegate! { fn wrapping_powi(self, exp: i32) -> Self }
+            trait_delegate! { fn unwrapped_powi(self, exp: i32) -> Self }
+            trait_delegate! { fn overflowing_powi(self, exp: i32) -> (Self, bool) }
+            trait_delegate! { fn powf(self, exp: Self) -> Self }
+            trait_delegate! { fn checked_powf(self, exp: Self) -> Option<Self> }
+            trait_delegate! { fn saturating_powf(self, exp: Self) -> Self }
+            trait_delegate! { fn wrapping_powf(self, exp: Self) -> Self }
+            trait_delegate! { fn unwrapped_powf(self, exp: Self) -> Self }
+            trait_delegate! { fn overflowing_powf(self, exp: Self) -> (Self, bool) }
+            trait_delegate! { fn sinh(self) -> Self }
+            trait_delegate! { fn checked_sinh(self) -> Option<Self> }
+            trait_delegate! { fn saturating_sinh(self) -> Self }
+            trait_delegate! { fn wrapping_sinh(self) -> Self }
+            trait_delegate! { fn unwrapped_sinh(self) -> Self }
+            trait_delegate! { fn overflowing_sinh(self) -> (Self, bool) }
+            trait_delegate! { fn cosh(self) -> Self }
+            trait_delegate! { fn checked_cosh(self) -> Option<Self> }
+            trait_delegate! { fn saturating_cosh(self) -> Self }
+            trait_delegate! { fn wrapping_cosh(self) -> Self }
+            trait_delegate! { fn unwrapped_cosh(self) -> Self }
+            trait_delegate! { fn overflowing_cosh(self) -> (Self, bool) }
+            trait_delegate! { fn tanh(self) -> Self }
+            trait_delegate! { fn asinh(self) -> Self }
+            trait_delegate! { fn acosh(self) -> Self }
+            trait_delegate! { fn checked_acosh(self) -> Option<Self> }
+            trait_delegate! { fn atanh(self) -> Self }
+            trait_delegate! { fn checked_atanh(self) -> Option<Self> }
+            trait_delegate! { fn saturating_atanh(self) -> Self }
+            trait_delegate! { fn wrapping_atanh(self) -> Self }
+            trait_delegate! { fn unwrapped_atanh(self) -> Self }
+            trait_delegate! { fn overflowing_atanh(self) -> (Self, bool) }
+            trait_delegate! { fn erf(self) -> Self }
+            trait_delegate! { fn checked_erf(self) -> Option<Self> }
+            trait_delegate! { fn saturating_erf(self) -> Self }
+            trait_delegate! { fn wrapping_erf(self) -> Self }
+            trait_delegate! { fn unwrapped_erf(self) -> Self }
+            trait_delegate! { fn overflowing_erf(self) -> (Self, bool) }
+            trait_delegate! { fn erfc(self) -> Self }
+            trait_delegate! { fn checked_erfc(self) -> Option<Self> }
+            trait_delegate! { fn saturating_erfc(self) -> Self }
+            trait_delegate! { fn wrapping_erfc(self) -> Self }
+            trait_delegate! { fn unwrapped_erfc(self) -> Self }
+            trait_delegate! { fn overflowing_erfc(self) -> (Self, bool) }
+            trait_delegate! {
+                fn sigmoid<const RET_FRAC: i32>(self) -> <<Self::Unsigned as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>
+            }
+            trait_delegate! { fn softplus(self) -> Self }
+            trait_delegate! { fn gelu(self) -> Self }
+            trait_delegate! { fn softmax(values: &mut [Self]) }
+            trait_delegate! { fn sin_lut<const N: usize>(self) -> Self }
+            trait_delegate! { fn checked_sin_lut<const N: usize>(self) -> Option<Self> }
+            trait_delegate! { fn saturating_sin_lut<const N: usize>(self) -> Self }
+            trait_delegate! { fn wrapping_sin_lut<const N: usize>(self) -> Self }
+            trait_delegate! { fn unwrapped_sin_lut<const N: usize>(self) -> Self }
+            trait_delegate! { fn overflowing_sin_lut<const N: usize>(self) -> (Self, bool) }
+            trait_delegate! { fn cos_lut<const N: usize>(self) -> Self }
+            trait_delegate! { fn checked_cos_lut<const N: usize>(self) -> Option<Self> }
+            trait_delegate! { fn saturating_cos_lut<const N: usize>(self) -> Self }
+            trait_delegate! { fn wrapping_cos_lut<const N: usize>(self) -> Self }
+            trait_delegate! { fn unwrapped_cos_lut<const N: usize>(self) -> Self }
+            trait_delegate! { fn overflowing_cos_lut<const N: usize>(self) -> (Self, bool) }
+            trait_delegate! {
+                fn poly_eval<const COEFF_FRAC: i32>(
+                    self,
+                    coeffs: &[<Self::Bits as FixedBits>::Fixed<COEFF_FRAC>],
+                ) -> Self
+            }
+            trait_delegate! {
+                fn checked_poly_eval<const COEFF_FRAC: i32>(
+                    self,
+                    coeffs: &[<Self::Bits as FixedBits>::Fixed<COEFF_FRAC>],
+                ) -> Option<Self>
+            }
+            trait_delegate! {
+                fn unwrapped_poly_eval<const COEFF_FRAC: i32>(
+                    self,
+                    coeffs: &[<Self::Bits as FixedBits>::Fixed<COEFF_FRAC>],
+                ) -> Self
+            }
+            trait_delegate! {
+                fn overflowing_poly_eval<const COEFF_FRAC: i32>(
+                    self,
+                    coeffs: &[<Self::Bits as FixedBits>::Fixed<COEFF_FRAC>],
+                ) -> (Self, bool)
+            }
         }
 
         if_signed! {
@@ -4646,6 +8052,17 @@ macro_rules! impl_fixed {
                     (_, true) => panic!("overflow"),
                 }
             }
+
+            /// Converts a fixed-point number, rounding according to the
+            /// given [`RoundingMode`].
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            #[inline]
+            fn overflowing_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> (Self, bool) {
+                $Fixed::fixed_from_bits_round(src.to_bits(), F::FRAC_BITS, mode)
+            }
         }
 
         impl<const FRAC: i32> ToFixed for $Fixed<FRAC> {
@@ -4717,6 +8134,43 @@ macro_rules! impl_fixed {
             fn unwrapped_to_fixed<F: Fixed>(self) -> F {
                 FromFixed::unwrapped_from_fixed(self)
             }
+
+            /// Converts a fixed-point number, rounding according to the
+            /// given [`RoundingMode`].
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            #[inline]
+            fn overflowing_to_fixed_round<F: Fixed>(self, mode: RoundingMode) -> (F, bool) {
+                FromFixed::overflowing_from_fixed_round(self, mode)
+            }
+
+            /// Converts a fixed-point number, rounding stochastically.
+            ///
+            /// Returns a [tuple] of the value and a [`bool`] indicating whether
+            /// an overflow has occurred. On overflow, the wrapped value is
+            /// returned.
+            #[inline]
+            fn overflowing_to_fixed_stochastic<F: Fixed, R: RandomBits + ?Sized>(
+                self,
+                rng: &mut R,
+            ) -> (F, bool) {
+                let shift = i64::from(FRAC) - i64::from(F::FRAC_BITS);
+                if shift <= 0 {
+                    return FromFixed::overflowing_from_fixed(self);
+                }
+                let shift = if shift > 384 { 384 } else { shift as u32 };
+                let bits = self.to_bits();
+                let (lo, ext) = if_signed_unsigned!(
+                    $Signedness,
+                    (bits as i128 as u128, if bits < 0 { u128::MAX } else { 0 }),
+                    (bits as u128, 0),
+                );
+                let discarded = arith::discarded_bits(ext, lo, ext, shift);
+                let mode = rounding::stochastic_mode(rng, discarded);
+                FromFixed::overflowing_from_fixed_round(self, mode)
+            }
         }
     };
 }
@@ -0,0 +1,465 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::consts;
+use crate::int256;
+use crate::int256::U256;
+use crate::real::Real;
+
+// 2/π with 384 fractional bits, most significant word first
+const FRAC_2_PI_384: [u128; 3] = [
+    0xA2F9_836E_4E44_1529_FC27_57D1_F534_DDC0,
+    0xDB62_9599_3C43_9041_FE51_63AB_DEBB_C561,
+    0xB724_6E3A_424D_D2E0_0649_2EEA_09D1_921C,
+];
+
+// atan(j/8) for j = 1, 2, …, 7 with 128 fractional bits
+const ATAN_EIGHTHS: [u128; 7] = [
+    0x1FD5_BA9A_AC2F_6DC6_5912_F313_E7D1_11DF,
+    0x3EB6_EBF2_5901_BAC5_5B71_E7BD_7DE8_85F9,
+    0x5BD8_6507_937B_C239_C551_9091_6E7F_2242,
+    0x76B1_9C15_86ED_3DA2_B7F2_22F6_5E1D_4682,
+    0x8F00_5D5E_F7F5_9F9B_5C83_5E16_65C4_3748,
+    0xA4BC_7D19_34F7_0924_19A8_7F2A_457D_AC9F,
+    0xB805_3E2B_C231_9E73_CB2D_A552_10A4_443D,
+];
+
+// Number of terms used in the Taylor series for |θ| ≤ π/4. The first term that
+// is not used is < 2^-128 relative to the result.
+const SIN_COS_TERMS: u32 = 16;
+
+// Number of terms used in the Taylor series for atan(u) with |u| ≤ 1/16.
+const ATAN_TERMS: u32 = 17;
+
+const fn pi() -> Real {
+    Real::from_u128(false, consts::PI.to_bits(), 126)
+}
+
+const fn frac_pi_2() -> Real {
+    Real::from_u128(false, consts::FRAC_PI_2.to_bits(), 127)
+}
+
+const fn frac_pi_4() -> Real {
+    Real::from_u128(false, consts::FRAC_PI_4.to_bits(), 128)
+}
+
+// 128 bits of the 512-bit number starting at bit pos
+const fn extract_128(limbs: &[u128; 4], pos: u32) -> u128 {
+    let index = (pos / 128) as usize;
+    let sh = pos % 128;
+    if index >= 4 {
+        return 0;
+    }
+    let lo = limbs[index] >> sh;
+    if sh == 0 || index == 3 {
+        return lo;
+    }
+    lo | limbs[index + 1] << (128 - sh)
+}
+
+const fn add_u256_at(limbs: &mut [u128; 4], index: usize, val: U256) {
+    let (lo, carry) = limbs[index].overflowing_add(val.lo);
+    limbs[index] = lo;
+    let (hi, carry1) = limbs[index + 1].overflowing_add(val.hi);
+    let (hi, carry2) = hi.overflowing_add(carry as u128);
+    limbs[index + 1] = hi;
+    let mut carry = carry1 | carry2;
+    let mut i = index + 2;
+    while carry && i < 4 {
+        let (next, c) = limbs[i].overflowing_add(1);
+        limbs[i] = next;
+        carry = c;
+        i += 1;
+    }
+}
+
+// Reduced angle: |x| = (quadrant + frac) × π/2 for 0 ≤ frac < 1.
+//
+// If frac < 0.5, theta = frac × π/2 and swap = false. Otherwise,
+// theta = (1 - frac) × π/2 and swap = true. Either way, 0 ≤ theta ≤ π/4.
+struct Reduced {
+    quadrant: u32,
+    theta: Real,
+    swap: bool,
+}
+
+// |x| must be < 2^128.
+//
+// This is Payne–Hanek reduction: the product |x| × 2/π is computed using 384
+// bits of 2/π, which is enough to get the 256 bits following the binary point
+// correct for all |x| < 2^128.
+const fn reduce(x: Real) -> Reduced {
+    let x = x.abs();
+    if x.cmp_abs(frac_pi_4()).is_le() {
+        return Reduced {
+            quadrant: 0,
+            theta: x,
+            swap: false,
+        };
+    }
+    debug_assert!(-128 <= x.exp && x.exp <= 0);
+
+    let mut prod = [0u128; 4];
+    add_u256_at(
+        &mut prod,
+        0,
+        int256::wide_mul_u128(x.mant, FRAC_2_PI_384[2]),
+    );
+    add_u256_at(
+        &mut prod,
+        1,
+        int256::wide_mul_u128(x.mant, FRAC_2_PI_384[1]),
+    );
+    add_u256_at(
+        &mut prod,
+        2,
+        int256::wide_mul_u128(x.mant, FRAC_2_PI_384[0]),
+    );
+
+    // |x| × 2/π = prod × 2^(exp - 384), so the units bit is bit 384 - exp
+    let units = (384 - x.exp) as u32;
+    let quadrant = (extract_128(&prod, units) & 3) as u32;
+    let frac = U256 {
+        lo: extract_128(&prod, units - 256),
+        hi: extract_128(&prod, units - 128),
+    };
+    let swap = (frac.hi >> 127) != 0;
+    let frac = if swap {
+        int256::wrapping_neg_u256(frac)
+    } else {
+        frac
+    };
+    Reduced {
+        quadrant,
+        theta: Real::from_u256(false, frac, 256).mul(frac_pi_2()),
+        swap,
+    }
+}
+
+// 0 ≤ theta ≤ π/4
+const fn sin_small(theta: Real) -> Real {
+    let theta2 = theta.mul(theta);
+    // sin θ = θ (1 - θ²/(2×3) (1 - θ²/(4×5) (1 - ...)))
+    let mut acc = Real::ONE;
+    let mut k = SIN_COS_TERMS;
+    while k > 0 {
+        let div = Real::from_u32((2 * k) * (2 * k + 1));
+        acc = Real::ONE.sub(theta2.mul(acc).div(div));
+        k -= 1;
+    }
+    theta.mul(acc)
+}
+
+// 0 ≤ theta ≤ π/4
+const fn cos_small(theta: Real) -> Real {
+    let theta2 = theta.mul(theta);
+    // cos θ = 1 - θ²/(1×2) (1 - θ²/(3×4) (1 - ...))
+    let mut acc = Real::ONE;
+    let mut k = SIN_COS_TERMS;
+    while k > 0 {
+        let div = Real::from_u32((2 * k - 1) * (2 * k));
+        acc = Real::ONE.sub(theta2.mul(acc).div(div));
+        k -= 1;
+    }
+    acc
+}
+
+// Returns sin(frac × π/2) if !cos_of_frac, otherwise cos(frac × π/2).
+const fn sin_or_cos_frac(reduced: &Reduced, cos_of_frac: bool) -> Real {
+    if cos_of_frac != reduced.swap {
+        cos_small(reduced.theta)
+    } else {
+        sin_small(reduced.theta)
+    }
+}
+
+// |x| must be < 2^128.
+pub const fn sin(x: Real) -> Real {
+    let reduced = reduce(x);
+    // sin(q × π/2 + φ) is sin φ, cos φ, -sin φ, -cos φ for q = 0, 1, 2, 3
+    let abs_sin = sin_or_cos_frac(&reduced, reduced.quadrant % 2 != 0);
+    if (reduced.quadrant >= 2) != x.neg {
+        abs_sin.neg()
+    } else {
+        abs_sin
+    }
+}
+
+// |x| must be < 2^128.
+pub const fn cos(x: Real) -> Real {
+    let reduced = reduce(x);
+    // cos(q × π/2 + φ) is cos φ, -sin φ, -cos φ, sin φ for q = 0, 1, 2, 3
+    let cos = sin_or_cos_frac(&reduced, reduced.quadrant % 2 == 0);
+    if reduced.quadrant == 1 || reduced.quadrant == 2 {
+        cos.neg()
+    } else {
+        cos
+    }
+}
+
+// |x| must be < 2^128.
+pub const fn sin_cos(x: Real) -> (Real, Real) {
+    let reduced = reduce(x);
+    let sin_frac = sin_or_cos_frac(&reduced, false);
+    let cos_frac = sin_or_cos_frac(&reduced, true);
+    let (abs_sin, cos) = match reduced.quadrant {
+        0 => (sin_frac, cos_frac),
+        1 => (cos_frac, sin_frac.neg()),
+        2 => (sin_frac.neg(), cos_frac.neg()),
+        _ => (cos_frac.neg(), sin_frac),
+    };
+    let sin = if x.neg { abs_sin.neg() } else { abs_sin };
+    (sin, cos)
+}
+
+// |x| must be < 2^128.
+pub const fn tan(x: Real) -> Real {
+    let reduced = reduce(x);
+    let sin_frac = sin_or_cos_frac(&reduced, false);
+    let cos_frac = sin_or_cos_frac(&reduced, true);
+    // tan(q × π/2 + φ) is tan φ for even q and -1/tan φ for odd q
+    let (num, den, neg) = if reduced.quadrant % 2 == 0 {
+        (sin_frac, cos_frac, x.neg)
+    } else {
+        (cos_frac, sin_frac, !x.neg)
+    };
+    if den.mant == 0 {
+        // too large to be represented by any fixed-point number
+        return Real {
+            neg,
+            mant: 1 << 127,
+            exp: 128,
+        };
+    }
+    let abs_tan = num.div(den);
+    if neg {
+        abs_tan.neg()
+    } else {
+        abs_tan
+    }
+}
+
+// 0 ≤ t ≤ 1
+const fn atan_unit(t: Real) -> Real {
+    // atan t = atan c + atan u, where c = j/8 is close to t and
+    // u = (t - c)/(1 + tc), so that |u| ≤ 1/16
+    let (j, _) = t.to_abs_u128(3);
+    let (atan_c, u) = if j == 0 {
+        (Real::ZERO, t)
+    } else {
+        let c = Real::from_u128(false, j, 3);
+        let u = t.sub(c).div(Real::ONE.add(t.mul(c)));
+        let atan_c = if j == 8 {
+            frac_pi_4()
+        } else {
+            Real::from_u128(false, ATAN_EIGHTHS[j as usize - 1], 128)
+        };
+        (atan_c, u)
+    };
+
+    // atan u = u (1 - u²/3 + u⁴/5 - …)
+    let u2 = u.mul(u);
+    let mut acc = Real::from_u32(2 * ATAN_TERMS + 1).recip();
+    let mut k = ATAN_TERMS;
+    while k > 0 {
+        k -= 1;
+        acc = Real::from_u32(2 * k + 1).recip().sub(u2.mul(acc));
+    }
+    atan_c.add(u.mul(acc))
+}
+
+pub const fn atan(x: Real) -> Real {
+    let abs = x.abs();
+    let abs_atan = if abs.cmp_abs(Real::ONE).is_le() {
+        atan_unit(abs)
+    } else {
+        frac_pi_2().sub(atan_unit(abs.recip()))
+    };
+    if x.neg {
+        abs_atan.neg()
+    } else {
+        abs_atan
+    }
+}
+
+pub const fn atan2(y: Real, x: Real) -> Real {
+    if y.mant == 0 && x.mant == 0 {
+        return Real::ZERO;
+    }
+    let (ay, ax) = (y.abs(), x.abs());
+    let first_octant = if ay.cmp_abs(ax).is_le() {
+        atan_unit(ay.div(ax))
+    } else {
+        frac_pi_2().sub(atan_unit(ax.div(ay)))
+    };
+    let abs_angle = if x.neg {
+        pi().sub(first_octant)
+    } else {
+        first_octant
+    };
+    if y.neg {
+        abs_angle.neg()
+    } else {
+        abs_angle
+    }
+}
+
+// Returns the arcsine and whether x is outside the domain. If it is, the arcsine
+// of the nearest end of the domain is returned.
+pub const fn asin(x: Real) -> (Real, bool) {
+    if x.cmp_abs(Real::ONE).is_ge() {
+        let abs_asin = frac_pi_2();
+        let asin = if x.neg { abs_asin.neg() } else { abs_asin };
+        return (asin, x.cmp_abs(Real::ONE).is_gt());
+    }
+    (atan2(x, cos_of_asin(x)), false)
+}
+
+// Returns the arccosine and whether x is outside the domain. If it is, the
+// arccosine of the nearest end of the domain is returned.
+pub const fn acos(x: Real) -> (Real, bool) {
+    if x.cmp_abs(Real::ONE).is_ge() {
+        let acos = if x.neg { pi() } else { Real::ZERO };
+        return (acos, x.cmp_abs(Real::ONE).is_gt());
+    }
+    (atan2(cos_of_asin(x), x), false)
+}
+
+// |x| < 1, returns √(1 - x²) computed as √((1 - x)(1 + x)) to avoid
+// cancellation when |x| is close to 1
+const fn cos_of_asin(x: Real) -> Real {
+    Real::ONE.sub(x).mul(Real::ONE.add(x)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::real;
+    use crate::trig;
+    use crate::types::{I16F16, I2F30, I32F32, I4F124, U0F128};
+
+    fn check_i32(val: I16F16) {
+        let x = real::i32::from_bits(val.to_bits(), 16);
+        let (sin, cos) = trig::sin_cos(x);
+        let f = val.to_num::<f64>();
+        for (r, expected) in [(sin, f.sin()), (cos, f.cos())] {
+            let (bits, overflow) = real::i32::to_bits(r, 16);
+            assert!(!overflow);
+            let diff = (bits as f64 / 65536.0 - expected).abs();
+            assert!(diff <= 0.5 / 65536.0 + 1e-12, "{val} {bits} {expected}");
+        }
+        assert_eq!(trig::sin(x).mant, sin.mant);
+        assert_eq!(trig::cos(x).mant, cos.mant);
+
+        let (bits, overflow) = real::i32::to_bits(trig::atan(x), 16);
+        assert!(!overflow);
+        let diff = (bits as f64 / 65536.0 - f.atan()).abs();
+        assert!(diff <= 0.5 / 65536.0 + 1e-12, "{val} {bits}");
+
+        if cos.mant != 0 && f.cos().abs() > 1e-3 {
+            let (bits, overflow) = real::i64::to_bits(trig::tan(x), 16);
+            assert!(!overflow);
+            let diff = (bits as f64 / 65536.0 - f.tan()).abs();
+            assert!(diff <= 0.5 / 65536.0 + 1e-9 * f.tan().abs(), "{val} {bits}");
+        }
+    }
+
+    #[test]
+    fn atan2_i16f16() {
+        let vals = [
+            -7.25, -3.0, -1.0, -0.5, -1e-4, 0.0, 1e-4, 0.5, 1.0, 3.0, 7.25,
+        ];
+        for y in vals {
+            for x in vals {
+                let ry = real::i32::from_bits(I16F16::from_num(y).to_bits(), 16);
+                let rx = real::i32::from_bits(I16F16::from_num(x).to_bits(), 16);
+                let (bits, overflow) = real::i32::to_bits(trig::atan2(ry, rx), 29);
+                assert!(!overflow);
+                let fy = I16F16::from_num(y).to_num::<f64>();
+                let fx = I16F16::from_num(x).to_num::<f64>();
+                let diff = (bits as f64 / 2f64.powi(29) - fy.atan2(fx)).abs();
+                assert!(diff <= 0.5 / 2f64.powi(29) + 1e-14, "{y} {x} {bits}");
+            }
+        }
+    }
+
+    #[test]
+    fn asin_acos_i2f30() {
+        let mut bits = i32::MIN;
+        while bits < i32::MAX - 0x0013_3337 {
+            let val = I2F30::from_bits(bits);
+            let f = val.to_num::<f64>();
+            let x = real::i32::from_bits(bits, 30);
+            let (asin, asin_domain) = trig::asin(x);
+            let (acos, acos_domain) = trig::acos(x);
+            assert_eq!(asin_domain, f.abs() > 1.0);
+            assert_eq!(acos_domain, f.abs() > 1.0);
+            let clamped = f.clamp(-1.0, 1.0);
+            let (asin, _) = real::i64::to_bits(asin, 30);
+            let (acos, _) = real::i64::to_bits(acos, 30);
+            let asin_diff = (asin as f64 / 2f64.powi(30) - clamped.asin()).abs();
+            let acos_diff = (acos as f64 / 2f64.powi(30) - clamped.acos()).abs();
+            // allow for the error of f64 functions near ±1
+            let delta = 0.5 / 2f64.powi(30) + 1e-12;
+            assert!(asin_diff <= delta, "{val} {asin}");
+            assert!(acos_diff <= delta, "{val} {acos}");
+            bits += 0x0013_3337;
+        }
+
+        // asin(1) = π/2, acos(-1) = π
+        let one = real::i128::from_bits(1 << 124, 124);
+        let (asin, _) = real::i128::to_bits(trig::asin(one).0, 124);
+        let (acos, _) = real::i128::to_bits(trig::acos(one.neg()).0, 124);
+        assert!(asin.abs_diff(I4F124::FRAC_PI_2.to_bits()) <= 1);
+        assert!(acos.abs_diff(I4F124::PI.to_bits()) <= 1);
+    }
+
+    #[test]
+    fn sin_cos_i16f16() {
+        let mut bits = i32::MIN;
+        while bits < i32::MAX - 0x0013_3337 {
+            check_i32(I16F16::from_bits(bits));
+            bits += 0x0013_3337;
+        }
+        for bits in -0x4000..0x4000 {
+            check_i32(I16F16::from_bits(bits * 37));
+        }
+    }
+
+    #[test]
+    fn sin_large() {
+        // sin(2^31 - 1) = -0.7249165551...
+        let x = real::i64::from_bits(I32F32::MAX.to_bits() >> 32 << 32, 32);
+        let (bits, _) = real::i64::to_bits(trig::sin(x), 32);
+        let expected = -0.724_916_555_144_556_f64;
+        assert!((bits as f64 / 2f64.powi(32) - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn sin_cos_wide() {
+        // sin(π) is within 2^-120 of 0, cos(π) is within 2^-120 of -1
+        let pi = real::i128::from_bits(I4F124::PI.to_bits(), 124);
+        let (sin, cos) = trig::sin_cos(pi);
+        let (sin, _) = real::i128::to_bits(sin, 124);
+        let (cos, _) = real::i128::to_bits(cos, 124);
+        assert!(sin.unsigned_abs() <= 16);
+        assert!((cos + (1 << 124)).unsigned_abs() <= 16);
+
+        // sin(0.5) = 0.4794255386...
+        let half = real::u128::from_bits(1 << 127, 128);
+        let (sin, _) = real::u128::to_bits(trig::sin(half), 128);
+        let expected = U0F128::from_bits(0x7ABB_A1D1_2C17_BFA1_D92F_0D93_F60D_ED9A);
+        assert!(sin.abs_diff(expected.to_bits()) <= 256);
+    }
+}
@@ -19,6 +19,12 @@ Extra types that do not need to be handled directly.
 These types are used for `where` constraints.
 */
 
+use crate::{helpers::Divisor, traits::FixedBits};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
+    NonZeroU32, NonZeroU64, NonZeroU8, TryFromIntError,
+};
+
 /// Used for constraints conditional on a [`bool`].
 ///
 /// # Examples
@@ -60,3 +66,172 @@ pub struct If<const CONDITION: bool>;
 pub trait True {}
 
 impl True for If<true> {}
+
+/// Used to select the underlying integers of [`FixedI`] and [`FixedU`] from
+/// their number of bits.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::{
+///     traits::FixedBits,
+///     types::extra::{Storage, Width},
+/// };
+/// fn storage_nbits<const NBITS: i32>() -> u32
+/// where
+///     Width<NBITS>: Storage,
+/// {
+///     <<Width<NBITS> as Storage>::Signed as FixedBits>::BITS
+/// }
+///
+/// assert_eq!(storage_nbits::<13>(), 16);
+/// assert_eq!(storage_nbits::<24>(), 32);
+/// ```
+///
+/// [`FixedI`]: crate::FixedI
+/// [`FixedU`]: crate::FixedU
+pub struct Width<const NBITS: i32>;
+
+/// This is implemented for [`Width`] when the number of bits is from 1 to 128.
+///
+/// The underlying integers are the smallest primitive integers with at least
+/// `NBITS` bits, and the constants are the bits of values that have `NBITS`
+/// bits.
+pub trait Storage {
+    /// The smallest signed primitive integer with at least `NBITS` bits.
+    type Signed: FixedBits + From<Self::NonZeroSigned> + Divisor<Bits = Self::Signed>;
+    /// The smallest unsigned primitive integer with at least `NBITS` bits.
+    type Unsigned: FixedBits + From<Self::NonZeroUnsigned> + Divisor<Bits = Self::Unsigned>;
+    /// The non-zero version of [`Signed`][Self::Signed].
+    type NonZeroSigned: TryFrom<Self::Signed, Error = TryFromIntError>;
+    /// The non-zero version of [`Unsigned`][Self::Unsigned].
+    type NonZeroUnsigned: TryFrom<Self::Unsigned, Error = TryFromIntError>
+        + Divisor<Bits = Self::Unsigned>;
+    /// The number of unused high bits in the primitive integers.
+    const PAD: u32;
+    /// Zero as a signed integer.
+    const SIGNED_ZERO: Self::Signed;
+    /// One as a signed integer.
+    const SIGNED_ONE: Self::Signed;
+    /// The smallest signed integer that fits in `NBITS` bits.
+    const SIGNED_MIN: Self::Signed;
+    /// The largest signed integer that fits in `NBITS` bits.
+    const SIGNED_MAX: Self::Signed;
+    /// Zero as an unsigned integer.
+    const UNSIGNED_ZERO: Self::Unsigned;
+    /// One as an unsigned integer.
+    const UNSIGNED_ONE: Self::Unsigned;
+    /// The largest unsigned integer that fits in `NBITS` bits.
+    const UNSIGNED_MAX: Self::Unsigned;
+    /// The constants that also depend on the number of fractional bits.
+    type Frac<const FRAC: i32>: StorageFrac<Signed = Self::Signed, Unsigned = Self::Unsigned>;
+}
+
+/// Used to select the constants of [`FixedI`] and [`FixedU`] that depend on
+/// both their number of bits and their number of fractional bits.
+///
+/// [`FixedI`]: crate::FixedI
+/// [`FixedU`]: crate::FixedU
+pub struct WidthFrac<const NBITS: i32, const FRAC: i32>;
+
+/// This is implemented for [`WidthFrac`] when the number of bits is from 1 to
+/// 128.
+///
+/// The constants are the bits of values that have `NBITS` bits, of which `FRAC`
+/// are fractional bits.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::types::extra::{Storage, StorageFrac, Width};
+/// type Frac<const FRAC: i32> = <Width<8> as Storage>::Frac<FRAC>;
+/// assert_eq!(<Frac<4> as StorageFrac>::SIGNED_TRY_ONE, Some(0x10));
+/// assert_eq!(<Frac<7> as StorageFrac>::SIGNED_TRY_ONE, None);
+/// assert_eq!(<Frac<7> as StorageFrac>::SIGNED_TRY_NEG_ONE, Some(-0x80));
+/// assert_eq!(<Frac<7> as StorageFrac>::UNSIGNED_TRY_ONE, Some(0x80));
+/// ```
+pub trait StorageFrac {
+    /// The smallest signed primitive integer with at least `NBITS` bits.
+    type Signed: FixedBits;
+    /// The smallest unsigned primitive integer with at least `NBITS` bits.
+    type Unsigned: FixedBits;
+    /// One as a signed integer with `FRAC` fractional bits if it fits in
+    /// `NBITS` bits, otherwise [`None`].
+    const SIGNED_TRY_ONE: Option<Self::Signed>;
+    /// Negative one as a signed integer with `FRAC` fractional bits if it fits
+    /// in `NBITS` bits, otherwise [`None`].
+    const SIGNED_TRY_NEG_ONE: Option<Self::Signed>;
+    /// One as an unsigned integer with `FRAC` fractional bits if it fits in
+    /// `NBITS` bits, otherwise [`None`].
+    const UNSIGNED_TRY_ONE: Option<Self::Unsigned>;
+}
+
+macro_rules! impl_storage {
+    (
+        $Signed:ident($NonZeroSigned:ident), $Unsigned:ident($NonZeroUnsigned:ident);
+        $($nbits:literal)*
+    ) => { $(
+        impl Storage for Width<$nbits> {
+            type Signed = $Signed;
+            type Unsigned = $Unsigned;
+            type NonZeroSigned = $NonZeroSigned;
+            type NonZeroUnsigned = $NonZeroUnsigned;
+            const PAD: u32 = $Signed::BITS - $nbits;
+            const SIGNED_ZERO: $Signed = 0;
+            const SIGNED_ONE: $Signed = 1;
+            const SIGNED_MIN: $Signed = $Signed::MIN >> Self::PAD;
+            const SIGNED_MAX: $Signed = $Signed::MAX >> Self::PAD;
+            const UNSIGNED_ZERO: $Unsigned = 0;
+            const UNSIGNED_ONE: $Unsigned = 1;
+            const UNSIGNED_MAX: $Unsigned = $Unsigned::MAX >> Self::PAD;
+            type Frac<const FRAC: i32> = WidthFrac<$nbits, FRAC>;
+        }
+
+        impl<const FRAC: i32> StorageFrac for WidthFrac<$nbits, FRAC> {
+            type Signed = $Signed;
+            type Unsigned = $Unsigned;
+            // The range is empty when there is only one bit.
+            #[allow(clippy::impossible_comparisons)]
+            const SIGNED_TRY_ONE: Option<$Signed> = if 0 <= FRAC && FRAC < $nbits - 1 {
+                Some(1 << FRAC)
+            } else {
+                None
+            };
+            const SIGNED_TRY_NEG_ONE: Option<$Signed> = if 0 <= FRAC && FRAC < $nbits {
+                Some(-1 << FRAC)
+            } else {
+                None
+            };
+            const UNSIGNED_TRY_ONE: Option<$Unsigned> = if 0 <= FRAC && FRAC < $nbits {
+                Some(1 << FRAC)
+            } else {
+                None
+            };
+        }
+    )* };
+}
+
+impl_storage! { i8(NonZeroI8), u8(NonZeroU8); 1 2 3 4 5 6 7 8 }
+impl_storage! { i16(NonZeroI16), u16(NonZeroU16); 9 10 11 12 13 14 15 16 }
+impl_storage! {
+    i32(NonZeroI32), u32(NonZeroU32);
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+}
+impl_storage! {
+    i64(NonZeroI64), u64(NonZeroU64);
+    33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48
+    49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64
+}
+impl_storage! {
+    i128(NonZeroI128), u128(NonZeroU128);
+    65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80
+    81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96
+    97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112
+    113 114 115 116 117 118 119 120 121 122 123 124 125 126 127 128
+}
@@ -18,7 +18,7 @@ use crate::{
     traits::{Fixed, FixedBits, FixedBoundFrac, FixedSigned, FixedUnsigned, FromFixed, ToFixed},
     types::extra::{If, True},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    FixedU8, RoundingMode,
 };
 use core::{
     fmt::{
@@ -1181,6 +1181,43 @@ impl<F: Fixed> Unwrapped<F> {
         Unwrapped(self.0.unwrapped_hypot(other.0))
     }
 
+    /// Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+    /// panicking on overflow.
+    ///
+    /// The angle is in radians, and is returned with `RET_FRAC` fractional
+    /// bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_atan2][FixedI32::unwrapped_atan2]</code> and
+    /// <code>FixedU32::[unwrapped\_atan2][FixedU32::unwrapped_atan2]</code>.    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, I3F29},
+    ///     Unwrapped,
+    /// };
+    /// let one = Unwrapped(I16F16::ONE);
+    /// let angle: Unwrapped<I3F29> = one.atan2(one);
+    /// assert!(angle.0.dist(I3F29::FRAC_PI_4) <= I3F29::DELTA);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn atan2<const RET_FRAC: i32>(
+        self,
+        other: Unwrapped<F>,
+    ) -> Unwrapped<<F::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Unwrapped(self.0.unwrapped_atan2(other.0))
+    }
+
     /// Returns the next multiple of `other`.
     ///
     /// See also
@@ -1600,45 +1637,91 @@ impl<F: FixedBoundFrac> Unwrapped<F> {
         Unwrapped(self.0.unwrapped_sqrt())
     }
 
-    /// Integer base-10 logarithm, rounded down.
+    /// Returns the square root, rounded according to the given [`RoundingMode`].
     ///
-    /// See also <code>FixedI32::[int\_log10][FixedI32::int_log10]</code> and
-    /// <code>FixedU32::[int\_log10][FixedU32::int_log10]</code>.
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sqrt\_round][FixedI32::unwrapped_sqrt_round]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_sqrt\_round][FixedU32::unwrapped_sqrt_round]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the fixed-point number is ≤&nbsp;0.
+    /// Panics if the number is negative, or on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::U0F32, RoundingMode, Unwrapped};
+    /// let max = Unwrapped(U0F32::MAX);
+    /// assert_eq!(max.sqrt_round(RoundingMode::Floor), max);
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::U0F32, RoundingMode, Unwrapped};
+    /// let max = Unwrapped(U0F32::MAX);
+    /// // √MAX rounded up is 1, which overflows
+    /// let _overflow = max.sqrt_round(RoundingMode::Ceil);
+    /// ```
     #[inline]
     #[track_caller]
-    #[doc(alias("ilog10"))]
-    pub fn int_log10(self) -> i32 {
-        self.0.int_log10()
+    pub fn sqrt_round(self, mode: RoundingMode) -> Self {
+        Unwrapped(self.0.unwrapped_sqrt_round(mode))
     }
 
-    /// Integer logarithm to the specified base, rounded down.
+    /// Returns the cube root.
     ///
-    /// See also <code>FixedI32::[int\_log][FixedI32::int_log]</code> and
-    /// <code>FixedU32::[int\_log][FixedU32::int_log]</code>.
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cbrt][FixedI32::unwrapped_cbrt]</code> and
+    /// <code>FixedU32::[unwrapped\_cbrt][FixedU32::unwrapped_cbrt]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the fixed-point number is ≤&nbsp;0 or if the base is <&nbsp;2.
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::from_num(-8)).cbrt().0, -2);
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I0F32, Unwrapped};
+    /// let u = Unwrapped(I0F32::from_num(0.125));
+    /// let _overflow = u.cbrt();
+    /// ```
     #[inline]
     #[track_caller]
-    #[doc(alias("ilog"))]
-    pub fn int_log(self, base: u32) -> i32 {
-        self.0.int_log(base)
+    pub fn cbrt(self) -> Self {
+        Unwrapped(self.0.unwrapped_cbrt())
     }
 
-    /// Returns the reciprocal (inverse), 1/`self`.
+    /// Returns the <i>n</i>th root.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_recip][FixedI32::unwrapped_recip]</code> and
-    /// <code>FixedU32::[unwrapped\_recip][FixedU32::unwrapped_recip]</code>.
+    /// <code>FixedI32::[unwrapped\_nth\_root][FixedI32::unwrapped_nth_root]</code> and
+    /// <code>FixedU32::[unwrapped\_nth\_root][FixedU32::unwrapped_nth_root]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if `self` is zero or on overflow.
+    /// Panics if `n` is zero, if the number is negative and `n` is even, or if
+    /// the result does not fit.
     ///
     /// # Examples
     ///
@@ -1646,26 +1729,970 @@ impl<F: FixedBoundFrac> Unwrapped<F> {
     /// #![feature(generic_const_exprs)]
     /// # #![allow(incomplete_features)]
     ///
-    /// use fixed::{types::I8F24, Unwrapped};
-    /// let quarter = Unwrapped(I8F24::from_num(0.25));
-    /// assert_eq!(quarter.recip(), Unwrapped(I8F24::from_num(4)));
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::from_num(16)).nth_root(4).0, 2);
+    /// ```
+    ///
+    /// The following panics because the input value is negative and `n` is
+    /// even.
+    ///
+    /// ```should_panic
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let neg = Unwrapped(I16F16::from_num(-1));
+    /// let _root_neg = neg.nth_root(4);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn nth_root(self, n: u32) -> Self {
+        Unwrapped(self.0.unwrapped_nth_root(n))
+    }
+
+    /// Returns the reciprocal square root, 1/√`self`, with `RET_FRAC`
+    /// fractional bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_rsqrt][FixedI32::unwrapped_rsqrt]</code> and
+    /// <code>FixedU32::[unwrapped\_rsqrt][FixedU32::unwrapped_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative, or if the result does not
+    /// fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, I24F8},
+    ///     Unwrapped,
+    /// };
+    /// let x = Unwrapped(I16F16::from_num(0.0625));
+    /// let r: Unwrapped<I24F8> = x.rsqrt();
+    /// assert_eq!(r.0, 4);
     /// ```
     ///
     /// The following panics because of overflow.
     ///
-    /// ```rust,should_panic
+    /// ```should_panic
     /// #![feature(generic_const_exprs)]
     /// # #![allow(incomplete_features)]
     ///
-    /// use fixed::{types::I8F24, Unwrapped};
-    /// let frac_1_512 = Unwrapped(I8F24::ONE / 512);
-    /// let _overflow = frac_1_512.recip();
+    /// use fixed::{
+    ///     types::{I16F16, I1F31},
+    ///     Unwrapped,
+    /// };
+    /// let x = Unwrapped(I16F16::from_num(0.25));
+    /// let _overflow: Unwrapped<I1F31> = x.rsqrt();
     /// ```
     #[inline]
     #[track_caller]
-    #[must_use]
-    pub fn recip(self) -> Unwrapped<F> {
-        Unwrapped(self.0.unwrapped_recip())
+    pub fn rsqrt<const RET_FRAC: i32>(self) -> Unwrapped<<F::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Unwrapped(self.0.unwrapped_rsqrt())
+    }
+
+    /// Sine, with `self` in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sin][FixedI32::unwrapped_sin]</code> and
+    /// <code>FixedU32::[unwrapped\_sin][FixedU32::unwrapped_sin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let x = Unwrapped(I16F16::FRAC_PI_2);
+    /// assert_eq!(x.sin().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn sin(self) -> Self {
+        Unwrapped(self.0.unwrapped_sin())
+    }
+
+    /// Cosine, with `self` in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cos][FixedI32::unwrapped_cos]</code> and
+    /// <code>FixedU32::[unwrapped\_cos][FixedU32::unwrapped_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).cos().0, 1);
+    /// ```
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I0F32, Unwrapped};
+    /// let _overflow = Unwrapped(I0F32::ZERO).cos();
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn cos(self) -> Self {
+        Unwrapped(self.0.unwrapped_cos())
+    }
+
+    /// Simultaneous sine and cosine, with `self` in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sin\_cos][FixedI32::unwrapped_sin_cos]</code> and
+    /// <code>FixedU32::[unwrapped\_sin\_cos][FixedU32::unwrapped_sin_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let (sin, cos) = Unwrapped(I16F16::ZERO).sin_cos();
+    /// assert_eq!(sin.0, 0);
+    /// assert_eq!(cos.0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.0.unwrapped_sin_cos();
+        (Unwrapped(sin), Unwrapped(cos))
+    }
+
+    /// Tangent, with `self` in radians, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_tan][FixedI32::unwrapped_tan]</code> and
+    /// <code>FixedU32::[unwrapped\_tan][FixedU32::unwrapped_tan]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).tan().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn tan(self) -> Self {
+        Unwrapped(self.0.unwrapped_tan())
+    }
+
+    /// Arctangent, returning the angle in radians.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[atan][FixedI32::atan]</code> and
+    /// <code>FixedU32::[atan][FixedU32::atan]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).atan().0, 0);
+    /// ```
+    #[inline]
+    pub fn atan(self) -> Self {
+        Unwrapped(self.0.atan())
+    }
+
+    /// Arcsine, returning the angle in radians, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_asin][FixedI32::unwrapped_asin]</code> and
+    /// <code>FixedU32::[unwrapped\_asin][FixedU32::unwrapped_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the domain or if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).asin().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn asin(self) -> Self {
+        Unwrapped(self.0.unwrapped_asin())
+    }
+
+    /// Arccosine, returning the angle in radians, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_acos][FixedI32::unwrapped_acos]</code> and
+    /// <code>FixedU32::[unwrapped\_acos][FixedU32::unwrapped_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the domain or if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ONE).acos().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn acos(self) -> Self {
+        Unwrapped(self.0.unwrapped_acos())
+    }
+
+    /// Exponential, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp][FixedI32::unwrapped_exp]</code> and
+    /// <code>FixedU32::[unwrapped\_exp][FixedU32::unwrapped_exp]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).exp().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp(self) -> Self {
+        Unwrapped(self.0.unwrapped_exp())
+    }
+
+    /// Base-2 exponential, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp2][FixedI32::unwrapped_exp2]</code> and
+    /// <code>FixedU32::[unwrapped\_exp2][FixedU32::unwrapped_exp2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::from_num(3)).exp2().0, 8);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp2(self) -> Self {
+        Unwrapped(self.0.unwrapped_exp2())
+    }
+
+    /// Exponential minus one, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp\_m1][FixedI32::unwrapped_exp_m1]</code> and
+    /// <code>FixedU32::[unwrapped\_exp\_m1][FixedU32::unwrapped_exp_m1]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).exp_m1().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp_m1(self) -> Self {
+        Unwrapped(self.0.unwrapped_exp_m1())
+    }
+
+    /// Natural logarithm, returned with `RET_FRAC` fractional bits, panicking on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_ln][FixedI32::unwrapped_ln]</code> and
+    /// <code>FixedU32::[unwrapped\_ln][FixedU32::unwrapped_ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, U16F16},
+    ///     Unwrapped,
+    /// };
+    /// let log: Unwrapped<I16F16> = Unwrapped(U16F16::from_num(1)).ln();
+    /// assert_eq!(log.0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn ln<const RET_FRAC: i32>(
+        self,
+    ) -> Unwrapped<<<F::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Unwrapped(self.0.unwrapped_ln())
+    }
+
+    /// Base-2 logarithm, returned with `RET_FRAC` fractional bits, panicking on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_log2][FixedI32::unwrapped_log2]</code> and
+    /// <code>FixedU32::[unwrapped\_log2][FixedU32::unwrapped_log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, U16F16},
+    ///     Unwrapped,
+    /// };
+    /// let log: Unwrapped<I16F16> = Unwrapped(U16F16::from_num(8)).log2();
+    /// assert_eq!(log.0, 3);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn log2<const RET_FRAC: i32>(
+        self,
+    ) -> Unwrapped<<<F::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Unwrapped(self.0.unwrapped_log2())
+    }
+
+    /// Base-10 logarithm, returned with `RET_FRAC` fractional bits, panicking on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_log10][FixedI32::unwrapped_log10]</code> and
+    /// <code>FixedU32::[unwrapped\_log10][FixedU32::unwrapped_log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, U16F16},
+    ///     Unwrapped,
+    /// };
+    /// let log: Unwrapped<I16F16> = Unwrapped(U16F16::from_num(100)).log10();
+    /// assert_eq!(log.0, 2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn log10<const RET_FRAC: i32>(
+        self,
+    ) -> Unwrapped<<<F::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Unwrapped(self.0.unwrapped_log10())
+    }
+
+    /// Raises `self` to the integer power `exp`, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powi][FixedI32::unwrapped_powi]</code> and
+    /// <code>FixedU32::[unwrapped\_powi][FixedU32::unwrapped_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit, or if `self` is zero and `exp` is
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let x = Unwrapped(I16F16::from_num(1.5));
+    /// assert_eq!(x.powi(3), Unwrapped(I16F16::from_num(3.375)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn powi(self, exp: i32) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_powi(exp))
+    }
+
+    /// Raises `self` to the fixed-point power `exp`, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powf][FixedI32::unwrapped_powf]</code> and
+    /// <code>FixedU32::[unwrapped\_powf][FixedU32::unwrapped_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit, on a domain error, or if `self` is
+    /// zero and `exp` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let x = Unwrapped(I16F16::from_num(4));
+    /// let exp = Unwrapped(I16F16::from_num(0.5));
+    /// assert_eq!(x.powf(exp), Unwrapped(I16F16::from_num(2)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn powf(self, exp: Unwrapped<F>) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_powf(exp.0))
+    }
+
+    /// Hyperbolic sine, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sinh][FixedI32::unwrapped_sinh]</code> and
+    /// <code>FixedU32::[unwrapped\_sinh][FixedU32::unwrapped_sinh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).sinh().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn sinh(self) -> Self {
+        Unwrapped(self.0.unwrapped_sinh())
+    }
+
+    /// Hyperbolic cosine, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cosh][FixedI32::unwrapped_cosh]</code> and
+    /// <code>FixedU32::[unwrapped\_cosh][FixedU32::unwrapped_cosh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).cosh().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn cosh(self) -> Self {
+        Unwrapped(self.0.unwrapped_cosh())
+    }
+
+    /// Hyperbolic tangent.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[tanh][FixedI32::tanh]</code> and
+    /// <code>FixedU32::[tanh][FixedU32::tanh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).tanh().0, 0);
+    /// ```
+    #[inline]
+    pub fn tanh(self) -> Self {
+        Unwrapped(self.0.tanh())
+    }
+
+    /// Inverse hyperbolic sine.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[asinh][FixedI32::asinh]</code> and
+    /// <code>FixedU32::[asinh][FixedU32::asinh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).asinh().0, 0);
+    /// ```
+    #[inline]
+    pub fn asinh(self) -> Self {
+        Unwrapped(self.0.asinh())
+    }
+
+    /// Inverse hyperbolic cosine.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[acosh][FixedI32::acosh]</code> and
+    /// <code>FixedU32::[acosh][FixedU32::acosh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is less than one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ONE).acosh().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn acosh(self) -> Self {
+        Unwrapped(self.0.acosh())
+    }
+
+    /// Inverse hyperbolic tangent, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_atanh][FixedI32::unwrapped_atanh]</code> and
+    /// <code>FixedU32::[unwrapped\_atanh][FixedU32::unwrapped_atanh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is outside the domain or if the result does not
+    /// fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).atanh().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn atanh(self) -> Self {
+        Unwrapped(self.0.unwrapped_atanh())
+    }
+
+    /// Error function, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_erf][FixedI32::unwrapped_erf]</code> and
+    /// <code>FixedU32::[unwrapped\_erf][FixedU32::unwrapped_erf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).erf().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn erf(self) -> Self {
+        Unwrapped(self.0.unwrapped_erf())
+    }
+
+    /// Complementary error function, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_erfc][FixedI32::unwrapped_erfc]</code> and
+    /// <code>FixedU32::[unwrapped\_erfc][FixedU32::unwrapped_erfc]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).erfc().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn erfc(self) -> Self {
+        Unwrapped(self.0.unwrapped_erfc())
+    }
+
+    /// Logistic sigmoid, returned with `RET_FRAC` fractional bits.
+    ///
+    /// The result is unsigned, and saturates if it does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[sigmoid][FixedI32::sigmoid]</code> and
+    /// <code>FixedU32::[sigmoid][FixedU32::sigmoid]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, U16F16},
+    ///     Unwrapped,
+    /// };
+    /// let half: Unwrapped<U16F16> = Unwrapped(I16F16::ZERO).sigmoid();
+    /// assert_eq!(half.0, 0.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sigmoid<const RET_FRAC: i32>(
+        self,
+    ) -> Unwrapped<<<F::Unsigned as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Unwrapped(self.0.sigmoid())
+    }
+
+    /// Softplus, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[softplus][FixedI32::softplus]</code> and
+    /// <code>FixedU32::[softplus][FixedU32::softplus]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::MAX).softplus(), Unwrapped(I16F16::MAX));
+    /// ```
+    #[inline]
+    pub fn softplus(self) -> Self {
+        Unwrapped(self.0.softplus())
+    }
+
+    /// Gaussian error linear unit (GELU).
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[gelu][FixedI32::gelu]</code> and
+    /// <code>FixedU32::[gelu][FixedU32::gelu]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).gelu().0, 0);
+    /// ```
+    #[inline]
+    pub fn gelu(self) -> Self {
+        Unwrapped(self.0.gelu())
+    }
+
+    /// Sine using a lookup table with `N` entries, with `self` in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sin\_lut][FixedI32::unwrapped_sin_lut]</code> and
+    /// <code>FixedU32::[unwrapped\_sin\_lut][FixedU32::unwrapped_sin_lut]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// let x = Unwrapped(I16F16::FRAC_PI_2);
+    /// assert_eq!(x.sin_lut::<256>().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn sin_lut<const N: usize>(self) -> Self {
+        Unwrapped(self.0.unwrapped_sin_lut::<N>())
+    }
+
+    /// Cosine using a lookup table with `N` entries, with `self` in
+    /// radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cos\_lut][FixedI32::unwrapped_cos_lut]</code> and
+    /// <code>FixedU32::[unwrapped\_cos\_lut][FixedU32::unwrapped_cos_lut]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::ZERO).cos_lut::<256>().0, 1);
+    /// ```
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I0F32, Unwrapped};
+    /// let _overflow = Unwrapped(I0F32::ZERO).cos_lut::<256>();
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn cos_lut<const N: usize>(self) -> Self {
+        Unwrapped(self.0.unwrapped_cos_lut::<N>())
+    }
+
+    /// Evaluates a polynomial at `self` using Horner’s method.
+    ///
+    /// The coefficients are in ascending order, that is `coeffs[i]` is the
+    /// coefficient of <i>x</i><sup><i>i</i></sup>.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_poly\_eval][FixedI32::unwrapped_poly_eval]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_poly\_eval][FixedU32::unwrapped_poly_eval]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, FixedI32, Unwrapped};
+    /// let coeffs = [FixedI32::<20>::ONE, FixedI32::from_num(0.5)];
+    /// let x = Unwrapped(I16F16::from_num(3));
+    /// assert_eq!(x.poly_eval(&coeffs).0, 2.5);
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, FixedI32, Unwrapped};
+    /// let coeffs = [FixedI32::<20>::ZERO, FixedI32::ZERO, FixedI32::ONE];
+    /// let _overflow = Unwrapped(I16F16::MAX).poly_eval(&coeffs);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn poly_eval<const COEFF_FRAC: i32>(
+        self,
+        coeffs: &[<F::Bits as FixedBits>::Fixed<COEFF_FRAC>],
+    ) -> Self {
+        Unwrapped(self.0.unwrapped_poly_eval(coeffs))
+    }
+
+    /// Integer base-10 logarithm, rounded down.
+    ///
+    /// See also <code>FixedI32::[int\_log10][FixedI32::int_log10]</code> and
+    /// <code>FixedU32::[int\_log10][FixedU32::int_log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed-point number is ≤&nbsp;0.
+    #[inline]
+    #[track_caller]
+    #[doc(alias("ilog10"))]
+    pub fn int_log10(self) -> i32 {
+        self.0.int_log10()
+    }
+
+    /// Integer logarithm to the specified base, rounded down.
+    ///
+    /// See also <code>FixedI32::[int\_log][FixedI32::int_log]</code> and
+    /// <code>FixedU32::[int\_log][FixedU32::int_log]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed-point number is ≤&nbsp;0 or if the base is <&nbsp;2.
+    #[inline]
+    #[track_caller]
+    #[doc(alias("ilog"))]
+    pub fn int_log(self, base: u32) -> i32 {
+        self.0.int_log(base)
+    }
+
+    /// Returns the reciprocal (inverse), 1/`self`.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_recip][FixedI32::unwrapped_recip]</code> and
+    /// <code>FixedU32::[unwrapped\_recip][FixedU32::unwrapped_recip]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I8F24, Unwrapped};
+    /// let quarter = Unwrapped(I8F24::from_num(0.25));
+    /// assert_eq!(quarter.recip(), Unwrapped(I8F24::from_num(4)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I8F24, Unwrapped};
+    /// let frac_1_512 = Unwrapped(I8F24::ONE / 512);
+    /// let _overflow = frac_1_512.recip();
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn recip(self) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_recip())
+    }
+
+    /// Returns the reciprocal (inverse), 1/`self`, rounded according to the
+    /// given [`RoundingMode`].
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_recip\_round][FixedI32::unwrapped_recip_round]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_recip\_round][FixedU32::unwrapped_recip_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I8F24, RoundingMode, Unwrapped};
+    /// let three = Unwrapped(I8F24::from_num(3));
+    /// assert_eq!(three.recip_round(RoundingMode::Floor), Unwrapped(I8F24::from_bits(0x55_5555)));
+    /// assert_eq!(three.recip_round(RoundingMode::Ceil), Unwrapped(I8F24::from_bits(0x55_5556)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I8F24, RoundingMode, Unwrapped};
+    /// let frac_1_512 = Unwrapped(I8F24::ONE / 512);
+    /// let _overflow = frac_1_512.recip_round(RoundingMode::Ceil);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn recip_round(self, mode: RoundingMode) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_recip_round(mode))
     }
 
     /// Euclidean division.
@@ -0,0 +1,1777 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::dot::saturate;
+use crate::traits::{Fixed, FixedBoundFrac};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Generates the checked, wrapping and unwrapped variants of an operation from
+// its overflowing variant.
+macro_rules! vec_op {
+    (
+        $Vec:ident,
+        {op, result} = {$op:literal, $result:literal},
+        fn($($arg:ident: $Arg:ty),*) -> $Ret:ty,
+        {checked, wrapping, unwrapped, overflowing} =
+            {$checked:ident, $wrapping:ident, $unwrapped:ident, $overflowing:ident},
+        {lets, lhs, args, ans} = {$lets:expr, $lhs:literal, $args:literal, $ans:expr},
+        {over_lets, over_lhs, over_args, wrap} =
+            {$over_lets:expr, $over_lhs:literal, $over_args:literal, $wrap:expr},
+    ) => {
+        comment! {
+            "Checked ", $op, ". Returns the ", $result, ", or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+", $lets, $over_lets,
+            "assert_eq!(", $lhs, ".", stringify!($checked), "(", $args, "), Some(", $ans, "));
+assert_eq!(", $over_lhs, ".", stringify!($checked), "(", $over_args, "), None);
+```
+";
+            #[inline]
+            #[must_use]
+            pub fn $checked(self $(, $arg: $Arg)*) -> Option<$Ret> {
+                match self.$overflowing($($arg),*) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        comment! {
+            "Wrapping ", $op, ". Returns the ", $result, ", wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+", $lets, $over_lets, "assert_eq!(", $lhs, ".", stringify!($wrapping), "(", $args, "), ", $ans, ");
+assert_eq!(", $over_lhs, ".", stringify!($wrapping), "(", $over_args, "), ", $wrap, ");
+```
+";
+            #[inline]
+            #[must_use]
+            pub fn $wrapping(self $(, $arg: $Arg)*) -> $Ret {
+                self.$overflowing($($arg),*).0
+            }
+        }
+
+        comment! {
+            "Unwrapped ", $op, ". Returns the ", $result, ", panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+", $lets, "assert_eq!(", $lhs, ".", stringify!($unwrapped), "(", $args, "), ", $ans, ");
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+", $over_lets, "let _overflow = ", $over_lhs, ".", stringify!($unwrapped), "(", $over_args, ");
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn $unwrapped(self $(, $arg: $Arg)*) -> $Ret {
+                match self.$overflowing($($arg),*) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+    };
+}
+
+// Generates the items common to all vector types. The string parameters are
+// used in the examples: a and b are two vectors, max and min have all their
+// components set to MAX and MIN, and the rest are the results of operations
+// on them.
+macro_rules! fixed_vec {
+    (
+        $Vec:ident[$n:literal] { $($c:ident),* },
+        {a, b, arr_a} = {$a:literal, $b:literal, $arr_a:literal},
+        {max, min, delta, max_wrapped} = {$max:literal, $min:literal, $delta:literal, $max_wrapped:literal},
+        {sum, diff, neg, scaled, lerp} = {$sum:literal, $diff:literal, $neg:literal, $scaled:literal, $lerp:literal},
+        {dot, max_dot_wrapped} = {$dot:literal, $max_dot_wrapped:literal},
+        {normalized} = {$normalized:literal},
+    ) => {
+        impl<F: Fixed> $Vec<F> {
+            comment! {
+                "The zero vector.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+assert_eq!(<[I16F16; ", $n, "]>::from(", stringify!($Vec), "::<I16F16>::ZERO), [I16F16::ZERO; ", $n, "]);
+```
+";
+                pub const ZERO: $Vec<F> = $Vec { $($c: F::ZERO),* };
+            }
+
+            comment! {
+                "Creates a vector from its components.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+assert_eq!(<[I16F16; ", $n, "]>::from(a), ", $arr_a, ");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn new($($c: F),*) -> $Vec<F> {
+                    $Vec { $($c),* }
+                }
+            }
+
+            comment! {
+                "Multiplies all the components by a scalar.
+
+This is equivalent to the `*` operator with a right-hand side of type `F`.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+assert_eq!(a.scale(I16F16::from_num(0.5)), ", $scaled, ");
+assert_eq!(a * I16F16::from_num(0.5), ", $scaled, ");
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn scale(self, factor: F) -> $Vec<F> {
+                    let (ans, overflow) = self.overflowing_scale(factor);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Returns the dot product.
+
+The products of the components are computed exactly and summed in double width,
+so the result is rounded down only once.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.dot(b), ", $dot, ");
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn dot(self, rhs: $Vec<F>) -> F {
+                    let (ans, overflow) = self.overflowing_dot(rhs);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            vec_op! {
+                $Vec,
+                {op, result} = {"addition", "sum"},
+                fn(rhs: $Vec<F>) -> $Vec<F>,
+                {checked, wrapping, unwrapped, overflowing} =
+                    {checked_add, wrapping_add, unwrapped_add, overflowing_add},
+                {lets, lhs, args, ans} = {
+                    concat!("let a = ", $a, ";\nlet b = ", $b, ";\n"),
+                    "a",
+                    "b",
+                    $sum
+                },
+                {over_lets, over_lhs, over_args, wrap} = {
+                    concat!("let max = ", $max, ";\n"),
+                    "max",
+                    "max",
+                    $max_wrapped
+                },
+            }
+
+            comment! {
+                "Saturating addition. Returns the sum, saturating each component on
+overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.saturating_add(b), ", $sum, ");
+let max = ", $max, ";
+assert_eq!(max.saturating_add(max), max);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_add(self, rhs: $Vec<F>) -> $Vec<F> {
+                    $Vec { $($c: self.$c.saturating_add(rhs.$c)),* }
+                }
+            }
+
+            comment! {
+                "Overflowing addition.
+
+Returns a [tuple] of the sum and a [`bool`] indicating whether an overflow has
+occurred in any component. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.overflowing_add(b), (", $sum, ", false));
+let max = ", $max, ";
+assert_eq!(max.overflowing_add(max), (", $max_wrapped, ", true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_add(self, rhs: $Vec<F>) -> ($Vec<F>, bool) {
+                    let mut overflow = false;
+                    let ans = $Vec {
+                        $($c: {
+                            let (val, o) = self.$c.overflowing_add(rhs.$c);
+                            overflow |= o;
+                            val
+                        }),*
+                    };
+                    (ans, overflow)
+                }
+            }
+
+            vec_op! {
+                $Vec,
+                {op, result} = {"subtraction", "difference"},
+                fn(rhs: $Vec<F>) -> $Vec<F>,
+                {checked, wrapping, unwrapped, overflowing} =
+                    {checked_sub, wrapping_sub, unwrapped_sub, overflowing_sub},
+                {lets, lhs, args, ans} = {
+                    concat!("let a = ", $a, ";\nlet b = ", $b, ";\n"),
+                    "a",
+                    "b",
+                    $diff
+                },
+                {over_lets, over_lhs, over_args, wrap} = {
+                    concat!("let max = ", $max, ";\nlet min = ", $min, ";\n"),
+                    "min",
+                    "max",
+                    $delta
+                },
+            }
+
+            comment! {
+                "Saturating subtraction. Returns the difference, saturating each
+component on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.saturating_sub(b), ", $diff, ");
+let max = ", $max, ";
+let min = ", $min, ";
+assert_eq!(min.saturating_sub(max), min);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_sub(self, rhs: $Vec<F>) -> $Vec<F> {
+                    $Vec { $($c: self.$c.saturating_sub(rhs.$c)),* }
+                }
+            }
+
+            comment! {
+                "Overflowing subtraction.
+
+Returns a [tuple] of the difference and a [`bool`] indicating whether an
+overflow has occurred in any component. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.overflowing_sub(b), (", $diff, ", false));
+let max = ", $max, ";
+let min = ", $min, ";
+assert_eq!(min.overflowing_sub(max), (", $delta, ", true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_sub(self, rhs: $Vec<F>) -> ($Vec<F>, bool) {
+                    let mut overflow = false;
+                    let ans = $Vec {
+                        $($c: {
+                            let (val, o) = self.$c.overflowing_sub(rhs.$c);
+                            overflow |= o;
+                            val
+                        }),*
+                    };
+                    (ans, overflow)
+                }
+            }
+
+            vec_op! {
+                $Vec,
+                {op, result} = {"negation", "negated vector"},
+                fn() -> $Vec<F>,
+                {checked, wrapping, unwrapped, overflowing} =
+                    {checked_neg, wrapping_neg, unwrapped_neg, overflowing_neg},
+                {lets, lhs, args, ans} = {
+                    concat!("let a = ", $a, ";\n"),
+                    "a",
+                    "",
+                    $neg
+                },
+                {over_lets, over_lhs, over_args, wrap} = {
+                    concat!("let min = ", $min, ";\n"),
+                    "min",
+                    "",
+                    "min"
+                },
+            }
+
+            comment! {
+                "Saturating negation. Returns the negated vector, saturating each
+component on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+assert_eq!(a.saturating_neg(), ", $neg, ");
+let max = ", $max, ";
+let min = ", $min, ";
+assert_eq!(min.saturating_neg(), max);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_neg(self) -> $Vec<F> {
+                    $Vec { $($c: self.$c.saturating_neg()),* }
+                }
+            }
+
+            comment! {
+                "Overflowing negation.
+
+Returns a [tuple] of the negated vector and a [`bool`] indicating whether an
+overflow has occurred in any component. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+assert_eq!(a.overflowing_neg(), (", $neg, ", false));
+let min = ", $min, ";
+assert_eq!(min.overflowing_neg(), (min, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_neg(self) -> ($Vec<F>, bool) {
+                    let mut overflow = false;
+                    let ans = $Vec {
+                        $($c: {
+                            let (val, o) = self.$c.overflowing_neg();
+                            overflow |= o;
+                            val
+                        }),*
+                    };
+                    (ans, overflow)
+                }
+            }
+
+            vec_op! {
+                $Vec,
+                {op, result} = {"multiplication by a scalar", "product"},
+                fn(factor: F) -> $Vec<F>,
+                {checked, wrapping, unwrapped, overflowing} =
+                    {checked_scale, wrapping_scale, unwrapped_scale, overflowing_scale},
+                {lets, lhs, args, ans} = {
+                    concat!("let a = ", $a, ";\n"),
+                    "a",
+                    "I16F16::from_num(0.5)",
+                    $scaled
+                },
+                {over_lets, over_lhs, over_args, wrap} = {
+                    concat!("let max = ", $max, ";\n"),
+                    "max",
+                    "I16F16::from_num(2)",
+                    $max_wrapped
+                },
+            }
+
+            comment! {
+                "Saturating multiplication by a scalar. Returns the product,
+saturating each component on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+assert_eq!(a.saturating_scale(I16F16::from_num(0.5)), ", $scaled, ");
+let max = ", $max, ";
+let min = ", $min, ";
+assert_eq!(max.saturating_scale(I16F16::from_num(-2)), min);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_scale(self, factor: F) -> $Vec<F> {
+                    $Vec { $($c: self.$c.saturating_mul(factor)),* }
+                }
+            }
+
+            comment! {
+                "Overflowing multiplication by a scalar.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an overflow
+has occurred in any component. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+assert_eq!(a.overflowing_scale(I16F16::from_num(0.5)), (", $scaled, ", false));
+let max = ", $max, ";
+assert_eq!(max.overflowing_scale(I16F16::from_num(2)), (", $max_wrapped, ", true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_scale(self, factor: F) -> ($Vec<F>, bool) {
+                    let mut overflow = false;
+                    let ans = $Vec {
+                        $($c: {
+                            let (val, o) = self.$c.overflowing_mul(factor);
+                            overflow |= o;
+                            val
+                        }),*
+                    };
+                    (ans, overflow)
+                }
+            }
+
+            vec_op! {
+                $Vec,
+                {op, result} = {"dot product", "dot product"},
+                fn(rhs: $Vec<F>) -> F,
+                {checked, wrapping, unwrapped, overflowing} =
+                    {checked_dot, wrapping_dot, unwrapped_dot, overflowing_dot},
+                {lets, lhs, args, ans} = {
+                    concat!("let a = ", $a, ";\nlet b = ", $b, ";\n"),
+                    "a",
+                    "b",
+                    concat!("I16F16::from_num(", $dot, ")")
+                },
+                {over_lets, over_lhs, over_args, wrap} = {
+                    concat!("let max = ", $max, ";\n"),
+                    "max",
+                    "max",
+                    concat!("I16F16::from_num(", $max_dot_wrapped, ")")
+                },
+            }
+
+            comment! {
+                "Saturating dot product. Returns the dot product, saturating on
+overflow.
+
+The dot product is computed exactly before it is saturated, so it does not
+saturate if only some of the intermediate products or partial sums do not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.saturating_dot(b), ", $dot, ");
+let max = ", $max, ";
+let min = ", $min, ";
+assert_eq!(max.saturating_dot(max), I16F16::MAX);
+assert_eq!(max.saturating_dot(min), I16F16::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_dot(self, rhs: $Vec<F>) -> F {
+                    let (ans, overflow, neg) = F::sum_prods(&[$((self.$c, rhs.$c)),*], &[]);
+                    saturate(ans, overflow, neg)
+                }
+            }
+
+            comment! {
+                "Overflowing dot product.
+
+Returns a [tuple] of the dot product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.overflowing_dot(b), (I16F16::from_num(", $dot, "), false));
+let max = ", $max, ";
+assert_eq!(max.overflowing_dot(max), (I16F16::from_num(", $max_dot_wrapped, "), true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_dot(self, rhs: $Vec<F>) -> (F, bool) {
+                    let (ans, overflow, _) = F::sum_prods(&[$((self.$c, rhs.$c)),*], &[]);
+                    (ans, overflow)
+                }
+            }
+        }
+
+        impl<F: FixedBoundFrac> $Vec<F> {
+            comment! {
+                "Linear interpolation between `self` and `end`.
+
+Each component is interpolated using [`lerp`], so that the result is
+`self`&nbsp;+&nbsp;`t`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`self`). The
+interpolation is not restricted to values of `t` between zero and one.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.lerp(b, I16F16::ZERO), a);
+assert_eq!(a.lerp(b, I16F16::from_num(0.5)), ", $lerp, ");
+assert_eq!(a.lerp(b, I16F16::ONE), b);
+```
+
+[`lerp`]: crate::FixedI32::lerp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn lerp(self, end: $Vec<F>, t: F) -> $Vec<F> {
+                    let (ans, overflow) = self.overflowing_lerp(end, t);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            vec_op! {
+                $Vec,
+                {op, result} = {"linear interpolation", "interpolated vector"},
+                fn(end: $Vec<F>, t: F) -> $Vec<F>,
+                {checked, wrapping, unwrapped, overflowing} =
+                    {checked_lerp, wrapping_lerp, unwrapped_lerp, overflowing_lerp},
+                {lets, lhs, args, ans} = {
+                    concat!("let a = ", $a, ";\nlet b = ", $b, ";\n"),
+                    "a",
+                    "b, I16F16::from_num(0.5)",
+                    $lerp
+                },
+                {over_lets, over_lhs, over_args, wrap} = {
+                    concat!("let max = ", $max, ";\n"),
+                    "V::ZERO",
+                    "max, I16F16::from_num(2)",
+                    $max_wrapped
+                },
+            }
+
+            comment! {
+                "Saturating linear interpolation. Returns the interpolated vector,
+saturating each component on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.saturating_lerp(b, I16F16::from_num(0.5)), ", $lerp, ");
+let max = ", $max, ";
+assert_eq!(V::ZERO.saturating_lerp(max, I16F16::from_num(2)), max);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_lerp(self, end: $Vec<F>, t: F) -> $Vec<F> {
+                    $Vec { $($c: t.saturating_lerp(self.$c, end.$c)),* }
+                }
+            }
+
+            comment! {
+                "Overflowing linear interpolation.
+
+Returns a [tuple] of the interpolated vector and a [`bool`] indicating whether
+an overflow has occurred in any component. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let b = ", $b, ";
+assert_eq!(a.overflowing_lerp(b, I16F16::from_num(0.5)), (", $lerp, ", false));
+let max = ", $max, ";
+let wrapped = ", $max_wrapped, ";
+assert_eq!(V::ZERO.overflowing_lerp(max, I16F16::from_num(2)), (wrapped, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn overflowing_lerp(self, end: $Vec<F>, t: F) -> ($Vec<F>, bool) {
+                    let mut overflow = false;
+                    let ans = $Vec {
+                        $($c: {
+                            let (val, o) = t.overflowing_lerp(self.$c, end.$c);
+                            overflow |= o;
+                            val
+                        }),*
+                    };
+                    (ans, overflow)
+                }
+            }
+
+            comment! {
+                "Returns the unit vector with the same direction.
+
+Each component is divided by the [length][", stringify!($Vec), "::length], so
+the components of the result are rounded towards zero. If the length does not
+fit, the components are first divided by four, so only vectors whose
+normalized components do not fit fail.
+
+# Panics
+
+Panics if the vector is zero.
+
+When debug assertions are enabled, this method also panics if the result
+overflows, which happens if the number type cannot represent one. When debug
+assertions are not enabled, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ", stringify!($Vec), "};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+let n = a.normalize();
+let expected: [f64; ", $n, "] = ", $normalized, ";
+for (c, e) in <[I16F16; ", $n, "]>::from(n).into_iter().zip(expected) {
+    assert!((c - I16F16::from_num(e)).abs() <= I16F16::DELTA);
+}
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn normalize(self) -> $Vec<F> {
+                    let (v, len) = self.scaled_length();
+                    $Vec { $($c: v.$c / len),* }
+                }
+            }
+
+            comment! {
+                "Checked normalization. Returns the unit vector with the same
+direction, or [`None`] if the vector is zero or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{
+    types::{I16F16, U0F16},
+    ", stringify!($Vec), ",
+};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+assert_eq!(a.checked_normalize(), Some(a.normalize()));
+assert_eq!(V::ZERO.checked_normalize(), None);
+// one cannot be represented by U0F16
+let mut unit = ", stringify!($Vec), "::<U0F16>::ZERO;
+unit.x = U0F16::MAX;
+assert_eq!(unit.checked_normalize(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn checked_normalize(self) -> Option<$Vec<F>> {
+                    let (v, len) = self.scaled_length();
+                    Some($Vec { $($c: v.$c.checked_div(len)?),* })
+                }
+            }
+
+            comment! {
+                "Saturating normalization. Returns the unit vector with the same
+direction, saturating each component on overflow.
+
+# Panics
+
+Panics if the vector is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{
+    types::{I16F16, U0F16},
+    ", stringify!($Vec), ",
+};
+type V = ", stringify!($Vec), "<I16F16>;
+let a = ", $a, ";
+assert_eq!(a.saturating_normalize(), a.normalize());
+// one cannot be represented by U0F16
+let mut unit = ", stringify!($Vec), "::<U0F16>::ZERO;
+unit.x = U0F16::MAX;
+assert_eq!(unit.saturating_normalize(), unit);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn saturating_normalize(self) -> $Vec<F> {
+                    let (v, len) = self.scaled_length();
+                    $Vec { $($c: v.$c.saturating_div(len)),* }
+                }
+            }
+
+            // Returns the vector and its length, with the vector divided by
+            // four if its length does not fit. The components of a vector
+            // divided by four are at most 1/4 of 2^(nbits - 1) in magnitude,
+            // so the length is less than √3/4 × 2^(nbits - 1) and fits.
+            #[inline]
+            fn scaled_length(self) -> ($Vec<F>, F) {
+                match self.checked_length() {
+                    Some(len) => (self, len),
+                    None => {
+                        let v = $Vec { $($c: self.$c >> 2u32),* };
+                        (v, v.length())
+                    }
+                }
+            }
+        }
+
+        macro_rules! op {
+            ($Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $Rhs:ty, $overflowing:ident) => {
+                impl<F: Fixed> $Op<$Rhs> for $Vec<F> {
+                    type Output = $Vec<F>;
+                    #[inline]
+                    #[track_caller]
+                    fn $op(self, rhs: $Rhs) -> $Vec<F> {
+                        let (ans, overflow) = self.$overflowing(rhs);
+                        debug_assert!(!overflow, "overflow");
+                        ans
+                    }
+                }
+
+                impl<F: Fixed> $OpAssign<$Rhs> for $Vec<F> {
+                    #[inline]
+                    #[track_caller]
+                    fn $op_assign(&mut self, rhs: $Rhs) {
+                        *self = $Op::$op(*self, rhs);
+                    }
+                }
+            };
+        }
+
+        op! { Add add, AddAssign add_assign, $Vec<F>, overflowing_add }
+        op! { Sub sub, SubAssign sub_assign, $Vec<F>, overflowing_sub }
+        op! { Mul mul, MulAssign mul_assign, F, overflowing_scale }
+
+        impl<F: Fixed> Neg for $Vec<F> {
+            type Output = $Vec<F>;
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> $Vec<F> {
+                let (ans, overflow) = self.overflowing_neg();
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        impl<F: Fixed> From<[F; $n]> for $Vec<F> {
+            #[inline]
+            fn from([$($c),*]: [F; $n]) -> $Vec<F> {
+                $Vec { $($c),* }
+            }
+        }
+
+        impl<F: Fixed> From<$Vec<F>> for [F; $n] {
+            #[inline]
+            fn from(v: $Vec<F>) -> [F; $n] {
+                [$(v.$c),*]
+            }
+        }
+    };
+}
+
+/**
+A two-dimensional vector with fixed-point components.
+
+Addition, subtraction, negation and multiplication by a scalar are performed on
+each component. For the [dot product][Vec2::dot] and the [cross
+product][Vec2::cross], the products of the components are computed exactly and
+summed in double width, so that the result is rounded down only once.
+
+The operators panic on overflow when debug assertions are enabled and wrap
+otherwise, like the operators of the fixed-point numbers. The methods have
+checked, saturating, wrapping, unwrapped and overflowing variants. The
+saturating variants saturate each component of the result separately.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Vec2};
+type V = Vec2<I16F16>;
+let a = V::new(I16F16::from_num(3), I16F16::from_num(4));
+let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+assert_eq!(a + b, V::new(I16F16::from_num(4.5), I16F16::from_num(2)));
+assert_eq!(a.dot(b), -3.5);
+assert_eq!(a.cross(b), -12);
+assert_eq!(a.length(), 5);
+```
+*/
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Vec2<F> {
+    /// The <i>x</i> component.
+    pub x: F,
+    /// The <i>y</i> component.
+    pub y: F,
+}
+
+/**
+A three-dimensional vector with fixed-point components.
+
+Addition, subtraction, negation and multiplication by a scalar are performed on
+each component. For the [dot product][Vec3::dot] and each component of the
+[cross product][Vec3::cross], the products of the components are computed
+exactly and summed in double width, so that the result is rounded down only
+once.
+
+The operators panic on overflow when debug assertions are enabled and wrap
+otherwise, like the operators of the fixed-point numbers. The methods have
+checked, saturating, wrapping, unwrapped and overflowing variants. The
+saturating variants saturate each component of the result separately.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, Vec3};
+type V = Vec3<I16F16>;
+let a = V::new(I16F16::from_num(2), I16F16::from_num(3), I16F16::from_num(6));
+let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2), I16F16::from_num(1));
+assert_eq!(a.dot(b), 3);
+let cross = V::new(I16F16::from_num(15), I16F16::from_num(7), I16F16::from_num(-8.5));
+assert_eq!(a.cross(b), cross);
+assert_eq!(a.length(), 7);
+```
+*/
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Vec3<F> {
+    /// The <i>x</i> component.
+    pub x: F,
+    /// The <i>y</i> component.
+    pub y: F,
+    /// The <i>z</i> component.
+    pub z: F,
+}
+
+fixed_vec! {
+    Vec2[2] { x, y },
+    {a, b, arr_a} = {
+        "V::new(I16F16::from_num(3), I16F16::from_num(4))",
+        "V::new(I16F16::from_num(1.5), I16F16::from_num(-2))",
+        "[I16F16::from_num(3), I16F16::from_num(4)]"
+    },
+    {max, min, delta, max_wrapped} = {
+        "V::new(I16F16::MAX, I16F16::MAX)",
+        "V::new(I16F16::MIN, I16F16::MIN)",
+        "V::new(I16F16::DELTA, I16F16::DELTA)",
+        "V::new(I16F16::from_bits(-2), I16F16::from_bits(-2))"
+    },
+    {sum, diff, neg, scaled, lerp} = {
+        "V::new(I16F16::from_num(4.5), I16F16::from_num(2))",
+        "V::new(I16F16::from_num(1.5), I16F16::from_num(6))",
+        "V::new(I16F16::from_num(-3), I16F16::from_num(-4))",
+        "V::new(I16F16::from_num(1.5), I16F16::from_num(2))",
+        "V::new(I16F16::from_num(2.25), I16F16::from_num(1))"
+    },
+    {dot, max_dot_wrapped} = {"-3.5", "-2"},
+    {normalized} = {"[0.6, 0.8]"},
+}
+
+fixed_vec! {
+    Vec3[3] { x, y, z },
+    {a, b, arr_a} = {
+        "V::new(I16F16::from_num(2), I16F16::from_num(3), I16F16::from_num(6))",
+        "V::new(I16F16::from_num(1.5), I16F16::from_num(-2), I16F16::from_num(1))",
+        "[I16F16::from_num(2), I16F16::from_num(3), I16F16::from_num(6)]"
+    },
+    {max, min, delta, max_wrapped} = {
+        "V::new(I16F16::MAX, I16F16::MAX, I16F16::MAX)",
+        "V::new(I16F16::MIN, I16F16::MIN, I16F16::MIN)",
+        "V::new(I16F16::DELTA, I16F16::DELTA, I16F16::DELTA)",
+        "V::new(I16F16::from_bits(-2), I16F16::from_bits(-2), I16F16::from_bits(-2))"
+    },
+    {sum, diff, neg, scaled, lerp} = {
+        "V::new(I16F16::from_num(3.5), I16F16::from_num(1), I16F16::from_num(7))",
+        "V::new(I16F16::from_num(0.5), I16F16::from_num(5), I16F16::from_num(5))",
+        "V::new(I16F16::from_num(-2), I16F16::from_num(-3), I16F16::from_num(-6))",
+        "V::new(I16F16::from_num(1), I16F16::from_num(1.5), I16F16::from_num(3))",
+        "V::new(I16F16::from_num(1.75), I16F16::from_num(0.5), I16F16::from_num(3.5))"
+    },
+    {dot, max_dot_wrapped} = {"3", "-3"},
+    {normalized} = {"[2.0 / 7.0, 3.0 / 7.0, 6.0 / 7.0]"},
+}
+
+impl<F: Fixed> Vec2<F> {
+    /// Returns the cross product, that is the <i>z</i> component of the cross
+    /// product of the two vectors extended to three dimensions,
+    /// <i>x</i><sub>1</sub><i>y</i><sub>2</sub>&nbsp;&minus;&nbsp;<i>y</i><sub>1</sub><i>x</i><sub>2</sub>.
+    ///
+    /// The products are computed exactly and summed in double width, so the
+    /// result is rounded down only once.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec2};
+    /// type V = Vec2<I16F16>;
+    /// let a = V::new(I16F16::from_num(3), I16F16::from_num(4));
+    /// let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// assert_eq!(a.cross(b), -12);
+    /// assert_eq!(b.cross(a), 12);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn cross(self, rhs: Vec2<F>) -> F {
+        let (ans, overflow) = self.overflowing_cross(rhs);
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    vec_op! {
+        Vec2,
+        {op, result} = {"cross product", "cross product"},
+        fn(rhs: Vec2<F>) -> F,
+        {checked, wrapping, unwrapped, overflowing} =
+            {checked_cross, wrapping_cross, unwrapped_cross, overflowing_cross},
+        {lets, lhs, args, ans} = {
+            "let a = V::new(I16F16::from_num(3), I16F16::from_num(4));\n\
+             let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2));\n",
+            "a",
+            "b",
+            "I16F16::from_num(-12)"
+        },
+        {over_lets, over_lhs, over_args, wrap} = {
+            "let c = V::new(I16F16::MAX, I16F16::MIN);\n\
+             let max = V::new(I16F16::MAX, I16F16::MAX);\n",
+            "c",
+            "max",
+            "I16F16::from_num(-1.5)"
+        },
+    }
+
+    /// Saturating cross product. Returns the cross product, saturating on
+    /// overflow.
+    ///
+    /// The cross product is computed exactly before it is saturated, so it
+    /// does not saturate if only the intermediate products do not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec2};
+    /// type V = Vec2<I16F16>;
+    /// let a = V::new(I16F16::from_num(3), I16F16::from_num(4));
+    /// let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// assert_eq!(a.saturating_cross(b), -12);
+    /// // MAX² - MAX² = 0 does not overflow
+    /// let max = V::new(I16F16::MAX, I16F16::MAX);
+    /// assert_eq!(max.saturating_cross(max), 0);
+    /// let c = V::new(I16F16::MAX, I16F16::MIN);
+    /// assert_eq!(c.saturating_cross(max), I16F16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_cross(self, rhs: Vec2<F>) -> F {
+        let (ans, overflow, neg) = F::sum_prods(&[(self.x, rhs.y)], &[(self.y, rhs.x)]);
+        saturate(ans, overflow, neg)
+    }
+
+    /// Overflowing cross product.
+    ///
+    /// Returns a [tuple] of the cross product and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec2};
+    /// type V = Vec2<I16F16>;
+    /// let a = V::new(I16F16::from_num(3), I16F16::from_num(4));
+    /// let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2));
+    /// assert_eq!(a.overflowing_cross(b), (I16F16::from_num(-12), false));
+    /// let c = V::new(I16F16::MAX, I16F16::MIN);
+    /// let max = V::new(I16F16::MAX, I16F16::MAX);
+    /// assert_eq!(c.overflowing_cross(max), (I16F16::from_num(-1.5), true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_cross(self, rhs: Vec2<F>) -> (F, bool) {
+        let (ans, overflow, _) = F::sum_prods(&[(self.x, rhs.y)], &[(self.y, rhs.x)]);
+        (ans, overflow)
+    }
+
+    /// Returns the length, also known as the Euclidean norm.
+    ///
+    /// The length is computed using [`hypot`], so it is rounded down and only
+    /// overflows if the result does not fit.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec2};
+    /// let a = Vec2::new(I16F16::from_num(3), I16F16::from_num(-4));
+    /// assert_eq!(a.length(), 5);
+    /// ```
+    ///
+    /// [`hypot`]: crate::FixedI32::hypot
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn length(self) -> F {
+        self.x.hypot(self.y)
+    }
+
+    /// Checked length. Returns the length, or [`None`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec2};
+    /// let a = Vec2::new(I16F16::from_num(3), I16F16::from_num(-4));
+    /// assert_eq!(a.checked_length(), Some(I16F16::from_num(5)));
+    /// let max = Vec2::new(I16F16::MAX, I16F16::MAX);
+    /// assert_eq!(max.checked_length(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_length(self) -> Option<F> {
+        self.x.checked_hypot(self.y)
+    }
+
+    /// Saturating length. Returns the length, saturating on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec2};
+    /// let a = Vec2::new(I16F16::from_num(3), I16F16::from_num(-4));
+    /// assert_eq!(a.saturating_length(), 5);
+    /// let max = Vec2::new(I16F16::MAX, I16F16::MAX);
+    /// assert_eq!(max.saturating_length(), I16F16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_length(self) -> F {
+        self.x.saturating_hypot(self.y)
+    }
+}
+
+impl<F: Fixed> Vec3<F> {
+    /// Returns the cross product.
+    ///
+    /// For each component of the result, the products are computed exactly
+    /// and summed in double width, so the component is rounded down only once.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec3};
+    /// type V = Vec3<I16F16>;
+    /// let a = V::new(I16F16::from_num(2), I16F16::from_num(3), I16F16::from_num(6));
+    /// let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2), I16F16::from_num(1));
+    /// let cross = V::new(I16F16::from_num(15), I16F16::from_num(7), I16F16::from_num(-8.5));
+    /// assert_eq!(a.cross(b), cross);
+    /// assert_eq!(b.cross(a), -cross);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn cross(self, rhs: Vec3<F>) -> Vec3<F> {
+        let (ans, overflow) = self.overflowing_cross(rhs);
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    vec_op! {
+        Vec3,
+        {op, result} = {"cross product", "cross product"},
+        fn(rhs: Vec3<F>) -> Vec3<F>,
+        {checked, wrapping, unwrapped, overflowing} =
+            {checked_cross, wrapping_cross, unwrapped_cross, overflowing_cross},
+        {lets, lhs, args, ans} = {
+            "let a = V::new(I16F16::from_num(2), I16F16::from_num(3), I16F16::from_num(6));\n\
+             let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2), I16F16::from_num(1));\n",
+            "a",
+            "b",
+            "V::new(I16F16::from_num(15), I16F16::from_num(7), I16F16::from_num(-8.5))"
+        },
+        {over_lets, over_lhs, over_args, wrap} = {
+            "let c = V::new(I16F16::ZERO, I16F16::MAX, I16F16::MIN);\n\
+             let max = V::new(I16F16::MAX, I16F16::MAX, I16F16::MAX);\n",
+            "c",
+            "max",
+            "V::new(I16F16::from_num(-1.5), I16F16::from_num(0.5), I16F16::ONE - I16F16::DELTA)"
+        },
+    }
+
+    /// Saturating cross product. Returns the cross product, saturating each
+    /// component on overflow.
+    ///
+    /// Each component is computed exactly before it is saturated, so it does
+    /// not saturate if only the intermediate products do not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec3};
+    /// type V = Vec3<I16F16>;
+    /// let a = V::new(I16F16::from_num(2), I16F16::from_num(3), I16F16::from_num(6));
+    /// let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2), I16F16::from_num(1));
+    /// let cross = V::new(I16F16::from_num(15), I16F16::from_num(7), I16F16::from_num(-8.5));
+    /// assert_eq!(a.saturating_cross(b), cross);
+    /// let c = V::new(I16F16::ZERO, I16F16::MAX, I16F16::MIN);
+    /// let max = V::new(I16F16::MAX, I16F16::MAX, I16F16::MAX);
+    /// let saturated = V::new(I16F16::MAX, I16F16::MIN, I16F16::MIN);
+    /// assert_eq!(c.saturating_cross(max), saturated);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_cross(self, rhs: Vec3<F>) -> Vec3<F> {
+        let (x, x_overflow, x_neg) = F::sum_prods(&[(self.y, rhs.z)], &[(self.z, rhs.y)]);
+        let (y, y_overflow, y_neg) = F::sum_prods(&[(self.z, rhs.x)], &[(self.x, rhs.z)]);
+        let (z, z_overflow, z_neg) = F::sum_prods(&[(self.x, rhs.y)], &[(self.y, rhs.x)]);
+        Vec3 {
+            x: saturate(x, x_overflow, x_neg),
+            y: saturate(y, y_overflow, y_neg),
+            z: saturate(z, z_overflow, z_neg),
+        }
+    }
+
+    /// Overflowing cross product.
+    ///
+    /// Returns a [tuple] of the cross product and a [`bool`] indicating
+    /// whether an overflow has occurred in any component. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec3};
+    /// type V = Vec3<I16F16>;
+    /// let a = V::new(I16F16::from_num(2), I16F16::from_num(3), I16F16::from_num(6));
+    /// let b = V::new(I16F16::from_num(1.5), I16F16::from_num(-2), I16F16::from_num(1));
+    /// let cross = V::new(I16F16::from_num(15), I16F16::from_num(7), I16F16::from_num(-8.5));
+    /// assert_eq!(a.overflowing_cross(b), (cross, false));
+    /// let c = V::new(I16F16::ZERO, I16F16::MAX, I16F16::MIN);
+    /// let max = V::new(I16F16::MAX, I16F16::MAX, I16F16::MAX);
+    /// let wrapped = V::new(
+    ///     I16F16::from_num(-1.5),
+    ///     I16F16::from_num(0.5),
+    ///     I16F16::ONE - I16F16::DELTA,
+    /// );
+    /// assert_eq!(c.overflowing_cross(max), (wrapped, true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_cross(self, rhs: Vec3<F>) -> (Vec3<F>, bool) {
+        let (x, x_overflow, _) = F::sum_prods(&[(self.y, rhs.z)], &[(self.z, rhs.y)]);
+        let (y, y_overflow, _) = F::sum_prods(&[(self.z, rhs.x)], &[(self.x, rhs.z)]);
+        let (z, z_overflow, _) = F::sum_prods(&[(self.x, rhs.y)], &[(self.y, rhs.x)]);
+        (Vec3 { x, y, z }, x_overflow | y_overflow | z_overflow)
+    }
+
+    /// Returns the length, also known as the Euclidean norm.
+    ///
+    /// The length is first computed as the [`hypot`] of the <i>z</i> component
+    /// and the [`hypot`] of the <i>x</i> and <i>y</i> components, which can be
+    /// one [`DELTA`] less than the exact length rounded down. This is then
+    /// corrected by comparing its square to the sum of the squares of the
+    /// components computed exactly in double width, so the result is rounded
+    /// down only once.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the
+    /// future it panics; if saturation is required use
+    /// [`saturating_length`][Vec3::saturating_length] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec3};
+    /// let a = Vec3::new(I16F16::from_num(2), I16F16::from_num(-3), I16F16::from_num(6));
+    /// assert_eq!(a.length(), 7);
+    /// ```
+    ///
+    /// [`DELTA`]: crate::FixedI32::DELTA
+    /// [`hypot`]: crate::FixedI32::hypot
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn length(self) -> F {
+        let (ans, overflow) = self.round_length(self.x.hypot(self.y).hypot(self.z));
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Checked length. Returns the length, or [`None`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec3};
+    /// let a = Vec3::new(I16F16::from_num(2), I16F16::from_num(-3), I16F16::from_num(6));
+    /// assert_eq!(a.checked_length(), Some(I16F16::from_num(7)));
+    /// let max = Vec3::new(I16F16::MAX, I16F16::ZERO, I16F16::MAX);
+    /// assert_eq!(max.checked_length(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_length(self) -> Option<F> {
+        let nested = self.x.checked_hypot(self.y)?.checked_hypot(self.z)?;
+        match self.round_length(nested) {
+            (ans, false) => Some(ans),
+            (_, true) => None,
+        }
+    }
+
+    /// Saturating length. Returns the length, saturating on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Vec3};
+    /// let a = Vec3::new(I16F16::from_num(2), I16F16::from_num(-3), I16F16::from_num(6));
+    /// assert_eq!(a.saturating_length(), 7);
+    /// let max = Vec3::new(I16F16::MAX, I16F16::MAX, I16F16::ZERO);
+    /// assert_eq!(max.saturating_length(), I16F16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_length(self) -> F {
+        self.checked_length().unwrap_or(F::MAX)
+    }
+
+    // Rounds the nested hypot, which is either the exact length rounded down
+    // or DELTA less than it, to the exact length rounded down. The nested
+    // hypot is increased by DELTA if
+    // x² + y² + z² - nested² - 2 × nested × DELTA - DELTA² is not negative.
+    #[inline]
+    fn round_length(self, nested: F) -> (F, bool) {
+        let delta = F::DELTA;
+        let (_, _, below) = F::sum_prods(
+            &[(self.x, self.x), (self.y, self.y), (self.z, self.z)],
+            &[
+                (nested, nested),
+                (nested, delta),
+                (nested, delta),
+                (delta, delta),
+            ],
+        );
+        if below {
+            (nested, false)
+        } else {
+            nested.overflowing_add(delta)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dot::tests::{round_prods, PROD_BITS};
+    use crate::types::{I0F16, I16F16, I8F8, U0F16, U8F8};
+    use crate::{FixedI128, FixedI16, Vec2, Vec3};
+
+    // Checks the dot and cross products against the exact results computed
+    // using integer arithmetic, with the results rounded down and wrapped to
+    // 16 bits.
+    fn check_products<const FRAC: i32>() {
+        type F<const FRAC: i32> = FixedI16<FRAC>;
+        let values = PROD_BITS;
+        let round = round_prods::<FRAC>;
+        for (i, &a) in values.iter().enumerate() {
+            for (&b, &c) in values.iter().zip(values.iter().skip(i)) {
+                let d = values[(i + 3) % values.len()];
+                let u = Vec3::new(F::<FRAC>::from_bits(a), F::from_bits(b), F::from_bits(c));
+                let v = Vec3::new(F::<FRAC>::from_bits(d), F::from_bits(c), F::from_bits(a));
+                let (a, b, c, d) = (i64::from(a), i64::from(b), i64::from(c), i64::from(d));
+
+                let (dot, overflow, sat) = round(a * d + b * c + c * a);
+                assert_eq!(u.overflowing_dot(v), (dot, overflow), "{u:?} · {v:?}");
+                assert_eq!(u.saturating_dot(v), sat, "{u:?} · {v:?}");
+
+                let (x, x_overflow, x_sat) = round(b * a - c * c);
+                let (y, y_overflow, y_sat) = round(c * d - a * a);
+                let (z, z_overflow, z_sat) = round(a * c - b * d);
+                let overflow = x_overflow | y_overflow | z_overflow;
+                let cross = Vec3::new(x, y, z);
+                assert_eq!(u.overflowing_cross(v), (cross, overflow), "{u:?} × {v:?}");
+                assert_eq!(u.checked_cross(v), (!overflow).then_some(cross));
+                assert_eq!(u.saturating_cross(v), Vec3::new(x_sat, y_sat, z_sat));
+
+                let u2 = Vec2::new(u.x, u.y);
+                let v2 = Vec2::new(v.x, v.y);
+                assert_eq!(u2.overflowing_cross(v2), (z, z_overflow), "{u2:?} × {v2:?}");
+                assert_eq!(u2.saturating_cross(v2), z_sat, "{u2:?} × {v2:?}");
+                let (dot, overflow, sat) = round(a * d + b * c);
+                assert_eq!(u2.overflowing_dot(v2), (dot, overflow), "{u2:?} · {v2:?}");
+                assert_eq!(u2.saturating_dot(v2), sat, "{u2:?} · {v2:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn products_round_once() {
+        check_products::<0>();
+        check_products::<8>();
+        check_products::<15>();
+        check_products::<16>();
+        check_products::<20>();
+        check_products::<{ -3 }>();
+    }
+
+    #[test]
+    fn single_rounding() {
+        let delta = I8F8::DELTA;
+        let v = Vec3::new(delta, delta, delta);
+        assert_eq!(v.dot(v), I8F8::ZERO);
+        // (12Δ)² = 0.5625Δ, so 2 × (12Δ)² = 1.125Δ rounds down to Δ, while
+        // rounding each product separately would give 0 + 0 = 0
+        let q = I8F8::from_bits(12);
+        let u = Vec2::new(q, q);
+        assert_eq!(u.dot(u), delta);
+        assert_eq!(q * q + q * q, I8F8::ZERO);
+        assert_eq!(Vec2::new(q, -q).cross(u), delta);
+    }
+
+    #[test]
+    fn components() {
+        let a = Vec3::new(I8F8::from_num(1.5), I8F8::from_num(-2), I8F8::MAX);
+        let b = Vec3::new(I8F8::from_num(0.25), I8F8::ONE, I8F8::DELTA);
+        assert_eq!(
+            a.overflowing_add(b),
+            (
+                Vec3::new(I8F8::from_num(1.75), I8F8::from_num(-1), I8F8::MIN),
+                true
+            )
+        );
+        assert_eq!(
+            a.saturating_add(b),
+            Vec3::new(I8F8::from_num(1.75), I8F8::from_num(-1), I8F8::MAX)
+        );
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(
+            a - b,
+            Vec3::new(
+                I8F8::from_num(1.25),
+                I8F8::from_num(-3),
+                I8F8::MAX - I8F8::DELTA
+            )
+        );
+        assert_eq!(
+            -a,
+            Vec3::new(I8F8::from_num(-1.5), I8F8::from_num(2), -I8F8::MAX)
+        );
+        let mut c = a;
+        c -= b;
+        c += b;
+        assert_eq!(c, a);
+        c *= I8F8::from_num(0.5);
+        assert_eq!(c, a.scale(I8F8::from_num(0.5)));
+        assert_eq!(a.checked_scale(I8F8::from_num(2)), None);
+        assert_eq!(
+            a.saturating_scale(I8F8::from_num(-2)),
+            Vec3::new(I8F8::from_num(-3), I8F8::from_num(4), I8F8::MIN)
+        );
+        assert_eq!(<[I8F8; 3]>::from(a), [a.x, a.y, a.z]);
+        assert_eq!(Vec2::from([a.x, a.y]), Vec2::new(a.x, a.y));
+        let min = Vec2::new(I8F8::MIN, I8F8::ZERO);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(min.saturating_neg(), Vec2::new(I8F8::MAX, I8F8::ZERO));
+        assert_eq!(min.wrapping_neg(), min);
+    }
+
+    #[test]
+    fn length() {
+        let a = Vec2::new(I8F8::from_num(-12), I8F8::from_num(12));
+        // 12² + 12² = 288 does not fit, but the length does
+        assert_eq!(a.checked_dot(a), None);
+        assert_eq!(a.saturating_dot(a), I8F8::MAX);
+        // √288 = 16.97…
+        assert_eq!(a.length(), I8F8::from_bits(4344));
+        let b = Vec3::new(I8F8::from_num(-12), I8F8::from_num(12), I8F8::ZERO);
+        assert_eq!(b.length(), I8F8::from_bits(4344));
+        let c = Vec3::new(
+            I8F8::from_num(100),
+            I8F8::from_num(-60),
+            I8F8::from_num(-80),
+        );
+        assert_eq!(c.checked_length(), None);
+        assert_eq!(c.saturating_length(), I8F8::MAX);
+        let d = Vec3::new(I8F8::from_num(100), I8F8::from_num(100), I8F8::ONE);
+        assert_eq!(d.checked_length(), None);
+        assert_eq!(d.saturating_length(), I8F8::MAX);
+        // the length is the exact length rounded down, even where the nested
+        // hypot is Δ less, such as for √(2² + 3² + 6²) = 7
+        let values = [0, 1, -2, 3, 6, 7, -11, 0x100, 0x301, -0x105, 0x1234, -0x7FF];
+        for &x in &values {
+            for &y in &values {
+                for &z in &values {
+                    let v = Vec3::new(
+                        I16F16::from_bits(x),
+                        I16F16::from_bits(y),
+                        I16F16::from_bits(z),
+                    );
+                    let sum = f64::from(x * x + y * y + z * z);
+                    let exact = I16F16::from_bits(sum.sqrt().floor() as i32);
+                    assert_eq!(v.length(), exact, "{v:?}");
+                }
+            }
+        }
+        let max = Vec3::new(I8F8::ZERO, I8F8::MIN + I8F8::DELTA, I8F8::ZERO);
+        assert_eq!(max.checked_length(), Some(I8F8::MAX));
+        let min = Vec3::new(I8F8::ZERO, I8F8::ZERO, I8F8::MIN);
+        assert_eq!(min.checked_length(), None);
+        assert_eq!(min.saturating_length(), I8F8::MAX);
+        let u = Vec2::new(U8F8::from_num(3), U8F8::from_num(4));
+        assert_eq!(u.length(), 5);
+    }
+
+    #[test]
+    fn length_nested_hypot() {
+        // The nested hypot rounds down twice. For (1, 4, 8), hypot(1, 4) is
+        // √17 = 4.12…, which is rounded down to 4, and hypot(4, 8) is
+        // √80 = 8.94…, which is rounded down to 8, but the exact length is 9.
+        let v = Vec3::new(I8F8::ONE, I8F8::from_num(4), I8F8::from_num(8));
+        assert_eq!(v.length(), 9);
+        let v = Vec3::new(
+            FixedI16::<0>::from_num(8),
+            FixedI16::from_num(1),
+            FixedI16::from_num(4),
+        );
+        assert_eq!(v.length(), 9);
+        // The same with bits near MAX: the exact length rounded down is
+        // 0x7FFF, and the nested hypot is 0x7FFE.
+        let v = Vec3::new(
+            I8F8::from_bits(20000),
+            I8F8::from_bits(18624),
+            I8F8::from_bits(18079),
+        );
+        assert_eq!(v.checked_length(), Some(I8F8::MAX));
+        // Here the nested hypot is MAX, but the exact length rounded down is
+        // 0x8000, which overflows.
+        let v = Vec3::new(
+            I8F8::from_bits(20000),
+            I8F8::from_bits(14065),
+            I8F8::from_bits(21816),
+        );
+        assert_eq!(v.x.hypot(v.y).checked_hypot(v.z), Some(I8F8::MAX));
+        assert_eq!(v.checked_length(), None);
+        assert_eq!(v.saturating_length(), I8F8::MAX);
+    }
+
+    #[test]
+    fn normalize() {
+        let a = Vec2::new(I16F16::ZERO, I16F16::from_num(-4));
+        assert_eq!(a.normalize(), Vec2::new(I16F16::ZERO, -I16F16::ONE));
+        let b = Vec3::new(
+            I16F16::from_num(2),
+            I16F16::from_num(-3),
+            I16F16::from_num(6),
+        );
+        let n = b.normalize();
+        // 2/7, -3/7 and 6/7 rounded towards zero
+        assert_eq!(
+            n,
+            Vec3::new(
+                I16F16::from_bits(18_724),
+                I16F16::from_bits(-28_086),
+                I16F16::from_bits(56_173)
+            )
+        );
+        assert_eq!(b.checked_normalize(), Some(n));
+        assert_eq!(b.saturating_normalize(), n);
+        // the length of max does not fit, but its direction can be computed
+        let max = Vec3::new(I16F16::MAX, I16F16::MAX, I16F16::MAX);
+        let n = max.checked_normalize().unwrap();
+        assert_eq!(n.x, n.y);
+        assert!((n.x - I16F16::from_num(3f64.sqrt().recip())).abs() <= 2 * I16F16::DELTA);
+        assert_eq!(Vec2::<I16F16>::ZERO.checked_normalize(), None);
+        // one does not fit in I0F16 or U0F16
+        let c = Vec2::new(I0F16::ZERO, I0F16::MIN);
+        assert_eq!(c.checked_normalize(), None);
+        assert_eq!(c.saturating_normalize(), Vec2::new(I0F16::ZERO, I0F16::MIN));
+        let d = Vec2::new(U0F16::from_num(0.5), U0F16::ZERO);
+        assert_eq!(d.checked_normalize(), None);
+        assert_eq!(d.saturating_normalize(), Vec2::new(U0F16::MAX, U0F16::ZERO));
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Vec2::new(I8F8::from_num(1), I8F8::from_num(-2));
+        let b = Vec2::new(I8F8::from_num(3), I8F8::from_num(2));
+        let t = I8F8::from_num(0.25);
+        assert_eq!(
+            a.lerp(b, t),
+            Vec2::new(I8F8::from_num(1.5), I8F8::from_num(-1))
+        );
+        assert_eq!(a.lerp(b, t).x, t.lerp(a.x, b.x));
+        let far = I8F8::from_num(100);
+        assert_eq!(a.checked_lerp(b, far), None);
+        assert_eq!(a.saturating_lerp(b, far), Vec2::new(I8F8::MAX, I8F8::MAX));
+        assert_eq!(b.saturating_lerp(a, far), Vec2::new(I8F8::MIN, I8F8::MIN));
+        let u = Vec2::new(U8F8::from_num(4), U8F8::ONE);
+        let v = Vec2::new(U8F8::ONE, U8F8::from_num(4));
+        assert_eq!(
+            u.lerp(v, U8F8::from_num(0.5)),
+            Vec2::new(U8F8::from_num(2.5), U8F8::from_num(2.5))
+        );
+    }
+
+    #[test]
+    fn wide() {
+        type F = FixedI128<64>;
+        let big = F::from_num(1u64 << 40);
+        let u = Vec3::new(big, big + F::DELTA, F::ZERO);
+        let v = Vec3::new(big, -big, F::DELTA);
+        // u · v = big² - big² - Δbig = -Δbig
+        assert_eq!(u.dot(v), -(big * F::DELTA));
+        // (u × v).z = -big² - big² - Δbig, which does not fit
+        let (cross, overflow) = u.overflowing_cross(v);
+        assert!(overflow);
+        assert_eq!(cross.x, (big + F::DELTA) * F::DELTA);
+        assert_eq!(u.saturating_cross(v).z, F::MIN);
+        let small = Vec2::new(F::DELTA, -F::DELTA);
+        assert_eq!(small.dot(small), F::ZERO);
+        assert_eq!(small.cross(small), F::ZERO);
+        assert_eq!(small.dot(-small), -F::DELTA);
+    }
+}
@@ -21,7 +21,7 @@ use crate::{
     traits::{Fixed, FixedBits, FixedBoundFrac, FixedSigned, FixedUnsigned, FromFixed, ToFixed},
     types::extra::{If, True},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    FixedU8, RoundingMode,
 };
 use core::{
     fmt::{
@@ -1090,6 +1090,39 @@ impl<F: Fixed> Wrapping<F> {
         Wrapping(self.0.wrapping_hypot(other.0))
     }
 
+    /// Four-quadrant arctangent of `self` (<i>y</i>) and `other` (<i>x</i>),
+    /// wrapping on overflow.
+    ///
+    /// The angle is in radians, and is returned with `RET_FRAC` fractional
+    /// bits.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_atan2][FixedI32::wrapping_atan2]</code> and
+    /// <code>FixedU32::[wrapping\_atan2][FixedU32::wrapping_atan2]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, I3F29},
+    ///     Wrapping,
+    /// };
+    /// let one = Wrapping(I16F16::ONE);
+    /// let angle: Wrapping<I3F29> = one.atan2(one);
+    /// assert!(angle.0.dist(I3F29::FRAC_PI_4) <= I3F29::DELTA);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn atan2<const RET_FRAC: i32>(
+        self,
+        other: Wrapping<F>,
+    ) -> Wrapping<<F::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Wrapping(self.0.wrapping_atan2(other.0))
+    }
+
     /// Returns the next multiple of `other`.
     ///
     /// See also
@@ -1348,45 +1381,99 @@ impl<F: FixedBoundFrac> Wrapping<F> {
         Wrapping(self.0.wrapping_sqrt())
     }
 
-    /// Integer base-10 logarithm, rounded down.
+    /// Returns the square root, rounded according to the given [`RoundingMode`].
     ///
-    /// See also <code>FixedI32::[int\_log10][FixedI32::int_log10]</code> and
-    /// <code>FixedU32::[int\_log10][FixedU32::int_log10]</code>.
+    /// See also
+    /// <code>FixedI32::[wrapping\_sqrt\_round][FixedI32::wrapping_sqrt_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_sqrt\_round][FixedU32::wrapping_sqrt_round]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the fixed-point number is ≤&nbsp;0.
+    /// Panics if the number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::U0F32, RoundingMode, Wrapping};
+    /// let max = Wrapping(U0F32::MAX);
+    /// assert_eq!(max.sqrt_round(RoundingMode::Floor), max);
+    /// // √MAX rounded up is 1, which wraps to 0
+    /// assert_eq!(max.sqrt_round(RoundingMode::Ceil), Wrapping(U0F32::ZERO));
+    /// ```
     #[inline]
     #[track_caller]
-    #[doc(alias("ilog10"))]
-    pub fn int_log10(self) -> i32 {
-        self.0.int_log10()
+    pub fn sqrt_round(self, mode: RoundingMode) -> Self {
+        Wrapping(self.0.wrapping_sqrt_round(mode))
     }
 
-    /// Integer logarithm to the specified base, rounded down.
+    /// Returns the cube root.
     ///
-    /// See also <code>FixedI32::[int\_log][FixedI32::int_log]</code> and
-    /// <code>FixedU32::[int\_log][FixedU32::int_log]</code>.
+    /// See also
+    /// <code>FixedI32::[wrapping\_cbrt][FixedI32::wrapping_cbrt]</code> and
+    /// <code>FixedU32::[wrapping\_cbrt][FixedU32::wrapping_cbrt]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I0F32, Wrapping};
+    /// assert_eq!(Wrapping(I0F32::from_num(-0.125)).cbrt().0, -0.5);
+    ///
+    /// // This method handles the overflow corner case.
+    /// let w = Wrapping(I0F32::from_num(0.125));
+    /// assert_eq!(w.cbrt().0, -0.5);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn cbrt(self) -> Self {
+        Wrapping(self.0.wrapping_cbrt())
+    }
+
+    /// Returns the <i>n</i>th root.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_nth\_root][FixedI32::wrapping_nth_root]</code> and
+    /// <code>FixedU32::[wrapping\_nth\_root][FixedU32::wrapping_nth_root]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the fixed-point number is ≤&nbsp;0 or if the base is <&nbsp;2.
+    /// Panics if `n` is zero, or if the number is negative and `n` is even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I0F32, Wrapping};
+    /// assert_eq!(Wrapping(I0F32::lit("0b0.0000_0001")).nth_root(4).0, I0F32::lit("0b0.01"));
+    ///
+    /// // This method handles the overflow corner case.
+    /// let w = Wrapping(I0F32::from_num(0.0625));
+    /// assert_eq!(w.nth_root(4).0, -0.5);
+    /// ```
     #[inline]
     #[track_caller]
-    #[doc(alias("ilog"))]
-    pub fn int_log(self, base: u32) -> i32 {
-        self.0.int_log(base)
+    pub fn nth_root(self, n: u32) -> Self {
+        Wrapping(self.0.wrapping_nth_root(n))
     }
 
-    /// Returns the reciprocal (inverse), 1/`self`.
+    /// Returns the reciprocal square root, 1/√`self`, with `RET_FRAC`
+    /// fractional bits.
     ///
     /// See also
-    /// <code>FixedI32::[wrapping\_recip][FixedI32::wrapping_recip]</code> and
-    /// <code>FixedU32::[wrapping\_recip][FixedU32::wrapping_recip]</code>.
+    /// <code>FixedI32::[wrapping\_rsqrt][FixedI32::wrapping_rsqrt]</code> and
+    /// <code>FixedU32::[wrapping\_rsqrt][FixedU32::wrapping_rsqrt]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if `self` is zero.
+    /// Panics if the number is zero or negative.
     ///
     /// # Examples
     ///
@@ -1394,17 +1481,791 @@ impl<F: FixedBoundFrac> Wrapping<F> {
     /// #![feature(generic_const_exprs)]
     /// # #![allow(incomplete_features)]
     ///
-    /// use fixed::{types::I8F24, Wrapping};
-    /// let quarter = Wrapping(I8F24::from_num(0.25));
-    /// let frac_1_512 = Wrapping(I8F24::ONE / 512);
-    /// assert_eq!(quarter.recip(), Wrapping(I8F24::from_num(4)));
-    /// assert_eq!(frac_1_512.recip(), Wrapping(I8F24::ZERO));
+    /// use fixed::{
+    ///     types::{I16F16, I1F31, I24F8},
+    ///     Wrapping,
+    /// };
+    /// let x = Wrapping(I16F16::from_num(0.0625));
+    /// let r: Wrapping<I24F8> = x.rsqrt();
+    /// assert_eq!(r.0, 4);
+    ///
+    /// let x = Wrapping(I16F16::from_num(0.25));
+    /// let r: Wrapping<I1F31> = x.rsqrt();
+    /// assert_eq!(r.0, I1F31::ZERO);
     /// ```
     #[inline]
     #[track_caller]
-    #[must_use]
-    pub fn recip(self) -> Wrapping<F> {
-        Wrapping(self.0.wrapping_recip())
+    pub fn rsqrt<const RET_FRAC: i32>(self) -> Wrapping<<F::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Wrapping(self.0.wrapping_rsqrt())
+    }
+
+    /// Sine, with `self` in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin][FixedI32::wrapping_sin]</code> and
+    /// <code>FixedU32::[wrapping\_sin][FixedU32::wrapping_sin]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// let x = Wrapping(I16F16::FRAC_PI_2);
+    /// assert_eq!(x.sin().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn sin(self) -> Self {
+        Wrapping(self.0.wrapping_sin())
+    }
+
+    /// Cosine, with `self` in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cos][FixedI32::wrapping_cos]</code> and
+    /// <code>FixedU32::[wrapping\_cos][FixedU32::wrapping_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::{I0F32, I16F16}, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).cos().0, 1);
+    ///
+    /// // 1 wraps to 0
+    /// assert_eq!(Wrapping(I0F32::ZERO).cos().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn cos(self) -> Self {
+        Wrapping(self.0.wrapping_cos())
+    }
+
+    /// Simultaneous sine and cosine, with `self` in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin\_cos][FixedI32::wrapping_sin_cos]</code> and
+    /// <code>FixedU32::[wrapping\_sin\_cos][FixedU32::wrapping_sin_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// let (sin, cos) = Wrapping(I16F16::ZERO).sin_cos();
+    /// assert_eq!(sin.0, 0);
+    /// assert_eq!(cos.0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.0.wrapping_sin_cos();
+        (Wrapping(sin), Wrapping(cos))
+    }
+
+    /// Tangent, with `self` in radians, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_tan][FixedI32::wrapping_tan]</code> and
+    /// <code>FixedU32::[wrapping\_tan][FixedU32::wrapping_tan]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).tan().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn tan(self) -> Self {
+        Wrapping(self.0.wrapping_tan())
+    }
+
+    /// Arctangent, returning the angle in radians.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[atan][FixedI32::atan]</code> and
+    /// <code>FixedU32::[atan][FixedU32::atan]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).atan().0, 0);
+    /// ```
+    #[inline]
+    pub fn atan(self) -> Self {
+        Wrapping(self.0.atan())
+    }
+
+    /// Arcsine, returning the angle in radians, wrapping on overflow.
+    ///
+    /// Inputs outside the domain are treated as the nearest end of the
+    /// domain.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_asin][FixedI32::wrapping_asin]</code> and
+    /// <code>FixedU32::[wrapping\_asin][FixedU32::wrapping_asin]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).asin().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn asin(self) -> Self {
+        Wrapping(self.0.wrapping_asin())
+    }
+
+    /// Arccosine, returning the angle in radians, wrapping on overflow.
+    ///
+    /// Inputs outside the domain are treated as the nearest end of the
+    /// domain.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_acos][FixedI32::wrapping_acos]</code> and
+    /// <code>FixedU32::[wrapping\_acos][FixedU32::wrapping_acos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ONE).acos().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn acos(self) -> Self {
+        Wrapping(self.0.wrapping_acos())
+    }
+
+    /// Exponential, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp][FixedI32::wrapping_exp]</code> and
+    /// <code>FixedU32::[wrapping\_exp][FixedU32::wrapping_exp]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).exp().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp(self) -> Self {
+        Wrapping(self.0.wrapping_exp())
+    }
+
+    /// Base-2 exponential, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp2][FixedI32::wrapping_exp2]</code> and
+    /// <code>FixedU32::[wrapping\_exp2][FixedU32::wrapping_exp2]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::from_num(3)).exp2().0, 8);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp2(self) -> Self {
+        Wrapping(self.0.wrapping_exp2())
+    }
+
+    /// Exponential minus one, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp\_m1][FixedI32::wrapping_exp_m1]</code> and
+    /// <code>FixedU32::[wrapping\_exp\_m1][FixedU32::wrapping_exp_m1]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).exp_m1().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp_m1(self) -> Self {
+        Wrapping(self.0.wrapping_exp_m1())
+    }
+
+    /// Natural logarithm, returned with `RET_FRAC` fractional bits, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_ln][FixedI32::wrapping_ln]</code> and
+    /// <code>FixedU32::[wrapping\_ln][FixedU32::wrapping_ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, U16F16},
+    ///     Wrapping,
+    /// };
+    /// let log: Wrapping<I16F16> = Wrapping(U16F16::from_num(1)).ln();
+    /// assert_eq!(log.0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn ln<const RET_FRAC: i32>(
+        self,
+    ) -> Wrapping<<<F::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Wrapping(self.0.wrapping_ln())
+    }
+
+    /// Base-2 logarithm, returned with `RET_FRAC` fractional bits, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_log2][FixedI32::wrapping_log2]</code> and
+    /// <code>FixedU32::[wrapping\_log2][FixedU32::wrapping_log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, U16F16},
+    ///     Wrapping,
+    /// };
+    /// let log: Wrapping<I16F16> = Wrapping(U16F16::from_num(8)).log2();
+    /// assert_eq!(log.0, 3);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn log2<const RET_FRAC: i32>(
+        self,
+    ) -> Wrapping<<<F::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Wrapping(self.0.wrapping_log2())
+    }
+
+    /// Base-10 logarithm, returned with `RET_FRAC` fractional bits, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_log10][FixedI32::wrapping_log10]</code> and
+    /// <code>FixedU32::[wrapping\_log10][FixedU32::wrapping_log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, U16F16},
+    ///     Wrapping,
+    /// };
+    /// let log: Wrapping<I16F16> = Wrapping(U16F16::from_num(100)).log10();
+    /// assert_eq!(log.0, 2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn log10<const RET_FRAC: i32>(
+        self,
+    ) -> Wrapping<<<F::Signed as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Wrapping(self.0.wrapping_log10())
+    }
+
+    /// Raises `self` to the integer power `exp`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powi][FixedI32::wrapping_powi]</code> and
+    /// <code>FixedU32::[wrapping\_powi][FixedU32::wrapping_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// let x = Wrapping(I16F16::from_num(1.5));
+    /// assert_eq!(x.powi(3), Wrapping(I16F16::from_num(3.375)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn powi(self, exp: i32) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_powi(exp))
+    }
+
+    /// Raises `self` to the fixed-point power `exp`, wrapping on overflow.
+    ///
+    /// Zero is returned on a domain error.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powf][FixedI32::wrapping_powf]</code> and
+    /// <code>FixedU32::[wrapping\_powf][FixedU32::wrapping_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// let x = Wrapping(I16F16::from_num(4));
+    /// let exp = Wrapping(I16F16::from_num(0.5));
+    /// assert_eq!(x.powf(exp), Wrapping(I16F16::from_num(2)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn powf(self, exp: Wrapping<F>) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_powf(exp.0))
+    }
+
+    /// Hyperbolic sine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sinh][FixedI32::wrapping_sinh]</code> and
+    /// <code>FixedU32::[wrapping\_sinh][FixedU32::wrapping_sinh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).sinh().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn sinh(self) -> Self {
+        Wrapping(self.0.wrapping_sinh())
+    }
+
+    /// Hyperbolic cosine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cosh][FixedI32::wrapping_cosh]</code> and
+    /// <code>FixedU32::[wrapping\_cosh][FixedU32::wrapping_cosh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).cosh().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn cosh(self) -> Self {
+        Wrapping(self.0.wrapping_cosh())
+    }
+
+    /// Hyperbolic tangent.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[tanh][FixedI32::tanh]</code> and
+    /// <code>FixedU32::[tanh][FixedU32::tanh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).tanh().0, 0);
+    /// ```
+    #[inline]
+    pub fn tanh(self) -> Self {
+        Wrapping(self.0.tanh())
+    }
+
+    /// Inverse hyperbolic sine.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[asinh][FixedI32::asinh]</code> and
+    /// <code>FixedU32::[asinh][FixedU32::asinh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).asinh().0, 0);
+    /// ```
+    #[inline]
+    pub fn asinh(self) -> Self {
+        Wrapping(self.0.asinh())
+    }
+
+    /// Inverse hyperbolic cosine.
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[acosh][FixedI32::acosh]</code> and
+    /// <code>FixedU32::[acosh][FixedU32::acosh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is less than one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ONE).acosh().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn acosh(self) -> Self {
+        Wrapping(self.0.acosh())
+    }
+
+    /// Inverse hyperbolic tangent, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_atanh][FixedI32::wrapping_atanh]</code> and
+    /// <code>FixedU32::[wrapping\_atanh][FixedU32::wrapping_atanh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is outside the domain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).atanh().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn atanh(self) -> Self {
+        Wrapping(self.0.wrapping_atanh())
+    }
+
+    /// Error function, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_erf][FixedI32::wrapping_erf]</code> and
+    /// <code>FixedU32::[wrapping\_erf][FixedU32::wrapping_erf]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).erf().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn erf(self) -> Self {
+        Wrapping(self.0.wrapping_erf())
+    }
+
+    /// Complementary error function, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_erfc][FixedI32::wrapping_erfc]</code> and
+    /// <code>FixedU32::[wrapping\_erfc][FixedU32::wrapping_erfc]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).erfc().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn erfc(self) -> Self {
+        Wrapping(self.0.wrapping_erfc())
+    }
+
+    /// Logistic sigmoid, returned with `RET_FRAC` fractional bits.
+    ///
+    /// The result is unsigned, and saturates if it does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[sigmoid][FixedI32::sigmoid]</code> and
+    /// <code>FixedU32::[sigmoid][FixedU32::sigmoid]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{
+    ///     types::{I16F16, U16F16},
+    ///     Wrapping,
+    /// };
+    /// let half: Wrapping<U16F16> = Wrapping(I16F16::ZERO).sigmoid();
+    /// assert_eq!(half.0, 0.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sigmoid<const RET_FRAC: i32>(
+        self,
+    ) -> Wrapping<<<F::Unsigned as Fixed>::Bits as FixedBits>::Fixed<RET_FRAC>> {
+        Wrapping(self.0.sigmoid())
+    }
+
+    /// Softplus, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[softplus][FixedI32::softplus]</code> and
+    /// <code>FixedU32::[softplus][FixedU32::softplus]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::MAX).softplus(), Wrapping(I16F16::MAX));
+    /// ```
+    #[inline]
+    pub fn softplus(self) -> Self {
+        Wrapping(self.0.softplus())
+    }
+
+    /// Gaussian error linear unit (GELU).
+    ///
+    /// This method never overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[gelu][FixedI32::gelu]</code> and
+    /// <code>FixedU32::[gelu][FixedU32::gelu]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).gelu().0, 0);
+    /// ```
+    #[inline]
+    pub fn gelu(self) -> Self {
+        Wrapping(self.0.gelu())
+    }
+
+    /// Sine using a lookup table with `N` entries, with `self` in radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin\_lut][FixedI32::wrapping_sin_lut]</code> and
+    /// <code>FixedU32::[wrapping\_sin\_lut][FixedU32::wrapping_sin_lut]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, Wrapping};
+    /// let x = Wrapping(I16F16::FRAC_PI_2);
+    /// assert_eq!(x.sin_lut::<256>().0, 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn sin_lut<const N: usize>(self) -> Self {
+        Wrapping(self.0.wrapping_sin_lut::<N>())
+    }
+
+    /// Cosine using a lookup table with `N` entries, with `self` in
+    /// radians.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cos\_lut][FixedI32::wrapping_cos_lut]</code> and
+    /// <code>FixedU32::[wrapping\_cos\_lut][FixedU32::wrapping_cos_lut]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::{I0F32, I16F16}, Wrapping};
+    /// assert_eq!(Wrapping(I16F16::ZERO).cos_lut::<256>().0, 1);
+    ///
+    /// // 1 wraps to 0
+    /// assert_eq!(Wrapping(I0F32::ZERO).cos_lut::<256>().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn cos_lut<const N: usize>(self) -> Self {
+        Wrapping(self.0.wrapping_cos_lut::<N>())
+    }
+
+    /// Integer base-10 logarithm, rounded down.
+    ///
+    /// See also <code>FixedI32::[int\_log10][FixedI32::int_log10]</code> and
+    /// <code>FixedU32::[int\_log10][FixedU32::int_log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed-point number is ≤&nbsp;0.
+    #[inline]
+    #[track_caller]
+    #[doc(alias("ilog10"))]
+    pub fn int_log10(self) -> i32 {
+        self.0.int_log10()
+    }
+
+    /// Integer logarithm to the specified base, rounded down.
+    ///
+    /// See also <code>FixedI32::[int\_log][FixedI32::int_log]</code> and
+    /// <code>FixedU32::[int\_log][FixedU32::int_log]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed-point number is ≤&nbsp;0 or if the base is <&nbsp;2.
+    #[inline]
+    #[track_caller]
+    #[doc(alias("ilog"))]
+    pub fn int_log(self, base: u32) -> i32 {
+        self.0.int_log(base)
+    }
+
+    /// Returns the reciprocal (inverse), 1/`self`.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_recip][FixedI32::wrapping_recip]</code> and
+    /// <code>FixedU32::[wrapping\_recip][FixedU32::wrapping_recip]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I8F24, Wrapping};
+    /// let quarter = Wrapping(I8F24::from_num(0.25));
+    /// let frac_1_512 = Wrapping(I8F24::ONE / 512);
+    /// assert_eq!(quarter.recip(), Wrapping(I8F24::from_num(4)));
+    /// assert_eq!(frac_1_512.recip(), Wrapping(I8F24::ZERO));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn recip(self) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_recip())
+    }
+
+    /// Returns the reciprocal (inverse), 1/`self`, rounded according to the
+    /// given [`RoundingMode`].
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_recip\_round][FixedI32::wrapping_recip_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_recip\_round][FixedU32::wrapping_recip_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I8F24, RoundingMode, Wrapping};
+    /// let three = Wrapping(I8F24::from_num(3));
+    /// assert_eq!(three.recip_round(RoundingMode::Floor), Wrapping(I8F24::from_bits(0x55_5555)));
+    /// assert_eq!(three.recip_round(RoundingMode::Ceil), Wrapping(I8F24::from_bits(0x55_5556)));
+    /// let frac_1_512 = Wrapping(I8F24::ONE / 512);
+    /// assert_eq!(frac_1_512.recip_round(RoundingMode::Ceil), Wrapping(I8F24::ZERO));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn recip_round(self, mode: RoundingMode) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_recip_round(mode))
     }
 
     /// Euclidean division.